@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+
+/// The exported functions that can not be invoked through the dispatcher and
+/// are therefore not in [`FUNCTION_NAMES`].
+const UNDISPATCHED_FUNCTION_NAMES: &[&str] =
+    &["batch", "submit_request", "poll_request", "cancel_request"];
+
+pub fn generate_c_header() -> String {
+    let mut header = format!(
+        r#"/* This file is generated by the generator crate, do not edit it. */
+
+#ifndef RADIX_ENGINE_TOOLKIT_H
+#define RADIX_ENGINE_TOOLKIT_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define RADIX_ENGINE_TOOLKIT_ABI_VERSION {ABI_VERSION}
+
+/*
+ * The buffer returned by all of the functions of the toolkit. It holds the
+ * serialized response of the function, its `output` along with any
+ * `warnings`, or the serialized error if `is_error` is set. The response and
+ * error are serialized as JSON by the functions that take a JSON string and
+ * as CBOR by their `_cbor` variants that take a CBOR encoded buffer. The
+ * buffer is not null-terminated, it's owned by the caller and must be released
+ * through `toolkit_free_ret_buffer` or by passing its `ptr` to
+ * `toolkit_free_response`.
+ */
+typedef struct RetBuffer {{
+    uint8_t *ptr;
+    size_t len;
+    bool is_error;
+}} RetBuffer;
+
+uint32_t toolkit_abi_version(void);
+char *toolkit_alloc(size_t capacity);
+void toolkit_free(char *pointer, size_t capacity);
+void toolkit_free_c_string(char *pointer);
+void toolkit_free_ret_buffer(RetBuffer buffer);
+bool toolkit_free_response(uint8_t *pointer);
+
+/*
+ * An arena that the buffers returned by the functions invoked on the thread
+ * that created it are allocated in. Freeing those buffers is a no-op, they're
+ * all released when the arena is reset or destroyed. A thread has at most one
+ * arena at a time: `toolkit_arena_create` returns NULL if the thread already
+ * has one.
+ */
+typedef struct Arena Arena;
+
+Arena *toolkit_arena_create(void);
+void toolkit_arena_reset(Arena *arena);
+void toolkit_arena_destroy(Arena *arena);
+
+"#
+    );
+
+    for function_name in
+        FUNCTION_NAMES.iter().chain(UNDISPATCHED_FUNCTION_NAMES)
+    {
+        header.push_str(&format!(
+            "RetBuffer {function_name}(const char *input);\n"
+        ));
+        header.push_str(&format!(
+            "RetBuffer {function_name}_cbor(const uint8_t *input, size_t len);\n"
+        ));
+    }
+
+    header.push_str("\n#endif /* RADIX_ENGINE_TOOLKIT_H */\n");
+    header
+}
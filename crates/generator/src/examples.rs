@@ -0,0 +1,46 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The canonical examples of the inputs and outputs of the functions of the
+//! JSON interface as a library API. These are the same examples that the
+//! generator writes to the `function_examples` output directory, which the
+//! test suites of the bindings can use to check that they serialize inputs and
+//! deserialize outputs exactly like the toolkit does.
+
+use indexmap::IndexMap;
+
+pub use crate::function_examples::traits::FunctionExample;
+
+/// A function example with the input and output in their JSON form.
+pub type JsonFunctionExample =
+    FunctionExample<serde_json::Value, serde_json::Value>;
+
+/// Returns the examples of all of the functions that have examples, grouped
+/// by the module of the functions and keyed by the name that the functions
+/// are dispatched under.
+pub fn all_examples(
+) -> IndexMap<&'static str, IndexMap<String, Vec<JsonFunctionExample>>> {
+    crate::function_examples::generator::generate_function_examples()
+}
+
+/// Returns the examples of the function with the given dispatch name or
+/// [`None`] if the function has no examples.
+pub fn examples_of(function_name: &str) -> Option<Vec<JsonFunctionExample>> {
+    all_examples()
+        .into_values()
+        .find_map(|mut functions| functions.shift_remove(function_name))
+}
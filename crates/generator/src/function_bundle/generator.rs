@@ -0,0 +1,131 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Generates documents that describe all of the functions of the toolkit and
+//! bundle the schemas of their inputs and outputs together with the types
+//! that they refer to, which allows typed clients to be generated for them.
+
+use radix_engine_toolkit::functions::information::information;
+use radix_engine_toolkit_json::prelude::*;
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::Schema;
+use schemars::Map;
+use serde_json::{json, Value};
+
+const TITLE: &str = "Radix Engine Toolkit";
+
+/// Generates a single JSON Schema document with a property for each of the
+/// functions holding the schemas of its input and output.
+pub fn generate_json_schema_bundle() -> Value {
+    let (functions, definitions) = function_schemas("#/definitions/");
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": TITLE,
+        "type": "object",
+        "properties": functions
+            .into_iter()
+            .map(|(name, input, output)| {
+                (
+                    name.to_owned(),
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "input": input,
+                            "output": output,
+                        },
+                        "required": ["input", "output"],
+                    }),
+                )
+            })
+            .collect::<Map<_, _>>(),
+        "definitions": definitions,
+    })
+}
+
+/// Generates an OpenRPC document with a method for each of the functions that
+/// takes the input of the function as its only parameter.
+pub fn generate_open_rpc_document() -> Value {
+    let (functions, definitions) = function_schemas("#/components/schemas/");
+    json!({
+        "openrpc": "1.2.6",
+        "info": {
+            "title": TITLE,
+            "version": information().version,
+        },
+        "methods": functions
+            .into_iter()
+            .map(|(name, input, output)| {
+                json!({
+                    "name": name,
+                    "paramStructure": "by-position",
+                    "params": [{
+                        "name": "input",
+                        "required": true,
+                        "schema": input,
+                    }],
+                    "result": {
+                        "name": "output",
+                        "schema": output,
+                    },
+                })
+            })
+            .collect::<Vec<_>>(),
+        "components": {
+            "schemas": definitions,
+        },
+    })
+}
+
+/// Generates the schemas of the inputs and outputs of all of the exported
+/// functions along with the definitions of the types that they refer to
+/// through the given path.
+fn function_schemas(
+    definitions_path: &str,
+) -> (Vec<(&'static str, Schema, Schema)>, Map<String, Schema>) {
+    let mut settings = SchemaSettings::draft07();
+    settings.definitions_path = definitions_path.to_owned();
+    let mut generator = settings.into_generator();
+
+    let mut functions =
+        radix_engine_toolkit_json::functions::dispatch::function_schemas(
+            &mut generator,
+        );
+    // The functions that can not be invoked through the dispatcher.
+    functions.extend([
+        function_schema::<Batch>("batch", &mut generator),
+        function_schema::<SubmitRequest>("submit_request", &mut generator),
+        function_schema::<PollRequest>("poll_request", &mut generator),
+        function_schema::<CancelRequest>("cancel_request", &mut generator),
+    ]);
+
+    let definitions = generator.take_definitions();
+    (functions, definitions)
+}
+
+fn function_schema<'f, F>(
+    name: &'static str,
+    generator: &mut SchemaGenerator,
+) -> (&'static str, Schema, Schema)
+where
+    F: Function<'f>,
+{
+    (
+        name,
+        generator.subschema_for::<F::Input>(),
+        generator.subschema_for::<F::Output>(),
+    )
+}
@@ -170,3 +170,35 @@ impl<'f> HasExamples<'f, EXAMPLE_SIZE> for AddressDecode {
         .map(|serializable| serializable.0.to_string())
     }
 }
+
+impl<'f> HasExamples<'f, EXAMPLE_SIZE> for AddressInspect {
+    fn example_inputs() -> [Self::Input; EXAMPLE_SIZE] {
+        <AddressDecode as HasExamples<'f, EXAMPLE_SIZE>>::example_inputs()
+    }
+}
+
+impl<'f> HasExamples<'f, 2> for AddressValidate {
+    fn example_inputs() -> [Self::Input; 2] {
+        let address = SerializableNodeId::new(XRD.into_node_id(), 0xf2)
+            .0
+            .to_string();
+
+        [
+            Self::Input {
+                address: address.clone(),
+                expected_entity_types: vec![
+                    SerializableEntityType::GlobalFungibleResourceManager,
+                ],
+                expected_network_id: 0xf2.into(),
+            },
+            Self::Input {
+                address,
+                expected_entity_types: vec![
+                    SerializableEntityType::GlobalVirtualSecp256k1Account,
+                    SerializableEntityType::GlobalVirtualEd25519Account,
+                ],
+                expected_network_id: 0x01.into(),
+            },
+        ]
+    }
+}
@@ -79,6 +79,40 @@ impl<'f> HasExamples<'f, 2>
     }
 }
 
+impl<'f> HasExamples<'f, 2> for DeriveVirtualSignatureBadgeFromPublicKey {
+    fn example_inputs() -> [Self::Input; 2] {
+        let public_key1 =
+            Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+        let public_key2 = Ed25519PrivateKey::from_u64(1).unwrap().public_key();
+
+        [
+            Self::Input {
+                public_key: PublicKey::from(public_key1).into(),
+                network_id: 1.into(),
+            },
+            Self::Input {
+                public_key: PublicKey::from(public_key2).into(),
+                network_id: 1.into(),
+            },
+        ]
+    }
+}
+
+impl<'f> HasExamples<'f, 1> for DeriveValidatorOwnerBadgeFromValidatorAddress {
+    fn example_inputs() -> [Self::Input; 1] {
+        let validator_address = ComponentAddress::new_or_panic(
+            [EntityType::GlobalValidator as u8; NodeId::LENGTH],
+        );
+
+        [Self::Input {
+            validator_address: SerializableNodeId::new(
+                validator_address.into_node_id(),
+                1,
+            ),
+        }]
+    }
+}
+
 impl<'f> HasExamples<'f, 1>
     for DeriveVirtualAccountAddressFromOlympiaAccountAddress
 {
@@ -29,12 +29,15 @@ pub fn generate_function_examples() -> IndexMap<
 > {
     indexmap!(
         "information" => function_examples![
-            BuildInformation
+            BuildInformation,
+            ListFunctions,
         ],
         "derive" => function_examples![
             DeriveVirtualAccountAddressFromPublicKey,
             DeriveVirtualIdentityAddressFromPublicKey,
             DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey,
+            DeriveVirtualSignatureBadgeFromPublicKey,
+            DeriveValidatorOwnerBadgeFromValidatorAddress,
             DeriveVirtualAccountAddressFromOlympiaAccountAddress,
             DeriveResourceAddressFromOlympiaResourceAddress,
             DerivePublicKeyFromOlympiaAccountAddress,
@@ -74,11 +77,14 @@ pub fn generate_function_examples() -> IndexMap<
             NotarizedTransactionStaticallyValidate,
         ],
         "utils" => function_examples![
-            UtilsKnownAddress
+            UtilsKnownAddress,
+            UtilsKnownAddressesForNetworks,
         ],
         "address" => function_examples![
             AddressEntityType,
             AddressDecode,
+            AddressInspect,
+            AddressValidate,
         ]
     )
 }
@@ -23,3 +23,9 @@ impl<'f> HasExamples<'f, 1> for BuildInformation {
         [BuildInformationInput {}]
     }
 }
+
+impl<'f> HasExamples<'f, 1> for ListFunctions {
+    fn example_inputs() -> [Self::Input; 1] {
+        [ListFunctionsInput {}]
+    }
+}
@@ -68,6 +68,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for InstructionsCompile {
              }| Self::Input {
                 instructions,
                 network_id,
+                limits: None,
             },
         )
     }
@@ -79,6 +80,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for InstructionsDecompile {
             InstructionsDecompileInput {
                 compiled: output,
                 instructions_kind: SerializableInstructionsKind::String,
+                annotate: false,
                 network_id: 0xf2.into(),
             }
         })
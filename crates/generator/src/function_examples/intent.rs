@@ -32,6 +32,10 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentCompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
         NotarizedTransactionHash::example_inputs()
             .map(|tx| tx.signed_intent.intent)
+            .map(|intent| Self::Input {
+                intent,
+                limits: None,
+            })
     }
 }
 
@@ -40,6 +44,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for IntentDecompile {
         IntentCompile::example_outputs().map(|output| IntentDecompileInput {
             compiled: output,
             instructions_kind: SerializableInstructionsKind::String,
+            annotate: false,
         })
     }
 }
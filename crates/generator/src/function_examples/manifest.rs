@@ -38,6 +38,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for ManifestCompile {
             .map(|transaction| Self::Input {
                 manifest: transaction,
                 network_id: 0xf2.into(),
+                limits: None,
             })
     }
 }
@@ -48,6 +49,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for ManifestDecompile {
             ManifestDecompileInput {
                 compiled: output,
                 instructions_kind: SerializableInstructionsKind::String,
+                annotate: false,
                 network_id: 0xf2.into(),
             }
         })
@@ -20,7 +20,7 @@ use radix_engine_toolkit_json::prelude::*;
 use radix_transactions::manifest::*;
 use walkdir::WalkDir;
 
-pub const NUMBER_OF_MANIFESTS: usize = 26;
+pub const NUMBER_OF_MANIFESTS: usize = 27;
 pub const NUMBER_OF_MANIFESTS_DOUBLE: usize = NUMBER_OF_MANIFESTS * 2;
 
 pub fn get_serializable_instructions(
@@ -38,7 +38,10 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE>
     for NotarizedTransactionCompile
 {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
-        notarized_transactions()
+        notarized_transactions().map(|notarized_transaction| Self::Input {
+            notarized_transaction,
+            limits: None,
+        })
     }
 }
 
@@ -50,6 +53,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE>
             NotarizedTransactionDecompileInput {
                 compiled: output,
                 instructions_kind: SerializableInstructionsKind::String,
+                annotate: false,
             }
         })
     }
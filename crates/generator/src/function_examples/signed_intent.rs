@@ -29,7 +29,10 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for SignedIntentHash {
 
 impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for SignedIntentCompile {
     fn example_inputs() -> [Self::Input; NUMBER_OF_MANIFESTS_DOUBLE] {
-        NotarizedTransactionHash::example_inputs().map(|tx| tx.signed_intent)
+        NotarizedTransactionHash::example_inputs().map(|tx| Self::Input {
+            signed_intent: tx.signed_intent,
+            limits: None,
+        })
     }
 }
 
@@ -39,6 +42,7 @@ impl<'f> HasExamples<'f, NUMBER_OF_MANIFESTS_DOUBLE> for SignedIntentDecompile {
             SignedIntentDecompileInput {
                 compiled: output,
                 instructions_kind: SerializableInstructionsKind::String,
+                annotate: false,
             }
         })
     }
@@ -54,10 +54,10 @@ where
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct FunctionExample<I, O> {
-    input: I,
-    output: O,
+    pub input: I,
+    pub output: O,
 }
 
 fn unwrap_or_panic<O, E>(result: Result<O, E>) -> O {
@@ -19,6 +19,12 @@ use super::traits::HasExamples;
 use radix_engine_toolkit_json::prelude::*;
 
 impl<'f> HasExamples<'f, 0xFF> for UtilsKnownAddress {
+    // The function is dispatched under a name that's different from the name
+    // of its type.
+    fn function_name() -> String {
+        "utils_known_addresses".to_owned()
+    }
+
     fn example_inputs() -> [Self::Input; 0xFF] {
         (1u8..=0xFFu8)
             .map(Into::into)
@@ -27,3 +33,9 @@ impl<'f> HasExamples<'f, 0xFF> for UtilsKnownAddress {
             .unwrap()
     }
 }
+
+impl<'f> HasExamples<'f, 1> for UtilsKnownAddressesForNetworks {
+    fn example_inputs() -> [Self::Input; 1] {
+        [vec![1.into(), 2.into()]]
+    }
+}
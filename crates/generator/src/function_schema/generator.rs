@@ -25,16 +25,23 @@ pub fn generate_function_schema(
     indexmap!(
         "information" => function_schema![
             BuildInformation,
+            ListFunctions,
+            ErrorCatalog,
         ],
         "derive" => function_schema![
             DeriveVirtualAccountAddressFromPublicKey,
             DeriveVirtualIdentityAddressFromPublicKey,
             DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey,
+            DeriveVirtualSignatureBadgeFromPublicKey,
+            DeriveValidatorOwnerBadgeFromValidatorAddress,
             DeriveVirtualAccountAddressFromOlympiaAccountAddress,
             DeriveResourceAddressFromOlympiaResourceAddress,
             DerivePublicKeyFromOlympiaAccountAddress,
             DeriveOlympiaAccountAddressFromPublicKey,
+            DeriveOlympiaAccountAddressDetails,
             DeriveNodeAddressFromPublicKey,
+            DerivePublicKeyFromMnemonic,
+            DeriveKeyPairAtDerivationPath,
         ],
         "instructions" => function_schema![
             InstructionsHash,
@@ -49,37 +56,168 @@ pub fn generate_function_schema(
             ManifestCompile,
             ManifestDecompile,
             ManifestStaticallyValidate,
+            ManifestAnalyze,
+            ManifestClassify,
+            ManifestConvertNetwork,
+            ManifestLint,
+            ManifestDiff,
+            ManifestInstantiateTemplate,
+            ManifestResourceMovementsGraph,
+            ManifestDetectReservedInstructions,
+            ManifestValidateInstructionArguments,
+            ManifestAccountInteractions,
+            ManifestRequiredSigners,
+            ManifestStatistics,
+            ManifestCheckReorder,
+            ManifestSetFeePayer,
+            ManifestAddBlob,
+            ManifestGetBlob,
+            ManifestSecurityImpacts,
+            ManifestRenderSummary,
+            ManifestRunAnalyzers,
         ],
         "intent" => function_schema![
             IntentHash,
+            IntentHashFromCompiled,
             IntentCompile,
             IntentDecompile,
             IntentStaticallyValidate,
+            IntentSign,
+            IntentMergeSignatures,
+            BuildTransactionHeader,
         ],
         "signed_intent" => function_schema![
             SignedIntentHash,
+            SignedIntentHashFromCompiled,
             SignedIntentCompile,
             SignedIntentDecompile,
             SignedIntentStaticallyValidate,
+            SignedIntentNotarize,
         ],
         "notarized_transaction" => function_schema![
             NotarizedTransactionHash,
+            NotarizedTransactionHashFromCompiled,
             NotarizedTransactionCompile,
             NotarizedTransactionDecompile,
             NotarizedTransactionStaticallyValidate,
+            NotarizedTransactionVerifySignatures,
+        ],
+        "package" => function_schema![
+            PackageExtractBlueprintInterfaces,
+            PackageBuildCallArguments,
+            PackageBuildPublishManifest,
+            PackageHash,
+            PackagePredictAddress,
+            PackageAllocationIndex,
+        ],
+        "payload" => function_schema![
+            PayloadDecompileUnknown,
+        ],
+        "preview" => function_schema![
+            BuildPreviewTransaction,
+            ParsePreviewReceipt,
+        ],
+        "signing" => function_schema![
+            SignHash,
+            VerifySignature,
+        ],
+        "random" => function_schema![
+            GenerateSecureRandomNonce,
+            GenerateEd25519Keypair,
+            GenerateSecp256k1Keypair,
         ],
         "utils" => function_schema![
-            UtilsKnownAddress
+            UtilsKnownAddress,
+            UtilsKnownAddressesForNetworks,
+            UtilsClearCaches,
+            UtilsMemoryStatistics,
+        ],
+        "decimal" => function_schema![
+            DecimalArithmetic,
+            PreciseDecimalArithmetic,
+            FormatDecimal,
+            ParseDecimal,
+        ],
+        "xrd" => function_schema![
+            XrdToAttos,
+            AttosToXrd,
+            CostUnitsToXrd,
+        ],
+        "royalty" => function_schema![
+            BuildSetComponentRoyaltyInstruction,
+        ],
+        "account" => function_schema![
+            BuildAccountInstruction,
+        ],
+        "validator" => function_schema![
+            BuildValidatorManifest,
+        ],
+        "pool" => function_schema![
+            BuildPoolManifest,
+        ],
+        "access_controller" => function_schema![
+            BuildAccessControllerManifest,
+        ],
+        "account_locker" => function_schema![
+            BuildAccountLockerManifest,
         ],
         "scrypto_sbor" => function_schema![
-            ScryptoSborDecodeToString
+            ScryptoSborDecodeToString,
+            ScryptoSborDecodeToEvents,
+            ScryptoSborQuery,
+        ],
+        "events" => function_schema![
+            DecodeEvents,
         ],
         "manifest_sbor" => function_schema![
             ManifestSborDecodeToString
         ],
+        "metadata" => function_schema![
+            MetadataValueEncode,
+            MetadataValueDecode,
+        ],
+        "non_fungible_data" => function_schema![
+            EncodeNonFungibleData,
+            DecodeNonFungibleData,
+        ],
+        "non_fungible_local_id" => function_schema![
+            NonFungibleLocalIdParse,
+            NonFungibleLocalIdFormat,
+            NonFungibleLocalIdValidate,
+        ],
+        "non_fungible_global_id" => function_schema![
+            BuildNonFungibleGlobalId,
+            ParseNonFungibleGlobalId,
+        ],
+        "access_rule" => function_schema![
+            AccessRuleDecode,
+            AccessRuleToManifestValue,
+        ],
+        "ledger" => function_schema![
+            LedgerSignTransactionPayload,
+        ],
+        "rola" => function_schema![
+            RolaMessage,
+            RolaVerifySignature,
+        ],
+        "wallet_interaction" => function_schema![
+            WalletInteractionCanonicalize,
+            WalletInteractionDeepLinkEncode,
+            WalletInteractionDeepLinkDecode,
+        ],
         "address" => function_schema![
             AddressEntityType,
             AddressDecode,
+            AddressInspect,
+            AddressValidate,
+        ],
+        "batch" => function_schema![
+            Batch
+        ],
+        "jobs" => function_schema![
+            SubmitRequest,
+            PollRequest,
+            CancelRequest,
         ]
     )
 }
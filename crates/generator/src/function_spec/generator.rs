@@ -21,14 +21,21 @@ use rocket_okapi::okapi::openapi3::OpenApi;
 pub fn generate_function_spec() -> OpenApi {
     open_api_spec![
         BuildInformation,
+        ListFunctions,
+        ErrorCatalog,
         DeriveVirtualAccountAddressFromPublicKey,
         DeriveVirtualIdentityAddressFromPublicKey,
         DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey,
+        DeriveVirtualSignatureBadgeFromPublicKey,
+        DeriveValidatorOwnerBadgeFromValidatorAddress,
         DeriveVirtualAccountAddressFromOlympiaAccountAddress,
         DeriveResourceAddressFromOlympiaResourceAddress,
         DerivePublicKeyFromOlympiaAccountAddress,
         DeriveOlympiaAccountAddressFromPublicKey,
+        DeriveOlympiaAccountAddressDetails,
         DeriveNodeAddressFromPublicKey,
+        DerivePublicKeyFromMnemonic,
+        DeriveKeyPairAtDerivationPath,
         InstructionsHash,
         InstructionsConvert,
         InstructionsCompile,
@@ -39,22 +46,98 @@ pub fn generate_function_spec() -> OpenApi {
         ManifestCompile,
         ManifestDecompile,
         ManifestStaticallyValidate,
+        ManifestConvertNetwork,
+        ManifestClassify,
+        ManifestLint,
+        ManifestDiff,
+        ManifestInstantiateTemplate,
+        ManifestResourceMovementsGraph,
+        ManifestDetectReservedInstructions,
+        ManifestValidateInstructionArguments,
+        ManifestAccountInteractions,
+        ManifestRequiredSigners,
+        ManifestStatistics,
+        ManifestCheckReorder,
+        ManifestSetFeePayer,
+        ManifestAddBlob,
+        ManifestGetBlob,
+        ManifestSecurityImpacts,
+        ManifestRenderSummary,
+        ManifestAnalyze,
+        ManifestRunAnalyzers,
         IntentHash,
+        IntentHashFromCompiled,
         IntentCompile,
         IntentDecompile,
         IntentStaticallyValidate,
+        IntentSign,
+        IntentMergeSignatures,
+        BuildTransactionHeader,
         SignedIntentHash,
+        SignedIntentHashFromCompiled,
         SignedIntentCompile,
         SignedIntentDecompile,
         SignedIntentStaticallyValidate,
+        SignedIntentNotarize,
+        SignHash,
+        VerifySignature,
+        GenerateSecureRandomNonce,
+        GenerateEd25519Keypair,
+        GenerateSecp256k1Keypair,
         NotarizedTransactionHash,
+        NotarizedTransactionHashFromCompiled,
         NotarizedTransactionCompile,
         NotarizedTransactionDecompile,
         NotarizedTransactionStaticallyValidate,
+        NotarizedTransactionVerifySignatures,
+        PackageExtractBlueprintInterfaces,
+        PackageBuildCallArguments,
+        PackageBuildPublishManifest,
+        PackageHash,
+        PackagePredictAddress,
+        PackageAllocationIndex,
+        BuildPreviewTransaction,
+        ParsePreviewReceipt,
         UtilsKnownAddress,
+        UtilsKnownAddressesForNetworks,
+        UtilsClearCaches,
+        UtilsMemoryStatistics,
         ScryptoSborDecodeToString,
+        ScryptoSborDecodeToEvents,
+        ScryptoSborQuery,
         ScryptoSborEncodeStringRepresentation,
+        DecodeEvents,
         ManifestSborDecodeToString,
+        MetadataValueEncode,
+        MetadataValueDecode,
+        EncodeNonFungibleData,
+        DecodeNonFungibleData,
+        NonFungibleLocalIdParse,
+        NonFungibleLocalIdFormat,
+        NonFungibleLocalIdValidate,
+        BuildNonFungibleGlobalId,
+        ParseNonFungibleGlobalId,
+        AccessRuleDecode,
+        AccessRuleToManifestValue,
+        LedgerSignTransactionPayload,
+        RolaMessage,
+        RolaVerifySignature,
+        WalletInteractionCanonicalize,
+        WalletInteractionDeepLinkEncode,
+        WalletInteractionDeepLinkDecode,
+        DecimalArithmetic,
+        PreciseDecimalArithmetic,
+        FormatDecimal,
+        ParseDecimal,
+        XrdToAttos,
+        AttosToXrd,
+        CostUnitsToXrd,
+        BuildSetComponentRoyaltyInstruction,
+        BuildAccountInstruction,
+        BuildValidatorManifest,
+        BuildPoolManifest,
+        BuildAccessControllerManifest,
+        BuildAccountLockerManifest,
     ]
 }
 
@@ -15,19 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
-mod function_examples;
-mod function_schema;
-mod function_spec;
-mod serializable_models;
-mod utils;
-
-use crate::function_examples::generator::generate_function_examples;
-use function_schema::generator::generate_function_schema;
-use function_spec::generator::generate_function_spec;
-use serializable_models::generator::generate_serializable_model_examples;
+use generator::c_header::generator::generate_c_header;
+use generator::function_bundle::generator::{
+    generate_json_schema_bundle, generate_open_rpc_document,
+};
+use generator::function_examples::generator::generate_function_examples;
+use generator::function_schema::generator::generate_function_schema;
+use generator::function_spec::generator::generate_function_spec;
+use generator::serializable_models::generator::{
+    generate_serializable_model_examples,
+};
+use generator::test_vectors::generator::generate_test_vectors;
+use generator::utils::convert_open_api_adts_to_discriminated_unions;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use utils::convert_open_api_adts_to_discriminated_unions;
 
 fn main() {
     let output_directory =
@@ -81,6 +82,28 @@ fn main() {
         }
     }
 
+    // Generating the bundled JSON schema and OpenRPC document
+    {
+        let output_directory = output_directory.join("function_bundle");
+        std::fs::create_dir_all(&output_directory).unwrap();
+
+        {
+            let output_path = output_directory.join("schema.json");
+            let serialized =
+                serde_json::to_string_pretty(&generate_json_schema_bundle())
+                    .unwrap();
+            std::fs::write(output_path, serialized).unwrap();
+        }
+
+        {
+            let output_path = output_directory.join("openrpc.json");
+            let serialized =
+                serde_json::to_string_pretty(&generate_open_rpc_document())
+                    .unwrap();
+            std::fs::write(output_path, serialized).unwrap();
+        }
+    }
+
     // Generating the model examples
     {
         let output_directory =
@@ -134,6 +157,18 @@ fn main() {
         }
     }
 
+    // Generating the test vectors of the hashing, derivation, compilation, and
+    // signing functions
+    {
+        let output_directory = output_directory.join("test_vectors");
+        std::fs::create_dir_all(&output_directory).unwrap();
+
+        let output_path = output_directory.join("test_vectors.json");
+        let serialized =
+            serde_json::to_string_pretty(&generate_test_vectors()).unwrap();
+        std::fs::write(output_path, serialized).unwrap();
+    }
+
     // Generating the OpenAPI spec
     {
         let output_directory = output_directory.join("function_spec");
@@ -150,4 +185,13 @@ fn main() {
             .unwrap();
         std::fs::write(output_path, serialized).unwrap();
     }
+
+    // Generating the C header
+    {
+        let output_directory = output_directory.join("c_header");
+        std::fs::create_dir_all(&output_directory).unwrap();
+
+        let output_path = output_directory.join("radix_engine_toolkit.h");
+        std::fs::write(output_path, generate_c_header()).unwrap();
+    }
 }
@@ -0,0 +1,222 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Test vectors of the hashing, derivation, compilation, and signing functions
+//! for each of the networks. Everything that goes into them is deterministic so
+//! the vectors only change when the behavior of the toolkit changes, which
+//! allows the bindings to check their conformance against them in their CI.
+
+use indexmap::{indexmap, IndexMap};
+use radix_common::prelude::*;
+use radix_engine_toolkit_json::prelude::*;
+use radix_transactions::prelude::{
+    ManifestBuilder, TransactionBuilder, TransactionHeaderV1,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::utils;
+
+/// The networks that the test vectors are generated for.
+const NETWORK_IDS: [u8; 3] = [0x01, 0x02, 0xf2];
+
+#[derive(Serialize, Deserialize)]
+pub struct TestVector {
+    function: String,
+    input: serde_json::Value,
+    output: serde_json::Value,
+}
+
+pub fn generate_test_vectors() -> IndexMap<&'static str, Vec<TestVector>> {
+    indexmap! {
+        "hashing" => hashing_test_vectors(),
+        "derivation" => derivation_test_vectors(),
+        "compilation" => compilation_test_vectors(),
+        "signing" => signing_test_vectors(),
+    }
+}
+
+fn hashing_test_vectors() -> Vec<TestVector> {
+    NETWORK_IDS
+        .into_iter()
+        .map(notarized_transaction)
+        .flat_map(|transaction| {
+            [
+                test_vector::<IntentHash>(
+                    transaction.signed_intent.intent.clone(),
+                ),
+                test_vector::<SignedIntentHash>(
+                    transaction.signed_intent.clone(),
+                ),
+                test_vector::<NotarizedTransactionHash>(transaction),
+            ]
+        })
+        .collect()
+}
+
+fn derivation_test_vectors() -> Vec<TestVector> {
+    NETWORK_IDS
+        .into_iter()
+        .flat_map(|network_id| {
+            public_keys().into_iter().flat_map(move |public_key| {
+                [
+                    test_vector::<DeriveVirtualAccountAddressFromPublicKey>(
+                        DeriveVirtualAccountAddressFromPublicKeyInput {
+                            public_key: public_key.into(),
+                            network_id: network_id.into(),
+                        },
+                    ),
+                    test_vector::<DeriveVirtualIdentityAddressFromPublicKey>(
+                        DeriveVirtualIdentityAddressFromPublicKeyInput {
+                            public_key: public_key.into(),
+                            network_id: network_id.into(),
+                        },
+                    ),
+                    test_vector::<
+                        DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey,
+                    >(
+                        DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKeyInput {
+                            public_key: public_key.into(),
+                            network_id: network_id.into(),
+                        },
+                    ),
+                ]
+            })
+        })
+        .collect()
+}
+
+fn compilation_test_vectors() -> Vec<TestVector> {
+    NETWORK_IDS
+        .into_iter()
+        .flat_map(|network_id| {
+            let transaction = notarized_transaction(network_id);
+            [
+                test_vector::<ManifestCompile>(ManifestCompileInput {
+                    manifest: transaction.signed_intent.intent.manifest.clone(),
+                    network_id: network_id.into(),
+                    limits: None,
+                }),
+                test_vector::<IntentCompile>(IntentCompileInput {
+                    intent: transaction.signed_intent.intent.clone(),
+                    limits: None,
+                }),
+                test_vector::<SignedIntentCompile>(SignedIntentCompileInput {
+                    signed_intent: transaction.signed_intent.clone(),
+                    limits: None,
+                }),
+                test_vector::<NotarizedTransactionCompile>(
+                    NotarizedTransactionCompileInput {
+                        notarized_transaction: transaction,
+                        limits: None,
+                    },
+                ),
+            ]
+        })
+        .collect()
+}
+
+fn signing_test_vectors() -> Vec<TestVector> {
+    let private_keys = [
+        (
+            Secp256k1PrivateKey::from_u64(1).unwrap().to_bytes(),
+            SerializableCurveType::Secp256k1,
+        ),
+        (
+            Ed25519PrivateKey::from_u64(1).unwrap().to_bytes(),
+            SerializableCurveType::Ed25519,
+        ),
+    ];
+
+    ["", "Radix Engine Toolkit"]
+        .into_iter()
+        .map(hash)
+        .flat_map(|hash| {
+            private_keys
+                .clone()
+                .into_iter()
+                .map(move |(private_key, curve)| {
+                    test_vector::<SignHash>(SignHashInput {
+                        hash: hash.into(),
+                        private_key: private_key.into(),
+                        curve,
+                    })
+                })
+        })
+        .collect()
+}
+
+fn test_vector<'f, F>(input: F::Input) -> TestVector
+where
+    F: Function<'f>,
+{
+    let serialized_input = serde_json::to_value(&input).unwrap();
+    let output = F::handle(input).unwrap();
+    TestVector {
+        function: utils::snake_case_type_name::<F>(),
+        input: serialized_input,
+        output: serde_json::to_value(output).unwrap(),
+    }
+}
+
+fn public_keys() -> [PublicKey; 2] {
+    [
+        Secp256k1PrivateKey::from_u64(1)
+            .unwrap()
+            .public_key()
+            .into(),
+        Ed25519PrivateKey::from_u64(1).unwrap().public_key().into(),
+    ]
+}
+
+fn notarized_transaction(network_id: u8) -> SerializableNotarizedTransaction {
+    let notary_private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let signer_private_key = Ed25519PrivateKey::from_u64(2).unwrap();
+    let account1 = ComponentAddress::virtual_account_from_public_key(
+        &notary_private_key.public_key(),
+    );
+    let account2 = ComponentAddress::virtual_account_from_public_key(
+        &signer_private_key.public_key(),
+    );
+
+    let transaction = TransactionBuilder::new()
+        .manifest(
+            ManifestBuilder::new()
+                .lock_fee(account1, 10)
+                .withdraw_from_account(account1, XRD, 10)
+                .try_deposit_entire_worktop_or_abort(account2, None)
+                .build(),
+        )
+        .header(TransactionHeaderV1 {
+            network_id,
+            start_epoch_inclusive: Epoch::of(90),
+            end_epoch_exclusive: Epoch::of(100),
+            nonce: 100,
+            notary_public_key: notary_private_key.public_key().into(),
+            notary_is_signatory: true,
+            tip_percentage: 0,
+        })
+        .sign(&signer_private_key)
+        .notarize(&notary_private_key)
+        .build();
+
+    SerializableNotarizedTransaction::from_native(
+        &transaction,
+        network_id,
+        SerializableInstructionsKind::String,
+    )
+    .unwrap()
+}
@@ -0,0 +1,72 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use generator::examples::*;
+use radix_engine_toolkit_json::functions::dispatch::{invoke, FUNCTION_NAMES};
+
+#[test]
+fn examples_are_keyed_by_the_names_that_functions_are_dispatched_under() {
+    // Arrange
+    let examples = all_examples();
+
+    // Act
+    let unknown_function_names = examples
+        .values()
+        .flat_map(|functions| functions.keys())
+        .filter(|name| !FUNCTION_NAMES.contains(&name.as_str()))
+        .collect::<Vec<_>>();
+
+    // Assert
+    assert!(
+        unknown_function_names.is_empty(),
+        "{unknown_function_names:?}"
+    );
+}
+
+#[test]
+fn invoking_functions_with_example_inputs_produces_example_outputs() {
+    for (function_name, examples) in all_examples()
+        .into_values()
+        .flat_map(IntoIterator::into_iter)
+    {
+        for FunctionExample { input, output } in examples {
+            // Act
+            let response = invoke(&function_name, input).unwrap();
+
+            // Assert
+            assert_eq!(response.output, output, "{function_name}");
+        }
+    }
+}
+
+#[test]
+fn examples_of_a_function_can_be_looked_up_by_its_name() {
+    // Act
+    let examples = examples_of("utils_known_addresses_for_networks");
+
+    // Assert
+    assert!(examples.is_some_and(|examples| !examples.is_empty()));
+}
+
+#[test]
+fn functions_without_examples_have_no_examples() {
+    // Act
+    let examples = examples_of("this_function_does_not_exist");
+
+    // Assert
+    assert!(examples.is_none());
+}
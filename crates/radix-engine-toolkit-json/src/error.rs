@@ -15,12 +15,41 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit::functions::access_controller::AccessControllerOperationError;
+use radix_engine_toolkit::functions::account_locker::AccountLockerOperationError;
+use radix_engine_toolkit::functions::decimal::DecimalFormatError;
+use radix_engine_toolkit::functions::diff::ManifestDiffError;
+use radix_engine_toolkit::functions::intent::{
+    SignatureMergeError, TransactionHeaderError,
+};
+use radix_engine_toolkit::functions::ledger::LedgerPayloadError;
+use radix_engine_toolkit::functions::limits::LimitExceeded;
 use radix_engine_toolkit::functions::manifest_sbor::ManifestSborError;
+use radix_engine_toolkit::functions::manifest_template::ManifestTemplateError;
+use radix_engine_toolkit::functions::non_fungible_data::NonFungibleDataError;
+use radix_engine_toolkit::functions::non_fungible_global_id::NonFungibleGlobalIdError;
+use radix_engine_toolkit::functions::non_fungible_local_id::NonFungibleLocalIdError;
+use radix_engine_toolkit::functions::package::{
+    CallArgumentsError, PackageDefinitionError,
+};
+use radix_engine_toolkit::functions::pool::PoolOperationError;
+use radix_engine_toolkit::functions::preview::{
+    PreviewReceiptError, PreviewTransactionError,
+};
+use radix_engine_toolkit::functions::random::RandomError;
+use radix_engine_toolkit::functions::reorder::ReorderError;
+use radix_engine_toolkit::functions::rola::RolaError;
 use radix_engine_toolkit::functions::scrypto_sbor::ScryptoSborError;
+use radix_engine_toolkit::functions::signing::SigningError;
+use radix_engine_toolkit::functions::wallet_interaction::WalletInteractionError;
+use radix_engine_toolkit::transaction_types::TransactionTypesError;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::models::common::SerializableU64;
+use crate::models::transaction::compilation_limits::SerializableLimit;
 use crate::models::transaction::instructions::SerializableInstructionsError;
+use crate::models::value::ValueConversionError;
 use crate::utils::debug_string;
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug)]
@@ -38,6 +67,11 @@ pub enum InvocationInterpretationError {
     Utf8Error(String),
     FailedToAllocateJniString(String),
     FailedToReadJniString(String),
+    UnknownFunction(String),
+    FramingError(String),
+    /// The request was written against a version of the request schema that
+    /// is not supported.
+    UnsupportedSchemaVersion(String),
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug)]
@@ -52,6 +86,200 @@ pub enum InvocationHandlingError {
     ScryptoSborError(String),
     InvalidAddress(String),
     ExecutionModuleError(String),
+    SigningError(String),
+    UnknownJob(String),
+    ArithmeticError(String),
+    ManifestTemplateError(String),
+    PackageDefinitionError(String),
+    CallArgumentsError(String),
+    PreviewTransactionError(String),
+    PreviewReceiptError(String),
+    SignatureMergeError(String),
+    TransactionHeaderError(String),
+    RandomError(String),
+    ReorderError(String),
+    ManifestModificationError(String),
+    ValueConversionError(String),
+    LedgerPayloadError(String),
+    RolaError(String),
+    WalletInteractionError(String),
+    NonFungibleDataError(String),
+    NonFungibleLocalIdError(String),
+    NonFungibleGlobalIdError(String),
+    DecimalFormatError(String),
+    PoolOperationError(String),
+    AccessControllerOperationError(String),
+    AccountLockerOperationError(String),
+    /// Handling a submitted request panicked.
+    JobPanicked(String),
+    ManifestDiffError(String),
+    /// The provided compilation limits can't be represented on this platform.
+    CompilationLimitsError(String),
+    /// The compiled payload exceeds one of the limits it was checked against.
+    LimitExceeded {
+        limit: SerializableLimit,
+        actual: SerializableU64,
+    },
+}
+
+/// The flat envelope that errors are returned to callers in, regardless of
+/// the interface that the function was invoked through. The `code` is stable
+/// across releases and is the recommended way for callers to identify errors,
+/// the `kind` is the name of the error variant, the `message` is meant for
+/// humans, and `details` holds any structured data of the error (or `null` if
+/// there's none).
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq)]
+pub struct ErrorResponse {
+    pub code: u32,
+    pub kind: String,
+    pub message: String,
+    pub details: serde_json::Value,
+}
+
+impl From<Error> for ErrorResponse {
+    fn from(value: Error) -> Self {
+        let code = value.code();
+        let kind = value.kind().to_owned();
+
+        // The serialized representation of the error is `{ kind, error }` at
+        // each level of nesting, the innermost `error` is the content of the
+        // variant.
+        let content = serde_json::to_value(&value)
+            .ok()
+            .and_then(|value| value.get("error")?.get("error").cloned())
+            .unwrap_or_default();
+        let (message, details) = match content {
+            serde_json::Value::String(message) => {
+                (message, serde_json::Value::Null)
+            }
+            details => (debug_string(&value), details),
+        };
+
+        Self {
+            code,
+            kind,
+            message,
+            details,
+        }
+    }
+}
+
+/// An entry of the [`ERROR_CATALOG`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorCatalogEntry {
+    pub code: u32,
+    pub kind: &'static str,
+    /// The name of the error enum that the kind belongs to.
+    pub category: &'static str,
+}
+
+macro_rules! define_error_codes {
+    (
+        $(
+            $enum: ident {
+                $($variant: ident = $code: literal),* $(,)?
+            }
+        )*
+    ) => {
+        $(
+            impl $enum {
+                pub fn code(&self) -> u32 {
+                    match self {
+                        $(Self::$variant { .. } => $code,)*
+                    }
+                }
+
+                pub fn kind(&self) -> &'static str {
+                    match self {
+                        $(Self::$variant { .. } => stringify!($variant),)*
+                    }
+                }
+            }
+        )*
+
+        /// All of the errors that the toolkit can return along with their
+        /// codes.
+        pub const ERROR_CATALOG: &[ErrorCatalogEntry] = &[
+            $($(
+                ErrorCatalogEntry {
+                    code: $code,
+                    kind: stringify!($variant),
+                    category: stringify!($enum),
+                },
+            )*)*
+        ];
+    };
+}
+
+// The codes are part of the interface of the toolkit: a code must never be
+// changed or reused once released. New errors are given the next free code of
+// their category.
+define_error_codes! {
+    InvocationInterpretationError {
+        SerializationError = 1000,
+        DeserializationError = 1001,
+        Utf8Error = 1002,
+        FailedToAllocateJniString = 1003,
+        FailedToReadJniString = 1004,
+        UnknownFunction = 1005,
+        FramingError = 1006,
+        UnsupportedSchemaVersion = 1007,
+    }
+    InvocationHandlingError {
+        DerivationError = 2000,
+        SerializableInstructionsError = 2001,
+        EncodeError = 2002,
+        DecodeError = 2003,
+        InstructionVisitorError = 2004,
+        ManifestSborError = 2005,
+        ScryptoSborError = 2006,
+        InvalidAddress = 2007,
+        ExecutionModuleError = 2008,
+        SigningError = 2009,
+        UnknownJob = 2010,
+        ArithmeticError = 2011,
+        ManifestTemplateError = 2012,
+        PackageDefinitionError = 2013,
+        CallArgumentsError = 2014,
+        PreviewTransactionError = 2015,
+        PreviewReceiptError = 2016,
+        SignatureMergeError = 2017,
+        TransactionHeaderError = 2018,
+        RandomError = 2019,
+        ReorderError = 2020,
+        ManifestModificationError = 2021,
+        ValueConversionError = 2022,
+        LedgerPayloadError = 2023,
+        RolaError = 2024,
+        WalletInteractionError = 2025,
+        LimitExceeded = 2026,
+        NonFungibleDataError = 2027,
+        NonFungibleLocalIdError = 2028,
+        NonFungibleGlobalIdError = 2029,
+        DecimalFormatError = 2030,
+        PoolOperationError = 2031,
+        AccessControllerOperationError = 2032,
+        AccountLockerOperationError = 2033,
+        JobPanicked = 2034,
+        ManifestDiffError = 2035,
+        CompilationLimitsError = 2036,
+    }
+}
+
+impl Error {
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::InvocationInterpretationError(error) => error.code(),
+            Self::InvocationHandlingError(error) => error.code(),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvocationInterpretationError(error) => error.kind(),
+            Self::InvocationHandlingError(error) => error.kind(),
+        }
+    }
 }
 
 impl From<InvocationHandlingError> for Error {
@@ -83,3 +311,150 @@ impl From<ScryptoSborError> for InvocationHandlingError {
         Self::ScryptoSborError(debug_string(value))
     }
 }
+
+impl From<SigningError> for InvocationHandlingError {
+    fn from(value: SigningError) -> Self {
+        Self::SigningError(debug_string(value))
+    }
+}
+
+impl From<ManifestTemplateError> for InvocationHandlingError {
+    fn from(value: ManifestTemplateError) -> Self {
+        Self::ManifestTemplateError(debug_string(value))
+    }
+}
+
+impl From<PackageDefinitionError> for InvocationHandlingError {
+    fn from(value: PackageDefinitionError) -> Self {
+        Self::PackageDefinitionError(debug_string(value))
+    }
+}
+
+impl From<ValueConversionError> for InvocationHandlingError {
+    fn from(value: ValueConversionError) -> Self {
+        Self::ValueConversionError(debug_string(value))
+    }
+}
+
+impl From<CallArgumentsError> for InvocationHandlingError {
+    fn from(value: CallArgumentsError) -> Self {
+        Self::CallArgumentsError(debug_string(value))
+    }
+}
+
+impl From<PreviewTransactionError> for InvocationHandlingError {
+    fn from(value: PreviewTransactionError) -> Self {
+        Self::PreviewTransactionError(debug_string(value))
+    }
+}
+
+impl From<PreviewReceiptError> for InvocationHandlingError {
+    fn from(value: PreviewReceiptError) -> Self {
+        Self::PreviewReceiptError(debug_string(value))
+    }
+}
+
+impl From<SignatureMergeError> for InvocationHandlingError {
+    fn from(value: SignatureMergeError) -> Self {
+        Self::SignatureMergeError(debug_string(value))
+    }
+}
+
+impl From<TransactionHeaderError> for InvocationHandlingError {
+    fn from(value: TransactionHeaderError) -> Self {
+        Self::TransactionHeaderError(debug_string(value))
+    }
+}
+
+impl From<RandomError> for InvocationHandlingError {
+    fn from(value: RandomError) -> Self {
+        Self::RandomError(debug_string(value))
+    }
+}
+
+impl From<ReorderError> for InvocationHandlingError {
+    fn from(value: ReorderError) -> Self {
+        Self::ReorderError(debug_string(value))
+    }
+}
+
+impl From<LedgerPayloadError> for InvocationHandlingError {
+    fn from(value: LedgerPayloadError) -> Self {
+        Self::LedgerPayloadError(debug_string(value))
+    }
+}
+
+impl From<RolaError> for InvocationHandlingError {
+    fn from(value: RolaError) -> Self {
+        Self::RolaError(debug_string(value))
+    }
+}
+
+impl From<WalletInteractionError> for InvocationHandlingError {
+    fn from(value: WalletInteractionError) -> Self {
+        Self::WalletInteractionError(debug_string(value))
+    }
+}
+
+impl From<NonFungibleDataError> for InvocationHandlingError {
+    fn from(value: NonFungibleDataError) -> Self {
+        Self::NonFungibleDataError(debug_string(value))
+    }
+}
+
+impl From<NonFungibleLocalIdError> for InvocationHandlingError {
+    fn from(value: NonFungibleLocalIdError) -> Self {
+        Self::NonFungibleLocalIdError(debug_string(value))
+    }
+}
+
+impl From<NonFungibleGlobalIdError> for InvocationHandlingError {
+    fn from(value: NonFungibleGlobalIdError) -> Self {
+        Self::NonFungibleGlobalIdError(debug_string(value))
+    }
+}
+
+impl From<DecimalFormatError> for InvocationHandlingError {
+    fn from(value: DecimalFormatError) -> Self {
+        Self::DecimalFormatError(debug_string(value))
+    }
+}
+
+impl From<PoolOperationError> for InvocationHandlingError {
+    fn from(value: PoolOperationError) -> Self {
+        Self::PoolOperationError(debug_string(value))
+    }
+}
+
+impl From<AccessControllerOperationError> for InvocationHandlingError {
+    fn from(value: AccessControllerOperationError) -> Self {
+        Self::AccessControllerOperationError(debug_string(value))
+    }
+}
+
+impl From<ManifestDiffError> for InvocationHandlingError {
+    fn from(value: ManifestDiffError) -> Self {
+        Self::ManifestDiffError(debug_string(value))
+    }
+}
+
+impl From<AccountLockerOperationError> for InvocationHandlingError {
+    fn from(value: AccountLockerOperationError) -> Self {
+        Self::AccountLockerOperationError(debug_string(value))
+    }
+}
+
+impl From<LimitExceeded> for InvocationHandlingError {
+    fn from(value: LimitExceeded) -> Self {
+        Self::LimitExceeded {
+            limit: value.limit.into(),
+            actual: (value.actual as u64).into(),
+        }
+    }
+}
+
+impl From<TransactionTypesError> for InvocationHandlingError {
+    fn from(value: TransactionTypesError) -> Self {
+        Self::ExecutionModuleError(debug_string(value))
+    }
+}
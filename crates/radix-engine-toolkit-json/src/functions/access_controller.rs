@@ -0,0 +1,225 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::access_controller::RuleSet;
+use radix_engine_toolkit::functions::access_controller::*;
+use schemars::JsonSchema;
+use scrypto::prelude::ComponentAddress;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableAccessControllerProposer {
+    Primary,
+    Recovery,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableRuleSet {
+    pub primary_role: SerializableAccessRule,
+    pub recovery_role: SerializableAccessRule,
+    pub confirmation_role: SerializableAccessRule,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableAccessControllerOperation {
+    InitiateRecovery {
+        proposer: SerializableAccessControllerProposer,
+        rule_set: SerializableRuleSet,
+        timed_recovery_delay_in_minutes: Option<SerializableU32>,
+    },
+    QuickConfirmRecovery {
+        proposer: SerializableAccessControllerProposer,
+        rule_set: SerializableRuleSet,
+        timed_recovery_delay_in_minutes: Option<SerializableU32>,
+    },
+    TimedConfirmRecovery {
+        rule_set: SerializableRuleSet,
+        timed_recovery_delay_in_minutes: Option<SerializableU32>,
+    },
+    CancelRecovery {
+        proposer: SerializableAccessControllerProposer,
+    },
+    InitiateBadgeWithdrawAttempt {
+        proposer: SerializableAccessControllerProposer,
+    },
+    QuickConfirmBadgeWithdrawAttempt {
+        proposer: SerializableAccessControllerProposer,
+        account_address: SerializableNodeId,
+    },
+    CancelBadgeWithdrawAttempt {
+        proposer: SerializableAccessControllerProposer,
+    },
+}
+
+impl From<SerializableAccessControllerProposer> for AccessControllerProposer {
+    fn from(value: SerializableAccessControllerProposer) -> Self {
+        match value {
+            SerializableAccessControllerProposer::Primary => Self::Primary,
+            SerializableAccessControllerProposer::Recovery => Self::Recovery,
+        }
+    }
+}
+
+impl SerializableRuleSet {
+    pub fn to_native(&self) -> Result<RuleSet, SerializableNodeIdError> {
+        Ok(RuleSet {
+            primary_role: self.primary_role.to_native()?,
+            recovery_role: self.recovery_role.to_native()?,
+            confirmation_role: self.confirmation_role.to_native()?,
+        })
+    }
+}
+
+impl SerializableAccessControllerOperation {
+    pub fn to_native(
+        &self,
+    ) -> Result<AccessControllerOperation, SerializableNodeIdError> {
+        let delay = |delay: &Option<SerializableU32>| {
+            delay.as_ref().map(|delay| **delay)
+        };
+
+        let operation = match self {
+            Self::InitiateRecovery {
+                proposer,
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } => AccessControllerOperation::InitiateRecovery {
+                proposer: proposer.clone().into(),
+                rule_set: rule_set.to_native()?,
+                timed_recovery_delay_in_minutes: delay(
+                    timed_recovery_delay_in_minutes,
+                ),
+            },
+            Self::QuickConfirmRecovery {
+                proposer,
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } => AccessControllerOperation::QuickConfirmRecovery {
+                proposer: proposer.clone().into(),
+                rule_set: rule_set.to_native()?,
+                timed_recovery_delay_in_minutes: delay(
+                    timed_recovery_delay_in_minutes,
+                ),
+            },
+            Self::TimedConfirmRecovery {
+                rule_set,
+                timed_recovery_delay_in_minutes,
+            } => AccessControllerOperation::TimedConfirmRecovery {
+                rule_set: rule_set.to_native()?,
+                timed_recovery_delay_in_minutes: delay(
+                    timed_recovery_delay_in_minutes,
+                ),
+            },
+            Self::CancelRecovery { proposer } => {
+                AccessControllerOperation::CancelRecovery {
+                    proposer: proposer.clone().into(),
+                }
+            }
+            Self::InitiateBadgeWithdrawAttempt { proposer } => {
+                AccessControllerOperation::InitiateBadgeWithdrawAttempt {
+                    proposer: proposer.clone().into(),
+                }
+            }
+            Self::QuickConfirmBadgeWithdrawAttempt {
+                proposer,
+                account_address,
+            } => AccessControllerOperation::QuickConfirmBadgeWithdrawAttempt {
+                proposer: proposer.clone().into(),
+                account_address: ComponentAddress::try_from(
+                    account_address.clone(),
+                )?,
+            },
+            Self::CancelBadgeWithdrawAttempt { proposer } => {
+                AccessControllerOperation::CancelBadgeWithdrawAttempt {
+                    proposer: proposer.clone().into(),
+                }
+            }
+        };
+        Ok(operation)
+    }
+}
+
+//==================================
+// Build Access Controller Manifest
+//==================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildAccessControllerManifestInput {
+    pub access_controller_address: SerializableNodeId,
+    pub operations: Vec<SerializableAccessControllerOperation>,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+pub type BuildAccessControllerManifestOutput = SerializableTransactionManifest;
+
+pub struct BuildAccessControllerManifest;
+impl<'f> Function<'f> for BuildAccessControllerManifest {
+    type Input = BuildAccessControllerManifestInput;
+    type Output = BuildAccessControllerManifestOutput;
+
+    fn handle(
+        BuildAccessControllerManifestInput {
+            access_controller_address,
+            operations,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let access_controller_address = ComponentAddress::try_from(
+            access_controller_address,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::InvalidAddress(debug_string(error))
+        })?;
+        let operations = operations
+            .iter()
+            .map(SerializableAccessControllerOperation::to_native)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| {
+                InvocationHandlingError::InvalidAddress(debug_string(error))
+            })?;
+
+        let manifest = radix_engine_toolkit::functions::access_controller::build_access_controller_manifest(
+            access_controller_address,
+            &operations,
+        )?;
+        Ok(SerializableTransactionManifest::from_native(
+            &manifest,
+            *network_id,
+            instructions_kind,
+        )?)
+    }
+}
+
+export_function!(
+    BuildAccessControllerManifest as build_access_controller_manifest
+);
+export_jni_function!(
+    BuildAccessControllerManifest as buildAccessControllerManifest
+);
+export_wasm_bindgen_function!(
+    BuildAccessControllerManifest as buildAccessControllerManifest
+);
@@ -0,0 +1,119 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Deref;
+
+use crate::prelude::*;
+
+use radix_common::prelude::*;
+use schemars::JsonSchema;
+use scrypto::prelude::AccessRule;
+use serde::{Deserialize, Serialize};
+
+//====================
+// Access Rule Decode
+//====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AccessRuleDecodeInput {
+    /// The Scrypto or Manifest SBOR encoded access rule.
+    pub encoded_access_rule: SerializableBytes,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type AccessRuleDecodeOutput = SerializableAccessRule;
+
+pub struct AccessRuleDecode;
+impl<'f> Function<'f> for AccessRuleDecode {
+    type Input = AccessRuleDecodeInput;
+    type Output = AccessRuleDecodeOutput;
+
+    fn handle(
+        AccessRuleDecodeInput {
+            encoded_access_rule,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let encoded = encoded_access_rule.deref();
+        let access_rule = match encoded.first().copied() {
+            Some(MANIFEST_SBOR_V1_PAYLOAD_PREFIX) => {
+                manifest_decode::<AccessRule>(encoded)
+            }
+            _ => scrypto_decode::<AccessRule>(encoded),
+        }
+        .map_err(|error| {
+            InvocationHandlingError::DecodeError(
+                debug_string(error),
+                debug_string(&encoded_access_rule),
+            )
+        })?;
+        Ok(SerializableAccessRule::from_native(
+            &access_rule,
+            *network_id,
+        ))
+    }
+}
+
+export_function!(AccessRuleDecode as access_rule_decode);
+export_jni_function!(AccessRuleDecode as accessRuleDecode);
+export_wasm_bindgen_function!(AccessRuleDecode as accessRuleDecode);
+
+//===============================
+// Access Rule to Manifest Value
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AccessRuleToManifestValueInput {
+    pub access_rule: SerializableAccessRule,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type AccessRuleToManifestValueOutput = SerializableManifestValue;
+
+pub struct AccessRuleToManifestValue;
+impl<'f> Function<'f> for AccessRuleToManifestValue {
+    type Input = AccessRuleToManifestValueInput;
+    type Output = AccessRuleToManifestValueOutput;
+
+    /// Converts the access rule to a manifest value which can be used as an
+    /// argument of the instructions that configure the security of entities.
+    fn handle(
+        AccessRuleToManifestValueInput {
+            access_rule,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let access_rule = access_rule.to_native().map_err(|error| {
+            InvocationHandlingError::InvalidAddress(debug_string(error))
+        })?;
+        let value = to_manifest_value_and_unwrap!(&access_rule);
+        Ok(SerializableManifestValue::from_manifest_value(
+            &value,
+            *network_id,
+        )?)
+    }
+}
+
+export_function!(AccessRuleToManifestValue as access_rule_to_manifest_value);
+export_jni_function!(AccessRuleToManifestValue as accessRuleToManifestValue);
+export_wasm_bindgen_function!(
+    AccessRuleToManifestValue as accessRuleToManifestValue
+);
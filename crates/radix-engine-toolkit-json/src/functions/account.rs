@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::account::*;
+use schemars::JsonSchema;
+use scrypto::prelude::{ComponentAddress, IndexSet, ResourceAddress};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableAccountMethod {
+    Securify,
+    LockFee {
+        amount: SerializableDecimal,
+    },
+    LockContingentFee {
+        amount: SerializableDecimal,
+    },
+    Withdraw {
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    WithdrawNonFungibles {
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+    LockFeeAndWithdraw {
+        amount_to_lock: SerializableDecimal,
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    LockFeeAndWithdrawNonFungibles {
+        amount_to_lock: SerializableDecimal,
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+    CreateProofOfAmount {
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    CreateProofOfNonFungibles {
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+    Deposit {
+        bucket: SerializableBucketId,
+    },
+    DepositBatch {
+        buckets: Vec<SerializableBucketId>,
+    },
+    TryDepositOrAbort {
+        bucket: SerializableBucketId,
+        authorized_depositor_badge: Option<SerializableResourceOrNonFungible>,
+    },
+    TryDepositBatchOrAbort {
+        buckets: Vec<SerializableBucketId>,
+        authorized_depositor_badge: Option<SerializableResourceOrNonFungible>,
+    },
+    TryDepositOrRefund {
+        bucket: SerializableBucketId,
+        authorized_depositor_badge: Option<SerializableResourceOrNonFungible>,
+    },
+    TryDepositBatchOrRefund {
+        buckets: Vec<SerializableBucketId>,
+        authorized_depositor_badge: Option<SerializableResourceOrNonFungible>,
+    },
+    Burn {
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    BurnNonFungibles {
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+    SetDefaultDepositRule {
+        default_deposit_rule: SerializableDefaultDepositRule,
+    },
+    SetResourcePreference {
+        resource_address: SerializableNodeId,
+        resource_preference: SerializableResourcePreference,
+    },
+    RemoveResourcePreference {
+        resource_address: SerializableNodeId,
+    },
+    AddAuthorizedDepositor {
+        badge: SerializableResourceOrNonFungible,
+    },
+    RemoveAuthorizedDepositor {
+        badge: SerializableResourceOrNonFungible,
+    },
+}
+
+impl SerializableAccountMethod {
+    pub fn to_native(&self) -> Result<AccountMethod, SerializableNodeIdError> {
+        let resource = |address: &SerializableNodeId| {
+            ResourceAddress::try_from(address.clone())
+        };
+        let ids = |ids: &[SerializableNonFungibleLocalId]| {
+            ids.iter().map(|id| (**id).clone()).collect::<IndexSet<_>>()
+        };
+        let buckets = |buckets: &[SerializableBucketId]| {
+            buckets.iter().map(|bucket| (*bucket).into()).collect()
+        };
+        let depositor_badge =
+            |badge: &Option<SerializableResourceOrNonFungible>| {
+                badge.as_ref().map(|badge| badge.to_native()).transpose()
+            };
+
+        let method = match self {
+            Self::Securify => AccountMethod::Securify,
+            Self::LockFee { amount } => {
+                AccountMethod::LockFee { amount: **amount }
+            }
+            Self::LockContingentFee { amount } => {
+                AccountMethod::LockContingentFee { amount: **amount }
+            }
+            Self::Withdraw {
+                resource_address,
+                amount,
+            } => AccountMethod::Withdraw {
+                resource_address: resource(resource_address)?,
+                amount: **amount,
+            },
+            Self::WithdrawNonFungibles {
+                resource_address,
+                ids: local_ids,
+            } => AccountMethod::WithdrawNonFungibles {
+                resource_address: resource(resource_address)?,
+                ids: ids(local_ids),
+            },
+            Self::LockFeeAndWithdraw {
+                amount_to_lock,
+                resource_address,
+                amount,
+            } => AccountMethod::LockFeeAndWithdraw {
+                amount_to_lock: **amount_to_lock,
+                resource_address: resource(resource_address)?,
+                amount: **amount,
+            },
+            Self::LockFeeAndWithdrawNonFungibles {
+                amount_to_lock,
+                resource_address,
+                ids: local_ids,
+            } => AccountMethod::LockFeeAndWithdrawNonFungibles {
+                amount_to_lock: **amount_to_lock,
+                resource_address: resource(resource_address)?,
+                ids: ids(local_ids),
+            },
+            Self::CreateProofOfAmount {
+                resource_address,
+                amount,
+            } => AccountMethod::CreateProofOfAmount {
+                resource_address: resource(resource_address)?,
+                amount: **amount,
+            },
+            Self::CreateProofOfNonFungibles {
+                resource_address,
+                ids: local_ids,
+            } => AccountMethod::CreateProofOfNonFungibles {
+                resource_address: resource(resource_address)?,
+                ids: ids(local_ids),
+            },
+            Self::Deposit { bucket } => AccountMethod::Deposit {
+                bucket: (*bucket).into(),
+            },
+            Self::DepositBatch {
+                buckets: bucket_ids,
+            } => AccountMethod::DepositBatch {
+                buckets: buckets(bucket_ids),
+            },
+            Self::TryDepositOrAbort {
+                bucket,
+                authorized_depositor_badge,
+            } => AccountMethod::TryDepositOrAbort {
+                bucket: (*bucket).into(),
+                authorized_depositor_badge: depositor_badge(
+                    authorized_depositor_badge,
+                )?,
+            },
+            Self::TryDepositBatchOrAbort {
+                buckets: bucket_ids,
+                authorized_depositor_badge,
+            } => AccountMethod::TryDepositBatchOrAbort {
+                buckets: buckets(bucket_ids),
+                authorized_depositor_badge: depositor_badge(
+                    authorized_depositor_badge,
+                )?,
+            },
+            Self::TryDepositOrRefund {
+                bucket,
+                authorized_depositor_badge,
+            } => AccountMethod::TryDepositOrRefund {
+                bucket: (*bucket).into(),
+                authorized_depositor_badge: depositor_badge(
+                    authorized_depositor_badge,
+                )?,
+            },
+            Self::TryDepositBatchOrRefund {
+                buckets: bucket_ids,
+                authorized_depositor_badge,
+            } => AccountMethod::TryDepositBatchOrRefund {
+                buckets: buckets(bucket_ids),
+                authorized_depositor_badge: depositor_badge(
+                    authorized_depositor_badge,
+                )?,
+            },
+            Self::Burn {
+                resource_address,
+                amount,
+            } => AccountMethod::Burn {
+                resource_address: resource(resource_address)?,
+                amount: **amount,
+            },
+            Self::BurnNonFungibles {
+                resource_address,
+                ids: local_ids,
+            } => AccountMethod::BurnNonFungibles {
+                resource_address: resource(resource_address)?,
+                ids: ids(local_ids),
+            },
+            Self::SetDefaultDepositRule {
+                default_deposit_rule,
+            } => AccountMethod::SetDefaultDepositRule {
+                default_deposit_rule: default_deposit_rule.clone().into(),
+            },
+            Self::SetResourcePreference {
+                resource_address,
+                resource_preference,
+            } => AccountMethod::SetResourcePreference {
+                resource_address: resource(resource_address)?,
+                resource_preference: resource_preference.clone().into(),
+            },
+            Self::RemoveResourcePreference { resource_address } => {
+                AccountMethod::RemoveResourcePreference {
+                    resource_address: resource(resource_address)?,
+                }
+            }
+            Self::AddAuthorizedDepositor { badge } => {
+                AccountMethod::AddAuthorizedDepositor {
+                    badge: badge.to_native()?,
+                }
+            }
+            Self::RemoveAuthorizedDepositor { badge } => {
+                AccountMethod::RemoveAuthorizedDepositor {
+                    badge: badge.to_native()?,
+                }
+            }
+        };
+        Ok(method)
+    }
+}
+
+//===========================
+// Build Account Instruction
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildAccountInstructionInput {
+    pub account_address: SerializableNodeId,
+    pub method: SerializableAccountMethod,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type BuildAccountInstructionOutput = SerializableInstruction;
+
+pub struct BuildAccountInstruction;
+impl<'f> Function<'f> for BuildAccountInstruction {
+    type Input = BuildAccountInstructionInput;
+    type Output = BuildAccountInstructionOutput;
+
+    fn handle(
+        BuildAccountInstructionInput {
+            account_address,
+            method,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let account_address =
+            ComponentAddress::try_from(account_address.clone())
+                .ok()
+                .filter(radix_engine_toolkit::utils::is_account)
+                .ok_or(InvocationHandlingError::InvalidAddress(
+                    account_address.0.to_string(),
+                ))?;
+        let method = method.to_native().map_err(|error| {
+            InvocationHandlingError::InvalidAddress(debug_string(error))
+        })?;
+
+        let instruction = call_account_method(account_address, &method);
+        SerializableInstruction::from_instruction(&instruction, *network_id)
+            .map_err(|error| LocatedInstructionConversionError {
+                instruction_index: 0,
+                error,
+            })
+            .map_err(SerializableInstructionsError::from)
+            .map_err(InvocationHandlingError::from)
+    }
+}
+
+export_function!(BuildAccountInstruction as build_account_instruction);
+export_jni_function!(BuildAccountInstruction as buildAccountInstruction);
+export_wasm_bindgen_function!(
+    BuildAccountInstruction as buildAccountInstruction
+);
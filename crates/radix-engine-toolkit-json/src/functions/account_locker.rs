@@ -0,0 +1,230 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::account_locker::*;
+use schemars::JsonSchema;
+use scrypto::prelude::{ComponentAddress, ResourceAddress};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableFungibleAirdropClaimant {
+    pub claimant: SerializableNodeId,
+    pub amount: SerializableDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableNonFungibleAirdropClaimant {
+    pub claimant: SerializableNodeId,
+    pub ids: Vec<SerializableNonFungibleLocalId>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableAccountLockerOperation {
+    Store {
+        claimant: SerializableNodeId,
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+        try_direct_send: bool,
+    },
+    StoreNonFungibles {
+        claimant: SerializableNodeId,
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+        try_direct_send: bool,
+    },
+    AirdropFungible {
+        resource_address: SerializableNodeId,
+        claimants: Vec<SerializableFungibleAirdropClaimant>,
+        try_direct_send: bool,
+    },
+    AirdropNonFungibles {
+        resource_address: SerializableNodeId,
+        claimants: Vec<SerializableNonFungibleAirdropClaimant>,
+        try_direct_send: bool,
+    },
+    Claim {
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    ClaimNonFungibles {
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+}
+
+impl SerializableAccountLockerOperation {
+    pub fn to_native(
+        &self,
+    ) -> Result<AccountLockerOperation, SerializableNodeIdError> {
+        let component = |address: &SerializableNodeId| {
+            ComponentAddress::try_from(address.clone())
+        };
+        let resource = |address: &SerializableNodeId| {
+            ResourceAddress::try_from(address.clone())
+        };
+        let ids = |ids: &[SerializableNonFungibleLocalId]| {
+            ids.iter().map(|id| (**id).clone()).collect()
+        };
+
+        let operation = match self {
+            Self::Store {
+                claimant,
+                resource_address,
+                amount,
+                try_direct_send,
+            } => AccountLockerOperation::Store {
+                claimant: component(claimant)?,
+                resource_address: resource(resource_address)?,
+                amount: **amount,
+                try_direct_send: *try_direct_send,
+            },
+            Self::StoreNonFungibles {
+                claimant,
+                resource_address,
+                ids: local_ids,
+                try_direct_send,
+            } => AccountLockerOperation::StoreNonFungibles {
+                claimant: component(claimant)?,
+                resource_address: resource(resource_address)?,
+                ids: ids(local_ids),
+                try_direct_send: *try_direct_send,
+            },
+            Self::AirdropFungible {
+                resource_address,
+                claimants,
+                try_direct_send,
+            } => AccountLockerOperation::AirdropFungible {
+                resource_address: resource(resource_address)?,
+                claimants: claimants
+                    .iter()
+                    .map(|claimant| {
+                        component(&claimant.claimant)
+                            .map(|address| (address, *claimant.amount))
+                    })
+                    .collect::<Result<_, _>>()?,
+                try_direct_send: *try_direct_send,
+            },
+            Self::AirdropNonFungibles {
+                resource_address,
+                claimants,
+                try_direct_send,
+            } => AccountLockerOperation::AirdropNonFungibles {
+                resource_address: resource(resource_address)?,
+                claimants: claimants
+                    .iter()
+                    .map(|claimant| {
+                        component(&claimant.claimant)
+                            .map(|address| (address, ids(&claimant.ids)))
+                    })
+                    .collect::<Result<_, _>>()?,
+                try_direct_send: *try_direct_send,
+            },
+            Self::Claim {
+                resource_address,
+                amount,
+            } => AccountLockerOperation::Claim {
+                resource_address: resource(resource_address)?,
+                amount: **amount,
+            },
+            Self::ClaimNonFungibles {
+                resource_address,
+                ids: local_ids,
+            } => AccountLockerOperation::ClaimNonFungibles {
+                resource_address: resource(resource_address)?,
+                ids: ids(local_ids),
+            },
+        };
+        Ok(operation)
+    }
+}
+
+//===============================
+// Build Account Locker Manifest
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildAccountLockerManifestInput {
+    pub locker_address: SerializableNodeId,
+    /// The account that stored and airdropped resources are withdrawn from and
+    /// that claims are made for.
+    pub account_address: SerializableNodeId,
+    pub operations: Vec<SerializableAccountLockerOperation>,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+pub type BuildAccountLockerManifestOutput = SerializableTransactionManifest;
+
+pub struct BuildAccountLockerManifest;
+impl<'f> Function<'f> for BuildAccountLockerManifest {
+    type Input = BuildAccountLockerManifestInput;
+    type Output = BuildAccountLockerManifestOutput;
+
+    fn handle(
+        BuildAccountLockerManifestInput {
+            locker_address,
+            account_address,
+            operations,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let locker_address = ComponentAddress::try_from(locker_address)
+            .map_err(|error| {
+                InvocationHandlingError::InvalidAddress(debug_string(error))
+            })?;
+        let account_address =
+            ComponentAddress::try_from(account_address.clone())
+                .ok()
+                .filter(radix_engine_toolkit::utils::is_account)
+                .ok_or(InvocationHandlingError::InvalidAddress(
+                    account_address.0.to_string(),
+                ))?;
+        let operations = operations
+            .iter()
+            .map(SerializableAccountLockerOperation::to_native)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| {
+                InvocationHandlingError::InvalidAddress(debug_string(error))
+            })?;
+
+        let manifest = radix_engine_toolkit::functions::account_locker::build_account_locker_manifest(
+            locker_address,
+            account_address,
+            &operations,
+        )?;
+        Ok(SerializableTransactionManifest::from_native(
+            &manifest,
+            *network_id,
+            instructions_kind,
+        )?)
+    }
+}
+
+export_function!(BuildAccountLockerManifest as build_account_locker_manifest);
+export_jni_function!(BuildAccountLockerManifest as buildAccountLockerManifest);
+export_wasm_bindgen_function!(
+    BuildAccountLockerManifest as buildAccountLockerManifest
+);
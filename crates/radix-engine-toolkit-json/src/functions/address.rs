@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::prelude::*;
+use radix_engine_toolkit::functions::address::AddressValidationFailure;
 use scrypto::prelude::*;
 
 use schemars::*;
@@ -45,6 +46,7 @@ impl<'f> Function<'f> for AddressEntityType {
 
 export_function!(AddressEntityType as address_entity_type);
 export_jni_function!(AddressEntityType as addressEntityType);
+export_wasm_bindgen_function!(AddressEntityType as addressEntityType);
 
 #[typeshare::typeshare]
 pub type AddressDecodeInput = String;
@@ -92,3 +94,145 @@ impl<'f> Function<'f> for AddressDecode {
 
 export_function!(AddressDecode as address_decode);
 export_jni_function!(AddressDecode as addressDecode);
+export_wasm_bindgen_function!(AddressDecode as addressDecode);
+
+#[typeshare::typeshare]
+pub type AddressInspectInput = String;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AddressInspectOutput {
+    pub network_id: SerializableU8,
+    pub network_name: String,
+    pub entity_type: SerializableEntityType,
+    pub hrp: String,
+    pub data: SerializableBytes,
+    pub is_global: bool,
+    pub is_internal: bool,
+    pub is_virtual: bool,
+}
+
+pub struct AddressInspect;
+impl<'f> Function<'f> for AddressInspect {
+    type Input = AddressInspectInput;
+    type Output = AddressInspectOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let radix_engine_toolkit::functions::address::AddressInspection {
+            network_id,
+            network_name,
+            entity_type,
+            hrp,
+            data,
+            is_global,
+            is_internal,
+            is_virtual,
+        } = radix_engine_toolkit::functions::address::inspect(&input)
+            .ok_or(InvocationHandlingError::InvalidAddress(input))?;
+
+        Ok(Self::Output {
+            network_id: network_id.into(),
+            network_name,
+            entity_type: entity_type.into(),
+            hrp,
+            data: data.to_vec().into(),
+            is_global,
+            is_internal,
+            is_virtual,
+        })
+    }
+}
+
+export_function!(AddressInspect as address_inspect);
+export_jni_function!(AddressInspect as addressInspect);
+export_wasm_bindgen_function!(AddressInspect as addressInspect);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AddressValidateInput {
+    pub address: String,
+    /// The entity types that the address is allowed to be of. An empty list
+    /// allows an address of any entity type.
+    pub expected_entity_types: Vec<SerializableEntityType>,
+    pub expected_network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct AddressValidateOutput {
+    pub is_valid: bool,
+    pub failures: Vec<SerializableAddressValidationFailure>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableAddressValidationFailure {
+    InvalidAddress,
+    NetworkMismatch {
+        expected: SerializableU8,
+        actual: SerializableU8,
+    },
+    UnexpectedEntityType {
+        expected: Vec<SerializableEntityType>,
+        actual: SerializableEntityType,
+    },
+}
+
+impl From<AddressValidationFailure> for SerializableAddressValidationFailure {
+    fn from(value: AddressValidationFailure) -> Self {
+        match value {
+            AddressValidationFailure::InvalidAddress => Self::InvalidAddress,
+            AddressValidationFailure::NetworkMismatch { expected, actual } => {
+                Self::NetworkMismatch {
+                    expected: expected.into(),
+                    actual: actual.into(),
+                }
+            }
+            AddressValidationFailure::UnexpectedEntityType {
+                expected,
+                actual,
+            } => Self::UnexpectedEntityType {
+                expected: expected.into_iter().map(Into::into).collect(),
+                actual: actual.into(),
+            },
+        }
+    }
+}
+
+pub struct AddressValidate;
+impl<'f> Function<'f> for AddressValidate {
+    type Input = AddressValidateInput;
+    type Output = AddressValidateOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let AddressValidateInput {
+            address,
+            expected_entity_types,
+            expected_network_id,
+        } = input;
+
+        let expected_entity_types = expected_entity_types
+            .into_iter()
+            .map(EntityType::from)
+            .collect::<Vec<_>>();
+        let failures = radix_engine_toolkit::functions::address::validate(
+            &address,
+            &expected_entity_types,
+            *expected_network_id,
+        );
+
+        Ok(Self::Output {
+            is_valid: failures.is_empty(),
+            failures: failures.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+export_function!(AddressValidate as address_validate);
+export_jni_function!(AddressValidate as addressValidate);
+export_wasm_bindgen_function!(AddressValidate as addressValidate);
@@ -0,0 +1,66 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A batch invocation entry point which invokes multiple functions in a single
+//! call across the FFI boundary. This reduces the number of round-trips that
+//! callers need to make when they invoke several functions in sequence (e.g.,
+//! compile, validate, and then hash).
+
+use crate::prelude::*;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//=======
+// Batch
+//=======
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BatchRequest {
+    /// The exported name of the function to invoke, e.g., `intent_compile`.
+    #[serde(rename = "type")]
+    pub function: String,
+    /// The input of the function.
+    pub payload: serde_json::Value,
+}
+
+pub type BatchInput = Vec<BatchRequest>;
+pub type BatchOutput = Vec<InvocationResponse>;
+
+pub struct Batch;
+impl<'a> Function<'a> for Batch {
+    type Input = BatchInput;
+    type Output = BatchOutput;
+
+    /// Invokes each of the requests in order. The failure of one request does
+    /// not prevent the ones after it from being invoked, each response holds
+    /// the output or error of the request at the same index.
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        Ok(input
+            .into_iter()
+            .map(|BatchRequest { function, payload }| {
+                invoke(&function, payload).into()
+            })
+            .collect())
+    }
+}
+
+export_function!(Batch as batch);
+export_jni_function!(Batch as batch);
+export_wasm_bindgen_function!(Batch as batch);
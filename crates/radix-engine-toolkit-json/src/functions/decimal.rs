@@ -0,0 +1,405 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Arithmetic on decimals with the exact semantics of the engine such that all
+//! of the bindings get the same results. All of the operations are checked and
+//! an overflow, a division by zero, or any other failed operation is reported
+//! as an error.
+
+use crate::prelude::*;
+use radix_engine_toolkit::functions::decimal::{
+    DecimalFormatOptions, DecimalLocaleOptions,
+};
+use scrypto::prelude::*;
+
+use schemars::*;
+use serde::*;
+
+#[typeshare::typeshare]
+#[derive(
+    Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq,
+)]
+pub enum SerializableRoundingMode {
+    ToPositiveInfinity,
+    ToNegativeInfinity,
+    ToZero,
+    AwayFromZero,
+    ToNearestMidpointTowardZero,
+    ToNearestMidpointAwayFromZero,
+    ToNearestMidpointToEven,
+}
+
+impl From<SerializableRoundingMode> for RoundingMode {
+    fn from(value: SerializableRoundingMode) -> Self {
+        match value {
+            SerializableRoundingMode::ToPositiveInfinity => {
+                Self::ToPositiveInfinity
+            }
+            SerializableRoundingMode::ToNegativeInfinity => {
+                Self::ToNegativeInfinity
+            }
+            SerializableRoundingMode::ToZero => Self::ToZero,
+            SerializableRoundingMode::AwayFromZero => Self::AwayFromZero,
+            SerializableRoundingMode::ToNearestMidpointTowardZero => {
+                Self::ToNearestMidpointTowardZero
+            }
+            SerializableRoundingMode::ToNearestMidpointAwayFromZero => {
+                Self::ToNearestMidpointAwayFromZero
+            }
+            SerializableRoundingMode::ToNearestMidpointToEven => {
+                Self::ToNearestMidpointToEven
+            }
+        }
+    }
+}
+
+fn arithmetic_error(operation: &str) -> InvocationHandlingError {
+    InvocationHandlingError::ArithmeticError(format!(
+        "The {operation} operation failed"
+    ))
+}
+
+/// Checks that the number of decimal places to round to is within `[0, scale]`
+/// as rounding to any other number of decimal places panics.
+fn check_decimal_places(
+    decimal_places: i32,
+    scale: u32,
+) -> Result<i32, InvocationHandlingError> {
+    if (0..=scale as i32).contains(&decimal_places) {
+        Ok(decimal_places)
+    } else {
+        Err(InvocationHandlingError::ArithmeticError(format!(
+            "Can't round to {decimal_places} decimal places, the number of \
+            decimal places must be between 0 and {scale}"
+        )))
+    }
+}
+
+//====================
+// Decimal Arithmetic
+//====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum DecimalArithmeticInput {
+    Add {
+        lhs: SerializableDecimal,
+        rhs: SerializableDecimal,
+    },
+    Sub {
+        lhs: SerializableDecimal,
+        rhs: SerializableDecimal,
+    },
+    Mul {
+        lhs: SerializableDecimal,
+        rhs: SerializableDecimal,
+    },
+    Div {
+        lhs: SerializableDecimal,
+        rhs: SerializableDecimal,
+    },
+    Pow {
+        base: SerializableDecimal,
+        exponent: SerializableI64,
+    },
+    Round {
+        value: SerializableDecimal,
+        decimal_places: SerializableI32,
+        rounding_mode: SerializableRoundingMode,
+    },
+}
+
+#[typeshare::typeshare]
+pub type DecimalArithmeticOutput = SerializableDecimal;
+
+pub struct DecimalArithmetic;
+impl<'f> Function<'f> for DecimalArithmetic {
+    type Input = DecimalArithmeticInput;
+    type Output = DecimalArithmeticOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let result = match input {
+            DecimalArithmeticInput::Add { lhs, rhs } => {
+                lhs.checked_add(*rhs).ok_or_else(|| arithmetic_error("add"))
+            }
+            DecimalArithmeticInput::Sub { lhs, rhs } => {
+                lhs.checked_sub(*rhs).ok_or_else(|| arithmetic_error("sub"))
+            }
+            DecimalArithmeticInput::Mul { lhs, rhs } => {
+                lhs.checked_mul(*rhs).ok_or_else(|| arithmetic_error("mul"))
+            }
+            DecimalArithmeticInput::Div { lhs, rhs } => {
+                lhs.checked_div(*rhs).ok_or_else(|| arithmetic_error("div"))
+            }
+            DecimalArithmeticInput::Pow { base, exponent } => base
+                .checked_powi(*exponent)
+                .ok_or_else(|| arithmetic_error("pow")),
+            DecimalArithmeticInput::Round {
+                value,
+                decimal_places,
+                rounding_mode,
+            } => value
+                .checked_round(
+                    check_decimal_places(*decimal_places, Decimal::SCALE)?,
+                    rounding_mode.into(),
+                )
+                .ok_or_else(|| arithmetic_error("round")),
+        }?;
+        Ok(result.into())
+    }
+}
+
+export_function!(DecimalArithmetic as decimal_arithmetic);
+export_jni_function!(DecimalArithmetic as decimalArithmetic);
+export_wasm_bindgen_function!(DecimalArithmetic as decimalArithmetic);
+
+//============================
+// Precise Decimal Arithmetic
+//============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum PreciseDecimalArithmeticInput {
+    Add {
+        lhs: SerializablePreciseDecimal,
+        rhs: SerializablePreciseDecimal,
+    },
+    Sub {
+        lhs: SerializablePreciseDecimal,
+        rhs: SerializablePreciseDecimal,
+    },
+    Mul {
+        lhs: SerializablePreciseDecimal,
+        rhs: SerializablePreciseDecimal,
+    },
+    Div {
+        lhs: SerializablePreciseDecimal,
+        rhs: SerializablePreciseDecimal,
+    },
+    Pow {
+        base: SerializablePreciseDecimal,
+        exponent: SerializableI64,
+    },
+    Round {
+        value: SerializablePreciseDecimal,
+        decimal_places: SerializableI32,
+        rounding_mode: SerializableRoundingMode,
+    },
+}
+
+#[typeshare::typeshare]
+pub type PreciseDecimalArithmeticOutput = SerializablePreciseDecimal;
+
+pub struct PreciseDecimalArithmetic;
+impl<'f> Function<'f> for PreciseDecimalArithmetic {
+    type Input = PreciseDecimalArithmeticInput;
+    type Output = PreciseDecimalArithmeticOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let result = match input {
+            PreciseDecimalArithmeticInput::Add { lhs, rhs } => {
+                lhs.checked_add(*rhs).ok_or_else(|| arithmetic_error("add"))
+            }
+            PreciseDecimalArithmeticInput::Sub { lhs, rhs } => {
+                lhs.checked_sub(*rhs).ok_or_else(|| arithmetic_error("sub"))
+            }
+            PreciseDecimalArithmeticInput::Mul { lhs, rhs } => {
+                lhs.checked_mul(*rhs).ok_or_else(|| arithmetic_error("mul"))
+            }
+            PreciseDecimalArithmeticInput::Div { lhs, rhs } => {
+                lhs.checked_div(*rhs).ok_or_else(|| arithmetic_error("div"))
+            }
+            PreciseDecimalArithmeticInput::Pow { base, exponent } => base
+                .checked_powi(*exponent)
+                .ok_or_else(|| arithmetic_error("pow")),
+            PreciseDecimalArithmeticInput::Round {
+                value,
+                decimal_places,
+                rounding_mode,
+            } => value
+                .checked_round(
+                    check_decimal_places(
+                        *decimal_places,
+                        PreciseDecimal::SCALE,
+                    )?,
+                    rounding_mode.into(),
+                )
+                .ok_or_else(|| arithmetic_error("round")),
+        }?;
+        Ok(result.into())
+    }
+}
+
+export_function!(PreciseDecimalArithmetic as precise_decimal_arithmetic);
+export_jni_function!(PreciseDecimalArithmetic as preciseDecimalArithmetic);
+export_wasm_bindgen_function!(
+    PreciseDecimalArithmetic as preciseDecimalArithmetic
+);
+
+//================
+// Format Decimal
+//================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableDecimalValue {
+    Decimal(SerializableDecimal),
+    PreciseDecimal(SerializablePreciseDecimal),
+}
+
+#[typeshare::typeshare]
+#[derive(
+    Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq,
+)]
+pub enum SerializableDecimalKind {
+    Decimal,
+    PreciseDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableDecimalLocaleOptions {
+    pub decimal_separator: String,
+    pub thousands_separator: Option<String>,
+}
+
+impl From<SerializableDecimalLocaleOptions> for DecimalLocaleOptions {
+    fn from(value: SerializableDecimalLocaleOptions) -> Self {
+        Self {
+            decimal_separator: value.decimal_separator,
+            thousands_separator: value.thousands_separator,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableDecimalFormatOptions {
+    pub locale: SerializableDecimalLocaleOptions,
+    pub max_fraction_digits: Option<SerializableU8>,
+    pub rounding_mode: SerializableRoundingMode,
+}
+
+impl From<SerializableDecimalFormatOptions> for DecimalFormatOptions {
+    fn from(value: SerializableDecimalFormatOptions) -> Self {
+        Self {
+            locale: value.locale.into(),
+            max_fraction_digits: value
+                .max_fraction_digits
+                .map(|digits| *digits),
+            rounding_mode: value.rounding_mode.into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct FormatDecimalInput {
+    pub value: SerializableDecimalValue,
+    pub options: SerializableDecimalFormatOptions,
+}
+
+#[typeshare::typeshare]
+pub type FormatDecimalOutput = String;
+
+pub struct FormatDecimal;
+impl<'f> Function<'f> for FormatDecimal {
+    type Input = FormatDecimalInput;
+    type Output = FormatDecimalOutput;
+
+    fn handle(
+        FormatDecimalInput { value, options }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let options = DecimalFormatOptions::from(options);
+        let formatted = match value {
+            SerializableDecimalValue::Decimal(value) => {
+                radix_engine_toolkit::functions::decimal::format_decimal(
+                    &value, &options,
+                )
+            }
+            SerializableDecimalValue::PreciseDecimal(value) => {
+                radix_engine_toolkit::functions::decimal::format_precise_decimal(
+                    &value, &options,
+                )
+            }
+        }?;
+        Ok(formatted)
+    }
+}
+
+export_function!(FormatDecimal as format_decimal);
+export_jni_function!(FormatDecimal as formatDecimal);
+export_wasm_bindgen_function!(FormatDecimal as formatDecimal);
+
+//===============
+// Parse Decimal
+//===============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ParseDecimalInput {
+    pub value: String,
+    pub kind: SerializableDecimalKind,
+    pub locale: SerializableDecimalLocaleOptions,
+}
+
+#[typeshare::typeshare]
+pub type ParseDecimalOutput = SerializableDecimalValue;
+
+pub struct ParseDecimal;
+impl<'f> Function<'f> for ParseDecimal {
+    type Input = ParseDecimalInput;
+    type Output = ParseDecimalOutput;
+
+    fn handle(
+        ParseDecimalInput {
+            value,
+            kind,
+            locale,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let locale = DecimalLocaleOptions::from(locale);
+        let parsed = match kind {
+            SerializableDecimalKind::Decimal => {
+                let value =
+                    radix_engine_toolkit::functions::decimal::parse_decimal(
+                        &value, &locale,
+                    )?;
+                SerializableDecimalValue::Decimal(value.into())
+            }
+            SerializableDecimalKind::PreciseDecimal => {
+                let value =
+                    radix_engine_toolkit::functions::decimal::parse_precise_decimal(
+                        &value, &locale,
+                    )?;
+                SerializableDecimalValue::PreciseDecimal(value.into())
+            }
+        };
+        Ok(parsed)
+    }
+}
+
+export_function!(ParseDecimal as parse_decimal);
+export_jni_function!(ParseDecimal as parseDecimal);
+export_wasm_bindgen_function!(ParseDecimal as parseDecimal);
@@ -17,8 +17,12 @@
 
 use crate::prelude::*;
 use radix_common::prelude::PublicKey;
-use radix_transactions::model::TransactionHashBech32Encoder;
+use radix_engine_toolkit::caches::bech32_coders;
+use radix_engine_toolkit::functions::key_derivation::*;
+use radix_transactions::model::CurveType;
+use radix_transactions::prelude::PrivateKey;
 use schemars::JsonSchema;
+use scrypto::prelude::{ComponentAddress, EntityType};
 use serde::{Deserialize, Serialize};
 
 //================================================
@@ -67,6 +71,10 @@ export_jni_function!(
     DeriveVirtualAccountAddressFromPublicKey
         as deriveVirtualAccountAddressFromPublicKey
 );
+export_wasm_bindgen_function!(
+    DeriveVirtualAccountAddressFromPublicKey
+        as deriveVirtualAccountAddressFromPublicKey
+);
 
 //=================================================
 // Derive Virtual Identity Address from Public Key
@@ -114,6 +122,10 @@ export_jni_function!(
     DeriveVirtualIdentityAddressFromPublicKey
         as deriveVirtualIdentityAddressFromPublicKey
 );
+export_wasm_bindgen_function!(
+    DeriveVirtualIdentityAddressFromPublicKey
+        as deriveVirtualIdentityAddressFromPublicKey
+);
 
 //=================================================================
 // Derive Virtual Signature Non-Fungible Global Id from Public Key
@@ -165,6 +177,144 @@ export_jni_function!(
     DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey
         as deriveVirtualSignatureNonFungibleGlobalIdFromPublicKey
 );
+export_wasm_bindgen_function!(
+    DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey
+        as deriveVirtualSignatureNonFungibleGlobalIdFromPublicKey
+);
+
+//================================================
+// Derive Virtual Signature Badge from Public Key
+//================================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DeriveVirtualSignatureBadgeFromPublicKeyInput {
+    pub public_key: SerializablePublicKey,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DeriveVirtualSignatureBadgeFromPublicKeyOutput {
+    /// The string representation of the non-fungible global id of the badge.
+    pub non_fungible_global_id: SerializableNonFungibleGlobalId,
+    /// The signature virtual badge resource of the curve of the public key.
+    pub resource_address: SerializableNodeId,
+    pub non_fungible_local_id: SerializableNonFungibleLocalId,
+}
+
+pub struct DeriveVirtualSignatureBadgeFromPublicKey;
+impl<'a> Function<'a> for DeriveVirtualSignatureBadgeFromPublicKey {
+    type Input = DeriveVirtualSignatureBadgeFromPublicKeyInput;
+    type Output = DeriveVirtualSignatureBadgeFromPublicKeyOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let DeriveVirtualSignatureBadgeFromPublicKeyInput {
+            public_key,
+            network_id,
+        } = input;
+
+        let non_fungible_global_id =
+            radix_engine_toolkit::functions::derive::virtual_signature_non_fungible_global_id_from_public_key(
+                &PublicKey::from(public_key),
+            );
+
+        Ok(DeriveVirtualSignatureBadgeFromPublicKeyOutput {
+            resource_address: SerializableNodeId::from_global_address(
+                non_fungible_global_id.resource_address(),
+                *network_id,
+            ),
+            non_fungible_local_id: non_fungible_global_id
+                .local_id()
+                .clone()
+                .into(),
+            non_fungible_global_id: SerializableNonFungibleGlobalId(
+                SerializableNonFungibleGlobalIdInternal {
+                    network_id: *network_id,
+                    non_fungible_global_id,
+                },
+            ),
+        })
+    }
+}
+
+export_function!(
+    DeriveVirtualSignatureBadgeFromPublicKey
+        as derive_virtual_signature_badge_from_public_key
+);
+export_jni_function!(
+    DeriveVirtualSignatureBadgeFromPublicKey
+        as deriveVirtualSignatureBadgeFromPublicKey
+);
+export_wasm_bindgen_function!(
+    DeriveVirtualSignatureBadgeFromPublicKey
+        as deriveVirtualSignatureBadgeFromPublicKey
+);
+
+//=====================================================
+// Derive Validator Owner Badge from Validator Address
+//=====================================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DeriveValidatorOwnerBadgeFromValidatorAddressInput {
+    pub validator_address: SerializableNodeId,
+}
+pub type DeriveValidatorOwnerBadgeFromValidatorAddressOutput =
+    SerializableNonFungibleGlobalId;
+
+pub struct DeriveValidatorOwnerBadgeFromValidatorAddress;
+impl<'a> Function<'a> for DeriveValidatorOwnerBadgeFromValidatorAddress {
+    type Input = DeriveValidatorOwnerBadgeFromValidatorAddressInput;
+    type Output = DeriveValidatorOwnerBadgeFromValidatorAddressOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let DeriveValidatorOwnerBadgeFromValidatorAddressInput {
+            validator_address,
+        } = input;
+
+        let network_id = validator_address.0.network_id;
+        let validator_address =
+            ComponentAddress::try_from(validator_address.clone())
+                .ok()
+                .filter(|address| {
+                    address.as_node_id().entity_type()
+                        == Some(EntityType::GlobalValidator)
+                })
+                .ok_or(InvocationHandlingError::InvalidAddress(
+                    validator_address.0.to_string(),
+                ))?;
+
+        let non_fungible_global_id =
+            radix_engine_toolkit::functions::derive::validator_owner_badge_from_validator_address(
+                &validator_address,
+            );
+
+        Ok(SerializableNonFungibleGlobalId(
+            SerializableNonFungibleGlobalIdInternal {
+                network_id,
+                non_fungible_global_id,
+            },
+        ))
+    }
+}
+
+export_function!(
+    DeriveValidatorOwnerBadgeFromValidatorAddress
+        as derive_validator_owner_badge_from_validator_address
+);
+export_jni_function!(
+    DeriveValidatorOwnerBadgeFromValidatorAddress
+        as deriveValidatorOwnerBadgeFromValidatorAddress
+);
+export_wasm_bindgen_function!(
+    DeriveValidatorOwnerBadgeFromValidatorAddress
+        as deriveValidatorOwnerBadgeFromValidatorAddress
+);
 
 //=============================================================
 // Derive Virtual Account Address from Olympia Account Address
@@ -214,6 +364,10 @@ export_jni_function!(
     DeriveVirtualAccountAddressFromOlympiaAccountAddress
         as deriveVirtualAccountAddressFromOlympiaAccountAddress
 );
+export_wasm_bindgen_function!(
+    DeriveVirtualAccountAddressFromOlympiaAccountAddress
+        as deriveVirtualAccountAddressFromOlympiaAccountAddress
+);
 
 //=======================================================
 // Derive Resource Address from Olympia Resource Address
@@ -263,6 +417,10 @@ export_jni_function!(
     DeriveResourceAddressFromOlympiaResourceAddress
         as deriveResourceAddressFromOlympiaResourceAddress
 );
+export_wasm_bindgen_function!(
+    DeriveResourceAddressFromOlympiaResourceAddress
+        as deriveResourceAddressFromOlympiaResourceAddress
+);
 
 //================================================
 // Derive Public Key from Olympia Account Address
@@ -298,6 +456,10 @@ export_jni_function!(
     DerivePublicKeyFromOlympiaAccountAddress
         as derivePublicKeyFromOlympiaAccountAddress
 );
+export_wasm_bindgen_function!(
+    DerivePublicKeyFromOlympiaAccountAddress
+        as derivePublicKeyFromOlympiaAccountAddress
+);
 
 //================================================
 // Derive Olympia Account from Public Key Address
@@ -343,6 +505,65 @@ export_jni_function!(
     DeriveOlympiaAccountAddressFromPublicKey
         as deriveOlympiaAccountAddressFromPublicKey
 );
+export_wasm_bindgen_function!(
+    DeriveOlympiaAccountAddressFromPublicKey
+        as deriveOlympiaAccountAddressFromPublicKey
+);
+
+//========================================
+// Derive Olympia Account Address Details
+//========================================
+
+#[typeshare::typeshare]
+pub type DeriveOlympiaAccountAddressDetailsInput = String;
+
+/// The result of validating the Olympia account address. A valid address is
+/// reported with the network it belongs to and the public key it encodes.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+#[serde(tag = "kind", content = "value")]
+pub enum DeriveOlympiaAccountAddressDetailsOutput {
+    Valid(SerializableOlympiaAccountAddressDetails),
+    Invalid(String),
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct SerializableOlympiaAccountAddressDetails {
+    pub olympia_network: SerializableOlympiaNetwork,
+    pub public_key: SerializableSecp256k1PublicKey,
+}
+
+pub struct DeriveOlympiaAccountAddressDetails;
+impl<'a> Function<'a> for DeriveOlympiaAccountAddressDetails {
+    type Input = DeriveOlympiaAccountAddressDetailsInput;
+    type Output = DeriveOlympiaAccountAddressDetailsOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        match radix_engine_toolkit::functions::derive::olympia_account_address_details(input) {
+            Ok(details) => Ok(Self::Output::Valid(
+                SerializableOlympiaAccountAddressDetails {
+                    olympia_network: details.olympia_network.into(),
+                    public_key: details.public_key.into(),
+                },
+            )),
+            Err(error) => Ok(Self::Output::Invalid(debug_string(error))),
+        }
+    }
+}
+
+export_function!(
+    DeriveOlympiaAccountAddressDetails
+        as derive_olympia_account_address_details
+);
+export_jni_function!(
+    DeriveOlympiaAccountAddressDetails as deriveOlympiaAccountAddressDetails
+);
+export_wasm_bindgen_function!(
+    DeriveOlympiaAccountAddressDetails as deriveOlympiaAccountAddressDetails
+);
 
 //=============================================
 // Derive Node Address from Public Key Address
@@ -386,6 +607,9 @@ export_function!(
 export_jni_function!(
     DeriveNodeAddressFromPublicKey as deriveNodeAddressFromPublicKey
 );
+export_wasm_bindgen_function!(
+    DeriveNodeAddressFromPublicKey as deriveNodeAddressFromPublicKey
+);
 
 //===============================================
 // Derive The Intent Identifier from Intent Hash
@@ -417,12 +641,10 @@ impl<'a> Function<'a> for DeriveBech32mTransactionIdentifierFromIntentHash {
         let intent_hash = radix_transactions::prelude::IntentHash(
             scrypto::prelude::Hash(hash.0),
         );
-        let network_definition =
-            radix_engine_toolkit::utils::network_definition_from_network_id(
-                *network_id,
-            );
-        let encoder = TransactionHashBech32Encoder::new(&network_definition);
-        let encoded = encoder.encode(&intent_hash).unwrap();
+        let encoded = bech32_coders(*network_id)
+            .transaction_hash_encoder
+            .encode(&intent_hash)
+            .unwrap();
 
         Ok(encoded)
     }
@@ -436,3 +658,158 @@ export_jni_function!(
     DeriveBech32mTransactionIdentifierFromIntentHash
         as deriveBech32mTransactionIdentifierFromIntentHash
 );
+export_wasm_bindgen_function!(
+    DeriveBech32mTransactionIdentifierFromIntentHash
+        as deriveBech32mTransactionIdentifierFromIntentHash
+);
+
+//=================================
+// Derive Public Key from Mnemonic
+//=================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DerivePublicKeyFromMnemonicInput {
+    pub mnemonic: String,
+    #[serde(default)]
+    pub passphrase: String,
+    pub curve: SerializableCurveType,
+    pub network_id: SerializableU8,
+    pub index: SerializableU32,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DerivePublicKeyFromMnemonicOutput {
+    pub derivation_path: String,
+    pub public_key: SerializablePublicKey,
+    pub virtual_account_address: SerializableNodeId,
+}
+
+pub struct DerivePublicKeyFromMnemonic;
+impl<'a> Function<'a> for DerivePublicKeyFromMnemonic {
+    type Input = DerivePublicKeyFromMnemonicInput;
+    type Output = DerivePublicKeyFromMnemonicOutput;
+
+    fn handle(
+        DerivePublicKeyFromMnemonicInput {
+            mnemonic,
+            passphrase,
+            curve,
+            network_id,
+            index,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let curve = CurveType::from(curve);
+        let derivation_path =
+            DerivationPath::account(*network_id, *index, curve);
+        let key_pair = derive_key_pair_from_mnemonic(
+            &mnemonic,
+            &passphrase,
+            &derivation_path,
+            curve,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::DerivationError(debug_string(error))
+        })?;
+
+        Ok(Self::Output {
+            derivation_path: derivation_path.to_string(),
+            public_key: key_pair.public_key.into(),
+            virtual_account_address: SerializableNodeId(
+                SerializableNodeIdInternal {
+                    network_id: *network_id,
+                    node_id: key_pair.virtual_account_address.into_node_id(),
+                },
+            ),
+        })
+    }
+}
+
+export_function!(
+    DerivePublicKeyFromMnemonic as derive_public_key_from_mnemonic
+);
+export_jni_function!(
+    DerivePublicKeyFromMnemonic as derivePublicKeyFromMnemonic
+);
+export_wasm_bindgen_function!(
+    DerivePublicKeyFromMnemonic as derivePublicKeyFromMnemonic
+);
+
+//====================================
+// Derive Key Pair at Derivation Path
+//====================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DeriveKeyPairAtDerivationPathInput {
+    pub mnemonic: String,
+    #[serde(default)]
+    pub passphrase: String,
+    pub curve: SerializableCurveType,
+    pub derivation_path: String,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct DeriveKeyPairAtDerivationPathOutput {
+    pub private_key: SerializableBytes,
+    pub public_key: SerializablePublicKey,
+    pub virtual_account_address: SerializableNodeId,
+}
+
+pub struct DeriveKeyPairAtDerivationPath;
+impl<'a> Function<'a> for DeriveKeyPairAtDerivationPath {
+    type Input = DeriveKeyPairAtDerivationPathInput;
+    type Output = DeriveKeyPairAtDerivationPathOutput;
+
+    fn handle(
+        DeriveKeyPairAtDerivationPathInput {
+            mnemonic,
+            passphrase,
+            curve,
+            derivation_path,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let key_pair = derivation_path
+            .parse::<DerivationPath>()
+            .and_then(|derivation_path| {
+                derive_key_pair_from_mnemonic(
+                    &mnemonic,
+                    &passphrase,
+                    &derivation_path,
+                    curve.into(),
+                )
+            })
+            .map_err(|error| {
+                InvocationHandlingError::DerivationError(debug_string(error))
+            })?;
+        let private_key = match &key_pair.private_key {
+            PrivateKey::Secp256k1(private_key) => private_key.to_bytes(),
+            PrivateKey::Ed25519(private_key) => private_key.to_bytes(),
+        };
+
+        Ok(Self::Output {
+            private_key: private_key.into(),
+            public_key: key_pair.public_key.into(),
+            virtual_account_address: SerializableNodeId(
+                SerializableNodeIdInternal {
+                    network_id: *network_id,
+                    node_id: key_pair.virtual_account_address.into_node_id(),
+                },
+            ),
+        })
+    }
+}
+
+export_function!(
+    DeriveKeyPairAtDerivationPath as derive_key_pair_at_derivation_path
+);
+export_jni_function!(
+    DeriveKeyPairAtDerivationPath as deriveKeyPairAtDerivationPath
+);
+export_wasm_bindgen_function!(
+    DeriveKeyPairAtDerivationPath as deriveKeyPairAtDerivationPath
+);
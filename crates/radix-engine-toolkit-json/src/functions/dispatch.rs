@@ -0,0 +1,296 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Dispatches invocations to the functions of the toolkit by their exported
+//! name. This is used by interfaces which receive the name of the function to
+//! invoke at runtime (e.g., the WASI interface) rather than through a distinct
+//! symbol per function.
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The outcome of invoking a function through [`invoke`] in a form that can be
+/// serialized and returned to the caller.
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+#[serde(tag = "kind", content = "value")]
+pub enum InvocationResponse {
+    Ok(Response<serde_json::Value>),
+    Err(ErrorResponse),
+}
+
+impl From<Result<Response<serde_json::Value>, Error>> for InvocationResponse {
+    fn from(value: Result<Response<serde_json::Value>, Error>) -> Self {
+        match value {
+            Ok(response) => Self::Ok(response),
+            Err(error) => Self::Err(error.into()),
+        }
+    }
+}
+
+macro_rules! define_dispatch {
+    (
+        $(
+            $(#[cfg(feature = $feature: literal)])?
+            $function: ident as $name: ident
+        ),* $(,)?
+    ) => {
+        /// The names of all of the functions that can be invoked through
+        /// [`invoke`].
+        pub const FUNCTION_NAMES: &[&str] = &[
+            $(
+                $(#[cfg(feature = $feature)])?
+                stringify!($name),
+            )*
+        ];
+
+        /// Describes the functions that can be invoked through [`invoke`].
+        pub fn dispatched_functions() -> Vec<SerializableFunctionDescription> {
+            let mut functions = Vec::new();
+            $(
+                $(#[cfg(feature = $feature)])?
+                functions.push(
+                    SerializableFunctionDescription::new::<$function>(
+                        stringify!($name),
+                        &[$($feature)?],
+                    ),
+                );
+            )*
+            functions
+        }
+
+        /// Generates the schemas of the input and output of each of the
+        /// functions that can be invoked through [`invoke`] along with their
+        /// names. The types that the schemas refer to are added to the
+        /// definitions of the generator.
+        pub fn function_schemas(
+            generator: &mut SchemaGenerator,
+        ) -> Vec<(&'static str, Schema, Schema)> {
+            let mut schemas = Vec::new();
+            $(
+                $(#[cfg(feature = $feature)])?
+                schemas.push((
+                    stringify!($name),
+                    generator.subschema_for::<
+                        <$function as Function>::Input
+                    >(),
+                    generator.subschema_for::<
+                        <$function as Function>::Output
+                    >(),
+                ));
+            )*
+            schemas
+        }
+
+        /// Invokes the function with the given name with the given JSON input
+        /// returning the JSON output of the function and any warnings that
+        /// were reported while handling the invocation.
+        pub fn invoke(
+            function_name: &str,
+            input: serde_json::Value,
+        ) -> Result<Response<serde_json::Value>, Error> {
+            match function_name {
+                $(
+                    $(#[cfg(feature = $feature)])?
+                    stringify!($name) => {
+                        handle_json_invocation::<$function>(input)
+                    }
+                )*
+                _ => Err(InvocationInterpretationError::UnknownFunction(
+                    function_name.to_owned(),
+                )
+                .into()),
+            }
+        }
+    };
+}
+
+define_dispatch! {
+    BuildInformation as build_information,
+    ListFunctions as list_functions,
+    ErrorCatalog as error_catalog,
+
+    AddressEntityType as address_entity_type,
+    AddressDecode as address_decode,
+    AddressInspect as address_inspect,
+    AddressValidate as address_validate,
+
+    DeriveVirtualAccountAddressFromPublicKey
+        as derive_virtual_account_address_from_public_key,
+    DeriveVirtualIdentityAddressFromPublicKey
+        as derive_virtual_identity_address_from_public_key,
+    DeriveVirtualSignatureNonFungibleGlobalIdFromPublicKey
+        as derive_virtual_signature_non_fungible_global_id_from_public_key,
+    DeriveVirtualSignatureBadgeFromPublicKey
+        as derive_virtual_signature_badge_from_public_key,
+    DeriveValidatorOwnerBadgeFromValidatorAddress
+        as derive_validator_owner_badge_from_validator_address,
+    DeriveVirtualAccountAddressFromOlympiaAccountAddress
+        as derive_virtual_account_address_from_olympia_account_address,
+    DeriveResourceAddressFromOlympiaResourceAddress
+        as derive_resource_address_from_olympia_resource_address,
+    DerivePublicKeyFromOlympiaAccountAddress
+        as derive_public_key_from_olympia_account_address,
+    DeriveOlympiaAccountAddressFromPublicKey
+        as derive_olympia_account_address_from_public_key,
+    DeriveOlympiaAccountAddressDetails
+        as derive_olympia_account_address_details,
+    DeriveNodeAddressFromPublicKey as derive_node_address_from_public_key,
+    DeriveBech32mTransactionIdentifierFromIntentHash
+        as derive_bech32m_transaction_identifier_from_intent_hash,
+    DerivePublicKeyFromMnemonic as derive_public_key_from_mnemonic,
+    DeriveKeyPairAtDerivationPath as derive_key_pair_at_derivation_path,
+
+    InstructionsHash as instructions_hash,
+    InstructionsConvert as instructions_convert,
+    InstructionsCompile as instructions_compile,
+    InstructionsDecompile as instructions_decompile,
+    InstructionsStaticallyValidate as instructions_statically_validate,
+    InstructionsExtractAddresses as instructions_extract_addresses,
+
+    ManifestHash as manifest_hash,
+    ManifestCompile as manifest_compile,
+    ManifestDecompile as manifest_decompile,
+    ManifestStaticallyValidate as manifest_statically_validate,
+    ManifestAnalyze as manifest_analyze,
+    ManifestClassify as manifest_classify,
+    ManifestConvertNetwork as manifest_convert_network,
+    ManifestLint as manifest_lint,
+    ManifestDiff as manifest_diff,
+    ManifestInstantiateTemplate as manifest_instantiate_template,
+    ManifestResourceMovementsGraph as manifest_resource_movements_graph,
+    ManifestDetectReservedInstructions
+        as manifest_detect_reserved_instructions,
+    #[cfg(feature = "native-blueprint-interfaces")]
+    ManifestValidateInstructionArguments
+        as manifest_validate_instruction_arguments,
+    ManifestAccountInteractions as manifest_account_interactions,
+    ManifestRequiredSigners as manifest_required_signers,
+    ManifestStatistics as manifest_statistics,
+    ManifestCheckReorder as manifest_check_reorder,
+    ManifestSetFeePayer as manifest_set_fee_payer,
+    ManifestAddBlob as manifest_add_blob,
+    ManifestGetBlob as manifest_get_blob,
+    ManifestSecurityImpacts as manifest_security_impacts,
+    ManifestRenderSummary as manifest_render_summary,
+    ManifestRunAnalyzers as manifest_run_analyzers,
+
+    IntentHash as intent_hash,
+    IntentHashFromCompiled as intent_hash_from_compiled,
+    IntentCompile as intent_compile,
+    IntentDecompile as intent_decompile,
+    IntentStaticallyValidate as intent_statically_validate,
+    IntentSign as intent_sign,
+    IntentMergeSignatures as intent_merge_signatures,
+    BuildTransactionHeader as build_transaction_header,
+
+    SignedIntentHash as signed_intent_hash,
+    SignedIntentHashFromCompiled as signed_intent_hash_from_compiled,
+    SignedIntentCompile as signed_intent_compile,
+    SignedIntentDecompile as signed_intent_decompile,
+    SignedIntentStaticallyValidate as signed_intent_statically_validate,
+    SignedIntentNotarize as signed_intent_notarize,
+
+    SignHash as sign_hash,
+    VerifySignature as verify_signature,
+
+    GenerateSecureRandomNonce as generate_secure_random_nonce,
+    GenerateEd25519Keypair as generate_ed25519_keypair,
+    GenerateSecp256k1Keypair as generate_secp256k1_keypair,
+
+    NotarizedTransactionHash as notarized_transaction_hash,
+    NotarizedTransactionHashFromCompiled
+        as notarized_transaction_hash_from_compiled,
+    NotarizedTransactionCompile as notarized_transaction_compile,
+    NotarizedTransactionDecompile as notarized_transaction_decompile,
+    NotarizedTransactionStaticallyValidate
+        as notarized_transaction_statically_validate,
+    NotarizedTransactionVerifySignatures
+        as notarized_transaction_verify_signatures,
+
+    PackageExtractBlueprintInterfaces as package_extract_blueprint_interfaces,
+    PackageBuildCallArguments as package_build_call_arguments,
+    PackageBuildPublishManifest as package_build_publish_manifest,
+    PackageHash as package_hash,
+    PackagePredictAddress as package_predict_address,
+    PackageAllocationIndex as package_allocation_index,
+
+    PayloadDecompileUnknown as payload_decompile_unknown,
+
+    BuildPreviewTransaction as build_preview_transaction,
+    ParsePreviewReceipt as parse_preview_receipt,
+
+    ManifestSborDecodeToString as manifest_sbor_decode_to_string,
+
+    MetadataValueEncode as metadata_value_encode,
+    MetadataValueDecode as metadata_value_decode,
+
+    EncodeNonFungibleData as encode_non_fungible_data,
+    DecodeNonFungibleData as decode_non_fungible_data,
+
+    NonFungibleLocalIdParse as non_fungible_local_id_parse,
+    NonFungibleLocalIdFormat as non_fungible_local_id_format,
+    NonFungibleLocalIdValidate as non_fungible_local_id_validate,
+    BuildNonFungibleGlobalId as build_non_fungible_global_id,
+    ParseNonFungibleGlobalId as parse_non_fungible_global_id,
+
+    AccessRuleDecode as access_rule_decode,
+    AccessRuleToManifestValue as access_rule_to_manifest_value,
+
+    LedgerSignTransactionPayload as ledger_sign_transaction_payload,
+
+    RolaMessage as rola_message,
+    RolaVerifySignature as rola_verify_signature,
+
+    WalletInteractionCanonicalize as wallet_interaction_canonicalize,
+    WalletInteractionDeepLinkEncode as wallet_interaction_deep_link_encode,
+    WalletInteractionDeepLinkDecode as wallet_interaction_deep_link_decode,
+
+    ScryptoSborDecodeToString as scrypto_sbor_decode_to_string,
+    ScryptoSborDecodeToEvents as scrypto_sbor_decode_to_events,
+    ScryptoSborQuery as scrypto_sbor_query,
+    ScryptoSborEncodeStringRepresentation
+        as scrypto_sbor_encode_string_representation,
+
+    DecodeEvents as decode_events,
+
+    UtilsKnownAddress as utils_known_addresses,
+    UtilsKnownAddressesForNetworks as utils_known_addresses_for_networks,
+    UtilsClearCaches as utils_clear_caches,
+    UtilsMemoryStatistics as utils_memory_statistics,
+
+    DecimalArithmetic as decimal_arithmetic,
+    PreciseDecimalArithmetic as precise_decimal_arithmetic,
+    FormatDecimal as format_decimal,
+    ParseDecimal as parse_decimal,
+
+    XrdToAttos as xrd_to_attos,
+    AttosToXrd as attos_to_xrd,
+    CostUnitsToXrd as cost_units_to_xrd,
+
+    BuildSetComponentRoyaltyInstruction
+        as build_set_component_royalty_instruction,
+
+    BuildAccountInstruction as build_account_instruction,
+    BuildValidatorManifest as build_validator_manifest,
+    BuildPoolManifest as build_pool_manifest,
+    BuildAccessControllerManifest as build_access_controller_manifest,
+    BuildAccountLockerManifest as build_account_locker_manifest,
+}
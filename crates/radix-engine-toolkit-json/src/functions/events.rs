@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Deref;
+
+use radix_common::prelude::*;
+use radix_engine_interface::prelude::{
+    BlueprintId, Emitter, EventTypeIdentifier,
+};
+use radix_engine_toolkit::caches::bech32_coders;
+use radix_engine_toolkit::functions::events::native_event_schema;
+use radix_engine_toolkit::functions::scrypto_sbor::decode_to_string_representation;
+use sbor::representations::SerializationMode;
+use sbor::{LocalTypeId, Schema};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//===============
+// Decode Events
+//===============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct DecodeEventsInput {
+    pub events: Vec<SerializableEncodedEvent>,
+    pub network_id: SerializableU8,
+}
+
+/// An event as emitted in a transaction receipt.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableEncodedEvent {
+    pub event_type_identifier: SerializableEventTypeIdentifier,
+    pub payload: SerializableBytes,
+    /// The schema to decode the payload with. When not provided the bundled
+    /// schema of the event is used if it's emitted by a native blueprint and
+    /// the payload is decoded without a schema otherwise.
+    pub schema: Option<PayloadSchema>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableEventTypeIdentifier {
+    pub emitter: SerializableEmitter,
+    pub event_name: String,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableEmitter {
+    Function {
+        package_address: SerializableNodeId,
+        blueprint_name: String,
+    },
+    Method {
+        address: SerializableNodeId,
+        module: SerializableModuleId,
+    },
+}
+
+pub type DecodeEventsOutput = Vec<SerializableDecodedEvent>;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableDecodedEvent {
+    pub event_name: String,
+    pub schema_source: SerializableEventSchemaSource,
+    /// The payload of the event in the programmatic JSON representation.
+    /// The values have the names of their types and fields when the event
+    /// is decoded with a schema.
+    pub value: serde_json::Value,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableEventSchemaSource {
+    Native,
+    Custom,
+    None,
+}
+
+impl SerializableEventTypeIdentifier {
+    pub fn to_native(
+        &self,
+    ) -> Result<EventTypeIdentifier, InvocationHandlingError> {
+        let emitter = match &self.emitter {
+            SerializableEmitter::Function {
+                package_address,
+                blueprint_name,
+            } => {
+                let package_address =
+                    PackageAddress::try_from(package_address.0.node_id.0)
+                        .map_err(|error| {
+                            InvocationHandlingError::InvalidAddress(
+                                debug_string(error),
+                            )
+                        })?;
+                Emitter::Function(BlueprintId::new(
+                    &package_address,
+                    blueprint_name,
+                ))
+            }
+            SerializableEmitter::Method { address, module } => {
+                Emitter::Method(address.0.node_id, module.clone().into())
+            }
+        };
+        Ok(EventTypeIdentifier(emitter, self.event_name.clone()))
+    }
+}
+
+pub struct DecodeEvents;
+impl<'a> Function<'a> for DecodeEvents {
+    type Input = DecodeEventsInput;
+    type Output = DecodeEventsOutput;
+
+    fn handle(
+        DecodeEventsInput { events, network_id }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let coders = bech32_coders(*network_id);
+        events
+            .into_iter()
+            .map(|event| {
+                let event_type_identifier =
+                    event.event_type_identifier.to_native()?;
+                let (schema_source, schema) = match event.schema {
+                    Some(PayloadSchema {
+                        local_type_id,
+                        schema,
+                    }) => {
+                        let schema = scrypto_decode::<
+                            Schema<ScryptoCustomSchema>,
+                        >(&schema)
+                        .map_err(|error| {
+                            InvocationHandlingError::DecodeError(
+                                debug_string(error),
+                                debug_string(schema),
+                            )
+                        })?;
+                        (
+                            SerializableEventSchemaSource::Custom,
+                            Some((LocalTypeId::from(local_type_id), schema)),
+                        )
+                    }
+                    None => match native_event_schema(&event_type_identifier) {
+                        Some(schema) => (
+                            SerializableEventSchemaSource::Native,
+                            Some(schema),
+                        ),
+                        None => (SerializableEventSchemaSource::None, None),
+                    },
+                };
+
+                let string = decode_to_string_representation(
+                    event.payload.deref(),
+                    SerializationMode::Programmatic,
+                    &coders.address_encoder,
+                    schema,
+                )?;
+                let value = serde_json::from_str(&string)
+                    .expect("The string representation is valid JSON");
+
+                Ok(SerializableDecodedEvent {
+                    event_name: event_type_identifier.1,
+                    schema_source,
+                    value,
+                })
+            })
+            .collect()
+    }
+}
+
+export_function!(DecodeEvents as decode_events);
+export_jni_function!(DecodeEvents as decodeEvents);
+export_wasm_bindgen_function!(DecodeEvents as decodeEvents);
@@ -16,34 +16,81 @@
 // under the License.
 
 use super::traits::Function;
-use crate::memory::Pointer;
-use crate::utils::{serialize_and_write_to_memory, serialize_to_jstring};
+use crate::memory::{Pointer, RetBuffer};
+use crate::utils::{
+    debug_string, serialize_to_cbor_ret_buffer, serialize_to_jstring,
+    serialize_to_ret_buffer,
+};
+use crate::versioning::upgrade_input;
+use crate::warning::{ignored_fields, Response};
 
-pub fn handle_invocation<'f, F>(input: Pointer) -> Pointer
+/// Handles an invocation whose input is a null-terminated JSON string. The
+/// [`Response`] or error is returned JSON serialized.
+pub fn handle_invocation<'f, F>(input: Pointer) -> RetBuffer
 where
     F: Function<'f>,
 {
+    let result = crate::utils::read_and_deserialize_from_memory::<
+        serde_json::Value,
+    >(input)
+    .map_err(crate::error::Error::from)
+    .and_then(handle_json_invocation::<F>)
+    .and_then(|response| {
+        serialize_to_ret_buffer(&response, false)
+            .map_err(crate::error::Error::from)
+    })
+    .map_err(|error| {
+        serialize_to_ret_buffer::<crate::error::ErrorResponse>(
+            &error.into(),
+            true,
+        )
+        .expect("can't fail")
+    });
+
+    match result {
+        Ok(buffer) => buffer,
+        Err(buffer) => buffer,
+    }
+}
+
+/// Handles an invocation whose input is a CBOR encoded buffer of the given
+/// length. The [`Response`] or error is returned CBOR encoded as well.
+///
+/// # Safety
+///
+/// The pointer must point to at least `len` bytes that are valid for reads.
+pub unsafe fn handle_cbor_invocation<'f, F>(
+    input: *const u8,
+    len: usize,
+) -> RetBuffer
+where
+    F: Function<'f>,
+{
+    let input = std::slice::from_raw_parts(input, len);
     let result =
-        crate::utils::read_and_deserialize_from_memory::<F::Input>(input)
+        crate::utils::deserialize_from_cbor::<serde_json::Value>(input)
             .map_err(crate::error::Error::from)
-            .and_then(
-                |input| F::handle(input).map_err(crate::error::Error::from)
-            )
-            .and_then(|output| {
-                serialize_and_write_to_memory::<F::Output>(&output)
+            .and_then(handle_json_invocation::<F>)
+            .and_then(|response| {
+                serialize_to_cbor_ret_buffer(&response, false)
                     .map_err(crate::error::Error::from)
             })
             .map_err(|error| {
-                serialize_and_write_to_memory::<crate::error::Error>(&error)
-                    .expect("can't fail")
+                serialize_to_cbor_ret_buffer::<crate::error::ErrorResponse>(
+                    &error.into(),
+                    true,
+                )
+                .expect("can't fail")
             });
 
     match result {
-        Ok(ptr) => ptr,
-        Err(ptr) => ptr,
+        Ok(buffer) => buffer,
+        Err(buffer) => buffer,
     }
 }
 
+/// Handles an invocation from the JVM whose input is a JSON string. The
+/// [`Response`] or error is returned as a JSON string.
 pub fn handle_jni_invocation<'f, F>(
     mut env: jni::JNIEnv,
     _: jni::objects::JClass,
@@ -52,23 +99,98 @@ pub fn handle_jni_invocation<'f, F>(
 where
     F: Function<'f>,
 {
-    let result =
-        crate::utils::deserialize_from_jstring::<F::Input>(&mut env, &input)
+    let result = crate::utils::deserialize_from_jstring::<serde_json::Value>(
+        &mut env, &input,
+    )
+    .map_err(crate::error::Error::from)
+    .and_then(handle_json_invocation::<F>)
+    .and_then(|response| {
+        serialize_to_jstring(&env, &response)
             .map_err(crate::error::Error::from)
-            .and_then(
-                |input| F::handle(input).map_err(crate::error::Error::from)
-            )
-            .and_then(|output| {
-                serialize_to_jstring::<F::Output>(&env, &output)
-                    .map_err(crate::error::Error::from)
-            })
-            .map_err(|error| {
-                serialize_to_jstring::<crate::error::Error>(&env, &error)
-                    .expect("can't fail")
-            });
+    })
+    .map_err(|error| {
+        serialize_to_jstring::<crate::error::ErrorResponse>(
+            &env,
+            &error.into(),
+        )
+        .expect("can't fail")
+    });
 
     match result {
         Ok(ptr) => ptr,
         Err(ptr) => ptr,
     }
 }
+
+/// Handles an invocation from JS through wasm-bindgen returning the
+/// [`Response`]. Errors are thrown as JS `Error` objects whose `error`
+/// property holds the [`ErrorResponse`].
+///
+/// [`ErrorResponse`]: crate::error::ErrorResponse
+#[cfg(feature = "wasm-bindgen")]
+pub fn handle_wasm_bindgen_invocation<'f, F>(
+    input: wasm_bindgen::JsValue,
+) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue>
+where
+    F: Function<'f>,
+{
+    crate::utils::deserialize_from_js_value::<serde_json::Value>(&input)
+        .map_err(crate::error::Error::from)
+        .and_then(handle_json_invocation::<F>)
+        .and_then(|response| {
+            crate::utils::serialize_to_js_value(&response)
+                .map_err(crate::error::Error::from)
+        })
+        .map_err(|error| {
+            let error = crate::error::ErrorResponse::from(error);
+            let js_error = js_sys::Error::new(&error.message);
+            if let Ok(value) = crate::utils::serialize_to_js_value::<
+                crate::error::ErrorResponse,
+            >(&error)
+            {
+                let _ = js_sys::Reflect::set(
+                    &js_error,
+                    &"error".into(),
+                    &value,
+                );
+            }
+            js_error.into()
+        })
+}
+
+/// Handles an invocation whose input has already been parsed into JSON. This
+/// is what all of the interfaces of the toolkit go through, the returned
+/// [`Response`] holds the output of the function along with any warnings that
+/// were reported while handling it.
+pub fn handle_json_invocation<'f, F>(
+    mut input: serde_json::Value,
+) -> Result<Response<serde_json::Value>, crate::error::Error>
+where
+    F: Function<'f>,
+{
+    let mut warnings = vec![];
+    upgrade_input::<F>(&mut input, &mut warnings)?;
+    let interpreted_input = serde_json::from_value::<F::Input>(input.clone())
+        .map_err(|error| {
+            crate::error::InvocationInterpretationError::DeserializationError(
+                debug_string(error),
+            )
+        })?;
+
+    // Any fields of the input that were not interpreted by the function are
+    // reported as warnings as they might indicate a mistake by the caller.
+    warnings.extend(
+        serde_json::to_value(&interpreted_input)
+            .map(|interpreted| ignored_fields(&input, &interpreted))
+            .unwrap_or_default(),
+    );
+
+    let output = F::handle_with_warnings(interpreted_input, &mut warnings)?;
+    let output = serde_json::to_value::<F::Output>(output).map_err(|error| {
+        crate::error::InvocationInterpretationError::SerializationError(
+            debug_string(error),
+        )
+    })?;
+
+    Ok(Response { output, warnings })
+}
@@ -15,12 +15,25 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use super::macros::{export_function, export_jni_function};
+use super::dispatch::dispatched_functions;
+use super::macros::{
+    export_function, export_jni_function, export_wasm_bindgen_function,
+};
 use super::traits::Function;
+use crate::error::{ErrorCatalogEntry, ERROR_CATALOG};
+use crate::functions::batch::Batch;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::functions::jobs::{CancelRequest, PollRequest, SubmitRequest};
+use crate::models::common::SerializableU32;
+use crate::versioning::{CURRENT_SCHEMA_VERSION, MINIMUM_SCHEMA_VERSION};
 use radix_engine_toolkit::functions::information::DependencyInformation;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+//===================
+// Build Information
+//===================
+
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone)]
 pub struct BuildInformationInput {}
@@ -30,6 +43,31 @@ pub struct BuildInformationInput {}
 pub struct BuildInformationOutput {
     pub version: String,
     pub scrypto_dependency: SerializableDependencyInformation,
+    pub capabilities: SerializableCapabilities,
+    pub schema_versions: SerializableSchemaVersions,
+}
+
+/// The range of versions of the request schema that requests can be written
+/// against. Requests state their version through the optional top-level
+/// `schema_version` field of their input.
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableSchemaVersions {
+    pub minimum: SerializableU32,
+    pub current: SerializableU32,
+}
+
+/// The optional capabilities of the interface that callers can check for
+/// before relying on them.
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableCapabilities {
+    /// Whether each function is also exported with a `_cbor` suffix which
+    /// takes and returns CBOR rather than JSON encoded payloads.
+    pub cbor_encoding: bool,
+    /// Whether the output buffers can be allocated in an arena created
+    /// through `toolkit_arena_create`.
+    pub arena_allocation: bool,
 }
 
 #[typeshare::typeshare]
@@ -68,9 +106,152 @@ impl<'a> Function<'a> for BuildInformation {
                     SerializableDependencyInformation::Rev(string)
                 }
             },
+            capabilities: SerializableCapabilities {
+                cbor_encoding: true,
+                arena_allocation: true,
+            },
+            schema_versions: SerializableSchemaVersions {
+                minimum: MINIMUM_SCHEMA_VERSION.into(),
+                current: CURRENT_SCHEMA_VERSION.into(),
+            },
         })
     }
 }
 
 export_function!(BuildInformation as build_information);
 export_jni_function!(BuildInformation as buildInformation);
+export_wasm_bindgen_function!(BuildInformation as buildInformation);
+
+//================
+// List Functions
+//================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct ListFunctionsInput {}
+
+#[typeshare::typeshare]
+pub type ListFunctionsOutput = Vec<SerializableFunctionDescription>;
+
+/// Describes one of the exported functions so that bindings can check that
+/// the loaded library exports the functions that they were generated for.
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableFunctionDescription {
+    /// The name that the function is exported and dispatched under.
+    pub name: String,
+    /// The names of the JSON schemas of the input and output of the function
+    /// as they appear in the generated schema bundle.
+    pub input_schema_id: String,
+    pub output_schema_id: String,
+    /// The cargo features of the JSON crate that the library must be built
+    /// with for the function to be available.
+    pub required_features: Vec<String>,
+}
+
+impl SerializableFunctionDescription {
+    pub fn new<'f, F>(name: &str, required_features: &[&str]) -> Self
+    where
+        F: Function<'f>,
+    {
+        Self {
+            name: name.to_owned(),
+            input_schema_id: <F::Input as JsonSchema>::schema_name(),
+            output_schema_id: <F::Output as JsonSchema>::schema_name(),
+            required_features: required_features
+                .iter()
+                .map(|feature| (*feature).to_owned())
+                .collect(),
+        }
+    }
+}
+
+pub struct ListFunctions;
+impl<'a> Function<'a> for ListFunctions {
+    type Input = ListFunctionsInput;
+    type Output = ListFunctionsOutput;
+
+    fn handle(
+        _: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let mut functions = dispatched_functions();
+        // The functions that can not be invoked through the dispatcher.
+        functions
+            .push(SerializableFunctionDescription::new::<Batch>("batch", &[]));
+        // The jobs are not available on wasm32 regardless of the features as
+        // there are no threads to handle the requests on.
+        #[cfg(not(target_arch = "wasm32"))]
+        functions.extend([
+            SerializableFunctionDescription::new::<SubmitRequest>(
+                "submit_request",
+                &[],
+            ),
+            SerializableFunctionDescription::new::<PollRequest>(
+                "poll_request",
+                &[],
+            ),
+            SerializableFunctionDescription::new::<CancelRequest>(
+                "cancel_request",
+                &[],
+            ),
+        ]);
+        Ok(functions)
+    }
+}
+
+export_function!(ListFunctions as list_functions);
+export_jni_function!(ListFunctions as listFunctions);
+export_wasm_bindgen_function!(ListFunctions as listFunctions);
+
+//===============
+// Error Catalog
+//===============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct ErrorCatalogInput {}
+
+#[typeshare::typeshare]
+pub type ErrorCatalogOutput = Vec<SerializableErrorCatalogEntry>;
+
+/// Describes one of the errors that the toolkit can return so that bindings
+/// can generate typed errors from the codes of error responses.
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SerializableErrorCatalogEntry {
+    pub code: u32,
+    pub kind: String,
+    pub category: String,
+}
+
+impl From<ErrorCatalogEntry> for SerializableErrorCatalogEntry {
+    fn from(
+        ErrorCatalogEntry {
+            code,
+            kind,
+            category,
+        }: ErrorCatalogEntry,
+    ) -> Self {
+        Self {
+            code,
+            kind: kind.to_owned(),
+            category: category.to_owned(),
+        }
+    }
+}
+
+pub struct ErrorCatalog;
+impl<'a> Function<'a> for ErrorCatalog {
+    type Input = ErrorCatalogInput;
+    type Output = ErrorCatalogOutput;
+
+    fn handle(
+        _: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(ERROR_CATALOG.iter().copied().map(Into::into).collect())
+    }
+}
+
+export_function!(ErrorCatalog as error_catalog);
+export_jni_function!(ErrorCatalog as errorCatalog);
+export_wasm_bindgen_function!(ErrorCatalog as errorCatalog);
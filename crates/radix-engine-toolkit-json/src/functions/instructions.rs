@@ -18,6 +18,11 @@
 use crate::prelude::*;
 
 use radix_common::types::EntityType;
+use radix_engine_toolkit::functions::instructions::{
+    compile_forward_compatible, decompile_forward_compatible,
+    extract_address_occurrences, AddressUsage, DecompiledInstruction,
+};
+use radix_engine_toolkit::functions::limits::check_limits;
 use radix_engine_toolkit::models::node_id::TypedNodeId;
 use sbor::prelude::{HashMap, HashSet};
 use schemars::JsonSchema;
@@ -64,6 +69,7 @@ impl<'a> Function<'a> for InstructionsHash {
 
 export_function!(InstructionsHash as instructions_hash);
 export_jni_function!(InstructionsHash as instructionsHash);
+export_wasm_bindgen_function!(InstructionsHash as instructionsHash);
 
 //======================
 // Instructions Convert
@@ -101,6 +107,7 @@ impl<'a> Function<'a> for InstructionsConvert {
 
 export_function!(InstructionsConvert as instructions_convert);
 export_jni_function!(InstructionsConvert as instructionsConvert);
+export_wasm_bindgen_function!(InstructionsConvert as instructionsConvert);
 
 //======================
 // Instructions Compile
@@ -111,6 +118,9 @@ export_jni_function!(InstructionsConvert as instructionsConvert);
 pub struct InstructionsCompileInput {
     pub instructions: SerializableInstructions,
     pub network_id: SerializableU8,
+    /// The limits that the compiled payload is checked against. No limits are
+    /// checked when absent.
+    pub limits: Option<SerializableCompilationLimitsSource>,
 }
 #[typeshare::typeshare]
 pub type InstructionsCompileOutput = SerializableBytes;
@@ -124,19 +134,27 @@ impl<'a> Function<'a> for InstructionsCompile {
         InstructionsCompileInput {
             instructions,
             network_id,
+            limits,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
-        let instructions = instructions.to_instructions(*network_id)?;
+        let instructions =
+            instructions.to_decompiled_instructions(*network_id)?;
+        let instruction_count = instructions.len();
 
-        let compiled = radix_engine_toolkit::functions::instructions::compile(
-            &instructions,
-        )
-        .map_err(|error| {
-            InvocationHandlingError::EncodeError(
-                debug_string(error),
-                debug_string(instructions),
-            )
-        })?;
+        let compiled =
+            compile_forward_compatible(&instructions).map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(instructions),
+                )
+            })?;
+        if let Some(limits) = limits {
+            check_limits(
+                &compiled,
+                instruction_count,
+                &limits.to_native(*network_id)?,
+            )?;
+        }
 
         Ok(compiled.into())
     }
@@ -144,6 +162,7 @@ impl<'a> Function<'a> for InstructionsCompile {
 
 export_function!(InstructionsCompile as instructions_compile);
 export_jni_function!(InstructionsCompile as instructionsCompile);
+export_wasm_bindgen_function!(InstructionsCompile as instructionsCompile);
 
 //========================
 // Instructions Decompile
@@ -155,9 +174,21 @@ pub struct InstructionsDecompileInput {
     pub compiled: SerializableBytes,
     pub network_id: SerializableU8,
     pub instructions_kind: SerializableInstructionsKind,
+    /// Whether the manifest string is annotated with comments that describe
+    /// the instructions. Has no effect on parsed instructions.
+    #[serde(default)]
+    pub annotate: bool,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionsDecompileOutput {
+    #[serde(flatten)]
+    pub instructions: SerializableInstructions,
+    /// Whether the instructions contain instructions that this version of the
+    /// toolkit does not know of. Such instructions are returned as unknown
+    /// parsed instructions and the instructions are always parsed then.
+    pub contains_unknown_instructions: bool,
 }
-#[typeshare::typeshare]
-pub type InstructionsDecompileOutput = SerializableInstructions;
 
 pub struct InstructionsDecompile;
 impl<'a> Function<'a> for InstructionsDecompile {
@@ -169,32 +200,41 @@ impl<'a> Function<'a> for InstructionsDecompile {
             compiled,
             network_id,
             instructions_kind,
+            annotate,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
-        let instructions =
-            radix_engine_toolkit::functions::instructions::decompile(
-                &**compiled,
-            )
+        let instructions = decompile_forward_compatible(&**compiled)
             .map_err(|error| {
                 InvocationHandlingError::EncodeError(
                     debug_string(error),
                     debug_string(compiled),
                 )
             })?;
+        let contains_unknown_instructions =
+            instructions.iter().any(DecompiledInstruction::is_unknown);
 
-        let instructions =
-            SerializableInstructions::new(
+        let mut serializable_instructions =
+            SerializableInstructions::from_decompiled_instructions(
                 &instructions,
                 instructions_kind,
                 *network_id,
             )?;
+        if annotate && !contains_unknown_instructions {
+            let instructions =
+                serializable_instructions.to_instructions(*network_id)?;
+            serializable_instructions.annotate(&instructions, *network_id)?;
+        }
 
-        Ok(instructions)
+        Ok(InstructionsDecompileOutput {
+            instructions: serializable_instructions,
+            contains_unknown_instructions,
+        })
     }
 }
 
 export_function!(InstructionsDecompile as instructions_decompile);
 export_jni_function!(InstructionsDecompile as instructionsDecompile);
+export_wasm_bindgen_function!(InstructionsDecompile as instructionsDecompile);
 
 //==================================
 // Instructions Statically Validate
@@ -243,6 +283,9 @@ export_function!(
 export_jni_function!(
     InstructionsStaticallyValidate as instructionsStaticallyValidate
 );
+export_wasm_bindgen_function!(
+    InstructionsStaticallyValidate as instructionsStaticallyValidate
+);
 
 //================================
 // Instructions Extract Addresses
@@ -261,6 +304,47 @@ pub struct InstructionsExtractAddressesOutput {
     pub addresses: HashMap<SerializableEntityType, Vec<SerializableNodeId>>,
     #[typeshare(serialized_as = "Vec<SerializableU32>")]
     pub named_addresses: HashSet<SerializableU32>,
+    /// The static addresses along with the instructions that they appear in
+    /// and how they're used there.
+    pub address_occurrences: Vec<SerializableAddressOccurrences>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAddressOccurrences {
+    pub address: SerializableNodeId,
+    pub occurrences: Vec<SerializableAddressOccurrence>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAddressOccurrence {
+    pub instruction_index: SerializableU64,
+    pub usage: SerializableAddressUsage,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableAddressUsage {
+    CallTarget,
+    Argument,
+    MetadataSubject,
+    RoyaltyTarget,
+    Metadata,
+    Resource,
+}
+
+impl From<AddressUsage> for SerializableAddressUsage {
+    fn from(value: AddressUsage) -> Self {
+        match value {
+            AddressUsage::CallTarget => Self::CallTarget,
+            AddressUsage::Argument => Self::Argument,
+            AddressUsage::MetadataSubject => Self::MetadataSubject,
+            AddressUsage::RoyaltyTarget => Self::RoyaltyTarget,
+            AddressUsage::Metadata => Self::Metadata,
+            AddressUsage::Resource => Self::Resource,
+        }
+    }
 }
 
 pub struct InstructionsExtractAddresses;
@@ -282,6 +366,24 @@ impl<'a> Function<'a> for InstructionsExtractAddresses {
             );
 
         let addresses = transform_addresses_set_to_map(addresses, *network_id);
+        let address_occurrences = extract_address_occurrences(&instructions)
+            .into_iter()
+            .map(|(address, occurrences)| SerializableAddressOccurrences {
+                address: SerializableNodeId::new(
+                    *address.as_node_id(),
+                    *network_id,
+                ),
+                occurrences: occurrences
+                    .into_iter()
+                    .map(|occurrence| SerializableAddressOccurrence {
+                        instruction_index: SerializableU64::from(
+                            occurrence.instruction_index as u64,
+                        ),
+                        usage: occurrence.usage.into(),
+                    })
+                    .collect(),
+            })
+            .collect();
 
         Ok(Self::Output {
             addresses,
@@ -289,6 +391,7 @@ impl<'a> Function<'a> for InstructionsExtractAddresses {
                 .into_iter()
                 .map(Into::into)
                 .collect(),
+            address_occurrences,
         })
     }
 }
@@ -299,6 +402,9 @@ export_function!(
 export_jni_function!(
     InstructionsExtractAddresses as instructionsExtractAddresses
 );
+export_wasm_bindgen_function!(
+    InstructionsExtractAddresses as instructionsExtractAddresses
+);
 
 #[typeshare::typeshare]
 #[derive(
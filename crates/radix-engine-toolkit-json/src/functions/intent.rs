@@ -15,6 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_common::types::Epoch;
+use radix_engine_toolkit::functions::intent::{
+    NonceSource, TransactionHeaderOptions,
+};
+use radix_engine_toolkit::functions::limits::check_limits;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -51,13 +56,57 @@ impl<'f> Function<'f> for IntentHash {
 
 export_function!(IntentHash as intent_hash);
 export_jni_function!(IntentHash as intentHash);
+export_wasm_bindgen_function!(IntentHash as intentHash);
+
+//===========================
+// Intent Hash from Compiled
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentHashFromCompiledInput {
+    pub compiled: SerializableBytes,
+}
+#[typeshare::typeshare]
+pub type IntentHashFromCompiledOutput = SerializableTransactionHash;
+
+pub struct IntentHashFromCompiled;
+impl<'a> Function<'a> for IntentHashFromCompiled {
+    type Input = IntentHashFromCompiledInput;
+    type Output = IntentHashFromCompiledOutput;
+
+    fn handle(
+        IntentHashFromCompiledInput { compiled }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let hash = radix_engine_toolkit::functions::intent::hash_from_payload(
+            &**compiled,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::DecodeError(
+                debug_string(error),
+                debug_string(compiled),
+            )
+        })?;
+        Ok(hash.into())
+    }
+}
+
+export_function!(IntentHashFromCompiled as intent_hash_from_compiled);
+export_jni_function!(IntentHashFromCompiled as intentHashFromCompiled);
+export_wasm_bindgen_function!(IntentHashFromCompiled as intentHashFromCompiled);
 
 //================
 // Intent Compile
 //================
 
-#[typeshare::typeshare]
-pub type IntentCompileInput = SerializableIntent;
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentCompileInput {
+    #[serde(flatten)]
+    pub intent: SerializableIntent,
+    /// The limits that the compiled payload is checked against. No limits are
+    /// checked when absent.
+    pub limits: Option<SerializableCompilationLimitsSource>,
+}
 #[typeshare::typeshare]
 pub type IntentCompileOutput = SerializableBytes;
 
@@ -67,9 +116,10 @@ impl<'f> Function<'f> for IntentCompile {
     type Output = IntentCompileOutput;
 
     fn handle(
-        intent: Self::Input,
+        IntentCompileInput { intent, limits }: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let intent = intent.to_native(*intent.header.network_id)?;
+        let instruction_count = intent.instructions.0.len();
         let compile = radix_engine_toolkit::functions::intent::compile(&intent)
             .map_err(|error| {
                 InvocationHandlingError::EncodeError(
@@ -77,12 +127,20 @@ impl<'f> Function<'f> for IntentCompile {
                     debug_string(intent),
                 )
             })?;
+        if let Some(limits) = limits {
+            check_limits(
+                &compile,
+                instruction_count,
+                &limits.to_native(intent.header.network_id)?,
+            )?;
+        }
         Ok(compile.into())
     }
 }
 
 export_function!(IntentCompile as intent_compile);
 export_jni_function!(IntentCompile as intentCompile);
+export_wasm_bindgen_function!(IntentCompile as intentCompile);
 
 //==================
 // Intent Decompile
@@ -93,6 +151,10 @@ export_jni_function!(IntentCompile as intentCompile);
 pub struct IntentDecompileInput {
     pub compiled: SerializableBytes,
     pub instructions_kind: SerializableInstructionsKind,
+    /// Whether the manifest string is annotated with comments that describe
+    /// the instructions. Has no effect on parsed instructions.
+    #[serde(default)]
+    pub annotate: bool,
 }
 #[typeshare::typeshare]
 pub type IntentDecompileOutput = SerializableIntent;
@@ -106,6 +168,7 @@ impl<'a> Function<'a> for IntentDecompile {
         IntentDecompileInput {
             compiled,
             instructions_kind,
+            annotate,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
         let intent =
@@ -117,18 +180,145 @@ impl<'a> Function<'a> for IntentDecompile {
                     )
                 })?;
 
-        let intent = SerializableIntent::from_native(
+        let mut serializable_intent = SerializableIntent::from_native(
             &intent,
             intent.header.network_id,
             instructions_kind,
         )?;
+        if annotate {
+            serializable_intent
+                .manifest
+                .instructions
+                .annotate(&intent.instructions.0, intent.header.network_id)?;
+        }
 
-        Ok(intent)
+        Ok(serializable_intent)
     }
 }
 
 export_function!(IntentDecompile as intent_decompile);
 export_jni_function!(IntentDecompile as intentDecompile);
+export_wasm_bindgen_function!(IntentDecompile as intentDecompile);
+
+//=============
+// Intent Sign
+//=============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentSignInput {
+    pub intent: SerializableIntent,
+    pub private_key: SerializableBytes,
+    pub curve: SerializableCurveType,
+    #[serde(default)]
+    pub assemble_signed_intent: bool,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentSignOutput {
+    pub signature: SerializableSignatureWithPublicKey,
+    /// The intent along with the signature, only present if the signed intent
+    /// was requested to be assembled.
+    pub signed_intent: Option<SerializableSignedIntent>,
+}
+
+pub struct IntentSign;
+impl<'a> Function<'a> for IntentSign {
+    type Input = IntentSignInput;
+    type Output = IntentSignOutput;
+
+    fn handle(
+        IntentSignInput {
+            intent,
+            private_key,
+            curve,
+            assemble_signed_intent,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let native_intent = intent.to_native(*intent.header.network_id)?;
+        let private_key =
+            radix_engine_toolkit::functions::signing::private_key_from_bytes(
+                &private_key,
+                curve.into(),
+            )?;
+
+        let signature: SerializableSignatureWithPublicKey =
+            radix_engine_toolkit::functions::intent::sign(
+                &native_intent,
+                &private_key,
+            )
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(&native_intent),
+                )
+            })?
+            .into();
+        let signed_intent =
+            assemble_signed_intent.then(|| SerializableSignedIntent {
+                intent,
+                intent_signatures: vec![signature.clone()],
+            });
+
+        Ok(Self::Output {
+            signature,
+            signed_intent,
+        })
+    }
+}
+
+export_function!(IntentSign as intent_sign);
+export_jni_function!(IntentSign as intentSign);
+export_wasm_bindgen_function!(IntentSign as intentSign);
+
+//=========================
+// Intent Merge Signatures
+//=========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct IntentMergeSignaturesInput {
+    pub intent: SerializableIntent,
+    /// The signatures produced by each of the signers of the intent.
+    pub signatures: Vec<Vec<SerializableSignatureWithPublicKey>>,
+}
+
+#[typeshare::typeshare]
+pub type IntentMergeSignaturesOutput = SerializableSignedIntent;
+
+pub struct IntentMergeSignatures;
+impl<'a> Function<'a> for IntentMergeSignatures {
+    type Input = IntentMergeSignaturesInput;
+    type Output = IntentMergeSignaturesOutput;
+
+    fn handle(
+        IntentMergeSignaturesInput { intent, signatures }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let native_intent = intent.to_native(*intent.header.network_id)?;
+        let signed_intent =
+            radix_engine_toolkit::functions::intent::merge_signatures(
+                native_intent,
+                signatures.into_iter().map(|signatures| {
+                    signatures.into_iter().map(Into::into).collect()
+                }),
+            )?;
+
+        Ok(Self::Output {
+            intent,
+            intent_signatures: signed_intent
+                .intent_signatures
+                .signatures
+                .into_iter()
+                .map(|signature| signature.0.into())
+                .collect(),
+        })
+    }
+}
+
+export_function!(IntentMergeSignatures as intent_merge_signatures);
+export_jni_function!(IntentMergeSignatures as intentMergeSignatures);
+export_wasm_bindgen_function!(IntentMergeSignatures as intentMergeSignatures);
 
 //============================
 // Intent Statically Validate
@@ -175,3 +365,80 @@ impl<'a> Function<'a> for IntentStaticallyValidate {
 
 export_function!(IntentStaticallyValidate as intent_statically_validate);
 export_jni_function!(IntentStaticallyValidate as intentStaticallyValidate);
+export_wasm_bindgen_function!(
+    IntentStaticallyValidate as intentStaticallyValidate
+);
+
+//==========================
+// Build Transaction Header
+//==========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildTransactionHeaderInput {
+    pub start_epoch_inclusive: SerializableU64,
+    pub end_epoch_exclusive: SerializableU64,
+    pub nonce: SerializableNonceSource,
+    pub notary_public_key: SerializablePublicKey,
+    pub notary_is_signatory: bool,
+    pub tip_percentage: SerializableU16,
+    /// The config of the network that the header is built for and validated
+    /// against.
+    pub validation_config: SerializableValidationConfig,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableNonceSource {
+    Random,
+    Fixed(SerializableU32),
+}
+
+impl From<SerializableNonceSource> for NonceSource {
+    fn from(value: SerializableNonceSource) -> Self {
+        match value {
+            SerializableNonceSource::Random => Self::Random,
+            SerializableNonceSource::Fixed(nonce) => Self::Fixed(*nonce),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+pub type BuildTransactionHeaderOutput = SerializableTransactionHeader;
+
+pub struct BuildTransactionHeader;
+impl<'a> Function<'a> for BuildTransactionHeader {
+    type Input = BuildTransactionHeaderInput;
+    type Output = BuildTransactionHeaderOutput;
+
+    fn handle(
+        BuildTransactionHeaderInput {
+            start_epoch_inclusive,
+            end_epoch_exclusive,
+            nonce,
+            notary_public_key,
+            notary_is_signatory,
+            tip_percentage,
+            validation_config,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let header =
+            radix_engine_toolkit::functions::intent::build_transaction_header(
+                TransactionHeaderOptions {
+                    start_epoch_inclusive: Epoch::of(*start_epoch_inclusive),
+                    end_epoch_exclusive: Epoch::of(*end_epoch_exclusive),
+                    nonce: nonce.into(),
+                    notary_public_key: notary_public_key.into(),
+                    notary_is_signatory,
+                    tip_percentage: *tip_percentage,
+                },
+                &validation_config.into(),
+            )?;
+        Ok(header.into())
+    }
+}
+
+export_function!(BuildTransactionHeader as build_transaction_header);
+export_jni_function!(BuildTransactionHeader as buildTransactionHeader);
+export_wasm_bindgen_function!(BuildTransactionHeader as buildTransactionHeader);
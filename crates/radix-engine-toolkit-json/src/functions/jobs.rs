@@ -0,0 +1,270 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! An asynchronous invocation surface for long-running requests such as the
+//! analysis of large manifests. A request is submitted and handled on a
+//! background thread while the caller gets back a handle that it can poll for
+//! the response or use to cancel the request. This allows callers to avoid
+//! blocking their UI threads on the synchronous FFI functions.
+//!
+//! Requests are handled by a fixed number of worker threads and are therefore
+//! not supported on targets without threads such as `wasm32-unknown-unknown`,
+//! this module is not compiled for those targets.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+use crate::prelude::*;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The requests that have been submitted and not yet collected or cancelled
+/// keyed by their handle.
+static JOBS: Mutex<BTreeMap<u64, JobState>> = Mutex::new(BTreeMap::new());
+static NEXT_JOB_HANDLE: AtomicU64 = AtomicU64::new(0);
+
+/// The requests that have been submitted and are waiting for a worker.
+static QUEUE: Mutex<VecDeque<QueuedJob>> = Mutex::new(VecDeque::new());
+static QUEUE_CONDVAR: Condvar = Condvar::new();
+
+/// The maximum number of worker threads that requests are handled on. The
+/// workers are spawned as requests are submitted and live for the lifetime of
+/// the process.
+const MAX_WORKERS: usize = 4;
+static SPAWNED_WORKERS: AtomicUsize = AtomicUsize::new(0);
+
+enum JobState {
+    Pending,
+    Completed(InvocationResponse),
+}
+
+struct QueuedJob {
+    handle: u64,
+    function: String,
+    input: serde_json::Value,
+}
+
+fn jobs() -> MutexGuard<'static, BTreeMap<u64, JobState>> {
+    // A panic while holding the lock can not leave the map in an inconsistent
+    // state so the poisoning is ignored.
+    JOBS.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+fn queue() -> MutexGuard<'static, VecDeque<QueuedJob>> {
+    QUEUE.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// Spawns another worker if the maximum number of workers has not yet been
+/// reached.
+fn ensure_worker() {
+    let spawned = SPAWNED_WORKERS.fetch_update(
+        Ordering::Relaxed,
+        Ordering::Relaxed,
+        |count| (count < MAX_WORKERS).then_some(count + 1),
+    );
+    if spawned.is_ok() {
+        std::thread::spawn(worker);
+    }
+}
+
+fn worker() {
+    loop {
+        let QueuedJob {
+            handle,
+            function,
+            input,
+        } = {
+            let mut queue = queue();
+            loop {
+                match queue.pop_front() {
+                    Some(job) => break job,
+                    None => {
+                        queue = QUEUE_CONDVAR
+                            .wait(queue)
+                            .unwrap_or_else(|error| error.into_inner())
+                    }
+                }
+            }
+        };
+
+        // Requests that were cancelled while waiting in the queue are not
+        // handled at all.
+        if !jobs().contains_key(&handle) {
+            continue;
+        }
+
+        // A panic while handling the request is reported as the response of
+        // the request so that it doesn't remain pending forever.
+        let response =
+            catch_unwind(AssertUnwindSafe(|| invoke(&function, input)))
+                .unwrap_or_else(|payload| {
+                    Err(InvocationHandlingError::JobPanicked(panic_message(
+                        payload.as_ref(),
+                    ))
+                    .into())
+                })
+                .into();
+
+        // The job is no longer in the map if it was cancelled while it was
+        // being handled, in which case the response is discarded.
+        if let Some(state) = jobs().get_mut(&handle) {
+            *state = JobState::Completed(response)
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "The request panicked".to_owned()
+    }
+}
+
+fn unknown_job(handle: u64) -> InvocationHandlingError {
+    InvocationHandlingError::UnknownJob(handle.to_string())
+}
+
+//================
+// Submit Request
+//================
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SubmitRequestInput {
+    /// The exported name of the function to invoke, e.g., `manifest_analyze`.
+    pub function: String,
+    /// The input of the function.
+    pub input: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SubmitRequestOutput {
+    pub handle: SerializableU64,
+}
+
+pub struct SubmitRequest;
+impl<'a> Function<'a> for SubmitRequest {
+    type Input = SubmitRequestInput;
+    type Output = SubmitRequestOutput;
+
+    fn handle(
+        SubmitRequestInput { function, input }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let handle = NEXT_JOB_HANDLE.fetch_add(1, Ordering::Relaxed);
+        jobs().insert(handle, JobState::Pending);
+
+        queue().push_back(QueuedJob {
+            handle,
+            function,
+            input,
+        });
+        ensure_worker();
+        QUEUE_CONDVAR.notify_one();
+
+        Ok(SubmitRequestOutput {
+            handle: handle.into(),
+        })
+    }
+}
+
+export_function!(SubmitRequest as submit_request);
+export_jni_function!(SubmitRequest as submitRequest);
+export_wasm_bindgen_function!(SubmitRequest as submitRequest);
+
+//==============
+// Poll Request
+//==============
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PollRequestInput {
+    pub handle: SerializableU64,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+#[serde(tag = "kind", content = "value")]
+pub enum PollRequestOutput {
+    Pending,
+    Completed(InvocationResponse),
+}
+
+pub struct PollRequest;
+impl<'a> Function<'a> for PollRequest {
+    type Input = PollRequestInput;
+    type Output = PollRequestOutput;
+
+    /// Returns the response of the request if it has been handled. Once the
+    /// response is returned the handle is released and polling it again is
+    /// an error.
+    fn handle(
+        PollRequestInput { handle }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let handle = *handle;
+        let mut jobs = jobs();
+        match jobs.remove(&handle) {
+            Some(JobState::Pending) => {
+                jobs.insert(handle, JobState::Pending);
+                Ok(PollRequestOutput::Pending)
+            }
+            Some(JobState::Completed(response)) => {
+                Ok(PollRequestOutput::Completed(response))
+            }
+            None => Err(unknown_job(handle)),
+        }
+    }
+}
+
+export_function!(PollRequest as poll_request);
+export_jni_function!(PollRequest as pollRequest);
+export_wasm_bindgen_function!(PollRequest as pollRequest);
+
+//================
+// Cancel Request
+//================
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct CancelRequestInput {
+    pub handle: SerializableU64,
+}
+
+pub type CancelRequestOutput = ();
+
+pub struct CancelRequest;
+impl<'a> Function<'a> for CancelRequest {
+    type Input = CancelRequestInput;
+    type Output = CancelRequestOutput;
+
+    /// Releases the handle of the request. A request that is being handled is
+    /// not interrupted but its response is discarded once it is available.
+    fn handle(
+        CancelRequestInput { handle }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let handle = *handle;
+        jobs()
+            .remove(&handle)
+            .map(|_| ())
+            .ok_or_else(|| unknown_job(handle))
+    }
+}
+
+export_function!(CancelRequest as cancel_request);
+export_jni_function!(CancelRequest as cancelRequest);
+export_wasm_bindgen_function!(CancelRequest as cancelRequest);
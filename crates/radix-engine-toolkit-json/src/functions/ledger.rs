@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::prelude::*;
+
+use radix_engine_toolkit::functions::key_derivation::DerivationPath;
+use radix_engine_toolkit::functions::ledger::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//=================================
+// Ledger Sign Transaction Payload
+//=================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct LedgerSignTransactionPayloadInput {
+    pub compiled_intent: SerializableBytes,
+    /// The derivation path of the signing key such as
+    /// `m/44'/1022'/1'/525'/1460'/0'`.
+    pub derivation_path: String,
+    pub curve: SerializableCurveType,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct LedgerSignTransactionPayloadOutput {
+    /// The encoded APDUs in the order they must be sent to the Ledger app in.
+    pub apdus: Vec<SerializableBytes>,
+    /// The intent hash of the transaction which the app displays and signs.
+    pub intent_hash: SerializableTransactionHash,
+}
+
+pub struct LedgerSignTransactionPayload;
+impl<'f> Function<'f> for LedgerSignTransactionPayload {
+    type Input = LedgerSignTransactionPayloadInput;
+    type Output = LedgerSignTransactionPayloadOutput;
+
+    fn handle(
+        LedgerSignTransactionPayloadInput {
+            compiled_intent,
+            derivation_path,
+            curve,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let derivation_path =
+            derivation_path.parse::<DerivationPath>().map_err(|error| {
+                InvocationHandlingError::DerivationError(debug_string(error))
+            })?;
+        let payload = ledger_signing_payload(
+            &compiled_intent,
+            &derivation_path,
+            curve.into(),
+        )?;
+
+        Ok(Self::Output {
+            apdus: payload
+                .apdus
+                .iter()
+                .map(|apdu| apdu.to_bytes().into())
+                .collect(),
+            intent_hash: payload.intent_hash.into(),
+        })
+    }
+}
+
+export_function!(
+    LedgerSignTransactionPayload as ledger_sign_transaction_payload
+);
+export_jni_function!(
+    LedgerSignTransactionPayload as ledgerSignTransactionPayload
+);
+export_wasm_bindgen_function!(
+    LedgerSignTransactionPayload as ledgerSignTransactionPayload
+);
@@ -17,18 +17,39 @@
 
 macro_rules! export_function {
     ($function: ident as $name: ident) => {
+        /// The returned buffer is owned by the caller and must be released
+        /// through `toolkit_free_ret_buffer` or `toolkit_free_response`.
         #[no_mangle]
         pub extern "C" fn $name(
             ptr: $crate::memory::Pointer,
-        ) -> $crate::memory::Pointer {
+        ) -> $crate::memory::RetBuffer {
             crate::functions::handler::handle_invocation::<$function>(ptr)
         }
+
+        paste::paste! {
+            /// # Safety
+            ///
+            /// The pointer must point to at least `len` bytes that are valid
+            /// for reads. The returned buffer is owned by the caller and must
+            /// be released through `toolkit_free_ret_buffer` or
+            /// `toolkit_free_response`.
+            #[no_mangle]
+            pub unsafe extern "C" fn [< $name _cbor >](
+                ptr: *const u8,
+                len: usize,
+            ) -> $crate::memory::RetBuffer {
+                crate::functions::handler::handle_cbor_invocation::<$function>(ptr, len)
+            }
+        }
     };
 }
 
 macro_rules! export_jni_function {
     ($function: ident as $name: ident) => {
         paste::paste! {
+            /// The returned string is a local reference managed by the JVM,
+            /// it's not allocated by the toolkit and must not be freed
+            /// through any of the `toolkit_free` functions.
             #[no_mangle]
             pub extern "system" fn [< Java_com_radixdlt_toolkit_RadixEngineToolkitFFI_ $name >](
                 env: jni::JNIEnv,
@@ -41,4 +62,24 @@ macro_rules! export_jni_function {
     };
 }
 
-pub(crate) use {export_function, export_jni_function};
+macro_rules! export_wasm_bindgen_function {
+    ($function: ident as $name: ident) => {
+        paste::paste! {
+            /// The returned value is owned by the JS garbage collector, it's
+            /// not allocated by the toolkit and must not be freed through any
+            /// of the `toolkit_free` functions.
+            #[cfg(feature = "wasm-bindgen")]
+            #[allow(non_snake_case)]
+            #[wasm_bindgen::prelude::wasm_bindgen(js_name = $name)]
+            pub fn [< wasm_bindgen_ $name >](
+                input: wasm_bindgen::JsValue,
+            ) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {
+                crate::functions::handler::handle_wasm_bindgen_invocation::<$function>(input)
+            }
+        }
+    };
+}
+
+pub(crate) use {
+    export_function, export_jni_function, export_wasm_bindgen_function,
+};
@@ -15,7 +15,51 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine::system::system_modules::execution_trace::ResourceSpecifier;
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::account::{
+    DefaultDepositRule, ResourcePreference,
+};
+use radix_engine_toolkit::functions::diff::InstructionDiff;
+#[cfg(feature = "native-blueprint-interfaces")]
+use radix_engine_toolkit::functions::instruction_arguments::{
+    native_blueprint_interfaces, validate_instruction_arguments,
+};
+use radix_engine_toolkit::functions::instruction_arguments::{
+    InstructionArgumentsIssue, InstructionArgumentsIssueKind,
+};
+use radix_engine_toolkit::functions::instructions::extract_addresses_with_usage;
+use radix_engine_toolkit::functions::limits::check_limits;
+use radix_engine_toolkit::functions::lint::{
+    LintConfig, LintRule, LintSeverity,
+};
+use radix_engine_toolkit::functions::manifest::{
+    account_interactions, detect_reserved_instructions, required_signers,
+    security_impacts, set_fee_payer, statistics,
+};
+use radix_engine_toolkit::functions::manifest_template::{
+    ManifestAstValueKind, ManifestTemplate,
+};
+use radix_engine_toolkit::functions::reorder::{
+    check_reorder, DependencyKind, InstructionDependency,
+};
+use radix_engine_toolkit::functions::review::{
+    render_manifest_summary, LocaleOptions,
+};
+use radix_engine_toolkit::transaction_types::{
+    AnalyzerRule, AnalyzerRuleCondition, AnalyzerRules, ExecutionReceipt,
+    ManifestAnalyzerRegistry, ManifestClassification, Operation,
+    ReservedInstruction, ResourceMovementsGraph, ResourceMovementsNode,
+    SecurityImpact, SecurityImpactKind, SensitiveInstruction,
+    SensitiveInstructionKind, SimpleTransferAmount, Update,
+};
+use radix_engine_toolkit::utils::network_definition_from_network_id;
+use radix_transactions::prelude::{DynamicGlobalAddress, InstructionV1};
 use schemars::JsonSchema;
+use scrypto::prelude::{
+    ComponentAddress, GlobalAddress, IndexMap, IndexSet, ManifestValue,
+    PackageAddress, ResourceAddress, ResourceOrNonFungible,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
@@ -58,6 +102,7 @@ impl<'f> Function<'f> for ManifestHash {
 
 export_function!(ManifestHash as manifest_hash);
 export_jni_function!(ManifestHash as manifestHash);
+export_wasm_bindgen_function!(ManifestHash as manifestHash);
 
 //==================
 // Manifest Compile
@@ -68,6 +113,9 @@ export_jni_function!(ManifestHash as manifestHash);
 pub struct ManifestCompileInput {
     pub manifest: SerializableTransactionManifest,
     pub network_id: SerializableU8,
+    /// The limits that the compiled payload is checked against. No limits are
+    /// checked when absent.
+    pub limits: Option<SerializableCompilationLimitsSource>,
 }
 #[typeshare::typeshare]
 pub type ManifestCompileOutput = SerializableBytes;
@@ -81,9 +129,11 @@ impl<'f> Function<'f> for ManifestCompile {
         ManifestCompileInput {
             manifest,
             network_id,
+            limits,
         }: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let manifest = manifest.to_native(*network_id)?;
+        let instruction_count = manifest.instructions.len();
         let compile =
             radix_engine_toolkit::functions::manifest::compile(&manifest)
                 .map_err(|error| {
@@ -92,12 +142,20 @@ impl<'f> Function<'f> for ManifestCompile {
                         debug_string(manifest),
                     )
                 })?;
+        if let Some(limits) = limits {
+            check_limits(
+                &compile,
+                instruction_count,
+                &limits.to_native(*network_id)?,
+            )?;
+        }
         Ok(compile.into())
     }
 }
 
 export_function!(ManifestCompile as manifest_compile);
 export_jni_function!(ManifestCompile as manifestCompile);
+export_wasm_bindgen_function!(ManifestCompile as manifestCompile);
 
 //====================
 // Manifest Decompile
@@ -109,6 +167,10 @@ pub struct ManifestDecompileInput {
     pub compiled: SerializableBytes,
     pub network_id: SerializableU8,
     pub instructions_kind: SerializableInstructionsKind,
+    /// Whether the manifest string is annotated with comments that describe
+    /// the instructions. Has no effect on parsed instructions.
+    #[serde(default)]
+    pub annotate: bool,
 }
 #[typeshare::typeshare]
 pub type ManifestDecompileOutput = SerializableTransactionManifest;
@@ -123,6 +185,7 @@ impl<'a> Function<'a> for ManifestDecompile {
             compiled,
             network_id,
             instructions_kind,
+            annotate,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
         let manifest =
@@ -134,18 +197,25 @@ impl<'a> Function<'a> for ManifestDecompile {
                 )
             })?;
 
-        let manifest = SerializableTransactionManifest::from_native(
-            &manifest,
-            *network_id,
-            instructions_kind,
-        )?;
+        let mut serializable_manifest =
+            SerializableTransactionManifest::from_native(
+                &manifest,
+                *network_id,
+                instructions_kind,
+            )?;
+        if annotate {
+            serializable_manifest
+                .instructions
+                .annotate(&manifest.instructions, *network_id)?;
+        }
 
-        Ok(manifest)
+        Ok(serializable_manifest)
     }
 }
 
 export_function!(ManifestDecompile as manifest_decompile);
 export_jni_function!(ManifestDecompile as manifestDecompile);
+export_wasm_bindgen_function!(ManifestDecompile as manifestDecompile);
 
 //==============================
 // Manifest Statically Validate
@@ -190,3 +260,2374 @@ impl<'a> Function<'a> for ManifestStaticallyValidate {
 
 export_function!(ManifestStaticallyValidate as manifest_statically_validate);
 export_jni_function!(ManifestStaticallyValidate as manifestStaticallyValidate);
+export_wasm_bindgen_function!(
+    ManifestStaticallyValidate as manifestStaticallyValidate
+);
+
+//==================
+// Manifest Analyze
+//==================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestAnalyzeInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+/// The result of the static analysis of a manifest. This analysis does not
+/// require the manifest to be executed and thus does not require a receipt.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestAnalyzeOutput {
+    pub accounts_withdrawn_from: Vec<SerializableNodeId>,
+    pub accounts_deposited_into: Vec<SerializableNodeId>,
+    pub accounts_requiring_auth: Vec<SerializableNodeId>,
+    pub identities_requiring_auth: Vec<SerializableNodeId>,
+    pub resources_used: Vec<SerializableNodeId>,
+    pub presented_proofs: Vec<SerializablePresentedProofs>,
+    pub encountered_entities: Vec<SerializableNodeId>,
+    pub reserved_instructions: Vec<SerializableReservedInstruction>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializablePresentedProofs {
+    pub account: SerializableNodeId,
+    pub resources: Vec<SerializableNodeId>,
+}
+
+impl SerializablePresentedProofs {
+    fn from_native(
+        presented_proofs: IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+        network_id: u8,
+    ) -> Vec<Self> {
+        presented_proofs
+            .into_iter()
+            .map(|(account, resources)| Self {
+                account: SerializableNodeId::from_global_address(
+                    account, network_id,
+                ),
+                resources: resources
+                    .into_iter()
+                    .map(|resource| {
+                        let resource_address = match resource {
+                            ResourceSpecifier::Amount(address, ..)
+                            | ResourceSpecifier::Ids(address, ..) => address,
+                        };
+                        SerializableNodeId::from_global_address(
+                            resource_address,
+                            network_id,
+                        )
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableReservedInstruction {
+    AccountLockFee,
+    AccountSecurify,
+    IdentitySecurify,
+    AccountUpdateSettings,
+    AccessControllerMethod,
+}
+
+impl From<ReservedInstruction> for SerializableReservedInstruction {
+    fn from(value: ReservedInstruction) -> Self {
+        match value {
+            ReservedInstruction::AccountLockFee => Self::AccountLockFee,
+            ReservedInstruction::AccountSecurify => Self::AccountSecurify,
+            ReservedInstruction::IdentitySecurify => Self::IdentitySecurify,
+            ReservedInstruction::AccountUpdateSettings => {
+                Self::AccountUpdateSettings
+            }
+            ReservedInstruction::AccessControllerMethod => {
+                Self::AccessControllerMethod
+            }
+        }
+    }
+}
+
+pub struct ManifestAnalyze;
+impl<'a> Function<'a> for ManifestAnalyze {
+    type Input = ManifestAnalyzeInput;
+    type Output = ManifestAnalyzeOutput;
+
+    fn handle(
+        ManifestAnalyzeInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let network_id = *network_id;
+        let manifest = manifest.to_native(network_id)?;
+
+        let summary =
+            radix_engine_toolkit::functions::manifest::summary(&manifest);
+        let resources_used =
+            extract_addresses_with_usage(&manifest.instructions)
+                .into_keys()
+                .filter(|address| {
+                    address.entity_type().is_global_resource_manager()
+                })
+                .map(|address| {
+                    SerializableNodeId::new(*address.as_node_id(), network_id)
+                })
+                .collect();
+
+        let node_ids = |addresses: IndexSet<ComponentAddress>| {
+            addresses
+                .into_iter()
+                .map(|address| {
+                    SerializableNodeId::from_global_address(address, network_id)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        Ok(Self::Output {
+            accounts_withdrawn_from: node_ids(summary.accounts_withdrawn_from),
+            accounts_deposited_into: node_ids(summary.accounts_deposited_into),
+            accounts_requiring_auth: node_ids(summary.accounts_requiring_auth),
+            identities_requiring_auth: node_ids(
+                summary.identities_requiring_auth,
+            ),
+            resources_used,
+            presented_proofs: SerializablePresentedProofs::from_native(
+                summary.presented_proofs,
+                network_id,
+            ),
+            encountered_entities: summary
+                .encountered_entities
+                .into_iter()
+                .map(|address| {
+                    SerializableNodeId::from_global_address(address, network_id)
+                })
+                .collect(),
+            reserved_instructions: summary
+                .reserved_instructions
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+export_function!(ManifestAnalyze as manifest_analyze);
+export_jni_function!(ManifestAnalyze as manifestAnalyze);
+export_wasm_bindgen_function!(ManifestAnalyze as manifestAnalyze);
+
+//===================
+// Manifest Classify
+//===================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestClassifyInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+/// The classes that the manifest conforms to with the most specific class
+/// first. An empty list means that the manifest is non-conforming.
+#[typeshare::typeshare]
+pub type ManifestClassifyOutput = Vec<SerializableManifestClassification>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableManifestClassification {
+    General,
+    SimpleTransfer {
+        from: SerializableNodeId,
+        to: SerializableNodeId,
+        resource_address: SerializableNodeId,
+        amount: SerializableSimpleTransferAmount,
+    },
+    GeneralTransfer,
+    PoolContribution {
+        pool_addresses: Vec<SerializableNodeId>,
+    },
+    PoolRedemption {
+        pool_addresses: Vec<SerializableNodeId>,
+    },
+    ValidatorStake {
+        validator_addresses: Vec<SerializableNodeId>,
+    },
+    ValidatorUnstake {
+        validator_addresses: Vec<SerializableNodeId>,
+    },
+    ValidatorClaim {
+        validator_addresses: Vec<SerializableNodeId>,
+    },
+    AccountDepositSettingsUpdate {
+        resource_preference_updates: Vec<SerializableResourcePreferenceUpdate>,
+        deposit_mode_updates: Vec<SerializableDepositModeUpdate>,
+        authorized_depositor_updates:
+            Vec<SerializableAuthorizedDepositorUpdate>,
+    },
+    AccountLockerClaim {
+        locker_addresses: Vec<SerializableNodeId>,
+    },
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableSimpleTransferAmount {
+    Amount(SerializableDecimal),
+    Ids(Vec<SerializableNonFungibleLocalId>),
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableResourcePreferenceUpdate {
+    pub account: SerializableNodeId,
+    pub resource_address: SerializableNodeId,
+    /// The new preference of the resource or [`None`] if the preference of
+    /// the resource is removed.
+    pub preference: Option<SerializableResourcePreference>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableDepositModeUpdate {
+    pub account: SerializableNodeId,
+    pub default_deposit_rule: SerializableDefaultDepositRule,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAuthorizedDepositorUpdate {
+    pub account: SerializableNodeId,
+    pub badge: SerializableResourceOrNonFungible,
+    pub operation: SerializableOperation,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableResourcePreference {
+    Allowed,
+    Disallowed,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableDefaultDepositRule {
+    Accept,
+    Reject,
+    AllowExisting,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableResourceOrNonFungible {
+    Resource(SerializableNodeId),
+    NonFungible(SerializableNonFungibleGlobalId),
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableOperation {
+    Added,
+    Removed,
+}
+
+impl From<ResourcePreference> for SerializableResourcePreference {
+    fn from(value: ResourcePreference) -> Self {
+        match value {
+            ResourcePreference::Allowed => Self::Allowed,
+            ResourcePreference::Disallowed => Self::Disallowed,
+        }
+    }
+}
+
+impl From<SerializableResourcePreference> for ResourcePreference {
+    fn from(value: SerializableResourcePreference) -> Self {
+        match value {
+            SerializableResourcePreference::Allowed => Self::Allowed,
+            SerializableResourcePreference::Disallowed => Self::Disallowed,
+        }
+    }
+}
+
+impl From<SerializableDefaultDepositRule> for DefaultDepositRule {
+    fn from(value: SerializableDefaultDepositRule) -> Self {
+        match value {
+            SerializableDefaultDepositRule::Accept => Self::Accept,
+            SerializableDefaultDepositRule::Reject => Self::Reject,
+            SerializableDefaultDepositRule::AllowExisting => {
+                Self::AllowExisting
+            }
+        }
+    }
+}
+
+impl From<DefaultDepositRule> for SerializableDefaultDepositRule {
+    fn from(value: DefaultDepositRule) -> Self {
+        match value {
+            DefaultDepositRule::Accept => Self::Accept,
+            DefaultDepositRule::Reject => Self::Reject,
+            DefaultDepositRule::AllowExisting => Self::AllowExisting,
+        }
+    }
+}
+
+impl From<Operation> for SerializableOperation {
+    fn from(value: Operation) -> Self {
+        match value {
+            Operation::Added => Self::Added,
+            Operation::Removed => Self::Removed,
+        }
+    }
+}
+
+impl SerializableResourceOrNonFungible {
+    pub fn new(value: ResourceOrNonFungible, network_id: u8) -> Self {
+        match value {
+            ResourceOrNonFungible::Resource(resource_address) => {
+                Self::Resource(SerializableNodeId::from_global_address(
+                    resource_address,
+                    network_id,
+                ))
+            }
+            ResourceOrNonFungible::NonFungible(non_fungible_global_id) => {
+                Self::NonFungible(SerializableNonFungibleGlobalId(
+                    SerializableNonFungibleGlobalIdInternal {
+                        network_id,
+                        non_fungible_global_id,
+                    },
+                ))
+            }
+        }
+    }
+
+    pub fn to_native(
+        &self,
+    ) -> Result<ResourceOrNonFungible, SerializableNodeIdError> {
+        match self {
+            Self::Resource(resource_address) => {
+                ResourceAddress::try_from(resource_address.clone())
+                    .map(ResourceOrNonFungible::Resource)
+            }
+            Self::NonFungible(non_fungible_global_id) => {
+                Ok(ResourceOrNonFungible::NonFungible(
+                    non_fungible_global_id.0.non_fungible_global_id.clone(),
+                ))
+            }
+        }
+    }
+}
+
+impl SerializableManifestClassification {
+    pub fn new(value: ManifestClassification, network_id: u8) -> Self {
+        let node_ids = |addresses: IndexSet<ComponentAddress>| {
+            addresses
+                .into_iter()
+                .map(|address| {
+                    SerializableNodeId::from_global_address(address, network_id)
+                })
+                .collect::<Vec<_>>()
+        };
+        let node_id = |address: ComponentAddress| {
+            SerializableNodeId::from_global_address(address, network_id)
+        };
+
+        match value {
+            ManifestClassification::General => Self::General,
+            ManifestClassification::SimpleTransfer(simple_transfer) => {
+                Self::SimpleTransfer {
+                    from: node_id(simple_transfer.from),
+                    to: node_id(simple_transfer.to),
+                    resource_address: SerializableNodeId::from_global_address(
+                        simple_transfer.resource,
+                        network_id,
+                    ),
+                    amount: match simple_transfer.amount {
+                        SimpleTransferAmount::Amount(amount) => {
+                            SerializableSimpleTransferAmount::Amount(
+                                amount.into(),
+                            )
+                        }
+                        SimpleTransferAmount::Ids(ids) => {
+                            SerializableSimpleTransferAmount::Ids(
+                                ids.into_iter().map(Into::into).collect(),
+                            )
+                        }
+                    },
+                }
+            }
+            ManifestClassification::GeneralTransfer => Self::GeneralTransfer,
+            ManifestClassification::PoolContribution { pool_addresses } => {
+                Self::PoolContribution {
+                    pool_addresses: node_ids(pool_addresses),
+                }
+            }
+            ManifestClassification::PoolRedemption { pool_addresses } => {
+                Self::PoolRedemption {
+                    pool_addresses: node_ids(pool_addresses),
+                }
+            }
+            ManifestClassification::ValidatorStake {
+                validator_addresses,
+            } => Self::ValidatorStake {
+                validator_addresses: node_ids(validator_addresses),
+            },
+            ManifestClassification::ValidatorUnstake {
+                validator_addresses,
+            } => Self::ValidatorUnstake {
+                validator_addresses: node_ids(validator_addresses),
+            },
+            ManifestClassification::ValidatorClaim {
+                validator_addresses,
+            } => Self::ValidatorClaim {
+                validator_addresses: node_ids(validator_addresses),
+            },
+            ManifestClassification::AccountDepositSettingsUpdate {
+                resource_preferences_updates,
+                deposit_mode_updates,
+                authorized_depositors_updates,
+            } => Self::AccountDepositSettingsUpdate {
+                resource_preference_updates: resource_preferences_updates
+                    .into_iter()
+                    .flat_map(|(account, updates)| {
+                        updates.into_iter().map(move |(resource, update)| {
+                            SerializableResourcePreferenceUpdate {
+                                account: node_id(account),
+                                resource_address:
+                                    SerializableNodeId::from_global_address(
+                                        resource, network_id,
+                                    ),
+                                preference: match update {
+                                    Update::Set(preference) => {
+                                        Some(preference.into())
+                                    }
+                                    Update::Remove => None,
+                                },
+                            }
+                        })
+                    })
+                    .collect(),
+                deposit_mode_updates: deposit_mode_updates
+                    .into_iter()
+                    .map(|(account, default_deposit_rule)| {
+                        SerializableDepositModeUpdate {
+                            account: node_id(account),
+                            default_deposit_rule: default_deposit_rule.into(),
+                        }
+                    })
+                    .collect(),
+                authorized_depositor_updates: authorized_depositors_updates
+                    .into_iter()
+                    .flat_map(|(account, updates)| {
+                        updates.into_iter().map(move |(badge, operation)| {
+                            SerializableAuthorizedDepositorUpdate {
+                                account: node_id(account),
+                                badge: SerializableResourceOrNonFungible::new(
+                                    badge, network_id,
+                                ),
+                                operation: operation.into(),
+                            }
+                        })
+                    })
+                    .collect(),
+            },
+            ManifestClassification::AccountLockerClaim { locker_addresses } => {
+                Self::AccountLockerClaim {
+                    locker_addresses: node_ids(locker_addresses),
+                }
+            }
+        }
+    }
+}
+
+pub struct ManifestClassify;
+impl<'a> Function<'a> for ManifestClassify {
+    type Input = ManifestClassifyInput;
+    type Output = ManifestClassifyOutput;
+
+    fn handle(
+        ManifestClassifyInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let network_id = *network_id;
+        let manifest = manifest.to_native(network_id)?;
+
+        Ok(
+            radix_engine_toolkit::functions::manifest::classify(&manifest)
+                .into_iter()
+                .map(|classification| {
+                    SerializableManifestClassification::new(
+                        classification,
+                        network_id,
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+export_function!(ManifestClassify as manifest_classify);
+export_jni_function!(ManifestClassify as manifestClassify);
+export_wasm_bindgen_function!(ManifestClassify as manifestClassify);
+
+//==========================
+// Manifest Convert Network
+//==========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestConvertNetworkInput {
+    pub manifest: SerializableTransactionManifest,
+    pub source_network_id: SerializableU8,
+    pub target_network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestConvertNetworkOutput {
+    pub manifest: SerializableTransactionManifest,
+    /// The addresses of entities that were allocated on the source network.
+    /// They are re-encoded for the target network but will either not exist
+    /// there or refer to a different entity. These are encoded with the HRP
+    /// of the source network.
+    pub network_bound_addresses: Vec<SerializableNodeId>,
+}
+
+pub struct ManifestConvertNetwork;
+impl<'a> Function<'a> for ManifestConvertNetwork {
+    type Input = ManifestConvertNetworkInput;
+    type Output = ManifestConvertNetworkOutput;
+
+    fn handle(
+        ManifestConvertNetworkInput {
+            manifest,
+            source_network_id,
+            target_network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let instructions_kind = match manifest.instructions {
+            SerializableInstructions::String(..) => {
+                SerializableInstructionsKind::String
+            }
+            SerializableInstructions::Parsed(..) => {
+                SerializableInstructionsKind::Parsed
+            }
+        };
+        let manifest = manifest.to_native(*source_network_id)?;
+
+        let network_bound_addresses =
+            radix_engine_toolkit::functions::manifest::network_bound_addresses(
+                &manifest,
+            )
+            .into_iter()
+            .map(|address| {
+                SerializableNodeId::new(
+                    *address.as_node_id(),
+                    *source_network_id,
+                )
+            })
+            .collect();
+        let manifest = SerializableTransactionManifest::from_native(
+            &manifest,
+            *target_network_id,
+            instructions_kind,
+        )?;
+
+        Ok(Self::Output {
+            manifest,
+            network_bound_addresses,
+        })
+    }
+}
+
+export_function!(ManifestConvertNetwork as manifest_convert_network);
+export_jni_function!(ManifestConvertNetwork as manifestConvertNetwork);
+export_wasm_bindgen_function!(ManifestConvertNetwork as manifestConvertNetwork);
+
+//===============
+// Manifest Lint
+//===============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestLintInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    /// The rules to lint the manifest against and the severity to report
+    /// them with. When not provided all of the rules are checked with their
+    /// default severity.
+    pub rules: Option<Vec<SerializableLintRuleConfig>>,
+}
+
+#[typeshare::typeshare]
+pub type ManifestLintOutput = Vec<SerializableLintDiagnostic>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableLintRuleConfig {
+    pub rule: SerializableLintRule,
+    pub severity: SerializableLintSeverity,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableLintDiagnostic {
+    pub rule: SerializableLintRule,
+    pub severity: SerializableLintSeverity,
+    pub instruction_index: Option<SerializableU64>,
+    pub message: String,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableLintRule {
+    MissingLockFee,
+    DepositWithoutWithdrawal,
+    DanglingBucket,
+    UndroppedProof,
+    ReservedBlueprintCall,
+    DuplicateAddressReservationUse,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableLintSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl From<LintRule> for SerializableLintRule {
+    fn from(value: LintRule) -> Self {
+        match value {
+            LintRule::MissingLockFee => Self::MissingLockFee,
+            LintRule::DepositWithoutWithdrawal => {
+                Self::DepositWithoutWithdrawal
+            }
+            LintRule::DanglingBucket => Self::DanglingBucket,
+            LintRule::UndroppedProof => Self::UndroppedProof,
+            LintRule::ReservedBlueprintCall => Self::ReservedBlueprintCall,
+            LintRule::DuplicateAddressReservationUse => {
+                Self::DuplicateAddressReservationUse
+            }
+        }
+    }
+}
+
+impl From<SerializableLintRule> for LintRule {
+    fn from(value: SerializableLintRule) -> Self {
+        match value {
+            SerializableLintRule::MissingLockFee => Self::MissingLockFee,
+            SerializableLintRule::DepositWithoutWithdrawal => {
+                Self::DepositWithoutWithdrawal
+            }
+            SerializableLintRule::DanglingBucket => Self::DanglingBucket,
+            SerializableLintRule::UndroppedProof => Self::UndroppedProof,
+            SerializableLintRule::ReservedBlueprintCall => {
+                Self::ReservedBlueprintCall
+            }
+            SerializableLintRule::DuplicateAddressReservationUse => {
+                Self::DuplicateAddressReservationUse
+            }
+        }
+    }
+}
+
+impl From<LintSeverity> for SerializableLintSeverity {
+    fn from(value: LintSeverity) -> Self {
+        match value {
+            LintSeverity::Info => Self::Info,
+            LintSeverity::Warning => Self::Warning,
+            LintSeverity::Error => Self::Error,
+        }
+    }
+}
+
+impl From<SerializableLintSeverity> for LintSeverity {
+    fn from(value: SerializableLintSeverity) -> Self {
+        match value {
+            SerializableLintSeverity::Info => Self::Info,
+            SerializableLintSeverity::Warning => Self::Warning,
+            SerializableLintSeverity::Error => Self::Error,
+        }
+    }
+}
+
+pub struct ManifestLint;
+impl<'a> Function<'a> for ManifestLint {
+    type Input = ManifestLintInput;
+    type Output = ManifestLintOutput;
+
+    fn handle(
+        ManifestLintInput {
+            manifest,
+            network_id,
+            rules,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let config = rules
+            .map(|rules| LintConfig {
+                rules: rules
+                    .into_iter()
+                    .map(|config| (config.rule.into(), config.severity.into()))
+                    .collect(),
+            })
+            .unwrap_or_default();
+
+        Ok(radix_engine_toolkit::functions::lint::lint(
+            &manifest.instructions,
+            &config,
+        )
+        .into_iter()
+        .map(|diagnostic| SerializableLintDiagnostic {
+            rule: diagnostic.rule.into(),
+            severity: diagnostic.severity.into(),
+            instruction_index: diagnostic
+                .instruction_index
+                .map(|index| (index as u64).into()),
+            message: diagnostic.message,
+        })
+        .collect())
+    }
+}
+
+export_function!(ManifestLint as manifest_lint);
+export_jni_function!(ManifestLint as manifestLint);
+export_wasm_bindgen_function!(ManifestLint as manifestLint);
+
+//===============
+// Manifest Diff
+//===============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestDiffInput {
+    pub old_manifest: SerializableTransactionManifest,
+    pub new_manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestDiffOutput {
+    pub instructions: Vec<SerializableInstructionDiff>,
+    pub added_blobs: Vec<SerializableHash>,
+    pub removed_blobs: Vec<SerializableHash>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableInstructionDiff {
+    Unchanged {
+        old_index: SerializableU64,
+        new_index: SerializableU64,
+    },
+    Added {
+        new_index: SerializableU64,
+        instruction: SerializableInstruction,
+    },
+    Removed {
+        old_index: SerializableU64,
+        instruction: SerializableInstruction,
+    },
+    Modified {
+        old_index: SerializableU64,
+        new_index: SerializableU64,
+        old_instruction: SerializableInstruction,
+        new_instruction: SerializableInstruction,
+        value_diffs: Vec<SerializableValueDiff>,
+    },
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableValueDiff {
+    pub path: Vec<SerializableU64>,
+    pub old_value: Option<SerializableManifestValue>,
+    pub new_value: Option<SerializableManifestValue>,
+}
+
+pub struct ManifestDiff;
+impl<'a> Function<'a> for ManifestDiff {
+    type Input = ManifestDiffInput;
+    type Output = ManifestDiffOutput;
+
+    fn handle(
+        ManifestDiffInput {
+            old_manifest,
+            new_manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let network_id = *network_id;
+        let old_manifest = old_manifest.to_native(network_id)?;
+        let new_manifest = new_manifest.to_native(network_id)?;
+
+        let diff = radix_engine_toolkit::functions::diff::diff_manifests(
+            &old_manifest,
+            &new_manifest,
+        )?;
+
+        let index = |index: usize| SerializableU64::from(index as u64);
+        let instruction =
+            |instruction_index: usize, instruction: &InstructionV1| {
+                SerializableInstruction::from_instruction(
+                    instruction,
+                    network_id,
+                )
+                .map_err(|error| LocatedInstructionConversionError {
+                    instruction_index,
+                    error,
+                })
+                .map_err(SerializableInstructionsError::from)
+            };
+        let value = |instruction_index: usize, value: Option<ManifestValue>| {
+            value
+                .map(|value| {
+                    SerializableManifestValue::from_manifest_value(
+                        &value, network_id,
+                    )
+                    .map_err(|error| LocatedInstructionConversionError {
+                        instruction_index,
+                        error: error.into(),
+                    })
+                    .map_err(SerializableInstructionsError::from)
+                })
+                .transpose()
+        };
+
+        let instructions =
+            diff.instructions
+                .into_iter()
+                .map(|instruction_diff| {
+                    Ok(match instruction_diff {
+                        InstructionDiff::Unchanged {
+                            old_index,
+                            new_index,
+                        } => SerializableInstructionDiff::Unchanged {
+                            old_index: index(old_index),
+                            new_index: index(new_index),
+                        },
+                        InstructionDiff::Added {
+                            new_index,
+                            instruction: added,
+                        } => SerializableInstructionDiff::Added {
+                            new_index: index(new_index),
+                            instruction: instruction(new_index, &added)?,
+                        },
+                        InstructionDiff::Removed {
+                            old_index,
+                            instruction: removed,
+                        } => SerializableInstructionDiff::Removed {
+                            old_index: index(old_index),
+                            instruction: instruction(old_index, &removed)?,
+                        },
+                        InstructionDiff::Modified {
+                            old_index,
+                            new_index,
+                            old_instruction,
+                            new_instruction,
+                            value_diffs,
+                        } => {
+                            SerializableInstructionDiff::Modified {
+                                old_index: index(old_index),
+                                new_index: index(new_index),
+                                old_instruction: instruction(
+                                    old_index,
+                                    &old_instruction,
+                                )?,
+                                new_instruction: instruction(
+                                    new_index,
+                                    &new_instruction,
+                                )?,
+                                value_diffs:
+                                    value_diffs
+                                        .into_iter()
+                                        .map(|value_diff| {
+                                            Ok(SerializableValueDiff {
+                                                path: value_diff
+                                                    .path
+                                                    .into_iter()
+                                                    .map(index)
+                                                    .collect(),
+                                                old_value: value(
+                                                    old_index,
+                                                    value_diff.old_value,
+                                                )?,
+                                                new_value: value(
+                                                    new_index,
+                                                    value_diff.new_value,
+                                                )?,
+                                            })
+                                        })
+                                        .collect::<Result<
+                                            _,
+                                            SerializableInstructionsError,
+                                        >>(
+                                        )?,
+                            }
+                        }
+                    })
+                })
+                .collect::<Result<_, SerializableInstructionsError>>()?;
+
+        Ok(Self::Output {
+            instructions,
+            added_blobs: diff.added_blobs.into_iter().map(Into::into).collect(),
+            removed_blobs: diff
+                .removed_blobs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+export_function!(ManifestDiff as manifest_diff);
+export_jni_function!(ManifestDiff as manifestDiff);
+export_wasm_bindgen_function!(ManifestDiff as manifestDiff);
+
+//===============================
+// Manifest Instantiate Template
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestInstantiateTemplateInput {
+    pub template: SerializableManifestTemplate,
+    /// The values to substitute for the placeholders of the template written
+    /// in the manifest syntax, e.g., `Decimal("10")`.
+    #[typeshare(serialized_as = "HashMap<String, String>")]
+    pub arguments: IndexMap<String, String>,
+    pub network_id: SerializableU8,
+    pub instructions_output_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+pub type ManifestInstantiateTemplateOutput = SerializableInstructions;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableManifestTemplate {
+    /// The manifest string with placeholders of the form `${name}`.
+    pub manifest: String,
+    #[typeshare(
+        serialized_as = "HashMap<String, SerializableManifestAstValueKind>"
+    )]
+    pub parameters: IndexMap<String, SerializableManifestAstValueKind>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableManifestAstValueKind {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    String,
+    Enum,
+    Array,
+    Tuple,
+    Map,
+    Address,
+    Bucket,
+    Proof,
+    Expression,
+    Blob,
+    Decimal,
+    PreciseDecimal,
+    NonFungibleLocalId,
+    AddressReservation,
+    NamedAddress,
+}
+
+impl From<SerializableManifestAstValueKind> for ManifestAstValueKind {
+    fn from(value: SerializableManifestAstValueKind) -> Self {
+        match value {
+            SerializableManifestAstValueKind::Bool => Self::Bool,
+            SerializableManifestAstValueKind::I8 => Self::I8,
+            SerializableManifestAstValueKind::I16 => Self::I16,
+            SerializableManifestAstValueKind::I32 => Self::I32,
+            SerializableManifestAstValueKind::I64 => Self::I64,
+            SerializableManifestAstValueKind::I128 => Self::I128,
+            SerializableManifestAstValueKind::U8 => Self::U8,
+            SerializableManifestAstValueKind::U16 => Self::U16,
+            SerializableManifestAstValueKind::U32 => Self::U32,
+            SerializableManifestAstValueKind::U64 => Self::U64,
+            SerializableManifestAstValueKind::U128 => Self::U128,
+            SerializableManifestAstValueKind::String => Self::String,
+            SerializableManifestAstValueKind::Enum => Self::Enum,
+            SerializableManifestAstValueKind::Array => Self::Array,
+            SerializableManifestAstValueKind::Tuple => Self::Tuple,
+            SerializableManifestAstValueKind::Map => Self::Map,
+            SerializableManifestAstValueKind::Address => Self::Address,
+            SerializableManifestAstValueKind::Bucket => Self::Bucket,
+            SerializableManifestAstValueKind::Proof => Self::Proof,
+            SerializableManifestAstValueKind::Expression => Self::Expression,
+            SerializableManifestAstValueKind::Blob => Self::Blob,
+            SerializableManifestAstValueKind::Decimal => Self::Decimal,
+            SerializableManifestAstValueKind::PreciseDecimal => {
+                Self::PreciseDecimal
+            }
+            SerializableManifestAstValueKind::NonFungibleLocalId => {
+                Self::NonFungibleLocalId
+            }
+            SerializableManifestAstValueKind::AddressReservation => {
+                Self::AddressReservation
+            }
+            SerializableManifestAstValueKind::NamedAddress => {
+                Self::NamedAddress
+            }
+        }
+    }
+}
+
+impl From<ManifestAstValueKind> for SerializableManifestAstValueKind {
+    fn from(value: ManifestAstValueKind) -> Self {
+        match value {
+            ManifestAstValueKind::Bool => Self::Bool,
+            ManifestAstValueKind::I8 => Self::I8,
+            ManifestAstValueKind::I16 => Self::I16,
+            ManifestAstValueKind::I32 => Self::I32,
+            ManifestAstValueKind::I64 => Self::I64,
+            ManifestAstValueKind::I128 => Self::I128,
+            ManifestAstValueKind::U8 => Self::U8,
+            ManifestAstValueKind::U16 => Self::U16,
+            ManifestAstValueKind::U32 => Self::U32,
+            ManifestAstValueKind::U64 => Self::U64,
+            ManifestAstValueKind::U128 => Self::U128,
+            ManifestAstValueKind::String => Self::String,
+            ManifestAstValueKind::Enum => Self::Enum,
+            ManifestAstValueKind::Array => Self::Array,
+            ManifestAstValueKind::Tuple => Self::Tuple,
+            ManifestAstValueKind::Map => Self::Map,
+            ManifestAstValueKind::Address => Self::Address,
+            ManifestAstValueKind::Bucket => Self::Bucket,
+            ManifestAstValueKind::Proof => Self::Proof,
+            ManifestAstValueKind::Expression => Self::Expression,
+            ManifestAstValueKind::Blob => Self::Blob,
+            ManifestAstValueKind::Decimal => Self::Decimal,
+            ManifestAstValueKind::PreciseDecimal => Self::PreciseDecimal,
+            ManifestAstValueKind::NonFungibleLocalId => {
+                Self::NonFungibleLocalId
+            }
+            ManifestAstValueKind::AddressReservation => {
+                Self::AddressReservation
+            }
+            ManifestAstValueKind::NamedAddress => Self::NamedAddress,
+        }
+    }
+}
+
+pub struct ManifestInstantiateTemplate;
+impl<'a> Function<'a> for ManifestInstantiateTemplate {
+    type Input = ManifestInstantiateTemplateInput;
+    type Output = ManifestInstantiateTemplateOutput;
+
+    fn handle(
+        ManifestInstantiateTemplateInput {
+            template,
+            arguments,
+            network_id,
+            instructions_output_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let template = ManifestTemplate {
+            manifest: template.manifest,
+            parameters: template
+                .parameters
+                .into_iter()
+                .map(|(name, kind)| (name, kind.into()))
+                .collect(),
+        };
+        let manifest =
+            radix_engine_toolkit::functions::manifest_template::instantiate(
+                &template, &arguments,
+            )?;
+
+        // The instantiated manifest is compiled to ensure that the arguments
+        // produced a valid manifest.
+        let instructions = SerializableInstructions::String(manifest)
+            .to_instructions(*network_id)?;
+        let instructions = SerializableInstructions::new(
+            &instructions,
+            instructions_output_kind,
+            *network_id,
+        )?;
+
+        Ok(instructions)
+    }
+}
+
+export_function!(ManifestInstantiateTemplate as manifest_instantiate_template);
+export_jni_function!(
+    ManifestInstantiateTemplate as manifestInstantiateTemplate
+);
+export_wasm_bindgen_function!(
+    ManifestInstantiateTemplate as manifestInstantiateTemplate
+);
+
+//===================================
+// Manifest Resource Movements Graph
+//===================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestResourceMovementsGraphInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    /// The SBOR encoded receipt of the preview of the manifest.
+    pub encoded_receipt: SerializableBytes,
+}
+
+/// The movements of resources in the manifest as a graph. The edges refer to
+/// the nodes by their index in the list of nodes.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestResourceMovementsGraphOutput {
+    pub nodes: Vec<SerializableResourceMovementsNode>,
+    pub edges: Vec<SerializableResourceMovementsEdge>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableResourceMovementsNode {
+    Worktop,
+    Account(SerializableNodeId),
+    Entity(SerializableNodeId),
+    NamedEntity(SerializableU32),
+    Vault(SerializableNodeId),
+    Mint,
+    Burn,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableResourceMovementsEdge {
+    pub from: SerializableU64,
+    pub to: SerializableU64,
+    pub resource: SerializableResourceSpecifier,
+    pub instruction_index: SerializableU64,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableResourceSpecifier {
+    Amount {
+        resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    Ids {
+        resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+}
+
+impl SerializableResourceMovementsNode {
+    pub fn new(node: ResourceMovementsNode, network_id: u8) -> Self {
+        match node {
+            ResourceMovementsNode::Worktop => Self::Worktop,
+            ResourceMovementsNode::Account(address) => Self::Account(
+                SerializableNodeId::from_global_address(address, network_id),
+            ),
+            ResourceMovementsNode::Entity(address) => Self::Entity(
+                SerializableNodeId::from_global_address(address, network_id),
+            ),
+            ResourceMovementsNode::NamedEntity(named_address) => {
+                Self::NamedEntity(named_address.0.into())
+            }
+            ResourceMovementsNode::Vault(address) => Self::Vault(
+                SerializableNodeId::new(*address.as_node_id(), network_id),
+            ),
+            ResourceMovementsNode::Mint => Self::Mint,
+            ResourceMovementsNode::Burn => Self::Burn,
+        }
+    }
+}
+
+impl SerializableResourceSpecifier {
+    pub fn new(resource_specifier: ResourceSpecifier, network_id: u8) -> Self {
+        match resource_specifier {
+            ResourceSpecifier::Amount(resource_address, amount) => {
+                Self::Amount {
+                    resource_address: SerializableNodeId::from_global_address(
+                        resource_address,
+                        network_id,
+                    ),
+                    amount: amount.into(),
+                }
+            }
+            ResourceSpecifier::Ids(resource_address, ids) => Self::Ids {
+                resource_address: SerializableNodeId::from_global_address(
+                    resource_address,
+                    network_id,
+                ),
+                ids: ids.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+impl ManifestResourceMovementsGraphOutput {
+    pub fn new(graph: ResourceMovementsGraph, network_id: u8) -> Self {
+        Self {
+            nodes: graph
+                .nodes
+                .into_iter()
+                .map(|node| {
+                    SerializableResourceMovementsNode::new(node, network_id)
+                })
+                .collect(),
+            edges: graph
+                .edges
+                .into_iter()
+                .map(|edge| SerializableResourceMovementsEdge {
+                    from: (edge.from as u64).into(),
+                    to: (edge.to as u64).into(),
+                    resource: SerializableResourceSpecifier::new(
+                        edge.resource,
+                        network_id,
+                    ),
+                    instruction_index: (edge.instruction_index as u64).into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct ManifestResourceMovementsGraph;
+impl<'a> Function<'a> for ManifestResourceMovementsGraph {
+    type Input = ManifestResourceMovementsGraphInput;
+    type Output = ManifestResourceMovementsGraphOutput;
+
+    fn handle(
+        ManifestResourceMovementsGraphInput {
+            manifest,
+            network_id,
+            encoded_receipt,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let network_id = *network_id;
+        let manifest = manifest.to_native(network_id)?;
+
+        let graph =
+            radix_engine_toolkit::functions::manifest::resource_movements_graph(
+                &manifest,
+                ExecutionReceipt::EncodedPreview(&encoded_receipt),
+            )?;
+
+        Ok(ManifestResourceMovementsGraphOutput::new(graph, network_id))
+    }
+}
+
+export_function!(
+    ManifestResourceMovementsGraph as manifest_resource_movements_graph
+);
+export_jni_function!(
+    ManifestResourceMovementsGraph as manifestResourceMovementsGraph
+);
+export_wasm_bindgen_function!(
+    ManifestResourceMovementsGraph as manifestResourceMovementsGraph
+);
+
+//=======================================
+// Manifest Detect Reserved Instructions
+//=======================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestDetectReservedInstructionsInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type ManifestDetectReservedInstructionsOutput =
+    Vec<SerializableSensitiveInstruction>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableSensitiveInstruction {
+    pub instruction_index: SerializableU64,
+    pub kind: SerializableSensitiveInstructionKind,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableSensitiveInstructionKind {
+    AccountLockerOperation,
+    AccessControllerRecovery,
+    IdentityOwnerBadgeUpdate,
+    ValidatorOwnerOperation,
+}
+
+impl From<SensitiveInstructionKind> for SerializableSensitiveInstructionKind {
+    fn from(value: SensitiveInstructionKind) -> Self {
+        match value {
+            SensitiveInstructionKind::AccountLockerOperation => {
+                Self::AccountLockerOperation
+            }
+            SensitiveInstructionKind::AccessControllerRecovery => {
+                Self::AccessControllerRecovery
+            }
+            SensitiveInstructionKind::IdentityOwnerBadgeUpdate => {
+                Self::IdentityOwnerBadgeUpdate
+            }
+            SensitiveInstructionKind::ValidatorOwnerOperation => {
+                Self::ValidatorOwnerOperation
+            }
+        }
+    }
+}
+
+impl From<SensitiveInstruction> for SerializableSensitiveInstruction {
+    fn from(value: SensitiveInstruction) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            kind: value.kind.into(),
+        }
+    }
+}
+
+pub struct ManifestDetectReservedInstructions;
+impl<'a> Function<'a> for ManifestDetectReservedInstructions {
+    type Input = ManifestDetectReservedInstructionsInput;
+    type Output = ManifestDetectReservedInstructionsOutput;
+
+    fn handle(
+        ManifestDetectReservedInstructionsInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        Ok(detect_reserved_instructions(&manifest)
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+}
+
+export_function!(
+    ManifestDetectReservedInstructions as manifest_detect_reserved_instructions
+);
+export_jni_function!(
+    ManifestDetectReservedInstructions as manifestDetectReservedInstructions
+);
+export_wasm_bindgen_function!(
+    ManifestDetectReservedInstructions as manifestDetectReservedInstructions
+);
+
+//=========================================
+// Manifest Validate Instruction Arguments
+//=========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestValidateInstructionArgumentsInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type ManifestValidateInstructionArgumentsOutput =
+    Vec<SerializableInstructionArgumentsIssue>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableInstructionArgumentsIssue {
+    pub instruction_index: SerializableU64,
+    pub kind: SerializableInstructionArgumentsIssueKind,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableInstructionArgumentsIssueKind {
+    FunctionNotFound {
+        blueprint_name: String,
+        function_name: String,
+    },
+    ArgumentsNotATuple,
+    ArgumentCountMismatch {
+        expected: SerializableU64,
+        actual: SerializableU64,
+    },
+    ValueKindMismatch {
+        path: String,
+        expected: SerializableManifestValueKind,
+        actual: SerializableManifestValueKind,
+    },
+    FieldCountMismatch {
+        path: String,
+        expected: SerializableU64,
+        actual: SerializableU64,
+    },
+    UnknownVariant {
+        path: String,
+        discriminator: SerializableU8,
+    },
+}
+
+impl From<InstructionArgumentsIssueKind>
+    for SerializableInstructionArgumentsIssueKind
+{
+    fn from(value: InstructionArgumentsIssueKind) -> Self {
+        match value {
+            InstructionArgumentsIssueKind::FunctionNotFound {
+                blueprint_name,
+                function_name,
+            } => Self::FunctionNotFound {
+                blueprint_name,
+                function_name,
+            },
+            InstructionArgumentsIssueKind::ArgumentsNotATuple => {
+                Self::ArgumentsNotATuple
+            }
+            InstructionArgumentsIssueKind::ArgumentCountMismatch {
+                expected,
+                actual,
+            } => Self::ArgumentCountMismatch {
+                expected: (expected as u64).into(),
+                actual: (actual as u64).into(),
+            },
+            InstructionArgumentsIssueKind::ValueKindMismatch {
+                path,
+                expected,
+                actual,
+            } => Self::ValueKindMismatch {
+                path,
+                expected: expected.into(),
+                actual: actual.into(),
+            },
+            InstructionArgumentsIssueKind::FieldCountMismatch {
+                path,
+                expected,
+                actual,
+            } => Self::FieldCountMismatch {
+                path,
+                expected: (expected as u64).into(),
+                actual: (actual as u64).into(),
+            },
+            InstructionArgumentsIssueKind::UnknownVariant {
+                path,
+                discriminator,
+            } => Self::UnknownVariant {
+                path,
+                discriminator: discriminator.into(),
+            },
+        }
+    }
+}
+
+impl From<InstructionArgumentsIssue> for SerializableInstructionArgumentsIssue {
+    fn from(value: InstructionArgumentsIssue) -> Self {
+        Self {
+            instruction_index: (value.instruction_index as u64).into(),
+            kind: value.kind.into(),
+        }
+    }
+}
+
+#[cfg(feature = "native-blueprint-interfaces")]
+pub struct ManifestValidateInstructionArguments;
+#[cfg(feature = "native-blueprint-interfaces")]
+impl<'a> Function<'a> for ManifestValidateInstructionArguments {
+    type Input = ManifestValidateInstructionArgumentsInput;
+    type Output = ManifestValidateInstructionArgumentsOutput;
+
+    fn handle(
+        ManifestValidateInstructionArgumentsInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        Ok(validate_instruction_arguments(
+            &manifest.instructions,
+            native_blueprint_interfaces(),
+        )
+        .into_iter()
+        .map(Into::into)
+        .collect())
+    }
+}
+
+#[cfg(feature = "native-blueprint-interfaces")]
+export_function!(
+    ManifestValidateInstructionArguments
+        as manifest_validate_instruction_arguments
+);
+#[cfg(feature = "native-blueprint-interfaces")]
+export_jni_function!(
+    ManifestValidateInstructionArguments
+        as manifestValidateInstructionArguments
+);
+#[cfg(feature = "native-blueprint-interfaces")]
+export_wasm_bindgen_function!(
+    ManifestValidateInstructionArguments
+        as manifestValidateInstructionArguments
+);
+
+//===============================
+// Manifest Account Interactions
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestAccountInteractionsInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+/// The accounts that the manifest interacts with. Unlike [`ManifestAnalyze`]
+/// this does not classify the manifest and is therefore cheaper to compute.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestAccountInteractionsOutput {
+    pub accounts_withdrawn_from: Vec<SerializableNodeId>,
+    pub accounts_deposited_into: Vec<SerializableNodeId>,
+    pub accounts_requiring_auth: Vec<SerializableNodeId>,
+    pub presented_proofs: Vec<SerializablePresentedProofs>,
+}
+
+pub struct ManifestAccountInteractions;
+impl<'a> Function<'a> for ManifestAccountInteractions {
+    type Input = ManifestAccountInteractionsInput;
+    type Output = ManifestAccountInteractionsOutput;
+
+    fn handle(
+        ManifestAccountInteractionsInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let network_id = *network_id;
+        let manifest = manifest.to_native(network_id)?;
+
+        let interactions = account_interactions(&manifest);
+        let node_ids = |addresses: IndexSet<ComponentAddress>| {
+            addresses
+                .into_iter()
+                .map(|address| {
+                    SerializableNodeId::from_global_address(address, network_id)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        Ok(Self::Output {
+            accounts_withdrawn_from: node_ids(
+                interactions.accounts_withdrawn_from,
+            ),
+            accounts_deposited_into: node_ids(
+                interactions.accounts_deposited_into,
+            ),
+            accounts_requiring_auth: node_ids(
+                interactions.accounts_requiring_auth,
+            ),
+            presented_proofs: SerializablePresentedProofs::from_native(
+                interactions.presented_proofs,
+                network_id,
+            ),
+        })
+    }
+}
+
+export_function!(ManifestAccountInteractions as manifest_account_interactions);
+export_jni_function!(
+    ManifestAccountInteractions as manifestAccountInteractions
+);
+export_wasm_bindgen_function!(
+    ManifestAccountInteractions as manifestAccountInteractions
+);
+
+//===========================
+// Manifest Required Signers
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestRequiredSignersInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    /// The public keys of the signers available to sign the transaction.
+    pub public_keys: Vec<SerializablePublicKey>,
+}
+
+/// The subset of the given public keys whose virtual accounts or identities
+/// require auth in the manifest.
+#[typeshare::typeshare]
+pub type ManifestRequiredSignersOutput = Vec<SerializablePublicKey>;
+
+pub struct ManifestRequiredSigners;
+impl<'a> Function<'a> for ManifestRequiredSigners {
+    type Input = ManifestRequiredSignersInput;
+    type Output = ManifestRequiredSignersOutput;
+
+    fn handle(
+        ManifestRequiredSignersInput {
+            manifest,
+            network_id,
+            public_keys,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let public_keys =
+            public_keys.into_iter().map(Into::into).collect::<Vec<_>>();
+        Ok(required_signers(&manifest, &public_keys)
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+}
+
+export_function!(ManifestRequiredSigners as manifest_required_signers);
+export_jni_function!(ManifestRequiredSigners as manifestRequiredSigners);
+export_wasm_bindgen_function!(
+    ManifestRequiredSigners as manifestRequiredSigners
+);
+
+//=====================
+// Manifest Statistics
+//=====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestStatisticsInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestStatisticsOutput {
+    /// The number of instructions of each kind keyed by the name of the kind.
+    #[typeshare(serialized_as = "HashMap<String, SerializableU64>")]
+    pub instruction_counts: ::indexmap::IndexMap<String, SerializableU64>,
+    pub component_count: SerializableU64,
+    pub package_count: SerializableU64,
+    pub resource_count: SerializableU64,
+    pub max_value_depth: SerializableU64,
+    pub blob_count: SerializableU64,
+    pub blobs_size: SerializableU64,
+    /// The size of the compiled manifest, absent if the manifest can't be
+    /// compiled.
+    pub compiled_size: Option<SerializableU64>,
+}
+
+pub struct ManifestStatistics;
+impl<'a> Function<'a> for ManifestStatistics {
+    type Input = ManifestStatisticsInput;
+    type Output = ManifestStatisticsOutput;
+
+    fn handle(
+        ManifestStatisticsInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let statistics = statistics(&manifest);
+        let to_u64 = |value: usize| SerializableU64::from(value as u64);
+
+        Ok(Self::Output {
+            instruction_counts: statistics
+                .instruction_counts
+                .into_iter()
+                .map(|(kind, count)| (kind.to_owned(), to_u64(count)))
+                .collect(),
+            component_count: to_u64(statistics.component_count),
+            package_count: to_u64(statistics.package_count),
+            resource_count: to_u64(statistics.resource_count),
+            max_value_depth: to_u64(statistics.max_value_depth),
+            blob_count: to_u64(statistics.blob_count),
+            blobs_size: to_u64(statistics.blobs_size),
+            compiled_size: statistics.compiled_size.map(to_u64),
+        })
+    }
+}
+
+export_function!(ManifestStatistics as manifest_statistics);
+export_jni_function!(ManifestStatistics as manifestStatistics);
+export_wasm_bindgen_function!(ManifestStatistics as manifestStatistics);
+
+//========================
+// Manifest Check Reorder
+//========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestCheckReorderInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    /// The original index of the instruction at each position of the
+    /// proposed order.
+    pub permutation: Vec<SerializableU64>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestCheckReorderOutput {
+    pub is_safe: bool,
+    /// The dependencies between the instructions that the proposed order
+    /// violates.
+    pub violated_dependencies: Vec<SerializableInstructionDependency>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableInstructionDependency {
+    pub kind: SerializableDependencyKind,
+    /// The original index of the instruction that must come first.
+    pub dependency: SerializableU64,
+    /// The original index of the instruction that must come after.
+    pub dependent: SerializableU64,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableDependencyKind {
+    BucketDefinition(SerializableU32),
+    BucketConsumption(SerializableU32),
+    ProofDefinition(SerializableU32),
+    ProofConsumption(SerializableU32),
+    AddressReservationDefinition(SerializableU32),
+    NamedAddressDefinition(SerializableU32),
+    Assertion,
+}
+
+impl From<InstructionDependency> for SerializableInstructionDependency {
+    fn from(
+        InstructionDependency {
+            kind,
+            dependency,
+            dependent,
+        }: InstructionDependency,
+    ) -> Self {
+        Self {
+            kind: match kind {
+                DependencyKind::BucketDefinition(bucket) => {
+                    SerializableDependencyKind::BucketDefinition(
+                        bucket.0.into(),
+                    )
+                }
+                DependencyKind::BucketConsumption(bucket) => {
+                    SerializableDependencyKind::BucketConsumption(
+                        bucket.0.into(),
+                    )
+                }
+                DependencyKind::ProofDefinition(proof) => {
+                    SerializableDependencyKind::ProofDefinition(proof.0.into())
+                }
+                DependencyKind::ProofConsumption(proof) => {
+                    SerializableDependencyKind::ProofConsumption(proof.0.into())
+                }
+                DependencyKind::AddressReservationDefinition(
+                    address_reservation,
+                ) => SerializableDependencyKind::AddressReservationDefinition(
+                    address_reservation.0.into(),
+                ),
+                DependencyKind::NamedAddressDefinition(named_address) => {
+                    SerializableDependencyKind::NamedAddressDefinition(
+                        named_address.into(),
+                    )
+                }
+                DependencyKind::Assertion => {
+                    SerializableDependencyKind::Assertion
+                }
+            },
+            dependency: (dependency as u64).into(),
+            dependent: (dependent as u64).into(),
+        }
+    }
+}
+
+pub struct ManifestCheckReorder;
+impl<'a> Function<'a> for ManifestCheckReorder {
+    type Input = ManifestCheckReorderInput;
+    type Output = ManifestCheckReorderOutput;
+
+    fn handle(
+        ManifestCheckReorderInput {
+            manifest,
+            network_id,
+            permutation,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let permutation = permutation
+            .into_iter()
+            .map(|index| *index as usize)
+            .collect::<Vec<_>>();
+        let violated_dependencies =
+            check_reorder(&manifest.instructions, &permutation)?;
+
+        Ok(Self::Output {
+            is_safe: violated_dependencies.is_empty(),
+            violated_dependencies: violated_dependencies
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+export_function!(ManifestCheckReorder as manifest_check_reorder);
+export_jni_function!(ManifestCheckReorder as manifestCheckReorder);
+export_wasm_bindgen_function!(ManifestCheckReorder as manifestCheckReorder);
+
+//========================
+// Manifest Set Fee Payer
+//========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestSetFeePayerInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    /// The account that the fee is locked against.
+    pub account: SerializableNodeId,
+    pub amount: SerializableDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestSetFeePayerOutput {
+    /// The updated manifest with its instructions as a manifest string.
+    pub string: SerializableTransactionManifest,
+    /// The updated manifest with its instructions parsed.
+    pub parsed: SerializableTransactionManifest,
+}
+
+pub struct ManifestSetFeePayer;
+impl<'a> Function<'a> for ManifestSetFeePayer {
+    type Input = ManifestSetFeePayerInput;
+    type Output = ManifestSetFeePayerOutput;
+
+    fn handle(
+        ManifestSetFeePayerInput {
+            manifest,
+            network_id,
+            account,
+            amount,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let account = ComponentAddress::try_from(account.clone())
+            .ok()
+            .filter(radix_engine_toolkit::utils::is_account)
+            .ok_or(InvocationHandlingError::InvalidAddress(
+                account.0.to_string(),
+            ))?;
+
+        let manifest =
+            set_fee_payer(&manifest, account, *amount).map_err(|error| {
+                InvocationHandlingError::ManifestModificationError(
+                    debug_string(error),
+                )
+            })?;
+
+        Ok(Self::Output {
+            string: SerializableTransactionManifest::from_native(
+                &manifest,
+                *network_id,
+                SerializableInstructionsKind::String,
+            )?,
+            parsed: SerializableTransactionManifest::from_native(
+                &manifest,
+                *network_id,
+                SerializableInstructionsKind::Parsed,
+            )?,
+        })
+    }
+}
+
+export_function!(ManifestSetFeePayer as manifest_set_fee_payer);
+export_jni_function!(ManifestSetFeePayer as manifestSetFeePayer);
+export_wasm_bindgen_function!(ManifestSetFeePayer as manifestSetFeePayer);
+
+//===================
+// Manifest Add Blob
+//===================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestAddBlobInput {
+    pub manifest: SerializableTransactionManifest,
+    pub blob: SerializableBytes,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestAddBlobOutput {
+    pub manifest: SerializableTransactionManifest,
+    /// The hash of the added blob which instructions reference it by.
+    pub hash: SerializableHash,
+}
+
+pub struct ManifestAddBlob;
+impl<'a> Function<'a> for ManifestAddBlob {
+    type Input = ManifestAddBlobInput;
+    type Output = ManifestAddBlobOutput;
+
+    fn handle(
+        ManifestAddBlobInput { mut manifest, blob }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let hash = SerializableHash::from(scrypto::prelude::hash(&*blob));
+        manifest.blobs.entry(hash).or_insert(blob);
+
+        Ok(Self::Output { manifest, hash })
+    }
+}
+
+export_function!(ManifestAddBlob as manifest_add_blob);
+export_jni_function!(ManifestAddBlob as manifestAddBlob);
+export_wasm_bindgen_function!(ManifestAddBlob as manifestAddBlob);
+
+//===================
+// Manifest Get Blob
+//===================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestGetBlobInput {
+    pub manifest: SerializableTransactionManifest,
+    pub hash: SerializableHash,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestGetBlobOutput {
+    /// The blob with the given hash or [`None`] if the manifest has no such
+    /// blob.
+    pub blob: Option<SerializableBytes>,
+}
+
+pub struct ManifestGetBlob;
+impl<'a> Function<'a> for ManifestGetBlob {
+    type Input = ManifestGetBlobInput;
+    type Output = ManifestGetBlobOutput;
+
+    fn handle(
+        ManifestGetBlobInput { manifest, hash }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        Ok(Self::Output {
+            blob: manifest.blobs.get(&hash).cloned(),
+        })
+    }
+}
+
+export_function!(ManifestGetBlob as manifest_get_blob);
+export_jni_function!(ManifestGetBlob as manifestGetBlob);
+export_wasm_bindgen_function!(ManifestGetBlob as manifestGetBlob);
+
+//===========================
+// Manifest Security Impacts
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestSecurityImpactsInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type ManifestSecurityImpactsOutput = Vec<SerializableSecurityImpact>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableSecurityImpact {
+    pub instruction_index: SerializableU64,
+    pub entity: SerializableManifestAddress,
+    pub kind: SerializableSecurityImpactKind,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableSecurityImpactKind {
+    OwnerRoleSet {
+        rule: SerializableAccessRule,
+    },
+    OwnerRoleLocked,
+    RoleSet {
+        module: SerializableModuleId,
+        role_key: String,
+        rule: SerializableAccessRule,
+    },
+    MetadataLocked {
+        key: String,
+    },
+    RoyaltySet {
+        method: String,
+        amount: SerializableRoyaltyAmount,
+    },
+    RoyaltyLocked {
+        method: String,
+    },
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableModuleId {
+    Main,
+    Metadata,
+    Royalty,
+    RoleAssignment,
+}
+
+impl SerializableSecurityImpact {
+    pub fn new(security_impact: SecurityImpact, network_id: u8) -> Self {
+        let entity = match security_impact.entity {
+            DynamicGlobalAddress::Static(address) => {
+                SerializableManifestAddress::Static(
+                    SerializableNodeId::from_global_address(
+                        address, network_id,
+                    ),
+                )
+            }
+            DynamicGlobalAddress::Named(named) => {
+                SerializableManifestAddress::Named(named.into())
+            }
+        };
+        let kind = match security_impact.kind {
+            SecurityImpactKind::OwnerRoleSet { rule } => {
+                SerializableSecurityImpactKind::OwnerRoleSet {
+                    rule: SerializableAccessRule::from_native(
+                        &rule, network_id,
+                    ),
+                }
+            }
+            SecurityImpactKind::OwnerRoleLocked => {
+                SerializableSecurityImpactKind::OwnerRoleLocked
+            }
+            SecurityImpactKind::RoleSet {
+                module,
+                role_key,
+                rule,
+            } => SerializableSecurityImpactKind::RoleSet {
+                module: module.into(),
+                role_key,
+                rule: SerializableAccessRule::from_native(&rule, network_id),
+            },
+            SecurityImpactKind::MetadataLocked { key } => {
+                SerializableSecurityImpactKind::MetadataLocked { key }
+            }
+            SecurityImpactKind::RoyaltySet { method, amount } => {
+                SerializableSecurityImpactKind::RoyaltySet {
+                    method,
+                    amount: amount.into(),
+                }
+            }
+            SecurityImpactKind::RoyaltyLocked { method } => {
+                SerializableSecurityImpactKind::RoyaltyLocked { method }
+            }
+        };
+        Self {
+            instruction_index: (security_impact.instruction_index as u64)
+                .into(),
+            entity,
+            kind,
+        }
+    }
+}
+
+impl From<ObjectModuleId> for SerializableModuleId {
+    fn from(value: ObjectModuleId) -> Self {
+        match value {
+            ObjectModuleId::Main => Self::Main,
+            ObjectModuleId::Metadata => Self::Metadata,
+            ObjectModuleId::Royalty => Self::Royalty,
+            ObjectModuleId::RoleAssignment => Self::RoleAssignment,
+        }
+    }
+}
+
+impl From<SerializableModuleId> for ObjectModuleId {
+    fn from(value: SerializableModuleId) -> Self {
+        match value {
+            SerializableModuleId::Main => Self::Main,
+            SerializableModuleId::Metadata => Self::Metadata,
+            SerializableModuleId::Royalty => Self::Royalty,
+            SerializableModuleId::RoleAssignment => Self::RoleAssignment,
+        }
+    }
+}
+
+pub struct ManifestSecurityImpacts;
+impl<'a> Function<'a> for ManifestSecurityImpacts {
+    type Input = ManifestSecurityImpactsInput;
+    type Output = ManifestSecurityImpactsOutput;
+
+    fn handle(
+        ManifestSecurityImpactsInput {
+            manifest,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        Ok(security_impacts(&manifest)
+            .into_iter()
+            .map(|security_impact| {
+                SerializableSecurityImpact::new(security_impact, *network_id)
+            })
+            .collect())
+    }
+}
+
+export_function!(ManifestSecurityImpacts as manifest_security_impacts);
+export_jni_function!(ManifestSecurityImpacts as manifestSecurityImpacts);
+export_wasm_bindgen_function!(
+    ManifestSecurityImpacts as manifestSecurityImpacts
+);
+
+//=========================
+// Manifest Render Summary
+//=========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestRenderSummaryInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    /// The options of how amounts and addresses are written. The amounts are
+    /// written in full with a `.` decimal separator and addresses are not
+    /// shortened if not provided.
+    pub locale_options: Option<SerializableLocaleOptions>,
+}
+
+#[typeshare::typeshare]
+pub type ManifestRenderSummaryOutput = Vec<String>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableLocaleOptions {
+    pub decimal_separator: String,
+    pub grouping_separator: Option<String>,
+    pub decimal_places: Option<SerializableU8>,
+    pub shorten_addresses: bool,
+}
+
+impl From<SerializableLocaleOptions> for LocaleOptions {
+    fn from(value: SerializableLocaleOptions) -> Self {
+        Self {
+            decimal_separator: value.decimal_separator,
+            grouping_separator: value.grouping_separator,
+            decimal_places: value.decimal_places.map(|places| *places),
+            shorten_addresses: value.shorten_addresses,
+        }
+    }
+}
+
+pub struct ManifestRenderSummary;
+impl<'a> Function<'a> for ManifestRenderSummary {
+    type Input = ManifestRenderSummaryInput;
+    type Output = ManifestRenderSummaryOutput;
+
+    fn handle(
+        ManifestRenderSummaryInput {
+            manifest,
+            network_id,
+            locale_options,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let network_definition =
+            network_definition_from_network_id(*network_id);
+        let locale_options = locale_options.map(Into::into).unwrap_or_default();
+
+        Ok(render_manifest_summary(
+            &manifest,
+            &network_definition,
+            &locale_options,
+        ))
+    }
+}
+
+export_function!(ManifestRenderSummary as manifest_render_summary);
+export_jni_function!(ManifestRenderSummary as manifestRenderSummary);
+export_wasm_bindgen_function!(ManifestRenderSummary as manifestRenderSummary);
+
+//========================
+// Manifest Run Analyzers
+//========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestRunAnalyzersInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    /// The declarative rules of the analyzers to run over the manifest. Each
+    /// set of rules contributes a section of the same name to the output.
+    pub analyzers: Vec<SerializableAnalyzerRules>,
+}
+
+#[typeshare::typeshare]
+pub type ManifestRunAnalyzersOutput = Vec<SerializableAnalysisSection>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAnalyzerRules {
+    pub name: String,
+    pub rules: Vec<SerializableAnalyzerRule>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAnalyzerRule {
+    pub condition: SerializableAnalyzerRuleCondition,
+    pub message: String,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableAnalyzerRuleCondition {
+    CallsMethod {
+        address: Option<SerializableNodeId>,
+        method_name: String,
+    },
+    CallsFunction {
+        package_address: Option<SerializableNodeId>,
+        blueprint_name: String,
+        function_name: String,
+    },
+    EncountersEntity {
+        address: SerializableNodeId,
+    },
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAnalysisSection {
+    pub name: String,
+    pub findings: Vec<SerializableAnalysisFinding>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableAnalysisFinding {
+    pub instruction_index: Option<SerializableU64>,
+    pub message: String,
+}
+
+impl SerializableAnalyzerRules {
+    pub fn to_native(&self) -> Result<AnalyzerRules, SerializableNodeIdError> {
+        Ok(AnalyzerRules {
+            name: self.name.clone(),
+            rules: self
+                .rules
+                .iter()
+                .map(|rule| {
+                    Ok(AnalyzerRule {
+                        condition: rule.condition.to_native()?,
+                        message: rule.message.clone(),
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl SerializableAnalyzerRuleCondition {
+    pub fn to_native(
+        &self,
+    ) -> Result<AnalyzerRuleCondition, SerializableNodeIdError> {
+        let condition = match self {
+            Self::CallsMethod {
+                address,
+                method_name,
+            } => AnalyzerRuleCondition::CallsMethod {
+                address: address
+                    .clone()
+                    .map(GlobalAddress::try_from)
+                    .transpose()?,
+                method_name: method_name.clone(),
+            },
+            Self::CallsFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+            } => AnalyzerRuleCondition::CallsFunction {
+                package_address: package_address
+                    .clone()
+                    .map(PackageAddress::try_from)
+                    .transpose()?,
+                blueprint_name: blueprint_name.clone(),
+                function_name: function_name.clone(),
+            },
+            Self::EncountersEntity { address } => {
+                AnalyzerRuleCondition::EncountersEntity {
+                    address: GlobalAddress::try_from(address.clone())?,
+                }
+            }
+        };
+        Ok(condition)
+    }
+}
+
+pub struct ManifestRunAnalyzers;
+impl<'a> Function<'a> for ManifestRunAnalyzers {
+    type Input = ManifestRunAnalyzersInput;
+    type Output = ManifestRunAnalyzersOutput;
+
+    fn handle(
+        ManifestRunAnalyzersInput {
+            manifest,
+            network_id,
+            analyzers,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+
+        let mut registry = ManifestAnalyzerRegistry::new();
+        for analyzer in analyzers.iter() {
+            let rules = analyzer.to_native().map_err(|error| {
+                InvocationHandlingError::InvalidAddress(debug_string(error))
+            })?;
+            registry.register_rules(rules);
+        }
+
+        Ok(registry
+            .analyze(&manifest)
+            .sections
+            .into_iter()
+            .map(|(name, findings)| SerializableAnalysisSection {
+                name,
+                findings: findings
+                    .into_iter()
+                    .map(|finding| SerializableAnalysisFinding {
+                        instruction_index: finding
+                            .instruction_index
+                            .map(|index| (index as u64).into()),
+                        message: finding.message,
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+}
+
+export_function!(ManifestRunAnalyzers as manifest_run_analyzers);
+export_jni_function!(ManifestRunAnalyzers as manifestRunAnalyzers);
+export_wasm_bindgen_function!(ManifestRunAnalyzers as manifestRunAnalyzers);
@@ -21,7 +21,7 @@ use crate::prelude::*;
 
 use radix_common::prelude::*;
 use radix_engine_toolkit::functions::manifest_sbor::*;
-use radix_engine_toolkit::utils::*;
+use radix_engine_toolkit::caches::bech32_coders;
 use sbor::{LocalTypeId, Schema};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -84,14 +84,13 @@ impl<'f> Function<'f> for ManifestSborDecodeToString {
             } else {
                 None
             };
-        let network_definition = network_definition_from_network_id(network_id);
-        let bech32_encoder = AddressBech32Encoder::new(&network_definition);
+        let coders = bech32_coders(network_id);
 
         let string =
             radix_engine_toolkit::functions::manifest_sbor::decode_to_string_representation(
                 encoded_payload,
                 representation,
-                &bech32_encoder,
+                &coders.address_encoder,
                 schema,
             )?;
 
@@ -101,6 +100,7 @@ impl<'f> Function<'f> for ManifestSborDecodeToString {
 
 export_function!(ManifestSborDecodeToString as manifest_sbor_decode_to_string);
 export_jni_function!(ManifestSborDecodeToString as manifestSborDecodeToString);
+export_wasm_bindgen_function!(ManifestSborDecodeToString as manifestSborDecodeToString);
 
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
@@ -0,0 +1,104 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Deref;
+
+use crate::prelude::*;
+
+use radix_engine_toolkit::functions::metadata::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//=======================
+// Metadata Value Encode
+//=======================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct MetadataValueEncodeInput {
+    pub value: SerializableMetadataValue,
+}
+
+#[typeshare::typeshare]
+pub type MetadataValueEncodeOutput = SerializableBytes;
+
+pub struct MetadataValueEncode;
+impl<'f> Function<'f> for MetadataValueEncode {
+    type Input = MetadataValueEncodeInput;
+    type Output = MetadataValueEncodeOutput;
+
+    fn handle(
+        MetadataValueEncodeInput { value }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let value = value.to_native().map_err(|error| {
+            InvocationHandlingError::InvalidAddress(debug_string(error))
+        })?;
+        encode_metadata_value(&value)
+            .map(Into::into)
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(value),
+                )
+            })
+    }
+}
+
+export_function!(MetadataValueEncode as metadata_value_encode);
+export_jni_function!(MetadataValueEncode as metadataValueEncode);
+export_wasm_bindgen_function!(MetadataValueEncode as metadataValueEncode);
+
+//=======================
+// Metadata Value Decode
+//=======================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct MetadataValueDecodeInput {
+    /// The Scrypto or Manifest SBOR encoded metadata value.
+    pub encoded_value: SerializableBytes,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type MetadataValueDecodeOutput = SerializableMetadataValue;
+
+pub struct MetadataValueDecode;
+impl<'f> Function<'f> for MetadataValueDecode {
+    type Input = MetadataValueDecodeInput;
+    type Output = MetadataValueDecodeOutput;
+
+    fn handle(
+        MetadataValueDecodeInput {
+            encoded_value,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let value =
+            decode_metadata_value(encoded_value.deref()).map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(encoded_value),
+                )
+            })?;
+        Ok(SerializableMetadataValue::from_native(&value, *network_id))
+    }
+}
+
+export_function!(MetadataValueDecode as metadata_value_decode);
+export_jni_function!(MetadataValueDecode as metadataValueDecode);
+export_wasm_bindgen_function!(MetadataValueDecode as metadataValueDecode);
@@ -15,17 +15,43 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod access_controller;
+pub mod access_rule;
+pub mod account;
+pub mod account_locker;
 pub mod address;
+pub mod batch;
+pub mod decimal;
 pub mod derive;
+pub mod dispatch;
+pub mod events;
 pub mod handler;
 pub mod information;
 pub mod instructions;
 pub mod intent;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod jobs;
+pub mod ledger;
 pub mod macros;
 pub mod manifest;
 pub mod manifest_sbor;
+pub mod metadata;
+pub mod non_fungible_data;
+pub mod non_fungible_global_id;
+pub mod non_fungible_local_id;
 pub mod notarized_transaction;
+pub mod package;
+pub mod payload;
+pub mod pool;
+pub mod preview;
+pub mod random;
+pub mod rola;
+pub mod royalty;
 pub mod scrypto_sbor;
 pub mod signed_intent;
+pub mod signing;
 pub mod traits;
 pub mod utils;
+pub mod validator;
+pub mod wallet_interaction;
+pub mod xrd;
@@ -0,0 +1,187 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Deref;
+
+use radix_common::prelude::*;
+use radix_engine_toolkit::caches::bech32_coders;
+use radix_engine_toolkit::functions::non_fungible_data::*;
+use radix_engine_toolkit::functions::scrypto_sbor::{
+    decode, decode_to_string_representation, encode,
+    encode_string_representation, ScryptoSborError, StringRepresentation,
+};
+use sbor::representations::SerializationMode;
+use sbor::{LocalTypeId, Schema};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The schema of the data of the non-fungibles of a resource along with the
+/// names of the fields of the data that are mutable.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableNonFungibleDataSchema {
+    pub schema: PayloadSchema,
+    pub mutable_fields: Vec<String>,
+}
+
+impl SerializableNonFungibleDataSchema {
+    pub fn to_native(
+        &self,
+    ) -> Result<NonFungibleDataDefinition, InvocationHandlingError> {
+        let schema =
+            scrypto_decode::<Schema<ScryptoCustomSchema>>(&self.schema.schema)
+                .map_err(|error| {
+                    InvocationHandlingError::DecodeError(
+                        debug_string(error),
+                        debug_string(&self.schema.schema),
+                    )
+                })?;
+        Ok(NonFungibleDataDefinition {
+            type_id: LocalTypeId::from(self.schema.local_type_id.clone()),
+            schema,
+            mutable_fields: self.mutable_fields.iter().cloned().collect(),
+        })
+    }
+}
+
+//=========================
+// Encode Non Fungible Data
+//=========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct EncodeNonFungibleDataInput {
+    pub fields: Vec<SerializableNonFungibleDataField>,
+    pub schema: SerializableNonFungibleDataSchema,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableNonFungibleDataField {
+    pub field_name: String,
+    /// The value of the field in the programmatic JSON representation.
+    pub value: String,
+}
+
+#[typeshare::typeshare]
+pub type EncodeNonFungibleDataOutput = SerializableBytes;
+
+pub struct EncodeNonFungibleData;
+impl<'f> Function<'f> for EncodeNonFungibleData {
+    type Input = EncodeNonFungibleDataInput;
+    type Output = EncodeNonFungibleDataOutput;
+
+    fn handle(
+        EncodeNonFungibleDataInput { fields, schema }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let definition = schema.to_native()?;
+        let mut decoded_fields = IndexMap::new();
+        for SerializableNonFungibleDataField { field_name, value } in fields {
+            if decoded_fields.contains_key(&field_name) {
+                return Err(
+                    NonFungibleDataError::DuplicateField(field_name).into()
+                );
+            }
+            let value = decode(encode_string_representation(
+                StringRepresentation::ProgrammaticJson(value),
+            )?)
+            .map_err(ScryptoSborError::DecodeError)?;
+            decoded_fields.insert(field_name, value);
+        }
+
+        let encoded =
+            radix_engine_toolkit::functions::non_fungible_data::encode_non_fungible_data(
+                decoded_fields,
+                &definition,
+            )?;
+        Ok(encoded.into())
+    }
+}
+
+export_function!(EncodeNonFungibleData as encode_non_fungible_data);
+export_jni_function!(EncodeNonFungibleData as encodeNonFungibleData);
+export_wasm_bindgen_function!(EncodeNonFungibleData as encodeNonFungibleData);
+
+//=========================
+// Decode Non Fungible Data
+//=========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct DecodeNonFungibleDataInput {
+    pub data: SerializableBytes,
+    pub schema: SerializableNonFungibleDataSchema,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type DecodeNonFungibleDataOutput =
+    Vec<SerializableDecodedNonFungibleDataField>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableDecodedNonFungibleDataField {
+    pub field_name: String,
+    pub mutable: bool,
+    /// The value of the field in the programmatic JSON representation with
+    /// the names of its types and fields from the schema.
+    pub value: String,
+}
+
+pub struct DecodeNonFungibleData;
+impl<'f> Function<'f> for DecodeNonFungibleData {
+    type Input = DecodeNonFungibleDataInput;
+    type Output = DecodeNonFungibleDataOutput;
+
+    fn handle(
+        DecodeNonFungibleDataInput {
+            data,
+            schema,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let definition = schema.to_native()?;
+        let coders = bech32_coders(*network_id);
+        radix_engine_toolkit::functions::non_fungible_data::decode_non_fungible_data(
+            data.deref(),
+            &definition,
+        )?
+            .into_iter()
+            .map(|field| {
+                let encoded_value = encode(&field.value)
+                    .map_err(ScryptoSborError::EncodeError)?;
+                let value = decode_to_string_representation(
+                    encoded_value,
+                    SerializationMode::Programmatic,
+                    &coders.address_encoder,
+                    Some((field.type_id, definition.schema.clone())),
+                )?;
+                Ok(SerializableDecodedNonFungibleDataField {
+                    field_name: field.name,
+                    mutable: field.mutable,
+                    value,
+                })
+            })
+            .collect()
+    }
+}
+
+export_function!(DecodeNonFungibleData as decode_non_fungible_data);
+export_jni_function!(DecodeNonFungibleData as decodeNonFungibleData);
+export_wasm_bindgen_function!(DecodeNonFungibleData as decodeNonFungibleData);
@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::non_fungible_global_id::*;
+use schemars::JsonSchema;
+use scrypto::prelude::NonFungibleGlobalId;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// A non-fungible global id in its canonical string form along with its
+/// parts.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableNonFungibleGlobalIdParts {
+    pub non_fungible_global_id: SerializableNonFungibleGlobalId,
+    pub resource_address: SerializableNodeId,
+    pub local_id: SerializableNonFungibleLocalId,
+    pub structured_local_id: SerializableStructuredNonFungibleLocalId,
+}
+
+impl SerializableNonFungibleGlobalIdParts {
+    pub fn new(
+        non_fungible_global_id: NonFungibleGlobalId,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            resource_address: SerializableNodeId::from_global_address(
+                non_fungible_global_id.resource_address(),
+                network_id,
+            ),
+            local_id: non_fungible_global_id.local_id().clone().into(),
+            structured_local_id:
+                SerializableStructuredNonFungibleLocalId::from_native(
+                    non_fungible_global_id.local_id(),
+                ),
+            non_fungible_global_id: SerializableNonFungibleGlobalId::new(
+                non_fungible_global_id,
+                network_id,
+            ),
+        }
+    }
+}
+
+//=============================
+// Build Non Fungible Global Id
+//=============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildNonFungibleGlobalIdInput {
+    pub resource_address: String,
+    pub local_id: String,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type BuildNonFungibleGlobalIdOutput = SerializableNonFungibleGlobalIdParts;
+
+pub struct BuildNonFungibleGlobalId;
+impl<'f> Function<'f> for BuildNonFungibleGlobalId {
+    type Input = BuildNonFungibleGlobalIdInput;
+    type Output = BuildNonFungibleGlobalIdOutput;
+
+    fn handle(
+        BuildNonFungibleGlobalIdInput {
+            resource_address,
+            local_id,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let non_fungible_global_id =
+            radix_engine_toolkit::functions::non_fungible_global_id::build_non_fungible_global_id(
+                &resource_address,
+                &local_id,
+                *network_id,
+            )?;
+        Ok(SerializableNonFungibleGlobalIdParts::new(
+            non_fungible_global_id,
+            *network_id,
+        ))
+    }
+}
+
+export_function!(BuildNonFungibleGlobalId as build_non_fungible_global_id);
+export_jni_function!(BuildNonFungibleGlobalId as buildNonFungibleGlobalId);
+export_wasm_bindgen_function!(
+    BuildNonFungibleGlobalId as buildNonFungibleGlobalId
+);
+
+//=============================
+// Parse Non Fungible Global Id
+//=============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ParseNonFungibleGlobalIdInput {
+    pub non_fungible_global_id: String,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type ParseNonFungibleGlobalIdOutput = SerializableNonFungibleGlobalIdParts;
+
+pub struct ParseNonFungibleGlobalId;
+impl<'f> Function<'f> for ParseNonFungibleGlobalId {
+    type Input = ParseNonFungibleGlobalIdInput;
+    type Output = ParseNonFungibleGlobalIdOutput;
+
+    fn handle(
+        ParseNonFungibleGlobalIdInput {
+            non_fungible_global_id,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let non_fungible_global_id =
+            radix_engine_toolkit::functions::non_fungible_global_id::parse_non_fungible_global_id(
+                &non_fungible_global_id,
+                *network_id,
+            )?;
+        Ok(SerializableNonFungibleGlobalIdParts::new(
+            non_fungible_global_id,
+            *network_id,
+        ))
+    }
+}
+
+export_function!(ParseNonFungibleGlobalId as parse_non_fungible_global_id);
+export_jni_function!(ParseNonFungibleGlobalId as parseNonFungibleGlobalId);
+export_wasm_bindgen_function!(
+    ParseNonFungibleGlobalId as parseNonFungibleGlobalId
+);
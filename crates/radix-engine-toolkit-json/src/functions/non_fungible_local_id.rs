@@ -0,0 +1,277 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::non_fungible_local_id::*;
+use schemars::JsonSchema;
+use scrypto::prelude::{NonFungibleIdType, NonFungibleLocalId};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableNonFungibleIdType {
+    Integer,
+    String,
+    Bytes,
+    Ruid,
+}
+
+impl From<NonFungibleIdType> for SerializableNonFungibleIdType {
+    fn from(value: NonFungibleIdType) -> Self {
+        match value {
+            NonFungibleIdType::Integer => Self::Integer,
+            NonFungibleIdType::String => Self::String,
+            NonFungibleIdType::Bytes => Self::Bytes,
+            NonFungibleIdType::RUID => Self::Ruid,
+        }
+    }
+}
+
+impl From<SerializableNonFungibleIdType> for NonFungibleIdType {
+    fn from(value: SerializableNonFungibleIdType) -> Self {
+        match value {
+            SerializableNonFungibleIdType::Integer => Self::Integer,
+            SerializableNonFungibleIdType::String => Self::String,
+            SerializableNonFungibleIdType::Bytes => Self::Bytes,
+            SerializableNonFungibleIdType::Ruid => Self::RUID,
+        }
+    }
+}
+
+/// A non-fungible local id broken down into its id type and its content.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableStructuredNonFungibleLocalId {
+    Integer(SerializableU64),
+    String(String),
+    Bytes(SerializableBytes),
+    /// The 32 bytes of the RUID.
+    Ruid(SerializableBytes),
+}
+
+impl SerializableStructuredNonFungibleLocalId {
+    pub fn from_native(local_id: &NonFungibleLocalId) -> Self {
+        match local_id {
+            NonFungibleLocalId::Integer(id) => Self::Integer(id.value().into()),
+            NonFungibleLocalId::String(id) => {
+                Self::String(id.value().to_owned())
+            }
+            NonFungibleLocalId::Bytes(id) => {
+                Self::Bytes(id.value().to_vec().into())
+            }
+            NonFungibleLocalId::RUID(id) => {
+                Self::Ruid(id.value().to_vec().into())
+            }
+        }
+    }
+
+    pub fn to_native(
+        &self,
+    ) -> Result<NonFungibleLocalId, NonFungibleLocalIdError> {
+        match self {
+            Self::Integer(value) => Ok(NonFungibleLocalId::integer(**value)),
+            Self::String(value) => string_non_fungible_local_id(value.clone()),
+            Self::Bytes(value) => bytes_non_fungible_local_id(value.to_vec()),
+            Self::Ruid(value) => ruid_non_fungible_local_id(value.to_vec()),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableNonFungibleLocalIdError {
+    InvalidDelimiters(String),
+    InvalidInteger(String),
+    Empty,
+    TooLong {
+        length: SerializableU64,
+        max_length: SerializableU64,
+    },
+    InvalidCharacter {
+        character: String,
+        index: SerializableU64,
+    },
+    InvalidHex(String),
+    InvalidRuid(String),
+    IdTypeMismatch {
+        expected: SerializableNonFungibleIdType,
+        actual: SerializableNonFungibleIdType,
+    },
+}
+
+impl From<NonFungibleLocalIdError> for SerializableNonFungibleLocalIdError {
+    fn from(value: NonFungibleLocalIdError) -> Self {
+        match value {
+            NonFungibleLocalIdError::InvalidDelimiters(string) => {
+                Self::InvalidDelimiters(string)
+            }
+            NonFungibleLocalIdError::InvalidInteger(string) => {
+                Self::InvalidInteger(string)
+            }
+            NonFungibleLocalIdError::Empty => Self::Empty,
+            NonFungibleLocalIdError::TooLong { length, max_length } => {
+                Self::TooLong {
+                    length: (length as u64).into(),
+                    max_length: (max_length as u64).into(),
+                }
+            }
+            NonFungibleLocalIdError::InvalidCharacter { character, index } => {
+                Self::InvalidCharacter {
+                    character: character.to_string(),
+                    index: (index as u64).into(),
+                }
+            }
+            NonFungibleLocalIdError::InvalidHex(string) => {
+                Self::InvalidHex(string)
+            }
+            NonFungibleLocalIdError::InvalidRuid(string) => {
+                Self::InvalidRuid(string)
+            }
+            NonFungibleLocalIdError::IdTypeMismatch { expected, actual } => {
+                Self::IdTypeMismatch {
+                    expected: expected.into(),
+                    actual: actual.into(),
+                }
+            }
+        }
+    }
+}
+
+//=============================
+// Non Fungible Local Id Parse
+//=============================
+
+#[typeshare::typeshare]
+pub type NonFungibleLocalIdParseInput = String;
+
+#[typeshare::typeshare]
+pub type NonFungibleLocalIdParseOutput =
+    SerializableStructuredNonFungibleLocalId;
+
+pub struct NonFungibleLocalIdParse;
+impl<'f> Function<'f> for NonFungibleLocalIdParse {
+    type Input = NonFungibleLocalIdParseInput;
+    type Output = NonFungibleLocalIdParseOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let local_id = parse_non_fungible_local_id(&input)?;
+        Ok(SerializableStructuredNonFungibleLocalId::from_native(
+            &local_id,
+        ))
+    }
+}
+
+export_function!(NonFungibleLocalIdParse as non_fungible_local_id_parse);
+export_jni_function!(NonFungibleLocalIdParse as nonFungibleLocalIdParse);
+export_wasm_bindgen_function!(
+    NonFungibleLocalIdParse as nonFungibleLocalIdParse
+);
+
+//==============================
+// Non Fungible Local Id Format
+//==============================
+
+#[typeshare::typeshare]
+pub type NonFungibleLocalIdFormatInput =
+    SerializableStructuredNonFungibleLocalId;
+
+#[typeshare::typeshare]
+pub type NonFungibleLocalIdFormatOutput = String;
+
+pub struct NonFungibleLocalIdFormat;
+impl<'f> Function<'f> for NonFungibleLocalIdFormat {
+    type Input = NonFungibleLocalIdFormatInput;
+    type Output = NonFungibleLocalIdFormatOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let local_id = input.to_native()?;
+        Ok(format_non_fungible_local_id(&local_id))
+    }
+}
+
+export_function!(NonFungibleLocalIdFormat as non_fungible_local_id_format);
+export_jni_function!(NonFungibleLocalIdFormat as nonFungibleLocalIdFormat);
+export_wasm_bindgen_function!(
+    NonFungibleLocalIdFormat as nonFungibleLocalIdFormat
+);
+
+//================================
+// Non Fungible Local Id Validate
+//================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NonFungibleLocalIdValidateInput {
+    pub local_id: String,
+    /// The id type that the id is expected to be of, e.g., the id type of
+    /// the resource that it's an id of. Ids of any type are allowed if it's
+    /// not given.
+    pub expected_id_type: Option<SerializableNonFungibleIdType>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NonFungibleLocalIdValidateOutput {
+    pub is_valid: bool,
+    /// The canonical string form of the id if it's valid.
+    pub canonical: Option<String>,
+    pub error: Option<SerializableNonFungibleLocalIdError>,
+}
+
+pub struct NonFungibleLocalIdValidate;
+impl<'f> Function<'f> for NonFungibleLocalIdValidate {
+    type Input = NonFungibleLocalIdValidateInput;
+    type Output = NonFungibleLocalIdValidateOutput;
+
+    fn handle(
+        NonFungibleLocalIdValidateInput {
+            local_id,
+            expected_id_type,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        Ok(
+            match validate_non_fungible_local_id(
+                &local_id,
+                expected_id_type.map(Into::into),
+            ) {
+                Ok(local_id) => Self::Output {
+                    is_valid: true,
+                    canonical: Some(format_non_fungible_local_id(&local_id)),
+                    error: None,
+                },
+                Err(error) => Self::Output {
+                    is_valid: false,
+                    canonical: None,
+                    error: Some(error.into()),
+                },
+            },
+        )
+    }
+}
+
+export_function!(NonFungibleLocalIdValidate as non_fungible_local_id_validate);
+export_jni_function!(NonFungibleLocalIdValidate as nonFungibleLocalIdValidate);
+export_wasm_bindgen_function!(
+    NonFungibleLocalIdValidate as nonFungibleLocalIdValidate
+);
@@ -15,6 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit::functions::limits::check_limits;
+use radix_engine_toolkit::functions::notarized_transaction::{
+    verify_signatures_from_payload, IntentSignatureVerification,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -56,13 +60,70 @@ impl<'f> Function<'f> for NotarizedTransactionHash {
 
 export_function!(NotarizedTransactionHash as notarized_transaction_hash);
 export_jni_function!(NotarizedTransactionHash as notarizedTransactionHash);
+export_wasm_bindgen_function!(
+    NotarizedTransactionHash as notarizedTransactionHash
+);
+
+//==========================================
+// Notarized Transaction Hash from Compiled
+//==========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NotarizedTransactionHashFromCompiledInput {
+    pub compiled: SerializableBytes,
+}
+#[typeshare::typeshare]
+pub type NotarizedTransactionHashFromCompiledOutput =
+    SerializableTransactionHash;
+
+pub struct NotarizedTransactionHashFromCompiled;
+impl<'a> Function<'a> for NotarizedTransactionHashFromCompiled {
+    type Input = NotarizedTransactionHashFromCompiledInput;
+    type Output = NotarizedTransactionHashFromCompiledOutput;
+
+    fn handle(
+        NotarizedTransactionHashFromCompiledInput { compiled }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let hash =
+            radix_engine_toolkit::functions::notarized_transaction::hash_from_payload(
+                &**compiled,
+            )
+            .map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(compiled),
+                )
+            })?;
+        Ok(hash.into())
+    }
+}
+
+export_function!(
+    NotarizedTransactionHashFromCompiled
+        as notarized_transaction_hash_from_compiled
+);
+export_jni_function!(
+    NotarizedTransactionHashFromCompiled
+        as notarizedTransactionHashFromCompiled
+);
+export_wasm_bindgen_function!(
+    NotarizedTransactionHashFromCompiled
+        as notarizedTransactionHashFromCompiled
+);
 
 //===============================
 // Notarized Transaction Compile
 //===============================
 
-#[typeshare::typeshare]
-pub type NotarizedTransactionCompileInput = SerializableNotarizedTransaction;
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NotarizedTransactionCompileInput {
+    #[serde(flatten)]
+    pub notarized_transaction: SerializableNotarizedTransaction,
+    /// The limits that the compiled payload is checked against. No limits are
+    /// checked when absent.
+    pub limits: Option<SerializableCompilationLimitsSource>,
+}
 #[typeshare::typeshare]
 pub type NotarizedTransactionCompileOutput = SerializableBytes;
 
@@ -72,11 +133,20 @@ impl<'f> Function<'f> for NotarizedTransactionCompile {
     type Output = NotarizedTransactionCompileOutput;
 
     fn handle(
-        notarized_transaction: Self::Input,
+        NotarizedTransactionCompileInput {
+            notarized_transaction,
+            limits,
+        }: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let notarized_transaction = notarized_transaction.to_native(
             *notarized_transaction.signed_intent.intent.header.network_id,
         )?;
+        let instruction_count = notarized_transaction
+            .signed_intent
+            .intent
+            .instructions
+            .0
+            .len();
         let compile =
             radix_engine_toolkit::functions::notarized_transaction::compile(
                 &notarized_transaction,
@@ -87,6 +157,19 @@ impl<'f> Function<'f> for NotarizedTransactionCompile {
                     debug_string(notarized_transaction),
                 )
             })?;
+        if let Some(limits) = limits {
+            check_limits(
+                &compile,
+                instruction_count,
+                &limits.to_native(
+                    notarized_transaction
+                        .signed_intent
+                        .intent
+                        .header
+                        .network_id,
+                )?,
+            )?;
+        }
         Ok(compile.into())
     }
 }
@@ -95,6 +178,9 @@ export_function!(NotarizedTransactionCompile as notarized_transaction_compile);
 export_jni_function!(
     NotarizedTransactionCompile as notarizedTransactionCompile
 );
+export_wasm_bindgen_function!(
+    NotarizedTransactionCompile as notarizedTransactionCompile
+);
 
 //=================================
 // Notarized Transaction Decompile
@@ -105,6 +191,10 @@ export_jni_function!(
 pub struct NotarizedTransactionDecompileInput {
     pub compiled: SerializableBytes,
     pub instructions_kind: SerializableInstructionsKind,
+    /// Whether the manifest string is annotated with comments that describe
+    /// the instructions. Has no effect on parsed instructions.
+    #[serde(default)]
+    pub annotate: bool,
 }
 #[typeshare::typeshare]
 pub type NotarizedTransactionDecompileOutput = SerializableNotarizedTransaction;
@@ -118,6 +208,7 @@ impl<'a> Function<'a> for NotarizedTransactionDecompile {
         NotarizedTransactionDecompileInput {
             compiled,
             instructions_kind,
+            annotate,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
         let notarized_transaction =
@@ -131,14 +222,23 @@ impl<'a> Function<'a> for NotarizedTransactionDecompile {
                 )
             })?;
 
-        let notarized_transaction =
+        let intent = &notarized_transaction.signed_intent.intent;
+        let mut serializable_notarized_transaction =
             SerializableNotarizedTransaction::from_native(
                 &notarized_transaction,
-                notarized_transaction.signed_intent.intent.header.network_id,
+                intent.header.network_id,
                 instructions_kind,
             )?;
+        if annotate {
+            serializable_notarized_transaction
+                .signed_intent
+                .intent
+                .manifest
+                .instructions
+                .annotate(&intent.instructions.0, intent.header.network_id)?;
+        }
 
-        Ok(notarized_transaction)
+        Ok(serializable_notarized_transaction)
     }
 }
 
@@ -148,6 +248,9 @@ export_function!(
 export_jni_function!(
     NotarizedTransactionDecompile as notarizedTransactionDecompile
 );
+export_wasm_bindgen_function!(
+    NotarizedTransactionDecompile as notarizedTransactionDecompile
+);
 
 //===========================================
 // Notarized Transaction Statically Validate
@@ -202,3 +305,87 @@ export_jni_function!(
     NotarizedTransactionStaticallyValidate
         as notarizedTransactionStaticallyValidate
 );
+export_wasm_bindgen_function!(
+    NotarizedTransactionStaticallyValidate
+        as notarizedTransactionStaticallyValidate
+);
+
+//=========================================
+// Notarized Transaction Verify Signatures
+//=========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NotarizedTransactionVerifySignaturesInput {
+    pub compiled: SerializableBytes,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct NotarizedTransactionVerifySignaturesOutput {
+    /// The result of verifying each of the intent signatures against the
+    /// intent hash in the order in which they appear in the transaction.
+    pub intent_signatures: Vec<SerializableIntentSignatureVerification>,
+    /// Whether the notary signature is a valid signature over the signed
+    /// intent hash by the notary public key in the header.
+    pub notary_signature_is_valid: bool,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableIntentSignatureVerification {
+    /// The public key of the signer. Only absent for invalid Secp256k1
+    /// signatures which the public key can't be recovered from.
+    pub public_key: Option<SerializablePublicKey>,
+    pub is_valid: bool,
+}
+
+impl From<IntentSignatureVerification>
+    for SerializableIntentSignatureVerification
+{
+    fn from(value: IntentSignatureVerification) -> Self {
+        Self {
+            public_key: value.public_key.map(Into::into),
+            is_valid: value.is_valid,
+        }
+    }
+}
+
+pub struct NotarizedTransactionVerifySignatures;
+impl<'a> Function<'a> for NotarizedTransactionVerifySignatures {
+    type Input = NotarizedTransactionVerifySignaturesInput;
+    type Output = NotarizedTransactionVerifySignaturesOutput;
+
+    fn handle(
+        NotarizedTransactionVerifySignaturesInput { compiled }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let verification = verify_signatures_from_payload(&**compiled)
+            .map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(compiled),
+                )
+            })?;
+        Ok(Self::Output {
+            intent_signatures: verification
+                .intent_signatures
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            notary_signature_is_valid: verification.notary_signature_is_valid,
+        })
+    }
+}
+
+export_function!(
+    NotarizedTransactionVerifySignatures
+        as notarized_transaction_verify_signatures
+);
+export_jni_function!(
+    NotarizedTransactionVerifySignatures
+        as notarizedTransactionVerifySignatures
+);
+export_wasm_bindgen_function!(
+    NotarizedTransactionVerifySignatures
+        as notarizedTransactionVerifySignatures
+);
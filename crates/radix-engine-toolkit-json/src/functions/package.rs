@@ -0,0 +1,519 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Deref;
+
+use crate::prelude::*;
+use radix_engine_toolkit::caches::bech32_coders;
+use radix_engine_toolkit::functions::package::*;
+use schemars::JsonSchema;
+use scrypto::prelude::*;
+use scrypto::radix_blueprint_schema_init::Receiver;
+use serde::{Deserialize, Serialize};
+
+//======================================
+// Package Extract Blueprint Interfaces
+//======================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PackageExtractBlueprintInterfacesInput {
+    /// The SBOR encoded package definition, e.g., the contents of the `.rpd`
+    /// file of a compiled package.
+    pub package_definition: SerializableBytes,
+}
+
+#[typeshare::typeshare]
+pub type PackageExtractBlueprintInterfacesOutput =
+    Vec<SerializableBlueprintInterface>;
+
+pub struct PackageExtractBlueprintInterfaces;
+impl<'f> Function<'f> for PackageExtractBlueprintInterfaces {
+    type Input = PackageExtractBlueprintInterfacesInput;
+    type Output = PackageExtractBlueprintInterfacesOutput;
+
+    fn handle(
+        PackageExtractBlueprintInterfacesInput { package_definition }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let blueprint_interfaces =
+            extract_blueprint_interfaces(package_definition.deref())?;
+        Ok(blueprint_interfaces
+            .into_iter()
+            .map(
+                |(name, blueprint_interface)| SerializableBlueprintInterface {
+                    name,
+                    functions: blueprint_interface
+                        .functions
+                        .into_iter()
+                        .map(|(name, function_signature)| {
+                            SerializableFunctionSignature::new(
+                                name,
+                                function_signature,
+                            )
+                        })
+                        .collect(),
+                },
+            )
+            .collect())
+    }
+}
+
+export_function!(
+    PackageExtractBlueprintInterfaces as package_extract_blueprint_interfaces
+);
+export_jni_function!(
+    PackageExtractBlueprintInterfaces as packageExtractBlueprintInterfaces
+);
+export_wasm_bindgen_function!(
+    PackageExtractBlueprintInterfaces as packageExtractBlueprintInterfaces
+);
+
+//==============================
+// Package Build Call Arguments
+//==============================
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PackageBuildCallArgumentsInput {
+    pub package_definition: SerializableBytes,
+    pub blueprint_name: String,
+    pub function_name: String,
+    /// The loosely typed JSON values of the arguments of the function.
+    pub arguments: Vec<serde_json::Value>,
+    pub network_id: SerializableU8,
+}
+
+pub type PackageBuildCallArgumentsOutput = SerializableManifestValue;
+
+pub struct PackageBuildCallArguments;
+impl<'f> Function<'f> for PackageBuildCallArguments {
+    type Input = PackageBuildCallArgumentsInput;
+    type Output = PackageBuildCallArgumentsOutput;
+
+    /// Builds the tuple of the arguments of the function which can be used
+    /// as the arguments of a call instruction.
+    fn handle(
+        PackageBuildCallArgumentsInput {
+            package_definition,
+            blueprint_name,
+            function_name,
+            arguments,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let package_definition =
+            decode_package_definition(package_definition.deref())?;
+        let coders = bech32_coders(*network_id);
+
+        let value = build_call_arguments(
+            &package_definition,
+            &blueprint_name,
+            &function_name,
+            &arguments,
+            &coders.address_decoder,
+        )?;
+        SerializableManifestValue::from_manifest_value(&value, *network_id)
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(value),
+                )
+            })
+    }
+}
+
+export_function!(PackageBuildCallArguments as package_build_call_arguments);
+export_jni_function!(PackageBuildCallArguments as packageBuildCallArguments);
+export_wasm_bindgen_function!(
+    PackageBuildCallArguments as packageBuildCallArguments
+);
+
+//================================
+// Package Build Publish Manifest
+//================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PackageBuildPublishManifestInput {
+    /// The WASM code of the package, e.g., the contents of the `.wasm` file of
+    /// a compiled package.
+    pub code: SerializableBytes,
+    /// The SBOR encoded package definition, e.g., the contents of the `.rpd`
+    /// file of a compiled package.
+    pub package_definition: SerializableBytes,
+    /// The `MetadataInit` of the package as a manifest value.
+    pub metadata: SerializableManifestValue,
+    /// The `OwnerRole` of the package as a manifest value.
+    pub owner_role: SerializableManifestValue,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PackageBuildPublishManifestOutput {
+    pub manifest: SerializableTransactionManifest,
+    pub code_hash: SerializableHash,
+    /// The hashes of the schemas of the blueprints of the package keyed by
+    /// the name of the blueprint.
+    #[typeshare(serialized_as = "HashMap<String, SerializableHash>")]
+    pub schema_hashes: ::indexmap::IndexMap<String, SerializableHash>,
+}
+
+pub struct PackageBuildPublishManifest;
+impl<'f> Function<'f> for PackageBuildPublishManifest {
+    type Input = PackageBuildPublishManifestInput;
+    type Output = PackageBuildPublishManifestOutput;
+
+    fn handle(
+        PackageBuildPublishManifestInput {
+            code,
+            package_definition,
+            metadata,
+            owner_role,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let PublishPackageManifest {
+            manifest,
+            code_hash,
+            schema_hashes,
+        } = build_publish_package_manifest(
+            code.deref().clone(),
+            package_definition.deref(),
+            metadata.to_typed()?,
+            owner_role.to_typed()?,
+        )?;
+
+        Ok(Self::Output {
+            manifest: SerializableTransactionManifest::from_native(
+                &manifest,
+                *network_id,
+                instructions_kind,
+            )?,
+            code_hash: code_hash.into(),
+            schema_hashes: schema_hashes
+                .into_iter()
+                .map(|(blueprint_name, schema_hash)| {
+                    (blueprint_name, schema_hash.into())
+                })
+                .collect(),
+        })
+    }
+}
+
+export_function!(PackageBuildPublishManifest as package_build_publish_manifest);
+export_jni_function!(
+    PackageBuildPublishManifest as packageBuildPublishManifest
+);
+export_wasm_bindgen_function!(
+    PackageBuildPublishManifest as packageBuildPublishManifest
+);
+
+//==============
+// Package Hash
+//==============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PackageHashInput {
+    pub code: SerializableBytes,
+    pub package_definition: SerializableBytes,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PackageHashOutput {
+    pub code_hash: SerializableHash,
+    /// The hashes of the schemas of the blueprints of the package keyed by
+    /// the name of the blueprint.
+    #[typeshare(serialized_as = "HashMap<String, SerializableHash>")]
+    pub schema_hashes: ::indexmap::IndexMap<String, SerializableHash>,
+}
+
+pub struct PackageHash;
+impl<'f> Function<'f> for PackageHash {
+    type Input = PackageHashInput;
+    type Output = PackageHashOutput;
+
+    fn handle(
+        PackageHashInput {
+            code,
+            package_definition,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let package_definition =
+            decode_package_definition(package_definition.deref())?;
+        let PackageHashes {
+            code_hash,
+            schema_hashes,
+        } = package_hashes(&code, &package_definition)?;
+
+        Ok(Self::Output {
+            code_hash: code_hash.into(),
+            schema_hashes: schema_hashes
+                .into_iter()
+                .map(|(blueprint_name, schema_hash)| {
+                    (blueprint_name, schema_hash.into())
+                })
+                .collect(),
+        })
+    }
+}
+
+export_function!(PackageHash as package_hash);
+export_jni_function!(PackageHash as packageHash);
+export_wasm_bindgen_function!(PackageHash as packageHash);
+
+//==========================
+// Package Predict Address
+//==========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PackagePredictAddressInput {
+    /// The intent hash of the transaction that publishes the package.
+    pub intent_hash: SerializableHash,
+    /// The index of the allocation of the address among all of the nodes that
+    /// the transaction allocates. This is not the index of the instruction,
+    /// it can be obtained by previewing the manifest and passing the address
+    /// of the package in the preview receipt to `package_allocation_index`.
+    pub allocation_index: SerializableU32,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type PackagePredictAddressOutput = SerializableNodeId;
+
+pub struct PackagePredictAddress;
+impl<'f> Function<'f> for PackagePredictAddress {
+    type Input = PackagePredictAddressInput;
+    type Output = PackagePredictAddressOutput;
+
+    fn handle(
+        PackagePredictAddressInput {
+            intent_hash,
+            allocation_index,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let package_address =
+            predict_package_address(&intent_hash, *allocation_index);
+        Ok(SerializableNodeId::from_global_address(
+            package_address,
+            *network_id,
+        ))
+    }
+}
+
+export_function!(PackagePredictAddress as package_predict_address);
+export_jni_function!(PackagePredictAddress as packagePredictAddress);
+export_wasm_bindgen_function!(PackagePredictAddress as packagePredictAddress);
+
+//==========================
+// Package Allocation Index
+//==========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PackageAllocationIndexInput {
+    /// The intent hash of the transaction that allocated the address, e.g., the
+    /// previewed transaction.
+    pub intent_hash: SerializableHash,
+    pub package_address: SerializableNodeId,
+}
+
+#[typeshare::typeshare]
+pub type PackageAllocationIndexOutput = Option<SerializableU32>;
+
+pub struct PackageAllocationIndex;
+impl<'f> Function<'f> for PackageAllocationIndex {
+    type Input = PackageAllocationIndexInput;
+    type Output = PackageAllocationIndexOutput;
+
+    fn handle(
+        PackageAllocationIndexInput {
+            intent_hash,
+            package_address,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let package_address = PackageAddress::try_from(package_address)
+            .map_err(|error| {
+                InvocationHandlingError::InvalidAddress(debug_string(error))
+            })?;
+        Ok(package_allocation_index(&intent_hash, &package_address)
+            .map(Into::into))
+    }
+}
+
+export_function!(PackageAllocationIndex as package_allocation_index);
+export_jni_function!(PackageAllocationIndex as packageAllocationIndex);
+export_wasm_bindgen_function!(PackageAllocationIndex as packageAllocationIndex);
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableBlueprintInterface {
+    pub name: String,
+    pub functions: Vec<SerializableFunctionSignature>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableFunctionSignature {
+    pub name: String,
+    /// The receiver of the function if it's a method and [`None`] if it's a
+    /// function.
+    pub receiver: Option<SerializableReceiver>,
+    pub arguments: Vec<SerializableFieldSignature>,
+    pub output: SerializableTypeSignature,
+}
+
+impl SerializableFunctionSignature {
+    pub fn new(name: String, function_signature: FunctionSignature) -> Self {
+        Self {
+            name,
+            receiver: function_signature.receiver.map(Into::into),
+            arguments: function_signature
+                .arguments
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            output: function_signature.output.into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializableReceiver {
+    SelfRef,
+    SelfRefMut,
+}
+
+impl From<Receiver> for SerializableReceiver {
+    fn from(value: Receiver) -> Self {
+        match value {
+            Receiver::SelfRef => Self::SelfRef,
+            Receiver::SelfRefMut => Self::SelfRefMut,
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableFieldSignature {
+    pub name: Option<String>,
+    pub type_signature: SerializableTypeSignature,
+}
+
+impl From<FieldSignature> for SerializableFieldSignature {
+    fn from(value: FieldSignature) -> Self {
+        Self {
+            name: value.name,
+            type_signature: value.type_signature.into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableVariantSignature {
+    pub discriminator: SerializableU8,
+    pub name: Option<String>,
+    pub fields: Vec<SerializableFieldSignature>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableTypeSignature {
+    Any,
+    Generic {
+        index: SerializableU8,
+    },
+    Recursive {
+        type_name: Option<String>,
+    },
+    Own,
+    Value {
+        value_kind: SerializableManifestValueKind,
+    },
+    Array {
+        element: Box<SerializableTypeSignature>,
+    },
+    Tuple {
+        type_name: Option<String>,
+        fields: Vec<SerializableFieldSignature>,
+    },
+    Enum {
+        type_name: Option<String>,
+        variants: Vec<SerializableVariantSignature>,
+    },
+    Map {
+        key: Box<SerializableTypeSignature>,
+        value: Box<SerializableTypeSignature>,
+    },
+}
+
+impl From<TypeSignature> for SerializableTypeSignature {
+    fn from(value: TypeSignature) -> Self {
+        match value {
+            TypeSignature::Any => Self::Any,
+            TypeSignature::Generic(index) => Self::Generic {
+                index: index.into(),
+            },
+            TypeSignature::Recursive { type_name } => {
+                Self::Recursive { type_name }
+            }
+            TypeSignature::Own => Self::Own,
+            TypeSignature::Value(value_kind) => Self::Value {
+                value_kind: value_kind.into(),
+            },
+            TypeSignature::Array { element } => Self::Array {
+                element: Box::new((*element).into()),
+            },
+            TypeSignature::Tuple { type_name, fields } => Self::Tuple {
+                type_name,
+                fields: fields.into_iter().map(Into::into).collect(),
+            },
+            TypeSignature::Enum {
+                type_name,
+                variants,
+            } => Self::Enum {
+                type_name,
+                variants: variants
+                    .into_iter()
+                    .map(|(discriminator, variant)| {
+                        SerializableVariantSignature {
+                            discriminator: discriminator.into(),
+                            name: variant.name,
+                            fields: variant
+                                .fields
+                                .into_iter()
+                                .map(Into::into)
+                                .collect(),
+                        }
+                    })
+                    .collect(),
+            },
+            TypeSignature::Map { key, value } => Self::Map {
+                key: Box::new((*key).into()),
+                value: Box::new((*value).into()),
+            },
+        }
+    }
+}
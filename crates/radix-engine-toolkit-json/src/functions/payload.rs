@@ -0,0 +1,178 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::payload::{
+    DecompiledPayload, PayloadKind,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//===========================
+// Payload Decompile Unknown
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PayloadDecompileUnknownInput {
+    pub compiled: SerializableBytes,
+    pub instructions_kind: SerializableInstructionsKind,
+    /// Controls whether the output includes a classification of the payload
+    /// and a summary of its instructions.
+    #[serde(default)]
+    pub include_classification: bool,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct PayloadDecompileUnknownOutput {
+    pub payload: SerializableDecompiledPayload,
+    pub classification: Option<SerializablePayloadClassification>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableDecompiledPayload {
+    Intent(SerializableIntent),
+    SignedIntent(SerializableSignedIntent),
+    NotarizedTransaction(SerializableNotarizedTransaction),
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializablePayloadClassification {
+    pub kind: SerializablePayloadKind,
+    pub instructions: Vec<SerializableInstructionSummary>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub enum SerializablePayloadKind {
+    Intent,
+    SignedIntent,
+    NotarizedTransaction,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableInstructionSummary {
+    pub kind: String,
+    pub addresses: Vec<SerializableNodeId>,
+}
+
+impl From<PayloadKind> for SerializablePayloadKind {
+    fn from(value: PayloadKind) -> Self {
+        match value {
+            PayloadKind::Intent => Self::Intent,
+            PayloadKind::SignedIntent => Self::SignedIntent,
+            PayloadKind::NotarizedTransaction => Self::NotarizedTransaction,
+        }
+    }
+}
+
+pub struct PayloadDecompileUnknown;
+impl<'a> Function<'a> for PayloadDecompileUnknown {
+    type Input = PayloadDecompileUnknownInput;
+    type Output = PayloadDecompileUnknownOutput;
+
+    fn handle(
+        PayloadDecompileUnknownInput {
+            compiled,
+            instructions_kind,
+            include_classification,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let decompiled =
+            radix_engine_toolkit::functions::payload::decompile_unknown(
+                &**compiled,
+            )
+            .map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(compiled),
+                )
+            })?;
+        let network_id = decompiled.intent().header.network_id;
+
+        let classification =
+            include_classification.then(|| SerializablePayloadClassification {
+                kind: decompiled.kind().into(),
+                instructions:
+                    radix_engine_toolkit::functions::instructions::summarize(
+                        &decompiled.intent().instructions.0,
+                    )
+                    .into_iter()
+                    .map(|summary| SerializableInstructionSummary {
+                        kind: summary.kind.to_owned(),
+                        addresses: summary
+                            .addresses
+                            .into_iter()
+                            .map(|address| {
+                                SerializableNodeId::new(
+                                    *address.as_node_id(),
+                                    network_id,
+                                )
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            });
+
+        let payload = match decompiled {
+            DecompiledPayload::Intent(intent) => {
+                SerializableDecompiledPayload::Intent(
+                    SerializableIntent::from_native(
+                        &intent,
+                        network_id,
+                        instructions_kind,
+                    )?,
+                )
+            }
+            DecompiledPayload::SignedIntent(signed_intent) => {
+                SerializableDecompiledPayload::SignedIntent(
+                    SerializableSignedIntent::from_native(
+                        &signed_intent,
+                        network_id,
+                        instructions_kind,
+                    )?,
+                )
+            }
+            DecompiledPayload::NotarizedTransaction(notarized_transaction) => {
+                SerializableDecompiledPayload::NotarizedTransaction(
+                    SerializableNotarizedTransaction::from_native(
+                        &notarized_transaction,
+                        network_id,
+                        instructions_kind,
+                    )?,
+                )
+            }
+        };
+
+        Ok(PayloadDecompileUnknownOutput {
+            payload,
+            classification,
+        })
+    }
+}
+
+export_function!(PayloadDecompileUnknown as payload_decompile_unknown);
+export_jni_function!(PayloadDecompileUnknown as payloadDecompileUnknown);
+export_wasm_bindgen_function!(
+    PayloadDecompileUnknown as payloadDecompileUnknown
+);
@@ -0,0 +1,142 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::pool::*;
+use schemars::JsonSchema;
+use scrypto::prelude::{ComponentAddress, ResourceAddress};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializablePoolContribution {
+    pub resource_address: SerializableNodeId,
+    pub amount: SerializableDecimal,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializablePoolOperation {
+    Contribute {
+        pool_address: SerializableNodeId,
+        contributions: Vec<SerializablePoolContribution>,
+    },
+    Redeem {
+        pool_address: SerializableNodeId,
+        pool_unit_resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+}
+
+impl SerializablePoolOperation {
+    pub fn to_native(&self) -> Result<PoolOperation, SerializableNodeIdError> {
+        let operation = match self {
+            Self::Contribute {
+                pool_address,
+                contributions,
+            } => PoolOperation::Contribute {
+                pool_address: ComponentAddress::try_from(pool_address.clone())?,
+                contributions: contributions
+                    .iter()
+                    .map(|contribution| {
+                        ResourceAddress::try_from(
+                            contribution.resource_address.clone(),
+                        )
+                        .map(|address| (address, *contribution.amount))
+                    })
+                    .collect::<Result<_, _>>()?,
+            },
+            Self::Redeem {
+                pool_address,
+                pool_unit_resource_address,
+                amount,
+            } => PoolOperation::Redeem {
+                pool_address: ComponentAddress::try_from(pool_address.clone())?,
+                pool_unit_resource_address: ResourceAddress::try_from(
+                    pool_unit_resource_address.clone(),
+                )?,
+                amount: **amount,
+            },
+        };
+        Ok(operation)
+    }
+}
+
+//=====================
+// Build Pool Manifest
+//=====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildPoolManifestInput {
+    /// The account that resources are withdrawn from and that the pool units
+    /// and redeemed resources are deposited into.
+    pub account_address: SerializableNodeId,
+    pub operations: Vec<SerializablePoolOperation>,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+pub type BuildPoolManifestOutput = SerializableTransactionManifest;
+
+pub struct BuildPoolManifest;
+impl<'f> Function<'f> for BuildPoolManifest {
+    type Input = BuildPoolManifestInput;
+    type Output = BuildPoolManifestOutput;
+
+    fn handle(
+        BuildPoolManifestInput {
+            account_address,
+            operations,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let account_address =
+            ComponentAddress::try_from(account_address.clone())
+                .ok()
+                .filter(radix_engine_toolkit::utils::is_account)
+                .ok_or(InvocationHandlingError::InvalidAddress(
+                    account_address.0.to_string(),
+                ))?;
+        let operations = operations
+            .iter()
+            .map(SerializablePoolOperation::to_native)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| {
+                InvocationHandlingError::InvalidAddress(debug_string(error))
+            })?;
+
+        let manifest =
+            radix_engine_toolkit::functions::pool::build_pool_manifest(
+                account_address,
+                &operations,
+            )?;
+        Ok(SerializableTransactionManifest::from_native(
+            &manifest,
+            *network_id,
+            instructions_kind,
+        )?)
+    }
+}
+
+export_function!(BuildPoolManifest as build_pool_manifest);
+export_jni_function!(BuildPoolManifest as buildPoolManifest);
+export_wasm_bindgen_function!(BuildPoolManifest as buildPoolManifest);
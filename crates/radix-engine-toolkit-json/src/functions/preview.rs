@@ -0,0 +1,320 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::*;
+use radix_engine_toolkit::functions::preview::{
+    PreviewFlags, PreviewHeader, PreviewReceipt, PreviewReceiptStatus,
+    PreviewTransaction,
+};
+use radix_engine_toolkit::transaction_types::FeeSummary;
+use radix_engine_toolkit::utils::network_definition_from_network_id;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//===========================
+// Build Preview Transaction
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildPreviewTransactionInput {
+    pub manifest: SerializableTransactionManifest,
+    pub network_id: SerializableU8,
+    pub header: SerializablePreviewHeader,
+    #[serde(default)]
+    pub signer_public_keys: Vec<SerializablePublicKey>,
+    #[serde(default)]
+    pub flags: SerializablePreviewFlags,
+}
+
+/// The preview request in the shape accepted by the `/transaction/preview`
+/// endpoint of the Gateway API, which is why it uses plain numbers and the
+/// Gateway's public key representation.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildPreviewTransactionOutput {
+    pub manifest: String,
+    pub blobs_hex: Vec<SerializableBytes>,
+    pub start_epoch_inclusive: u64,
+    pub end_epoch_exclusive: u64,
+    pub notary_public_key: Option<SerializableGatewayPublicKey>,
+    pub notary_is_signatory: bool,
+    pub tip_percentage: u16,
+    pub nonce: u32,
+    pub signer_public_keys: Vec<SerializableGatewayPublicKey>,
+    pub flags: SerializablePreviewFlags,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializablePreviewHeader {
+    pub start_epoch_inclusive: SerializableU64,
+    pub end_epoch_exclusive: SerializableU64,
+    pub notary_public_key: Option<SerializablePublicKey>,
+    #[serde(default)]
+    pub notary_is_signatory: bool,
+    pub tip_percentage: SerializableU16,
+    pub nonce: SerializableU32,
+}
+
+#[typeshare::typeshare]
+#[derive(
+    Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq, Default,
+)]
+pub struct SerializablePreviewFlags {
+    #[serde(default)]
+    pub use_free_credit: bool,
+    #[serde(default)]
+    pub assume_all_signature_proofs: bool,
+    #[serde(default)]
+    pub skip_epoch_check: bool,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "key_type")]
+pub enum SerializableGatewayPublicKey {
+    EcdsaSecp256k1 {
+        key_hex: SerializableSecp256k1PublicKey,
+    },
+    EddsaEd25519 {
+        key_hex: SerializableEd25519PublicKey,
+    },
+}
+
+impl From<PublicKey> for SerializableGatewayPublicKey {
+    fn from(value: PublicKey) -> Self {
+        match value {
+            PublicKey::Secp256k1(public_key) => Self::EcdsaSecp256k1 {
+                key_hex: public_key.into(),
+            },
+            PublicKey::Ed25519(public_key) => Self::EddsaEd25519 {
+                key_hex: public_key.into(),
+            },
+        }
+    }
+}
+
+impl From<SerializablePreviewHeader> for PreviewHeader {
+    fn from(value: SerializablePreviewHeader) -> Self {
+        Self {
+            start_epoch_inclusive: Epoch::of(*value.start_epoch_inclusive),
+            end_epoch_exclusive: Epoch::of(*value.end_epoch_exclusive),
+            notary_public_key: value.notary_public_key.map(Into::into),
+            notary_is_signatory: value.notary_is_signatory,
+            tip_percentage: *value.tip_percentage,
+            nonce: *value.nonce,
+        }
+    }
+}
+
+impl From<SerializablePreviewFlags> for PreviewFlags {
+    fn from(value: SerializablePreviewFlags) -> Self {
+        Self {
+            use_free_credit: value.use_free_credit,
+            assume_all_signature_proofs: value.assume_all_signature_proofs,
+            skip_epoch_check: value.skip_epoch_check,
+        }
+    }
+}
+
+impl From<PreviewFlags> for SerializablePreviewFlags {
+    fn from(value: PreviewFlags) -> Self {
+        Self {
+            use_free_credit: value.use_free_credit,
+            assume_all_signature_proofs: value.assume_all_signature_proofs,
+            skip_epoch_check: value.skip_epoch_check,
+        }
+    }
+}
+
+impl From<PreviewTransaction> for BuildPreviewTransactionOutput {
+    fn from(value: PreviewTransaction) -> Self {
+        Self {
+            manifest: value.manifest,
+            blobs_hex: value.blobs.into_iter().map(Into::into).collect(),
+            start_epoch_inclusive: value.header.start_epoch_inclusive.number(),
+            end_epoch_exclusive: value.header.end_epoch_exclusive.number(),
+            notary_public_key: value.header.notary_public_key.map(Into::into),
+            notary_is_signatory: value.header.notary_is_signatory,
+            tip_percentage: value.header.tip_percentage,
+            nonce: value.header.nonce,
+            signer_public_keys: value
+                .signer_public_keys
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            flags: value.flags.into(),
+        }
+    }
+}
+
+pub struct BuildPreviewTransaction;
+impl<'f> Function<'f> for BuildPreviewTransaction {
+    type Input = BuildPreviewTransactionInput;
+    type Output = BuildPreviewTransactionOutput;
+
+    fn handle(
+        BuildPreviewTransactionInput {
+            manifest,
+            network_id,
+            header,
+            signer_public_keys,
+            flags,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let manifest = manifest.to_native(*network_id)?;
+        let network_definition =
+            network_definition_from_network_id(*network_id);
+        let preview_transaction =
+            radix_engine_toolkit::functions::preview::build_preview_transaction(
+            &manifest,
+            header.into(),
+            signer_public_keys.into_iter().map(Into::into).collect(),
+            flags.into(),
+            &network_definition,
+        )?;
+        Ok(preview_transaction.into())
+    }
+}
+
+export_function!(BuildPreviewTransaction as build_preview_transaction);
+export_jni_function!(BuildPreviewTransaction as buildPreviewTransaction);
+export_wasm_bindgen_function!(
+    BuildPreviewTransaction as buildPreviewTransaction
+);
+
+//=======================
+// Parse Preview Receipt
+//=======================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ParsePreviewReceiptInput {
+    /// The SBOR encoded receipt as returned in the `encoded_receipt` field
+    /// of the preview response.
+    pub encoded_receipt: SerializableBytes,
+    pub network_id: SerializableU8,
+}
+#[typeshare::typeshare]
+pub type ParsePreviewReceiptOutput = SerializablePreviewReceipt;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializablePreviewReceipt {
+    pub status: SerializablePreviewReceiptStatus,
+    pub fee_summary: SerializableFeeSummary,
+    pub new_package_addresses: Vec<SerializableNodeId>,
+    pub new_component_addresses: Vec<SerializableNodeId>,
+    pub new_resource_addresses: Vec<SerializableNodeId>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializablePreviewReceiptStatus {
+    Succeeded,
+    Failed(String),
+    Rejected(String),
+    Aborted(String),
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableFeeSummary {
+    pub execution_cost: SerializableDecimal,
+    pub finalization_cost: SerializableDecimal,
+    pub storage_expansion_cost: SerializableDecimal,
+    pub royalty_cost: SerializableDecimal,
+    pub tipping_cost: SerializableDecimal,
+}
+
+impl From<PreviewReceiptStatus> for SerializablePreviewReceiptStatus {
+    fn from(value: PreviewReceiptStatus) -> Self {
+        match value {
+            PreviewReceiptStatus::Succeeded => Self::Succeeded,
+            PreviewReceiptStatus::Failed(reason) => Self::Failed(reason),
+            PreviewReceiptStatus::Rejected(reason) => Self::Rejected(reason),
+            PreviewReceiptStatus::Aborted(reason) => Self::Aborted(reason),
+        }
+    }
+}
+
+impl From<FeeSummary> for SerializableFeeSummary {
+    fn from(value: FeeSummary) -> Self {
+        Self {
+            execution_cost: value.execution_cost.into(),
+            finalization_cost: value.finalization_cost.into(),
+            storage_expansion_cost: value.storage_expansion_cost.into(),
+            royalty_cost: value.royalty_cost.into(),
+            tipping_cost: value.tipping_cost.into(),
+        }
+    }
+}
+
+impl SerializablePreviewReceipt {
+    pub fn new(receipt: PreviewReceipt, network_id: u8) -> Self {
+        Self {
+            status: receipt.status.into(),
+            fee_summary: receipt.fee_summary.into(),
+            new_package_addresses: receipt
+                .new_package_addresses
+                .into_iter()
+                .map(|address| {
+                    SerializableNodeId::from_global_address(address, network_id)
+                })
+                .collect(),
+            new_component_addresses: receipt
+                .new_component_addresses
+                .into_iter()
+                .map(|address| {
+                    SerializableNodeId::from_global_address(address, network_id)
+                })
+                .collect(),
+            new_resource_addresses: receipt
+                .new_resource_addresses
+                .into_iter()
+                .map(|address| {
+                    SerializableNodeId::from_global_address(address, network_id)
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct ParsePreviewReceipt;
+impl<'f> Function<'f> for ParsePreviewReceipt {
+    type Input = ParsePreviewReceiptInput;
+    type Output = ParsePreviewReceiptOutput;
+
+    fn handle(
+        ParsePreviewReceiptInput {
+            encoded_receipt,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let receipt =
+            radix_engine_toolkit::functions::preview::parse_preview_receipt(
+                &*encoded_receipt,
+            )?;
+        Ok(SerializablePreviewReceipt::new(receipt, *network_id))
+    }
+}
+
+export_function!(ParsePreviewReceipt as parse_preview_receipt);
+export_jni_function!(ParsePreviewReceipt as parsePreviewReceipt);
+export_wasm_bindgen_function!(ParsePreviewReceipt as parsePreviewReceipt);
@@ -0,0 +1,121 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::PublicKey;
+use radix_engine_toolkit::functions::random;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//==============================
+// Generate Secure Random Nonce
+//==============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct GenerateSecureRandomNonceInput {}
+#[typeshare::typeshare]
+pub type GenerateSecureRandomNonceOutput = SerializableU32;
+
+pub struct GenerateSecureRandomNonce;
+impl<'f> Function<'f> for GenerateSecureRandomNonce {
+    type Input = GenerateSecureRandomNonceInput;
+    type Output = GenerateSecureRandomNonceOutput;
+
+    fn handle(
+        _: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let nonce = random::generate_secure_random_nonce()?;
+        Ok(nonce.into())
+    }
+}
+
+export_function!(GenerateSecureRandomNonce as generate_secure_random_nonce);
+export_jni_function!(GenerateSecureRandomNonce as generateSecureRandomNonce);
+export_wasm_bindgen_function!(
+    GenerateSecureRandomNonce as generateSecureRandomNonce
+);
+
+//===========================
+// Generate Ed25519 Key Pair
+//===========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct GenerateEd25519KeypairInput {}
+#[typeshare::typeshare]
+pub type GenerateEd25519KeypairOutput = SerializableGeneratedKeypair;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableGeneratedKeypair {
+    pub private_key: SerializableBytes,
+    pub public_key: SerializablePublicKey,
+}
+
+pub struct GenerateEd25519Keypair;
+impl<'f> Function<'f> for GenerateEd25519Keypair {
+    type Input = GenerateEd25519KeypairInput;
+    type Output = GenerateEd25519KeypairOutput;
+
+    fn handle(
+        _: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let private_key = random::generate_ed25519_keypair()?;
+        Ok(SerializableGeneratedKeypair {
+            private_key: private_key.to_bytes().into(),
+            public_key: PublicKey::from(private_key.public_key()).into(),
+        })
+    }
+}
+
+export_function!(GenerateEd25519Keypair as generate_ed25519_keypair);
+export_jni_function!(GenerateEd25519Keypair as generateEd25519Keypair);
+export_wasm_bindgen_function!(GenerateEd25519Keypair as generateEd25519Keypair);
+
+//=============================
+// Generate Secp256k1 Key Pair
+//=============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct GenerateSecp256k1KeypairInput {}
+#[typeshare::typeshare]
+pub type GenerateSecp256k1KeypairOutput = SerializableGeneratedKeypair;
+
+pub struct GenerateSecp256k1Keypair;
+impl<'f> Function<'f> for GenerateSecp256k1Keypair {
+    type Input = GenerateSecp256k1KeypairInput;
+    type Output = GenerateSecp256k1KeypairOutput;
+
+    fn handle(
+        _: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let private_key = random::generate_secp256k1_keypair()?;
+        Ok(SerializableGeneratedKeypair {
+            private_key: private_key.to_bytes().into(),
+            public_key: PublicKey::from(private_key.public_key()).into(),
+        })
+    }
+}
+
+export_function!(GenerateSecp256k1Keypair as generate_secp256k1_keypair);
+export_jni_function!(GenerateSecp256k1Keypair as generateSecp256k1Keypair);
+export_wasm_bindgen_function!(
+    GenerateSecp256k1Keypair as generateSecp256k1Keypair
+);
@@ -0,0 +1,159 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_toolkit::functions::rola::*;
+use radix_engine_toolkit::functions::signing::{
+    public_key_from_bytes, signature_from_bytes,
+};
+use schemars::JsonSchema;
+use scrypto::prelude::{hash, ComponentAddress};
+use serde::{Deserialize, Serialize};
+
+//==============
+// Rola Message
+//==============
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct RolaMessageInput {
+    pub challenge: SerializableBytes,
+    pub dapp_definition_address: String,
+    pub origin: String,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct RolaMessageOutput {
+    pub message: SerializableBytes,
+    /// The hash of the message which is what the wallet signs.
+    pub hash: SerializableHash,
+}
+
+pub struct RolaMessage;
+impl<'f> Function<'f> for RolaMessage {
+    type Input = RolaMessageInput;
+    type Output = RolaMessageOutput;
+
+    fn handle(
+        RolaMessageInput {
+            challenge,
+            dapp_definition_address,
+            origin,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let message = radix_engine_toolkit::functions::rola::rola_message(
+            &challenge,
+            &dapp_definition_address,
+            &origin,
+        )?;
+        let hash = hash(&message);
+
+        Ok(Self::Output {
+            message: message.into(),
+            hash: hash.into(),
+        })
+    }
+}
+
+export_function!(RolaMessage as rola_message);
+export_jni_function!(RolaMessage as rolaMessage);
+export_wasm_bindgen_function!(RolaMessage as rolaMessage);
+
+//=======================
+// Rola Verify Signature
+//=======================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct RolaVerifySignatureInput {
+    pub challenge: SerializableBytes,
+    pub dapp_definition_address: String,
+    pub origin: String,
+    /// The account or identity that the signature proves ownership of.
+    pub entity_address: SerializableNodeId,
+    pub public_key: SerializableBytes,
+    pub signature: SerializableBytes,
+    pub curve: SerializableCurveType,
+    /// The `owner_keys` metadata of the entity. When not provided the public
+    /// key must be the one that the virtual entity address was derived from.
+    pub owner_keys: Option<SerializableMetadataValue>,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum RolaVerifySignatureOutput {
+    Valid,
+    Invalid(String),
+}
+
+pub struct RolaVerifySignature;
+impl<'f> Function<'f> for RolaVerifySignature {
+    type Input = RolaVerifySignatureInput;
+    type Output = RolaVerifySignatureOutput;
+
+    fn handle(
+        RolaVerifySignatureInput {
+            challenge,
+            dapp_definition_address,
+            origin,
+            entity_address,
+            public_key,
+            signature,
+            curve,
+            owner_keys,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let entity_address = ComponentAddress::try_from(entity_address.clone())
+            .map_err(|_| {
+                InvocationHandlingError::InvalidAddress(
+                    entity_address.0.to_string(),
+                )
+            })?;
+        let public_key = public_key_from_bytes(&public_key, curve.into())?;
+        let signature = signature_from_bytes(&signature, curve.into())?;
+        let owner_keys = owner_keys
+            .map(|owner_keys| owner_keys.to_native())
+            .transpose()
+            .map_err(|error| {
+                InvocationHandlingError::InvalidAddress(debug_string(error))
+            })?;
+
+        match verify_rola_signature(
+            &challenge,
+            &dapp_definition_address,
+            &origin,
+            &entity_address,
+            &public_key,
+            &signature,
+            owner_keys.as_ref(),
+        ) {
+            Ok(()) => Ok(Self::Output::Valid),
+            Err(
+                error @ (RolaError::InvalidChallengeLength { .. }
+                | RolaError::DappDefinitionAddressTooLong { .. }),
+            ) => Err(error.into()),
+            Err(error) => Ok(Self::Output::Invalid(debug_string(error))),
+        }
+    }
+}
+
+export_function!(RolaVerifySignature as rola_verify_signature);
+export_jni_function!(RolaVerifySignature as rolaVerifySignature);
+export_wasm_bindgen_function!(RolaVerifySignature as rolaVerifySignature);
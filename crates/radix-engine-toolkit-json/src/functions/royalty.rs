@@ -0,0 +1,84 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::royalty::*;
+use schemars::JsonSchema;
+use scrypto::prelude::ComponentAddress;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//=========================================
+// Build Set Component Royalty Instruction
+//=========================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildSetComponentRoyaltyInstructionInput {
+    pub component_address: SerializableNodeId,
+    pub method: String,
+    pub amount: SerializableRoyaltyAmount,
+    pub network_id: SerializableU8,
+}
+
+#[typeshare::typeshare]
+pub type BuildSetComponentRoyaltyInstructionOutput = SerializableInstruction;
+
+pub struct BuildSetComponentRoyaltyInstruction;
+impl<'f> Function<'f> for BuildSetComponentRoyaltyInstruction {
+    type Input = BuildSetComponentRoyaltyInstructionInput;
+    type Output = BuildSetComponentRoyaltyInstructionOutput;
+
+    fn handle(
+        BuildSetComponentRoyaltyInstructionInput {
+            component_address,
+            method,
+            amount,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let component_address = ComponentAddress::try_from(
+            component_address.clone(),
+        )
+        .map_err(|_| {
+            InvocationHandlingError::InvalidAddress(
+                component_address.0.to_string(),
+            )
+        })?;
+
+        let instruction =
+            set_component_royalty(component_address, &method, amount.into());
+        SerializableInstruction::from_instruction(&instruction, *network_id)
+            .map_err(|error| LocatedInstructionConversionError {
+                instruction_index: 0,
+                error,
+            })
+            .map_err(SerializableInstructionsError::from)
+            .map_err(InvocationHandlingError::from)
+    }
+}
+
+export_function!(
+    BuildSetComponentRoyaltyInstruction
+        as build_set_component_royalty_instruction
+);
+export_jni_function!(
+    BuildSetComponentRoyaltyInstruction as buildSetComponentRoyaltyInstruction
+);
+export_wasm_bindgen_function!(
+    BuildSetComponentRoyaltyInstruction as buildSetComponentRoyaltyInstruction
+);
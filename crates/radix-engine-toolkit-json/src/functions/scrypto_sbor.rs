@@ -20,7 +20,12 @@ use std::ops::Deref;
 use crate::prelude::*;
 
 use radix_common::prelude::*;
-use radix_engine_toolkit::utils::*;
+use radix_engine_toolkit::functions::scrypto_sbor::{
+    decode_to_events, decode_to_string_representation, ScryptoSborError,
+};
+use radix_engine_toolkit::sbor::scrypto_event_stream::ScryptoSborEvent;
+use radix_engine_toolkit::caches::bech32_coders;
+use sbor::representations::SerializationMode;
 use sbor::{LocalTypeId, Schema};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -76,14 +81,13 @@ impl<'f> Function<'f> for ScryptoSborDecodeToString {
             } else {
                 None
             };
-        let network_definition = network_definition_from_network_id(network_id);
-        let bech32_encoder = AddressBech32Encoder::new(&network_definition);
+        let coders = bech32_coders(network_id);
 
         let string =
             radix_engine_toolkit::functions::scrypto_sbor::decode_to_string_representation(
                 encoded_payload,
                 representation,
-                &bech32_encoder,
+                &coders.address_encoder,
                 schema,
             )?;
 
@@ -93,6 +97,192 @@ impl<'f> Function<'f> for ScryptoSborDecodeToString {
 
 export_function!(ScryptoSborDecodeToString as scrypto_sbor_decode_to_string);
 export_jni_function!(ScryptoSborDecodeToString as scryptoSborDecodeToString);
+export_wasm_bindgen_function!(ScryptoSborDecodeToString as scryptoSborDecodeToString);
+
+//====================
+// Scrypto Sbor Query
+//====================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ScryptoSborQueryInput {
+    encoded_payload: SerializableBytes,
+    /// The path of the sub-values to return, e.g., `$.0[*]#1.0`.
+    path: String,
+    representation: SerializableSerializationMode,
+    network_id: SerializableU8,
+}
+#[typeshare::typeshare]
+pub type ScryptoSborQueryOutput = Vec<String>;
+
+pub struct ScryptoSborQuery;
+impl<'f> Function<'f> for ScryptoSborQuery {
+    type Input = ScryptoSborQueryInput;
+    type Output = ScryptoSborQueryOutput;
+
+    fn handle(
+        ScryptoSborQueryInput {
+            encoded_payload,
+            path,
+            representation,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let representation = representation.into();
+        let coders = bech32_coders(*network_id);
+
+        let values = radix_engine_toolkit::functions::scrypto_sbor::query(
+            encoded_payload.deref(),
+            &path,
+        )?;
+        values
+            .iter()
+            .map(|value| {
+                let encoded_value = scrypto_encode(value).map_err(|error| {
+                    InvocationHandlingError::EncodeError(
+                        debug_string(error),
+                        debug_string(value),
+                    )
+                })?;
+                decode_to_string_representation(
+                    encoded_value,
+                    representation,
+                    &coders.address_encoder,
+                    None,
+                )
+                .map_err(Into::into)
+            })
+            .collect()
+    }
+}
+
+export_function!(ScryptoSborQuery as scrypto_sbor_query);
+export_jni_function!(ScryptoSborQuery as scryptoSborQuery);
+export_wasm_bindgen_function!(ScryptoSborQuery as scryptoSborQuery);
+
+//===============================
+// Scrypto Sbor Decode to Events
+//===============================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct ScryptoSborDecodeToEventsInput {
+    encoded_payload: SerializableBytes,
+    representation: SerializableSerializationMode,
+    network_id: SerializableU8,
+}
+#[typeshare::typeshare]
+pub type ScryptoSborDecodeToEventsOutput = Vec<SerializableScryptoSborEvent>;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableScryptoSborEvent {
+    TupleStart {
+        length: SerializableU64,
+    },
+    EnumVariantStart {
+        discriminator: SerializableU8,
+        length: SerializableU64,
+    },
+    ArrayStart {
+        element_value_kind: SerializableScryptoValueKind,
+        length: SerializableU64,
+    },
+    MapStart {
+        key_value_kind: SerializableScryptoValueKind,
+        value_value_kind: SerializableScryptoValueKind,
+        length: SerializableU64,
+    },
+    ContainerEnd {
+        value_kind: SerializableScryptoValueKind,
+    },
+    Value {
+        value: String,
+    },
+    Bytes {
+        value: SerializableBytes,
+    },
+}
+
+pub struct ScryptoSborDecodeToEvents;
+impl<'f> Function<'f> for ScryptoSborDecodeToEvents {
+    type Input = ScryptoSborDecodeToEventsInput;
+    type Output = ScryptoSborDecodeToEventsOutput;
+
+    fn handle(
+        ScryptoSborDecodeToEventsInput {
+            encoded_payload,
+            representation,
+            network_id,
+        }: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let coders = bech32_coders(*network_id);
+        let representation = SerializationMode::from(representation);
+
+        decode_to_events(&encoded_payload)
+            .map(|event| -> Result<_, InvocationHandlingError> {
+                let event = event.map_err(ScryptoSborError::DecodeError)?;
+                let event = match event {
+                    ScryptoSborEvent::TupleStart { length } => {
+                        SerializableScryptoSborEvent::TupleStart {
+                            length: (length as u64).into(),
+                        }
+                    }
+                    ScryptoSborEvent::EnumVariantStart {
+                        discriminator,
+                        length,
+                    } => SerializableScryptoSborEvent::EnumVariantStart {
+                        discriminator: discriminator.into(),
+                        length: (length as u64).into(),
+                    },
+                    ScryptoSborEvent::ArrayStart {
+                        element_value_kind,
+                        length,
+                    } => SerializableScryptoSborEvent::ArrayStart {
+                        element_value_kind: element_value_kind.into(),
+                        length: (length as u64).into(),
+                    },
+                    ScryptoSborEvent::MapStart {
+                        key_value_kind,
+                        value_value_kind,
+                        length,
+                    } => SerializableScryptoSborEvent::MapStart {
+                        key_value_kind: key_value_kind.into(),
+                        value_value_kind: value_value_kind.into(),
+                        length: (length as u64).into(),
+                    },
+                    ScryptoSborEvent::ContainerEnd { value_kind } => {
+                        SerializableScryptoSborEvent::ContainerEnd {
+                            value_kind: value_kind.into(),
+                        }
+                    }
+                    ScryptoSborEvent::Value(value) => {
+                        let encoded = scrypto_encode(&value)
+                            .map_err(ScryptoSborError::EncodeError)?;
+                        let value = decode_to_string_representation(
+                            encoded,
+                            representation,
+                            &coders.address_encoder,
+                            None,
+                        )?;
+                        SerializableScryptoSborEvent::Value { value }
+                    }
+                    ScryptoSborEvent::Bytes(bytes) => {
+                        SerializableScryptoSborEvent::Bytes {
+                            value: bytes.into(),
+                        }
+                    }
+                };
+                Ok(event)
+            })
+            .collect()
+    }
+}
+
+export_function!(ScryptoSborDecodeToEvents as scrypto_sbor_decode_to_events);
+export_jni_function!(ScryptoSborDecodeToEvents as scryptoSborDecodeToEvents);
+export_wasm_bindgen_function!(ScryptoSborDecodeToEvents as scryptoSborDecodeToEvents);
 
 #[typeshare::typeshare]
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
@@ -137,3 +327,7 @@ export_jni_function!(
     ScryptoSborEncodeStringRepresentation
         as scryptoSborEncodeStringRepresentation
 );
+export_wasm_bindgen_function!(
+    ScryptoSborEncodeStringRepresentation
+        as scryptoSborEncodeStringRepresentation
+);
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit::functions::limits::check_limits;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -54,13 +55,64 @@ impl<'f> Function<'f> for SignedIntentHash {
 
 export_function!(SignedIntentHash as signed_intent_hash);
 export_jni_function!(SignedIntentHash as signedIntentHash);
+export_wasm_bindgen_function!(SignedIntentHash as signedIntentHash);
+
+//==================================
+// Signed Intent Hash from Compiled
+//==================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SignedIntentHashFromCompiledInput {
+    pub compiled: SerializableBytes,
+}
+#[typeshare::typeshare]
+pub type SignedIntentHashFromCompiledOutput = SerializableTransactionHash;
+
+pub struct SignedIntentHashFromCompiled;
+impl<'a> Function<'a> for SignedIntentHashFromCompiled {
+    type Input = SignedIntentHashFromCompiledInput;
+    type Output = SignedIntentHashFromCompiledOutput;
+
+    fn handle(
+        SignedIntentHashFromCompiledInput { compiled }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let hash =
+            radix_engine_toolkit::functions::signed_intent::hash_from_payload(
+                &**compiled,
+            )
+            .map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(compiled),
+                )
+            })?;
+        Ok(hash.into())
+    }
+}
+
+export_function!(
+    SignedIntentHashFromCompiled as signed_intent_hash_from_compiled
+);
+export_jni_function!(
+    SignedIntentHashFromCompiled as signedIntentHashFromCompiled
+);
+export_wasm_bindgen_function!(
+    SignedIntentHashFromCompiled as signedIntentHashFromCompiled
+);
 
 //=======================
 // Signed Intent Compile
 //=======================
 
-#[typeshare::typeshare]
-pub type SignedIntentCompileInput = SerializableSignedIntent;
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SignedIntentCompileInput {
+    #[serde(flatten)]
+    pub signed_intent: SerializableSignedIntent,
+    /// The limits that the compiled payload is checked against. No limits are
+    /// checked when absent.
+    pub limits: Option<SerializableCompilationLimitsSource>,
+}
 #[typeshare::typeshare]
 pub type SignedIntentCompileOutput = SerializableBytes;
 
@@ -70,10 +122,14 @@ impl<'f> Function<'f> for SignedIntentCompile {
     type Output = SignedIntentCompileOutput;
 
     fn handle(
-        signed_intent: Self::Input,
+        SignedIntentCompileInput {
+            signed_intent,
+            limits,
+        }: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
         let signed_intent =
             signed_intent.to_native(*signed_intent.intent.header.network_id)?;
+        let instruction_count = signed_intent.intent.instructions.0.len();
         let compile = radix_engine_toolkit::functions::signed_intent::compile(
             &signed_intent,
         )
@@ -83,12 +139,20 @@ impl<'f> Function<'f> for SignedIntentCompile {
                 debug_string(signed_intent),
             )
         })?;
+        if let Some(limits) = limits {
+            check_limits(
+                &compile,
+                instruction_count,
+                &limits.to_native(signed_intent.intent.header.network_id)?,
+            )?;
+        }
         Ok(compile.into())
     }
 }
 
 export_function!(SignedIntentCompile as signed_intent_compile);
 export_jni_function!(SignedIntentCompile as signedIntentCompile);
+export_wasm_bindgen_function!(SignedIntentCompile as signedIntentCompile);
 
 //=========================
 // Signed Intent Decompile
@@ -99,6 +163,10 @@ export_jni_function!(SignedIntentCompile as signedIntentCompile);
 pub struct SignedIntentDecompileInput {
     pub compiled: SerializableBytes,
     pub instructions_kind: SerializableInstructionsKind,
+    /// Whether the manifest string is annotated with comments that describe
+    /// the instructions. Has no effect on parsed instructions.
+    #[serde(default)]
+    pub annotate: bool,
 }
 #[typeshare::typeshare]
 pub type SignedIntentDecompileOutput = SerializableSignedIntent;
@@ -112,6 +180,7 @@ impl<'a> Function<'a> for SignedIntentDecompile {
         SignedIntentDecompileInput {
             compiled,
             instructions_kind,
+            annotate,
         }: Self::Input,
     ) -> Result<Self::Output, InvocationHandlingError> {
         let signed_intent =
@@ -125,18 +194,30 @@ impl<'a> Function<'a> for SignedIntentDecompile {
                 )
             })?;
 
-        let signed_intent = SerializableSignedIntent::from_native(
-            &signed_intent,
-            signed_intent.intent.header.network_id,
-            instructions_kind,
-        )?;
+        let mut serializable_signed_intent =
+            SerializableSignedIntent::from_native(
+                &signed_intent,
+                signed_intent.intent.header.network_id,
+                instructions_kind,
+            )?;
+        if annotate {
+            serializable_signed_intent
+                .intent
+                .manifest
+                .instructions
+                .annotate(
+                    &signed_intent.intent.instructions.0,
+                    signed_intent.intent.header.network_id,
+                )?;
+        }
 
-        Ok(signed_intent)
+        Ok(serializable_signed_intent)
     }
 }
 
 export_function!(SignedIntentDecompile as signed_intent_decompile);
 export_jni_function!(SignedIntentDecompile as signedIntentDecompile);
+export_wasm_bindgen_function!(SignedIntentDecompile as signedIntentDecompile);
 
 //===================================
 // Signed Intent Statically Validate
@@ -188,3 +269,108 @@ export_function!(
 export_jni_function!(
     SignedIntentStaticallyValidate as signedIntentStaticallyValidate
 );
+export_wasm_bindgen_function!(
+    SignedIntentStaticallyValidate as signedIntentStaticallyValidate
+);
+
+//========================
+// Signed Intent Notarize
+//========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SignedIntentNotarizeInput {
+    pub compiled_signed_intent: SerializableBytes,
+    pub notary_private_key: SerializableBytes,
+    pub curve: SerializableCurveType,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SignedIntentNotarizeOutput {
+    pub compiled_notarized_transaction: SerializableBytes,
+    pub intent_hash: SerializableTransactionHash,
+    pub notarized_transaction_hash: SerializableTransactionHash,
+}
+
+pub struct SignedIntentNotarize;
+impl<'a> Function<'a> for SignedIntentNotarize {
+    type Input = SignedIntentNotarizeInput;
+    type Output = SignedIntentNotarizeOutput;
+
+    fn handle(
+        SignedIntentNotarizeInput {
+            compiled_signed_intent,
+            notary_private_key,
+            curve,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let signed_intent =
+            radix_engine_toolkit::functions::signed_intent::decompile(
+                &**compiled_signed_intent,
+            )
+            .map_err(|error| {
+                InvocationHandlingError::DecodeError(
+                    debug_string(error),
+                    debug_string(compiled_signed_intent),
+                )
+            })?;
+        let notary_private_key =
+            radix_engine_toolkit::functions::signing::private_key_from_bytes(
+                &notary_private_key,
+                curve.into(),
+            )?;
+
+        let notarized_transaction =
+            radix_engine_toolkit::functions::signed_intent::notarize(
+                signed_intent.clone(),
+                &notary_private_key,
+            )
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(signed_intent),
+                )
+            })?;
+        let intent_hash = radix_engine_toolkit::functions::intent::hash(
+            &notarized_transaction.signed_intent.intent,
+        )
+        .map_err(|error| {
+            InvocationHandlingError::EncodeError(
+                debug_string(error),
+                debug_string(&notarized_transaction),
+            )
+        })?;
+        let notarized_transaction_hash =
+            radix_engine_toolkit::functions::notarized_transaction::hash(
+                &notarized_transaction,
+            )
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(&notarized_transaction),
+                )
+            })?;
+        let compiled_notarized_transaction =
+            radix_engine_toolkit::functions::notarized_transaction::compile(
+                &notarized_transaction,
+            )
+            .map_err(|error| {
+                InvocationHandlingError::EncodeError(
+                    debug_string(error),
+                    debug_string(&notarized_transaction),
+                )
+            })?;
+
+        Ok(Self::Output {
+            compiled_notarized_transaction: compiled_notarized_transaction
+                .into(),
+            intent_hash: intent_hash.into(),
+            notarized_transaction_hash: notarized_transaction_hash.into(),
+        })
+    }
+}
+
+export_function!(SignedIntentNotarize as signed_intent_notarize);
+export_jni_function!(SignedIntentNotarize as signedIntentNotarize);
+export_wasm_bindgen_function!(SignedIntentNotarize as signedIntentNotarize);
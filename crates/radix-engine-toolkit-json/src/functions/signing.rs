@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::signing::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//===========
+// Sign Hash
+//===========
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SignHashInput {
+    pub hash: SerializableHash,
+    pub private_key: SerializableBytes,
+    pub curve: SerializableCurveType,
+}
+#[typeshare::typeshare]
+pub type SignHashOutput = SerializableSignatureWithPublicKey;
+
+pub struct SignHash;
+impl<'f> Function<'f> for SignHash {
+    type Input = SignHashInput;
+    type Output = SignHashOutput;
+
+    fn handle(
+        SignHashInput {
+            hash,
+            private_key,
+            curve,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let private_key = private_key_from_bytes(&private_key, curve.into())?;
+        let signature = radix_engine_toolkit::functions::signing::sign_hash(
+            &hash,
+            &private_key,
+        );
+        Ok(signature.into())
+    }
+}
+
+export_function!(SignHash as sign_hash);
+export_jni_function!(SignHash as signHash);
+export_wasm_bindgen_function!(SignHash as signHash);
+
+//==================
+// Verify Signature
+//==================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct VerifySignatureInput {
+    pub hash: SerializableHash,
+    pub signature: SerializableBytes,
+    pub public_key: SerializableBytes,
+    pub curve: SerializableCurveType,
+}
+#[typeshare::typeshare]
+pub type VerifySignatureOutput = bool;
+
+pub struct VerifySignature;
+impl<'f> Function<'f> for VerifySignature {
+    type Input = VerifySignatureInput;
+    type Output = VerifySignatureOutput;
+
+    fn handle(
+        VerifySignatureInput {
+            hash,
+            signature,
+            public_key,
+            curve,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let signature = signature_from_bytes(&signature, curve.into())?;
+        let public_key = public_key_from_bytes(&public_key, curve.into())?;
+        Ok(radix_engine_toolkit::functions::signing::verify_signature(
+            &hash,
+            &signature,
+            &public_key,
+        ))
+    }
+}
+
+export_function!(VerifySignature as verify_signature);
+export_jni_function!(VerifySignature as verifySignature);
+export_wasm_bindgen_function!(VerifySignature as verifySignature);
@@ -19,6 +19,8 @@ use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::warning::Warning;
+
 pub trait Function<'a> {
     type Input: Serialize + Deserialize<'a> + DeserializeOwned + JsonSchema;
     type Output: Serialize + Deserialize<'a> + DeserializeOwned + JsonSchema;
@@ -26,4 +28,25 @@ pub trait Function<'a> {
     fn handle(
         input: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError>;
+
+    /// Handles the invocation while reporting any non-fatal warnings to the
+    /// caller. Functions that have warnings to report override this method,
+    /// the default implementation reports no warnings.
+    fn handle_with_warnings(
+        input: Self::Input,
+        _warnings: &mut Vec<Warning>,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Self::handle(input)
+    }
+
+    /// Converts an input written against the given version of the request
+    /// schema to the next version. Functions whose input changed between the
+    /// versions override this method, the default implementation leaves the
+    /// input as it is.
+    fn upgrade_input(
+        _input: &mut serde_json::Value,
+        _from_version: u32,
+        _warnings: &mut Vec<Warning>,
+    ) {
+    }
 }
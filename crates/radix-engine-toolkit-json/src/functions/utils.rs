@@ -39,58 +39,137 @@ impl<'f> Function<'f> for UtilsKnownAddress {
     fn handle(
         input: Self::Input,
     ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
-        let network_id = *input;
-
-        let resource_addresses = construct_addresses! {
-            ResourceAddresses,
-            network_id,
-            [
-                xrd,
-                secp256k1_signature_virtual_badge,
-                ed25519_signature_virtual_badge,
-                package_of_direct_caller_virtual_badge,
-                global_caller_virtual_badge,
-                system_transaction_badge,
-                package_owner_badge,
-                validator_owner_badge,
-                account_owner_badge,
-                identity_owner_badge,
-            ]
-        };
-        let package_addresses = construct_addresses! {
-            PackageAddresses,
-            network_id,
-            [
-                package_package,
-                resource_package,
-                account_package,
-                identity_package,
-                consensus_manager_package,
-                access_controller_package,
-                pool_package,
-                transaction_processor_package,
-                metadata_module_package,
-                royalty_module_package,
-                role_assignment_module_package,
-                genesis_helper_package,
-                faucet_package,
-            ]
-        };
-        let component_addresses = construct_addresses! {
-            ComponentAddresses,
-            network_id,
-            [
-                consensus_manager,
-                genesis_helper,
-                faucet,
-            ]
-        };
-
-        Ok(Self::Output {
-            component_addresses,
-            package_addresses,
-            resource_addresses,
-        })
+        Ok(known_addresses(*input))
+    }
+}
+
+export_function!(UtilsKnownAddress as utils_known_addresses);
+export_jni_function!(UtilsKnownAddress as utilsKnownAddresses);
+export_wasm_bindgen_function!(UtilsKnownAddress as utilsKnownAddresses);
+
+#[typeshare::typeshare]
+pub type UtilsKnownAddressesForNetworksInput = Vec<SerializableU8>;
+
+#[typeshare::typeshare]
+pub type UtilsKnownAddressesForNetworksOutput = Vec<KnownAddressesOfNetwork>;
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct KnownAddressesOfNetwork {
+    pub network_id: SerializableU8,
+    pub known_addresses: UtilsKnownAddressesOutput,
+}
+
+pub struct UtilsKnownAddressesForNetworks;
+impl<'f> Function<'f> for UtilsKnownAddressesForNetworks {
+    type Input = UtilsKnownAddressesForNetworksInput;
+    type Output = UtilsKnownAddressesForNetworksOutput;
+
+    /// Returns the known addresses of each of the networks in the order that
+    /// the networks were given in.
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        Ok(input
+            .into_iter()
+            .map(|network_id| KnownAddressesOfNetwork {
+                network_id,
+                known_addresses: known_addresses(*network_id),
+            })
+            .collect())
+    }
+}
+
+export_function!(
+    UtilsKnownAddressesForNetworks as utils_known_addresses_for_networks
+);
+export_jni_function!(
+    UtilsKnownAddressesForNetworks as utilsKnownAddressesForNetworks
+);
+export_wasm_bindgen_function!(
+    UtilsKnownAddressesForNetworks as utilsKnownAddressesForNetworks
+);
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct UtilsClearCachesInput {}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct UtilsClearCachesOutput {}
+
+pub struct UtilsClearCaches;
+impl<'f> Function<'f> for UtilsClearCaches {
+    type Input = UtilsClearCachesInput;
+    type Output = UtilsClearCachesOutput;
+
+    /// Clears the caches of the toolkit such as the cached Bech32 encoders and
+    /// decoders of each network, releasing the memory that they hold.
+    fn handle(
+        UtilsClearCachesInput {}: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        radix_engine_toolkit::caches::clear_caches();
+        Ok(UtilsClearCachesOutput {})
+    }
+}
+
+export_function!(UtilsClearCaches as utils_clear_caches);
+export_jni_function!(UtilsClearCaches as utilsClearCaches);
+export_wasm_bindgen_function!(UtilsClearCaches as utilsClearCaches);
+
+fn known_addresses(network_id: u8) -> UtilsKnownAddressesOutput {
+    let resource_addresses = construct_addresses! {
+        ResourceAddresses,
+        network_id,
+        [
+            xrd,
+            secp256k1_signature_virtual_badge,
+            ed25519_signature_virtual_badge,
+            package_of_direct_caller_virtual_badge,
+            global_caller_virtual_badge,
+            system_transaction_badge,
+            package_owner_badge,
+            validator_owner_badge,
+            account_owner_badge,
+            identity_owner_badge,
+        ]
+    };
+    let package_addresses = construct_addresses! {
+        PackageAddresses,
+        network_id,
+        [
+            package_package,
+            resource_package,
+            account_package,
+            identity_package,
+            consensus_manager_package,
+            access_controller_package,
+            pool_package,
+            transaction_processor_package,
+            metadata_module_package,
+            royalty_module_package,
+            role_assignment_module_package,
+            genesis_helper_package,
+            faucet_package,
+            transaction_tracker_package,
+            locker_package,
+        ]
+    };
+    let component_addresses = construct_addresses! {
+        ComponentAddresses,
+        network_id,
+        [
+            consensus_manager,
+            genesis_helper,
+            faucet,
+            transaction_tracker,
+        ]
+    };
+
+    UtilsKnownAddressesOutput {
+        component_addresses,
+        package_addresses,
+        resource_addresses,
     }
 }
 
@@ -125,6 +204,8 @@ pub struct PackageAddresses {
     pub role_assignment_module_package: SerializableNodeId,
     pub genesis_helper_package: SerializableNodeId,
     pub faucet_package: SerializableNodeId,
+    pub transaction_tracker_package: SerializableNodeId,
+    pub locker_package: SerializableNodeId,
 }
 
 #[typeshare::typeshare]
@@ -133,6 +214,7 @@ pub struct ComponentAddresses {
     pub consensus_manager: SerializableNodeId,
     pub genesis_helper: SerializableNodeId,
     pub faucet: SerializableNodeId,
+    pub transaction_tracker: SerializableNodeId,
 }
 
 macro_rules! construct_addresses {
@@ -148,5 +230,46 @@ macro_rules! construct_addresses {
 }
 use construct_addresses;
 
-export_function!(UtilsKnownAddress as utils_known_addresses);
-export_jni_function!(UtilsKnownAddress as utilsKnownAddresses);
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct UtilsMemoryStatisticsInput {}
+
+#[typeshare::typeshare]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct UtilsMemoryStatisticsOutput {
+    pub live_responses: SerializableU64,
+    pub live_bytes: SerializableU64,
+    pub allocated_responses: SerializableU64,
+    pub freed_responses: SerializableU64,
+}
+
+pub struct UtilsMemoryStatistics;
+impl<'f> Function<'f> for UtilsMemoryStatistics {
+    type Input = UtilsMemoryStatisticsInput;
+    type Output = UtilsMemoryStatisticsOutput;
+
+    /// Returns statistics on the response buffers allocated by the toolkit.
+    /// A number of live responses that keeps growing across calls indicates
+    /// that the host is leaking response buffers. The response of this call
+    /// is itself included in the statistics only after it's been returned.
+    fn handle(
+        UtilsMemoryStatisticsInput {}: Self::Input,
+    ) -> Result<Self::Output, crate::error::InvocationHandlingError> {
+        let MemoryStatistics {
+            live_responses,
+            live_bytes,
+            allocated_responses,
+            freed_responses,
+        } = memory_statistics();
+        Ok(UtilsMemoryStatisticsOutput {
+            live_responses: live_responses.into(),
+            live_bytes: live_bytes.into(),
+            allocated_responses: allocated_responses.into(),
+            freed_responses: freed_responses.into(),
+        })
+    }
+}
+
+export_function!(UtilsMemoryStatistics as utils_memory_statistics);
+export_jni_function!(UtilsMemoryStatistics as utilsMemoryStatistics);
+export_wasm_bindgen_function!(UtilsMemoryStatistics as utilsMemoryStatistics);
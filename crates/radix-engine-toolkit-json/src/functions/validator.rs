@@ -0,0 +1,195 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::validator::*;
+use schemars::JsonSchema;
+use scrypto::prelude::{ComponentAddress, ResourceAddress};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableValidatorOperation {
+    Stake {
+        validator_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    Unstake {
+        validator_address: SerializableNodeId,
+        stake_unit_resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+    ClaimXrd {
+        validator_address: SerializableNodeId,
+        claim_nft_resource_address: SerializableNodeId,
+        ids: Vec<SerializableNonFungibleLocalId>,
+    },
+    Register {
+        validator_address: SerializableNodeId,
+    },
+    Unregister {
+        validator_address: SerializableNodeId,
+    },
+    UpdateFee {
+        validator_address: SerializableNodeId,
+        new_fee_factor: SerializableDecimal,
+    },
+    LockOwnerStakeUnits {
+        validator_address: SerializableNodeId,
+        stake_unit_resource_address: SerializableNodeId,
+        amount: SerializableDecimal,
+    },
+}
+
+impl SerializableValidatorOperation {
+    pub fn to_native(
+        &self,
+    ) -> Result<ValidatorOperation, SerializableNodeIdError> {
+        let validator = |address: &SerializableNodeId| {
+            ComponentAddress::try_from(address.clone())
+        };
+        let resource = |address: &SerializableNodeId| {
+            ResourceAddress::try_from(address.clone())
+        };
+
+        let operation = match self {
+            Self::Stake {
+                validator_address,
+                amount,
+            } => ValidatorOperation::Stake {
+                validator_address: validator(validator_address)?,
+                amount: **amount,
+            },
+            Self::Unstake {
+                validator_address,
+                stake_unit_resource_address,
+                amount,
+            } => ValidatorOperation::Unstake {
+                validator_address: validator(validator_address)?,
+                stake_unit_resource_address: resource(
+                    stake_unit_resource_address,
+                )?,
+                amount: **amount,
+            },
+            Self::ClaimXrd {
+                validator_address,
+                claim_nft_resource_address,
+                ids,
+            } => ValidatorOperation::ClaimXrd {
+                validator_address: validator(validator_address)?,
+                claim_nft_resource_address: resource(
+                    claim_nft_resource_address,
+                )?,
+                ids: ids.iter().map(|id| (**id).clone()).collect(),
+            },
+            Self::Register { validator_address } => {
+                ValidatorOperation::Register {
+                    validator_address: validator(validator_address)?,
+                }
+            }
+            Self::Unregister { validator_address } => {
+                ValidatorOperation::Unregister {
+                    validator_address: validator(validator_address)?,
+                }
+            }
+            Self::UpdateFee {
+                validator_address,
+                new_fee_factor,
+            } => ValidatorOperation::UpdateFee {
+                validator_address: validator(validator_address)?,
+                new_fee_factor: **new_fee_factor,
+            },
+            Self::LockOwnerStakeUnits {
+                validator_address,
+                stake_unit_resource_address,
+                amount,
+            } => ValidatorOperation::LockOwnerStakeUnits {
+                validator_address: validator(validator_address)?,
+                stake_unit_resource_address: resource(
+                    stake_unit_resource_address,
+                )?,
+                amount: **amount,
+            },
+        };
+        Ok(operation)
+    }
+}
+
+//==========================
+// Build Validator Manifest
+//==========================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct BuildValidatorManifestInput {
+    /// The account that resources are withdrawn from and deposited into and
+    /// that holds the owner badges of the validators.
+    pub account_address: SerializableNodeId,
+    pub operations: Vec<SerializableValidatorOperation>,
+    pub network_id: SerializableU8,
+    pub instructions_kind: SerializableInstructionsKind,
+}
+
+#[typeshare::typeshare]
+pub type BuildValidatorManifestOutput = SerializableTransactionManifest;
+
+pub struct BuildValidatorManifest;
+impl<'f> Function<'f> for BuildValidatorManifest {
+    type Input = BuildValidatorManifestInput;
+    type Output = BuildValidatorManifestOutput;
+
+    fn handle(
+        BuildValidatorManifestInput {
+            account_address,
+            operations,
+            network_id,
+            instructions_kind,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let account_address =
+            ComponentAddress::try_from(account_address.clone())
+                .ok()
+                .filter(radix_engine_toolkit::utils::is_account)
+                .ok_or(InvocationHandlingError::InvalidAddress(
+                    account_address.0.to_string(),
+                ))?;
+        let operations = operations
+            .iter()
+            .map(SerializableValidatorOperation::to_native)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|error| {
+                InvocationHandlingError::InvalidAddress(debug_string(error))
+            })?;
+
+        let manifest =
+            radix_engine_toolkit::functions::validator::build_validator_manifest(
+                account_address,
+                &operations,
+            );
+        Ok(SerializableTransactionManifest::from_native(
+            &manifest,
+            *network_id,
+            instructions_kind,
+        )?)
+    }
+}
+
+export_function!(BuildValidatorManifest as build_validator_manifest);
+export_jni_function!(BuildValidatorManifest as buildValidatorManifest);
+export_wasm_bindgen_function!(BuildValidatorManifest as buildValidatorManifest);
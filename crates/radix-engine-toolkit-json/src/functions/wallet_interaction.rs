@@ -0,0 +1,147 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+use radix_engine_toolkit::functions::wallet_interaction::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+//================================
+// Wallet Interaction Canonicalize
+//================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct WalletInteractionCanonicalizeInput {
+    /// The JSON of the wallet interaction in any formatting.
+    pub interaction: String,
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct WalletInteractionCanonicalizeOutput {
+    pub canonical_interaction: String,
+    pub hash: SerializableHash,
+}
+
+pub struct WalletInteractionCanonicalize;
+impl<'f> Function<'f> for WalletInteractionCanonicalize {
+    type Input = WalletInteractionCanonicalizeInput;
+    type Output = WalletInteractionCanonicalizeOutput;
+
+    fn handle(
+        WalletInteractionCanonicalizeInput { interaction }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let interaction = decode_wallet_interaction(&interaction)?;
+        Ok(Self::Output {
+            canonical_interaction: encode_wallet_interaction(&interaction),
+            hash: wallet_interaction_hash(&interaction).into(),
+        })
+    }
+}
+
+export_function!(
+    WalletInteractionCanonicalize as wallet_interaction_canonicalize
+);
+export_jni_function!(
+    WalletInteractionCanonicalize as walletInteractionCanonicalize
+);
+export_wasm_bindgen_function!(
+    WalletInteractionCanonicalize as walletInteractionCanonicalize
+);
+
+//====================================
+// Wallet Interaction Deep Link Encode
+//====================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct WalletInteractionDeepLinkEncodeInput {
+    pub interaction: String,
+    /// The URL that the wallet handles such as `radixwallet://connect`.
+    pub base_url: String,
+}
+
+#[typeshare::typeshare]
+pub type WalletInteractionDeepLinkEncodeOutput = String;
+
+pub struct WalletInteractionDeepLinkEncode;
+impl<'f> Function<'f> for WalletInteractionDeepLinkEncode {
+    type Input = WalletInteractionDeepLinkEncodeInput;
+    type Output = WalletInteractionDeepLinkEncodeOutput;
+
+    fn handle(
+        WalletInteractionDeepLinkEncodeInput {
+            interaction,
+            base_url,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let interaction = decode_wallet_interaction(&interaction)?;
+        Ok(wallet_interaction_deep_link(&base_url, &interaction))
+    }
+}
+
+export_function!(
+    WalletInteractionDeepLinkEncode as wallet_interaction_deep_link_encode
+);
+export_jni_function!(
+    WalletInteractionDeepLinkEncode as walletInteractionDeepLinkEncode
+);
+export_wasm_bindgen_function!(
+    WalletInteractionDeepLinkEncode as walletInteractionDeepLinkEncode
+);
+
+//====================================
+// Wallet Interaction Deep Link Decode
+//====================================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct WalletInteractionDeepLinkDecodeInput {
+    pub deep_link: String,
+}
+
+#[typeshare::typeshare]
+pub type WalletInteractionDeepLinkDecodeOutput =
+    WalletInteractionCanonicalizeOutput;
+
+pub struct WalletInteractionDeepLinkDecode;
+impl<'f> Function<'f> for WalletInteractionDeepLinkDecode {
+    type Input = WalletInteractionDeepLinkDecodeInput;
+    type Output = WalletInteractionDeepLinkDecodeOutput;
+
+    fn handle(
+        WalletInteractionDeepLinkDecodeInput { deep_link }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let interaction = decode_wallet_interaction_deep_link(&deep_link)?;
+        Ok(Self::Output {
+            canonical_interaction: encode_wallet_interaction(&interaction),
+            hash: wallet_interaction_hash(&interaction).into(),
+        })
+    }
+}
+
+export_function!(
+    WalletInteractionDeepLinkDecode as wallet_interaction_deep_link_decode
+);
+export_jni_function!(
+    WalletInteractionDeepLinkDecode as walletInteractionDeepLinkDecode
+);
+export_wasm_bindgen_function!(
+    WalletInteractionDeepLinkDecode as walletInteractionDeepLinkDecode
+);
@@ -0,0 +1,125 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use schemars::JsonSchema;
+use scrypto::prelude::I192;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+//==============
+// Xrd To Attos
+//==============
+
+#[typeshare::typeshare]
+pub type XrdToAttosInput = SerializableDecimal;
+
+#[typeshare::typeshare]
+pub type XrdToAttosOutput = String;
+
+pub struct XrdToAttos;
+impl<'f> Function<'f> for XrdToAttos {
+    type Input = XrdToAttosInput;
+    type Output = XrdToAttosOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        Ok(radix_engine_toolkit::functions::xrd::xrd_to_attos(&input)
+            .to_string())
+    }
+}
+
+export_function!(XrdToAttos as xrd_to_attos);
+export_jni_function!(XrdToAttos as xrdToAttos);
+export_wasm_bindgen_function!(XrdToAttos as xrdToAttos);
+
+//==============
+// Attos To Xrd
+//==============
+
+#[typeshare::typeshare]
+pub type AttosToXrdInput = String;
+
+#[typeshare::typeshare]
+pub type AttosToXrdOutput = SerializableDecimal;
+
+pub struct AttosToXrd;
+impl<'f> Function<'f> for AttosToXrd {
+    type Input = AttosToXrdInput;
+    type Output = AttosToXrdOutput;
+
+    fn handle(
+        input: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        let attos = input.parse::<I192>().map_err(|_| {
+            InvocationHandlingError::ArithmeticError(format!(
+                "The attos amount {input} is not a valid integer"
+            ))
+        })?;
+        Ok(radix_engine_toolkit::functions::xrd::attos_to_xrd(attos).into())
+    }
+}
+
+export_function!(AttosToXrd as attos_to_xrd);
+export_jni_function!(AttosToXrd as attosToXrd);
+export_wasm_bindgen_function!(AttosToXrd as attosToXrd);
+
+//===================
+// Cost Units To Xrd
+//===================
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct CostUnitsToXrdInput {
+    pub cost_units: SerializableU32,
+    pub cost_unit_price: SerializableDecimal,
+    pub tip_percentage: SerializableU16,
+}
+
+#[typeshare::typeshare]
+pub type CostUnitsToXrdOutput = SerializableDecimal;
+
+pub struct CostUnitsToXrd;
+impl<'f> Function<'f> for CostUnitsToXrd {
+    type Input = CostUnitsToXrdInput;
+    type Output = CostUnitsToXrdOutput;
+
+    fn handle(
+        CostUnitsToXrdInput {
+            cost_units,
+            cost_unit_price,
+            tip_percentage,
+        }: Self::Input,
+    ) -> Result<Self::Output, InvocationHandlingError> {
+        radix_engine_toolkit::functions::xrd::cost_units_to_xrd(
+            *cost_units,
+            &cost_unit_price,
+            *tip_percentage,
+        )
+        .map(Into::into)
+        .ok_or_else(|| {
+            InvocationHandlingError::ArithmeticError(
+                "The fee of the cost units overflows".to_owned(),
+            )
+        })
+    }
+}
+
+export_function!(CostUnitsToXrd as cost_units_to_xrd);
+export_jni_function!(CostUnitsToXrd as costUnitsToXrd);
+export_wasm_bindgen_function!(CostUnitsToXrd as costUnitsToXrd);
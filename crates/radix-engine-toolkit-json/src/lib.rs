@@ -21,3 +21,6 @@ pub mod memory;
 pub mod models;
 pub mod prelude;
 pub mod utils;
+pub mod versioning;
+pub mod warning;
+pub mod wasi;
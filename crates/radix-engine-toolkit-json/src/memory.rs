@@ -18,9 +18,39 @@
 #![allow(clippy::missing_safety_doc)]
 
 use std::alloc::{alloc, dealloc, Layout};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+#[cfg(debug_assertions)]
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 
 pub type Pointer = *mut std::ffi::c_char;
 
+/// The version of the C ABI of the toolkit. This is incremented whenever the
+/// signatures of the exported functions or the layout of the types that they
+/// exchange change in a way that is incompatible with earlier versions.
+pub const ABI_VERSION: u32 = 2;
+
+/// The buffer returned by all of the exported functions. The buffer holds the
+/// serialized output of the function or the serialized error if the function
+/// failed. The buffer is not null-terminated, `len` is the number of bytes
+/// that `ptr` points to. Buffers are owned by the host once returned and must
+/// be released through [`toolkit_free_ret_buffer`] or
+/// [`toolkit_free_response`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetBuffer {
+    pub ptr: *mut u8,
+    pub len: usize,
+    pub is_error: bool,
+}
+
+#[no_mangle]
+pub extern "C" fn toolkit_abi_version() -> u32 {
+    ABI_VERSION
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn toolkit_alloc(capacity: usize) -> Pointer {
     let align = std::mem::align_of::<usize>();
@@ -35,11 +65,274 @@ pub unsafe extern "C" fn toolkit_free(pointer: Pointer, capacity: usize) {
     dealloc(pointer as *mut _, layout);
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn toolkit_free_ret_buffer(buffer: RetBuffer) {
+    release_output(buffer.ptr);
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn toolkit_free_c_string(pointer: Pointer) {
-    // Loading the C-String from memory to get the byte-count of the string.
-    let length = std::ffi::CStr::from_ptr(pointer as *const std::ffi::c_char)
-        .to_bytes()
-        .len();
-    toolkit_free(pointer, length);
+    release_output(pointer as *mut u8);
+}
+
+/// The size of the header that precedes every individually allocated response
+/// and holds its length, which is how responses are freed given only their
+/// pointer.
+const RESPONSE_HEADER_SIZE: usize = std::mem::size_of::<usize>();
+
+static ALLOCATED_RESPONSES: AtomicU64 = AtomicU64::new(0);
+static FREED_RESPONSES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// The pointers of the responses that have been returned to the host and not
+/// yet freed. They're only tracked in debug builds where they're used to
+/// detect pointers that are freed twice or were never returned by the toolkit.
+/// Responses allocated in an arena are not tracked as they're released
+/// together with the arena.
+#[cfg(debug_assertions)]
+static LIVE_RESPONSES: Mutex<BTreeSet<usize>> = Mutex::new(BTreeSet::new());
+
+#[cfg(debug_assertions)]
+fn live_responses() -> std::sync::MutexGuard<'static, BTreeSet<usize>> {
+    // A panic while holding the lock can not leave the set in an inconsistent
+    // state so the poisoning is ignored.
+    LIVE_RESPONSES
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+#[cfg(debug_assertions)]
+fn track_response(pointer: *mut u8) {
+    live_responses().insert(pointer as usize);
+}
+
+#[cfg(not(debug_assertions))]
+fn track_response(_: *mut u8) {}
+
+/// Stops tracking the response returning whether it was a live response. In
+/// release builds every pointer is assumed to be that of a live response.
+#[cfg(debug_assertions)]
+fn untrack_response(pointer: *mut u8) -> bool {
+    live_responses().remove(&(pointer as usize))
+}
+
+#[cfg(not(debug_assertions))]
+fn untrack_response(_: *mut u8) -> bool {
+    true
+}
+
+/// Statistics on the responses allocated by the toolkit which hosts can use
+/// to detect response buffers that they've leaked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStatistics {
+    /// The number of responses that have been returned and not yet freed.
+    pub live_responses: u64,
+    /// The total number of bytes held by the live responses.
+    pub live_bytes: u64,
+    /// The number of responses allocated since the toolkit was loaded.
+    pub allocated_responses: u64,
+    /// The number of responses freed since the toolkit was loaded.
+    pub freed_responses: u64,
+}
+
+pub fn memory_statistics() -> MemoryStatistics {
+    let allocated_responses = ALLOCATED_RESPONSES.load(Ordering::Relaxed);
+    let freed_responses = FREED_RESPONSES.load(Ordering::Relaxed);
+    MemoryStatistics {
+        live_responses: allocated_responses.saturating_sub(freed_responses),
+        live_bytes: LIVE_BYTES.load(Ordering::Relaxed),
+        allocated_responses,
+        freed_responses,
+    }
+}
+
+/// Frees a response returned by the toolkit given only its pointer, this is
+/// the `ptr` of a [`RetBuffer`] or a C-string returned by the toolkit. This
+/// is the only way that responses should be freed: responses must not be
+/// freed through the allocator of the host or through [`toolkit_free`].
+///
+/// In debug builds, returns `false` without freeing anything if the pointer
+/// is not that of a live response, such as when the response was already
+/// freed, which makes double frees detectable by the host rather than
+/// corrupting the heap. Release builds don't track the live responses and the
+/// pointer must be that of a live response. Responses allocated in an arena
+/// can be passed to this function as well, in which case it's a no-op that
+/// returns `true`.
+#[no_mangle]
+pub unsafe extern "C" fn toolkit_free_response(pointer: *mut u8) -> bool {
+    release_output(pointer)
+}
+
+/// The size of the chunks that the [`Arena`] allocates. Outputs larger than
+/// this get a chunk of their own.
+const ARENA_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The address ranges of the chunks of all of the live arenas of all threads
+/// keyed by their start. This is how outputs allocated in an arena are told
+/// apart from individually allocated ones which have a header, regardless of
+/// the thread that they're freed on.
+static ARENA_CHUNKS: Mutex<BTreeMap<usize, usize>> =
+    Mutex::new(BTreeMap::new());
+
+fn arena_chunks() -> std::sync::MutexGuard<'static, BTreeMap<usize, usize>> {
+    // A panic while holding the lock can not leave the map in an inconsistent
+    // state so the poisoning is ignored.
+    ARENA_CHUNKS
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+/// Checks whether the pointer is in a chunk of any of the live arenas. The
+/// end of a chunk is excluded since it may be the start of another
+/// allocation. Outputs are never allocated there as every output takes up at
+/// least one byte of the arena.
+fn is_in_arena(pointer: *const u8) -> bool {
+    let pointer = pointer as usize;
+    arena_chunks()
+        .range(..=pointer)
+        .next_back()
+        .is_some_and(|(_, end)| pointer < *end)
+}
+
+thread_local! {
+    /// The arena that the outputs of the functions invoked on this thread are
+    /// written to, or null if they're individually allocated.
+    static ACTIVE_ARENA: Cell<*mut Arena> = const {
+        Cell::new(std::ptr::null_mut())
+    };
+}
+
+/// A bump allocator for the output buffers of the exported functions. Hosts
+/// that make a large number of calls can create an arena to have the outputs
+/// written into a few large chunks rather than allocating each individually,
+/// and then release all of them at once through [`toolkit_arena_reset`].
+///
+/// An arena is bound to the thread that created it: while it's alive, all of
+/// the outputs of the functions invoked on that thread are written to it and
+/// [`toolkit_free_ret_buffer`] and [`toolkit_free_c_string`] become no-ops
+/// for them, on any thread. The arena must be reset and destroyed on the same
+/// thread.
+#[derive(Default)]
+pub struct Arena {
+    chunks: Vec<Box<[u8]>>,
+    chunk: usize,
+    offset: usize,
+}
+
+impl Arena {
+    fn allocate(&mut self, len: usize) -> *mut u8 {
+        let len = len.max(1);
+        while let Some(chunk) = self.chunks.get_mut(self.chunk) {
+            if chunk.len() - self.offset >= len {
+                let pointer = chunk[self.offset..].as_mut_ptr();
+                self.offset += len;
+                return pointer;
+            }
+            self.chunk += 1;
+            self.offset = 0;
+        }
+
+        let mut chunk = vec![0u8; len.max(ARENA_CHUNK_SIZE)].into_boxed_slice();
+        let range = chunk.as_mut_ptr_range();
+        arena_chunks().insert(range.start as usize, range.end as usize);
+
+        self.chunks.push(chunk);
+        self.chunk = self.chunks.len() - 1;
+        self.offset = len;
+        self.chunks[self.chunk].as_mut_ptr()
+    }
+
+    fn reset(&mut self) {
+        self.chunk = 0;
+        self.offset = 0;
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        let mut arena_chunks = arena_chunks();
+        for chunk in self.chunks.iter() {
+            arena_chunks.remove(&(chunk.as_ptr() as usize));
+        }
+    }
+}
+
+/// Creates an [`Arena`] and makes it the active arena of the calling thread.
+/// Returns null if the calling thread already has an active arena, which must
+/// be destroyed first. The returned handle must be released through
+/// [`toolkit_arena_destroy`].
+#[no_mangle]
+pub extern "C" fn toolkit_arena_create() -> *mut Arena {
+    ACTIVE_ARENA.with(|active| {
+        if !active.get().is_null() {
+            return std::ptr::null_mut();
+        }
+        let arena = Box::into_raw(Box::<Arena>::default());
+        active.set(arena);
+        arena
+    })
+}
+
+/// Invalidates all of the buffers allocated in the arena so that its memory
+/// is reused by the calls that follow.
+#[no_mangle]
+pub unsafe extern "C" fn toolkit_arena_reset(arena: *mut Arena) {
+    if let Some(arena) = arena.as_mut() {
+        arena.reset()
+    }
+}
+
+/// Frees the arena and all of the buffers allocated in it. If the arena is
+/// the active arena of the calling thread then outputs go back to being
+/// individually allocated.
+#[no_mangle]
+pub unsafe extern "C" fn toolkit_arena_destroy(arena: *mut Arena) {
+    if arena.is_null() {
+        return;
+    }
+    ACTIVE_ARENA.with(|active| {
+        if active.get() == arena {
+            active.set(std::ptr::null_mut())
+        }
+    });
+    drop(Box::from_raw(arena));
+}
+
+/// Allocates the memory of an output of `len` bytes, in the active arena of
+/// the calling thread if there is one.
+pub(crate) fn allocate_output(len: usize) -> *mut u8 {
+    ACTIVE_ARENA.with(|active| match unsafe { active.get().as_mut() } {
+        Some(arena) => arena.allocate(len),
+        None => unsafe {
+            let header = toolkit_alloc(RESPONSE_HEADER_SIZE + len) as *mut u8;
+            (header as *mut usize).write(len);
+            let pointer = header.add(RESPONSE_HEADER_SIZE);
+
+            track_response(pointer);
+            ALLOCATED_RESPONSES.fetch_add(1, Ordering::Relaxed);
+            LIVE_BYTES.fetch_add(len as u64, Ordering::Relaxed);
+
+            pointer
+        },
+    })
+}
+
+/// Frees an output given its pointer. Outputs allocated in a live arena are
+/// left to the arena. In debug builds, pointers that aren't those of live
+/// responses are not freed and `false` is returned.
+unsafe fn release_output(pointer: *mut u8) -> bool {
+    if is_in_arena(pointer) {
+        return true;
+    }
+
+    if !untrack_response(pointer) {
+        return false;
+    }
+
+    let header = pointer.sub(RESPONSE_HEADER_SIZE);
+    let len = (header as *const usize).read();
+    FREED_RESPONSES.fetch_add(1, Ordering::Relaxed);
+    LIVE_BYTES.fetch_sub(len as u64, Ordering::Relaxed);
+    toolkit_free(header as Pointer, RESPONSE_HEADER_SIZE + len);
+    true
 }
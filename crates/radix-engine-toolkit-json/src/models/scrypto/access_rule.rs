@@ -0,0 +1,278 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::ResourceAddress;
+use schemars::JsonSchema;
+use scrypto::prelude::{
+    AccessRule, AccessRuleNode, OwnerRole, ProofRule, ResourceOrNonFungible,
+    RoleAssignmentInit, RoleKey,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableAccessRule {
+    AllowAll,
+    DenyAll,
+    Protected(SerializableCompositeRequirement),
+}
+
+/// A requirement of an access rule which is either a proof rule or a
+/// composition of other requirements. This is the `AccessRuleNode` of the
+/// engine.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableCompositeRequirement {
+    ProofRule(SerializableProofRule),
+    AnyOf(Vec<SerializableCompositeRequirement>),
+    AllOf(Vec<SerializableCompositeRequirement>),
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableProofRule {
+    Require(SerializableResourceOrNonFungible),
+    AmountOf {
+        amount: SerializableDecimal,
+        resource_address: SerializableNodeId,
+    },
+    CountOf {
+        count: SerializableU8,
+        resources: Vec<SerializableResourceOrNonFungible>,
+    },
+    AllOf(Vec<SerializableResourceOrNonFungible>),
+    AnyOf(Vec<SerializableResourceOrNonFungible>),
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableOwnerRole {
+    None,
+    Fixed(SerializableAccessRule),
+    Updatable(SerializableAccessRule),
+}
+
+/// The roles of a role assignment keyed by the role key. A role without an
+/// access rule uses the access rule of the owner role.
+#[typeshare::typeshare(
+    serialized_as = "HashMap<String, Option<SerializableAccessRule>>"
+)]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(transparent)]
+#[schemars(transparent)]
+pub struct SerializableRoleAssignmentInit(
+    #[typeshare(
+        serialized_as = "HashMap<String, Option<SerializableAccessRule>>"
+    )]
+    pub ::indexmap::IndexMap<String, Option<SerializableAccessRule>>,
+);
+
+impl SerializableAccessRule {
+    pub fn from_native(native: &AccessRule, network_id: u8) -> Self {
+        match native {
+            AccessRule::AllowAll => Self::AllowAll,
+            AccessRule::DenyAll => Self::DenyAll,
+            AccessRule::Protected(node) => Self::Protected(
+                SerializableCompositeRequirement::from_native(node, network_id),
+            ),
+        }
+    }
+
+    pub fn to_native(&self) -> Result<AccessRule, SerializableNodeIdError> {
+        match self {
+            Self::AllowAll => Ok(AccessRule::AllowAll),
+            Self::DenyAll => Ok(AccessRule::DenyAll),
+            Self::Protected(requirement) => {
+                requirement.to_native().map(AccessRule::Protected)
+            }
+        }
+    }
+}
+
+impl SerializableCompositeRequirement {
+    pub fn from_native(native: &AccessRuleNode, network_id: u8) -> Self {
+        let from_natives = |nodes: &[AccessRuleNode]| {
+            nodes
+                .iter()
+                .map(|node| Self::from_native(node, network_id))
+                .collect()
+        };
+
+        match native {
+            AccessRuleNode::ProofRule(proof_rule) => Self::ProofRule(
+                SerializableProofRule::from_native(proof_rule, network_id),
+            ),
+            AccessRuleNode::AnyOf(nodes) => Self::AnyOf(from_natives(nodes)),
+            AccessRuleNode::AllOf(nodes) => Self::AllOf(from_natives(nodes)),
+        }
+    }
+
+    pub fn to_native(&self) -> Result<AccessRuleNode, SerializableNodeIdError> {
+        let to_natives = |requirements: &[Self]| {
+            requirements
+                .iter()
+                .map(Self::to_native)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        match self {
+            Self::ProofRule(proof_rule) => {
+                proof_rule.to_native().map(AccessRuleNode::ProofRule)
+            }
+            Self::AnyOf(requirements) => {
+                to_natives(requirements).map(AccessRuleNode::AnyOf)
+            }
+            Self::AllOf(requirements) => {
+                to_natives(requirements).map(AccessRuleNode::AllOf)
+            }
+        }
+    }
+}
+
+impl SerializableProofRule {
+    pub fn from_native(native: &ProofRule, network_id: u8) -> Self {
+        let from_natives = |resources: &[ResourceOrNonFungible]| {
+            resources
+                .iter()
+                .map(|resource| {
+                    SerializableResourceOrNonFungible::new(
+                        resource.clone(),
+                        network_id,
+                    )
+                })
+                .collect()
+        };
+
+        match native {
+            ProofRule::Require(resource) => {
+                Self::Require(SerializableResourceOrNonFungible::new(
+                    resource.clone(),
+                    network_id,
+                ))
+            }
+            ProofRule::AmountOf(amount, resource_address) => Self::AmountOf {
+                amount: (*amount).into(),
+                resource_address: SerializableNodeId::from_global_address(
+                    *resource_address,
+                    network_id,
+                ),
+            },
+            ProofRule::CountOf(count, resources) => Self::CountOf {
+                count: (*count).into(),
+                resources: from_natives(resources),
+            },
+            ProofRule::AllOf(resources) => Self::AllOf(from_natives(resources)),
+            ProofRule::AnyOf(resources) => Self::AnyOf(from_natives(resources)),
+        }
+    }
+
+    pub fn to_native(&self) -> Result<ProofRule, SerializableNodeIdError> {
+        let to_natives = |resources: &[SerializableResourceOrNonFungible]| {
+            resources
+                .iter()
+                .map(SerializableResourceOrNonFungible::to_native)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        match self {
+            Self::Require(resource) => {
+                resource.to_native().map(ProofRule::Require)
+            }
+            Self::AmountOf {
+                amount,
+                resource_address,
+            } => ResourceAddress::try_from(resource_address.clone()).map(
+                |resource_address| {
+                    ProofRule::AmountOf(**amount, resource_address)
+                },
+            ),
+            Self::CountOf { count, resources } => to_natives(resources)
+                .map(|resources| ProofRule::CountOf(**count, resources)),
+            Self::AllOf(resources) => {
+                to_natives(resources).map(ProofRule::AllOf)
+            }
+            Self::AnyOf(resources) => {
+                to_natives(resources).map(ProofRule::AnyOf)
+            }
+        }
+    }
+}
+
+impl SerializableOwnerRole {
+    pub fn from_native(native: &OwnerRole, network_id: u8) -> Self {
+        match native {
+            OwnerRole::None => Self::None,
+            OwnerRole::Fixed(rule) => Self::Fixed(
+                SerializableAccessRule::from_native(rule, network_id),
+            ),
+            OwnerRole::Updatable(rule) => Self::Updatable(
+                SerializableAccessRule::from_native(rule, network_id),
+            ),
+        }
+    }
+
+    pub fn to_native(&self) -> Result<OwnerRole, SerializableNodeIdError> {
+        match self {
+            Self::None => Ok(OwnerRole::None),
+            Self::Fixed(rule) => rule.to_native().map(OwnerRole::Fixed),
+            Self::Updatable(rule) => rule.to_native().map(OwnerRole::Updatable),
+        }
+    }
+}
+
+impl SerializableRoleAssignmentInit {
+    pub fn from_native(native: &RoleAssignmentInit, network_id: u8) -> Self {
+        Self(
+            native
+                .data
+                .iter()
+                .map(|(role_key, rule)| {
+                    (
+                        role_key.key.clone(),
+                        rule.as_ref().map(|rule| {
+                            SerializableAccessRule::from_native(
+                                rule, network_id,
+                            )
+                        }),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    pub fn to_native(
+        &self,
+    ) -> Result<RoleAssignmentInit, SerializableNodeIdError> {
+        let data = self
+            .0
+            .iter()
+            .map(|(role_key, rule)| {
+                rule.as_ref()
+                    .map(SerializableAccessRule::to_native)
+                    .transpose()
+                    .map(|rule| (RoleKey::new(role_key.clone()), rule))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(RoleAssignmentInit { data })
+    }
+}
@@ -0,0 +1,265 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::{GlobalAddress, NonFungibleGlobalId};
+use schemars::JsonSchema;
+use scrypto::object_modules::metadata::MetadataValue;
+use scrypto::prelude::{Instant, UncheckedOrigin, UncheckedUrl};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableMetadataValue {
+    String(String),
+    Bool(bool),
+    U8(SerializableU8),
+    U32(SerializableU32),
+    U64(SerializableU64),
+    I32(SerializableI32),
+    I64(SerializableI64),
+    Decimal(SerializableDecimal),
+    GlobalAddress(SerializableNodeId),
+    PublicKey(SerializablePublicKey),
+    NonFungibleGlobalId(SerializableNonFungibleGlobalId),
+    NonFungibleLocalId(SerializableNonFungibleLocalId),
+    /// The number of seconds since the unix epoch.
+    Instant(SerializableI64),
+    Url(String),
+    Origin(String),
+    PublicKeyHash(SerializablePublicKeyHash),
+
+    StringArray(Vec<String>),
+    BoolArray(Vec<bool>),
+    U8Array(Vec<SerializableU8>),
+    U32Array(Vec<SerializableU32>),
+    U64Array(Vec<SerializableU64>),
+    I32Array(Vec<SerializableI32>),
+    I64Array(Vec<SerializableI64>),
+    DecimalArray(Vec<SerializableDecimal>),
+    GlobalAddressArray(Vec<SerializableNodeId>),
+    PublicKeyArray(Vec<SerializablePublicKey>),
+    NonFungibleGlobalIdArray(Vec<SerializableNonFungibleGlobalId>),
+    NonFungibleLocalIdArray(Vec<SerializableNonFungibleLocalId>),
+    InstantArray(Vec<SerializableI64>),
+    UrlArray(Vec<String>),
+    OriginArray(Vec<String>),
+    PublicKeyHashArray(Vec<SerializablePublicKeyHash>),
+}
+
+impl SerializableMetadataValue {
+    pub fn from_native(native: &MetadataValue, network_id: u8) -> Self {
+        let global_address = |address: &GlobalAddress| {
+            SerializableNodeId::from_global_address(*address, network_id)
+        };
+        let non_fungible_global_id = |id: &NonFungibleGlobalId| {
+            SerializableNonFungibleGlobalId::new(id.clone(), network_id)
+        };
+
+        match native {
+            MetadataValue::String(value) => Self::String(value.clone()),
+            MetadataValue::Bool(value) => Self::Bool(*value),
+            MetadataValue::U8(value) => Self::U8((*value).into()),
+            MetadataValue::U32(value) => Self::U32((*value).into()),
+            MetadataValue::U64(value) => Self::U64((*value).into()),
+            MetadataValue::I32(value) => Self::I32((*value).into()),
+            MetadataValue::I64(value) => Self::I64((*value).into()),
+            MetadataValue::Decimal(value) => Self::Decimal((*value).into()),
+            MetadataValue::GlobalAddress(value) => {
+                Self::GlobalAddress(global_address(value))
+            }
+            MetadataValue::PublicKey(value) => Self::PublicKey((*value).into()),
+            MetadataValue::NonFungibleGlobalId(value) => {
+                Self::NonFungibleGlobalId(non_fungible_global_id(value))
+            }
+            MetadataValue::NonFungibleLocalId(value) => {
+                Self::NonFungibleLocalId(value.clone().into())
+            }
+            MetadataValue::Instant(value) => {
+                Self::Instant(value.seconds_since_unix_epoch.into())
+            }
+            MetadataValue::Url(value) => Self::Url(value.0.clone()),
+            MetadataValue::Origin(value) => Self::Origin(value.0.clone()),
+            MetadataValue::PublicKeyHash(value) => {
+                Self::PublicKeyHash((*value).into())
+            }
+
+            MetadataValue::StringArray(value) => {
+                Self::StringArray(value.clone())
+            }
+            MetadataValue::BoolArray(value) => Self::BoolArray(value.clone()),
+            MetadataValue::U8Array(value) => {
+                Self::U8Array(value.iter().copied().map(Into::into).collect())
+            }
+            MetadataValue::U32Array(value) => {
+                Self::U32Array(value.iter().copied().map(Into::into).collect())
+            }
+            MetadataValue::U64Array(value) => {
+                Self::U64Array(value.iter().copied().map(Into::into).collect())
+            }
+            MetadataValue::I32Array(value) => {
+                Self::I32Array(value.iter().copied().map(Into::into).collect())
+            }
+            MetadataValue::I64Array(value) => {
+                Self::I64Array(value.iter().copied().map(Into::into).collect())
+            }
+            MetadataValue::DecimalArray(value) => Self::DecimalArray(
+                value.iter().copied().map(Into::into).collect(),
+            ),
+            MetadataValue::GlobalAddressArray(value) => {
+                Self::GlobalAddressArray(
+                    value.iter().map(global_address).collect(),
+                )
+            }
+            MetadataValue::PublicKeyArray(value) => Self::PublicKeyArray(
+                value.iter().copied().map(Into::into).collect(),
+            ),
+            MetadataValue::NonFungibleGlobalIdArray(value) => {
+                Self::NonFungibleGlobalIdArray(
+                    value.iter().map(non_fungible_global_id).collect(),
+                )
+            }
+            MetadataValue::NonFungibleLocalIdArray(value) => {
+                Self::NonFungibleLocalIdArray(
+                    value.iter().cloned().map(Into::into).collect(),
+                )
+            }
+            MetadataValue::InstantArray(value) => Self::InstantArray(
+                value
+                    .iter()
+                    .map(|value| value.seconds_since_unix_epoch.into())
+                    .collect(),
+            ),
+            MetadataValue::UrlArray(value) => Self::UrlArray(
+                value.iter().map(|value| value.0.clone()).collect(),
+            ),
+            MetadataValue::OriginArray(value) => Self::OriginArray(
+                value.iter().map(|value| value.0.clone()).collect(),
+            ),
+            MetadataValue::PublicKeyHashArray(value) => {
+                Self::PublicKeyHashArray(
+                    value.iter().copied().map(Into::into).collect(),
+                )
+            }
+        }
+    }
+
+    pub fn to_native(&self) -> Result<MetadataValue, SerializableNodeIdError> {
+        let global_address = |address: &SerializableNodeId| {
+            GlobalAddress::try_from(address.clone())
+        };
+        let non_fungible_global_id = |id: &SerializableNonFungibleGlobalId| {
+            id.0.non_fungible_global_id.clone()
+        };
+
+        let value = match self {
+            Self::String(value) => MetadataValue::String(value.clone()),
+            Self::Bool(value) => MetadataValue::Bool(*value),
+            Self::U8(value) => MetadataValue::U8(**value),
+            Self::U32(value) => MetadataValue::U32(**value),
+            Self::U64(value) => MetadataValue::U64(**value),
+            Self::I32(value) => MetadataValue::I32(**value),
+            Self::I64(value) => MetadataValue::I64(**value),
+            Self::Decimal(value) => MetadataValue::Decimal(**value),
+            Self::GlobalAddress(value) => {
+                MetadataValue::GlobalAddress(global_address(value)?)
+            }
+            Self::PublicKey(value) => {
+                MetadataValue::PublicKey(value.clone().into())
+            }
+            Self::NonFungibleGlobalId(value) => {
+                MetadataValue::NonFungibleGlobalId(non_fungible_global_id(
+                    value,
+                ))
+            }
+            Self::NonFungibleLocalId(value) => {
+                MetadataValue::NonFungibleLocalId((**value).clone())
+            }
+            Self::Instant(value) => {
+                MetadataValue::Instant(Instant::new(**value))
+            }
+            Self::Url(value) => MetadataValue::Url(UncheckedUrl::of(value)),
+            Self::Origin(value) => {
+                MetadataValue::Origin(UncheckedOrigin::of(value))
+            }
+            Self::PublicKeyHash(value) => {
+                MetadataValue::PublicKeyHash(value.clone().into())
+            }
+
+            Self::StringArray(value) => {
+                MetadataValue::StringArray(value.clone())
+            }
+            Self::BoolArray(value) => MetadataValue::BoolArray(value.clone()),
+            Self::U8Array(value) => MetadataValue::U8Array(
+                value.iter().map(|value| **value).collect(),
+            ),
+            Self::U32Array(value) => MetadataValue::U32Array(
+                value.iter().map(|value| **value).collect(),
+            ),
+            Self::U64Array(value) => MetadataValue::U64Array(
+                value.iter().map(|value| **value).collect(),
+            ),
+            Self::I32Array(value) => MetadataValue::I32Array(
+                value.iter().map(|value| **value).collect(),
+            ),
+            Self::I64Array(value) => MetadataValue::I64Array(
+                value.iter().map(|value| **value).collect(),
+            ),
+            Self::DecimalArray(value) => MetadataValue::DecimalArray(
+                value.iter().map(|value| **value).collect(),
+            ),
+            Self::GlobalAddressArray(value) => {
+                MetadataValue::GlobalAddressArray(
+                    value
+                        .iter()
+                        .map(global_address)
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            Self::PublicKeyArray(value) => MetadataValue::PublicKeyArray(
+                value.iter().cloned().map(Into::into).collect(),
+            ),
+            Self::NonFungibleGlobalIdArray(value) => {
+                MetadataValue::NonFungibleGlobalIdArray(
+                    value.iter().map(non_fungible_global_id).collect(),
+                )
+            }
+            Self::NonFungibleLocalIdArray(value) => {
+                MetadataValue::NonFungibleLocalIdArray(
+                    value.iter().map(|value| (**value).clone()).collect(),
+                )
+            }
+            Self::InstantArray(value) => MetadataValue::InstantArray(
+                value.iter().map(|value| Instant::new(**value)).collect(),
+            ),
+            Self::UrlArray(value) => MetadataValue::UrlArray(
+                value.iter().map(UncheckedUrl::of).collect(),
+            ),
+            Self::OriginArray(value) => MetadataValue::OriginArray(
+                value.iter().map(UncheckedOrigin::of).collect(),
+            ),
+            Self::PublicKeyHashArray(value) => {
+                MetadataValue::PublicKeyHashArray(
+                    value.iter().cloned().map(Into::into).collect(),
+                )
+            }
+        };
+        Ok(value)
+    }
+}
@@ -15,5 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod access_rule;
+pub mod metadata_value;
 pub mod node_id;
 pub mod non_fungible_global_id;
+pub mod royalty_amount;
+pub mod value_kind;
@@ -15,9 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use radix_common::prelude::{
-    AddressBech32DecodeError, AddressBech32Decoder, AddressBech32Encoder,
-};
+use radix_common::prelude::AddressBech32DecodeError;
+use radix_engine_toolkit::caches::bech32_coders;
 use schemars::JsonSchema;
 use scrypto::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -75,12 +74,8 @@ pub struct SerializableNodeIdInternal {
 
 impl Display for SerializableNodeIdInternal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let network_definition =
-            radix_engine_toolkit::utils::network_definition_from_network_id(
-                self.network_id,
-            );
-        let bech32_encoder = AddressBech32Encoder::new(&network_definition);
-        let string = bech32_encoder
+        let string = bech32_coders(self.network_id)
+            .address_encoder
             .encode(&self.node_id.0)
             .map_err(|_| fmt::Error)?;
         write!(f, "{}", string)
@@ -100,12 +95,9 @@ impl FromStr for SerializableNodeIdInternal {
                     Ok,
                 )?;
 
-        let network_definition =
-            radix_engine_toolkit::utils::network_definition_from_network_id(
-                network_id,
-            );
-        let bech32_decoder = AddressBech32Decoder::new(&network_definition);
-        let (_, data) = bech32_decoder.validate_and_decode(s)?;
+        let (_, data) = bech32_coders(network_id)
+            .address_decoder
+            .validate_and_decode(s)?;
 
         data.try_into()
             .map_err(|_| SerializableNodeIdError::InvalidAddressLength)
@@ -17,10 +17,8 @@
 
 use crate::prelude::*;
 
-use radix_common::prelude::{
-    AddressBech32Decoder, AddressBech32Encoder, NonFungibleGlobalId,
-};
-use radix_engine_toolkit::utils::network_definition_from_network_id;
+use radix_common::prelude::NonFungibleGlobalId;
+use radix_engine_toolkit::caches::bech32_coders;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -56,14 +54,12 @@ pub struct SerializableNonFungibleGlobalIdInternal {
 
 impl Display for SerializableNonFungibleGlobalIdInternal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let network_definition =
-            network_definition_from_network_id(self.network_id);
-        let bech32_encoder = AddressBech32Encoder::new(&network_definition);
+        let coders = bech32_coders(self.network_id);
         write!(
             f,
             "{}",
             self.non_fungible_global_id
-                .to_canonical_string(&bech32_encoder)
+                .to_canonical_string(&coders.address_encoder)
         )
     }
 }
@@ -85,16 +81,18 @@ impl FromStr for SerializableNonFungibleGlobalIdInternal {
             .ok_or(
                 SerializableNonFungibleGlobalIdError::InvalidResourceAddress,
             )?;
-        let network_definition = network_definition_from_network_id(network_id);
-        let bech32_decoder = AddressBech32Decoder::new(&network_definition);
+        let coders = bech32_coders(network_id);
 
         let non_fungible_global_id =
-            NonFungibleGlobalId::try_from_canonical_string(&bech32_decoder, s)
-                .map_err(|error| {
-                    SerializableNonFungibleGlobalIdError::ParsingError(
-                        debug_string(error),
-                    )
-                })?;
+            NonFungibleGlobalId::try_from_canonical_string(
+                &coders.address_decoder,
+                s,
+            )
+            .map_err(|error| {
+                SerializableNonFungibleGlobalIdError::ParsingError(
+                    debug_string(error),
+                )
+            })?;
 
         Ok(SerializableNonFungibleGlobalIdInternal {
             non_fungible_global_id,
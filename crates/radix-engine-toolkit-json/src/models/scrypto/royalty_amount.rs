@@ -0,0 +1,51 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use schemars::JsonSchema;
+use scrypto::prelude::RoyaltyAmount;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableRoyaltyAmount {
+    Free,
+    Xrd(SerializableDecimal),
+    Usd(SerializableDecimal),
+}
+
+impl From<RoyaltyAmount> for SerializableRoyaltyAmount {
+    fn from(value: RoyaltyAmount) -> Self {
+        match value {
+            RoyaltyAmount::Free => Self::Free,
+            RoyaltyAmount::Xrd(amount) => Self::Xrd(amount.into()),
+            RoyaltyAmount::Usd(amount) => Self::Usd(amount.into()),
+        }
+    }
+}
+
+impl From<SerializableRoyaltyAmount> for RoyaltyAmount {
+    fn from(value: SerializableRoyaltyAmount) -> Self {
+        match value {
+            SerializableRoyaltyAmount::Free => Self::Free,
+            SerializableRoyaltyAmount::Xrd(amount) => Self::Xrd(*amount),
+            SerializableRoyaltyAmount::Usd(amount) => Self::Usd(*amount),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use scrypto::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+    PartialEq,
+    Eq,
+    Hash,
+)]
+pub enum SerializableScryptoValueKind {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    String,
+    Enum,
+    Array,
+    Tuple,
+    Map,
+    Reference,
+    Own,
+    Decimal,
+    PreciseDecimal,
+    NonFungibleLocalId,
+}
+
+impl From<SerializableScryptoValueKind> for ScryptoValueKind {
+    fn from(value: SerializableScryptoValueKind) -> Self {
+        match value {
+            SerializableScryptoValueKind::Bool => Self::Bool,
+            SerializableScryptoValueKind::I8 => Self::I8,
+            SerializableScryptoValueKind::I16 => Self::I16,
+            SerializableScryptoValueKind::I32 => Self::I32,
+            SerializableScryptoValueKind::I64 => Self::I64,
+            SerializableScryptoValueKind::I128 => Self::I128,
+            SerializableScryptoValueKind::U8 => Self::U8,
+            SerializableScryptoValueKind::U16 => Self::U16,
+            SerializableScryptoValueKind::U32 => Self::U32,
+            SerializableScryptoValueKind::U64 => Self::U64,
+            SerializableScryptoValueKind::U128 => Self::U128,
+            SerializableScryptoValueKind::String => Self::String,
+            SerializableScryptoValueKind::Enum => Self::Enum,
+            SerializableScryptoValueKind::Array => Self::Array,
+            SerializableScryptoValueKind::Tuple => Self::Tuple,
+            SerializableScryptoValueKind::Map => Self::Map,
+            SerializableScryptoValueKind::Reference => {
+                Self::Custom(ScryptoCustomValueKind::Reference)
+            }
+            SerializableScryptoValueKind::Own => {
+                Self::Custom(ScryptoCustomValueKind::Own)
+            }
+            SerializableScryptoValueKind::Decimal => {
+                Self::Custom(ScryptoCustomValueKind::Decimal)
+            }
+            SerializableScryptoValueKind::PreciseDecimal => {
+                Self::Custom(ScryptoCustomValueKind::PreciseDecimal)
+            }
+            SerializableScryptoValueKind::NonFungibleLocalId => {
+                Self::Custom(ScryptoCustomValueKind::NonFungibleLocalId)
+            }
+        }
+    }
+}
+
+impl From<ScryptoValueKind> for SerializableScryptoValueKind {
+    fn from(value: ScryptoValueKind) -> Self {
+        match value {
+            ScryptoValueKind::Bool => Self::Bool,
+            ScryptoValueKind::I8 => Self::I8,
+            ScryptoValueKind::I16 => Self::I16,
+            ScryptoValueKind::I32 => Self::I32,
+            ScryptoValueKind::I64 => Self::I64,
+            ScryptoValueKind::I128 => Self::I128,
+            ScryptoValueKind::U8 => Self::U8,
+            ScryptoValueKind::U16 => Self::U16,
+            ScryptoValueKind::U32 => Self::U32,
+            ScryptoValueKind::U64 => Self::U64,
+            ScryptoValueKind::U128 => Self::U128,
+            ScryptoValueKind::String => Self::String,
+            ScryptoValueKind::Enum => Self::Enum,
+            ScryptoValueKind::Array => Self::Array,
+            ScryptoValueKind::Tuple => Self::Tuple,
+            ScryptoValueKind::Map => Self::Map,
+            ScryptoValueKind::Custom(custom) => match custom {
+                ScryptoCustomValueKind::Reference => Self::Reference,
+                ScryptoCustomValueKind::Own => Self::Own,
+                ScryptoCustomValueKind::Decimal => Self::Decimal,
+                ScryptoCustomValueKind::PreciseDecimal => Self::PreciseDecimal,
+                ScryptoCustomValueKind::NonFungibleLocalId => {
+                    Self::NonFungibleLocalId
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::limits::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct SerializableCompilationLimits {
+    pub max_payload_size: SerializableU64,
+    pub max_instructions: SerializableU64,
+    pub max_depth: SerializableU64,
+}
+
+impl From<CompilationLimits> for SerializableCompilationLimits {
+    fn from(value: CompilationLimits) -> Self {
+        Self {
+            max_payload_size: (value.max_payload_size as u64).into(),
+            max_instructions: (value.max_instructions as u64).into(),
+            max_depth: (value.max_depth as u64).into(),
+        }
+    }
+}
+
+impl TryFrom<SerializableCompilationLimits> for CompilationLimits {
+    type Error = InvocationHandlingError;
+
+    fn try_from(
+        value: SerializableCompilationLimits,
+    ) -> Result<Self, Self::Error> {
+        let to_usize = |value: SerializableU64| {
+            usize::try_from(*value).map_err(|error| {
+                InvocationHandlingError::CompilationLimitsError(debug_string(
+                    error,
+                ))
+            })
+        };
+        Ok(Self {
+            max_payload_size: to_usize(value.max_payload_size)?,
+            max_instructions: to_usize(value.max_instructions)?,
+            max_depth: to_usize(value.max_depth)?,
+        })
+    }
+}
+
+/// The limits that a compiled payload is checked against, either the limits
+/// of the network that it's compiled for or custom ones.
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableCompilationLimitsSource {
+    Network,
+    Custom(SerializableCompilationLimits),
+}
+
+impl SerializableCompilationLimitsSource {
+    pub fn to_native(
+        &self,
+        network_id: u8,
+    ) -> Result<CompilationLimits, InvocationHandlingError> {
+        match self {
+            Self::Network => Ok(CompilationLimits::for_network(network_id)),
+            Self::Custom(limits) => limits.clone().try_into(),
+        }
+    }
+}
+
+#[typeshare::typeshare]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "value")]
+pub enum SerializableLimit {
+    PayloadSize(SerializableU64),
+    InstructionCount(SerializableU64),
+    Depth(SerializableU64),
+}
+
+impl From<Limit> for SerializableLimit {
+    fn from(value: Limit) -> Self {
+        match value {
+            Limit::PayloadSize(maximum) => {
+                Self::PayloadSize((maximum as u64).into())
+            }
+            Limit::InstructionCount(maximum) => {
+                Self::InstructionCount((maximum as u64).into())
+            }
+            Limit::Depth(maximum) => Self::Depth((maximum as u64).into()),
+        }
+    }
+}
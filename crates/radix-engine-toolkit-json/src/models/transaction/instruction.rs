@@ -20,6 +20,7 @@ use std::ops::Deref;
 use crate::prelude::*;
 
 use radix_common::prelude::*;
+use radix_engine_toolkit::functions::instructions::DecompiledInstruction;
 use radix_transactions::prelude::*;
 use schemars::*;
 use serde::*;
@@ -155,6 +156,15 @@ pub enum SerializableInstruction {
         package_address: SerializableNodeId,
         blueprint_name: String,
     },
+
+    /// An instruction that this version of the toolkit does not know of, kept
+    /// as its raw discriminator and fields so that it can be compiled back.
+    /// Only produced when decompiling compiled instructions and can only be
+    /// compiled, not converted to a manifest string or analyzed.
+    Unknown {
+        discriminator: SerializableU8,
+        fields: Vec<SerializableManifestValue>,
+    },
 }
 
 impl SerializableInstruction {
@@ -699,9 +709,57 @@ impl SerializableInstruction {
                 package_address: (*package_address).try_into()?,
                 blueprint_name: blueprint_name.to_owned(),
             },
+            Self::Unknown { discriminator, .. } => {
+                return Err(InstructionConversionError::UnknownInstruction(
+                    **discriminator,
+                ))
+            }
         };
         Ok(instruction)
     }
+
+    pub fn from_decompiled_instruction(
+        instruction: &DecompiledInstruction,
+        network_id: u8,
+    ) -> Result<Self, InstructionConversionError> {
+        match instruction {
+            DecompiledInstruction::Known(instruction) => {
+                Self::from_instruction(instruction, network_id)
+            }
+            DecompiledInstruction::Unknown {
+                discriminator,
+                fields,
+            } => Ok(Self::Unknown {
+                discriminator: (*discriminator).into(),
+                fields: fields
+                    .iter()
+                    .map(|field| {
+                        SerializableManifestValue::from_manifest_value(
+                            field, network_id,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?,
+            }),
+        }
+    }
+
+    pub fn to_decompiled_instruction(
+        &self,
+    ) -> Result<DecompiledInstruction, InstructionConversionError> {
+        match self {
+            Self::Unknown {
+                discriminator,
+                fields,
+            } => Ok(DecompiledInstruction::Unknown {
+                discriminator: **discriminator,
+                fields: fields
+                    .iter()
+                    .map(SerializableManifestValue::to_manifest_value)
+                    .collect::<Result<_, _>>()?,
+            }),
+            _ => self.to_instruction().map(DecompiledInstruction::Known),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
@@ -709,6 +767,9 @@ impl SerializableInstruction {
 pub enum InstructionConversionError {
     ValueConversionError(ValueConversionError),
     NodeIdConversionError(SerializableNodeIdError),
+    /// The instruction is not known to this version of the toolkit and has
+    /// no native representation.
+    UnknownInstruction(u8),
 }
 
 impl From<ValueConversionError> for InstructionConversionError {
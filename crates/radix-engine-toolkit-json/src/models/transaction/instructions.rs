@@ -17,7 +17,13 @@
 
 use crate::prelude::*;
 
-use radix_engine_toolkit::utils::*;
+use radix_engine_toolkit::caches::bech32_coders;
+use radix_engine_toolkit::functions::annotations::*;
+use radix_engine_toolkit::functions::instructions::DecompiledInstruction;
+use radix_engine_toolkit::functions::manifest::{
+    compile_string, BlobReferenceError, LocatedCompileError,
+    ManifestSourceLocation,
+};
 use radix_transactions::manifest::*;
 use radix_transactions::prelude::*;
 use schemars::JsonSchema;
@@ -46,11 +52,9 @@ impl SerializableInstructions {
     ) -> Result<Self, SerializableInstructionsError> {
         match kind {
             SerializableInstructionsKind::String => {
-                let network_definition =
-                    network_definition_from_network_id(network_id);
                 let string = radix_transactions::manifest::decompile(
                     instructions,
-                    &network_definition,
+                    &bech32_coders(network_id).network_definition,
                 )?;
                 Ok(Self::String(string))
             }
@@ -62,15 +66,104 @@ impl SerializableInstructions {
         }
     }
 
+    /// Creates the serializable instructions from decompiled instructions.
+    /// Unknown instructions can't be written as a manifest string and are
+    /// therefore always returned as parsed instructions regardless of the
+    /// requested kind.
+    pub fn from_decompiled_instructions(
+        instructions: &[DecompiledInstruction],
+        kind: SerializableInstructionsKind,
+        network_id: u8,
+    ) -> Result<Self, SerializableInstructionsError> {
+        if instructions.iter().any(DecompiledInstruction::is_unknown) {
+            let instructions = instructions
+                .iter()
+                .enumerate()
+                .map(|(instruction_index, instruction)| {
+                    SerializableInstruction::from_decompiled_instruction(
+                        instruction,
+                        network_id,
+                    )
+                    .map_err(|error| {
+                        LocatedInstructionConversionError {
+                            instruction_index,
+                            error,
+                        }
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Self::Parsed(instructions))
+        } else {
+            let instructions = instructions
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    DecompiledInstruction::Known(instruction) => {
+                        Some(instruction.clone())
+                    }
+                    DecompiledInstruction::Unknown { .. } => None,
+                })
+                .collect::<Vec<_>>();
+            Self::new(&instructions, kind, network_id)
+        }
+    }
+
+    /// Converts the instructions to decompiled instructions which, unlike
+    /// [`Self::to_instructions`], allows for unknown instructions.
+    pub fn to_decompiled_instructions(
+        &self,
+        network_id: u8,
+    ) -> Result<Vec<DecompiledInstruction>, SerializableInstructionsError> {
+        match self {
+            Self::String(..) => {
+                self.to_instructions(network_id).map(|instructions| {
+                    instructions
+                        .into_iter()
+                        .map(DecompiledInstruction::Known)
+                        .collect()
+                })
+            }
+            Self::Parsed(parsed) => parsed
+                .iter()
+                .enumerate()
+                .map(|(instruction_index, instruction)| {
+                    instruction.to_decompiled_instruction().map_err(|error| {
+                        SerializableInstructionsError::from(
+                            LocatedInstructionConversionError {
+                                instruction_index,
+                                error,
+                            },
+                        )
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Replaces the manifest string with one annotated with comments that
+    /// describe the instructions. Parsed instructions have no place for the
+    /// comments and are left as they are.
+    pub fn annotate(
+        &mut self,
+        instructions: &[InstructionV1],
+        network_id: u8,
+    ) -> Result<(), SerializableInstructionsError> {
+        if let Self::String(string) = self {
+            *string = decompile_annotated(
+                instructions,
+                &bech32_coders(network_id).network_definition,
+            )?;
+        }
+        Ok(())
+    }
+
     pub fn to_instructions(
         &self,
         network_id: u8,
     ) -> Result<Vec<InstructionV1>, SerializableInstructionsError> {
         match self {
-            Self::String(string) => radix_transactions::manifest::compile(
+            Self::String(string) => compile_string(
                 string,
-                &network_definition_from_network_id(network_id),
-                MockBlobProvider::new(),
+                &bech32_coders(network_id).network_definition,
             )
             .map_err(SerializableInstructionsError::from)
             .map(|manifest| manifest.instructions),
@@ -93,16 +186,15 @@ impl SerializableInstructions {
                 let instructions = to_native_instructions(parsed)?;
                 let string = decompile(
                     &instructions,
-                    &network_definition_from_network_id(network_id),
+                    &bech32_coders(network_id).network_definition,
                 )?;
                 *self = Self::String(string);
                 Ok(())
             }
             (Self::String(string), SerializableInstructionsKind::Parsed) => {
-                let instructions = radix_transactions::manifest::compile(
+                let instructions = compile_string(
                     string,
-                    &network_definition_from_network_id(network_id),
-                    MockBlobProvider::new(),
+                    &bech32_coders(network_id).network_definition,
                 )
                 .map(|manifest| manifest.instructions)?;
                 let instructions =
@@ -117,8 +209,21 @@ impl SerializableInstructions {
 #[derive(Debug, Clone)]
 pub enum SerializableInstructionsError {
     CompilationError(String),
+    /// A manifest string failed to compile because of the instruction at the
+    /// given index which starts at the given 1-based line and column.
+    LocatedCompilationError {
+        instruction_index: usize,
+        line: usize,
+        column: usize,
+        error: String,
+    },
     DecompilationError(String),
     LocatedInstructionConversionError(LocatedInstructionConversionError),
+    BlobHashMismatch {
+        hash: Hash,
+        actual_hash: Hash,
+    },
+    BlobReferenceError(BlobReferenceError),
 }
 
 impl From<CompileError> for SerializableInstructionsError {
@@ -127,6 +232,26 @@ impl From<CompileError> for SerializableInstructionsError {
     }
 }
 
+impl From<LocatedCompileError> for SerializableInstructionsError {
+    fn from(
+        LocatedCompileError { error, location }: LocatedCompileError,
+    ) -> Self {
+        match location {
+            Some(ManifestSourceLocation {
+                instruction_index,
+                line,
+                column,
+            }) => Self::LocatedCompilationError {
+                instruction_index,
+                line,
+                column,
+                error: format!("{error:?}"),
+            },
+            None => Self::from(error),
+        }
+    }
+}
+
 impl From<DecompileError> for SerializableInstructionsError {
     fn from(value: DecompileError) -> Self {
         Self::CompilationError(format!("{value:?}"))
@@ -139,6 +264,12 @@ impl From<LocatedInstructionConversionError> for SerializableInstructionsError {
     }
 }
 
+impl From<BlobReferenceError> for SerializableInstructionsError {
+    fn from(value: BlobReferenceError) -> Self {
+        Self::BlobReferenceError(value)
+    }
+}
+
 impl FromNative for SerializableInstructions {
     type Native = Vec<InstructionV1>;
     type Error = SerializableInstructionsError;
@@ -67,7 +67,7 @@ impl FromNative for SerializableIntent {
             .blobs
             .clone()
             .into_iter()
-            .map(|blob| blob.0.into())
+            .map(|blob| (scrypto::prelude::hash(&blob.0).into(), blob.0.into()))
             .collect();
         let message = native.message.clone().into();
 
@@ -17,6 +17,8 @@
 
 use std::ops::Deref;
 
+use ::indexmap::IndexMap;
+use radix_engine_toolkit::functions::manifest::validate_blob_references;
 use radix_transactions::prelude::TransactionManifestV1;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -27,7 +29,12 @@ use crate::prelude::*;
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
 pub struct SerializableTransactionManifest {
     pub instructions: SerializableInstructions,
-    pub blobs: Vec<SerializableBytes>,
+    /// The blobs of the manifest keyed by their hash. Every blob referenced by
+    /// the instructions must be found here.
+    #[typeshare(
+        serialized_as = "HashMap<SerializableHash, SerializableBytes>"
+    )]
+    pub blobs: IndexMap<SerializableHash, SerializableBytes>,
 }
 
 impl FromNative for SerializableTransactionManifest {
@@ -40,15 +47,25 @@ impl FromNative for SerializableTransactionManifest {
         let blobs = self
             .blobs
             .iter()
-            .map(|value| {
-                (scrypto::prelude::hash(&**value), value.deref().clone())
+            .map(|(hash, value)| {
+                let actual_hash = scrypto::prelude::hash(&**value);
+                if **hash == actual_hash {
+                    Ok((actual_hash, value.deref().clone()))
+                } else {
+                    Err(SerializableInstructionsError::BlobHashMismatch {
+                        hash: **hash,
+                        actual_hash,
+                    })
+                }
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Ok(TransactionManifestV1 {
+        let manifest = TransactionManifestV1 {
             instructions,
             blobs,
-        })
+        };
+        validate_blob_references(&manifest)?;
+        Ok(manifest)
     }
 
     fn from_native(
@@ -61,7 +78,11 @@ impl FromNative for SerializableTransactionManifest {
             network_id,
             instructions_kind,
         )?;
-        let blobs = native.blobs.values().cloned().map(Into::into).collect();
+        let blobs = native
+            .blobs
+            .iter()
+            .map(|(hash, value)| ((*hash).into(), value.clone().into()))
+            .collect();
 
         Ok(Self {
             instructions,
@@ -20,21 +20,50 @@
 pub use crate::error::*;
 pub use crate::memory::*;
 pub use crate::utils::*;
+pub use crate::versioning::*;
+pub use crate::warning::*;
+pub use crate::wasi::*;
 
+pub use crate::functions::access_controller::*;
+pub use crate::functions::access_rule::*;
+pub use crate::functions::account::*;
+pub use crate::functions::account_locker::*;
 pub use crate::functions::address::*;
+pub use crate::functions::batch::*;
+pub use crate::functions::decimal::*;
 pub use crate::functions::derive::*;
+pub use crate::functions::dispatch::*;
+pub use crate::functions::events::*;
 pub use crate::functions::handler::*;
 pub use crate::functions::information::*;
 pub use crate::functions::instructions::*;
 pub use crate::functions::intent::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::functions::jobs::*;
+pub use crate::functions::ledger::*;
 pub use crate::functions::macros::*;
 pub use crate::functions::manifest::*;
 pub use crate::functions::manifest_sbor::*;
+pub use crate::functions::metadata::*;
+pub use crate::functions::non_fungible_data::*;
+pub use crate::functions::non_fungible_global_id::*;
+pub use crate::functions::non_fungible_local_id::*;
 pub use crate::functions::notarized_transaction::*;
+pub use crate::functions::package::*;
+pub use crate::functions::payload::*;
+pub use crate::functions::pool::*;
+pub use crate::functions::preview::*;
+pub use crate::functions::random::*;
+pub use crate::functions::rola::*;
+pub use crate::functions::royalty::*;
 pub use crate::functions::scrypto_sbor::*;
 pub use crate::functions::signed_intent::*;
+pub use crate::functions::signing::*;
 pub use crate::functions::traits::*;
 pub use crate::functions::utils::*;
+pub use crate::functions::validator::*;
+pub use crate::functions::wallet_interaction::*;
+pub use crate::functions::xrd::*;
 
 pub use crate::models::common::*;
 pub use crate::models::cryptographic::public_key::*;
@@ -46,9 +75,14 @@ pub use crate::models::olympia::network::*;
 pub use crate::models::sbor::local_type_id::*;
 pub use crate::models::sbor::schema::*;
 pub use crate::models::sbor::serialization_mode::*;
+pub use crate::models::scrypto::access_rule::*;
+pub use crate::models::scrypto::metadata_value::*;
 pub use crate::models::scrypto::node_id::*;
 pub use crate::models::scrypto::non_fungible_global_id::*;
+pub use crate::models::scrypto::royalty_amount::*;
+pub use crate::models::scrypto::value_kind::*;
 pub use crate::models::traits::*;
+pub use crate::models::transaction::compilation_limits::*;
 pub use crate::models::transaction::hash::*;
 pub use crate::models::transaction::header::*;
 pub use crate::models::transaction::instruction::*;
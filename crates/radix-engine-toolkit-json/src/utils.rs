@@ -21,7 +21,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::error::InvocationInterpretationError;
-use crate::memory::Pointer;
+use crate::memory::{Pointer, RetBuffer};
 
 pub fn debug_string<T: Debug>(object: T) -> String {
     format!("{:?}", object)
@@ -41,7 +41,8 @@ pub fn serialize_and_write_to_memory<S: Serialize>(
             let byte_count = object_bytes.len() + 1;
 
             unsafe {
-                let pointer = crate::memory::toolkit_alloc(byte_count);
+                let pointer =
+                    crate::memory::allocate_output(byte_count) as Pointer;
                 pointer.copy_from(
                     [object_bytes, &[0]].concat().as_ptr() as Pointer,
                     byte_count,
@@ -52,6 +53,52 @@ pub fn serialize_and_write_to_memory<S: Serialize>(
         })
 }
 
+pub fn serialize_to_ret_buffer<S: Serialize>(
+    object: &S,
+    is_error: bool,
+) -> Result<RetBuffer, InvocationInterpretationError> {
+    serde_json::to_vec(object)
+        .map_err(|error| {
+            InvocationInterpretationError::SerializationError(debug_string(
+                error,
+            ))
+        })
+        .map(|bytes| write_to_ret_buffer(&bytes, is_error))
+}
+
+pub fn serialize_to_cbor_ret_buffer<S: Serialize>(
+    object: &S,
+    is_error: bool,
+) -> Result<RetBuffer, InvocationInterpretationError> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(object, &mut bytes)
+        .map_err(|error| {
+            InvocationInterpretationError::SerializationError(debug_string(
+                error,
+            ))
+        })
+        .map(|_| write_to_ret_buffer(&bytes, is_error))
+}
+
+pub fn deserialize_from_cbor<D: DeserializeOwned>(
+    bytes: &[u8],
+) -> Result<D, InvocationInterpretationError> {
+    ciborium::from_reader(bytes).map_err(|error| {
+        InvocationInterpretationError::DeserializationError(debug_string(error))
+    })
+}
+
+fn write_to_ret_buffer(bytes: &[u8], is_error: bool) -> RetBuffer {
+    let len = bytes.len();
+    let ptr = unsafe {
+        let pointer = crate::memory::allocate_output(len);
+        pointer.copy_from(bytes.as_ptr(), len);
+        pointer
+    };
+
+    RetBuffer { ptr, len, is_error }
+}
+
 pub fn read_and_deserialize_from_memory<'s, D: Deserialize<'s>>(
     string_pointer: Pointer,
 ) -> Result<D, InvocationInterpretationError> {
@@ -108,3 +155,38 @@ pub fn deserialize_from_jstring<D: DeserializeOwned>(
 
     Ok(result)
 }
+
+#[cfg(feature = "wasm-bindgen")]
+pub fn serialize_to_js_value<S: Serialize>(
+    object: &S,
+) -> Result<wasm_bindgen::JsValue, InvocationInterpretationError> {
+    serde_json::to_string(object)
+        .map_err(|error| {
+            InvocationInterpretationError::SerializationError(debug_string(
+                error,
+            ))
+        })
+        .and_then(|string| {
+            js_sys::JSON::parse(&string).map_err(|error| {
+                InvocationInterpretationError::SerializationError(
+                    debug_string(error),
+                )
+            })
+        })
+}
+
+#[cfg(feature = "wasm-bindgen")]
+pub fn deserialize_from_js_value<D: DeserializeOwned>(
+    value: &wasm_bindgen::JsValue,
+) -> Result<D, InvocationInterpretationError> {
+    let string = js_sys::JSON::stringify(value).map_err(|error| {
+        InvocationInterpretationError::DeserializationError(debug_string(error))
+    })?;
+
+    let string = String::from(string);
+    let result = serde_json::from_str(&string).map_err(|error| {
+        InvocationInterpretationError::DeserializationError(debug_string(error))
+    })?;
+
+    Ok(result)
+}
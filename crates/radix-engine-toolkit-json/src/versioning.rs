@@ -0,0 +1,84 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Versioning of the schema of the requests. Callers may state the version of
+//! the schema that their request was written against through an optional
+//! top-level `schema_version` field of the input. Requests written against an
+//! older version are upgraded to the current version before they're handled,
+//! which allows long-lived callers to keep working across changes to the
+//! inputs of the functions. Requests without a version are interpreted as
+//! being written against the current version.
+
+use crate::error::InvocationInterpretationError;
+use crate::functions::traits::Function;
+use crate::warning::Warning;
+
+/// The oldest version of the request schema that requests can be written
+/// against.
+pub const MINIMUM_SCHEMA_VERSION: u32 = 1;
+
+/// The version of the request schema that the functions currently accept.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// The name of the optional field of the input that holds the version of the
+/// request schema.
+pub const SCHEMA_VERSION_FIELD: &str = "schema_version";
+
+/// Removes the schema version from the input, if any, and upgrades the input
+/// from that version to the current one. The version may be given either as
+/// a number or as a string like the other integers of the schema.
+pub fn upgrade_input<'f, F>(
+    input: &mut serde_json::Value,
+    warnings: &mut Vec<Warning>,
+) -> Result<(), InvocationInterpretationError>
+where
+    F: Function<'f>,
+{
+    let schema_version = match input
+        .as_object_mut()
+        .and_then(|object| object.remove(SCHEMA_VERSION_FIELD))
+    {
+        Some(schema_version) => parse_schema_version(&schema_version)?,
+        None => CURRENT_SCHEMA_VERSION,
+    };
+
+    for version in schema_version..CURRENT_SCHEMA_VERSION {
+        F::upgrade_input(input, version, warnings)
+    }
+    Ok(())
+}
+
+fn parse_schema_version(
+    schema_version: &serde_json::Value,
+) -> Result<u32, InvocationInterpretationError> {
+    let version = match schema_version {
+        serde_json::Value::Number(number) => number
+            .as_u64()
+            .and_then(|number| u32::try_from(number).ok()),
+        serde_json::Value::String(string) => string.parse().ok(),
+        _ => None,
+    };
+    version
+        .filter(|version| {
+            (MINIMUM_SCHEMA_VERSION..=CURRENT_SCHEMA_VERSION).contains(version)
+        })
+        .ok_or_else(|| {
+            InvocationInterpretationError::UnsupportedSchemaVersion(
+                schema_version.to_string(),
+            )
+        })
+}
@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A non-fatal signal reported alongside the successful output of a function
+/// to inform the caller of something that they might want to act upon, such
+/// as parts of their input being ignored.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", content = "warning")]
+pub enum Warning {
+    /// A field of the input is not known to the function and was ignored. The
+    /// path is a JSON pointer to the ignored field.
+    UnknownFieldIgnored { path: String },
+}
+
+/// The envelope of a successful response which contains the output of the
+/// function and the warnings reported while handling the invocation.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct Response<T> {
+    pub output: T,
+    pub warnings: Vec<Warning>,
+}
+
+/// Finds the fields of the raw input that were ignored during deserialization
+/// by comparing the raw input against the serialized form of the deserialized
+/// input. A warning is reported for each field that's missing from the latter.
+pub fn ignored_fields(
+    raw: &serde_json::Value,
+    interpreted: &serde_json::Value,
+) -> Vec<Warning> {
+    let mut warnings = vec![];
+    find_ignored_fields(raw, interpreted, &mut String::new(), &mut warnings);
+    warnings
+}
+
+fn find_ignored_fields(
+    raw: &serde_json::Value,
+    interpreted: &serde_json::Value,
+    path: &mut String,
+    warnings: &mut Vec<Warning>,
+) {
+    match (raw, interpreted) {
+        (
+            serde_json::Value::Object(raw),
+            serde_json::Value::Object(interpreted),
+        ) => {
+            for (key, raw_value) in raw {
+                let length = path.len();
+                path.push('/');
+                path.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                match interpreted.get(key) {
+                    Some(interpreted_value) => find_ignored_fields(
+                        raw_value,
+                        interpreted_value,
+                        path,
+                        warnings,
+                    ),
+                    None => warnings.push(Warning::UnknownFieldIgnored {
+                        path: path.clone(),
+                    }),
+                }
+                path.truncate(length);
+            }
+        }
+        (
+            serde_json::Value::Array(raw),
+            serde_json::Value::Array(interpreted),
+        ) if raw.len() == interpreted.len() => {
+            for (index, (raw_value, interpreted_value)) in
+                raw.iter().zip(interpreted).enumerate()
+            {
+                let length = path.len();
+                path.push('/');
+                path.push_str(&index.to_string());
+                find_ignored_fields(
+                    raw_value,
+                    interpreted_value,
+                    path,
+                    warnings,
+                );
+                path.truncate(length);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,142 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The WASI interface of the toolkit. Requests and responses are exchanged
+//! over a pair of byte streams (typically the stdin and stdout of the WASI
+//! module) as length-prefixed frames. Each frame is made up of a 4-byte
+//! little-endian length followed by that many bytes of a UTF-8 encoded JSON
+//! payload.
+
+use std::io::{ErrorKind, Read, Write};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The maximum length of a single frame. Frames longer than this are rejected
+/// without reading their payload.
+pub const MAX_FRAME_LENGTH: u32 = 64 * 1024 * 1024;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+pub struct WasiRequest {
+    pub function: String,
+    pub input: serde_json::Value,
+}
+
+pub type WasiResponse = InvocationResponse;
+
+#[derive(Debug)]
+pub enum FramingError {
+    IoError(std::io::Error),
+    FrameTooLong { length: u32, maximum: u32 },
+    TruncatedFrame { expected: u32 },
+}
+
+impl From<std::io::Error> for FramingError {
+    fn from(value: std::io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
+
+impl From<FramingError> for InvocationInterpretationError {
+    fn from(value: FramingError) -> Self {
+        Self::FramingError(debug_string(value))
+    }
+}
+
+/// Reads a single frame from the reader. [`None`] is returned if the reader
+/// has reached its end before any byte of the frame was read.
+pub fn read_frame<R: Read>(
+    reader: &mut R,
+) -> Result<Option<Vec<u8>>, FramingError> {
+    let mut length_bytes = [0u8; 4];
+    let mut read = 0;
+    while read < length_bytes.len() {
+        match reader.read(&mut length_bytes[read..]) {
+            Ok(0) if read == 0 => return Ok(None),
+            Ok(0) => return Err(FramingError::TruncatedFrame { expected: 4 }),
+            Ok(count) => read += count,
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    let length = u32::from_le_bytes(length_bytes);
+    if length > MAX_FRAME_LENGTH {
+        return Err(FramingError::FrameTooLong {
+            length,
+            maximum: MAX_FRAME_LENGTH,
+        });
+    }
+
+    let mut payload = vec![0u8; length as usize];
+    reader.read_exact(&mut payload).map_err(|error| {
+        if error.kind() == ErrorKind::UnexpectedEof {
+            FramingError::TruncatedFrame { expected: length }
+        } else {
+            error.into()
+        }
+    })?;
+    Ok(Some(payload))
+}
+
+/// Writes the payload to the writer as a single frame.
+pub fn write_frame<W: Write>(
+    writer: &mut W,
+    payload: &[u8],
+) -> Result<(), FramingError> {
+    let length = u32::try_from(payload.len())
+        .ok()
+        .filter(|length| *length <= MAX_FRAME_LENGTH)
+        .ok_or(FramingError::FrameTooLong {
+            length: u32::MAX,
+            maximum: MAX_FRAME_LENGTH,
+        })?;
+    writer.write_all(&length.to_le_bytes())?;
+    writer.write_all(payload)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Handles the payload of a single request frame returning the payload of the
+/// response frame. This function never fails, all errors are reported in the
+/// [`WasiResponse`].
+pub fn handle_frame(payload: &[u8]) -> Vec<u8> {
+    let response = serde_json::from_slice::<WasiRequest>(payload)
+        .map_err(|error| {
+            Error::from(InvocationInterpretationError::DeserializationError(
+                debug_string(error),
+            ))
+        })
+        .and_then(|WasiRequest { function, input }| invoke(&function, input));
+    serde_json::to_vec(&WasiResponse::from(response)).expect("can't fail")
+}
+
+/// Serves requests read from the reader until it reaches its end, writing a
+/// response frame to the writer for every request frame. A framing error ends
+/// the session since the stream can no longer be trusted to be aligned on a
+/// frame boundary.
+pub fn serve<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+) -> Result<(), FramingError> {
+    while let Some(payload) = read_frame(reader)? {
+        write_frame(writer, &handle_frame(&payload))?;
+    }
+    Ok(())
+}
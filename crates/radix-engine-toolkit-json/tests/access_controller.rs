@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn cancel_recovery_manifest_is_built() {
+    // Arrange
+    let input = BuildAccessControllerManifestInput {
+        access_controller_address: SerializableNodeId::new(
+            access_controller(),
+            1,
+        ),
+        operations: vec![
+            SerializableAccessControllerOperation::CancelRecovery {
+                proposer: SerializableAccessControllerProposer::Recovery,
+            },
+        ],
+        network_id: 1.into(),
+        instructions_kind: SerializableInstructionsKind::Parsed,
+    };
+
+    // Act
+    let output = BuildAccessControllerManifest::handle(input).unwrap();
+
+    // Assert
+    let SerializableInstructions::Parsed(instructions) = output.instructions
+    else {
+        panic!("The instructions are not parsed");
+    };
+    assert!(matches!(
+        instructions.as_slice(),
+        [SerializableInstruction::CallMethod { method_name, .. }]
+            if method_name == "cancel_recovery_role_recovery_proposal"
+    ));
+}
+
+#[test]
+fn unrecoverable_rule_set_is_an_access_controller_operation_error() {
+    // Arrange
+    let input = BuildAccessControllerManifestInput {
+        access_controller_address: SerializableNodeId::new(
+            access_controller(),
+            1,
+        ),
+        operations: vec![
+            SerializableAccessControllerOperation::InitiateRecovery {
+                proposer: SerializableAccessControllerProposer::Primary,
+                rule_set: SerializableRuleSet {
+                    primary_role: SerializableAccessRule::DenyAll,
+                    recovery_role: SerializableAccessRule::DenyAll,
+                    confirmation_role: SerializableAccessRule::DenyAll,
+                },
+                timed_recovery_delay_in_minutes: None,
+            },
+        ],
+        network_id: 1.into(),
+        instructions_kind: SerializableInstructionsKind::String,
+    };
+
+    // Act
+    let output = BuildAccessControllerManifest::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::AccessControllerOperationError(..))
+    ));
+}
+
+fn access_controller() -> NodeId {
+    NodeId::new(
+        EntityType::GlobalAccessController as u8,
+        &[1; NodeId::RID_LENGTH],
+    )
+}
@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn access_rule_survives_a_conversion_round_trip() {
+    // Arrange
+    let access_rule = rule!(
+        require(XRD)
+            && require_n_of(
+                2,
+                vec![
+                    ResourceOrNonFungible::from(ACCOUNT_OWNER_BADGE),
+                    ResourceOrNonFungible::from(NonFungibleGlobalId::new(
+                        IDENTITY_OWNER_BADGE,
+                        NonFungibleLocalId::integer(1)
+                    ))
+                ]
+            )
+    );
+
+    // Act
+    let serializable_access_rule =
+        SerializableAccessRule::from_native(&access_rule, 0x01);
+    let converted_access_rule = serializable_access_rule.to_native().unwrap();
+
+    // Assert
+    assert_eq!(converted_access_rule, access_rule);
+}
+
+#[test]
+fn access_rule_can_be_decoded() {
+    // Arrange
+    let access_rule = rule!(require_amount(dec!("10"), XRD));
+    let encoded_access_rule = scrypto_encode(&access_rule).unwrap();
+
+    // Act
+    let output = AccessRuleDecode::handle(AccessRuleDecodeInput {
+        encoded_access_rule: encoded_access_rule.into(),
+        network_id: 0x01.into(),
+    })
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        output,
+        SerializableAccessRule::Protected(
+            SerializableCompositeRequirement::ProofRule(
+                SerializableProofRule::AmountOf {
+                    amount: dec!("10").into(),
+                    resource_address: SerializableNodeId::from_global_address(
+                        XRD, 0x01
+                    ),
+                }
+            )
+        )
+    );
+}
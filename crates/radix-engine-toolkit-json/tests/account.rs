@@ -0,0 +1,86 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+use serde_json::json;
+
+#[test]
+fn account_withdraw_instruction_is_built() {
+    // Arrange
+    let account = account(1);
+    let input = BuildAccountInstructionInput {
+        account_address: SerializableNodeId::new(account.into_node_id(), 1),
+        method: SerializableAccountMethod::Withdraw {
+            resource_address: SerializableNodeId::new(XRD.into_node_id(), 1),
+            amount: dec!(10).into(),
+        },
+        network_id: 1.into(),
+    };
+
+    // Act
+    let output = BuildAccountInstruction::handle(input).unwrap();
+
+    // Assert
+    let SerializableInstruction::CallMethod { method_name, .. } = output else {
+        panic!("Not a method call: {output:?}");
+    };
+    assert_eq!(method_name, "withdraw");
+}
+
+#[test]
+fn account_method_is_deserialized_from_its_tagged_form() {
+    // Act
+    let method = serde_json::from_value::<SerializableAccountMethod>(json!({
+        "kind": "TryDepositOrAbort",
+        "value": {
+            "bucket": "bucket0",
+            "authorized_depositor_badge": null
+        }
+    }));
+
+    // Assert
+    assert!(matches!(
+        method,
+        Ok(SerializableAccountMethod::TryDepositOrAbort { .. })
+    ));
+}
+
+#[test]
+fn account_instruction_on_a_non_account_is_an_error() {
+    // Arrange
+    let input = BuildAccountInstructionInput {
+        account_address: SerializableNodeId::new(XRD.into_node_id(), 1),
+        method: SerializableAccountMethod::Securify,
+        network_id: 1.into(),
+    };
+
+    // Act
+    let output = BuildAccountInstruction::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::InvalidAddress(..))
+    ));
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
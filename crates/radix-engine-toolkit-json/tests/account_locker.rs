@@ -0,0 +1,83 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn claim_manifest_is_built() {
+    // Arrange
+    let input = BuildAccountLockerManifestInput {
+        locker_address: SerializableNodeId::new(locker(), 1),
+        account_address: SerializableNodeId::new(account(1).into_node_id(), 1),
+        operations: vec![SerializableAccountLockerOperation::Claim {
+            resource_address: SerializableNodeId::new(XRD.into_node_id(), 1),
+            amount: dec!(10).into(),
+        }],
+        network_id: 1.into(),
+        instructions_kind: SerializableInstructionsKind::Parsed,
+    };
+
+    // Act
+    let output = BuildAccountLockerManifest::handle(input).unwrap();
+
+    // Assert
+    let SerializableInstructions::Parsed(instructions) = output.instructions
+    else {
+        panic!("The instructions are not parsed");
+    };
+    assert_eq!(instructions.len(), 2);
+    assert!(matches!(
+        &instructions[0],
+        SerializableInstruction::CallMethod { method_name, .. }
+            if method_name == "claim"
+    ));
+}
+
+#[test]
+fn operations_on_a_non_locker_address_are_an_account_locker_error() {
+    // Arrange
+    let input = BuildAccountLockerManifestInput {
+        locker_address: SerializableNodeId::new(account(2).into_node_id(), 1),
+        account_address: SerializableNodeId::new(account(1).into_node_id(), 1),
+        operations: vec![],
+        network_id: 1.into(),
+        instructions_kind: SerializableInstructionsKind::String,
+    };
+
+    // Act
+    let output = BuildAccountLockerManifest::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::AccountLockerOperationError(..))
+    ));
+}
+
+fn locker() -> NodeId {
+    NodeId::new(
+        EntityType::GlobalAccountLocker as u8,
+        &[1; NodeId::RID_LENGTH],
+    )
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
@@ -0,0 +1,55 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use serde_json::json;
+
+#[test]
+fn batch_responds_to_every_request_in_order() {
+    // Arrange
+    let input = serde_json::from_value::<BatchInput>(json!([
+        { "type": "build_information", "payload": {} },
+        { "type": "no_such_function", "payload": {} },
+        { "type": "utils_known_addresses", "payload": "1" },
+    ]))
+    .unwrap();
+
+    // Act
+    let output = Batch::handle(input).unwrap();
+
+    // Assert
+    let output = serde_json::to_value(output).unwrap();
+    assert_eq!(output.as_array().unwrap().len(), 3);
+    assert_eq!(output[0]["kind"], json!("Ok"));
+    assert!(output[0]["value"]["output"]["version"].is_string());
+    assert_eq!(output[1]["kind"], json!("Err"));
+    assert_eq!(output[1]["value"]["kind"], json!("UnknownFunction"));
+    assert_eq!(output[1]["value"]["code"], json!(1005));
+    assert_eq!(output[2]["kind"], json!("Ok"));
+    assert!(
+        output[2]["value"]["output"]["resource_addresses"]["xrd"].is_string()
+    );
+}
+
+#[test]
+fn empty_batch_produces_an_empty_response() {
+    // Act
+    let output = Batch::handle(vec![]).unwrap();
+
+    // Assert
+    assert!(output.is_empty())
+}
@@ -0,0 +1,196 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+use serde_json::json;
+
+#[test]
+fn decimals_can_be_multiplied() {
+    // Arrange
+    let input = serde_json::from_value::<DecimalArithmeticInput>(json!({
+        "kind": "Mul",
+        "value": { "lhs": "1.5", "rhs": "2" }
+    }))
+    .unwrap();
+
+    // Act
+    let output = DecimalArithmetic::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(*output, dec!("3"));
+}
+
+#[test]
+fn decimals_are_rounded_with_the_rounding_mode() {
+    // Arrange
+    let input = serde_json::from_value::<DecimalArithmeticInput>(json!({
+        "kind": "Round",
+        "value": {
+            "value": "2.5",
+            "decimal_places": "0",
+            "rounding_mode": "ToNearestMidpointToEven"
+        }
+    }))
+    .unwrap();
+
+    // Act
+    let output = DecimalArithmetic::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(*output, dec!("2"));
+}
+
+#[test]
+fn overflowing_decimal_operation_is_an_error() {
+    // Arrange
+    let input = DecimalArithmeticInput::Add {
+        lhs: Decimal::MAX.into(),
+        rhs: Decimal::ONE.into(),
+    };
+
+    // Act
+    let output = DecimalArithmetic::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::ArithmeticError(..))
+    ));
+}
+
+#[test]
+fn rounding_a_decimal_to_more_decimal_places_than_its_scale_is_an_error() {
+    // Arrange
+    let input = DecimalArithmeticInput::Round {
+        value: dec!("2.5").into(),
+        decimal_places: 19.into(),
+        rounding_mode: SerializableRoundingMode::ToZero,
+    };
+
+    // Act
+    let output = DecimalArithmetic::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::ArithmeticError(..))
+    ));
+}
+
+#[test]
+fn rounding_a_precise_decimal_to_negative_decimal_places_is_an_error() {
+    // Arrange
+    let input = PreciseDecimalArithmeticInput::Round {
+        value: pdec!("2.5").into(),
+        decimal_places: (-1).into(),
+        rounding_mode: SerializableRoundingMode::ToZero,
+    };
+
+    // Act
+    let output = PreciseDecimalArithmetic::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::ArithmeticError(..))
+    ));
+}
+
+#[test]
+fn precise_decimal_division_by_zero_is_an_error() {
+    // Arrange
+    let input = PreciseDecimalArithmeticInput::Div {
+        lhs: PreciseDecimal::ONE.into(),
+        rhs: PreciseDecimal::ZERO.into(),
+    };
+
+    // Act
+    let output = PreciseDecimalArithmetic::handle(input);
+
+    // Assert
+    assert!(output.is_err());
+}
+
+#[test]
+fn decimal_is_formatted_with_the_options() {
+    // Arrange
+    let input = serde_json::from_value::<FormatDecimalInput>(json!({
+        "value": { "kind": "Decimal", "value": "1234567.891" },
+        "options": {
+            "locale": {
+                "decimal_separator": ",",
+                "thousands_separator": "."
+            },
+            "max_fraction_digits": "2",
+            "rounding_mode": "ToZero"
+        }
+    }))
+    .unwrap();
+
+    // Act
+    let output = FormatDecimal::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(output, "1.234.567,89");
+}
+
+#[test]
+fn localized_precise_decimal_is_parsed() {
+    // Arrange
+    let input = ParseDecimalInput {
+        value: "1 234,000000000000000000001".to_owned(),
+        kind: SerializableDecimalKind::PreciseDecimal,
+        locale: SerializableDecimalLocaleOptions {
+            decimal_separator: ",".to_owned(),
+            thousands_separator: Some(" ".to_owned()),
+        },
+    };
+
+    // Act
+    let output = ParseDecimal::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(
+        output,
+        SerializableDecimalValue::PreciseDecimal(
+            pdec!("1234.000000000000000000001").into()
+        )
+    );
+}
+
+#[test]
+fn decimal_with_invalid_grouping_is_an_error() {
+    // Arrange
+    let input = ParseDecimalInput {
+        value: "1,2345".to_owned(),
+        kind: SerializableDecimalKind::Decimal,
+        locale: SerializableDecimalLocaleOptions {
+            decimal_separator: ".".to_owned(),
+            thousands_separator: Some(",".to_owned()),
+        },
+    };
+
+    // Act
+    let output = ParseDecimal::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::DecimalFormatError(..))
+    ));
+}
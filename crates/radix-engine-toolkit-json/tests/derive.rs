@@ -0,0 +1,49 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn virtual_signature_badge_is_derived_in_string_and_structured_form() {
+    // Arrange
+    let public_key = Ed25519PrivateKey::from_u64(1).unwrap().public_key();
+    let input = DeriveVirtualSignatureBadgeFromPublicKeyInput {
+        public_key: PublicKey::from(public_key).into(),
+        network_id: 1.into(),
+    };
+
+    // Act
+    let output =
+        DeriveVirtualSignatureBadgeFromPublicKey::handle(input).unwrap();
+
+    // Assert
+    let non_fungible_global_id =
+        NonFungibleGlobalId::from_public_key(&public_key);
+    assert_eq!(
+        output.non_fungible_global_id.0.non_fungible_global_id,
+        non_fungible_global_id
+    );
+    assert_eq!(
+        output.resource_address.0.node_id,
+        ED25519_SIGNATURE_VIRTUAL_BADGE.into_node_id()
+    );
+    assert_eq!(
+        *output.non_fungible_local_id,
+        non_fungible_global_id.local_id().clone()
+    );
+}
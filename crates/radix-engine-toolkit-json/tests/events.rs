@@ -0,0 +1,165 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use sbor::generate_full_schema_from_single_type;
+use scrypto::prelude::*;
+
+#[test]
+fn native_vault_event_is_decoded_with_the_bundled_schema() {
+    // Arrange
+    let vault =
+        NodeId([EntityType::InternalFungibleVault as u8; NodeId::LENGTH]);
+    let event = SerializableEncodedEvent {
+        event_type_identifier: SerializableEventTypeIdentifier {
+            emitter: SerializableEmitter::Method {
+                address: SerializableNodeId::new(vault, 1),
+                module: SerializableModuleId::Main,
+            },
+            event_name: "WithdrawEvent".to_owned(),
+        },
+        payload: scrypto_encode(&(dec!(10),)).unwrap().into(),
+        schema: None,
+    };
+
+    // Act
+    let output = DecodeEvents::handle(DecodeEventsInput {
+        events: vec![event],
+        network_id: 1.into(),
+    })
+    .unwrap();
+
+    // Assert
+    assert_eq!(output.len(), 1);
+    assert_eq!(output[0].event_name, "WithdrawEvent");
+    assert_eq!(
+        output[0].schema_source,
+        SerializableEventSchemaSource::Native
+    );
+    assert_eq!(output[0].value["type_name"], "WithdrawEvent");
+    assert_eq!(output[0].value["fields"][0]["field_name"], "amount");
+    assert_eq!(output[0].value["fields"][0]["value"], "10");
+}
+
+#[test]
+fn event_of_an_unknown_blueprint_is_decoded_without_a_schema() {
+    // Arrange
+    let component =
+        NodeId([EntityType::GlobalGenericComponent as u8; NodeId::LENGTH]);
+    let event = SerializableEncodedEvent {
+        event_type_identifier: SerializableEventTypeIdentifier {
+            emitter: SerializableEmitter::Method {
+                address: SerializableNodeId::new(component, 1),
+                module: SerializableModuleId::Main,
+            },
+            event_name: "SwapEvent".to_owned(),
+        },
+        payload: scrypto_encode(&(dec!(10),)).unwrap().into(),
+        schema: None,
+    };
+
+    // Act
+    let output = DecodeEvents::handle(DecodeEventsInput {
+        events: vec![event],
+        network_id: 1.into(),
+    })
+    .unwrap();
+
+    // Assert
+    assert_eq!(output[0].schema_source, SerializableEventSchemaSource::None);
+    assert_eq!(output[0].value["kind"], "Tuple");
+    assert!(output[0].value.get("type_name").is_none());
+}
+
+#[test]
+fn event_is_decoded_with_the_schema_supplied_by_the_caller() {
+    // Arrange
+    #[derive(ScryptoSbor)]
+    struct SwapEvent {
+        input_amount: Decimal,
+    }
+
+    let component =
+        NodeId([EntityType::GlobalGenericComponent as u8; NodeId::LENGTH]);
+    let (local_type_id, schema) = generate_full_schema_from_single_type::<
+        SwapEvent,
+        ScryptoCustomSchema,
+    >();
+    let event = SerializableEncodedEvent {
+        event_type_identifier: SerializableEventTypeIdentifier {
+            emitter: SerializableEmitter::Method {
+                address: SerializableNodeId::new(component, 1),
+                module: SerializableModuleId::Main,
+            },
+            event_name: "SwapEvent".to_owned(),
+        },
+        payload: scrypto_encode(&SwapEvent {
+            input_amount: dec!(10),
+        })
+        .unwrap()
+        .into(),
+        schema: Some(PayloadSchema {
+            local_type_id: local_type_id.into(),
+            schema: scrypto_encode(schema.v1()).unwrap().into(),
+        }),
+    };
+
+    // Act
+    let output = DecodeEvents::handle(DecodeEventsInput {
+        events: vec![event],
+        network_id: 1.into(),
+    })
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        output[0].schema_source,
+        SerializableEventSchemaSource::Custom
+    );
+    assert_eq!(output[0].value["type_name"], "SwapEvent");
+    assert_eq!(output[0].value["fields"][0]["field_name"], "input_amount");
+}
+
+#[test]
+fn payload_that_does_not_match_the_native_schema_is_an_error() {
+    // Arrange
+    let vault =
+        NodeId([EntityType::InternalFungibleVault as u8; NodeId::LENGTH]);
+    let event = SerializableEncodedEvent {
+        event_type_identifier: SerializableEventTypeIdentifier {
+            emitter: SerializableEmitter::Method {
+                address: SerializableNodeId::new(vault, 1),
+                module: SerializableModuleId::Main,
+            },
+            event_name: "WithdrawEvent".to_owned(),
+        },
+        payload: scrypto_encode(&("10",)).unwrap().into(),
+        schema: None,
+    };
+
+    // Act
+    let output = DecodeEvents::handle(DecodeEventsInput {
+        events: vec![event],
+        network_id: 1.into(),
+    });
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::ScryptoSborError(..))
+    ));
+}
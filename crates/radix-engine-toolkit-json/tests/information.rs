@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+
+#[test]
+fn all_exported_functions_are_listed() {
+    // Act
+    let functions = ListFunctions::handle(ListFunctionsInput {}).unwrap();
+
+    // Assert
+    let names = functions
+        .iter()
+        .map(|function| function.name.as_str())
+        .collect::<Vec<_>>();
+    for name in FUNCTION_NAMES {
+        assert!(names.contains(name), "{name} is not listed");
+    }
+    assert!(names.contains(&"batch"));
+    assert!(names.contains(&"list_functions"));
+}
+
+#[test]
+fn listed_functions_report_the_names_of_their_schemas() {
+    // Act
+    let functions = ListFunctions::handle(ListFunctionsInput {}).unwrap();
+
+    // Assert
+    let manifest_decompile = functions
+        .iter()
+        .find(|function| function.name == "manifest_decompile")
+        .unwrap();
+    assert_eq!(manifest_decompile.input_schema_id, "ManifestDecompileInput");
+    assert_eq!(
+        manifest_decompile.output_schema_id,
+        "SerializableTransactionManifest"
+    );
+}
+
+#[test]
+fn listed_functions_report_the_features_that_they_require() {
+    // Act
+    let functions = ListFunctions::handle(ListFunctionsInput {}).unwrap();
+
+    // Assert
+    let required_features = |name: &str| {
+        functions
+            .iter()
+            .find(|function| function.name == name)
+            .unwrap()
+            .required_features
+            .clone()
+    };
+    assert_eq!(
+        required_features("manifest_validate_instruction_arguments"),
+        vec!["native-blueprint-interfaces".to_owned()]
+    );
+    assert_eq!(
+        required_features("manifest_decompile"),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn error_codes_in_the_catalog_are_unique() {
+    // Act
+    let catalog = ErrorCatalog::handle(ErrorCatalogInput {}).unwrap();
+
+    // Assert
+    let codes = catalog
+        .iter()
+        .map(|entry| entry.code)
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(codes.len(), catalog.len());
+}
+
+#[test]
+fn error_responses_hold_the_code_and_message_of_the_error() {
+    // Arrange
+    let error = Error::InvocationHandlingError(
+        InvocationHandlingError::UnknownJob("10".to_owned()),
+    );
+
+    // Act
+    let response = ErrorResponse::from(error);
+
+    // Assert
+    assert_eq!(
+        response,
+        ErrorResponse {
+            code: 2010,
+            kind: "UnknownJob".to_owned(),
+            message: "10".to_owned(),
+            details: serde_json::Value::Null,
+        }
+    );
+    assert!(ERROR_CATALOG.iter().any(
+        |entry| entry.code == response.code && entry.kind == response.kind
+    ));
+}
+
+#[test]
+fn error_responses_hold_the_structured_details_of_the_error() {
+    // Arrange
+    let error = Error::InvocationHandlingError(
+        InvocationHandlingError::EncodeError("a".to_owned(), "b".to_owned()),
+    );
+
+    // Act
+    let response = ErrorResponse::from(error);
+
+    // Assert
+    assert_eq!(response.kind, "EncodeError");
+    assert_eq!(response.details, serde_json::json!(["a", "b"]));
+}
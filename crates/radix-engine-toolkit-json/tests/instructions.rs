@@ -21,6 +21,7 @@
 use radix_common::prelude::*;
 use radix_engine_toolkit::functions::manifest::summary;
 use radix_engine_toolkit_json::models::transaction::instruction::*;
+use radix_engine_toolkit_json::models::transaction::instructions::*;
 use radix_transactions::manifest::*;
 use walkdir::WalkDir;
 
@@ -114,6 +115,34 @@ fn common_manifests_can_be_converted_to_serialized_and_back_and_are_equal() {
     }
 }
 
+#[test]
+fn address_reservations_and_named_addresses_survive_round_trips() {
+    // Arrange
+    let manifest = compile(
+        include_str!("manifests/call/address_reservation.rtm"),
+        &NetworkDefinition::simulator(),
+        MockBlobProvider::new(),
+    )
+    .unwrap();
+    let mut serializable_instructions = SerializableInstructions::Parsed(
+        to_serializable_instructions(&manifest.instructions, 0xf2).unwrap(),
+    );
+
+    // Act
+    let from_parsed = serializable_instructions.to_instructions(0xf2).unwrap();
+    serializable_instructions
+        .convert_serializable_instructions_kind(
+            SerializableInstructionsKind::String,
+            0xf2,
+        )
+        .unwrap();
+    let from_string = serializable_instructions.to_instructions(0xf2).unwrap();
+
+    // Assert
+    assert_eq!(from_parsed, manifest.instructions);
+    assert_eq!(from_string, manifest.instructions);
+}
+
 #[test]
 fn manifest_with_invalid_invocation_can_be_summarized() {
     // Arrange
@@ -0,0 +1,105 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use serde_json::json;
+
+#[test]
+fn submitted_request_can_be_polled_until_completed() {
+    // Arrange
+    let SubmitRequestOutput { handle } =
+        SubmitRequest::handle(SubmitRequestInput {
+            function: "utils_known_addresses".to_owned(),
+            input: json!("1"),
+        })
+        .unwrap();
+
+    // Act
+    let response = loop {
+        match PollRequest::handle(PollRequestInput {
+            handle: handle.clone(),
+        })
+        .unwrap()
+        {
+            PollRequestOutput::Pending => std::thread::yield_now(),
+            PollRequestOutput::Completed(response) => break response,
+        }
+    };
+
+    // Assert
+    let response = serde_json::to_value(response).unwrap();
+    assert_eq!(response["kind"], json!("Ok"));
+    assert!(
+        response["value"]["output"]["resource_addresses"]["xrd"].is_string()
+    );
+    assert!(PollRequest::handle(PollRequestInput { handle }).is_err());
+}
+
+#[test]
+fn more_requests_than_workers_are_all_completed() {
+    // Arrange
+    let handles = (0..32)
+        .map(|_| {
+            SubmitRequest::handle(SubmitRequestInput {
+                function: "build_information".to_owned(),
+                input: json!({}),
+            })
+            .unwrap()
+            .handle
+        })
+        .collect::<Vec<_>>();
+
+    // Act
+    let responses = handles
+        .into_iter()
+        .map(|handle| loop {
+            match PollRequest::handle(PollRequestInput {
+                handle: handle.clone(),
+            })
+            .unwrap()
+            {
+                PollRequestOutput::Pending => std::thread::yield_now(),
+                PollRequestOutput::Completed(response) => break response,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Assert
+    assert!(responses
+        .iter()
+        .all(|response| matches!(response, InvocationResponse::Ok(..))));
+}
+
+#[test]
+fn cancelled_request_can_no_longer_be_polled() {
+    // Arrange
+    let SubmitRequestOutput { handle } =
+        SubmitRequest::handle(SubmitRequestInput {
+            function: "build_information".to_owned(),
+            input: json!({}),
+        })
+        .unwrap();
+
+    // Act
+    let result = CancelRequest::handle(CancelRequestInput {
+        handle: handle.clone(),
+    });
+
+    // Assert
+    assert!(result.is_ok());
+    assert!(PollRequest::handle(PollRequestInput { handle }).is_err());
+}
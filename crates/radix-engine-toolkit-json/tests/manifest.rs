@@ -0,0 +1,348 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn manifest_analysis_reports_withdraws_deposits_and_resources() {
+    // Arrange
+    let account1 = account(1);
+    let account2 = account(2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10)
+        .withdraw_from_account(account1, XRD, 10)
+        .take_from_worktop(XRD, 10, "bucket")
+        .try_deposit_or_abort(account2, None, "bucket")
+        .build();
+    let input = ManifestAnalyzeInput {
+        manifest: SerializableTransactionManifest::from_native(
+            &manifest,
+            0xf2,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap(),
+        network_id: 0xf2.into(),
+    };
+
+    // Act
+    let output = ManifestAnalyze::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(
+        output.accounts_withdrawn_from,
+        vec![SerializableNodeId::from_global_address(account1, 0xf2)]
+    );
+    assert_eq!(
+        output.accounts_deposited_into,
+        vec![SerializableNodeId::from_global_address(account2, 0xf2)]
+    );
+    assert_eq!(
+        output.resources_used,
+        vec![SerializableNodeId::from_global_address(XRD, 0xf2)]
+    );
+    assert_eq!(
+        output.reserved_instructions,
+        vec![SerializableReservedInstruction::AccountLockFee]
+    );
+}
+
+#[test]
+fn analyzer_rules_contribute_sections_with_their_findings() {
+    // Arrange
+    let account = account(1);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10)
+        .withdraw_from_account(account, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let input = ManifestRunAnalyzersInput {
+        manifest: SerializableTransactionManifest::from_native(
+            &manifest,
+            0xf2,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap(),
+        network_id: 0xf2.into(),
+        analyzers: vec![SerializableAnalyzerRules {
+            name: "withdrawals".to_owned(),
+            rules: vec![SerializableAnalyzerRule {
+                condition: SerializableAnalyzerRuleCondition::CallsMethod {
+                    address: None,
+                    method_name: "withdraw".to_owned(),
+                },
+                message: "Withdraws from an account".to_owned(),
+            }],
+        }],
+    };
+
+    // Act
+    let output = ManifestRunAnalyzers::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(
+        output,
+        vec![SerializableAnalysisSection {
+            name: "withdrawals".to_owned(),
+            findings: vec![SerializableAnalysisFinding {
+                instruction_index: Some(1u64.into()),
+                message: "Withdraws from an account".to_owned(),
+            }],
+        }]
+    );
+}
+
+#[test]
+fn account_interactions_report_the_accounts_that_must_sign() {
+    // Arrange
+    let account1 = account(1);
+    let account2 = account(2);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10)
+        .create_proof_from_account_of_amount(account1, XRD, 1)
+        .withdraw_from_account(account1, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account2, None)
+        .build();
+    let input = ManifestAccountInteractionsInput {
+        manifest: SerializableTransactionManifest::from_native(
+            &manifest,
+            0xf2,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap(),
+        network_id: 0xf2.into(),
+    };
+
+    // Act
+    let output = ManifestAccountInteractions::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(
+        output.accounts_withdrawn_from,
+        vec![SerializableNodeId::from_global_address(account1, 0xf2)]
+    );
+    assert_eq!(
+        output.accounts_deposited_into,
+        vec![SerializableNodeId::from_global_address(account2, 0xf2)]
+    );
+    assert_eq!(
+        output.accounts_requiring_auth,
+        vec![SerializableNodeId::from_global_address(account1, 0xf2)]
+    );
+    assert_eq!(
+        output.presented_proofs,
+        vec![SerializablePresentedProofs {
+            account: SerializableNodeId::from_global_address(account1, 0xf2),
+            resources: vec![SerializableNodeId::from_global_address(XRD, 0xf2)],
+        }]
+    );
+}
+
+#[test]
+fn manifest_can_be_converted_to_another_network() {
+    // Arrange
+    let account1 = account(1);
+    let account2 = account(2);
+    let component = ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    );
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10)
+        .call_method(component, "free", ())
+        .try_deposit_entire_worktop_or_abort(account2, None)
+        .build();
+    let input = ManifestConvertNetworkInput {
+        manifest: SerializableTransactionManifest::from_native(
+            &manifest,
+            0x02,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap(),
+        source_network_id: 0x02.into(),
+        target_network_id: 0x01.into(),
+    };
+
+    // Act
+    let output = ManifestConvertNetwork::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(
+        output.manifest,
+        SerializableTransactionManifest::from_native(
+            &manifest,
+            0x01,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        output.network_bound_addresses,
+        vec![SerializableNodeId::from_global_address(component, 0x02)]
+    );
+}
+
+#[test]
+fn manifest_exceeding_the_compilation_limits_is_rejected() {
+    // Arrange
+    let account1 = account(1);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10)
+        .withdraw_from_account(account1, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account1, None)
+        .build();
+    let input = ManifestCompileInput {
+        manifest: SerializableTransactionManifest::from_native(
+            &manifest,
+            0xf2,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap(),
+        network_id: 0xf2.into(),
+        limits: Some(SerializableCompilationLimitsSource::Custom(
+            SerializableCompilationLimits {
+                max_payload_size: 1_000_000.into(),
+                max_instructions: 2.into(),
+                max_depth: 24.into(),
+            },
+        )),
+    };
+
+    // Act
+    let output = ManifestCompile::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::LimitExceeded {
+            limit: SerializableLimit::InstructionCount(maximum),
+            actual,
+        }) if *maximum == 2 && *actual == 3
+    ));
+}
+
+#[test]
+fn manifest_within_the_network_limits_is_compiled() {
+    // Arrange
+    let account1 = account(1);
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10)
+        .withdraw_from_account(account1, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account1, None)
+        .build();
+    let input = ManifestCompileInput {
+        manifest: SerializableTransactionManifest::from_native(
+            &manifest,
+            0xf2,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap(),
+        network_id: 0xf2.into(),
+        limits: Some(SerializableCompilationLimitsSource::Network),
+    };
+
+    // Act
+    let output = ManifestCompile::handle(input);
+
+    // Assert
+    assert!(output.is_ok());
+}
+
+#[test]
+fn manifest_referencing_a_missing_blob_is_rejected() {
+    // Arrange
+    let blob = vec![1u8, 2, 3];
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            account(1),
+            "some_method",
+            manifest_args!(ManifestBlobRef(hash(&blob).0)),
+        )
+        .build();
+    let input = ManifestCompileInput {
+        manifest: SerializableTransactionManifest::from_native(
+            &manifest,
+            0xf2,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap(),
+        network_id: 0xf2.into(),
+        limits: None,
+    };
+
+    // Act
+    let output = ManifestCompile::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::SerializableInstructionsError(..))
+    ));
+}
+
+#[test]
+fn manifest_referencing_an_added_blob_can_be_compiled() {
+    // Arrange
+    let blob = vec![1u8, 2, 3];
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            account(1),
+            "some_method",
+            manifest_args!(ManifestBlobRef(hash(&blob).0)),
+        )
+        .build();
+    let ManifestAddBlobOutput {
+        manifest,
+        hash: blob_hash,
+    } = ManifestAddBlob::handle(ManifestAddBlobInput {
+        manifest: SerializableTransactionManifest::from_native(
+            &manifest,
+            0xf2,
+            SerializableInstructionsKind::String,
+        )
+        .unwrap(),
+        blob: blob.clone().into(),
+    })
+    .unwrap();
+
+    // Act
+    let output = ManifestCompile::handle(ManifestCompileInput {
+        manifest: manifest.clone(),
+        network_id: 0xf2.into(),
+        limits: None,
+    });
+
+    // Assert
+    assert_eq!(*blob_hash, hash(&blob));
+    assert!(output.is_ok());
+    assert_eq!(
+        ManifestGetBlob::handle(ManifestGetBlobInput {
+            manifest,
+            hash: blob_hash,
+        })
+        .unwrap()
+        .blob
+        .map(|blob| blob.to_vec()),
+        Some(blob)
+    );
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
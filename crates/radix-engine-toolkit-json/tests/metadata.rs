@@ -0,0 +1,64 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::object_modules::metadata::MetadataValue;
+use scrypto::prelude::*;
+
+#[test]
+fn metadata_value_survives_an_encode_decode_round_trip() {
+    // Arrange
+    let value = SerializableMetadataValue::from_native(
+        &MetadataValue::NonFungibleGlobalIdArray(vec![
+            NonFungibleGlobalId::new(XRD, NonFungibleLocalId::integer(1)),
+        ]),
+        0x01,
+    );
+
+    // Act
+    let encoded_value = MetadataValueEncode::handle(MetadataValueEncodeInput {
+        value: value.clone(),
+    })
+    .unwrap();
+    let decoded_value = MetadataValueDecode::handle(MetadataValueDecodeInput {
+        encoded_value,
+        network_id: 0x01.into(),
+    })
+    .unwrap();
+
+    // Assert
+    assert_eq!(decoded_value, value);
+}
+
+#[test]
+fn metadata_value_is_serialized_with_its_kind() {
+    // Arrange
+    let value =
+        SerializableMetadataValue::from_native(&MetadataValue::U64(10), 0x01);
+
+    // Act
+    let serialized_value = serde_json::to_value(&value).unwrap();
+
+    // Assert
+    assert_eq!(
+        serialized_value,
+        serde_json::json!({
+            "kind": "U64",
+            "value": "10"
+        })
+    );
+}
@@ -0,0 +1,130 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use sbor::generate_full_schema_from_single_type;
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor)]
+struct Ticket {
+    name: String,
+    seat: u32,
+}
+
+fn ticket_schema() -> SerializableNonFungibleDataSchema {
+    let (local_type_id, schema) =
+        generate_full_schema_from_single_type::<Ticket, ScryptoCustomSchema>();
+    SerializableNonFungibleDataSchema {
+        schema: PayloadSchema {
+            local_type_id: local_type_id.into(),
+            schema: scrypto_encode(schema.v1()).unwrap().into(),
+        },
+        mutable_fields: vec!["seat".to_owned()],
+    }
+}
+
+#[test]
+fn non_fungible_data_survives_an_encode_decode_round_trip() {
+    // Arrange
+    let fields = vec![
+        SerializableNonFungibleDataField {
+            field_name: "seat".to_owned(),
+            value: r#"{"kind":"U32","value":"12"}"#.to_owned(),
+        },
+        SerializableNonFungibleDataField {
+            field_name: "name".to_owned(),
+            value: r#"{"kind":"String","value":"Concert"}"#.to_owned(),
+        },
+    ];
+
+    // Act
+    let data = EncodeNonFungibleData::handle(EncodeNonFungibleDataInput {
+        fields,
+        schema: ticket_schema(),
+    })
+    .unwrap();
+    let decoded = DecodeNonFungibleData::handle(DecodeNonFungibleDataInput {
+        data: data.clone(),
+        schema: ticket_schema(),
+        network_id: 1.into(),
+    })
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        *data,
+        scrypto_encode(&Ticket {
+            name: "Concert".to_owned(),
+            seat: 12,
+        })
+        .unwrap()
+    );
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(decoded[0].field_name, "name");
+    assert!(!decoded[0].mutable);
+    assert_eq!(decoded[1].field_name, "seat");
+    assert!(decoded[1].mutable);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&decoded[1].value).unwrap()
+            ["value"],
+        "12"
+    );
+}
+
+#[test]
+fn missing_field_is_reported_as_a_non_fungible_data_error() {
+    // Arrange
+    let fields = vec![SerializableNonFungibleDataField {
+        field_name: "name".to_owned(),
+        value: r#"{"kind":"String","value":"Concert"}"#.to_owned(),
+    }];
+
+    // Act
+    let result = EncodeNonFungibleData::handle(EncodeNonFungibleDataInput {
+        fields,
+        schema: ticket_schema(),
+    });
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(InvocationHandlingError::NonFungibleDataError(..))
+    ));
+}
+
+#[test]
+fn duplicate_field_is_reported_as_a_non_fungible_data_error() {
+    // Arrange
+    let field = |value: &str| SerializableNonFungibleDataField {
+        field_name: "name".to_owned(),
+        value: format!(r#"{{"kind":"String","value":"{value}"}}"#),
+    };
+    let fields = vec![field("Concert"), field("Play")];
+
+    // Act
+    let result = EncodeNonFungibleData::handle(EncodeNonFungibleDataInput {
+        fields,
+        schema: ticket_schema(),
+    });
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(InvocationHandlingError::NonFungibleDataError(error))
+            if error == "DuplicateField(\"name\")"
+    ));
+}
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn parsed_global_id_is_returned_with_its_parts() {
+    // Arrange
+    let resource_address = SerializableNodeId::from_global_address(
+        SECP256K1_SIGNATURE_RESOURCE,
+        1,
+    )
+    .to_string();
+
+    // Act
+    let output =
+        ParseNonFungibleGlobalId::handle(ParseNonFungibleGlobalIdInput {
+            non_fungible_global_id: format!("{resource_address}:<badge>"),
+            network_id: 1.into(),
+        })
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        output.non_fungible_global_id.0.to_string(),
+        format!("{resource_address}:<badge>")
+    );
+    assert_eq!(output.resource_address.0.to_string(), resource_address);
+    assert_eq!((*output.local_id).to_string(), "<badge>");
+    assert_eq!(
+        output.structured_local_id,
+        SerializableStructuredNonFungibleLocalId::String("badge".to_owned())
+    );
+}
+
+#[test]
+fn global_id_built_for_another_network_is_an_error() {
+    // Arrange
+    let resource_address = SerializableNodeId::from_global_address(
+        SECP256K1_SIGNATURE_RESOURCE,
+        2,
+    )
+    .to_string();
+
+    // Act
+    let result =
+        BuildNonFungibleGlobalId::handle(BuildNonFungibleGlobalIdInput {
+            resource_address,
+            local_id: "#1#".to_owned(),
+            network_id: 1.into(),
+        });
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(InvocationHandlingError::NonFungibleGlobalIdError(..))
+    ));
+}
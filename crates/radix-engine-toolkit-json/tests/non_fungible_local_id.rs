@@ -0,0 +1,68 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+
+#[test]
+fn parsed_local_id_is_formatted_back_to_its_canonical_form() {
+    // Act
+    let structured =
+        NonFungibleLocalIdParse::handle("#007#".to_owned()).unwrap();
+    let formatted = NonFungibleLocalIdFormat::handle(structured.clone());
+
+    // Assert
+    assert_eq!(
+        structured,
+        SerializableStructuredNonFungibleLocalId::Integer(7.into())
+    );
+    assert_eq!(formatted.unwrap(), "#7#");
+}
+
+#[test]
+fn string_id_with_an_invalid_character_cannot_be_formatted() {
+    // Act
+    let result = NonFungibleLocalIdFormat::handle(
+        SerializableStructuredNonFungibleLocalId::String("ticket 1".to_owned()),
+    );
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(InvocationHandlingError::NonFungibleLocalIdError(..))
+    ));
+}
+
+#[test]
+fn validation_reports_the_broken_rule() {
+    // Act
+    let output =
+        NonFungibleLocalIdValidate::handle(NonFungibleLocalIdValidateInput {
+            local_id: "<ticket_1>".to_owned(),
+            expected_id_type: Some(SerializableNonFungibleIdType::Integer),
+        })
+        .unwrap();
+
+    // Assert
+    assert!(!output.is_valid);
+    assert_eq!(
+        output.error,
+        Some(SerializableNonFungibleLocalIdError::IdTypeMismatch {
+            expected: SerializableNonFungibleIdType::Integer,
+            actual: SerializableNonFungibleIdType::String,
+        })
+    );
+}
@@ -0,0 +1,99 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn contribution_manifest_is_built() {
+    // Arrange
+    let input = BuildPoolManifestInput {
+        account_address: SerializableNodeId::new(account(1).into_node_id(), 1),
+        operations: vec![SerializablePoolOperation::Contribute {
+            pool_address: SerializableNodeId::new(
+                pool(EntityType::GlobalOneResourcePool),
+                1,
+            ),
+            contributions: vec![SerializablePoolContribution {
+                resource_address: SerializableNodeId::new(
+                    XRD.into_node_id(),
+                    1,
+                ),
+                amount: dec!(10).into(),
+            }],
+        }],
+        network_id: 1.into(),
+        instructions_kind: SerializableInstructionsKind::Parsed,
+    };
+
+    // Act
+    let output = BuildPoolManifest::handle(input).unwrap();
+
+    // Assert
+    let SerializableInstructions::Parsed(instructions) = output.instructions
+    else {
+        panic!("The instructions are not parsed");
+    };
+    assert_eq!(instructions.len(), 4);
+    assert!(matches!(
+        &instructions[2],
+        SerializableInstruction::CallMethod { method_name, .. }
+            if method_name == "contribute"
+    ));
+}
+
+#[test]
+fn contribution_count_mismatch_is_a_pool_operation_error() {
+    // Arrange
+    let input = BuildPoolManifestInput {
+        account_address: SerializableNodeId::new(account(1).into_node_id(), 1),
+        operations: vec![SerializablePoolOperation::Contribute {
+            pool_address: SerializableNodeId::new(
+                pool(EntityType::GlobalTwoResourcePool),
+                1,
+            ),
+            contributions: vec![SerializablePoolContribution {
+                resource_address: SerializableNodeId::new(
+                    XRD.into_node_id(),
+                    1,
+                ),
+                amount: dec!(10).into(),
+            }],
+        }],
+        network_id: 1.into(),
+        instructions_kind: SerializableInstructionsKind::String,
+    };
+
+    // Act
+    let output = BuildPoolManifest::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::PoolOperationError(..))
+    ));
+}
+
+fn pool(entity_type: EntityType) -> NodeId {
+    NodeId::new(entity_type as u8, &[1; NodeId::RID_LENGTH])
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use serde_json::json;
+
+#[test]
+fn preview_transaction_is_built_in_the_shape_of_the_gateway_request() {
+    // Arrange
+    let input = json!({
+        "manifest": {
+            "instructions": {
+                "kind": "String",
+                "value": "DROP_ALL_PROOFS;"
+            },
+            "blobs": {
+                "11c0e79b71c3976ccd0c02d1310e2516c08edc9d8b6f57ccd680d63a4d8e72da": "010203"
+            }
+        },
+        "network_id": "242",
+        "header": {
+            "start_epoch_inclusive": "10",
+            "end_epoch_exclusive": "20",
+            "notary_public_key": null,
+            "tip_percentage": "0",
+            "nonce": "100"
+        },
+        "signer_public_keys": [{
+            "kind": "Ed25519",
+            "value": "4cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba29"
+        }],
+        "flags": {
+            "use_free_credit": true
+        }
+    });
+
+    // Act
+    let response = invoke("build_preview_transaction", input).unwrap();
+
+    // Assert
+    assert_eq!(
+        response.output,
+        json!({
+            "manifest": "DROP_ALL_PROOFS;\n",
+            "blobs_hex": ["010203"],
+            "start_epoch_inclusive": 10,
+            "end_epoch_exclusive": 20,
+            "notary_public_key": null,
+            "notary_is_signatory": false,
+            "tip_percentage": 0,
+            "nonce": 100,
+            "signer_public_keys": [{
+                "key_type": "EddsaEd25519",
+                "key_hex": "4cb5abf6ad79fbf5abbccafcc269d85cd2651ed4b885b5869f241aedf0a5ba29"
+            }],
+            "flags": {
+                "use_free_credit": true,
+                "assume_all_signature_proofs": false,
+                "skip_epoch_check": false
+            }
+        })
+    );
+}
+
+#[test]
+fn invalid_preview_receipts_are_rejected() {
+    // Act
+    let response = invoke(
+        "parse_preview_receipt",
+        json!({
+            "encoded_receipt": "010203",
+            "network_id": "242"
+        }),
+    );
+
+    // Assert
+    assert!(matches!(
+        response,
+        Err(Error::InvocationHandlingError(
+            InvocationHandlingError::PreviewReceiptError(..)
+        ))
+    ));
+}
@@ -0,0 +1,230 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use serde_json::json;
+
+#[test]
+fn ret_buffer_holds_the_serialized_object_without_a_terminator() {
+    // Arrange
+    let object = json!({ "network_id": "1" });
+
+    // Act
+    let buffer = serialize_to_ret_buffer(&object, false).unwrap();
+
+    // Assert
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.ptr, buffer.len) };
+    assert_eq!(bytes, serde_json::to_vec(&object).unwrap());
+    assert!(!buffer.is_error);
+    unsafe { toolkit_free_ret_buffer(buffer) };
+}
+
+#[test]
+fn functions_can_be_invoked_with_cbor_payloads() {
+    // Arrange
+    let mut input = Vec::new();
+    ciborium::into_writer(&BuildInformationInput {}, &mut input).unwrap();
+
+    // Act
+    let buffer = unsafe { build_information_cbor(input.as_ptr(), input.len()) };
+
+    // Assert
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.ptr, buffer.len) };
+    let response =
+        ciborium::from_reader::<Response<BuildInformationOutput>, _>(bytes)
+            .unwrap();
+    assert!(!buffer.is_error);
+    assert!(response.output.capabilities.cbor_encoding);
+    unsafe { toolkit_free_ret_buffer(buffer) };
+}
+
+#[test]
+fn exported_functions_report_warnings_alongside_the_output() {
+    // Arrange
+    let input =
+        std::ffi::CString::new(json!({ "unknown": "1" }).to_string()).unwrap();
+
+    // Act
+    let buffer = build_information(input.as_ptr() as Pointer);
+
+    // Assert
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.ptr, buffer.len) };
+    let response =
+        serde_json::from_slice::<Response<BuildInformationOutput>>(bytes)
+            .unwrap();
+    assert!(!buffer.is_error);
+    assert_eq!(
+        response.warnings,
+        vec![Warning::UnknownFieldIgnored {
+            path: "/unknown".to_owned()
+        }]
+    );
+    unsafe { toolkit_free_ret_buffer(buffer) };
+}
+
+#[test]
+fn ret_buffers_are_allocated_in_the_active_arena() {
+    // Arrange
+    let arena = toolkit_arena_create();
+
+    // Act
+    let first = serialize_to_ret_buffer(&json!({ "value": 1 }), false).unwrap();
+    let second =
+        serialize_to_ret_buffer(&json!({ "value": 2 }), false).unwrap();
+
+    // Assert
+    assert_eq!(unsafe { first.ptr.add(first.len) }, second.ptr);
+    let bytes = unsafe { std::slice::from_raw_parts(second.ptr, second.len) };
+    assert_eq!(bytes, br#"{"value":2}"#);
+    unsafe {
+        toolkit_free_ret_buffer(first);
+        toolkit_free_ret_buffer(second);
+        toolkit_arena_destroy(arena);
+    }
+}
+
+#[test]
+fn resetting_an_arena_reuses_its_memory() {
+    // Arrange
+    let arena = toolkit_arena_create();
+    let first = serialize_to_ret_buffer(&json!({ "value": 1 }), false).unwrap();
+
+    // Act
+    unsafe { toolkit_arena_reset(arena) };
+    let second =
+        serialize_to_ret_buffer(&json!({ "value": 2 }), false).unwrap();
+
+    // Assert
+    assert_eq!(first.ptr, second.ptr);
+    unsafe { toolkit_arena_destroy(arena) };
+}
+
+#[test]
+fn ret_buffers_are_individually_allocated_after_the_arena_is_destroyed() {
+    // Arrange
+    let arena = toolkit_arena_create();
+
+    // Act
+    unsafe { toolkit_arena_destroy(arena) };
+    let buffer =
+        serialize_to_ret_buffer(&json!({ "value": 1 }), false).unwrap();
+
+    // Assert
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.ptr, buffer.len) };
+    assert_eq!(bytes, br#"{"value":1}"#);
+    unsafe { toolkit_free_ret_buffer(buffer) };
+}
+
+#[test]
+fn an_arena_can_not_be_created_while_another_is_active() {
+    // Arrange
+    let arena = toolkit_arena_create();
+    let buffer =
+        serialize_to_ret_buffer(&json!({ "value": 1 }), false).unwrap();
+
+    // Act
+    let replacement = toolkit_arena_create();
+    let freed = unsafe { toolkit_free_response(buffer.ptr) };
+
+    // Assert
+    assert!(replacement.is_null());
+    assert!(freed);
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.ptr, buffer.len) };
+    assert_eq!(bytes, br#"{"value":1}"#);
+    unsafe { toolkit_arena_destroy(arena) };
+}
+
+#[test]
+fn ret_buffers_of_the_arena_of_another_thread_are_left_to_the_arena() {
+    // Arrange
+    let arena = toolkit_arena_create();
+    let buffer =
+        serialize_to_ret_buffer(&json!({ "value": 1 }), false).unwrap();
+    let pointer = buffer.ptr as usize;
+
+    // Act
+    let freed = std::thread::spawn(move || unsafe {
+        toolkit_free_response(pointer as *mut u8)
+    })
+    .join()
+    .unwrap();
+
+    // Assert
+    assert!(freed);
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.ptr, buffer.len) };
+    assert_eq!(bytes, br#"{"value":1}"#);
+    unsafe { toolkit_arena_destroy(arena) };
+}
+
+#[test]
+fn responses_can_be_freed_through_their_pointer() {
+    // Arrange
+    let buffer =
+        serialize_to_ret_buffer(&json!({ "value": 1 }), false).unwrap();
+
+    // Act
+    let freed = unsafe { toolkit_free_response(buffer.ptr) };
+
+    // Assert
+    assert!(freed);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn pointers_which_are_not_responses_are_not_freed() {
+    // Arrange
+    let mut bytes = [0u8; 4];
+
+    // Act
+    let freed = unsafe { toolkit_free_response(bytes.as_mut_ptr()) };
+
+    // Assert
+    assert!(!freed);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn responses_freed_as_ret_buffers_can_not_be_freed_again() {
+    // Arrange
+    let buffer =
+        serialize_to_ret_buffer(&json!({ "value": 1 }), false).unwrap();
+    unsafe { toolkit_free_ret_buffer(buffer) };
+
+    // Act
+    let freed = unsafe { toolkit_free_response(buffer.ptr) };
+
+    // Assert
+    assert!(!freed);
+}
+
+#[test]
+fn memory_statistics_track_allocated_and_freed_responses() {
+    // Arrange
+    let before = memory_statistics();
+
+    // Act
+    let buffer =
+        serialize_to_ret_buffer(&json!({ "value": 1 }), false).unwrap();
+    let during = memory_statistics();
+    unsafe { toolkit_free_ret_buffer(buffer) };
+    let after = memory_statistics();
+
+    // Assert
+    assert!(during.allocated_responses > before.allocated_responses);
+    assert!(during.live_responses >= 1);
+    assert!(after.freed_responses > before.freed_responses);
+}
@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+use serde_json::json;
+
+#[test]
+fn set_component_royalty_instruction_is_built() {
+    // Arrange
+    let component = ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    );
+    let input = BuildSetComponentRoyaltyInstructionInput {
+        component_address: SerializableNodeId::new(component.into_node_id(), 1),
+        method: "swap".to_owned(),
+        amount: serde_json::from_value(json!({
+            "kind": "Usd",
+            "value": "0.05"
+        }))
+        .unwrap(),
+        network_id: 1.into(),
+    };
+
+    // Act
+    let output = BuildSetComponentRoyaltyInstruction::handle(input).unwrap();
+
+    // Assert
+    let SerializableInstruction::CallRoyaltyMethod {
+        address: SerializableManifestAddress::Static(address),
+        method_name,
+        ..
+    } = output
+    else {
+        panic!("Not a royalty method call: {output:?}");
+    };
+    assert_eq!(address.0.node_id, component.into_node_id());
+    assert_eq!(method_name, "set_royalty");
+}
+
+#[test]
+fn royalty_amount_converts_to_and_from_the_native_model() {
+    // Act
+    let serializable =
+        SerializableRoyaltyAmount::from(RoyaltyAmount::Xrd(dec!("1.5")));
+    let native = RoyaltyAmount::from(serializable.clone());
+
+    // Assert
+    assert_eq!(
+        serializable,
+        SerializableRoyaltyAmount::Xrd(dec!("1.5").into())
+    );
+    assert_eq!(native, RoyaltyAmount::Xrd(dec!("1.5")));
+}
+
+#[test]
+fn royalty_of_a_non_component_address_is_an_error() {
+    // Arrange
+    let input = BuildSetComponentRoyaltyInstructionInput {
+        component_address: SerializableNodeId::new(XRD.into_node_id(), 1),
+        method: "swap".to_owned(),
+        amount: SerializableRoyaltyAmount::Free,
+        network_id: 1.into(),
+    };
+
+    // Act
+    let output = BuildSetComponentRoyaltyInstruction::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::InvalidAddress(..))
+    ));
+}
@@ -0,0 +1,40 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn known_addresses_are_returned_for_each_network_in_order() {
+    // Act
+    let output =
+        UtilsKnownAddressesForNetworks::handle(vec![2.into(), 1.into()])
+            .unwrap();
+
+    // Assert
+    assert_eq!(output.len(), 2);
+    assert_eq!(*output[0].network_id, 2);
+    assert_eq!(*output[1].network_id, 1);
+    assert_eq!(
+        output[1].known_addresses,
+        UtilsKnownAddress::handle(1.into()).unwrap()
+    );
+    assert_eq!(
+        output[0].known_addresses.package_addresses.locker_package,
+        SerializableNodeId::from_global_address(LOCKER_PACKAGE, 2)
+    );
+}
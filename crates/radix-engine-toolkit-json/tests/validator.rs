@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn stake_manifest_is_built() {
+    // Arrange
+    let input = BuildValidatorManifestInput {
+        account_address: SerializableNodeId::new(account(1).into_node_id(), 1),
+        operations: vec![SerializableValidatorOperation::Stake {
+            validator_address: SerializableNodeId::new(validator(), 1),
+            amount: dec!(100).into(),
+        }],
+        network_id: 1.into(),
+        instructions_kind: SerializableInstructionsKind::Parsed,
+    };
+
+    // Act
+    let output = BuildValidatorManifest::handle(input).unwrap();
+
+    // Assert
+    let SerializableInstructions::Parsed(instructions) = output.instructions
+    else {
+        panic!("The instructions are not parsed");
+    };
+    assert_eq!(instructions.len(), 4);
+    assert!(matches!(
+        &instructions[2],
+        SerializableInstruction::CallMethod { method_name, .. }
+            if method_name == "stake"
+    ));
+}
+
+#[test]
+fn validator_operation_on_a_non_validator_address_is_an_error() {
+    // Arrange
+    let input = BuildValidatorManifestInput {
+        account_address: SerializableNodeId::new(account(1).into_node_id(), 1),
+        operations: vec![SerializableValidatorOperation::Register {
+            validator_address: SerializableNodeId::new(XRD.into_node_id(), 1),
+        }],
+        network_id: 1.into(),
+        instructions_kind: SerializableInstructionsKind::String,
+    };
+
+    // Act
+    let output = BuildValidatorManifest::handle(input);
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::InvalidAddress(..))
+    ));
+}
+
+fn validator() -> NodeId {
+    NodeId::new(EntityType::GlobalValidator as u8, &[1; NodeId::RID_LENGTH])
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use serde_json::json;
+
+#[test]
+fn inputs_that_are_not_objects_are_interpreted_as_the_current_version() {
+    // Act
+    let response = invoke("utils_known_addresses", json!("1")).unwrap();
+
+    // Assert
+    assert!(response.output["resource_addresses"]["xrd"].is_string());
+}
+
+#[test]
+fn schema_version_can_be_given_as_a_number_or_a_string() {
+    for schema_version in [json!(1), json!("1")] {
+        // Act
+        let response = invoke(
+            "build_information",
+            json!({ "schema_version": schema_version }),
+        )
+        .unwrap();
+
+        // Assert
+        assert!(response.warnings.is_empty());
+    }
+}
+
+#[test]
+fn unsupported_schema_versions_are_rejected() {
+    for schema_version in
+        [json!(0), json!(CURRENT_SCHEMA_VERSION + 1), json!(-1)]
+    {
+        // Act
+        let response = invoke(
+            "build_information",
+            json!({ "schema_version": schema_version }),
+        );
+
+        // Assert
+        assert!(matches!(
+            response,
+            Err(Error::InvocationInterpretationError(
+                InvocationInterpretationError::UnsupportedSchemaVersion(..)
+            ))
+        ));
+    }
+}
+
+#[test]
+fn build_information_reports_the_supported_schema_versions() {
+    // Act
+    let output = BuildInformation::handle(BuildInformationInput {}).unwrap();
+
+    // Assert
+    assert_eq!(*output.schema_versions.minimum, MINIMUM_SCHEMA_VERSION);
+    assert_eq!(*output.schema_versions.current, CURRENT_SCHEMA_VERSION);
+}
@@ -0,0 +1,210 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io::Cursor;
+
+use radix_engine_toolkit_json::wasi::*;
+use serde_json::json;
+
+#[test]
+fn frame_can_be_written_and_read_back() {
+    // Arrange
+    let payload = b"{\"hello\":\"world\"}";
+    let mut buffer = Vec::new();
+
+    // Act
+    write_frame(&mut buffer, payload).unwrap();
+    let frame = read_frame(&mut Cursor::new(buffer.clone())).unwrap();
+
+    // Assert
+    assert_eq!(&buffer[..4], &(payload.len() as u32).to_le_bytes());
+    assert_eq!(frame.as_deref(), Some(&payload[..]));
+}
+
+#[test]
+fn reading_from_an_empty_stream_returns_no_frame() {
+    // Arrange
+    let mut reader = Cursor::new(Vec::<u8>::new());
+
+    // Act
+    let frame = read_frame(&mut reader);
+
+    // Assert
+    assert!(matches!(frame, Ok(None)))
+}
+
+#[test]
+fn truncated_frames_are_rejected() {
+    // Arrange
+    let mut bytes = 10u32.to_le_bytes().to_vec();
+    bytes.extend([0u8; 5]);
+
+    // Act
+    let frame = read_frame(&mut Cursor::new(bytes));
+
+    // Assert
+    assert!(matches!(
+        frame,
+        Err(FramingError::TruncatedFrame { expected: 10 })
+    ))
+}
+
+#[test]
+fn frames_longer_than_the_maximum_are_rejected() {
+    // Arrange
+    let bytes = (MAX_FRAME_LENGTH + 1).to_le_bytes().to_vec();
+
+    // Act
+    let frame = read_frame(&mut Cursor::new(bytes));
+
+    // Assert
+    assert!(matches!(frame, Err(FramingError::FrameTooLong { .. })))
+}
+
+#[test]
+fn serve_responds_to_every_request_in_order() {
+    // Arrange
+    let requests = [
+        json!({ "function": "build_information", "input": {} }),
+        json!({ "function": "utils_known_addresses", "input": "1" }),
+    ];
+    let mut input = Vec::new();
+    for request in requests.iter() {
+        write_frame(&mut input, &serde_json::to_vec(request).unwrap()).unwrap();
+    }
+    let mut output = Vec::new();
+
+    // Act
+    serve(&mut Cursor::new(input), &mut output).unwrap();
+
+    // Assert
+    let responses = read_all_responses(output);
+    assert_eq!(responses.len(), 2);
+    assert!(responses
+        .iter()
+        .all(|response| response["kind"] == json!("Ok")));
+    assert!(responses[0]["value"]["output"]["version"].is_string());
+    assert!(responses[1]["value"]["output"]["resource_addresses"]["xrd"]
+        .is_string());
+}
+
+#[test]
+fn unknown_functions_produce_an_error_response() {
+    // Arrange
+    let request = json!({ "function": "no_such_function", "input": {} });
+
+    // Act
+    let response = handle_frame(&serde_json::to_vec(&request).unwrap());
+
+    // Assert
+    let response =
+        serde_json::from_slice::<serde_json::Value>(&response).unwrap();
+    assert_eq!(response["kind"], json!("Err"));
+    assert_eq!(response["value"]["kind"], json!("UnknownFunction"));
+}
+
+#[test]
+fn malformed_requests_produce_an_error_response_and_do_not_end_the_session() {
+    // Arrange
+    let mut input = Vec::new();
+    write_frame(&mut input, b"not json").unwrap();
+    write_frame(
+        &mut input,
+        &serde_json::to_vec(
+            &json!({ "function": "build_information", "input": {} }),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let mut output = Vec::new();
+
+    // Act
+    serve(&mut Cursor::new(input), &mut output).unwrap();
+
+    // Assert
+    let responses = read_all_responses(output);
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0]["kind"], json!("Err"));
+    assert_eq!(responses[1]["kind"], json!("Ok"));
+}
+
+#[test]
+fn identical_requests_produce_identical_responses() {
+    // Arrange
+    let request = serde_json::to_vec(
+        &json!({ "function": "utils_known_addresses", "input": "2" }),
+    )
+    .unwrap();
+
+    // Act
+    let response1 = handle_frame(&request);
+    let response2 = handle_frame(&request);
+
+    // Assert
+    assert_eq!(response1, response2)
+}
+
+#[test]
+fn unknown_input_fields_are_reported_as_warnings() {
+    // Arrange
+    let request = json!({
+        "function": "derive_virtual_account_address_from_public_key",
+        "input": {
+            "public_key": {
+                "kind": "Secp256k1",
+                "value": "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+            },
+            "network_id": "1",
+            "networkId": "1"
+        }
+    });
+
+    // Act
+    let response = handle_frame(&serde_json::to_vec(&request).unwrap());
+
+    // Assert
+    let response =
+        serde_json::from_slice::<serde_json::Value>(&response).unwrap();
+    assert_eq!(response["kind"], json!("Ok"));
+    assert_eq!(
+        response["value"]["warnings"],
+        json!([{ "kind": "UnknownFieldIgnored", "warning": { "path": "/networkId" } }])
+    );
+}
+
+#[test]
+fn responses_without_warnings_have_an_empty_warnings_list() {
+    // Arrange
+    let request = json!({ "function": "build_information", "input": {} });
+
+    // Act
+    let response = handle_frame(&serde_json::to_vec(&request).unwrap());
+
+    // Assert
+    let response =
+        serde_json::from_slice::<serde_json::Value>(&response).unwrap();
+    assert_eq!(response["value"]["warnings"], json!([]));
+}
+
+fn read_all_responses(output: Vec<u8>) -> Vec<serde_json::Value> {
+    let mut reader = Cursor::new(output);
+    let mut responses = Vec::new();
+    while let Some(frame) = read_frame(&mut reader).unwrap() {
+        responses.push(serde_json::from_slice(&frame).unwrap());
+    }
+    responses
+}
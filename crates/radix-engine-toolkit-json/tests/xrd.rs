@@ -0,0 +1,65 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit_json::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn xrd_is_converted_to_attos() {
+    // Act
+    let output = XrdToAttos::handle(dec!("1.5").into()).unwrap();
+
+    // Assert
+    assert_eq!(output, "1500000000000000000");
+}
+
+#[test]
+fn attos_are_converted_to_xrd() {
+    // Act
+    let output = AttosToXrd::handle("1".to_owned()).unwrap();
+
+    // Assert
+    assert_eq!(*output, dec!("0.000000000000000001"));
+}
+
+#[test]
+fn invalid_attos_are_an_error() {
+    // Act
+    let output = AttosToXrd::handle("1.5".to_owned());
+
+    // Assert
+    assert!(matches!(
+        output,
+        Err(InvocationHandlingError::ArithmeticError(..))
+    ));
+}
+
+#[test]
+fn cost_units_are_converted_to_xrd_with_the_tip() {
+    // Arrange
+    let input = CostUnitsToXrdInput {
+        cost_units: 10_000_000.into(),
+        cost_unit_price: dec!("0.00000005").into(),
+        tip_percentage: 10.into(),
+    };
+
+    // Act
+    let output = CostUnitsToXrd::handle(input).unwrap();
+
+    // Assert
+    assert_eq!(*output, dec!("0.55"));
+}
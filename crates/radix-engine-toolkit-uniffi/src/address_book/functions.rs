@@ -0,0 +1,60 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::prelude::*;
+
+#[uniffi::export]
+pub fn address_book_validate(address_book: AddressBook) -> Result<()> {
+    core_address_book_validate(&address_book.into()).map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn address_book_export(address_book: AddressBook) -> Result<Vec<u8>> {
+    core_address_book_export(&address_book.into()).map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn address_book_import(payload: Vec<u8>) -> Result<AddressBook> {
+    core_address_book_import(payload)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+#[uniffi::export]
+pub fn address_book_merge(
+    base: AddressBook,
+    other: AddressBook,
+) -> Result<AddressBookMerge> {
+    let merge = core_address_book_merge(&base.into(), &other.into())?;
+    Ok(AddressBookMerge {
+        address_book: merge.address_book.into(),
+        conflicts: merge.conflicts.into_iter().map(Into::into).collect(),
+    })
+}
+
+#[uniffi::export]
+pub fn address_book_diff(
+    old: AddressBook,
+    new: AddressBook,
+) -> AddressBookDiff {
+    let diff = core_address_book_diff(&old.into(), &new.into());
+    AddressBookDiff {
+        added: diff.added.into_iter().map(Into::into).collect(),
+        removed: diff.removed.into_iter().map(Into::into).collect(),
+        relabeled: diff.relabeled.into_iter().map(Into::into).collect(),
+    }
+}
@@ -0,0 +1,104 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+pub mod functions;
+
+use crate::prelude::*;
+
+#[derive(Clone, Debug, Record)]
+pub struct AddressBook {
+    pub entries: Vec<AddressBookEntry>,
+    pub allow_mixed_networks: bool,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AddressBookEntry {
+    pub label: String,
+    pub address: String,
+    pub entity_type: EntityType,
+    pub network_id: u8,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AddressBookMerge {
+    pub address_book: AddressBook,
+    pub conflicts: Vec<AddressBookLabelConflict>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AddressBookDiff {
+    pub added: Vec<AddressBookEntry>,
+    pub removed: Vec<AddressBookEntry>,
+    pub relabeled: Vec<AddressBookLabelConflict>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AddressBookLabelConflict {
+    pub address: String,
+    pub base_label: String,
+    pub other_label: String,
+}
+
+impl From<CoreAddressBook> for AddressBook {
+    fn from(value: CoreAddressBook) -> Self {
+        Self {
+            entries: value.entries.into_iter().map(Into::into).collect(),
+            allow_mixed_networks: value.allow_mixed_networks,
+        }
+    }
+}
+
+impl From<AddressBook> for CoreAddressBook {
+    fn from(value: AddressBook) -> Self {
+        Self {
+            entries: value.entries.into_iter().map(Into::into).collect(),
+            allow_mixed_networks: value.allow_mixed_networks,
+        }
+    }
+}
+
+impl From<CoreAddressBookEntry> for AddressBookEntry {
+    fn from(value: CoreAddressBookEntry) -> Self {
+        Self {
+            label: value.label,
+            address: value.address,
+            entity_type: value.entity_type.into(),
+            network_id: value.network_id,
+        }
+    }
+}
+
+impl From<AddressBookEntry> for CoreAddressBookEntry {
+    fn from(value: AddressBookEntry) -> Self {
+        Self {
+            label: value.label,
+            address: value.address,
+            entity_type: value.entity_type.into(),
+            network_id: value.network_id,
+        }
+    }
+}
+
+impl From<CoreAddressBookLabelConflict> for AddressBookLabelConflict {
+    fn from(value: CoreAddressBookLabelConflict) -> Self {
+        Self {
+            address: value.address,
+            base_label: value.base_label,
+            other_label: value.other_label,
+        }
+    }
+}
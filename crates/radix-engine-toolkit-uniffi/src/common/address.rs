@@ -30,13 +30,9 @@ impl Address {
                     address: address.clone(),
                 },
             )?;
-        let network_definition =
-            core_network_definition_from_network_id(network_id);
-        let bech32_decoder =
-            NativeAddressBech32Decoder::new(&network_definition);
-
         let (_, bytes) =
-            bech32_decoder
+            core_bech32_coders(network_id)
+                .address_decoder
                 .validate_and_decode(&address)
                 .map_err(|error| {
                     RadixEngineToolkitError::Bech32DecodeError {
@@ -113,11 +109,10 @@ impl Address {
     }
 
     pub fn address_string(&self) -> String {
-        let network_definition =
-            core_network_definition_from_network_id(self.1);
-        let bech32_encoder =
-            NativeAddressBech32Encoder::new(&network_definition);
-        bech32_encoder.encode(self.0.as_bytes()).expect(
+        core_bech32_coders(self.1)
+            .address_encoder
+            .encode(self.0.as_bytes())
+            .expect(
             "Safe to unwrap here. Node id has a valid entity type byte.",
         )
     }
@@ -39,14 +39,11 @@ impl NonFungibleGlobalId {
                     "Failed to obtain network id from non-fungible global id"
                         .to_owned(),
             })?;
-        let network_definition =
-            core_network_definition_from_network_id(network_id);
-        let bech32_decoder =
-            NativeAddressBech32Decoder::new(&network_definition);
+        let coders = core_bech32_coders(network_id);
 
         let non_fungible_global_id =
             NativeNonFungibleGlobalId::try_from_canonical_string(
-                &bech32_decoder,
+                &coders.address_decoder,
                 &non_fungible_global_id,
             )?;
         Ok(Arc::new(Self(non_fungible_global_id, network_id)))
@@ -102,12 +99,8 @@ impl NonFungibleGlobalId {
     }
 
     pub fn as_str(&self) -> String {
-        let network_id = self.1;
-        let network_definition =
-            core_network_definition_from_network_id(network_id);
-        let bech32_encoder =
-            NativeAddressBech32Encoder::new(&network_definition);
-        self.0.to_canonical_string(&bech32_encoder)
+        let coders = core_bech32_coders(self.1);
+        self.0.to_canonical_string(&coders.address_encoder)
     }
 }
 
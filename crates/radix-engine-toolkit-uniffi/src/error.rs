@@ -55,7 +55,14 @@ pub enum RadixEngineToolkitError {
     InvalidPublicKey,
 
     #[error("Manifest compilation errored out")]
-    CompileError { error: String },
+    CompileError {
+        error: String,
+        /// The index of the instruction that caused the error and the 1-based
+        /// line and column that it starts at, if it could be located.
+        instruction_index: Option<u64>,
+        line: Option<u64>,
+        column: Option<u64>,
+    },
 
     #[error("Manifest decompilation errored out")]
     DecompileError { error: String },
@@ -108,6 +115,12 @@ pub enum RadixEngineToolkitError {
 
     #[error("The receipt is not a valid preview receipt")]
     InvalidReceipt,
+
+    #[error("The address book is invalid or could not be imported")]
+    AddressBookError { error: String },
+
+    #[error("A blob referenced by the manifest was not found in its blobs")]
+    BlobReferenceError { error: String },
 }
 
 macro_rules! dbg_str {
@@ -158,7 +171,6 @@ impl_parse_error! { scrypto::prelude::InternalAddress, scrypto::prelude::ParseIn
 
 impl_dbg_str_from! { NativeContentValidationError, NonFungibleContentValidationError }
 impl_dbg_str_from! { CoreDerivationError, DerivationError }
-impl_dbg_str_from! { NativeCompileError, CompileError }
 impl_dbg_str_from! { NativeDecompileError, DecompileError }
 impl_dbg_str_from! { NativePrepareError, PrepareError }
 impl_dbg_str_from! { NativeEncodeError, EncodeError }
@@ -170,9 +182,44 @@ impl_dbg_str_from! { CoreScryptoSborError, ScryptoSborError }
 impl_dbg_str_from! { NativeTypedNativeEventError, TypedNativeEventError }
 impl_dbg_str_from! { CoreManifestModificationError, ManifestModificationError }
 impl_dbg_str_from! { CoreInvalidEntityTypeIdError, InvalidEntityTypeIdError }
+impl_dbg_str_from! { CoreAddressBookError, AddressBookError }
+impl_dbg_str_from! { CoreBlobReferenceError, BlobReferenceError }
+
+impl From<CoreReplayProtectionError> for RadixEngineToolkitError {
+    fn from(value: CoreReplayProtectionError) -> Self {
+        match value {
+            CoreReplayProtectionError::DecodeError(error) => error.into(),
+            CoreReplayProtectionError::PrepareError(error) => error.into(),
+        }
+    }
+}
 
 impl From<NameRecordError> for RadixEngineToolkitError {
     fn from(value: NameRecordError) -> Self {
         Self::ManifestBuilderNameRecordError { error: value }
     }
 }
+
+impl From<NativeCompileError> for RadixEngineToolkitError {
+    fn from(value: NativeCompileError) -> Self {
+        Self::CompileError {
+            error: dbg_str!(value),
+            instruction_index: None,
+            line: None,
+            column: None,
+        }
+    }
+}
+
+impl From<CoreLocatedCompileError> for RadixEngineToolkitError {
+    fn from(value: CoreLocatedCompileError) -> Self {
+        let location = value.location;
+        Self::CompileError {
+            error: dbg_str!(value.error),
+            instruction_index: location
+                .map(|location| location.instruction_index as u64),
+            line: location.map(|location| location.line as u64),
+            column: location.map(|location| location.column as u64),
+        }
+    }
+}
@@ -54,6 +54,10 @@ mod core {
         decompile as core_instructions_decompile,
         statically_validate as core_instructions_statically_validate,
         extract_addresses as core_instructions_extract_addresses,
+        extract_addresses_with_usage as core_instructions_extract_addresses_with_usage,
+        extract_address_occurrences as core_instructions_extract_address_occurrences,
+        AddressUsage as CoreAddressUsage,
+        AddressOccurrence as CoreAddressOccurrence,
         InstructionValidationError as CoreInstructionValidationError,
     };
     pub use radix_engine_toolkit::functions::utils::{
@@ -66,16 +70,29 @@ mod core {
         statically_validate as core_manifest_statically_validate,
         modify as core_manifest_modify,
         summary as core_manifest_summary,
+        simple_transfer as core_manifest_simple_transfer,
         execution_summary as core_manifest_execution_summary,
+        execution_summary_with_deposit_settings as core_manifest_execution_summary_with_deposit_settings,
+        trusted_worktop as core_manifest_trusted_worktop,
+        add_blob as core_manifest_add_blob,
+        get_blob as core_manifest_get_blob,
+        validate_blob_references as core_manifest_validate_blob_references,
+        compile_string as core_manifest_compile_string,
+        LocatedCompileError as CoreLocatedCompileError,
         TransactionManifestModifications as CoreManifestTransactionManifestModifications,
         Assertion as CoreManifestAssertion,
-        ManifestModificationError as CoreManifestModificationError
+        ManifestModificationError as CoreManifestModificationError,
+        BlobReferenceError as CoreBlobReferenceError
     };
     pub use radix_engine_toolkit::functions::intent::{
         hash as core_intent_hash,
         compile as core_intent_compile,
         decompile as core_intent_decompile,
         statically_validate as core_intent_statically_validate,
+        replay_protection as core_intent_replay_protection,
+        replay_protection_from_payload as core_intent_replay_protection_from_payload,
+        ReplayProtection as CoreReplayProtection,
+        ReplayProtectionError as CoreReplayProtectionError,
     };
     pub use radix_engine_toolkit::functions::signed_intent::{
         hash as core_signed_intent_hash,
@@ -99,6 +116,23 @@ mod core {
         StringRepresentation as CoreScryptoStringRepresentation,
         decode_to_string_representation as core_scrypto_decode_to_string_representation,
         encode_string_representation as core_scrypto_encode_string_representation,
+        encode_string_representation_with_schema as core_scrypto_encode_string_representation_with_schema,
+    };
+    pub use radix_engine_toolkit::functions::address_book::{
+        validate as core_address_book_validate,
+        export as core_address_book_export,
+        import as core_address_book_import,
+        merge as core_address_book_merge,
+        diff as core_address_book_diff,
+        AddressBook as CoreAddressBook,
+        AddressBookEntry as CoreAddressBookEntry,
+        AddressBookLabelConflict as CoreAddressBookLabelConflict,
+        AddressBookError as CoreAddressBookError,
+    };
+    pub use radix_engine_toolkit::functions::explain::{
+        explain_validation_failure as core_explain_validation_failure,
+        ValidationFailureExplanation as CoreValidationFailureExplanation,
+        ValidationFailureLocation as CoreValidationFailureLocation,
     };
     pub use radix_engine_toolkit::functions::events::{
         sbor_decode_to_native_event as core_events_sbor_decode_to_native_event
@@ -111,7 +145,12 @@ mod core {
         TransactionTypesError as CoreTransactionTypesError,
         ManifestSummary as CoreManifestSummary,
         ExecutionSummary as CoreExecutionSummary,
+        AccountDepositSettings as CoreAccountDepositSettings,
+        DepositCompatibility as CoreDepositCompatibility,
         TransactionTypesReceipt as CoreTransactionTypesReceipt,
+        ExecutionReceipt as CoreExecutionReceipt,
+        AccountBalanceChanges as CoreAccountBalanceChanges,
+        NonFungibleBalanceChange as CoreNonFungibleBalanceChange,
         NewEntities as CoreNewEntities,
         FnRules as CoreFnRules,
         Predicted as CorePredicted,
@@ -129,6 +168,7 @@ mod core {
         PresentedProofsDetector as CorePresentedProofsDetector,
         RequiringAuthDetector as CoreRequiringAuthDetector,
         ReservedInstructionsDetector as CoreReservedInstructionsDetector,
+        TrustedWorktopInstruction as CoreTrustedWorktopInstruction,
         StaticAccountResourceMovementsDetector as CoreStaticAccountResourceMovementsDetector,
         AccountResourceMovementsDetector as CoreAccountResourceMovementsDetector,
         AccountSettingsUpdateDetector as CoreAccountSettingsUpdateDetector,
@@ -144,10 +184,20 @@ mod core {
         TrackedValidatorUnstake as CoreTrackedValidatorUnstake,
         ValidatorClaimDetector as CoreValidatorClaimDetector,
         TrackedValidatorClaim as CoreTrackedValidatorClaim,
+        AccountLockerClaimDetector as CoreAccountLockerClaimDetector,
+        TrackedAccountLockerClaim as CoreTrackedAccountLockerClaim,
+        SimpleTransfer as CoreSimpleTransfer,
+        SimpleTransferAmount as CoreSimpleTransferAmount,
         summary as core_summary,
         execution_summary as core_execution_summary,
     };
 
+    /* Caches */
+    pub use radix_engine_toolkit::caches::{
+        bech32_coders as core_bech32_coders,
+        clear_caches as core_clear_caches,
+    };
+
     /* Utils */
     pub use radix_engine_toolkit::utils::{
         manifest_from_intent as core_manifest_from_intent,
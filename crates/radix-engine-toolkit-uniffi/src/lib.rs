@@ -17,6 +17,7 @@
 
 #![allow(unused_imports)]
 
+pub mod address_book;
 pub mod blueprints;
 pub mod build;
 pub mod builder;
@@ -108,6 +109,10 @@ pub mod prelude {
     /* Utils */
     pub use crate::utils::functions::*;
 
+    /* Address Book */
+    pub use crate::address_book::functions::*;
+    pub use crate::address_book::*;
+
     /* Derive */
     pub use crate::derive::functions::*;
 
@@ -58,14 +58,12 @@ pub fn scrypto_sbor_decode_to_string_representation(
     network_id: u8,
     schema: Option<Schema>,
 ) -> Result<String> {
-    let network_definition =
-        core_network_definition_from_network_id(network_id);
-    let bech32_encoder = NativeAddressBech32Encoder::new(&network_definition);
+    let coders = core_bech32_coders(network_id);
     let string =
         core_scrypto_decode_to_string_representation(
             bytes,
             representation.into(),
-            &bech32_encoder,
+            &coders.address_encoder,
             if let Some(schema) = schema {
                 Some(schema.try_into()?)
             } else {
@@ -83,6 +81,23 @@ pub fn scrypto_sbor_encode_string_representation(
         .map_err(Into::into)
 }
 
+#[uniffi::export]
+pub fn sbor_encode_with_schema(
+    representation: ScryptoSborString,
+    schema: Schema,
+) -> Result<Vec<u8>> {
+    let (local_type_id, schema): (
+        NativeLocalTypeId,
+        NativeSchema<NativeScryptoCustomSchema>,
+    ) = schema.try_into()?;
+    core_scrypto_encode_string_representation_with_schema(
+        representation.into(),
+        local_type_id,
+        &schema,
+    )
+    .map_err(Into::into)
+}
+
 #[uniffi::export]
 pub fn manifest_sbor_decode_to_string_representation(
     bytes: Vec<u8>,
@@ -90,14 +105,12 @@ pub fn manifest_sbor_decode_to_string_representation(
     network_id: u8,
     schema: Option<Schema>,
 ) -> Result<String> {
-    let network_definition =
-        core_network_definition_from_network_id(network_id);
-    let bech32_encoder = NativeAddressBech32Encoder::new(&network_definition);
+    let coders = core_bech32_coders(network_id);
     let string =
         core_manifest_decode_to_string_representation(
             bytes,
             representation.into(),
-            &bech32_encoder,
+            &coders.address_encoder,
             if let Some(schema) = schema {
                 Some(schema.try_into()?)
             } else {
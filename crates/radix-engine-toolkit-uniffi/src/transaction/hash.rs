@@ -59,11 +59,8 @@ impl TransactionHash {
     where
         T: NativeHashHasHrp + NativeIsHash,
     {
-        let network_definition =
-            core_network_definition_from_network_id(network_id);
-        let bech32_encoder =
-            NativeTransactionHashBech32Encoder::new(&network_definition);
-        let encoded = bech32_encoder
+        let encoded = core_bech32_coders(network_id)
+            .transaction_hash_encoder
             .encode(hash)
             .expect("Bech32m encoding tx hashes cant fail");
         Self(*hash.as_hash(), encoded, network_id)
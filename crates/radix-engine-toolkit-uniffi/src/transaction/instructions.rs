@@ -26,8 +26,7 @@ impl Instructions {
     pub fn from_string(string: String, network_id: u8) -> Result<Arc<Self>> {
         let network_definition =
             core_network_definition_from_network_id(network_id);
-        let blob_provider = NativeMockBlobProvider::new();
-        native_compile(&string, &network_definition, blob_provider)
+        core_manifest_compile_string(&string, &network_definition)
             .map_err(Into::into)
             .map(|manifest| Arc::new(Self(manifest.instructions, network_id)))
     }
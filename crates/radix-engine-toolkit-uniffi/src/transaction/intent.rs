@@ -88,6 +88,45 @@ impl Intent {
         )
         .map_err(Into::into)
     }
+
+    pub fn replay_protection(&self) -> Result<ReplayProtection> {
+        core_intent_replay_protection(&self.clone().try_into()?)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+#[uniffi::export]
+pub fn replay_protection_from_compiled_intent(
+    compiled_intent: Vec<u8>,
+) -> Result<ReplayProtection> {
+    core_intent_replay_protection_from_payload(compiled_intent)
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ReplayProtection {
+    pub network_id: u8,
+    pub start_epoch_inclusive: u64,
+    pub end_epoch_exclusive: u64,
+    pub nonce: u32,
+    pub intent_hash: Arc<TransactionHash>,
+}
+
+impl From<CoreReplayProtection> for ReplayProtection {
+    fn from(value: CoreReplayProtection) -> Self {
+        Self {
+            network_id: value.network_id,
+            start_epoch_inclusive: value.start_epoch_inclusive.number(),
+            end_epoch_exclusive: value.end_epoch_exclusive.number(),
+            nonce: value.nonce,
+            intent_hash: Arc::new(TransactionHash::new(
+                &value.intent_hash,
+                value.network_id,
+            )),
+        }
+    }
 }
 
 impl From<NativeIntent> for Intent {
@@ -46,6 +46,16 @@ impl TransactionManifest {
         self.blobs.clone()
     }
 
+    pub fn add_blob(&self, blob: Vec<u8>) -> Arc<Self> {
+        let mut native = self.to_native();
+        core_manifest_add_blob(&mut native, blob);
+        Arc::new(Self::from_native(&native, self.instructions.network_id()))
+    }
+
+    pub fn get_blob(&self, hash: Arc<Hash>) -> Option<Vec<u8>> {
+        core_manifest_get_blob(&self.to_native(), &hash.0).cloned()
+    }
+
     pub fn compile(&self) -> Result<Vec<u8>> {
         let native = self.clone().to_native();
         Ok(core_manifest_compile(&native)?)
@@ -60,6 +70,7 @@ impl TransactionManifest {
     pub fn statically_validate(&self) -> Result<()> {
         core_instructions_statically_validate(&self.instructions.0)?;
         core_manifest_statically_validate(&self.to_native())?;
+        core_manifest_validate_blob_references(&self.to_native())?;
         Ok(())
     }
 
@@ -78,6 +89,29 @@ impl TransactionManifest {
         map
     }
 
+    pub fn extract_addresses_with_usage(&self) -> Vec<AddressWithUsage> {
+        let network_id = self.instructions.1;
+        core_instructions_extract_address_occurrences(&self.instructions.0)
+            .into_iter()
+            .map(|(address, occurrences)| AddressWithUsage {
+                address: Arc::new(Address::from_typed_node_id(
+                    address, network_id,
+                )),
+                usages: occurrences
+                    .iter()
+                    .map(|occurrence| occurrence.usage)
+                    .collect::<IndexSet<_>>()
+                    .into_iter()
+                    .map(AddressUsage::from)
+                    .collect(),
+                occurrences: occurrences
+                    .into_iter()
+                    .map(AddressOccurrence::from)
+                    .collect(),
+            })
+            .collect()
+    }
+
     pub fn modify(
         &self,
         modifications: TransactionManifestModifications,
@@ -95,23 +129,55 @@ impl TransactionManifest {
         ManifestSummary::from_native(core_manifest_summary(&native), network_id)
     }
 
+    pub fn simple_transfer(&self, network_id: u8) -> Option<SimpleTransfer> {
+        let native = self.clone().to_native();
+        core_manifest_simple_transfer(&native).map(|simple_transfer| {
+            SimpleTransfer::from_native(simple_transfer, network_id)
+        })
+    }
+
     pub fn execution_summary(
         &self,
         network_id: u8,
         encoded_receipt: Vec<u8>,
+    ) -> Result<ExecutionSummary> {
+        self.execution_summary_with_deposit_settings(
+            network_id,
+            encoded_receipt,
+            vec![],
+        )
+    }
+
+    pub fn execution_summary_with_deposit_settings(
+        &self,
+        network_id: u8,
+        encoded_receipt: Vec<u8>,
+        account_deposit_settings: Vec<AccountDepositSettings>,
     ) -> Result<ExecutionSummary> {
         let native = self.clone().to_native();
-        let versioned_transaction_receipt = native_scrypto_decode::<
-            NativeVersionedTransactionReceipt,
-        >(&encoded_receipt)?;
-        let receipt = versioned_transaction_receipt
-            .as_latest_version()
-            .ok_or(RadixEngineToolkitError::InvalidReceipt)?;
-
-        core_manifest_execution_summary(&native, &receipt)
-            .map_err(|_| RadixEngineToolkitError::InvalidReceipt)
+        let account_deposit_settings = account_deposit_settings
+            .into_iter()
+            .map(ToNative::to_native)
+            .collect::<Result<_>>()?;
+
+        core_manifest_execution_summary_with_deposit_settings(
+            &native,
+            CoreExecutionReceipt::EncodedPreview(&encoded_receipt),
+            &account_deposit_settings,
+        )
+        .map_err(|_| RadixEngineToolkitError::InvalidReceipt)
             .map(|summary| ExecutionSummary::from_native(summary, network_id))?
     }
+
+    pub fn trusted_worktop_analysis(&self) -> Vec<TrustedWorktopInstruction> {
+        let network_id = self.instructions.network_id();
+        core_manifest_trusted_worktop(&self.to_native())
+            .iter()
+            .map(|instruction| {
+                TrustedWorktopInstruction::from_native(instruction, network_id)
+            })
+            .collect()
+    }
 }
 
 impl TransactionManifest {
@@ -145,12 +211,58 @@ impl TransactionManifest {
     }
 }
 
+#[derive(Clone, Debug, Record)]
+pub struct AddressWithUsage {
+    pub address: Arc<Address>,
+    pub usages: Vec<AddressUsage>,
+    pub occurrences: Vec<AddressOccurrence>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AddressOccurrence {
+    pub instruction_index: u64,
+    pub usage: AddressUsage,
+}
+
+impl From<CoreAddressOccurrence> for AddressOccurrence {
+    fn from(value: CoreAddressOccurrence) -> Self {
+        Self {
+            instruction_index: value.instruction_index as u64,
+            usage: value.usage.into(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Enum)]
+pub enum AddressUsage {
+    CallTarget,
+    Argument,
+    MetadataSubject,
+    RoyaltyTarget,
+    Metadata,
+    Resource,
+}
+
+impl From<CoreAddressUsage> for AddressUsage {
+    fn from(value: CoreAddressUsage) -> Self {
+        match value {
+            CoreAddressUsage::CallTarget => Self::CallTarget,
+            CoreAddressUsage::Argument => Self::Argument,
+            CoreAddressUsage::MetadataSubject => Self::MetadataSubject,
+            CoreAddressUsage::RoyaltyTarget => Self::RoyaltyTarget,
+            CoreAddressUsage::Metadata => Self::Metadata,
+            CoreAddressUsage::Resource => Self::Resource,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Record)]
 pub struct FeeSummary {
     pub execution_cost: Arc<Decimal>,
     pub finalization_cost: Arc<Decimal>,
     pub storage_expansion_cost: Arc<Decimal>,
     pub royalty_cost: Arc<Decimal>,
+    pub tipping_cost: Arc<Decimal>,
 }
 
 impl FeeSummary {
@@ -160,6 +272,7 @@ impl FeeSummary {
             royalty_cost,
             finalization_cost,
             storage_expansion_cost,
+            tipping_cost,
         }: &CoreFeeSummary,
     ) -> Self {
         Self {
@@ -167,6 +280,70 @@ impl FeeSummary {
             royalty_cost: Arc::new(Decimal(*royalty_cost)),
             finalization_cost: Arc::new(Decimal(*finalization_cost)),
             storage_expansion_cost: Arc::new(Decimal(*storage_expansion_cost)),
+            tipping_cost: Arc::new(Decimal(*tipping_cost)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct AccountDepositSettings {
+    pub account_address: Arc<Address>,
+    pub default_deposit_rule: AccountDefaultDepositRule,
+    pub resource_preferences: HashMap<String, ResourcePreference>,
+    pub existing_resources: Vec<Arc<Address>>,
+}
+
+impl ToNative for AccountDepositSettings {
+    type Native = (NativeComponentAddress, CoreAccountDepositSettings);
+
+    fn to_native(self) -> Result<Self::Native> {
+        Ok((
+            (*self.account_address).try_into()?,
+            CoreAccountDepositSettings {
+                default_deposit_rule: self.default_deposit_rule.to_native()?,
+                resource_preferences: self
+                    .resource_preferences
+                    .into_iter()
+                    .map(|(resource_address, preference)| {
+                        Ok((
+                            (*Address::new(resource_address)?).try_into()?,
+                            preference.to_native()?,
+                        ))
+                    })
+                    .collect::<Result<_>>()?,
+                existing_resources: self
+                    .existing_resources
+                    .into_iter()
+                    .map(|resource_address| (*resource_address).try_into())
+                    .collect::<Result<_>>()?,
+            },
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum DepositCompatibility {
+    AllowedByResourcePreference,
+    DisallowedByResourcePreference,
+    AcceptedByDefaultDepositRule,
+    RejectedByDefaultDepositRule,
+}
+
+impl From<CoreDepositCompatibility> for DepositCompatibility {
+    fn from(value: CoreDepositCompatibility) -> Self {
+        match value {
+            CoreDepositCompatibility::AllowedByResourcePreference => {
+                Self::AllowedByResourcePreference
+            }
+            CoreDepositCompatibility::DisallowedByResourcePreference => {
+                Self::DisallowedByResourcePreference
+            }
+            CoreDepositCompatibility::AcceptedByDefaultDepositRule => {
+                Self::AcceptedByDefaultDepositRule
+            }
+            CoreDepositCompatibility::RejectedByDefaultDepositRule => {
+                Self::RejectedByDefaultDepositRule
+            }
         }
     }
 }
@@ -191,6 +368,32 @@ impl FeeLocks {
     }
 }
 
+#[derive(Clone, Debug, Record)]
+pub struct TrustedWorktopInstruction {
+    pub is_trusted: bool,
+    pub resources: Vec<ResourceSpecifier>,
+}
+
+impl TrustedWorktopInstruction {
+    pub fn from_native(
+        CoreTrustedWorktopInstruction {
+            is_trusted,
+            resources,
+        }: &CoreTrustedWorktopInstruction,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            is_trusted: *is_trusted,
+            resources: resources
+                .iter()
+                .map(|resource| {
+                    ResourceSpecifier::from_native(resource, network_id)
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Enum)]
 pub enum ResourceSpecifier {
     Amount {
@@ -458,6 +661,7 @@ pub enum ManifestClass {
     ValidatorUnstake,
     ValidatorClaim,
     AccountDepositSettingsUpdate,
+    AccountLockerClaim,
 }
 
 impl From<CoreManifestClass> for ManifestClass {
@@ -473,6 +677,7 @@ impl From<CoreManifestClass> for ManifestClass {
             CoreManifestClass::AccountDepositSettingsUpdate => {
                 Self::AccountDepositSettingsUpdate
             }
+            CoreManifestClass::AccountLockerClaim => Self::AccountLockerClaim,
         }
     }
 }
@@ -513,6 +718,10 @@ pub enum DetailedManifestClass {
         authorized_depositors_removed:
             HashMap<String, Vec<ResourceOrNonFungible>>,
     },
+    AccountLockerClaim {
+        locker_addresses: Vec<Arc<Address>>,
+        locker_claims: Vec<TrackedAccountLockerClaim>,
+    },
 }
 
 impl DetailedManifestClass {
@@ -704,6 +913,24 @@ impl DetailedManifestClass {
                     })
                     .collect(),
             },
+            CoreDetailedManifestClass::AccountLockerClaim {
+                locker_addresses,
+                locker_claims,
+            } => Self::AccountLockerClaim {
+                locker_addresses: locker_addresses
+                    .into_iter()
+                    .map(|item| {
+                        Arc::new(Address::unsafe_from_raw(
+                            item.into_node_id(),
+                            network_id,
+                        ))
+                    })
+                    .collect(),
+                locker_claims: locker_claims
+                    .into_iter()
+                    .map(|item| TrackedAccountLockerClaim::from_native(item, network_id))
+                    .collect(),
+            },
         }
     }
 }
@@ -719,9 +946,13 @@ pub struct ExecutionSummary {
     pub identities_requiring_auth: Vec<Arc<Address>>,
     pub reserved_instructions: Vec<ReservedInstruction>,
     pub fee_locks: FeeLocks,
+    pub fee_locks_by_account: HashMap<String, FeeLocks>,
     pub fee_summary: FeeSummary,
     pub detailed_classification: Vec<DetailedManifestClass>,
     pub newly_created_non_fungibles: Vec<Arc<NonFungibleGlobalId>>,
+    pub deposit_compatibility:
+        HashMap<String, HashMap<String, DepositCompatibility>>,
+    pub balance_changes: HashMap<String, AccountBalanceChanges>,
 }
 
 impl ExecutionSummary {
@@ -819,6 +1050,20 @@ impl ExecutionSummary {
                 .map(ReservedInstruction::from)
                 .collect(),
             fee_locks: FeeLocks::from_native(&native.fee_locks),
+            fee_locks_by_account: native
+                .fee_locks_by_account
+                .iter()
+                .map(|(account, fee_locks)| {
+                    (
+                        Address::unsafe_from_raw(
+                            account.into_node_id(),
+                            network_id,
+                        )
+                        .address_string(),
+                        FeeLocks::from_native(fee_locks),
+                    )
+                })
+                .collect(),
             fee_summary: FeeSummary::from_native(&native.fee_summary),
             detailed_classification:
                 native
@@ -841,10 +1086,157 @@ impl ExecutionSummary {
                     )
                 })
                 .collect::<Result<Vec<_>>>()?,
+            deposit_compatibility: native
+                .deposit_compatibility
+                .into_iter()
+                .map(|(account, deposits)| {
+                    (
+                        Address::unsafe_from_raw(
+                            account.into_node_id(),
+                            network_id,
+                        )
+                        .address_string(),
+                        deposits
+                            .into_iter()
+                            .map(|(resource_address, compatibility)| {
+                                (
+                                    Address::unsafe_from_raw(
+                                        resource_address.into_node_id(),
+                                        network_id,
+                                    )
+                                    .address_string(),
+                                    compatibility.into(),
+                                )
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+            balance_changes: native
+                .balance_changes
+                .into_iter()
+                .map(|(account, changes)| {
+                    (
+                        Address::unsafe_from_raw(
+                            account.into_node_id(),
+                            network_id,
+                        )
+                        .address_string(),
+                        AccountBalanceChanges::from_native(changes, network_id),
+                    )
+                })
+                .collect(),
         })
     }
 }
 
+#[derive(Clone, Debug, Record)]
+pub struct AccountBalanceChanges {
+    pub fungible: HashMap<String, Arc<Decimal>>,
+    pub non_fungible: HashMap<String, NonFungibleBalanceChange>,
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct NonFungibleBalanceChange {
+    pub added: Vec<NonFungibleLocalId>,
+    pub removed: Vec<NonFungibleLocalId>,
+}
+
+impl AccountBalanceChanges {
+    pub fn from_native(
+        CoreAccountBalanceChanges {
+            fungible,
+            non_fungible,
+        }: CoreAccountBalanceChanges,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            fungible: fungible
+                .into_iter()
+                .map(|(resource_address, amount)| {
+                    (
+                        Address::unsafe_from_raw(
+                            resource_address.into_node_id(),
+                            network_id,
+                        )
+                        .address_string(),
+                        Arc::new(Decimal(amount)),
+                    )
+                })
+                .collect(),
+            non_fungible: non_fungible
+                .into_iter()
+                .map(
+                    |(
+                        resource_address,
+                        CoreNonFungibleBalanceChange { added, removed },
+                    )| {
+                        (
+                            Address::unsafe_from_raw(
+                                resource_address.into_node_id(),
+                                network_id,
+                            )
+                            .address_string(),
+                            NonFungibleBalanceChange {
+                                added: added
+                                    .into_iter()
+                                    .map(Into::into)
+                                    .collect(),
+                                removed: removed
+                                    .into_iter()
+                                    .map(Into::into)
+                                    .collect(),
+                            },
+                        )
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct SimpleTransfer {
+    pub from: Arc<Address>,
+    pub to: Arc<Address>,
+    pub resource: Arc<Address>,
+    pub amount: SimpleTransferAmount,
+}
+
+impl SimpleTransfer {
+    fn from_native(native: CoreSimpleTransfer, network_id: u8) -> Self {
+        Self {
+            from: Arc::new(Address::from_typed_node_id(
+                native.from,
+                network_id,
+            )),
+            to: Arc::new(Address::from_typed_node_id(native.to, network_id)),
+            resource: Arc::new(Address::from_typed_node_id(
+                native.resource,
+                network_id,
+            )),
+            amount: match native.amount {
+                CoreSimpleTransferAmount::Amount(amount) => {
+                    SimpleTransferAmount::Amount {
+                        value: Arc::new(Decimal(amount)),
+                    }
+                }
+                CoreSimpleTransferAmount::Ids(ids) => {
+                    SimpleTransferAmount::Ids {
+                        value: ids.into_iter().map(Into::into).collect(),
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum SimpleTransferAmount {
+    Amount { value: Arc<Decimal> },
+    Ids { value: Vec<NonFungibleLocalId> },
+}
+
 #[derive(Clone, Debug, Record)]
 pub struct ManifestSummary {
     pub presented_proofs: HashMap<String, Vec<ResourceSpecifier>>,
@@ -1414,3 +1806,32 @@ impl From<NativeUnstakeData> for UnstakeData {
         }
     }
 }
+
+#[derive(Clone, Debug, Record)]
+pub struct TrackedAccountLockerClaim {
+    pub locker_address: Arc<Address>,
+    pub claimant: Arc<Address>,
+    pub claimed_resources: ResourceSpecifier,
+}
+
+impl TrackedAccountLockerClaim {
+    pub fn from_native(
+        native: CoreTrackedAccountLockerClaim,
+        network_id: u8,
+    ) -> Self {
+        Self {
+            locker_address: Arc::new(Address::unsafe_from_raw(
+                native.locker_address.into_node_id(),
+                network_id,
+            )),
+            claimant: Arc::new(Address::unsafe_from_raw(
+                native.claimant.into_node_id(),
+                network_id,
+            )),
+            claimed_resources: ResourceSpecifier::from_native(
+                &native.claimed_resources,
+                network_id,
+            ),
+        }
+    }
+}
@@ -187,3 +187,58 @@ impl From<NativeMessageValidationConfig> for MessageValidationConfig {
         }
     }
 }
+
+/// Explains a validation failure given the error string reported by the
+/// static validation functions or the rejection reason reported by the node.
+#[uniffi::export]
+pub fn explain_validation_failure(
+    error: String,
+) -> ValidationFailureExplanation {
+    core_explain_validation_failure(&error).into()
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct ValidationFailureExplanation {
+    pub error: Option<String>,
+    pub location: Option<ValidationFailureLocation>,
+    pub causes: Vec<String>,
+    pub fixes: Vec<String>,
+}
+
+#[derive(Clone, Debug, Enum)]
+pub enum ValidationFailureLocation {
+    Header { field: String },
+    Signatures,
+    Manifest { detail: Option<String> },
+    Message,
+    Payload,
+    Ledger,
+}
+
+impl From<CoreValidationFailureExplanation> for ValidationFailureExplanation {
+    fn from(value: CoreValidationFailureExplanation) -> Self {
+        Self {
+            error: value.error.map(ToOwned::to_owned),
+            location: value.location.map(Into::into),
+            causes: value.causes.into_iter().map(ToOwned::to_owned).collect(),
+            fixes: value.fixes.into_iter().map(ToOwned::to_owned).collect(),
+        }
+    }
+}
+
+impl From<CoreValidationFailureLocation> for ValidationFailureLocation {
+    fn from(value: CoreValidationFailureLocation) -> Self {
+        match value {
+            CoreValidationFailureLocation::Header { field } => Self::Header {
+                field: field.to_owned(),
+            },
+            CoreValidationFailureLocation::Signatures => Self::Signatures,
+            CoreValidationFailureLocation::Manifest { detail } => {
+                Self::Manifest { detail }
+            }
+            CoreValidationFailureLocation::Message => Self::Message,
+            CoreValidationFailureLocation::Payload => Self::Payload,
+            CoreValidationFailureLocation::Ledger => Self::Ledger,
+        }
+    }
+}
@@ -22,6 +22,13 @@ pub fn get_known_addresses(network_id: u8) -> KnownAddresses {
     KnownAddresses::new_from_network(network_id)
 }
 
+/// Clears the caches of the toolkit such as the cached Bech32 encoders and
+/// decoders of each network.
+#[uniffi::export]
+pub fn clear_caches() {
+    core_clear_caches()
+}
+
 #[uniffi::export]
 pub fn get_hash(data: Vec<u8>) -> Arc<Hash> {
     Hash::from_unhashed_bytes(data)
@@ -0,0 +1,92 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Caches of the per-network objects that are expensive to construct relative
+//! to the requests that use them, such as the Bech32 encoders and decoders
+//! which build the human-readable parts of all entity types when constructed.
+//! The caches are filled lazily and are safe to use from multiple threads.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use lazy_static::lazy_static;
+use radix_common::prelude::*;
+use radix_transactions::prelude::*;
+
+use crate::utils::network_definition_from_network_id;
+
+/// The Bech32 encoders and decoders of a network along with its definition.
+pub struct NetworkBech32Coders {
+    pub network_definition: NetworkDefinition,
+    pub address_encoder: AddressBech32Encoder,
+    pub address_decoder: AddressBech32Decoder,
+    pub transaction_hash_encoder: TransactionHashBech32Encoder,
+    pub transaction_hash_decoder: TransactionHashBech32Decoder,
+}
+
+impl NetworkBech32Coders {
+    pub fn new(network_definition: NetworkDefinition) -> Self {
+        Self {
+            address_encoder: AddressBech32Encoder::new(&network_definition),
+            address_decoder: AddressBech32Decoder::new(&network_definition),
+            transaction_hash_encoder: TransactionHashBech32Encoder::new(
+                &network_definition,
+            ),
+            transaction_hash_decoder: TransactionHashBech32Decoder::new(
+                &network_definition,
+            ),
+            network_definition,
+        }
+    }
+}
+
+lazy_static! {
+    static ref BECH32_CODERS: RwLock<HashMap<u8, Arc<NetworkBech32Coders>>> =
+        Default::default();
+}
+
+/// Returns the cached Bech32 encoders and decoders of the network, creating
+/// them on the first use.
+pub fn bech32_coders(network_id: u8) -> Arc<NetworkBech32Coders> {
+    if let Some(coders) = BECH32_CODERS
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .get(&network_id)
+    {
+        return coders.clone();
+    }
+
+    BECH32_CODERS
+        .write()
+        .unwrap_or_else(|error| error.into_inner())
+        .entry(network_id)
+        .or_insert_with(|| {
+            Arc::new(NetworkBech32Coders::new(
+                network_definition_from_network_id(network_id),
+            ))
+        })
+        .clone()
+}
+
+/// Clears all of the caches, releasing the memory held by them. The caches
+/// are filled again as they're used.
+pub fn clear_caches() {
+    BECH32_CODERS
+        .write()
+        .unwrap_or_else(|error| error.into_inner())
+        .clear();
+}
@@ -0,0 +1,257 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Manifests for the recovery and badge withdraw flows of access controllers.
+//! A flow is proposed by the primary or the recovery role and is either
+//! confirmed by one of the other two roles, confirmed by the recovery role
+//! once the timed recovery delay has passed, or cancelled by the proposer.
+//! The proofs that satisfy the roles aren't created by the manifests and are
+//! expected to be added to them by the caller.
+
+use radix_engine_interface::blueprints::access_controller::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+/// The role that proposes a recovery or a badge withdraw attempt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessControllerProposer {
+    Primary,
+    Recovery,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessControllerRole {
+    Primary,
+    Recovery,
+    Confirmation,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessControllerOperation {
+    /// Proposes to replace the rule set of the access controller.
+    InitiateRecovery {
+        proposer: AccessControllerProposer,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    /// Confirms the recovery proposal of the proposer with the authority of
+    /// one of the other two roles. The rule set and delay must be the ones
+    /// that were proposed.
+    QuickConfirmRecovery {
+        proposer: AccessControllerProposer,
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    /// Confirms the recovery proposal of the recovery role once its timed
+    /// recovery delay has passed.
+    TimedConfirmRecovery {
+        rule_set: RuleSet,
+        timed_recovery_delay_in_minutes: Option<u32>,
+    },
+    CancelRecovery {
+        proposer: AccessControllerProposer,
+    },
+    /// Proposes to withdraw the badge that the access controller protects.
+    InitiateBadgeWithdrawAttempt {
+        proposer: AccessControllerProposer,
+    },
+    /// Confirms the badge withdraw attempt of the proposer and deposits the
+    /// withdrawn badge into the account.
+    QuickConfirmBadgeWithdrawAttempt {
+        proposer: AccessControllerProposer,
+        account_address: ComponentAddress,
+    },
+    CancelBadgeWithdrawAttempt {
+        proposer: AccessControllerProposer,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessControllerOperationError {
+    NotAnAccessController(ComponentAddress),
+    /// The role of the rule set can be satisfied by anyone.
+    RoleAllowsAll(AccessControllerRole),
+    /// Neither the recovery nor the confirmation role of the rule set can be
+    /// satisfied, so no recovery could ever be initiated and confirmed.
+    UnrecoverableRuleSet,
+}
+
+/// Checks that none of the roles of the rule set can be satisfied by anyone
+/// and that recoveries remain possible under the rule set.
+pub fn validate_rule_set(
+    rule_set: &RuleSet,
+) -> Result<(), AccessControllerOperationError> {
+    let roles = [
+        (AccessControllerRole::Primary, &rule_set.primary_role),
+        (AccessControllerRole::Recovery, &rule_set.recovery_role),
+        (
+            AccessControllerRole::Confirmation,
+            &rule_set.confirmation_role,
+        ),
+    ];
+    if let Some((role, _)) = roles
+        .iter()
+        .find(|(_, rule)| matches!(rule, AccessRule::AllowAll))
+    {
+        return Err(AccessControllerOperationError::RoleAllowsAll(*role));
+    }
+
+    match (&rule_set.recovery_role, &rule_set.confirmation_role) {
+        (AccessRule::DenyAll, AccessRule::DenyAll) => {
+            Err(AccessControllerOperationError::UnrecoverableRuleSet)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Builds the manifest that performs the operations in order on the access
+/// controller after validating the rule sets that they propose.
+pub fn build_access_controller_manifest(
+    access_controller_address: ComponentAddress,
+    operations: &[AccessControllerOperation],
+) -> Result<TransactionManifestV1, AccessControllerOperationError> {
+    if access_controller_address.as_node_id().entity_type()
+        != Some(EntityType::GlobalAccessController)
+    {
+        return Err(AccessControllerOperationError::NotAnAccessController(
+            access_controller_address,
+        ));
+    }
+
+    operations
+        .iter()
+        .try_fold(ManifestBuilder::new(), |builder, operation| {
+            add_access_controller_operation(
+                builder,
+                access_controller_address,
+                operation,
+            )
+        })
+        .map(ManifestBuilder::build)
+}
+
+fn add_access_controller_operation(
+    builder: ManifestBuilder,
+    address: ComponentAddress,
+    operation: &AccessControllerOperation,
+) -> Result<ManifestBuilder, AccessControllerOperationError> {
+    let builder = match operation {
+        AccessControllerOperation::InitiateRecovery {
+            proposer,
+            rule_set,
+            timed_recovery_delay_in_minutes,
+        } => {
+            validate_rule_set(rule_set)?;
+            let method_name = match proposer {
+                AccessControllerProposer::Primary => {
+                    ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT
+                }
+                AccessControllerProposer::Recovery => {
+                    ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT
+                }
+            };
+            builder.call_method(
+                address,
+                method_name,
+                (rule_set.clone(), *timed_recovery_delay_in_minutes),
+            )
+        }
+        AccessControllerOperation::QuickConfirmRecovery {
+            proposer,
+            rule_set,
+            timed_recovery_delay_in_minutes,
+        } => {
+            validate_rule_set(rule_set)?;
+            let method_name = match proposer {
+                AccessControllerProposer::Primary => {
+                    ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT
+                }
+                AccessControllerProposer::Recovery => {
+                    ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT
+                }
+            };
+            builder.call_method(
+                address,
+                method_name,
+                (rule_set.clone(), *timed_recovery_delay_in_minutes),
+            )
+        }
+        AccessControllerOperation::TimedConfirmRecovery {
+            rule_set,
+            timed_recovery_delay_in_minutes,
+        } => {
+            validate_rule_set(rule_set)?;
+            builder.call_method(
+                address,
+                ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+                (rule_set.clone(), *timed_recovery_delay_in_minutes),
+            )
+        }
+        AccessControllerOperation::CancelRecovery { proposer } => {
+            let method_name = match proposer {
+                AccessControllerProposer::Primary => {
+                    ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT
+                }
+                AccessControllerProposer::Recovery => {
+                    ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT
+                }
+            };
+            builder.call_method(address, method_name, ())
+        }
+        AccessControllerOperation::InitiateBadgeWithdrawAttempt {
+            proposer,
+        } => {
+            let method_name = match proposer {
+                AccessControllerProposer::Primary => {
+                    ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_PRIMARY_IDENT
+                }
+                AccessControllerProposer::Recovery => {
+                    ACCESS_CONTROLLER_INITIATE_BADGE_WITHDRAW_ATTEMPT_AS_RECOVERY_IDENT
+                }
+            };
+            builder.call_method(address, method_name, ())
+        }
+        AccessControllerOperation::QuickConfirmBadgeWithdrawAttempt {
+            proposer,
+            account_address,
+        } => {
+            let method_name = match proposer {
+                AccessControllerProposer::Primary => {
+                    ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
+                }
+                AccessControllerProposer::Recovery => {
+                    ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
+                }
+            };
+            builder
+                .call_method(address, method_name, ())
+                .try_deposit_entire_worktop_or_abort(*account_address, None)
+        }
+        AccessControllerOperation::CancelBadgeWithdrawAttempt { proposer } => {
+            let method_name = match proposer {
+                AccessControllerProposer::Primary => {
+                    ACCESS_CONTROLLER_CANCEL_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
+                }
+                AccessControllerProposer::Recovery => {
+                    ACCESS_CONTROLLER_CANCEL_RECOVERY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT
+                }
+            };
+            builder.call_method(address, method_name, ())
+        }
+    };
+    Ok(builder)
+}
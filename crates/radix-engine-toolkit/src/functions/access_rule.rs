@@ -0,0 +1,84 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for building access rules that combine rules in the same way that
+//! the `rule!` macro does while simplifying rules that allow or deny all.
+
+use scrypto::prelude::*;
+
+pub fn require<T>(resource_or_non_fungible: T) -> AccessRule
+where
+    T: Into<ResourceOrNonFungible>,
+{
+    AccessRule::Protected(AccessRuleNode::ProofRule(ProofRule::Require(
+        resource_or_non_fungible.into(),
+    )))
+}
+
+pub fn require_amount(
+    amount: Decimal,
+    resource_address: ResourceAddress,
+) -> AccessRule {
+    AccessRule::Protected(AccessRuleNode::ProofRule(ProofRule::AmountOf(
+        amount,
+        resource_address,
+    )))
+}
+
+/// Combines the rules into a rule that is satisfied when any of them is. The
+/// combination of no rules is never satisfied.
+pub fn any_of<I>(rules: I) -> AccessRule
+where
+    I: IntoIterator<Item = AccessRule>,
+{
+    let mut nodes = Vec::new();
+    for rule in rules {
+        match rule {
+            AccessRule::AllowAll => return AccessRule::AllowAll,
+            AccessRule::DenyAll => {}
+            AccessRule::Protected(node) => nodes.push(node),
+        }
+    }
+
+    match nodes.len() {
+        0 => AccessRule::DenyAll,
+        1 => AccessRule::Protected(nodes.remove(0)),
+        _ => AccessRule::Protected(AccessRuleNode::AnyOf(nodes)),
+    }
+}
+
+/// Combines the rules into a rule that is satisfied when all of them are. The
+/// combination of no rules is always satisfied.
+pub fn all_of<I>(rules: I) -> AccessRule
+where
+    I: IntoIterator<Item = AccessRule>,
+{
+    let mut nodes = Vec::new();
+    for rule in rules {
+        match rule {
+            AccessRule::AllowAll => {}
+            AccessRule::DenyAll => return AccessRule::DenyAll,
+            AccessRule::Protected(node) => nodes.push(node),
+        }
+    }
+
+    match nodes.len() {
+        0 => AccessRule::AllowAll,
+        1 => AccessRule::Protected(nodes.remove(0)),
+        _ => AccessRule::Protected(AccessRuleNode::AllOf(nodes)),
+    }
+}
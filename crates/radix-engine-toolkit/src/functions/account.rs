@@ -0,0 +1,340 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Typed construction of calls to the methods of accounts. The arguments are
+//! encoded from the input types of the account blueprint such that the enum
+//! discriminators and the argument tuples always match what the blueprint
+//! expects.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+/// A method of the account blueprint along with its arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountMethod {
+    Securify,
+    LockFee {
+        amount: Decimal,
+    },
+    LockContingentFee {
+        amount: Decimal,
+    },
+    Withdraw {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    WithdrawNonFungibles {
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+    },
+    LockFeeAndWithdraw {
+        amount_to_lock: Decimal,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    LockFeeAndWithdrawNonFungibles {
+        amount_to_lock: Decimal,
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+    },
+    CreateProofOfAmount {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    CreateProofOfNonFungibles {
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+    },
+    Deposit {
+        bucket: ManifestBucket,
+    },
+    DepositBatch {
+        buckets: Vec<ManifestBucket>,
+    },
+    TryDepositOrAbort {
+        bucket: ManifestBucket,
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+    },
+    TryDepositBatchOrAbort {
+        buckets: Vec<ManifestBucket>,
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+    },
+    TryDepositOrRefund {
+        bucket: ManifestBucket,
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+    },
+    TryDepositBatchOrRefund {
+        buckets: Vec<ManifestBucket>,
+        authorized_depositor_badge: Option<ResourceOrNonFungible>,
+    },
+    Burn {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    BurnNonFungibles {
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+    },
+    SetDefaultDepositRule {
+        default_deposit_rule: DefaultDepositRule,
+    },
+    SetResourcePreference {
+        resource_address: ResourceAddress,
+        resource_preference: ResourcePreference,
+    },
+    RemoveResourcePreference {
+        resource_address: ResourceAddress,
+    },
+    AddAuthorizedDepositor {
+        badge: ResourceOrNonFungible,
+    },
+    RemoveAuthorizedDepositor {
+        badge: ResourceOrNonFungible,
+    },
+}
+
+impl AccountMethod {
+    pub fn method_name(&self) -> &'static str {
+        match self {
+            Self::Securify => ACCOUNT_SECURIFY_IDENT,
+            Self::LockFee { .. } => ACCOUNT_LOCK_FEE_IDENT,
+            Self::LockContingentFee { .. } => ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+            Self::Withdraw { .. } => ACCOUNT_WITHDRAW_IDENT,
+            Self::WithdrawNonFungibles { .. } => {
+                ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT
+            }
+            Self::LockFeeAndWithdraw { .. } => {
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT
+            }
+            Self::LockFeeAndWithdrawNonFungibles { .. } => {
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT
+            }
+            Self::CreateProofOfAmount { .. } => {
+                ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+            }
+            Self::CreateProofOfNonFungibles { .. } => {
+                ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+            }
+            Self::Deposit { .. } => ACCOUNT_DEPOSIT_IDENT,
+            Self::DepositBatch { .. } => ACCOUNT_DEPOSIT_BATCH_IDENT,
+            Self::TryDepositOrAbort { .. } => {
+                ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+            }
+            Self::TryDepositBatchOrAbort { .. } => {
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+            }
+            Self::TryDepositOrRefund { .. } => {
+                ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT
+            }
+            Self::TryDepositBatchOrRefund { .. } => {
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT
+            }
+            Self::Burn { .. } => ACCOUNT_BURN_IDENT,
+            Self::BurnNonFungibles { .. } => ACCOUNT_BURN_NON_FUNGIBLES_IDENT,
+            Self::SetDefaultDepositRule { .. } => {
+                ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT
+            }
+            Self::SetResourcePreference { .. } => {
+                ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT
+            }
+            Self::RemoveResourcePreference { .. } => {
+                ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT
+            }
+            Self::AddAuthorizedDepositor { .. } => {
+                ACCOUNT_ADD_AUTHORIZED_DEPOSITOR
+            }
+            Self::RemoveAuthorizedDepositor { .. } => {
+                ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR
+            }
+        }
+    }
+
+    /// The arguments of the method encoded from the input type of the method.
+    pub fn args(&self) -> ManifestValue {
+        match self.clone() {
+            Self::Securify => {
+                to_manifest_value_and_unwrap!(&AccountSecurifyInput {})
+            }
+            Self::LockFee { amount } => {
+                to_manifest_value_and_unwrap!(&AccountLockFeeInput { amount })
+            }
+            Self::LockContingentFee { amount } => {
+                to_manifest_value_and_unwrap!(&AccountLockContingentFeeInput {
+                    amount
+                })
+            }
+            Self::Withdraw {
+                resource_address,
+                amount,
+            } => to_manifest_value_and_unwrap!(&AccountWithdrawInput {
+                resource_address,
+                amount,
+            }),
+            Self::WithdrawNonFungibles {
+                resource_address,
+                ids,
+            } => to_manifest_value_and_unwrap!(
+                &AccountWithdrawNonFungiblesInput {
+                    resource_address,
+                    ids,
+                }
+            ),
+            Self::LockFeeAndWithdraw {
+                amount_to_lock,
+                resource_address,
+                amount,
+            } => {
+                to_manifest_value_and_unwrap!(&AccountLockFeeAndWithdrawInput {
+                    amount_to_lock,
+                    resource_address,
+                    amount,
+                })
+            }
+            Self::LockFeeAndWithdrawNonFungibles {
+                amount_to_lock,
+                resource_address,
+                ids,
+            } => to_manifest_value_and_unwrap!(
+                &AccountLockFeeAndWithdrawNonFungiblesInput {
+                    amount_to_lock,
+                    resource_address,
+                    ids,
+                }
+            ),
+            Self::CreateProofOfAmount {
+                resource_address,
+                amount,
+            } => to_manifest_value_and_unwrap!(
+                &AccountCreateProofOfAmountInput {
+                    resource_address,
+                    amount,
+                }
+            ),
+            Self::CreateProofOfNonFungibles {
+                resource_address,
+                ids,
+            } => to_manifest_value_and_unwrap!(
+                &AccountCreateProofOfNonFungiblesInput {
+                    resource_address,
+                    ids,
+                }
+            ),
+            Self::Deposit { bucket } => {
+                to_manifest_value_and_unwrap!(&AccountDepositManifestInput {
+                    bucket
+                })
+            }
+            Self::DepositBatch { buckets } => to_manifest_value_and_unwrap!(
+                &AccountDepositBatchManifestInput { buckets }
+            ),
+            Self::TryDepositOrAbort {
+                bucket,
+                authorized_depositor_badge,
+            } => to_manifest_value_and_unwrap!(
+                &AccountTryDepositOrAbortManifestInput {
+                    bucket,
+                    authorized_depositor_badge,
+                }
+            ),
+            Self::TryDepositBatchOrAbort {
+                buckets,
+                authorized_depositor_badge,
+            } => to_manifest_value_and_unwrap!(
+                &AccountTryDepositBatchOrAbortManifestInput {
+                    buckets,
+                    authorized_depositor_badge,
+                }
+            ),
+            Self::TryDepositOrRefund {
+                bucket,
+                authorized_depositor_badge,
+            } => to_manifest_value_and_unwrap!(
+                &AccountTryDepositOrRefundManifestInput {
+                    bucket,
+                    authorized_depositor_badge,
+                }
+            ),
+            Self::TryDepositBatchOrRefund {
+                buckets,
+                authorized_depositor_badge,
+            } => to_manifest_value_and_unwrap!(
+                &AccountTryDepositBatchOrRefundManifestInput {
+                    buckets,
+                    authorized_depositor_badge,
+                }
+            ),
+            Self::Burn {
+                resource_address,
+                amount,
+            } => to_manifest_value_and_unwrap!(&AccountBurnInput {
+                resource_address,
+                amount,
+            }),
+            Self::BurnNonFungibles {
+                resource_address,
+                ids,
+            } => to_manifest_value_and_unwrap!(&AccountBurnNonFungiblesInput {
+                resource_address,
+                ids,
+            }),
+            Self::SetDefaultDepositRule {
+                default_deposit_rule,
+            } => to_manifest_value_and_unwrap!(
+                &AccountSetDefaultDepositRuleInput {
+                    default: default_deposit_rule,
+                }
+            ),
+            Self::SetResourcePreference {
+                resource_address,
+                resource_preference,
+            } => to_manifest_value_and_unwrap!(
+                &AccountSetResourcePreferenceInput {
+                    resource_address,
+                    resource_preference,
+                }
+            ),
+            Self::RemoveResourcePreference { resource_address } => {
+                to_manifest_value_and_unwrap!(
+                    &AccountRemoveResourcePreferenceInput { resource_address }
+                )
+            }
+            Self::AddAuthorizedDepositor { badge } => {
+                to_manifest_value_and_unwrap!(
+                    &AccountAddAuthorizedDepositorInput { badge }
+                )
+            }
+            Self::RemoveAuthorizedDepositor { badge } => {
+                to_manifest_value_and_unwrap!(
+                    &AccountRemoveAuthorizedDepositorInput { badge }
+                )
+            }
+        }
+    }
+}
+
+/// The instruction that calls the method on the account.
+pub fn call_account_method(
+    account: impl Into<DynamicGlobalAddress>,
+    method: &AccountMethod,
+) -> InstructionV1 {
+    InstructionV1::CallMethod {
+        address: account.into(),
+        method_name: method.method_name().to_owned(),
+        args: method.args(),
+    }
+}
@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Manifests for storing resources in account lockers, airdropping them to
+//! many claimants at once, and claiming them. Stored and airdropped resources
+//! are withdrawn from the account of the storer and taken from the worktop
+//! into a bucket that's passed to the locker, while claimed resources are
+//! deposited into the account of the claimant. The proof that satisfies the
+//! storer role of the locker isn't created by the manifests.
+
+use radix_engine_interface::blueprints::locker::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::utils::is_account_locker;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountLockerOperation {
+    /// Stores the amount of the resource for the claimant. When sending
+    /// directly is tried the resources are deposited into the account of the
+    /// claimant if its deposit rules allow it.
+    Store {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        try_direct_send: bool,
+    },
+    StoreNonFungibles {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+        try_direct_send: bool,
+    },
+    /// Stores the amounts of the resource for each of the claimants.
+    AirdropFungible {
+        resource_address: ResourceAddress,
+        claimants: IndexMap<ComponentAddress, Decimal>,
+        try_direct_send: bool,
+    },
+    /// Stores the non-fungibles of the resource for each of the claimants.
+    AirdropNonFungibles {
+        resource_address: ResourceAddress,
+        claimants: IndexMap<ComponentAddress, IndexSet<NonFungibleLocalId>>,
+        try_direct_send: bool,
+    },
+    /// Claims the amount of the resource stored for the account.
+    Claim {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    ClaimNonFungibles {
+        resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountLockerOperationError {
+    NotAnAccountLocker(ComponentAddress),
+    /// The total amount of an airdrop overflows.
+    AirdropAmountOverflow,
+}
+
+/// Builds the manifest that performs the operations in order on the locker.
+/// The account is the one that stored and airdropped resources are withdrawn
+/// from and the one that claims are made for.
+pub fn build_account_locker_manifest(
+    locker_address: ComponentAddress,
+    account_address: ComponentAddress,
+    operations: &[AccountLockerOperation],
+) -> Result<TransactionManifestV1, AccountLockerOperationError> {
+    if !is_account_locker(&locker_address) {
+        return Err(AccountLockerOperationError::NotAnAccountLocker(
+            locker_address,
+        ));
+    }
+
+    operations
+        .iter()
+        .enumerate()
+        .try_fold(ManifestBuilder::new(), |builder, (index, operation)| {
+            add_account_locker_operation(
+                builder,
+                locker_address,
+                account_address,
+                operation,
+                &format!("bucket{index}"),
+            )
+        })
+        .map(ManifestBuilder::build)
+}
+
+fn add_account_locker_operation(
+    builder: ManifestBuilder,
+    locker_address: ComponentAddress,
+    account_address: ComponentAddress,
+    operation: &AccountLockerOperation,
+    bucket: &str,
+) -> Result<ManifestBuilder, AccountLockerOperationError> {
+    let take_fungible = |builder: ManifestBuilder,
+                         resource_address: ResourceAddress,
+                         amount: Decimal| {
+        builder
+            .withdraw_from_account(account_address, resource_address, amount)
+            .take_from_worktop(resource_address, amount, bucket)
+    };
+    let take_non_fungibles =
+        |builder: ManifestBuilder,
+         resource_address: ResourceAddress,
+         ids: IndexSet<NonFungibleLocalId>| {
+            builder
+                .withdraw_non_fungibles_from_account(
+                    account_address,
+                    resource_address,
+                    ids.clone(),
+                )
+                .take_non_fungibles_from_worktop(resource_address, ids, bucket)
+        };
+
+    let builder = match operation {
+        AccountLockerOperation::Store {
+            claimant,
+            resource_address,
+            amount,
+            try_direct_send,
+        } => take_fungible(builder, *resource_address, *amount)
+            .call_method_with_name_lookup(
+                locker_address,
+                ACCOUNT_LOCKER_STORE_IDENT,
+                |lookup| (*claimant, lookup.bucket(bucket), *try_direct_send),
+            ),
+        AccountLockerOperation::StoreNonFungibles {
+            claimant,
+            resource_address,
+            ids,
+            try_direct_send,
+        } => take_non_fungibles(builder, *resource_address, ids.clone())
+            .call_method_with_name_lookup(
+                locker_address,
+                ACCOUNT_LOCKER_STORE_IDENT,
+                |lookup| (*claimant, lookup.bucket(bucket), *try_direct_send),
+            ),
+        AccountLockerOperation::AirdropFungible {
+            resource_address,
+            claimants,
+            try_direct_send,
+        } => {
+            let amount = claimants
+                .values()
+                .try_fold(Decimal::ZERO, |total, amount| {
+                    total.checked_add(*amount)
+                })
+                .ok_or(AccountLockerOperationError::AirdropAmountOverflow)?;
+            let claimants = claimants
+                .iter()
+                .map(|(claimant, amount)| {
+                    (*claimant, ResourceSpecifier::Fungible(*amount))
+                })
+                .collect::<IndexMap<_, _>>();
+            take_fungible(builder, *resource_address, amount)
+                .call_method_with_name_lookup(
+                    locker_address,
+                    ACCOUNT_LOCKER_AIRDROP_IDENT,
+                    |lookup| {
+                        (claimants, lookup.bucket(bucket), *try_direct_send)
+                    },
+                )
+        }
+        AccountLockerOperation::AirdropNonFungibles {
+            resource_address,
+            claimants,
+            try_direct_send,
+        } => {
+            let ids = claimants.values().flatten().cloned().collect();
+            let claimants = claimants
+                .iter()
+                .map(|(claimant, ids)| {
+                    (*claimant, ResourceSpecifier::NonFungible(ids.clone()))
+                })
+                .collect::<IndexMap<_, _>>();
+            take_non_fungibles(builder, *resource_address, ids)
+                .call_method_with_name_lookup(
+                    locker_address,
+                    ACCOUNT_LOCKER_AIRDROP_IDENT,
+                    |lookup| {
+                        (claimants, lookup.bucket(bucket), *try_direct_send)
+                    },
+                )
+        }
+        AccountLockerOperation::Claim {
+            resource_address,
+            amount,
+        } => builder
+            .call_method(
+                locker_address,
+                ACCOUNT_LOCKER_CLAIM_IDENT,
+                (account_address, *resource_address, *amount),
+            )
+            .deposit_entire_worktop(account_address),
+        AccountLockerOperation::ClaimNonFungibles {
+            resource_address,
+            ids,
+        } => builder
+            .call_method(
+                locker_address,
+                ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT,
+                (account_address, *resource_address, ids.clone()),
+            )
+            .deposit_entire_worktop(account_address),
+    };
+    Ok(builder)
+}
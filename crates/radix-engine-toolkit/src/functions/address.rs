@@ -26,12 +26,93 @@ pub fn entity_type(node_id: TypedNodeId) -> EntityType {
 
 pub fn decode(node_id: &str) -> Option<(u8, EntityType, String, [u8; 30])> {
     let network_id = network_id_from_address_string(node_id)?;
-    let network_definition = network_definition_from_network_id(network_id);
-    let decoder = AddressBech32Decoder::new(&network_definition);
+    let coders = crate::caches::bech32_coders(network_id);
     let (hrp, _, _) =
         AddressBech32Decoder::validate_and_decode_ignore_hrp(node_id).ok()?;
-    let (entity_type, data) = decoder.validate_and_decode(node_id).ok()?;
+    let (entity_type, data) =
+        coders.address_decoder.validate_and_decode(node_id).ok()?;
     data.try_into()
         .map(|data| (network_id, entity_type, hrp, data))
         .ok()
 }
+
+/// Inspects the given address string, reporting its entity type and what it
+/// says about the entity alongside the information that [`decode`] reports.
+pub fn inspect(address: &str) -> Option<AddressInspection> {
+    let (network_id, entity_type, hrp, data) = decode(address)?;
+    let network_name = crate::caches::bech32_coders(network_id)
+        .network_definition
+        .logical_name
+        .clone();
+    Some(AddressInspection {
+        network_id,
+        network_name,
+        entity_type,
+        hrp,
+        data,
+        is_global: entity_type.is_global(),
+        is_internal: entity_type.is_internal(),
+        is_virtual: entity_type.is_global_virtual(),
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressInspection {
+    pub network_id: u8,
+    /// The logical name of the network, e.g., `mainnet` or `stokenet`.
+    pub network_name: String,
+    pub entity_type: EntityType,
+    pub hrp: String,
+    pub data: [u8; 30],
+    pub is_global: bool,
+    pub is_internal: bool,
+    /// Whether the address is of a virtual entity whose address is derived
+    /// from a public key and exists before being allocated on ledger.
+    pub is_virtual: bool,
+}
+
+/// Validates the given address string against the network that it's expected
+/// to be on and the entity types that are allowed, returning all of the ways
+/// in which the address fails validation. An empty list of expected entity
+/// types allows an address of any entity type. The address is valid if no
+/// failures are returned.
+pub fn validate(
+    address: &str,
+    expected_entity_types: &[EntityType],
+    expected_network_id: u8,
+) -> Vec<AddressValidationFailure> {
+    let Some((network_id, entity_type, ..)) = decode(address) else {
+        return vec![AddressValidationFailure::InvalidAddress];
+    };
+
+    let mut failures = vec![];
+    if network_id != expected_network_id {
+        failures.push(AddressValidationFailure::NetworkMismatch {
+            expected: expected_network_id,
+            actual: network_id,
+        })
+    }
+    if !expected_entity_types.is_empty()
+        && !expected_entity_types.contains(&entity_type)
+    {
+        failures.push(AddressValidationFailure::UnexpectedEntityType {
+            expected: expected_entity_types.to_vec(),
+            actual: entity_type,
+        })
+    }
+    failures
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressValidationFailure {
+    /// The address is not a valid Bech32m encoded address of any network.
+    InvalidAddress,
+    NetworkMismatch {
+        expected: u8,
+        actual: u8,
+    },
+    UnexpectedEntityType {
+        expected: Vec<EntityType>,
+        actual: EntityType,
+    },
+}
@@ -0,0 +1,294 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! An address book model that wallets can use to exchange address books with
+//! each other through a format that's validated by the toolkit. The exported
+//! form of an address book is its SBOR encoding prefixed by a checksum which
+//! is checked when the address book is imported.
+
+use radix_common::prelude::*;
+
+use crate::functions::address::decode;
+
+/// The version of the exported address book format.
+pub const ADDRESS_BOOK_FORMAT_VERSION: u8 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct AddressBook {
+    pub entries: Vec<AddressBookEntry>,
+    /// Controls whether the address book may contain addresses of more than
+    /// a single network. When `false` all of the entries must be of the same
+    /// network.
+    pub allow_mixed_networks: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct AddressBookEntry {
+    pub label: String,
+    /// The Bech32m encoded address.
+    pub address: String,
+    pub entity_type: EntityType,
+    pub network_id: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, ScryptoSbor)]
+struct ExportedAddressBook {
+    version: u8,
+    checksum: Hash,
+    address_book: AddressBook,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressBookMerge {
+    pub address_book: AddressBook,
+    /// The addresses found in both address books with different labels. The
+    /// merged address book keeps the label of the base address book.
+    pub conflicts: Vec<AddressBookLabelConflict>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressBookLabelConflict {
+    pub address: String,
+    pub base_label: String,
+    pub other_label: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AddressBookDiff {
+    /// The entries that are only in the new address book.
+    pub added: Vec<AddressBookEntry>,
+    /// The entries that are only in the old address book.
+    pub removed: Vec<AddressBookEntry>,
+    /// The addresses found in both address books with different labels.
+    pub relabeled: Vec<AddressBookLabelConflict>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressBookError {
+    InvalidAddress {
+        index: usize,
+        address: String,
+    },
+    EntityTypeMismatch {
+        index: usize,
+        declared: EntityType,
+        actual: EntityType,
+    },
+    NetworkMismatch {
+        index: usize,
+        declared: u8,
+        actual: u8,
+    },
+    MixedNetworks {
+        index: usize,
+        expected: u8,
+        actual: u8,
+    },
+    DuplicateAddress {
+        address: String,
+        first_index: usize,
+        duplicate_index: usize,
+    },
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+    UnsupportedVersion {
+        expected: u8,
+        actual: u8,
+    },
+    ChecksumMismatch {
+        expected: Hash,
+        actual: Hash,
+    },
+}
+
+/// Validates the address book. All of the addresses must be valid and match
+/// the entity type and network declared in their entry, no address may appear
+/// more than once, and the entries may not be of different networks unless
+/// the address book allows mixed networks.
+pub fn validate(address_book: &AddressBook) -> Result<(), AddressBookError> {
+    let mut seen_addresses = IndexMap::<&str, usize>::new();
+    let mut expected_network_id = None;
+
+    for (index, entry) in address_book.entries.iter().enumerate() {
+        let (network_id, entity_type, ..) =
+            decode(&entry.address).ok_or(AddressBookError::InvalidAddress {
+                index,
+                address: entry.address.clone(),
+            })?;
+        if entity_type != entry.entity_type {
+            return Err(AddressBookError::EntityTypeMismatch {
+                index,
+                declared: entry.entity_type,
+                actual: entity_type,
+            });
+        }
+        if network_id != entry.network_id {
+            return Err(AddressBookError::NetworkMismatch {
+                index,
+                declared: entry.network_id,
+                actual: network_id,
+            });
+        }
+
+        match expected_network_id {
+            Some(expected)
+                if expected != network_id
+                    && !address_book.allow_mixed_networks =>
+            {
+                return Err(AddressBookError::MixedNetworks {
+                    index,
+                    expected,
+                    actual: network_id,
+                });
+            }
+            Some(_) => {}
+            None => expected_network_id = Some(network_id),
+        }
+
+        if let Some(first_index) =
+            seen_addresses.insert(entry.address.as_str(), index)
+        {
+            return Err(AddressBookError::DuplicateAddress {
+                address: entry.address.clone(),
+                first_index,
+                duplicate_index: index,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates and exports the address book to its exchange format.
+pub fn export(address_book: &AddressBook) -> Result<Vec<u8>, AddressBookError> {
+    validate(address_book)?;
+    let checksum = checksum(address_book)?;
+    scrypto_encode(&ExportedAddressBook {
+        version: ADDRESS_BOOK_FORMAT_VERSION,
+        checksum,
+        address_book: address_book.clone(),
+    })
+    .map_err(AddressBookError::EncodeError)
+}
+
+/// Imports an address book from its exchange format verifying its checksum
+/// and validating it.
+pub fn import<T>(payload_bytes: T) -> Result<AddressBook, AddressBookError>
+where
+    T: AsRef<[u8]>,
+{
+    let ExportedAddressBook {
+        version,
+        checksum: expected_checksum,
+        address_book,
+    } = scrypto_decode::<ExportedAddressBook>(payload_bytes.as_ref())
+        .map_err(AddressBookError::DecodeError)?;
+
+    if version != ADDRESS_BOOK_FORMAT_VERSION {
+        return Err(AddressBookError::UnsupportedVersion {
+            expected: ADDRESS_BOOK_FORMAT_VERSION,
+            actual: version,
+        });
+    }
+
+    let actual_checksum = checksum(&address_book)?;
+    if actual_checksum != expected_checksum {
+        return Err(AddressBookError::ChecksumMismatch {
+            expected: expected_checksum,
+            actual: actual_checksum,
+        });
+    }
+
+    validate(&address_book)?;
+    Ok(address_book)
+}
+
+/// Merges the other address book into the base address book. Entries of the
+/// other address book whose addresses are not in the base are appended to it
+/// and label conflicts are resolved in favor of the base address book. The
+/// merged address book is validated before being returned.
+pub fn merge(
+    base: &AddressBook,
+    other: &AddressBook,
+) -> Result<AddressBookMerge, AddressBookError> {
+    let mut address_book = base.clone();
+    let mut conflicts = vec![];
+
+    for entry in other.entries.iter() {
+        match base
+            .entries
+            .iter()
+            .find(|base_entry| base_entry.address == entry.address)
+        {
+            Some(base_entry) if base_entry.label != entry.label => conflicts
+                .push(AddressBookLabelConflict {
+                    address: entry.address.clone(),
+                    base_label: base_entry.label.clone(),
+                    other_label: entry.label.clone(),
+                }),
+            Some(_) => {}
+            None => address_book.entries.push(entry.clone()),
+        }
+    }
+
+    validate(&address_book)?;
+    Ok(AddressBookMerge {
+        address_book,
+        conflicts,
+    })
+}
+
+/// Computes the difference between the old and the new address book.
+pub fn diff(old: &AddressBook, new: &AddressBook) -> AddressBookDiff {
+    let old_entries = old
+        .entries
+        .iter()
+        .map(|entry| (entry.address.as_str(), entry))
+        .collect::<IndexMap<_, _>>();
+    let new_entries = new
+        .entries
+        .iter()
+        .map(|entry| (entry.address.as_str(), entry))
+        .collect::<IndexMap<_, _>>();
+
+    let mut diff = AddressBookDiff::default();
+    for (address, new_entry) in new_entries.iter() {
+        match old_entries.get(address) {
+            Some(old_entry) if old_entry.label != new_entry.label => {
+                diff.relabeled.push(AddressBookLabelConflict {
+                    address: address.to_string(),
+                    base_label: old_entry.label.clone(),
+                    other_label: new_entry.label.clone(),
+                })
+            }
+            Some(_) => {}
+            None => diff.added.push((*new_entry).clone()),
+        }
+    }
+    diff.removed = old_entries
+        .iter()
+        .filter(|(address, _)| !new_entries.contains_key(*address))
+        .map(|(_, entry)| (*entry).clone())
+        .collect();
+    diff
+}
+
+fn checksum(address_book: &AddressBook) -> Result<Hash, AddressBookError> {
+    scrypto_encode(address_book)
+        .map(hash)
+        .map_err(AddressBookError::EncodeError)
+}
@@ -0,0 +1,423 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decompiles instructions into manifest strings that are annotated with
+//! comments to help humans review them. The comments name the well-known
+//! addresses, describe the methods of native blueprints that are called, and
+//! report where the named buckets and proofs come from. The annotations are
+//! written as `#` comments so the annotated manifest still compiles to the
+//! same instructions.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::identity::*;
+use radix_transactions::manifest::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::utils::*;
+
+/// Decompiles the instructions into a manifest string with annotations. Each
+/// instruction is preceded by comments that describe it and lines that
+/// contain well-known addresses end with a comment that names them.
+pub fn decompile_annotated(
+    instructions: &[InstructionV1],
+    network_definition: &NetworkDefinition,
+) -> Result<String, DecompileError> {
+    let manifest = decompile(instructions, network_definition)?;
+
+    // The decompiler ends every instruction with a semicolon on its own line
+    // and escapes the line breaks in string values, so the instructions can
+    // be found by splitting the manifest after the semicolons.
+    let decompiled_instructions =
+        manifest.split_inclusive(";\n").collect::<Vec<_>>();
+    if decompiled_instructions.len() != instructions.len() {
+        return Ok(manifest);
+    }
+
+    let bech32_encoder = AddressBech32Encoder::new(network_definition);
+    let bech32_decoder = AddressBech32Decoder::new(network_definition);
+    let mut annotator = InstructionAnnotator {
+        bech32_encoder: &bech32_encoder,
+        next_bucket_id: 0,
+        next_proof_id: 0,
+    };
+
+    let mut annotated = String::with_capacity(manifest.len());
+    for (instruction, decompiled_instruction) in
+        instructions.iter().zip(decompiled_instructions)
+    {
+        for comment in annotator.annotate(instruction) {
+            annotated.push_str(&format!("# {comment}\n"));
+        }
+        for line in decompiled_instruction.lines() {
+            annotated.push_str(line);
+            let names = well_known_names_in_line(line, &bech32_decoder);
+            if !names.is_empty() {
+                annotated.push_str(&format!(" # {}", names.join(", ")));
+            }
+            annotated.push('\n');
+        }
+    }
+
+    Ok(annotated)
+}
+
+struct InstructionAnnotator<'a> {
+    bech32_encoder: &'a AddressBech32Encoder,
+    next_bucket_id: u32,
+    next_proof_id: u32,
+}
+
+impl<'a> InstructionAnnotator<'a> {
+    /// Returns the comments that describe the instruction. The names of the
+    /// buckets and proofs match the names that the decompiler gives them.
+    fn annotate(&mut self, instruction: &InstructionV1) -> Vec<String> {
+        match instruction {
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => vec![format!(
+                "{}: {amount} of {} taken from the worktop",
+                self.new_bucket(),
+                self.resource(resource_address)
+            )],
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => vec![format!(
+                "{}: {} non-fungibles of {} taken from the worktop",
+                self.new_bucket(),
+                ids.len(),
+                self.resource(resource_address)
+            )],
+            InstructionV1::TakeAllFromWorktop { resource_address } => {
+                vec![format!(
+                    "{}: all of {} taken from the worktop",
+                    self.new_bucket(),
+                    self.resource(resource_address)
+                )]
+            }
+            InstructionV1::PopFromAuthZone => vec![format!(
+                "{}: the last proof popped from the auth zone",
+                self.new_proof()
+            )],
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                amount,
+            } => vec![format!(
+                "{}: proof of {amount} of {} from the auth zone",
+                self.new_proof(),
+                self.resource(resource_address)
+            )],
+            InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ids,
+            } => vec![format!(
+                "{}: proof of {} non-fungibles of {} from the auth zone",
+                self.new_proof(),
+                ids.len(),
+                self.resource(resource_address)
+            )],
+            InstructionV1::CreateProofFromAuthZoneOfAll {
+                resource_address,
+            } => {
+                vec![format!(
+                    "{}: proof of all of {} in the auth zone",
+                    self.new_proof(),
+                    self.resource(resource_address)
+                )]
+            }
+            InstructionV1::CreateProofFromBucketOfAmount {
+                bucket_id,
+                amount,
+            } => vec![format!(
+                "{}: proof of {amount} of the resources in {}",
+                self.new_proof(),
+                bucket_name(bucket_id)
+            )],
+            InstructionV1::CreateProofFromBucketOfNonFungibles {
+                bucket_id,
+                ids,
+            } => vec![format!(
+                "{}: proof of {} non-fungibles in {}",
+                self.new_proof(),
+                ids.len(),
+                bucket_name(bucket_id)
+            )],
+            InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
+                vec![format!(
+                    "{}: proof of all of the resources in {}",
+                    self.new_proof(),
+                    bucket_name(bucket_id)
+                )]
+            }
+            InstructionV1::CloneProof { proof_id } => vec![format!(
+                "{}: clone of {}",
+                self.new_proof(),
+                proof_name(proof_id)
+            )],
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            } => method_description(address, method_name)
+                .map(ToOwned::to_owned)
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    fn new_bucket(&mut self) -> String {
+        let bucket_id = ManifestBucket(self.next_bucket_id);
+        self.next_bucket_id += 1;
+        bucket_name(&bucket_id)
+    }
+
+    fn new_proof(&mut self) -> String {
+        let proof_id = ManifestProof(self.next_proof_id);
+        self.next_proof_id += 1;
+        proof_name(&proof_id)
+    }
+
+    /// The name of the resource if it's well-known and its address otherwise.
+    fn resource(&self, resource_address: &ResourceAddress) -> String {
+        well_known_address_name(resource_address.as_node_id())
+            .map(ToOwned::to_owned)
+            .or_else(|| {
+                self.bech32_encoder
+                    .encode(resource_address.as_node_id().as_bytes())
+                    .ok()
+            })
+            .unwrap_or_else(|| "an unknown resource".to_owned())
+    }
+}
+
+fn bucket_name(bucket_id: &ManifestBucket) -> String {
+    format!("bucket{}", bucket_id.0 + 1)
+}
+
+fn proof_name(proof_id: &ManifestProof) -> String {
+    format!("proof{}", proof_id.0 + 1)
+}
+
+/// Describes the methods of the native blueprints that are commonly called
+/// in manifests.
+fn method_description(
+    address: &DynamicGlobalAddress,
+    method_name: &str,
+) -> Option<&'static str> {
+    if is_account(address) {
+        let description = match method_name {
+            ACCOUNT_LOCK_FEE_IDENT => "Locks a fee from the XRD in the account",
+            ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                "Locks a contingent fee from the XRD in the account"
+            }
+            ACCOUNT_WITHDRAW_IDENT => {
+                "Withdraws an amount of a resource from the account"
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                "Withdraws non-fungibles from the account"
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                "Locks a fee and withdraws an amount of a resource from the \
+                 account"
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                "Locks a fee and withdraws non-fungibles from the account"
+            }
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                "Creates a proof of an amount of a resource in the account"
+            }
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                "Creates a proof of non-fungibles in the account"
+            }
+            ACCOUNT_DEPOSIT_IDENT => "Deposits a bucket into the account",
+            ACCOUNT_DEPOSIT_BATCH_IDENT => {
+                "Deposits the buckets into the account"
+            }
+            ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => {
+                "Deposits a bucket into the account or aborts the transaction \
+                 if the account does not accept it"
+            }
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => {
+                "Deposits the buckets into the account or aborts the \
+                 transaction if the account does not accept them"
+            }
+            ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT => {
+                "Deposits a bucket into the account or returns it if the \
+                 account does not accept it"
+            }
+            ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => {
+                "Deposits the buckets into the account or returns them if the \
+                 account does not accept them"
+            }
+            ACCOUNT_BURN_IDENT => {
+                "Burns an amount of a resource in the account"
+            }
+            ACCOUNT_BURN_NON_FUNGIBLES_IDENT => {
+                "Burns non-fungibles in the account"
+            }
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT => {
+                "Sets the default deposit rule of the account"
+            }
+            ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT => {
+                "Sets the deposit preference of the account for a resource"
+            }
+            ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT => {
+                "Removes the deposit preference of the account for a resource"
+            }
+            ACCOUNT_ADD_AUTHORIZED_DEPOSITOR => {
+                "Allows a badge holder to deposit into the account"
+            }
+            ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => {
+                "Stops allowing a badge holder to deposit into the account"
+            }
+            ACCOUNT_SECURIFY_IDENT => {
+                "Securifies the account and returns its owner badge"
+            }
+            _ => return None,
+        };
+        Some(description)
+    } else if is_validator(address) {
+        match method_name {
+            VALIDATOR_STAKE_IDENT => {
+                Some("Stakes XRD to the validator in exchange for stake units")
+            }
+            VALIDATOR_UNSTAKE_IDENT => Some(
+                "Unstakes stake units from the validator in exchange for a \
+                 claim NFT",
+            ),
+            VALIDATOR_CLAIM_XRD_IDENT => {
+                Some("Claims the XRD of a claim NFT from the validator")
+            }
+            _ => None,
+        }
+    } else if is_identity(address) {
+        match method_name {
+            IDENTITY_SECURIFY_IDENT => {
+                Some("Securifies the identity and returns its owner badge")
+            }
+            _ => None,
+        }
+    } else if matches!(
+        address,
+        DynamicGlobalAddress::Static(address)
+            if address.as_node_id() == FAUCET.as_node_id()
+    ) {
+        match method_name {
+            "free" => Some("Takes free XRD from the faucet"),
+            "lock_fee" => Some("Locks a fee from the XRD in the faucet"),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Returns the names of the well-known addresses in the line of a decompiled
+/// manifest.
+fn well_known_names_in_line(
+    line: &str,
+    bech32_decoder: &AddressBech32Decoder,
+) -> Vec<&'static str> {
+    line.split("Address(\"")
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .filter_map(|address| bech32_decoder.validate_and_decode(address).ok())
+        .filter_map(|(_, node_id)| {
+            NodeId::try_from(node_id.as_slice())
+                .ok()
+                .as_ref()
+                .and_then(well_known_address_name)
+        })
+        .collect()
+}
+
+/// The name of the well-known address or [`None`] if it's not well-known.
+pub fn well_known_address_name(node_id: &NodeId) -> Option<&'static str> {
+    [
+        (XRD.into_node_id(), "XRD"),
+        (
+            SECP256K1_SIGNATURE_VIRTUAL_BADGE.into_node_id(),
+            "Secp256k1 signature badge",
+        ),
+        (
+            ED25519_SIGNATURE_VIRTUAL_BADGE.into_node_id(),
+            "Ed25519 signature badge",
+        ),
+        (
+            PACKAGE_OF_DIRECT_CALLER_VIRTUAL_BADGE.into_node_id(),
+            "Package of direct caller badge",
+        ),
+        (
+            GLOBAL_CALLER_VIRTUAL_BADGE.into_node_id(),
+            "Global caller badge",
+        ),
+        (
+            SYSTEM_TRANSACTION_BADGE.into_node_id(),
+            "System transaction badge",
+        ),
+        (PACKAGE_OWNER_BADGE.into_node_id(), "Package owner badge"),
+        (
+            VALIDATOR_OWNER_BADGE.into_node_id(),
+            "Validator owner badge",
+        ),
+        (ACCOUNT_OWNER_BADGE.into_node_id(), "Account owner badge"),
+        (IDENTITY_OWNER_BADGE.into_node_id(), "Identity owner badge"),
+        (PACKAGE_PACKAGE.into_node_id(), "Package package"),
+        (RESOURCE_PACKAGE.into_node_id(), "Resource package"),
+        (ACCOUNT_PACKAGE.into_node_id(), "Account package"),
+        (IDENTITY_PACKAGE.into_node_id(), "Identity package"),
+        (
+            CONSENSUS_MANAGER_PACKAGE.into_node_id(),
+            "Consensus manager package",
+        ),
+        (
+            ACCESS_CONTROLLER_PACKAGE.into_node_id(),
+            "Access controller package",
+        ),
+        (POOL_PACKAGE.into_node_id(), "Pool package"),
+        (
+            TRANSACTION_PROCESSOR_PACKAGE.into_node_id(),
+            "Transaction processor package",
+        ),
+        (
+            METADATA_MODULE_PACKAGE.into_node_id(),
+            "Metadata module package",
+        ),
+        (
+            ROYALTY_MODULE_PACKAGE.into_node_id(),
+            "Royalty module package",
+        ),
+        (
+            ROLE_ASSIGNMENT_MODULE_PACKAGE.into_node_id(),
+            "Role assignment module package",
+        ),
+        (
+            GENESIS_HELPER_PACKAGE.into_node_id(),
+            "Genesis helper package",
+        ),
+        (FAUCET_PACKAGE.into_node_id(), "Faucet package"),
+        (CONSENSUS_MANAGER.into_node_id(), "Consensus manager"),
+        (GENESIS_HELPER.into_node_id(), "Genesis helper"),
+        (FAUCET.into_node_id(), "Faucet"),
+    ]
+    .into_iter()
+    .find(|(well_known_node_id, _)| well_known_node_id == node_id)
+    .map(|(_, name)| name)
+}
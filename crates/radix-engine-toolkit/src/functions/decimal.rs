@@ -0,0 +1,246 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Formats decimals for display and parses decimals that were entered by
+//! users with the separators of their locale. Amounts are rounded to the
+//! number of fraction digits that's shown with the rounding mode of the
+//! caller, which is [`RoundingMode::ToZero`] when amounts must be truncated
+//! rather than rounded up, such as the balance that's available to spend.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use scrypto::prelude::*;
+
+/// The separators that are used to write numbers in a locale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecimalLocaleOptions {
+    /// The separator between the integer and fractional parts.
+    pub decimal_separator: String,
+    /// The separator between groups of three digits of the integer part or
+    /// [`None`] if the digits are not grouped.
+    pub thousands_separator: Option<String>,
+}
+
+impl Default for DecimalLocaleOptions {
+    fn default() -> Self {
+        Self {
+            decimal_separator: ".".to_owned(),
+            thousands_separator: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecimalFormatOptions {
+    pub locale: DecimalLocaleOptions,
+    /// The maximum number of digits of the fractional part or [`None`] if
+    /// values are written in full. Trailing zeros are never written.
+    pub max_fraction_digits: Option<u8>,
+    /// How values with more fraction digits than the maximum are rounded.
+    pub rounding_mode: RoundingMode,
+}
+
+impl Default for DecimalFormatOptions {
+    fn default() -> Self {
+        Self {
+            locale: Default::default(),
+            max_fraction_digits: None,
+            rounding_mode: RoundingMode::ToNearestMidpointAwayFromZero,
+        }
+    }
+}
+
+pub fn format_decimal(
+    value: &Decimal,
+    options: &DecimalFormatOptions,
+) -> Result<String, DecimalFormatError> {
+    format(*value, options)
+}
+
+pub fn format_precise_decimal(
+    value: &PreciseDecimal,
+    options: &DecimalFormatOptions,
+) -> Result<String, DecimalFormatError> {
+    format(*value, options)
+}
+
+pub fn parse_decimal(
+    string: &str,
+    locale: &DecimalLocaleOptions,
+) -> Result<Decimal, DecimalFormatError> {
+    parse(string, locale)
+}
+
+pub fn parse_precise_decimal(
+    string: &str,
+    locale: &DecimalLocaleOptions,
+) -> Result<PreciseDecimal, DecimalFormatError> {
+    parse(string, locale)
+}
+
+trait DecimalType: Copy + Display + FromStr {
+    const SCALE: u32;
+
+    fn checked_round(
+        self,
+        decimal_places: i32,
+        mode: RoundingMode,
+    ) -> Option<Self>;
+}
+
+impl DecimalType for Decimal {
+    const SCALE: u32 = Decimal::SCALE;
+
+    fn checked_round(
+        self,
+        decimal_places: i32,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        Decimal::checked_round(&self, decimal_places, mode)
+    }
+}
+
+impl DecimalType for PreciseDecimal {
+    const SCALE: u32 = PreciseDecimal::SCALE;
+
+    fn checked_round(
+        self,
+        decimal_places: i32,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        PreciseDecimal::checked_round(&self, decimal_places, mode)
+    }
+}
+
+fn format<D: DecimalType>(
+    value: D,
+    options: &DecimalFormatOptions,
+) -> Result<String, DecimalFormatError> {
+    let value = match options.max_fraction_digits {
+        Some(max_fraction_digits)
+            if (max_fraction_digits as u32) < D::SCALE =>
+        {
+            value
+                .checked_round(
+                    max_fraction_digits as i32,
+                    options.rounding_mode,
+                )
+                .ok_or(DecimalFormatError::RoundingOverflow)?
+        }
+        _ => value,
+    }
+    .to_string();
+
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", value.as_str()),
+    };
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits, None),
+    };
+
+    let mut formatted = sign.to_owned();
+    for (index, digit) in integer.chars().enumerate() {
+        if index != 0 && (integer.len() - index) % 3 == 0 {
+            if let Some(separator) = &options.locale.thousands_separator {
+                formatted.push_str(separator);
+            }
+        }
+        formatted.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        formatted.push_str(&options.locale.decimal_separator);
+        formatted.push_str(fraction);
+    }
+    Ok(formatted)
+}
+
+fn parse<D: DecimalType>(
+    string: &str,
+    locale: &DecimalLocaleOptions,
+) -> Result<D, DecimalFormatError> {
+    let invalid_number =
+        || DecimalFormatError::InvalidNumber(string.to_owned());
+
+    let trimmed = string.trim();
+    let (sign, digits) = match trimmed.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", trimmed),
+    };
+    let (integer, fraction) =
+        match digits.split_once(locale.decimal_separator.as_str()) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+
+    let integer = match &locale.thousands_separator {
+        Some(separator) if integer.contains(separator.as_str()) => {
+            let groups = integer.split(separator.as_str()).collect::<Vec<_>>();
+            let is_grouped_correctly =
+                groups.iter().enumerate().all(|(index, group)| match index {
+                    0 => (1..=3).contains(&group.len()),
+                    _ => group.len() == 3,
+                });
+            if !is_grouped_correctly {
+                return Err(DecimalFormatError::InvalidGrouping(
+                    string.to_owned(),
+                ));
+            }
+            groups.concat()
+        }
+        _ => integer.to_owned(),
+    };
+    let is_digits = |digits: &str| {
+        !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit())
+    };
+    if !is_digits(&integer)
+        || fraction.is_some_and(|fraction| !is_digits(fraction))
+    {
+        return Err(invalid_number());
+    }
+
+    let fraction_digits = fraction.map_or(0, str::len);
+    if fraction_digits > D::SCALE as usize {
+        return Err(DecimalFormatError::TooManyFractionDigits {
+            digits: fraction_digits,
+            max_digits: D::SCALE as usize,
+        });
+    }
+
+    let normalized = match fraction {
+        Some(fraction) => format!("{sign}{integer}.{fraction}"),
+        None => format!("{sign}{integer}"),
+    };
+    D::from_str(&normalized).map_err(|_| invalid_number())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecimalFormatError {
+    /// The value is too large to be rounded to the number of fraction digits.
+    RoundingOverflow,
+    /// The string isn't a number in the locale or the number is out of the
+    /// range of the type.
+    InvalidNumber(String),
+    /// The thousands separators of the string don't separate groups of
+    /// three digits.
+    InvalidGrouping(String),
+    /// The number has more fraction digits than the type can hold, i.e.,
+    /// more than 18 for decimals and more than 36 for precise decimals.
+    TooManyFractionDigits { digits: usize, max_digits: usize },
+}
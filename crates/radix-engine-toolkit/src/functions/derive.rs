@@ -47,6 +47,19 @@ where
     NonFungibleGlobalId::from_public_key(public_key)
 }
 
+/// Derives the global id of the owner badge that the engine mints when the
+/// validator is created. The local id of the badge is the bytes of the node id
+/// of the validator.
+pub fn validator_owner_badge_from_validator_address(
+    validator_address: &ComponentAddress,
+) -> NonFungibleGlobalId {
+    NonFungibleGlobalId::new(
+        VALIDATOR_OWNER_BADGE,
+        NonFungibleLocalId::bytes(validator_address.as_node_id().0.to_vec())
+            .expect("Node ids are shorter than the maximum local id length"),
+    )
+}
+
 pub fn virtual_account_address_from_olympia_account_address<S>(
     olympia_account_address: S,
 ) -> Result<ComponentAddress, DerivationError>
@@ -79,7 +92,9 @@ where
     let data = Vec::<u8>::from_base32(&data)
         .map_err(DerivationError::Bech32BaseConversionError)?;
 
-    // Check the length of the data to ensure that it's valid.
+    // Check the prefix and length of the data to ensure that it's valid. The
+    // native token of Olympia has a prefix of 0x01 and no other data while all
+    // other tokens have a prefix of 0x03 followed by 26 bytes.
     let prefix = data.first();
     let length = data.len();
 
@@ -94,10 +109,20 @@ where
 
             Ok(ResourceAddress::new_or_panic(bytes))
         }
-        _ => Err(DerivationError::InvalidOlympiaAddressLength {
-            expected: 27,
+        (Some(0x01), _) => Err(DerivationError::InvalidOlympiaAddressLength {
+            expected: 1,
             actual: length,
         }),
+        (Some(0x03), _) | (None, _) => {
+            Err(DerivationError::InvalidOlympiaAddressLength {
+                expected: 27,
+                actual: length,
+            })
+        }
+        (Some(prefix), _) => Err(DerivationError::InvalidOlympiaAddressPrefix {
+            expected: 0x03,
+            actual: *prefix,
+        }),
     }
 }
 
@@ -172,6 +197,29 @@ where
     }
 }
 
+/// Validates an Olympia account address and extracts the network that it
+/// belongs to and the public key that it encodes.
+pub fn olympia_account_address_details<S>(
+    olympia_account_address: S,
+) -> Result<OlympiaAccountAddressDetails, DerivationError>
+where
+    S: AsRef<str>,
+{
+    let olympia_account_address = olympia_account_address.as_ref();
+    let public_key =
+        public_key_from_olympia_account_address(olympia_account_address)?;
+
+    let (hrp, ..) = bech32::decode(olympia_account_address)
+        .map_err(DerivationError::Bech32DecodeError)?;
+    let olympia_network = OlympiaNetwork::from_hrp(&hrp)
+        .ok_or(DerivationError::UnknownOlympiaNetworkHrp(hrp))?;
+
+    Ok(OlympiaAccountAddressDetails {
+        olympia_network,
+        public_key,
+    })
+}
+
 pub fn olympia_account_address_from_public_key(
     public_key: &Secp256k1PublicKey,
     olympia_network: OlympiaNetwork,
@@ -203,6 +251,13 @@ pub fn node_address_from_public_key(
         .expect("Should not panic since all data is trusted.")
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OlympiaAccountAddressDetails {
+    pub olympia_network: OlympiaNetwork,
+    pub public_key: Secp256k1PublicKey,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum OlympiaNetwork {
     Mainnet,
     Stokenet,
@@ -227,6 +282,21 @@ impl OlympiaNetwork {
             Self::Localnet => "ddx",
         }
     }
+
+    pub fn from_hrp(hrp: &str) -> Option<Self> {
+        [
+            Self::Mainnet,
+            Self::Stokenet,
+            Self::Releasenet,
+            Self::RCNet,
+            Self::Milestonenet,
+            Self::Devopsnet,
+            Self::Sandpitnet,
+            Self::Localnet,
+        ]
+        .into_iter()
+        .find(|network| network.hrp() == hrp)
+    }
 }
 
 #[derive(Debug)]
@@ -247,6 +317,7 @@ pub enum DerivationError {
         expected: u8,
         actual: u8,
     },
+    UnknownOlympiaNetworkHrp(String),
     Bech32DecodeError(bech32::Error),
     Bech32BaseConversionError(bech32::Error),
 }
@@ -0,0 +1,370 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Computes structured diffs between two manifests. Instructions are matched
+//! through their longest common subsequence, which is found in linear space,
+//! and the unmatched instructions of the same kind that take the same
+//! position in both manifests are reported as modified with a diff of their
+//! values.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::functions::instructions::instruction_kind;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestDiff {
+    pub instructions: Vec<InstructionDiff>,
+    pub added_blobs: Vec<Hash>,
+    pub removed_blobs: Vec<Hash>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstructionDiff {
+    Unchanged {
+        old_index: usize,
+        new_index: usize,
+    },
+    Added {
+        new_index: usize,
+        instruction: InstructionV1,
+    },
+    Removed {
+        old_index: usize,
+        instruction: InstructionV1,
+    },
+    Modified {
+        old_index: usize,
+        new_index: usize,
+        old_instruction: InstructionV1,
+        new_instruction: InstructionV1,
+        value_diffs: Vec<ValueDiff>,
+    },
+}
+
+/// A difference between two values found at the given path. The path is made
+/// up of the indices of the fields or elements that lead to the value from
+/// the root of the instruction, e.g., the path of the first argument of a
+/// `CallMethod` instruction is `[2, 0]` as the arguments are the third field
+/// of the instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValueDiff {
+    pub path: Vec<usize>,
+    pub old_value: Option<ManifestValue>,
+    pub new_value: Option<ManifestValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifestDiffError {
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+}
+
+pub fn diff_manifests(
+    old: &TransactionManifestV1,
+    new: &TransactionManifestV1,
+) -> Result<ManifestDiff, ManifestDiffError> {
+    Ok(ManifestDiff {
+        instructions: diff_instructions(&old.instructions, &new.instructions)?,
+        added_blobs: new
+            .blobs
+            .keys()
+            .filter(|hash| !old.blobs.contains_key(*hash))
+            .cloned()
+            .collect(),
+        removed_blobs: old
+            .blobs
+            .keys()
+            .filter(|hash| !new.blobs.contains_key(*hash))
+            .cloned()
+            .collect(),
+    })
+}
+
+pub fn diff_instructions(
+    old: &[InstructionV1],
+    new: &[InstructionV1],
+) -> Result<Vec<InstructionDiff>, ManifestDiffError> {
+    let mut diffs = Vec::new();
+    let (mut old_index, mut new_index) = (0, 0);
+    for (old_common_index, new_common_index) in common_subsequence(old, new)
+        .into_iter()
+        .chain([(old.len(), new.len())])
+    {
+        flush_changes(
+            old,
+            new,
+            old_index..old_common_index,
+            new_index..new_common_index,
+            &mut diffs,
+        )?;
+        if old_common_index < old.len() {
+            diffs.push(InstructionDiff::Unchanged {
+                old_index: old_common_index,
+                new_index: new_common_index,
+            });
+        }
+        old_index = old_common_index + 1;
+        new_index = new_common_index + 1;
+    }
+
+    Ok(diffs)
+}
+
+/// Finds the pairs of indices of the elements of the longest common
+/// subsequence of both lists. Hirschberg's algorithm is used so that only
+/// linear space is needed regardless of the lengths of the lists.
+fn common_subsequence<T: PartialEq>(
+    old: &[T],
+    new: &[T],
+) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    push_common_subsequence(old, new, 0, 0, &mut pairs);
+    pairs
+}
+
+fn push_common_subsequence<T: PartialEq>(
+    old: &[T],
+    new: &[T],
+    old_offset: usize,
+    new_offset: usize,
+    pairs: &mut Vec<(usize, usize)>,
+) {
+    // The common prefix and suffix are matched directly which is the common
+    // case of manifests that differ in a few instructions.
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    pairs.extend(
+        (0..prefix).map(|index| (old_offset + index, new_offset + index)),
+    );
+
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+    match old_middle {
+        [] => {}
+        [element] => {
+            if let Some(new_index) =
+                new_middle.iter().position(|new| new == element)
+            {
+                pairs.push((
+                    old_offset + prefix,
+                    new_offset + prefix + new_index,
+                ))
+            }
+        }
+        _ if new_middle.is_empty() => {}
+        _ => {
+            // The old instructions are split in half and the new ones are
+            // split where the longest common subsequences of the halves add
+            // up to the longest one.
+            let middle = old_middle.len() / 2;
+            let forward =
+                lcs_lengths(old_middle[..middle].iter(), new_middle.iter());
+            let backward = lcs_lengths(
+                old_middle[middle..].iter().rev(),
+                new_middle.iter().rev(),
+            );
+            let split = (0..=new_middle.len())
+                .max_by_key(|index| {
+                    forward[*index] + backward[new_middle.len() - index]
+                })
+                .unwrap_or_default();
+            push_common_subsequence(
+                &old_middle[..middle],
+                &new_middle[..split],
+                old_offset + prefix,
+                new_offset + prefix,
+                pairs,
+            );
+            push_common_subsequence(
+                &old_middle[middle..],
+                &new_middle[split..],
+                old_offset + prefix + middle,
+                new_offset + prefix + split,
+                pairs,
+            );
+        }
+    }
+
+    let old_suffix_start = old_offset + old.len() - suffix;
+    let new_suffix_start = new_offset + new.len() - suffix;
+    pairs.extend(
+        (0..suffix)
+            .map(|index| (old_suffix_start + index, new_suffix_start + index)),
+    );
+}
+
+/// The lengths of the longest common subsequences of all of `old` and each
+/// prefix of `new`, computed one row at a time.
+fn lcs_lengths<'a, T: PartialEq + 'a>(
+    old: impl Iterator<Item = &'a T>,
+    new: impl Iterator<Item = &'a T> + Clone,
+) -> Vec<usize> {
+    let mut lengths = vec![0usize; new.clone().count() + 1];
+    for old in old {
+        let mut diagonal = 0;
+        for (index, new) in new.clone().enumerate() {
+            let above = lengths[index + 1];
+            lengths[index + 1] = if old == new {
+                diagonal + 1
+            } else {
+                above.max(lengths[index])
+            };
+            diagonal = above;
+        }
+    }
+    lengths
+}
+
+/// Reports a run of removed and added instructions found between two
+/// unchanged instructions. The removed and added instructions are paired up
+/// in order and the pairs of the same instruction kind are reported as
+/// modified.
+fn flush_changes(
+    old: &[InstructionV1],
+    new: &[InstructionV1],
+    removed: std::ops::Range<usize>,
+    added: std::ops::Range<usize>,
+    diffs: &mut Vec<InstructionDiff>,
+) -> Result<(), ManifestDiffError> {
+    let mut removed_iter = removed.peekable();
+    let mut added_iter = added.peekable();
+    loop {
+        match (removed_iter.peek().copied(), added_iter.peek().copied()) {
+            (Some(old_index), Some(new_index))
+                if instruction_kind(&old[old_index])
+                    == instruction_kind(&new[new_index]) =>
+            {
+                removed_iter.next();
+                added_iter.next();
+                diffs.push(InstructionDiff::Modified {
+                    old_index,
+                    new_index,
+                    old_instruction: old[old_index].clone(),
+                    new_instruction: new[new_index].clone(),
+                    value_diffs: diff_values(
+                        &to_manifest_value(&old[old_index])?,
+                        &to_manifest_value(&new[new_index])?,
+                    ),
+                })
+            }
+            (Some(old_index), _) => {
+                removed_iter.next();
+                diffs.push(InstructionDiff::Removed {
+                    old_index,
+                    instruction: old[old_index].clone(),
+                })
+            }
+            (None, Some(new_index)) => {
+                added_iter.next();
+                diffs.push(InstructionDiff::Added {
+                    new_index,
+                    instruction: new[new_index].clone(),
+                })
+            }
+            (None, None) => break,
+        }
+    }
+    Ok(())
+}
+
+/// Computes the differences between two values. Values of the same kind and
+/// shape are compared field by field and any other values are reported as a
+/// single difference.
+pub fn diff_values(old: &ManifestValue, new: &ManifestValue) -> Vec<ValueDiff> {
+    let mut diffs = Vec::new();
+    diff_values_at_path(&mut vec![], Some(old), Some(new), &mut diffs);
+    diffs
+}
+
+fn diff_values_at_path(
+    path: &mut Vec<usize>,
+    old: Option<&ManifestValue>,
+    new: Option<&ManifestValue>,
+    diffs: &mut Vec<ValueDiff>,
+) {
+    if old == new {
+        return;
+    }
+
+    let children = match (old, new) {
+        (
+            Some(ManifestValue::Tuple { fields: old_fields }),
+            Some(ManifestValue::Tuple { fields: new_fields }),
+        ) => Some((old_fields, new_fields)),
+        (
+            Some(ManifestValue::Enum {
+                discriminator: old_discriminator,
+                fields: old_fields,
+            }),
+            Some(ManifestValue::Enum {
+                discriminator: new_discriminator,
+                fields: new_fields,
+            }),
+        ) if old_discriminator == new_discriminator => {
+            Some((old_fields, new_fields))
+        }
+        (
+            Some(ManifestValue::Array {
+                element_value_kind: old_kind,
+                elements: old_elements,
+            }),
+            Some(ManifestValue::Array {
+                element_value_kind: new_kind,
+                elements: new_elements,
+            }),
+        ) if old_kind == new_kind => Some((old_elements, new_elements)),
+        _ => None,
+    };
+
+    match children {
+        Some((old_children, new_children)) => {
+            for index in 0..old_children.len().max(new_children.len()) {
+                path.push(index);
+                diff_values_at_path(
+                    path,
+                    old_children.get(index),
+                    new_children.get(index),
+                    diffs,
+                );
+                path.pop();
+            }
+        }
+        None => diffs.push(ValueDiff {
+            path: path.clone(),
+            old_value: old.cloned(),
+            new_value: new.cloned(),
+        }),
+    }
+}
+
+fn to_manifest_value(
+    instruction: &InstructionV1,
+) -> Result<ManifestValue, ManifestDiffError> {
+    let encoded =
+        manifest_encode(instruction).map_err(ManifestDiffError::EncodeError)?;
+    manifest_decode(&encoded).map_err(ManifestDiffError::DecodeError)
+}
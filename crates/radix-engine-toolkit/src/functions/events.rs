@@ -16,8 +16,26 @@
 // under the License.
 
 use radix_common::prelude::*;
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::prelude::*;
 use radix_substate_store_queries::typed_native_events::*;
+use sbor::{LocalTypeId, Schema};
+use scrypto::radix_blueprint_schema_init::*;
+
+use crate::functions::instruction_arguments::main_module_blueprint_id;
+use crate::functions::package::native_package_definitions;
+
+/// The schema of a blueprint along with the local type ids of its events in
+/// the schema keyed by the name of the event.
+type BlueprintEventSchemas =
+    (Schema<ScryptoCustomSchema>, IndexMap<String, LocalTypeId>);
+type NativeEventSchemas = IndexMap<BlueprintId, BlueprintEventSchemas>;
+
+lazy_static::lazy_static! {
+    static ref NATIVE_EVENT_SCHEMAS: NativeEventSchemas =
+        native_event_schemas();
+}
 
 /// Attempts to decode the passed in event into a structured
 /// [`TypedNativeEvent`] if the event is emitted from a native blueprint of a
@@ -28,3 +46,72 @@ pub fn sbor_decode_to_native_event(
 ) -> Result<TypedNativeEvent, TypedNativeEventError> {
     to_typed_native_event(event_type_identifier, event_data)
 }
+
+/// Returns the schema of the payload of the event and the local type id of
+/// the event in it if the event is emitted from a native blueprint. Events of
+/// other blueprints, and method events of components whose blueprint can't be
+/// determined from their address alone, have no native schema.
+pub fn native_event_schema(
+    event_type_identifier: &EventTypeIdentifier,
+) -> Option<(LocalTypeId, Schema<ScryptoCustomSchema>)> {
+    let EventTypeIdentifier(emitter, event_name) = event_type_identifier;
+    let (schema, events) =
+        NATIVE_EVENT_SCHEMAS.get(&emitter_blueprint_id(emitter)?)?;
+    events
+        .get(event_name)
+        .map(|local_type_id| (*local_type_id, schema.clone()))
+}
+
+fn emitter_blueprint_id(emitter: &Emitter) -> Option<BlueprintId> {
+    match emitter {
+        Emitter::Function(blueprint_id) => Some(blueprint_id.clone()),
+        Emitter::Method(node_id, ObjectModuleId::Main) => {
+            main_module_blueprint_id(node_id.entity_type()?)
+        }
+        Emitter::Method(_, ObjectModuleId::Metadata) => Some(BlueprintId::new(
+            &METADATA_MODULE_PACKAGE,
+            METADATA_BLUEPRINT,
+        )),
+        Emitter::Method(_, ObjectModuleId::Royalty) => Some(BlueprintId::new(
+            &ROYALTY_MODULE_PACKAGE,
+            COMPONENT_ROYALTY_BLUEPRINT,
+        )),
+        Emitter::Method(_, ObjectModuleId::RoleAssignment) => {
+            Some(BlueprintId::new(
+                &ROLE_ASSIGNMENT_MODULE_PACKAGE,
+                ROLE_ASSIGNMENT_BLUEPRINT,
+            ))
+        }
+    }
+}
+
+fn native_event_schemas() -> NativeEventSchemas {
+    native_package_definitions()
+        .into_iter()
+        .flat_map(|(package_address, package_definition)| {
+            package_definition.blueprints.iter().map(
+                move |(blueprint_name, blueprint_definition)| {
+                    let events = blueprint_definition
+                        .schema
+                        .events
+                        .event_schema
+                        .iter()
+                        .filter_map(|(event_name, type_ref)| match type_ref {
+                            TypeRef::Static(local_type_id) => {
+                                Some((event_name.clone(), *local_type_id))
+                            }
+                            TypeRef::Generic(..) => None,
+                        })
+                        .collect();
+                    (
+                        BlueprintId::new(&package_address, blueprint_name),
+                        (
+                            blueprint_definition.schema.schema.v1().clone(),
+                            events,
+                        ),
+                    )
+                },
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,318 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Explains transaction validation failures and rejections in terms of which
+//! part of the transaction caused them, what typically causes them, and how
+//! they can be fixed.
+
+use radix_transactions::errors::*;
+
+/// The part of the transaction that a validation failure was caused by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationFailureLocation {
+    Header { field: &'static str },
+    Signatures,
+    Manifest { detail: Option<String> },
+    Message,
+    Payload,
+    Ledger,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationFailureExplanation {
+    /// The name of the error that was recognized or [`None`] if the error was
+    /// not recognized.
+    pub error: Option<&'static str>,
+    pub location: Option<ValidationFailureLocation>,
+    pub causes: Vec<&'static str>,
+    pub fixes: Vec<&'static str>,
+}
+
+/// Explains a typed transaction validation error.
+pub fn explain_validation_error(
+    error: &TransactionValidationError,
+) -> ValidationFailureExplanation {
+    explain_validation_failure(&format!("{error:?}"))
+}
+
+/// Explains a validation failure from its string representation. This could
+/// either be the debug string of a [`TransactionValidationError`] or the
+/// rejection reason reported by the node. The most specific known error that
+/// is mentioned in the string is explained.
+pub fn explain_validation_failure(error: &str) -> ValidationFailureExplanation {
+    KNOWN_FAILURES
+        .iter()
+        .find(|failure| error.contains(failure.error))
+        .map(|failure| ValidationFailureExplanation {
+            error: Some(failure.error),
+            location: Some(failure.location.to_location(error, failure.error)),
+            causes: failure.causes.to_vec(),
+            fixes: failure.fixes.to_vec(),
+        })
+        .unwrap_or(ValidationFailureExplanation {
+            error: None,
+            location: None,
+            causes: vec![],
+            fixes: vec![],
+        })
+}
+
+enum KnownLocation {
+    Header(&'static str),
+    Signatures,
+    Manifest,
+    Message,
+    Payload,
+    Ledger,
+}
+
+impl KnownLocation {
+    fn to_location(
+        &self,
+        error: &str,
+        error_name: &str,
+    ) -> ValidationFailureLocation {
+        match self {
+            Self::Header(field) => {
+                ValidationFailureLocation::Header { field: *field }
+            }
+            Self::Signatures => ValidationFailureLocation::Signatures,
+            Self::Manifest => ValidationFailureLocation::Manifest {
+                detail: error_detail(error, error_name),
+            },
+            Self::Message => ValidationFailureLocation::Message,
+            Self::Payload => ValidationFailureLocation::Payload,
+            Self::Ledger => ValidationFailureLocation::Ledger,
+        }
+    }
+}
+
+/// Extracts the contents of the parenthesis that follow the error name, e.g.,
+/// `ManifestBucket(1)` from `BucketNotFound(ManifestBucket(1))`.
+fn error_detail(error: &str, error_name: &str) -> Option<String> {
+    let rest = &error[error.find(error_name)? + error_name.len()..];
+    let rest = rest.strip_prefix('(')?;
+    let mut depth = 1usize;
+    for (index, character) in rest.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(rest[..index].to_owned());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+struct KnownFailure {
+    error: &'static str,
+    location: KnownLocation,
+    causes: &'static [&'static str],
+    fixes: &'static [&'static str],
+}
+
+/// The known failures ordered from the most to the least specific, the first
+/// failure whose name is found in the error string is the one explained.
+const KNOWN_FAILURES: &[KnownFailure] = &[
+    // Header validation errors.
+    KnownFailure {
+        error: "UnknownVersion",
+        location: KnownLocation::Payload,
+        causes: &["The transaction was built with an unsupported version of the transaction model."],
+        fixes: &["Build the transaction with a version of the toolkit that matches the network."],
+    },
+    KnownFailure {
+        error: "InvalidEpochRange",
+        location: KnownLocation::Header("end_epoch_exclusive"),
+        causes: &["The end epoch of the header is not after its start epoch."],
+        fixes: &["Set the end epoch to be strictly greater than the start epoch."],
+    },
+    KnownFailure {
+        error: "EpochRangeTooLarge",
+        location: KnownLocation::Header("end_epoch_exclusive"),
+        causes: &["The epoch window of the header is larger than what the network permits."],
+        fixes: &["Reduce the epoch window; a window of a few epochs starting at the current epoch is typical."],
+    },
+    KnownFailure {
+        error: "InvalidNetwork",
+        location: KnownLocation::Header("network_id"),
+        causes: &[
+            "The network id of the header does not match the network the transaction was submitted to.",
+            "The addresses in the manifest were encoded for a different network.",
+        ],
+        fixes: &["Use the network id of the network the transaction is submitted to in the header and in all of the addresses."],
+    },
+    KnownFailure {
+        error: "InvalidTipPercentage",
+        location: KnownLocation::Header("tip_percentage"),
+        causes: &["The tip percentage is larger than the maximum permitted by the network."],
+        fixes: &["Lower the tip percentage, a tip of zero is typical."],
+    },
+    KnownFailure {
+        error: "InvalidCostUnitLimit",
+        location: KnownLocation::Header("cost_unit_limit"),
+        causes: &["The cost unit limit is outside of the range permitted by the network."],
+        fixes: &["Use a cost unit limit within the range permitted by the network."],
+    },
+    // Signature validation errors.
+    KnownFailure {
+        error: "TooManySignatures",
+        location: KnownLocation::Signatures,
+        causes: &["The transaction has more intent signatures than the network permits."],
+        fixes: &["Reduce the number of signers, e.g., by using access controllers or multi-factor badges."],
+    },
+    KnownFailure {
+        error: "InvalidIntentSignature",
+        location: KnownLocation::Signatures,
+        causes: &[
+            "An intent signature was not produced over the hash of this intent.",
+            "The intent was modified after it was signed.",
+            "The public key does not match the key that produced the signature.",
+        ],
+        fixes: &["Sign the intent hash of the final intent and don't modify the intent after signing it."],
+    },
+    KnownFailure {
+        error: "InvalidNotarySignature",
+        location: KnownLocation::Signatures,
+        causes: &[
+            "The notary signature was not produced over the hash of the signed intent.",
+            "The notary public key in the header does not match the key used to notarize.",
+        ],
+        fixes: &["Notarize the signed intent hash with the key of the notary public key in the header after all intent signatures are added."],
+    },
+    KnownFailure {
+        error: "DuplicateSigner",
+        location: KnownLocation::Signatures,
+        causes: &[
+            "The same key signed the intent more than once.",
+            "The notary also signed the intent as a signatory.",
+        ],
+        fixes: &["Remove the duplicate signatures; use notary_is_signatory rather than adding a notary intent signature."],
+    },
+    // Manifest id validation errors.
+    KnownFailure {
+        error: "BucketNotFound",
+        location: KnownLocation::Manifest,
+        causes: &["An instruction uses a bucket that was never created or has already been consumed."],
+        fixes: &["Make sure every bucket is created before it is used and is used at most once."],
+    },
+    KnownFailure {
+        error: "ProofNotFound",
+        location: KnownLocation::Manifest,
+        causes: &["An instruction uses a proof that was never created or has already been dropped."],
+        fixes: &["Make sure every proof is created before it is used and is not used after it's dropped."],
+    },
+    KnownFailure {
+        error: "BucketLocked",
+        location: KnownLocation::Manifest,
+        causes: &["A bucket is consumed while there are proofs created from it."],
+        fixes: &["Drop the proofs created from the bucket before consuming the bucket."],
+    },
+    KnownFailure {
+        error: "AddressReservationNotFound",
+        location: KnownLocation::Manifest,
+        causes: &["An address reservation is used that was never allocated or has already been consumed."],
+        fixes: &["Allocate the address reservation with ALLOCATE_GLOBAL_ADDRESS and use it only once."],
+    },
+    KnownFailure {
+        error: "AddressNotFound",
+        location: KnownLocation::Manifest,
+        causes: &["A named address is used that was never allocated."],
+        fixes: &["Allocate the named address with ALLOCATE_GLOBAL_ADDRESS before using it."],
+    },
+    KnownFailure {
+        error: "OutOfID",
+        location: KnownLocation::Manifest,
+        causes: &["The manifest allocates more buckets, proofs, or address reservations than can be identified."],
+        fixes: &["Split the manifest into multiple transactions."],
+    },
+    KnownFailure {
+        error: "CallDataValidationError",
+        location: KnownLocation::Manifest,
+        causes: &["The arguments of an invocation are not valid manifest SBOR or reference invalid buckets, proofs, or reservations."],
+        fixes: &["Check the arguments of the invocations; building them with the manifest builder avoids most of these issues."],
+    },
+    // Other static validation errors.
+    KnownFailure {
+        error: "InvalidMessage",
+        location: KnownLocation::Message,
+        causes: &[
+            "The message is too large or its encryption metadata is invalid.",
+            "An encrypted message has no decryptors or mismatched curves.",
+        ],
+        fixes: &["Keep messages small and ensure every encrypted message has at least one decryptor per curve used."],
+    },
+    KnownFailure {
+        error: "TransactionTooLarge",
+        location: KnownLocation::Payload,
+        causes: &["The transaction payload is larger than the maximum permitted by the network."],
+        fixes: &["Reduce the size of the manifest, blobs, or message, or split the transaction."],
+    },
+    KnownFailure {
+        error: "PrepareError",
+        location: KnownLocation::Payload,
+        causes: &["The transaction could not be prepared, e.g., it exceeds a size or depth limit."],
+        fixes: &["Reduce the size and the nesting depth of the values in the transaction."],
+    },
+    KnownFailure {
+        error: "EncodeError",
+        location: KnownLocation::Payload,
+        causes: &["The transaction could not be SBOR encoded, e.g., a value is nested too deeply."],
+        fixes: &["Reduce the nesting depth of the values in the transaction."],
+    },
+    // Rejections reported by the node.
+    KnownFailure {
+        error: "EpochNotYetValid",
+        location: KnownLocation::Header("start_epoch_inclusive"),
+        causes: &["The start epoch of the header is in the future."],
+        fixes: &["Use the current epoch of the network as the start epoch."],
+    },
+    KnownFailure {
+        error: "EpochNoLongerValid",
+        location: KnownLocation::Header("end_epoch_exclusive"),
+        causes: &["The epoch window of the transaction has passed."],
+        fixes: &["Rebuild the transaction with an epoch window that starts at the current epoch."],
+    },
+    KnownFailure {
+        error: "IntentHashPreviouslyCommitted",
+        location: KnownLocation::Ledger,
+        causes: &["An intent with the same intent hash has already been committed."],
+        fixes: &[
+            "Check the status of the previously committed transaction rather than resubmitting.",
+            "Use a different nonce to submit a new, distinct, intent.",
+        ],
+    },
+    KnownFailure {
+        error: "IntentHashPreviouslyCancelled",
+        location: KnownLocation::Ledger,
+        causes: &["An intent with the same intent hash has been cancelled."],
+        fixes: &["Use a different nonce to submit a new, distinct, intent."],
+    },
+    KnownFailure {
+        error: "FeeLoanNotRepaid",
+        location: KnownLocation::Manifest,
+        causes: &[
+            "The manifest does not lock enough fees before the fee loan runs out.",
+            "The account used to lock fees does not have enough XRD.",
+        ],
+        fixes: &["Lock a fee at the start of the manifest from an account that holds enough XRD."],
+    },
+];
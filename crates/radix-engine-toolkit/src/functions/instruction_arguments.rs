@@ -0,0 +1,482 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Checks the arguments of the invocations in a manifest against the
+//! interfaces of the blueprints that they invoke. This catches mistakes such
+//! as a wrong number of arguments or an argument of the wrong kind before the
+//! manifest is previewed. The interfaces of the native blueprints are bundled
+//! with the toolkit when the `native-blueprint-interfaces` feature is enabled.
+
+use radix_engine_interface::prelude::*;
+use radix_transactions::prelude::*;
+
+use crate::functions::package::{
+    BlueprintInterface, FieldSignature, TypeSignature,
+};
+
+/// The interfaces of blueprints keyed by the id of the blueprint.
+pub type BlueprintInterfaces = IndexMap<BlueprintId, BlueprintInterface>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionArgumentsIssue {
+    pub instruction_index: usize,
+    pub kind: InstructionArgumentsIssueKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InstructionArgumentsIssueKind {
+    /// The blueprint has no function or method with the name, or the name is
+    /// that of a function and it's called as a method or the other way
+    /// around.
+    FunctionNotFound {
+        blueprint_name: String,
+        function_name: String,
+    },
+    /// The arguments are not a tuple of the arguments of the function.
+    ArgumentsNotATuple,
+    ArgumentCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    /// The value at the path is of a different kind than the one that the
+    /// interface expects.
+    ValueKindMismatch {
+        path: String,
+        expected: ManifestValueKind,
+        actual: ManifestValueKind,
+    },
+    /// The tuple or enum variant at the path has a different number of
+    /// fields than the one that the interface expects.
+    FieldCountMismatch {
+        path: String,
+        expected: usize,
+        actual: usize,
+    },
+    UnknownVariant {
+        path: String,
+        discriminator: u8,
+    },
+}
+
+/// Checks the arguments of the function and method calls in the instructions
+/// against the interfaces of the blueprints that they call. Calls to
+/// blueprints that have no interface in the given interfaces, and method
+/// calls on named addresses whose blueprint is unknown, are not checked.
+///
+/// The paths of the issues are in the notation of the
+/// [`value_query`](crate::sbor::value_query) module and start at the tuple of
+/// the arguments.
+pub fn validate_instruction_arguments(
+    instructions: &[InstructionV1],
+    interfaces: &BlueprintInterfaces,
+) -> Vec<InstructionArgumentsIssue> {
+    instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(instruction_index, instruction)| {
+            let (blueprint_id, function_name, is_method, args) =
+                invoked_function(instruction)?;
+            let interface = interfaces.get(&blueprint_id)?;
+            Some(
+                validate_arguments(
+                    &blueprint_id,
+                    interface,
+                    function_name,
+                    is_method,
+                    args,
+                )
+                .into_iter()
+                .map(move |kind| InstructionArgumentsIssue {
+                    instruction_index,
+                    kind,
+                }),
+            )
+        })
+        .flatten()
+        .collect()
+}
+
+#[cfg(feature = "native-blueprint-interfaces")]
+lazy_static::lazy_static! {
+    static ref NATIVE_BLUEPRINT_INTERFACES: BlueprintInterfaces =
+        native_blueprint_interfaces_uncached();
+}
+
+/// The interfaces of the blueprints of the native packages, i.e., of the
+/// account, identity, validator, pool, access controller, and resource
+/// manager blueprints among others, as well as of the blueprints of the
+/// metadata, royalty, and role assignment modules.
+#[cfg(feature = "native-blueprint-interfaces")]
+pub fn native_blueprint_interfaces() -> &'static BlueprintInterfaces {
+    &NATIVE_BLUEPRINT_INTERFACES
+}
+
+#[cfg(feature = "native-blueprint-interfaces")]
+fn native_blueprint_interfaces_uncached() -> BlueprintInterfaces {
+    use crate::functions::package::{
+        blueprint_interfaces, native_package_definitions,
+    };
+
+    native_package_definitions()
+        .into_iter()
+        .flat_map(|(package_address, package_definition)| {
+            blueprint_interfaces(package_definition)
+                .expect("Native package definitions are valid")
+                .into_iter()
+                .map(move |(blueprint_name, interface)| {
+                    (
+                        BlueprintId::new(&package_address, blueprint_name),
+                        interface,
+                    )
+                })
+        })
+        .collect()
+}
+
+/// The blueprint and name of the function that the instruction calls along
+/// with whether it's a method and the arguments it's called with.
+fn invoked_function(
+    instruction: &InstructionV1,
+) -> Option<(BlueprintId, &str, bool, &ManifestValue)> {
+    match instruction {
+        InstructionV1::CallFunction {
+            package_address: DynamicPackageAddress::Static(package_address),
+            blueprint_name,
+            function_name,
+            args,
+        } => Some((
+            BlueprintId::new(package_address, blueprint_name),
+            function_name,
+            false,
+            args,
+        )),
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(address),
+            method_name,
+            args,
+        } => Some((
+            main_module_blueprint_id(address.as_node_id().entity_type()?)?,
+            method_name,
+            true,
+            args,
+        )),
+        InstructionV1::CallMetadataMethod {
+            method_name, args, ..
+        } => Some((
+            BlueprintId::new(&METADATA_MODULE_PACKAGE, METADATA_BLUEPRINT),
+            method_name,
+            true,
+            args,
+        )),
+        InstructionV1::CallRoyaltyMethod {
+            method_name, args, ..
+        } => Some((
+            BlueprintId::new(
+                &ROYALTY_MODULE_PACKAGE,
+                COMPONENT_ROYALTY_BLUEPRINT,
+            ),
+            method_name,
+            true,
+            args,
+        )),
+        InstructionV1::CallRoleAssignmentMethod {
+            method_name, args, ..
+        } => Some((
+            BlueprintId::new(
+                &ROLE_ASSIGNMENT_MODULE_PACKAGE,
+                ROLE_ASSIGNMENT_BLUEPRINT,
+            ),
+            method_name,
+            true,
+            args,
+        )),
+        _ => None,
+    }
+}
+
+/// The blueprint of the main module of the native components, resource
+/// managers, and vaults with the given entity type. The blueprint of other
+/// components can't be determined from their address alone.
+pub(crate) fn main_module_blueprint_id(
+    entity_type: EntityType,
+) -> Option<BlueprintId> {
+    use radix_engine::blueprints::pool::v1::constants::*;
+
+    let (package_address, blueprint_name) = match entity_type {
+        EntityType::GlobalAccount
+        | EntityType::GlobalVirtualSecp256k1Account
+        | EntityType::GlobalVirtualEd25519Account => {
+            (ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT)
+        }
+        EntityType::GlobalIdentity
+        | EntityType::GlobalVirtualSecp256k1Identity
+        | EntityType::GlobalVirtualEd25519Identity => {
+            (IDENTITY_PACKAGE, IDENTITY_BLUEPRINT)
+        }
+        EntityType::GlobalValidator => {
+            (CONSENSUS_MANAGER_PACKAGE, VALIDATOR_BLUEPRINT)
+        }
+        EntityType::GlobalConsensusManager => {
+            (CONSENSUS_MANAGER_PACKAGE, CONSENSUS_MANAGER_BLUEPRINT)
+        }
+        EntityType::GlobalAccessController => {
+            (ACCESS_CONTROLLER_PACKAGE, ACCESS_CONTROLLER_BLUEPRINT)
+        }
+        EntityType::GlobalOneResourcePool => {
+            (POOL_PACKAGE, ONE_RESOURCE_POOL_BLUEPRINT_IDENT)
+        }
+        EntityType::GlobalTwoResourcePool => {
+            (POOL_PACKAGE, TWO_RESOURCE_POOL_BLUEPRINT_IDENT)
+        }
+        EntityType::GlobalMultiResourcePool => {
+            (POOL_PACKAGE, MULTI_RESOURCE_POOL_BLUEPRINT_IDENT)
+        }
+        EntityType::GlobalFungibleResourceManager => {
+            (RESOURCE_PACKAGE, FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT)
+        }
+        EntityType::GlobalNonFungibleResourceManager => {
+            (RESOURCE_PACKAGE, NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT)
+        }
+        EntityType::InternalFungibleVault => {
+            (RESOURCE_PACKAGE, FUNGIBLE_VAULT_BLUEPRINT)
+        }
+        EntityType::InternalNonFungibleVault => {
+            (RESOURCE_PACKAGE, NON_FUNGIBLE_VAULT_BLUEPRINT)
+        }
+        _ => return None,
+    };
+    Some(BlueprintId::new(&package_address, blueprint_name))
+}
+
+fn validate_arguments(
+    blueprint_id: &BlueprintId,
+    interface: &BlueprintInterface,
+    function_name: &str,
+    is_method: bool,
+    args: &ManifestValue,
+) -> Vec<InstructionArgumentsIssueKind> {
+    let Some(function) = interface
+        .functions
+        .get(function_name)
+        .filter(|function| function.receiver.is_some() == is_method)
+    else {
+        return vec![InstructionArgumentsIssueKind::FunctionNotFound {
+            blueprint_name: blueprint_id.blueprint_name.clone(),
+            function_name: function_name.to_owned(),
+        }];
+    };
+    let ManifestValue::Tuple { fields } = args else {
+        return vec![InstructionArgumentsIssueKind::ArgumentsNotATuple];
+    };
+    if fields.len() != function.arguments.len() {
+        return vec![InstructionArgumentsIssueKind::ArgumentCountMismatch {
+            expected: function.arguments.len(),
+            actual: fields.len(),
+        }];
+    }
+
+    let mut issues = vec![];
+    for (index, (value, argument)) in
+        fields.iter().zip(&function.arguments).enumerate()
+    {
+        validate_value(
+            value,
+            &argument.type_signature,
+            &format!("$.{index}"),
+            &mut issues,
+        );
+    }
+    issues
+}
+
+fn validate_value(
+    value: &ManifestValue,
+    type_signature: &TypeSignature,
+    path: &str,
+    issues: &mut Vec<InstructionArgumentsIssueKind>,
+) {
+    let expected = match type_signature {
+        // These can't be checked without more information than what the
+        // interface has.
+        TypeSignature::Any
+        | TypeSignature::Generic(..)
+        | TypeSignature::Recursive { .. }
+        | TypeSignature::Own => return,
+        TypeSignature::Value(value_kind) => *value_kind,
+        TypeSignature::Array { .. } => ManifestValueKind::Array,
+        TypeSignature::Tuple { .. } => ManifestValueKind::Tuple,
+        TypeSignature::Enum { .. } => ManifestValueKind::Enum,
+        TypeSignature::Map { .. } => ManifestValueKind::Map,
+    };
+    let actual = manifest_value_kind(value);
+    // Expressions are resolved into arrays of buckets or proofs and blobs into
+    // arrays of bytes by the transaction processor.
+    let is_resolved_into_array = expected == ManifestValueKind::Array
+        && matches!(
+            actual,
+            ManifestValueKind::Custom(
+                ManifestCustomValueKind::Expression
+                    | ManifestCustomValueKind::Blob
+            )
+        );
+    if is_resolved_into_array {
+        return;
+    }
+    if expected != actual {
+        issues.push(InstructionArgumentsIssueKind::ValueKindMismatch {
+            path: path.to_owned(),
+            expected,
+            actual,
+        });
+        return;
+    }
+
+    match (value, type_signature) {
+        (
+            ManifestValue::Array { elements, .. },
+            TypeSignature::Array { element },
+        ) => {
+            for (index, value) in elements.iter().enumerate() {
+                validate_value(
+                    value,
+                    element,
+                    &format!("{path}.{index}"),
+                    issues,
+                );
+            }
+        }
+        (
+            ManifestValue::Tuple { fields: values },
+            TypeSignature::Tuple { fields, .. },
+        ) => validate_fields(values, fields, path, issues),
+        (
+            ManifestValue::Enum {
+                discriminator,
+                fields: values,
+            },
+            TypeSignature::Enum { variants, .. },
+        ) => match variants.get(discriminator) {
+            Some(variant) => validate_fields(
+                values,
+                &variant.fields,
+                &format!("{path}#{discriminator}"),
+                issues,
+            ),
+            None => {
+                issues.push(InstructionArgumentsIssueKind::UnknownVariant {
+                    path: path.to_owned(),
+                    discriminator: *discriminator,
+                })
+            }
+        },
+        (
+            ManifestValue::Map { entries, .. },
+            TypeSignature::Map { key, value },
+        ) => {
+            for (index, (entry_key, entry_value)) in entries.iter().enumerate()
+            {
+                validate_value(
+                    entry_key,
+                    key,
+                    &format!("{path}.{index}.0"),
+                    issues,
+                );
+                validate_value(
+                    entry_value,
+                    value,
+                    &format!("{path}.{index}.1"),
+                    issues,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+fn validate_fields(
+    values: &[ManifestValue],
+    fields: &[FieldSignature],
+    path: &str,
+    issues: &mut Vec<InstructionArgumentsIssueKind>,
+) {
+    if values.len() != fields.len() {
+        issues.push(InstructionArgumentsIssueKind::FieldCountMismatch {
+            path: path.to_owned(),
+            expected: fields.len(),
+            actual: values.len(),
+        });
+        return;
+    }
+    for (index, (value, field)) in values.iter().zip(fields).enumerate() {
+        validate_value(
+            value,
+            &field.type_signature,
+            &format!("{path}.{index}"),
+            issues,
+        );
+    }
+}
+
+fn manifest_value_kind(value: &ManifestValue) -> ManifestValueKind {
+    match value {
+        ManifestValue::Bool { .. } => ManifestValueKind::Bool,
+        ManifestValue::I8 { .. } => ManifestValueKind::I8,
+        ManifestValue::I16 { .. } => ManifestValueKind::I16,
+        ManifestValue::I32 { .. } => ManifestValueKind::I32,
+        ManifestValue::I64 { .. } => ManifestValueKind::I64,
+        ManifestValue::I128 { .. } => ManifestValueKind::I128,
+        ManifestValue::U8 { .. } => ManifestValueKind::U8,
+        ManifestValue::U16 { .. } => ManifestValueKind::U16,
+        ManifestValue::U32 { .. } => ManifestValueKind::U32,
+        ManifestValue::U64 { .. } => ManifestValueKind::U64,
+        ManifestValue::U128 { .. } => ManifestValueKind::U128,
+        ManifestValue::String { .. } => ManifestValueKind::String,
+        ManifestValue::Enum { .. } => ManifestValueKind::Enum,
+        ManifestValue::Array { .. } => ManifestValueKind::Array,
+        ManifestValue::Tuple { .. } => ManifestValueKind::Tuple,
+        ManifestValue::Map { .. } => ManifestValueKind::Map,
+        ManifestValue::Custom { value } => {
+            ManifestValueKind::Custom(match value {
+                ManifestCustomValue::Address(..) => {
+                    ManifestCustomValueKind::Address
+                }
+                ManifestCustomValue::Bucket(..) => {
+                    ManifestCustomValueKind::Bucket
+                }
+                ManifestCustomValue::Proof(..) => {
+                    ManifestCustomValueKind::Proof
+                }
+                ManifestCustomValue::Expression(..) => {
+                    ManifestCustomValueKind::Expression
+                }
+                ManifestCustomValue::Blob(..) => ManifestCustomValueKind::Blob,
+                ManifestCustomValue::Decimal(..) => {
+                    ManifestCustomValueKind::Decimal
+                }
+                ManifestCustomValue::PreciseDecimal(..) => {
+                    ManifestCustomValueKind::PreciseDecimal
+                }
+                ManifestCustomValue::NonFungibleLocalId(..) => {
+                    ManifestCustomValueKind::NonFungibleLocalId
+                }
+                ManifestCustomValue::AddressReservation(..) => {
+                    ManifestCustomValueKind::AddressReservation
+                }
+            })
+        }
+    }
+}
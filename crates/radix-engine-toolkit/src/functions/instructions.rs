@@ -40,6 +40,106 @@ where
     manifest_decode(payload_bytes.as_ref())
 }
 
+/// An instruction decompiled by [`decompile_forward_compatible`]. Instructions
+/// that this version of the toolkit does not know of, such as ones introduced
+/// by a later protocol update, are kept as their raw manifest values so that
+/// they can be compiled back into the same bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecompiledInstruction {
+    Known(InstructionV1),
+    Unknown {
+        discriminator: u8,
+        fields: Vec<ManifestValue>,
+    },
+}
+
+impl DecompiledInstruction {
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown { .. })
+    }
+}
+
+/// Decompiles the instructions like [`decompile`] does but keeps the
+/// instructions with unknown discriminators instead of failing to decode them.
+pub fn decompile_forward_compatible<T>(
+    payload_bytes: T,
+) -> Result<Vec<DecompiledInstruction>, DecodeError>
+where
+    T: AsRef<[u8]>,
+{
+    let elements =
+        match manifest_decode::<ManifestValue>(payload_bytes.as_ref())? {
+            ManifestValue::Array {
+                element_value_kind: ManifestValueKind::Enum,
+                elements,
+            } => elements,
+            // Not a list of instructions, decoding it as one reports why.
+            _ => {
+                return decompile(payload_bytes).map(|instructions| {
+                    instructions
+                        .into_iter()
+                        .map(DecompiledInstruction::Known)
+                        .collect()
+                })
+            }
+        };
+
+    elements
+        .into_iter()
+        .map(|element| {
+            let encoded = manifest_encode(&element)
+                .expect("Decoded values can be encoded");
+            match (manifest_decode(&encoded), element) {
+                (Ok(instruction), _) => {
+                    Ok(DecompiledInstruction::Known(instruction))
+                }
+                (
+                    Err(DecodeError::UnknownDiscriminator(unknown)),
+                    ManifestValue::Enum {
+                        discriminator,
+                        fields,
+                    },
+                ) if unknown == discriminator => {
+                    Ok(DecompiledInstruction::Unknown {
+                        discriminator,
+                        fields,
+                    })
+                }
+                (Err(error), _) => Err(error),
+            }
+        })
+        .collect()
+}
+
+/// Compiles instructions decompiled by [`decompile_forward_compatible`],
+/// including the unknown ones, into the bytes that they were decompiled from.
+pub fn compile_forward_compatible(
+    instructions: &[DecompiledInstruction],
+) -> Result<Vec<u8>, EncodeError> {
+    let elements = instructions
+        .iter()
+        .map(|instruction| match instruction {
+            DecompiledInstruction::Known(instruction) => {
+                manifest_encode(instruction).map(|encoded| {
+                    manifest_decode(&encoded)
+                        .expect("Encoded instructions can be decoded")
+                })
+            }
+            DecompiledInstruction::Unknown {
+                discriminator,
+                fields,
+            } => Ok(ManifestValue::Enum {
+                discriminator: *discriminator,
+                fields: fields.clone(),
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    manifest_encode(&ManifestValue::Array {
+        element_value_kind: ManifestValueKind::Enum,
+        elements,
+    })
+}
+
 pub fn statically_validate(
     instructions: &[InstructionV1],
 ) -> Result<(), InstructionValidationError> {
@@ -71,6 +171,271 @@ pub fn extract_addresses(
     (static_addresses, named_addresses)
 }
 
+/// Extracts the static addresses found in the instructions along with how each
+/// of them was used. An address may be used in more than one way across the
+/// instructions, in which case all of its usages are reported.
+pub fn extract_addresses_with_usage(
+    instructions: &[InstructionV1],
+) -> IndexMap<TypedNodeId, IndexSet<AddressUsage>> {
+    extract_address_occurrences(instructions)
+        .into_iter()
+        .map(|(address, occurrences)| {
+            (
+                address,
+                occurrences
+                    .into_iter()
+                    .map(|occurrence| occurrence.usage)
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Extracts the static addresses found in the instructions along with every
+/// occurrence of them, i.e., the index of the instruction that the address
+/// appears in and how it's used there. The occurrences of each address are
+/// in the order of the instructions.
+pub fn extract_address_occurrences(
+    instructions: &[InstructionV1],
+) -> IndexMap<TypedNodeId, Vec<AddressOccurrence>> {
+    let mut addresses = IndexMap::<TypedNodeId, Vec<AddressOccurrence>>::new();
+    let mut add_address = |instruction_index: usize,
+                           address: TypedNodeId,
+                           usage: AddressUsage| {
+        let occurrence = AddressOccurrence {
+            instruction_index,
+            usage,
+        };
+        let occurrences = addresses.entry(address).or_default();
+        if !occurrences.contains(&occurrence) {
+            occurrences.push(occurrence)
+        }
+    };
+    let value_addresses = |value: &ManifestValue| {
+        IndexedManifestValue::from_typed(value).static_addresses()
+    };
+
+    for (instruction_index, instruction) in instructions.iter().enumerate() {
+        let mut add_address = |address: TypedNodeId, usage: AddressUsage| {
+            add_address(instruction_index, address, usage)
+        };
+        match instruction {
+            InstructionV1::TakeFromWorktop {
+                resource_address, ..
+            }
+            | InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ..
+            }
+            | InstructionV1::TakeAllFromWorktop { resource_address }
+            | InstructionV1::AssertWorktopContainsAny { resource_address }
+            | InstructionV1::AssertWorktopContains {
+                resource_address, ..
+            }
+            | InstructionV1::AssertWorktopContainsNonFungibles {
+                resource_address,
+                ..
+            }
+            | InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                ..
+            }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ..
+            }
+            | InstructionV1::CreateProofFromAuthZoneOfAll {
+                resource_address,
+            } => {
+                add_address((*resource_address).into(), AddressUsage::Resource)
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                args,
+                ..
+            } => {
+                if let DynamicPackageAddress::Static(package_address) =
+                    package_address
+                {
+                    add_address(
+                        (*package_address).into(),
+                        AddressUsage::CallTarget,
+                    );
+                }
+                for address in value_addresses(args) {
+                    add_address(address, AddressUsage::Argument);
+                }
+            }
+            InstructionV1::CallMethod { address, args, .. }
+            | InstructionV1::CallRoleAssignmentMethod {
+                address, args, ..
+            } => {
+                if let DynamicGlobalAddress::Static(address) = address {
+                    add_address((*address).into(), AddressUsage::CallTarget);
+                }
+                for address in value_addresses(args) {
+                    add_address(address, AddressUsage::Argument);
+                }
+            }
+            InstructionV1::CallRoyaltyMethod { address, args, .. } => {
+                if let DynamicGlobalAddress::Static(address) = address {
+                    add_address((*address).into(), AddressUsage::RoyaltyTarget);
+                }
+                for address in value_addresses(args) {
+                    add_address(address, AddressUsage::Argument);
+                }
+            }
+            InstructionV1::CallMetadataMethod { address, args, .. } => {
+                if let DynamicGlobalAddress::Static(address) = address {
+                    add_address(
+                        (*address).into(),
+                        AddressUsage::MetadataSubject,
+                    );
+                }
+                for address in value_addresses(args) {
+                    add_address(address, AddressUsage::Metadata);
+                }
+            }
+            InstructionV1::CallDirectVaultMethod { address, args, .. } => {
+                add_address((*address).into(), AddressUsage::CallTarget);
+                for address in value_addresses(args) {
+                    add_address(address, AddressUsage::Argument);
+                }
+            }
+            InstructionV1::AllocateGlobalAddress {
+                package_address, ..
+            } => {
+                add_address((*package_address).into(), AddressUsage::CallTarget)
+            }
+            InstructionV1::ReturnToWorktop { .. }
+            | InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. }
+            | InstructionV1::BurnResource { .. }
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropNamedProofs
+            | InstructionV1::DropAllProofs => {}
+        }
+    }
+
+    addresses
+}
+
+/// Describes how an address was used in the instructions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AddressUsage {
+    /// The address is the target of an invocation: the component or vault of
+    /// a method call or the package of a function call or an address
+    /// allocation.
+    CallTarget,
+    /// The address is a value inside the arguments of an invocation.
+    Argument,
+    /// The address is the entity whose metadata is read or updated by a call
+    /// to the metadata module.
+    MetadataSubject,
+    /// The address is the entity whose royalties are configured or claimed by
+    /// a call to the royalty module.
+    RoyaltyTarget,
+    /// The address is a value inside the arguments of a call to the metadata
+    /// module.
+    Metadata,
+    /// The address is the resource of a worktop, assertion, or auth zone
+    /// instruction.
+    Resource,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AddressOccurrence {
+    pub instruction_index: usize,
+    pub usage: AddressUsage,
+}
+
+/// Summarizes each of the instructions by their kind and the addresses that
+/// they touch, in the order that the instructions appear in.
+pub fn summarize(instructions: &[InstructionV1]) -> Vec<InstructionSummary> {
+    instructions
+        .iter()
+        .map(|instruction| InstructionSummary {
+            kind: instruction_kind(instruction),
+            addresses: extract_addresses_with_usage(std::slice::from_ref(
+                instruction,
+            ))
+            .into_keys()
+            .collect(),
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionSummary {
+    /// The name of the instruction kind, e.g., `CallMethod`.
+    pub kind: &'static str,
+    pub addresses: IndexSet<TypedNodeId>,
+}
+
+pub fn instruction_kind(instruction: &InstructionV1) -> &'static str {
+    match instruction {
+        InstructionV1::TakeFromWorktop { .. } => "TakeFromWorktop",
+        InstructionV1::TakeNonFungiblesFromWorktop { .. } => {
+            "TakeNonFungiblesFromWorktop"
+        }
+        InstructionV1::TakeAllFromWorktop { .. } => "TakeAllFromWorktop",
+        InstructionV1::ReturnToWorktop { .. } => "ReturnToWorktop",
+        InstructionV1::AssertWorktopContainsAny { .. } => {
+            "AssertWorktopContainsAny"
+        }
+        InstructionV1::AssertWorktopContains { .. } => "AssertWorktopContains",
+        InstructionV1::AssertWorktopContainsNonFungibles { .. } => {
+            "AssertWorktopContainsNonFungibles"
+        }
+        InstructionV1::PopFromAuthZone => "PopFromAuthZone",
+        InstructionV1::PushToAuthZone { .. } => "PushToAuthZone",
+        InstructionV1::CreateProofFromAuthZoneOfAmount { .. } => {
+            "CreateProofFromAuthZoneOfAmount"
+        }
+        InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. } => {
+            "CreateProofFromAuthZoneOfNonFungibles"
+        }
+        InstructionV1::CreateProofFromAuthZoneOfAll { .. } => {
+            "CreateProofFromAuthZoneOfAll"
+        }
+        InstructionV1::DropAuthZoneProofs => "DropAuthZoneProofs",
+        InstructionV1::DropAuthZoneRegularProofs => "DropAuthZoneRegularProofs",
+        InstructionV1::DropAuthZoneSignatureProofs => {
+            "DropAuthZoneSignatureProofs"
+        }
+        InstructionV1::CreateProofFromBucketOfAmount { .. } => {
+            "CreateProofFromBucketOfAmount"
+        }
+        InstructionV1::CreateProofFromBucketOfNonFungibles { .. } => {
+            "CreateProofFromBucketOfNonFungibles"
+        }
+        InstructionV1::CreateProofFromBucketOfAll { .. } => {
+            "CreateProofFromBucketOfAll"
+        }
+        InstructionV1::BurnResource { .. } => "BurnResource",
+        InstructionV1::CloneProof { .. } => "CloneProof",
+        InstructionV1::DropProof { .. } => "DropProof",
+        InstructionV1::CallFunction { .. } => "CallFunction",
+        InstructionV1::CallMethod { .. } => "CallMethod",
+        InstructionV1::CallRoyaltyMethod { .. } => "CallRoyaltyMethod",
+        InstructionV1::CallMetadataMethod { .. } => "CallMetadataMethod",
+        InstructionV1::CallRoleAssignmentMethod { .. } => {
+            "CallRoleAssignmentMethod"
+        }
+        InstructionV1::CallDirectVaultMethod { .. } => "CallDirectVaultMethod",
+        InstructionV1::DropNamedProofs => "DropNamedProofs",
+        InstructionV1::DropAllProofs => "DropAllProofs",
+        InstructionV1::AllocateGlobalAddress { .. } => "AllocateGlobalAddress",
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum InstructionValidationError {
     TransactionValidationError(TransactionValidationError),
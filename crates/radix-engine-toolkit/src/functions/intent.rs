@@ -17,11 +17,14 @@
 
 use radix_transactions::errors::*;
 use radix_transactions::model::*;
+use radix_transactions::prelude::{PrivateKey, Signer};
 use radix_transactions::validation::*;
 use sbor::*;
 use scrypto::prelude::*;
 
-use crate::models::transaction_hash::TransactionHash;
+use crate::functions::random::{generate_secure_random_nonce, RandomError};
+use crate::functions::signing::recover_public_key;
+use crate::models::transaction_hash::{PayloadHashError, TransactionHash};
 
 pub fn hash(intent: &IntentV1) -> Result<TransactionHash, PrepareError> {
     intent
@@ -30,6 +33,18 @@ pub fn hash(intent: &IntentV1) -> Result<TransactionHash, PrepareError> {
         .map(|hash| TransactionHash::new(hash, intent.header.network_id))
 }
 
+/// Computes the intent hash of a compiled intent.
+pub fn hash_from_payload<T>(
+    payload_bytes: T,
+) -> Result<TransactionHash, PayloadHashError>
+where
+    T: AsRef<[u8]>,
+{
+    let intent =
+        decompile(payload_bytes).map_err(PayloadHashError::DecodeError)?;
+    hash(&intent).map_err(PayloadHashError::PrepareError)
+}
+
 pub fn compile(intent: &IntentV1) -> Result<Vec<u8>, EncodeError> {
     intent.to_payload_bytes()
 }
@@ -51,3 +66,238 @@ pub fn statically_validate(
         .map_err(TransactionValidationError::PrepareError)
         .and_then(|prepared| validator.validate_intent_v1(&prepared))
 }
+
+/// Signs the intent hash of the given intent with the given private key.
+pub fn sign(
+    intent: &IntentV1,
+    private_key: &PrivateKey,
+) -> Result<SignatureWithPublicKeyV1, PrepareError> {
+    intent.prepare().map(|prepared| {
+        private_key.sign_with_public_key(&prepared.intent_hash())
+    })
+}
+
+/// Signs the given intent with each of the given private keys and assembles
+/// the signatures and the intent into a signed intent.
+pub fn sign_to_signed_intent(
+    intent: IntentV1,
+    private_keys: &[PrivateKey],
+) -> Result<SignedIntentV1, PrepareError> {
+    let intent_hash = intent.prepare()?.intent_hash();
+    let signatures = private_keys
+        .iter()
+        .map(|private_key| {
+            IntentSignatureV1(private_key.sign_with_public_key(&intent_hash))
+        })
+        .collect();
+    Ok(SignedIntentV1 {
+        intent,
+        intent_signatures: IntentSignaturesV1 { signatures },
+    })
+}
+
+/// Assembles a signed intent from the intent and the lists of signatures that
+/// each of the signers of the intent produced independently. Every signature is
+/// validated against the intent hash and signatures from a public key that has
+/// already signed are dropped so that the signers can be merged in any order.
+pub fn merge_signatures<I>(
+    intent: IntentV1,
+    signatures: I,
+) -> Result<SignedIntentV1, SignatureMergeError>
+where
+    I: IntoIterator<Item = Vec<SignatureWithPublicKeyV1>>,
+{
+    let intent_hash = intent
+        .prepare()
+        .map_err(SignatureMergeError::PrepareError)?
+        .intent_hash();
+
+    let mut signers = IndexSet::<PublicKey>::new();
+    let mut merged_signatures = Vec::new();
+    for signature in signatures.into_iter().flatten() {
+        let public_key = recover_public_key(&intent_hash.0, &signature)
+            .ok_or(SignatureMergeError::InvalidSignature(signature.clone()))?;
+        if signers.insert(public_key) {
+            merged_signatures.push(IntentSignatureV1(signature));
+        }
+    }
+
+    Ok(SignedIntentV1 {
+        intent,
+        intent_signatures: IntentSignaturesV1 {
+            signatures: merged_signatures,
+        },
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureMergeError {
+    PrepareError(PrepareError),
+    /// The signature is not a valid signature over the intent hash.
+    InvalidSignature(SignatureWithPublicKeyV1),
+}
+
+/// The fields of an intent that are relevant to replay protection along with
+/// the intent hash which is the key that the network uses to guarantee that an
+/// intent is committed at most once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayProtection {
+    pub network_id: u8,
+    pub start_epoch_inclusive: Epoch,
+    pub end_epoch_exclusive: Epoch,
+    pub nonce: u32,
+    /// The uniqueness key of the intent. Two intents with the same intent hash
+    /// are the same intent and only one of them can ever be committed.
+    pub intent_hash: IntentHash,
+}
+
+impl ReplayProtection {
+    /// A key that orders intents by the start of their epoch window and then
+    /// by their nonce, with the intent hash as a tie-breaker. This gives the
+    /// retries in a submission queue a consistent order.
+    pub fn ordering_key(&self) -> (Epoch, u32, Hash) {
+        (self.start_epoch_inclusive, self.nonce, self.intent_hash.0)
+    }
+}
+
+pub fn replay_protection(
+    intent: &IntentV1,
+) -> Result<ReplayProtection, PrepareError> {
+    let intent_hash = intent.prepare()?.intent_hash();
+    Ok(ReplayProtection {
+        network_id: intent.header.network_id,
+        start_epoch_inclusive: intent.header.start_epoch_inclusive,
+        end_epoch_exclusive: intent.header.end_epoch_exclusive,
+        nonce: intent.header.nonce,
+        intent_hash,
+    })
+}
+
+pub fn replay_protection_from_payload<T>(
+    payload_bytes: T,
+) -> Result<ReplayProtection, ReplayProtectionError>
+where
+    T: AsRef<[u8]>,
+{
+    let intent =
+        decompile(payload_bytes).map_err(ReplayProtectionError::DecodeError)?;
+    replay_protection(&intent).map_err(ReplayProtectionError::PrepareError)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplayProtectionError {
+    DecodeError(DecodeError),
+    PrepareError(PrepareError),
+}
+
+/// The source of the nonce of a transaction header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NonceSource {
+    /// A nonce drawn from the randomness of the operating system.
+    Random,
+    Fixed(u32),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionHeaderOptions {
+    pub start_epoch_inclusive: Epoch,
+    pub end_epoch_exclusive: Epoch,
+    pub nonce: NonceSource,
+    pub notary_public_key: PublicKey,
+    pub notary_is_signatory: bool,
+    pub tip_percentage: u16,
+}
+
+/// Builds a transaction header for the network of the validation config after
+/// checking the epoch window, tip, and notary against the limits that the
+/// network validates the header against.
+pub fn build_transaction_header(
+    options: TransactionHeaderOptions,
+    validation_config: &ValidationConfig,
+) -> Result<TransactionHeaderV1, TransactionHeaderError> {
+    let TransactionHeaderOptions {
+        start_epoch_inclusive,
+        end_epoch_exclusive,
+        nonce,
+        notary_public_key,
+        notary_is_signatory,
+        tip_percentage,
+    } = options;
+
+    if end_epoch_exclusive <= start_epoch_inclusive {
+        return Err(TransactionHeaderError::InvalidEpochRange {
+            start_epoch_inclusive,
+            end_epoch_exclusive,
+        });
+    }
+
+    let epoch_range =
+        end_epoch_exclusive.number() - start_epoch_inclusive.number();
+    if epoch_range > validation_config.max_epoch_range {
+        return Err(TransactionHeaderError::EpochRangeTooLarge {
+            max_epoch_range: validation_config.max_epoch_range,
+            epoch_range,
+        });
+    }
+
+    if tip_percentage < validation_config.min_tip_percentage
+        || tip_percentage > validation_config.max_tip_percentage
+    {
+        return Err(TransactionHeaderError::TipPercentageOutOfRange {
+            min_tip_percentage: validation_config.min_tip_percentage,
+            max_tip_percentage: validation_config.max_tip_percentage,
+            tip_percentage,
+        });
+    }
+
+    let is_valid_notary_public_key = match notary_public_key {
+        PublicKey::Secp256k1(public_key) => {
+            secp256k1::PublicKey::from_slice(&public_key.0).is_ok()
+        }
+        PublicKey::Ed25519(public_key) => {
+            ed25519_dalek::PublicKey::from_bytes(&public_key.0).is_ok()
+        }
+    };
+    if !is_valid_notary_public_key {
+        return Err(TransactionHeaderError::InvalidNotaryPublicKey(
+            notary_public_key,
+        ));
+    }
+
+    let nonce = match nonce {
+        NonceSource::Random => generate_secure_random_nonce()
+            .map_err(TransactionHeaderError::RandomError)?,
+        NonceSource::Fixed(nonce) => nonce,
+    };
+
+    Ok(TransactionHeaderV1 {
+        network_id: validation_config.network_id,
+        start_epoch_inclusive,
+        end_epoch_exclusive,
+        nonce,
+        notary_public_key,
+        notary_is_signatory,
+        tip_percentage,
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionHeaderError {
+    /// The end epoch is not after the start epoch.
+    InvalidEpochRange {
+        start_epoch_inclusive: Epoch,
+        end_epoch_exclusive: Epoch,
+    },
+    EpochRangeTooLarge {
+        max_epoch_range: u64,
+        epoch_range: u64,
+    },
+    TipPercentageOutOfRange {
+        min_tip_percentage: u16,
+        max_tip_percentage: u16,
+        tip_percentage: u16,
+    },
+    /// The notary public key is not a valid point on its curve.
+    InvalidNotaryPublicKey(PublicKey),
+    RandomError(RandomError),
+}
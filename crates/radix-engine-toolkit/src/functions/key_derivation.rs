@@ -0,0 +1,299 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Derivation of Radix keys from BIP39 mnemonics through SLIP-10 for Ed25519
+//! keys and BIP32 for Secp256k1 keys.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use bip39::{Language, Mnemonic};
+use hmac::{Hmac, Mac, NewMac};
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+use sha2::Sha512;
+
+use crate::functions::derive::virtual_account_address_from_public_key;
+
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+const PBKDF2_ROUNDS: u32 = 2048;
+const VALID_MNEMONIC_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// A BIP32 derivation path such as `m/44'/1022'/1'/525'/1460'/0'`. Both `'`
+/// and `H` are accepted as the hardening marker when parsing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivationPath(pub Vec<DerivationPathComponent>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DerivationPathComponent {
+    pub index: u32,
+    pub is_hardened: bool,
+}
+
+impl DerivationPath {
+    /// The Radix standard derivation path of the transaction signing key of
+    /// an account. For Ed25519 this is the CAP-26 path and for Secp256k1 this
+    /// is the path that was used by the Olympia wallets.
+    pub fn account(network_id: u8, index: u32, curve: CurveType) -> Self {
+        let components = match curve {
+            CurveType::Ed25519 => vec![
+                (44, true),
+                (1022, true),
+                (network_id as u32, true),
+                (525, true),
+                (1460, true),
+                (index, true),
+            ],
+            CurveType::Secp256k1 => vec![
+                (44, true),
+                (1022, true),
+                (0, true),
+                (0, false),
+                (index, true),
+            ],
+        };
+        Self(
+            components
+                .into_iter()
+                .map(|(index, is_hardened)| DerivationPathComponent {
+                    index,
+                    is_hardened,
+                })
+                .collect(),
+        )
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = KeyDerivationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid_path =
+            || KeyDerivationError::InvalidDerivationPath { path: s.to_owned() };
+
+        let mut segments = s.trim().split('/');
+        if segments.next() != Some("m") {
+            return Err(invalid_path());
+        }
+        segments
+            .map(|segment| {
+                let (index, is_hardened) = match segment
+                    .strip_suffix('\'')
+                    .or_else(|| segment.strip_suffix('H'))
+                {
+                    Some(index) => (index, true),
+                    None => (segment, false),
+                };
+                index
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|index| *index < HARDENED_OFFSET)
+                    .map(|index| DerivationPathComponent { index, is_hardened })
+                    .ok_or_else(invalid_path)
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "m")?;
+        for component in self.0.iter() {
+            write!(f, "/{}", component.index)?;
+            if component.is_hardened {
+                write!(f, "'")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A key pair derived from a mnemonic along with the address of the virtual
+/// account that the key pair controls.
+pub struct DerivedKeyPair {
+    pub private_key: PrivateKey,
+    pub public_key: PublicKey,
+    pub virtual_account_address: ComponentAddress,
+}
+
+/// Computes the BIP39 seed of a mnemonic. The words of the mnemonic must be
+/// from the English word list and its checksum must be valid so that a
+/// mistyped mnemonic is rejected rather than deriving the keys of another
+/// wallet. Only ASCII passphrases are supported since they are unaffected by
+/// the NFKD normalization that BIP39 requires.
+pub fn seed_from_mnemonic(
+    mnemonic: &str,
+    passphrase: &str,
+) -> Result<[u8; 64], KeyDerivationError> {
+    if !mnemonic.is_ascii() || !passphrase.is_ascii() {
+        return Err(KeyDerivationError::NonAsciiMnemonic);
+    }
+    let words = mnemonic.split_whitespace().collect::<Vec<_>>();
+    if !VALID_MNEMONIC_WORD_COUNTS.contains(&words.len()) {
+        return Err(KeyDerivationError::InvalidMnemonicWordCount {
+            word_count: words.len(),
+        });
+    }
+
+    let mnemonic = words.join(" ");
+    Mnemonic::parse_in_normalized(Language::English, &mnemonic).map_err(
+        |error| match error {
+            bip39::Error::UnknownWord(index) => {
+                KeyDerivationError::UnknownMnemonicWord {
+                    word: words[index].to_owned(),
+                }
+            }
+            _ => KeyDerivationError::InvalidMnemonicChecksum,
+        },
+    )?;
+
+    let salt = format!("mnemonic{passphrase}");
+    let mut seed = [0u8; 64];
+    pbkdf2::pbkdf2::<Hmac<Sha512>>(
+        mnemonic.as_bytes(),
+        salt.as_bytes(),
+        PBKDF2_ROUNDS,
+        &mut seed,
+    );
+    Ok(seed)
+}
+
+/// Derives the private key at the given derivation path from a BIP39 seed.
+/// SLIP-10 is used for Ed25519 keys, which only supports hardened derivation,
+/// and BIP32 is used for Secp256k1 keys.
+pub fn derive_private_key(
+    seed: &[u8],
+    derivation_path: &DerivationPath,
+    curve: CurveType,
+) -> Result<PrivateKey, KeyDerivationError> {
+    match curve {
+        CurveType::Ed25519 => {
+            let private_key = slip10_ed25519(seed, derivation_path)?;
+            Ed25519PrivateKey::from_bytes(&private_key)
+                .map(PrivateKey::Ed25519)
+                .map_err(|_| KeyDerivationError::InvalidDerivedKey)
+        }
+        CurveType::Secp256k1 => {
+            let private_key = bip32_secp256k1(seed, derivation_path)?;
+            Secp256k1PrivateKey::from_bytes(&private_key)
+                .map(PrivateKey::Secp256k1)
+                .map_err(|_| KeyDerivationError::InvalidDerivedKey)
+        }
+    }
+}
+
+pub fn derive_key_pair_from_mnemonic(
+    mnemonic: &str,
+    passphrase: &str,
+    derivation_path: &DerivationPath,
+    curve: CurveType,
+) -> Result<DerivedKeyPair, KeyDerivationError> {
+    let seed = seed_from_mnemonic(mnemonic, passphrase)?;
+    let private_key = derive_private_key(&seed, derivation_path, curve)?;
+    let public_key = private_key.public_key();
+    let virtual_account_address =
+        virtual_account_address_from_public_key(&public_key);
+
+    Ok(DerivedKeyPair {
+        private_key,
+        public_key,
+        virtual_account_address,
+    })
+}
+
+fn slip10_ed25519(
+    seed: &[u8],
+    derivation_path: &DerivationPath,
+) -> Result<[u8; 32], KeyDerivationError> {
+    let (mut key, mut chain_code) =
+        split(hmac_sha512(b"ed25519 seed", &[seed]));
+    for component in derivation_path.0.iter() {
+        if !component.is_hardened {
+            return Err(KeyDerivationError::NonHardenedEd25519Derivation {
+                index: component.index,
+            });
+        }
+        let index = (component.index | HARDENED_OFFSET).to_be_bytes();
+        (key, chain_code) =
+            split(hmac_sha512(&chain_code, &[&[0], &key, &index]));
+    }
+    Ok(key)
+}
+
+fn bip32_secp256k1(
+    seed: &[u8],
+    derivation_path: &DerivationPath,
+) -> Result<[u8; 32], KeyDerivationError> {
+    let secp256k1 = secp256k1::Secp256k1::signing_only();
+
+    let (mut key, mut chain_code) =
+        split(hmac_sha512(b"Bitcoin seed", &[seed]));
+    for component in derivation_path.0.iter() {
+        let secret_key = secp256k1::SecretKey::from_slice(&key)
+            .map_err(|_| KeyDerivationError::InvalidDerivedKey)?;
+        let (tweak, next_chain_code) = if component.is_hardened {
+            let index = (component.index | HARDENED_OFFSET).to_be_bytes();
+            split(hmac_sha512(&chain_code, &[&[0], &key, &index]))
+        } else {
+            let public_key =
+                secp256k1::PublicKey::from_secret_key(&secp256k1, &secret_key)
+                    .serialize();
+            let index = component.index.to_be_bytes();
+            split(hmac_sha512(&chain_code, &[&public_key, &index]))
+        };
+        let tweak = secp256k1::Scalar::from_be_bytes(tweak)
+            .map_err(|_| KeyDerivationError::InvalidDerivedKey)?;
+        key = secret_key
+            .add_tweak(&tweak)
+            .map_err(|_| KeyDerivationError::InvalidDerivedKey)?
+            .secret_bytes();
+        chain_code = next_chain_code;
+    }
+    secp256k1::SecretKey::from_slice(&key)
+        .map_err(|_| KeyDerivationError::InvalidDerivedKey)?;
+    Ok(key)
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key)
+        .expect("HMAC accepts keys of any length");
+    data.iter().for_each(|data| mac.update(data));
+
+    let mut output = [0u8; 64];
+    output.copy_from_slice(&mac.finalize().into_bytes());
+    output
+}
+
+fn split(bytes: [u8; 64]) -> ([u8; 32], [u8; 32]) {
+    let mut left = [0u8; 32];
+    let mut right = [0u8; 32];
+    left.copy_from_slice(&bytes[..32]);
+    right.copy_from_slice(&bytes[32..]);
+    (left, right)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyDerivationError {
+    InvalidDerivationPath { path: String },
+    InvalidMnemonicWordCount { word_count: usize },
+    UnknownMnemonicWord { word: String },
+    InvalidMnemonicChecksum,
+    NonAsciiMnemonic,
+    NonHardenedEd25519Derivation { index: u32 },
+    InvalidDerivedKey,
+}
@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Encodes transactions into the APDUs (Application Protocol Data Units) that
+//! the Radix Ledger app expects when signing them. The first APDU of a command
+//! carries the derivation path of the signing key and the compiled intent
+//! follows in chunks, each in its own APDU, where the class byte tells the app
+//! whether more chunks follow.
+
+use radix_transactions::prelude::*;
+
+use crate::functions::intent::hash_from_payload;
+use crate::functions::key_derivation::DerivationPath;
+use crate::models::transaction_hash::{PayloadHashError, TransactionHash};
+
+/// The class byte of the first APDU of a command.
+pub const LEDGER_CLA: u8 = 0xAA;
+/// The class byte of the APDUs of a command that are followed by more data.
+pub const LEDGER_CLA_CONTINUATION: u8 = 0xAB;
+/// The class byte of the APDU that carries the last chunk of data.
+pub const LEDGER_CLA_LAST_DATA: u8 = 0xAC;
+
+/// The instruction that signs a transaction with an Ed25519 key.
+pub const LEDGER_INS_SIGN_TX_ED25519: u8 = 0x41;
+/// The instruction that signs a transaction with a Secp256k1 key.
+pub const LEDGER_INS_SIGN_TX_SECP256K1: u8 = 0x51;
+
+/// The maximum length of the data of a single APDU.
+pub const LEDGER_MAX_APDU_DATA_LENGTH: usize = 255;
+/// The maximum number of components of a derivation path that the app
+/// accepts.
+pub const LEDGER_MAX_DERIVATION_PATH_LENGTH: usize = 10;
+
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerApdu {
+    pub cla: u8,
+    pub ins: u8,
+    pub p1: u8,
+    pub p2: u8,
+    pub data: Vec<u8>,
+}
+
+impl LedgerApdu {
+    /// Encodes the APDU as the header bytes followed by the length of the data
+    /// and the data itself.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.data.len() + 5);
+        bytes.extend([
+            self.cla,
+            self.ins,
+            self.p1,
+            self.p2,
+            self.data.len() as u8,
+        ]);
+        bytes.extend(&self.data);
+        bytes
+    }
+}
+
+/// The APDUs to send to the Ledger app to sign a transaction along with the
+/// hashes that the app displays, which companion apps are expected to show as
+/// well so that the user can compare them.
+pub struct LedgerSigningPayload {
+    /// The APDUs in the order they must be sent in.
+    pub apdus: Vec<LedgerApdu>,
+    /// The intent hash of the transaction which is what the app signs.
+    pub intent_hash: TransactionHash,
+}
+
+/// Builds the APDUs that sign the compiled intent with the key at the
+/// derivation path. Ed25519 keys are derived through SLIP-10 which only
+/// supports hardened derivation so every component of their path must be
+/// hardened.
+pub fn ledger_signing_payload(
+    compiled_intent: &[u8],
+    derivation_path: &DerivationPath,
+    curve: CurveType,
+) -> Result<LedgerSigningPayload, LedgerPayloadError> {
+    let intent_hash = hash_from_payload(compiled_intent)?;
+    let ins = match curve {
+        CurveType::Ed25519 => LEDGER_INS_SIGN_TX_ED25519,
+        CurveType::Secp256k1 => LEDGER_INS_SIGN_TX_SECP256K1,
+    };
+
+    let mut apdus = vec![LedgerApdu {
+        cla: LEDGER_CLA,
+        ins,
+        p1: 0,
+        p2: 0,
+        data: encode_derivation_path(derivation_path, curve)?,
+    }];
+    let chunks = compiled_intent
+        .chunks(LEDGER_MAX_APDU_DATA_LENGTH)
+        .collect::<Vec<_>>();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let cla = if index == chunks.len() - 1 {
+            LEDGER_CLA_LAST_DATA
+        } else {
+            LEDGER_CLA_CONTINUATION
+        };
+        apdus.push(LedgerApdu {
+            cla,
+            ins,
+            p1: 0,
+            p2: 0,
+            data: chunk.to_vec(),
+        });
+    }
+
+    Ok(LedgerSigningPayload { apdus, intent_hash })
+}
+
+/// Encodes the derivation path as the number of components followed by each
+/// component as a big-endian integer with the hardening bit set for hardened
+/// components.
+pub fn encode_derivation_path(
+    derivation_path: &DerivationPath,
+    curve: CurveType,
+) -> Result<Vec<u8>, LedgerPayloadError> {
+    let components = &derivation_path.0;
+    if components.len() > LEDGER_MAX_DERIVATION_PATH_LENGTH {
+        return Err(LedgerPayloadError::DerivationPathTooLong {
+            length: components.len(),
+        });
+    }
+
+    let mut bytes = Vec::with_capacity(components.len() * 4 + 1);
+    bytes.push(components.len() as u8);
+    for component in components {
+        if matches!(curve, CurveType::Ed25519) && !component.is_hardened {
+            return Err(LedgerPayloadError::NonHardenedEd25519Derivation {
+                index: component.index,
+            });
+        }
+        let index = if component.is_hardened {
+            component.index | HARDENED_OFFSET
+        } else {
+            component.index
+        };
+        bytes.extend(index.to_be_bytes());
+    }
+    Ok(bytes)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LedgerPayloadError {
+    PayloadHashError(PayloadHashError),
+    DerivationPathTooLong { length: usize },
+    NonHardenedEd25519Derivation { index: u32 },
+}
+
+impl From<PayloadHashError> for LedgerPayloadError {
+    fn from(value: PayloadHashError) -> Self {
+        Self::PayloadHashError(value)
+    }
+}
@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::*;
+use radix_transactions::validation::*;
+
+use crate::utils::manifest_value_depth;
+
+/// The limits that a compiled payload is checked against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompilationLimits {
+    /// The maximum size of the compiled payload in bytes.
+    pub max_payload_size: usize,
+    /// The maximum number of instructions in the payload.
+    pub max_instructions: usize,
+    /// The maximum SBOR depth of the compiled payload.
+    pub max_depth: usize,
+}
+
+impl CompilationLimits {
+    /// The limits enforced by the given network. The network doesn't limit
+    /// the number of instructions directly, they're bounded by the payload
+    /// size and the execution cost limits instead.
+    pub fn for_network(network_id: u8) -> Self {
+        Self {
+            max_payload_size: ValidationConfig::default(network_id)
+                .max_notarized_payload_size,
+            max_instructions: usize::MAX,
+            max_depth: MANIFEST_SBOR_V1_MAX_DEPTH,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Limit {
+    PayloadSize(usize),
+    InstructionCount(usize),
+    Depth(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LimitExceeded {
+    /// The limit that was exceeded along with its maximum.
+    pub limit: Limit,
+    pub actual: usize,
+}
+
+/// Checks a payload compiled by the toolkit against the limits. The depth is
+/// measured by decoding the payload with a depth limit of at least the
+/// maximum depth. Payloads compiled by the toolkit only fail to decode when
+/// they're deeper than that, so a payload that can't be decoded is reported
+/// as exceeding the depth limit with an `actual` depth of one more than the
+/// depth limit of the decoder, the lowest depth that it could have.
+pub fn check_limits(
+    payload: &[u8],
+    instruction_count: usize,
+    limits: &CompilationLimits,
+) -> Result<(), LimitExceeded> {
+    if payload.len() > limits.max_payload_size {
+        return Err(LimitExceeded {
+            limit: Limit::PayloadSize(limits.max_payload_size),
+            actual: payload.len(),
+        });
+    }
+
+    if instruction_count > limits.max_instructions {
+        return Err(LimitExceeded {
+            limit: Limit::InstructionCount(limits.max_instructions),
+            actual: instruction_count,
+        });
+    }
+
+    let depth_limit = limits.max_depth.max(MANIFEST_SBOR_V1_MAX_DEPTH);
+    let depth =
+        manifest_decode_with_depth_limit::<ManifestValue>(payload, depth_limit)
+            .map(|value| manifest_value_depth(&value))
+            .unwrap_or(depth_limit.saturating_add(1));
+    if depth > limits.max_depth {
+        return Err(LimitExceeded {
+            limit: Limit::Depth(limits.max_depth),
+            actual: depth,
+        });
+    }
+
+    Ok(())
+}
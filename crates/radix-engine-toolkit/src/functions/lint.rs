@@ -0,0 +1,301 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Lints manifests against a configurable set of rules that catch common
+//! mistakes which static validation does not reject, such as forgetting to
+//! lock a fee or leaving a bucket on the worktop.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::contains;
+use crate::sbor::indexed_manifest_value::*;
+use crate::statics::*;
+use crate::transaction_types::*;
+use crate::utils::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LintRule {
+    /// The manifest does not lock a fee.
+    MissingLockFee,
+    /// Resources are deposited into an account before any instruction that
+    /// could have put resources on the worktop.
+    DepositWithoutWithdrawal,
+    /// A bucket is taken from the worktop and is never returned, burned, or
+    /// passed to an invocation.
+    DanglingBucket,
+    /// A proof is created and is never dropped, pushed to the auth zone, or
+    /// passed to an invocation.
+    UndroppedProof,
+    /// A method is called that wallets reserve for themselves, such as
+    /// securifying an account or calling an access controller.
+    ReservedBlueprintCall,
+    /// An address reservation is passed to more than one invocation.
+    DuplicateAddressReservationUse,
+}
+
+impl LintRule {
+    pub const ALL: [Self; 6] = [
+        Self::MissingLockFee,
+        Self::DepositWithoutWithdrawal,
+        Self::DanglingBucket,
+        Self::UndroppedProof,
+        Self::ReservedBlueprintCall,
+        Self::DuplicateAddressReservationUse,
+    ];
+
+    pub fn default_severity(&self) -> LintSeverity {
+        match self {
+            Self::MissingLockFee
+            | Self::DepositWithoutWithdrawal
+            | Self::ReservedBlueprintCall => LintSeverity::Warning,
+            Self::DanglingBucket | Self::DuplicateAddressReservationUse => {
+                LintSeverity::Error
+            }
+            Self::UndroppedProof => LintSeverity::Info,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LintSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintConfig {
+    /// The rules to lint the manifest against and the severity that their
+    /// diagnostics are reported with. Rules that are not in the map are not
+    /// checked.
+    pub rules: IndexMap<LintRule, LintSeverity>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            rules: LintRule::ALL
+                .into_iter()
+                .map(|rule| (rule, rule.default_severity()))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    pub rule: LintRule,
+    pub severity: LintSeverity,
+    /// The index of the instruction that the diagnostic is about or [`None`]
+    /// if it is about the manifest as a whole.
+    pub instruction_index: Option<usize>,
+    pub message: String,
+}
+
+/// Lints the instructions against the rules in the config. The diagnostics
+/// are returned ordered by instruction index with the diagnostics about the
+/// manifest as a whole coming first.
+pub fn lint(
+    instructions: &[InstructionV1],
+    config: &LintConfig,
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut report =
+        |rule: LintRule, instruction_index: Option<usize>, message: String| {
+            if let Some(severity) = config.rules.get(&rule) {
+                diagnostics.push(LintDiagnostic {
+                    rule,
+                    severity: *severity,
+                    instruction_index,
+                    message,
+                })
+            }
+        };
+
+    // Buckets and proofs are allocated sequential ids in the order of the
+    // instructions that create them.
+    let mut next_bucket_id = 0u32;
+    let mut next_proof_id = 0u32;
+    let mut open_buckets = IndexMap::<ManifestBucket, usize>::new();
+    let mut open_proofs = IndexMap::<ManifestProof, usize>::new();
+    let mut used_address_reservations =
+        IndexSet::<ManifestAddressReservation>::new();
+    let mut locks_fee = false;
+    let mut worktop_may_have_resources = false;
+
+    for (instruction_index, instruction) in instructions.iter().enumerate() {
+        match instruction {
+            InstructionV1::TakeFromWorktop { .. }
+            | InstructionV1::TakeNonFungiblesFromWorktop { .. }
+            | InstructionV1::TakeAllFromWorktop { .. } => {
+                open_buckets
+                    .insert(ManifestBucket(next_bucket_id), instruction_index);
+                next_bucket_id += 1;
+            }
+            InstructionV1::ReturnToWorktop { bucket_id }
+            | InstructionV1::BurnResource { bucket_id } => {
+                open_buckets.shift_remove(bucket_id);
+            }
+            InstructionV1::PopFromAuthZone
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
+            | InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. }
+            | InstructionV1::CloneProof { .. } => {
+                open_proofs
+                    .insert(ManifestProof(next_proof_id), instruction_index);
+                next_proof_id += 1;
+            }
+            InstructionV1::PushToAuthZone { proof_id }
+            | InstructionV1::DropProof { proof_id } => {
+                open_proofs.shift_remove(proof_id);
+            }
+            InstructionV1::DropNamedProofs | InstructionV1::DropAllProofs => {
+                open_proofs.clear();
+            }
+            InstructionV1::CallFunction { args, .. }
+            | InstructionV1::CallMethod { args, .. }
+            | InstructionV1::CallRoyaltyMethod { args, .. }
+            | InstructionV1::CallMetadataMethod { args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { args, .. }
+            | InstructionV1::CallDirectVaultMethod { args, .. } => {
+                let args = IndexedManifestValue::from_typed(args);
+                for bucket in args.buckets() {
+                    open_buckets.shift_remove(bucket);
+                }
+                for proof in args.proofs() {
+                    open_proofs.shift_remove(proof);
+                }
+                for address_reservation in args.address_reservations() {
+                    if !used_address_reservations.insert(*address_reservation) {
+                        report(
+                            LintRule::DuplicateAddressReservationUse,
+                            Some(instruction_index),
+                            format!(
+                                "Address reservation {} is used more than once",
+                                address_reservation.0
+                            ),
+                        )
+                    }
+                }
+            }
+            InstructionV1::AllocateGlobalAddress { .. }
+            | InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs => {}
+        }
+
+        match instruction {
+            InstructionV1::CallMethod { method_name, .. }
+                if contains!(
+                    method_name => [
+                        ACCOUNT_LOCK_FEE_IDENT,
+                        ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+                    ]
+                ) =>
+            {
+                locks_fee = true;
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            } if is_account(address)
+                && ACCOUNT_DEPOSIT_METHODS.contains(method_name) =>
+            {
+                if !worktop_may_have_resources {
+                    report(
+                        LintRule::DepositWithoutWithdrawal,
+                        Some(instruction_index),
+                        "Resources are deposited before any instruction that \
+                         could have put resources on the worktop"
+                            .to_owned(),
+                    )
+                }
+            }
+            InstructionV1::CallMethod { method_name, .. } => {
+                locks_fee |= contains!(
+                    method_name => [
+                        ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT,
+                        ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT,
+                    ]
+                );
+                worktop_may_have_resources = true;
+            }
+            InstructionV1::CallFunction { .. }
+            | InstructionV1::CallRoyaltyMethod { .. }
+            | InstructionV1::CallDirectVaultMethod { .. } => {
+                worktop_may_have_resources = true;
+            }
+            _ => {}
+        }
+
+        // Locking fees is reserved for the wallet but is already covered by
+        // the missing lock fee rule so it is not reported here.
+        let mut reserved_instructions_detector =
+            ReservedInstructionsDetector::default();
+        reserved_instructions_detector
+            .on_instruction(instruction, instruction_index);
+        for reserved_instruction in reserved_instructions_detector
+            .output()
+            .into_iter()
+            .filter(|instruction| {
+                *instruction != ReservedInstruction::AccountLockFee
+            })
+        {
+            report(
+                LintRule::ReservedBlueprintCall,
+                Some(instruction_index),
+                format!(
+                    "The instruction is reserved: {reserved_instruction:?}"
+                ),
+            )
+        }
+    }
+
+    if !locks_fee {
+        report(
+            LintRule::MissingLockFee,
+            None,
+            "The manifest does not lock a fee".to_owned(),
+        )
+    }
+    for (bucket, instruction_index) in open_buckets {
+        report(
+            LintRule::DanglingBucket,
+            Some(instruction_index),
+            format!("Bucket {} is never consumed", bucket.0),
+        )
+    }
+    for (proof, instruction_index) in open_proofs {
+        report(
+            LintRule::UndroppedProof,
+            Some(instruction_index),
+            format!("Proof {} is never dropped", proof.0),
+        )
+    }
+
+    diagnostics.sort_by_key(|diagnostic| diagnostic.instruction_index);
+    diagnostics
+}
@@ -19,12 +19,20 @@ use radix_common::prelude::*;
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_transactions::errors::*;
+use radix_transactions::manifest::{CompileError, MockBlobProvider};
 use radix_transactions::prelude::*;
 use radix_transactions::validation::*;
 
 use radix_engine::transaction::*;
 
+use crate::functions::instructions::{
+    extract_addresses, extract_addresses_with_usage, instruction_kind,
+};
+use crate::models::node_id::TypedNodeId;
+use crate::sbor::indexed_manifest_value::IndexedManifestValue;
+use crate::statics::WELL_KNOWN_ADDRESSES;
 use crate::transaction_types::*;
+use crate::utils::{is_account, manifest_value_depth, to_manifest_type};
 
 pub fn hash(manifest: &TransactionManifestV1) -> Result<Hash, EncodeError> {
     compile(manifest).map(scrypto::prelude::hash)
@@ -36,6 +44,149 @@ pub fn compile(
     manifest_encode(manifest)
 }
 
+/// The location of an instruction in a manifest string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ManifestSourceLocation {
+    pub instruction_index: usize,
+    /// The 1-based line and column that the instruction starts at.
+    pub line: usize,
+    pub column: usize,
+}
+
+/// An error encountered when compiling a manifest string along with the
+/// location of the instruction that caused it, if it could be located.
+#[derive(Debug)]
+pub struct LocatedCompileError {
+    pub error: CompileError,
+    pub location: Option<ManifestSourceLocation>,
+}
+
+/// Compiles a manifest string into a manifest. If compilation fails then the
+/// instruction that caused it is located through [`locate_compile_error`].
+pub fn compile_string(
+    manifest_string: &str,
+    network_definition: &NetworkDefinition,
+) -> Result<TransactionManifestV1, LocatedCompileError> {
+    radix_transactions::manifest::compile(
+        manifest_string,
+        network_definition,
+        MockBlobProvider::new(),
+    )
+    .map_err(|error| LocatedCompileError {
+        error,
+        location: locate_compile_error(manifest_string, network_definition),
+    })
+}
+
+/// Finds the first instruction of a manifest string that fails to compile.
+/// The errors of the manifest compiler do not carry the location of the
+/// instruction that caused them, so the manifest is split into statements
+/// and the shortest prefix of statements that fails to compile is searched
+/// for. [`None`] is returned if the manifest compiles.
+pub fn locate_compile_error(
+    manifest_string: &str,
+    network_definition: &NetworkDefinition,
+) -> Option<ManifestSourceLocation> {
+    let statements = manifest_statements(manifest_string);
+    let prefix_compiles = |count: usize| {
+        let end = match count {
+            0 => 0,
+            count => statements[count - 1].1,
+        };
+        radix_transactions::manifest::compile(
+            &manifest_string[..end],
+            network_definition,
+            MockBlobProvider::new(),
+        )
+        .is_ok()
+    };
+
+    if prefix_compiles(statements.len()) {
+        return None;
+    }
+
+    // A prefix that fails to compile can't be made to compile by adding more
+    // statements to it, so the first failing statement is binary searched.
+    let (mut low, mut high) = (0, statements.len());
+    while low + 1 < high {
+        let middle = (low + high) / 2;
+        if prefix_compiles(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    let instruction_index = high.saturating_sub(1);
+
+    let start = statements
+        .get(instruction_index)
+        .map(|(start, _)| *start)
+        .unwrap_or(manifest_string.len());
+    let preceding = &manifest_string[..start];
+    let line = preceding.matches('\n').count() + 1;
+    let column = preceding
+        .rsplit('\n')
+        .next()
+        .map(|line| line.chars().count())
+        .unwrap_or_default()
+        + 1;
+
+    Some(ManifestSourceLocation {
+        instruction_index,
+        line,
+        column,
+    })
+}
+
+/// Splits a manifest string into the byte ranges of its statements. Each
+/// range starts at the first character of the statement and ends after its
+/// terminating semicolon, or at the end of the string for a trailing
+/// statement that's not terminated. Semicolons in strings and comments do not
+/// terminate statements.
+fn manifest_statements(manifest_string: &str) -> Vec<(usize, usize)> {
+    let mut statements = vec![];
+    let mut start = None;
+    let mut in_string = false;
+    let mut in_comment = false;
+    let mut escaped = false;
+
+    for (index, character) in manifest_string.char_indices() {
+        if in_comment {
+            in_comment = character != '\n';
+            continue;
+        }
+        if in_string {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match character {
+            '#' => in_comment = true,
+            character if character.is_whitespace() => {}
+            ';' => {
+                statements.push((start.unwrap_or(index), index + 1));
+                start = None;
+            }
+            '"' => {
+                in_string = true;
+                start.get_or_insert(index);
+            }
+            _ => {
+                start.get_or_insert(index);
+            }
+        }
+    }
+    if let Some(start) = start {
+        statements.push((start, manifest_string.len()));
+    }
+
+    statements
+}
+
 pub fn decompile<T>(
     payload_bytes: T,
 ) -> Result<TransactionManifestV1, DecodeError>
@@ -53,6 +204,142 @@ pub fn statically_validate(
     )
 }
 
+/// Returns the hashes of all of the blobs referenced by the instructions of the
+/// manifest in the order they are first referenced.
+pub fn blob_references(manifest: &TransactionManifestV1) -> IndexSet<Hash> {
+    manifest
+        .instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            InstructionV1::CallFunction { args, .. }
+            | InstructionV1::CallMethod { args, .. }
+            | InstructionV1::CallRoyaltyMethod { args, .. }
+            | InstructionV1::CallMetadataMethod { args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { args, .. }
+            | InstructionV1::CallDirectVaultMethod { args, .. } => Some(args),
+            _ => None,
+        })
+        .flat_map(|args| {
+            IndexedManifestValue::from_manifest_value(args)
+                .blobs()
+                .iter()
+                .map(|blob| Hash(blob.0))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Checks that every blob referenced by the instructions of the manifest can
+/// be resolved from the blobs of the manifest.
+pub fn validate_blob_references(
+    manifest: &TransactionManifestV1,
+) -> Result<(), BlobReferenceError> {
+    match blob_references(manifest)
+        .into_iter()
+        .find(|hash| !manifest.blobs.contains_key(hash))
+    {
+        Some(hash) => Err(BlobReferenceError::BlobNotFound(hash)),
+        None => Ok(()),
+    }
+}
+
+/// Adds the blob to the manifest returning its hash. Adding a blob that the
+/// manifest already has leaves the manifest unchanged.
+pub fn add_blob(manifest: &mut TransactionManifestV1, blob: Vec<u8>) -> Hash {
+    let hash = scrypto::prelude::hash(&blob);
+    manifest.blobs.entry(hash).or_insert(blob);
+    hash
+}
+
+pub fn get_blob<'m>(
+    manifest: &'m TransactionManifestV1,
+    hash: &Hash,
+) -> Option<&'m Vec<u8>> {
+    manifest.blobs.get(hash)
+}
+
+/// Returns the addresses in the manifest that do not carry their meaning over
+/// to other networks. The node ids of well-known entities and of virtual
+/// accounts and identities are the same on all networks, so only re-encoding
+/// them with another network's HRP is needed for them to be valid there. All
+/// other addresses belong to entities that were allocated on the network of
+/// the manifest and will either not exist or refer to a different entity on
+/// another network.
+pub fn network_bound_addresses(
+    manifest: &TransactionManifestV1,
+) -> IndexSet<TypedNodeId> {
+    extract_addresses_with_usage(&manifest.instructions)
+        .into_keys()
+        .filter(|address| {
+            !address.entity_type().is_global_virtual()
+                && !WELL_KNOWN_ADDRESSES.contains(address.as_node_id())
+        })
+        .collect()
+}
+
+/// Computes statistics on the contents of the manifest which can be checked
+/// against the limits of the network before the manifest is compiled.
+pub fn statistics(manifest: &TransactionManifestV1) -> ManifestStatistics {
+    let mut instruction_counts = IndexMap::<&'static str, usize>::new();
+    let mut max_value_depth = 0;
+    for instruction in manifest.instructions.iter() {
+        *instruction_counts
+            .entry(instruction_kind(instruction))
+            .or_default() += 1;
+
+        let args = match instruction {
+            InstructionV1::CallFunction { args, .. }
+            | InstructionV1::CallMethod { args, .. }
+            | InstructionV1::CallRoyaltyMethod { args, .. }
+            | InstructionV1::CallMetadataMethod { args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { args, .. }
+            | InstructionV1::CallDirectVaultMethod { args, .. } => args,
+            _ => continue,
+        };
+        max_value_depth = max_value_depth.max(manifest_value_depth(args));
+    }
+
+    let (addresses, _) = extract_addresses(&manifest.instructions);
+    let count_addresses = |predicate: fn(&EntityType) -> bool| {
+        addresses
+            .iter()
+            .filter(|address| predicate(&address.entity_type()))
+            .count()
+    };
+
+    ManifestStatistics {
+        instruction_counts,
+        component_count: count_addresses(EntityType::is_global_component),
+        package_count: count_addresses(EntityType::is_global_package),
+        resource_count: count_addresses(EntityType::is_global_resource_manager),
+        max_value_depth,
+        blob_count: manifest.blobs.len(),
+        blobs_size: manifest.blobs.values().map(Vec::len).sum(),
+        compiled_size: compile(manifest).ok().map(|compiled| compiled.len()),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestStatistics {
+    /// The number of instructions of each kind keyed by the name of the kind,
+    /// e.g., `CallMethod`, in the order that the kinds first appear in.
+    pub instruction_counts: IndexMap<&'static str, usize>,
+    /// The number of distinct static component addresses in the manifest.
+    pub component_count: usize,
+    /// The number of distinct static package addresses in the manifest.
+    pub package_count: usize,
+    /// The number of distinct static resource addresses in the manifest.
+    pub resource_count: usize,
+    /// The depth of the most deeply nested arguments of an invocation.
+    pub max_value_depth: usize,
+    pub blob_count: usize,
+    /// The total size of the blobs in bytes.
+    pub blobs_size: usize,
+    /// The size of the compiled manifest in bytes or [`None`] if the manifest
+    /// can't be compiled, e.g., if its values are nested too deeply.
+    pub compiled_size: Option<usize>,
+}
+
 pub fn modify(
     manifest: &TransactionManifestV1,
     mut modifications: TransactionManifestModifications,
@@ -221,17 +508,288 @@ pub fn modify(
     })
 }
 
+/// Makes the account the fee payer of the manifest. The call in the manifest
+/// that locks a fee is replaced in place with one that locks the amount from
+/// the account, keeping whether the fee is contingent or not. Withdrawals made
+/// by that call from another account are kept as a separate withdrawal that
+/// follows it. Otherwise, a call that locks the fee is inserted at the start
+/// of the manifest. Manifests with more than one call that locks a fee are
+/// rejected as it's ambiguous which of them to replace. The rest of the
+/// instructions are left untouched as neither call creates buckets or proofs.
+pub fn set_fee_payer(
+    manifest: &TransactionManifestV1,
+    account: ComponentAddress,
+    amount: Decimal,
+) -> Result<TransactionManifestV1, ManifestModificationError> {
+    let mut fee_locks = Vec::new();
+    for (index, instruction) in manifest.instructions.iter().enumerate() {
+        if let Some(fee_instructions) =
+            replace_fee_lock(instruction, account, amount)?
+        {
+            fee_locks.push((index, fee_instructions));
+        }
+    }
+
+    let mut instructions = manifest.instructions.clone();
+    match fee_locks.len() {
+        0 => {
+            instructions.insert(0, lock_fee(account, amount));
+        }
+        1 => {
+            let (index, fee_instructions) = fee_locks.remove(0);
+            instructions.splice(index..=index, fee_instructions);
+        }
+        _ => {
+            return Err(ManifestModificationError::MultipleFeeLocks {
+                instruction_indices: fee_locks
+                    .into_iter()
+                    .map(|(index, _)| index)
+                    .collect(),
+            })
+        }
+    }
+
+    Ok(TransactionManifestV1 {
+        instructions,
+        blobs: manifest.blobs.clone(),
+    })
+}
+
+fn lock_fee(account: ComponentAddress, amount: Decimal) -> InstructionV1 {
+    InstructionV1::CallMethod {
+        address: DynamicGlobalAddress::Static(account.into()),
+        method_name: ACCOUNT_LOCK_FEE_IDENT.to_owned(),
+        args: to_manifest_value_and_unwrap!(&AccountLockFeeInput { amount }),
+    }
+}
+
+/// The instructions that replace the instruction if it locks a fee, or [`None`]
+/// if it doesn't.
+fn replace_fee_lock(
+    instruction: &InstructionV1,
+    account: ComponentAddress,
+    amount: Decimal,
+) -> Result<Option<Vec<InstructionV1>>, ManifestModificationError> {
+    let InstructionV1::CallMethod {
+        address: DynamicGlobalAddress::Static(address),
+        method_name,
+        args,
+    } = instruction
+    else {
+        return Ok(None);
+    };
+    if !is_account(address) {
+        return Ok(None);
+    }
+
+    let fee_instructions = match method_name.as_str() {
+        ACCOUNT_LOCK_FEE_IDENT => vec![lock_fee(account, amount)],
+        ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+            vec![InstructionV1::CallMethod {
+                address: DynamicGlobalAddress::Static(account.into()),
+                method_name: ACCOUNT_LOCK_CONTINGENT_FEE_IDENT.to_owned(),
+                args: to_manifest_value_and_unwrap!(
+                    &AccountLockContingentFeeInput { amount }
+                ),
+            }]
+        }
+        ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+            let AccountLockFeeAndWithdrawInput {
+                resource_address,
+                amount: withdraw_amount,
+                ..
+            } = decode_arguments(method_name, args)?;
+            if *address == GlobalAddress::from(account) {
+                vec![InstructionV1::CallMethod {
+                    address: DynamicGlobalAddress::Static(*address),
+                    method_name: method_name.to_owned(),
+                    args: to_manifest_value_and_unwrap!(
+                        &AccountLockFeeAndWithdrawInput {
+                            amount_to_lock: amount,
+                            resource_address,
+                            amount: withdraw_amount,
+                        }
+                    ),
+                }]
+            } else {
+                vec![
+                    lock_fee(account, amount),
+                    InstructionV1::CallMethod {
+                        address: DynamicGlobalAddress::Static(*address),
+                        method_name: ACCOUNT_WITHDRAW_IDENT.to_owned(),
+                        args: to_manifest_value_and_unwrap!(
+                            &AccountWithdrawInput {
+                                resource_address,
+                                amount: withdraw_amount,
+                            }
+                        ),
+                    },
+                ]
+            }
+        }
+        ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+            let AccountLockFeeAndWithdrawNonFungiblesInput {
+                resource_address,
+                ids,
+                ..
+            } = decode_arguments(method_name, args)?;
+            if *address == GlobalAddress::from(account) {
+                vec![InstructionV1::CallMethod {
+                    address: DynamicGlobalAddress::Static(*address),
+                    method_name: method_name.to_owned(),
+                    args: to_manifest_value_and_unwrap!(
+                        &AccountLockFeeAndWithdrawNonFungiblesInput {
+                            amount_to_lock: amount,
+                            resource_address,
+                            ids,
+                        }
+                    ),
+                }]
+            } else {
+                vec![
+                    lock_fee(account, amount),
+                    InstructionV1::CallMethod {
+                        address: DynamicGlobalAddress::Static(*address),
+                        method_name: ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT
+                            .to_owned(),
+                        args: to_manifest_value_and_unwrap!(
+                            &AccountWithdrawNonFungiblesInput {
+                                resource_address,
+                                ids,
+                            }
+                        ),
+                    },
+                ]
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(fee_instructions))
+}
+
+fn decode_arguments<T: ManifestDecode>(
+    method_name: &str,
+    args: &ManifestValue,
+) -> Result<T, ManifestModificationError> {
+    to_manifest_type(args).ok_or_else(|| {
+        ManifestModificationError::InvalidArguments {
+            method_name: method_name.to_owned(),
+            arguments: args.clone(),
+        }
+    })
+}
+
 pub fn summary(manifest: &TransactionManifestV1) -> ManifestSummary {
     crate::transaction_types::summary(manifest)
 }
 
-pub fn execution_summary(
+pub fn simple_transfer(
+    manifest: &TransactionManifestV1,
+) -> Option<SimpleTransfer> {
+    crate::transaction_types::simple_transfer(&manifest.instructions)
+}
+
+pub fn simple_transfer_or_summary(
+    manifest: &TransactionManifestV1,
+) -> SimpleTransferOrSummary {
+    crate::transaction_types::simple_transfer_or_summary(manifest)
+}
+
+pub fn classify(
+    manifest: &TransactionManifestV1,
+) -> Vec<ManifestClassification> {
+    crate::transaction_types::classify_manifest(manifest)
+}
+
+pub fn trusted_worktop(
+    manifest: &TransactionManifestV1,
+) -> Vec<TrustedWorktopInstruction> {
+    crate::transaction_types::trusted_worktop(manifest)
+}
+
+pub fn proof_lineage(manifest: &TransactionManifestV1) -> Vec<ProofLineage> {
+    crate::transaction_types::proof_lineage(manifest)
+}
+
+pub fn account_interactions(
+    manifest: &TransactionManifestV1,
+) -> AccountInteractions {
+    crate::transaction_types::account_interactions(manifest)
+}
+
+/// Determines which of the given public keys are required to sign the
+/// transaction based on the accounts and identities whose privileged methods
+/// are called in the manifest. Only the public keys of virtual accounts and
+/// identities can be determined; securified entities are not matched.
+pub fn required_signers(
     manifest: &TransactionManifestV1,
-    receipt: &TransactionReceipt,
+    public_keys: &[PublicKey],
+) -> Vec<PublicKey> {
+    let ManifestSummary {
+        accounts_requiring_auth,
+        identities_requiring_auth,
+        ..
+    } = crate::transaction_types::summary(manifest);
+
+    public_keys
+        .iter()
+        .filter(|public_key| {
+            accounts_requiring_auth.contains(
+                &ComponentAddress::virtual_account_from_public_key(*public_key),
+            ) || identities_requiring_auth.contains(
+                &ComponentAddress::virtual_identity_from_public_key(
+                    *public_key,
+                ),
+            )
+        })
+        .copied()
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+pub fn detect_reserved_instructions(
+    manifest: &TransactionManifestV1,
+) -> Vec<SensitiveInstruction> {
+    crate::transaction_types::detect_reserved_instructions(manifest)
+}
+
+pub fn security_impacts(
+    manifest: &TransactionManifestV1,
+) -> Vec<SecurityImpact> {
+    crate::transaction_types::security_impacts(manifest)
+}
+
+pub fn execution_summary<'r>(
+    manifest: &TransactionManifestV1,
+    receipt: impl Into<ExecutionReceipt<'r>>,
 ) -> Result<ExecutionSummary, TransactionTypesError> {
     crate::transaction_types::execution_summary(manifest, receipt)
 }
 
+pub fn resource_movements_graph<'r>(
+    manifest: &TransactionManifestV1,
+    receipt: impl Into<ExecutionReceipt<'r>>,
+) -> Result<ResourceMovementsGraph, TransactionTypesError> {
+    crate::transaction_types::resource_movements_graph(manifest, receipt)
+}
+
+pub fn execution_summary_with_deposit_settings<'r>(
+    manifest: &TransactionManifestV1,
+    receipt: impl Into<ExecutionReceipt<'r>>,
+    account_deposit_settings: &IndexMap<
+        ComponentAddress,
+        AccountDepositSettings,
+    >,
+) -> Result<ExecutionSummary, TransactionTypesError> {
+    crate::transaction_types::execution_summary_with_deposit_settings(
+        manifest,
+        receipt,
+        account_deposit_settings,
+    )
+}
+
 #[derive(Clone, Debug)]
 pub struct TransactionManifestModifications {
     /// The [`ComponentAddress`]es of the access controllers to add create
@@ -292,4 +850,12 @@ pub enum ManifestModificationError {
         value: ManifestValue,
         error: EncodeError,
     },
+    MultipleFeeLocks {
+        instruction_indices: Vec<usize>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlobReferenceError {
+    BlobNotFound(Hash),
 }
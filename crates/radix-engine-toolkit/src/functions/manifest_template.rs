@@ -0,0 +1,193 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Manifest templates are manifest strings in which values are replaced by
+//! named placeholders of the form `${name}`. A template declares the kind of
+//! value expected for each of its placeholders and is instantiated by
+//! substituting values written in the manifest syntax for the placeholders.
+//! Each value must be a single value of the declared kind so that the values
+//! can not change the structure of the manifest.
+
+use radix_transactions::manifest::ast::Value;
+use radix_transactions::manifest::lexer::tokenize;
+use radix_transactions::manifest::parser::{Parser, PARSER_MAX_DEPTH};
+use scrypto::prelude::*;
+
+/// The kinds of values as they're written in the manifest syntax.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ManifestAstValueKind {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    String,
+    Enum,
+    Array,
+    Tuple,
+    Map,
+    Address,
+    Bucket,
+    Proof,
+    Expression,
+    Blob,
+    Decimal,
+    PreciseDecimal,
+    NonFungibleLocalId,
+    AddressReservation,
+    NamedAddress,
+}
+
+impl ManifestAstValueKind {
+    /// Determines the kind of the value written in the manifest syntax by
+    /// parsing it with the manifest lexer and parser. [`None`] is returned if
+    /// the value is not exactly one value, i.e., if it fails to parse, if
+    /// anything other than whitespace follows it or if it ends with a comment
+    /// that would comment out the rest of the line that it's substituted
+    /// into. Aliases such as `Some` or `Bytes` are reported as the kind of
+    /// value that they're an alias of.
+    pub fn of_value(value: &str) -> Option<Self> {
+        let tokens = tokenize(value).ok()?;
+        let end = tokens.last()?.span.end.full_index;
+        let mut parser = Parser::new(tokens, PARSER_MAX_DEPTH).ok()?;
+        let value_with_span = parser.parse_value().ok()?;
+        if !parser.is_eof()
+            || !value
+                .chars()
+                .skip(end)
+                .all(|char| matches!(char, ' ' | '\t' | '\r' | '\n'))
+        {
+            return None;
+        }
+
+        Some(match value_with_span.value {
+            Value::Bool(..) => Self::Bool,
+            Value::I8(..) => Self::I8,
+            Value::I16(..) => Self::I16,
+            Value::I32(..) => Self::I32,
+            Value::I64(..) => Self::I64,
+            Value::I128(..) => Self::I128,
+            Value::U8(..) => Self::U8,
+            Value::U16(..) => Self::U16,
+            Value::U32(..) => Self::U32,
+            Value::U64(..) => Self::U64,
+            Value::U128(..) => Self::U128,
+            Value::String(..) => Self::String,
+            Value::Enum(..)
+            | Value::Some(..)
+            | Value::None
+            | Value::Ok(..)
+            | Value::Err(..) => Self::Enum,
+            Value::Array(..) | Value::Bytes(..) => Self::Array,
+            Value::Tuple(..) | Value::NonFungibleGlobalId(..) => Self::Tuple,
+            Value::Map(..) => Self::Map,
+            Value::Address(..) => Self::Address,
+            Value::NamedAddress(..) => Self::NamedAddress,
+            Value::Bucket(..) => Self::Bucket,
+            Value::Proof(..) => Self::Proof,
+            Value::Expression(..) => Self::Expression,
+            Value::Blob(..) => Self::Blob,
+            Value::Decimal(..) => Self::Decimal,
+            Value::PreciseDecimal(..) => Self::PreciseDecimal,
+            Value::NonFungibleLocalId(..) => Self::NonFungibleLocalId,
+            Value::AddressReservation(..) => Self::AddressReservation,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestTemplate {
+    /// The manifest string with placeholders of the form `${name}`.
+    pub manifest: String,
+    /// The kind of value expected for each of the placeholders.
+    pub parameters: IndexMap<String, ManifestAstValueKind>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifestTemplateError {
+    UnterminatedPlaceholder,
+    UndeclaredParameter(String),
+    MissingArgument(String),
+    UnexpectedArgument(String),
+    /// The argument is not a single value written in the manifest syntax.
+    InvalidArgument(String),
+    ValueKindMismatch {
+        parameter: String,
+        expected: ManifestAstValueKind,
+        actual: ManifestAstValueKind,
+    },
+}
+
+/// Instantiates the template by substituting the arguments for the
+/// placeholders, returning the instantiated manifest string. Every placeholder
+/// must be declared as a parameter of the template and every parameter must be
+/// given an argument of its declared kind.
+pub fn instantiate(
+    template: &ManifestTemplate,
+    arguments: &IndexMap<String, String>,
+) -> Result<String, ManifestTemplateError> {
+    if let Some(name) = arguments
+        .keys()
+        .find(|name| !template.parameters.contains_key(*name))
+    {
+        return Err(ManifestTemplateError::UnexpectedArgument(name.clone()));
+    }
+    for (name, expected) in template.parameters.iter() {
+        let argument = arguments.get(name).ok_or_else(|| {
+            ManifestTemplateError::MissingArgument(name.clone())
+        })?;
+        let actual =
+            ManifestAstValueKind::of_value(argument).ok_or_else(|| {
+                ManifestTemplateError::InvalidArgument(name.clone())
+            })?;
+        if actual != *expected {
+            return Err(ManifestTemplateError::ValueKindMismatch {
+                parameter: name.clone(),
+                expected: *expected,
+                actual,
+            });
+        }
+    }
+
+    let mut instantiated = String::with_capacity(template.manifest.len());
+    let mut remaining = template.manifest.as_str();
+    while let Some(start) = remaining.find("${") {
+        instantiated.push_str(&remaining[..start]);
+        remaining = &remaining[start + 2..];
+
+        let end = remaining
+            .find('}')
+            .ok_or(ManifestTemplateError::UnterminatedPlaceholder)?;
+        let name = &remaining[..end];
+        if !template.parameters.contains_key(name) {
+            return Err(ManifestTemplateError::UndeclaredParameter(
+                name.to_owned(),
+            ));
+        }
+        instantiated.push_str(&arguments[name]);
+        remaining = &remaining[end + 1..];
+    }
+    instantiated.push_str(remaining);
+
+    Ok(instantiated)
+}
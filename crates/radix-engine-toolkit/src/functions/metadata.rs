@@ -0,0 +1,56 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::*;
+use scrypto::object_modules::metadata::MetadataValue;
+use scrypto::prelude::*;
+
+use crate::utils::to_manifest_type;
+
+/// Encodes the metadata value as Scrypto SBOR which is how the engine stores
+/// metadata values.
+pub fn encode_metadata_value(
+    value: &MetadataValue,
+) -> Result<Vec<u8>, EncodeError> {
+    scrypto_encode(value)
+}
+
+/// Decodes a metadata value that is either Scrypto or Manifest SBOR encoded.
+pub fn decode_metadata_value<T>(value: T) -> Result<MetadataValue, DecodeError>
+where
+    T: AsRef<[u8]>,
+{
+    let value = value.as_ref();
+    match value.first().copied() {
+        Some(MANIFEST_SBOR_V1_PAYLOAD_PREFIX) => manifest_decode(value),
+        _ => scrypto_decode(value),
+    }
+}
+
+pub fn metadata_value_to_manifest_value(
+    value: &MetadataValue,
+) -> ManifestValue {
+    to_manifest_value_and_unwrap!(value)
+}
+
+/// Converts the manifest value to a metadata value returning [`None`] if the
+/// manifest value is not a valid metadata value.
+pub fn metadata_value_from_manifest_value(
+    value: &ManifestValue,
+) -> Option<MetadataValue> {
+    to_manifest_type(value)
+}
@@ -18,16 +18,48 @@
 pub mod information;
 
 pub mod derive;
+pub mod key_derivation;
+pub mod ledger;
 
+pub mod access_controller;
+pub mod account;
+pub mod account_locker;
+pub mod annotations;
+pub mod diff;
+pub mod instruction_arguments;
 pub mod instructions;
 pub mod intent;
+pub mod limits;
+pub mod lint;
 pub mod manifest;
+pub mod manifest_template;
+pub mod metadata;
+pub mod non_fungible_data;
+pub mod non_fungible_global_id;
+pub mod non_fungible_local_id;
 pub mod notarized_transaction;
+pub mod package;
+pub mod payload;
+pub mod pool;
+pub mod preview;
+pub mod random;
+pub mod reorder;
+pub mod review;
+pub mod rola;
+pub mod royalty;
 pub mod signed_intent;
+pub mod signing;
+pub mod validator;
+pub mod wallet_interaction;
+pub mod xrd;
 
 pub mod manifest_sbor;
 pub mod scrypto_sbor;
 
+pub mod access_rule;
 pub mod address;
+pub mod address_book;
+pub mod decimal;
 pub mod events;
+pub mod explain;
 pub mod utils;
@@ -0,0 +1,173 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encodes and decodes the data of non-fungibles against the schema of the
+//! non-fungible data of their resource so that non-fungibles can be minted
+//! and displayed without hand-written SBOR. The data of a non-fungible is a
+//! struct whose fields are given by name. The fields marked as `#[mutable]`
+//! in the blueprint that defines the data are the only ones that can be
+//! updated once the non-fungible is minted.
+
+use sbor::*;
+use scrypto::prelude::*;
+
+/// The schema of the data of the non-fungibles of a resource, i.e., the type
+/// of the data in the schema and the names of its mutable fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonFungibleDataDefinition {
+    pub type_id: LocalTypeId,
+    pub schema: Schema<ScryptoCustomSchema>,
+    pub mutable_fields: IndexSet<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonFungibleDataField {
+    pub name: String,
+    pub mutable: bool,
+    /// The type of the field in the schema of the data.
+    pub type_id: LocalTypeId,
+    pub value: ScryptoValue,
+}
+
+/// Encodes the data of a non-fungible from its fields given by name. Every
+/// field of the data must be given exactly once, the order that they're
+/// given in doesn't matter.
+pub fn encode_non_fungible_data(
+    mut fields: IndexMap<String, ScryptoValue>,
+    definition: &NonFungibleDataDefinition,
+) -> Result<Vec<u8>, NonFungibleDataError> {
+    let field_types = field_types(definition)?;
+    if let Some(field_name) = fields
+        .keys()
+        .find(|field_name| !field_types.contains_key(*field_name))
+    {
+        return Err(NonFungibleDataError::UnknownField(field_name.clone()));
+    }
+
+    let value = ScryptoValue::Tuple {
+        fields: field_types
+            .keys()
+            .map(|field_name| {
+                fields.swap_remove(field_name).ok_or_else(|| {
+                    NonFungibleDataError::MissingField(field_name.clone())
+                })
+            })
+            .collect::<Result<_, _>>()?,
+    };
+    let encoded =
+        scrypto_encode(&value).map_err(NonFungibleDataError::EncodeError)?;
+    validate(&encoded, definition)?;
+    Ok(encoded)
+}
+
+/// Decodes the data of a non-fungible into its fields in the order that
+/// they're defined in along with whether they're mutable.
+pub fn decode_non_fungible_data(
+    data: &[u8],
+    definition: &NonFungibleDataDefinition,
+) -> Result<Vec<NonFungibleDataField>, NonFungibleDataError> {
+    let field_types = field_types(definition)?;
+    validate(data, definition)?;
+    let ScryptoValue::Tuple { fields } =
+        scrypto_decode(data).map_err(NonFungibleDataError::DecodeError)?
+    else {
+        return Err(NonFungibleDataError::SchemaValidationError);
+    };
+
+    Ok(field_types
+        .into_iter()
+        .zip(fields)
+        .map(|((name, type_id), value)| NonFungibleDataField {
+            mutable: definition.mutable_fields.contains(&name),
+            name,
+            type_id,
+            value,
+        })
+        .collect())
+}
+
+/// The names and types of the fields of the data in the order that they're
+/// encoded in. The mutable fields must be among them.
+fn field_types(
+    definition: &NonFungibleDataDefinition,
+) -> Result<IndexMap<String, LocalTypeId>, NonFungibleDataError> {
+    let type_kind = definition
+        .schema
+        .resolve_type_kind(definition.type_id)
+        .ok_or(NonFungibleDataError::InvalidSchema)?;
+    let type_metadata = definition
+        .schema
+        .resolve_type_metadata(definition.type_id)
+        .ok_or(NonFungibleDataError::InvalidSchema)?;
+    let (
+        TypeKind::Tuple { field_types },
+        Some(ChildNames::NamedFields(field_names)),
+    ) = (type_kind, &type_metadata.child_names)
+    else {
+        return Err(NonFungibleDataError::NotAStructWithNamedFields);
+    };
+    if field_types.len() != field_names.len() {
+        return Err(NonFungibleDataError::InvalidSchema);
+    }
+
+    let field_types = field_names
+        .iter()
+        .map(|field_name| field_name.to_string())
+        .zip(field_types.iter().copied())
+        .collect::<IndexMap<_, _>>();
+    match definition
+        .mutable_fields
+        .iter()
+        .find(|mutable_field| !field_types.contains_key(*mutable_field))
+    {
+        Some(mutable_field) => Err(NonFungibleDataError::UnknownMutableField(
+            mutable_field.clone(),
+        )),
+        None => Ok(field_types),
+    }
+}
+
+fn validate(
+    data: &[u8],
+    definition: &NonFungibleDataDefinition,
+) -> Result<(), NonFungibleDataError> {
+    validate_payload_against_schema::<ScryptoCustomExtension, _>(
+        data,
+        &definition.schema,
+        definition.type_id,
+        &(),
+        SCRYPTO_SBOR_V1_MAX_DEPTH,
+    )
+    .map_err(|_| NonFungibleDataError::SchemaValidationError)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NonFungibleDataError {
+    /// The type of the data in the schema doesn't exist.
+    InvalidSchema,
+    /// The data of non-fungibles must be a struct with named fields.
+    NotAStructWithNamedFields,
+    UnknownField(String),
+    MissingField(String),
+    /// A value was given for the field more than once.
+    DuplicateField(String),
+    /// A field that's marked as mutable doesn't exist in the data.
+    UnknownMutableField(String),
+    SchemaValidationError,
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+}
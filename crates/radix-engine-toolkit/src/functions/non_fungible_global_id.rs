@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Builds and parses non-fungible global ids, i.e., the address of a
+//! non-fungible resource and the local id of one of its non-fungibles in the
+//! `<resource_address>:<local_id>` form, such as the ids of the badges that
+//! access rules require.
+
+use scrypto::prelude::*;
+
+use crate::functions::address::{
+    decode, validate as validate_address, AddressValidationFailure,
+};
+use crate::functions::non_fungible_local_id::{
+    parse_non_fungible_local_id, NonFungibleLocalIdError,
+};
+
+/// Builds the global id of a non-fungible from the address of its resource
+/// and its local id in their string forms. The address must be that of a
+/// non-fungible resource on the given network.
+pub fn build_non_fungible_global_id(
+    resource_address: &str,
+    local_id: &str,
+    network_id: u8,
+) -> Result<NonFungibleGlobalId, NonFungibleGlobalIdError> {
+    let failures = validate_address(
+        resource_address,
+        &[EntityType::GlobalNonFungibleResourceManager],
+        network_id,
+    );
+    if !failures.is_empty() {
+        return Err(NonFungibleGlobalIdError::InvalidResourceAddress(failures));
+    }
+    let (.., data) = decode(resource_address)
+        .expect("The resource address was validated before");
+    let resource_address = ResourceAddress::try_from(data)
+        .expect("The address is of a non-fungible resource");
+
+    let local_id = parse_non_fungible_local_id(local_id)
+        .map_err(NonFungibleGlobalIdError::InvalidLocalId)?;
+    Ok(NonFungibleGlobalId::new(resource_address, local_id))
+}
+
+/// Parses the global id of a non-fungible from its string form, e.g.,
+/// `resource_rdx1...:#1#`. The address of the resource must be that of a
+/// non-fungible resource on the given network.
+pub fn parse_non_fungible_global_id(
+    string: &str,
+    network_id: u8,
+) -> Result<NonFungibleGlobalId, NonFungibleGlobalIdError> {
+    let (resource_address, local_id) =
+        string.split_once(':').ok_or_else(|| {
+            NonFungibleGlobalIdError::NoSeparator(string.to_owned())
+        })?;
+    build_non_fungible_global_id(resource_address, local_id, network_id)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NonFungibleGlobalIdError {
+    /// The string has no `:` between the resource address and the local id.
+    NoSeparator(String),
+    /// The address is invalid, on another network, or not the address of a
+    /// non-fungible resource.
+    InvalidResourceAddress(Vec<AddressValidationFailure>),
+    InvalidLocalId(NonFungibleLocalIdError),
+}
@@ -0,0 +1,211 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parses, validates, and formats non-fungible local ids in their canonical
+//! string forms: `#1#` for integer ids, `<ticket_1>` for string ids, `[c0de]`
+//! for bytes ids, and `{...}` for RUID ids. String and bytes ids are checked
+//! against the same length and character rules that the engine applies so
+//! that the ids accepted here are also accepted on ledger.
+
+use scrypto::prelude::*;
+
+/// The maximum length of the string ids in characters and of the bytes ids
+/// in bytes.
+pub const NON_FUNGIBLE_LOCAL_ID_MAX_LENGTH: usize = 64;
+
+/// Parses a non-fungible local id from its string form.
+pub fn parse_non_fungible_local_id(
+    string: &str,
+) -> Result<NonFungibleLocalId, NonFungibleLocalIdError> {
+    let (id_type, content) = split_delimiters(string)?;
+    match id_type {
+        NonFungibleIdType::Integer => {
+            if content.is_empty()
+                || !content.bytes().all(|byte| byte.is_ascii_digit())
+            {
+                return Err(NonFungibleLocalIdError::InvalidInteger(
+                    content.to_owned(),
+                ));
+            }
+            content
+                .parse::<u64>()
+                .map(NonFungibleLocalId::integer)
+                .map_err(|_| {
+                    NonFungibleLocalIdError::InvalidInteger(content.to_owned())
+                })
+        }
+        NonFungibleIdType::String => {
+            string_non_fungible_local_id(content.to_owned())
+        }
+        NonFungibleIdType::Bytes => hex::decode(content)
+            .map_err(|_| {
+                NonFungibleLocalIdError::InvalidHex(content.to_owned())
+            })
+            .and_then(bytes_non_fungible_local_id),
+        NonFungibleIdType::RUID => {
+            let groups = content.split('-').collect::<Vec<_>>();
+            if groups.len() != 4 || groups.iter().any(|group| group.len() != 16)
+            {
+                return Err(NonFungibleLocalIdError::InvalidRuid(
+                    content.to_owned(),
+                ));
+            }
+            hex::decode(groups.concat())
+                .map_err(|_| {
+                    NonFungibleLocalIdError::InvalidRuid(content.to_owned())
+                })
+                .and_then(ruid_non_fungible_local_id)
+        }
+    }
+}
+
+/// Parses a non-fungible local id from its string form and checks that it's
+/// of the expected id type if one is given.
+pub fn validate_non_fungible_local_id(
+    string: &str,
+    expected_id_type: Option<NonFungibleIdType>,
+) -> Result<NonFungibleLocalId, NonFungibleLocalIdError> {
+    let local_id = parse_non_fungible_local_id(string)?;
+    match expected_id_type {
+        Some(expected) if expected != local_id.id_type() => {
+            Err(NonFungibleLocalIdError::IdTypeMismatch {
+                expected,
+                actual: local_id.id_type(),
+            })
+        }
+        _ => Ok(local_id),
+    }
+}
+
+/// Formats the non-fungible local id in its canonical string form, e.g.,
+/// integer ids are formatted without leading zeros and bytes ids in lower
+/// case hex.
+pub fn format_non_fungible_local_id(local_id: &NonFungibleLocalId) -> String {
+    local_id.to_string()
+}
+
+/// Creates a string id from its content, i.e., without the `<` and `>`
+/// delimiters. The content must be 1 to 64 characters out of `a-z`, `A-Z`,
+/// `0-9`, and `_`.
+pub fn string_non_fungible_local_id(
+    value: String,
+) -> Result<NonFungibleLocalId, NonFungibleLocalIdError> {
+    check_length(value.len())?;
+    if let Some((index, character)) = value
+        .char_indices()
+        .find(|(_, c)| !(c.is_ascii_alphanumeric() || *c == '_'))
+    {
+        return Err(NonFungibleLocalIdError::InvalidCharacter {
+            character,
+            index,
+        });
+    }
+    NonFungibleLocalId::string(value).map_err(Into::into)
+}
+
+/// Creates a bytes id from its content. The content must be 1 to 64 bytes.
+pub fn bytes_non_fungible_local_id(
+    value: Vec<u8>,
+) -> Result<NonFungibleLocalId, NonFungibleLocalIdError> {
+    check_length(value.len())?;
+    NonFungibleLocalId::bytes(value).map_err(Into::into)
+}
+
+/// Creates a RUID id from its 32 bytes.
+pub fn ruid_non_fungible_local_id(
+    value: Vec<u8>,
+) -> Result<NonFungibleLocalId, NonFungibleLocalIdError> {
+    <[u8; 32]>::try_from(value.as_slice())
+        .map(NonFungibleLocalId::ruid)
+        .map_err(|_| NonFungibleLocalIdError::InvalidRuid(hex::encode(&value)))
+}
+
+fn split_delimiters(
+    string: &str,
+) -> Result<(NonFungibleIdType, &str), NonFungibleLocalIdError> {
+    let id_type = match (string.chars().next(), string.chars().last()) {
+        (Some('#'), Some('#')) => NonFungibleIdType::Integer,
+        (Some('<'), Some('>')) => NonFungibleIdType::String,
+        (Some('['), Some(']')) => NonFungibleIdType::Bytes,
+        (Some('{'), Some('}')) => NonFungibleIdType::RUID,
+        _ => {
+            return Err(NonFungibleLocalIdError::InvalidDelimiters(
+                string.to_owned(),
+            ))
+        }
+    };
+    match string.len() {
+        0 | 1 => Err(NonFungibleLocalIdError::InvalidDelimiters(
+            string.to_owned(),
+        )),
+        length => Ok((id_type, &string[1..length - 1])),
+    }
+}
+
+fn check_length(length: usize) -> Result<(), NonFungibleLocalIdError> {
+    match length {
+        0 => Err(NonFungibleLocalIdError::Empty),
+        length if length > NON_FUNGIBLE_LOCAL_ID_MAX_LENGTH => {
+            Err(NonFungibleLocalIdError::TooLong {
+                length,
+                max_length: NON_FUNGIBLE_LOCAL_ID_MAX_LENGTH,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NonFungibleLocalIdError {
+    /// The string isn't enclosed in the `#`, `<>`, `[]`, or `{}` delimiters
+    /// of any of the id types.
+    InvalidDelimiters(String),
+    /// The content of an integer id isn't a decimal number that fits in 64
+    /// bits.
+    InvalidInteger(String),
+    /// The content of a string or bytes id is empty.
+    Empty,
+    TooLong {
+        length: usize,
+        max_length: usize,
+    },
+    /// The content of a string id has a character other than `a-z`, `A-Z`,
+    /// `0-9`, and `_`. The index is that of the byte the character starts
+    /// at.
+    InvalidCharacter {
+        character: char,
+        index: usize,
+    },
+    InvalidHex(String),
+    /// The content of a RUID id isn't four groups of 16 hex characters
+    /// separated by `-`, or a RUID isn't 32 bytes.
+    InvalidRuid(String),
+    IdTypeMismatch {
+        expected: NonFungibleIdType,
+        actual: NonFungibleIdType,
+    },
+    /// The engine rejected the content of the id. The content is checked
+    /// against the engine's rules beforehand so this is only reported if the
+    /// engine's rules are stricter than those of the toolkit.
+    ContentValidationError(ContentValidationError),
+}
+
+impl From<ContentValidationError> for NonFungibleLocalIdError {
+    fn from(value: ContentValidationError) -> Self {
+        Self::ContentValidationError(value)
+    }
+}
@@ -21,7 +21,8 @@ use radix_transactions::validation::*;
 use sbor::*;
 use scrypto::prelude::*;
 
-use crate::models::transaction_hash::TransactionHash;
+use crate::functions::signing::{recover_public_key, verify_signature};
+use crate::models::transaction_hash::{PayloadHashError, TransactionHash};
 
 pub fn hash(
     notarized_transaction: &NotarizedTransactionV1,
@@ -37,6 +38,19 @@ pub fn hash(
         })
 }
 
+/// Computes the notarized transaction hash of a compiled notarized
+/// transaction.
+pub fn hash_from_payload<T>(
+    payload_bytes: T,
+) -> Result<TransactionHash, PayloadHashError>
+where
+    T: AsRef<[u8]>,
+{
+    let notarized_transaction =
+        decompile(payload_bytes).map_err(PayloadHashError::DecodeError)?;
+    hash(&notarized_transaction).map_err(PayloadHashError::PrepareError)
+}
+
 pub fn compile(
     notarized_transaction: &NotarizedTransactionV1,
 ) -> Result<Vec<u8>, EncodeError> {
@@ -63,3 +77,88 @@ pub fn statically_validate(
         .and_then(|prepared| validator.validate(prepared))
         .map(|_| ())
 }
+
+/// Verifies each of the intent signatures of the notarized transaction against
+/// the intent hash and the notary signature against the signed intent hash.
+pub fn verify_signatures(
+    notarized_transaction: &NotarizedTransactionV1,
+) -> Result<SignaturesVerification, PrepareError> {
+    let prepared = notarized_transaction.prepare()?;
+    let intent_hash = prepared.intent_hash();
+    let signed_intent_hash = prepared.signed_intent_hash();
+
+    let intent_signatures = notarized_transaction
+        .signed_intent
+        .intent_signatures
+        .signatures
+        .iter()
+        .map(|IntentSignatureV1(signature)| {
+            match recover_public_key(&intent_hash.0, signature) {
+                Some(public_key) => IntentSignatureVerification {
+                    public_key: Some(public_key),
+                    is_valid: true,
+                },
+                None => IntentSignatureVerification {
+                    public_key: match signature {
+                        SignatureWithPublicKeyV1::Secp256k1 { .. } => None,
+                        SignatureWithPublicKeyV1::Ed25519 {
+                            public_key,
+                            ..
+                        } => Some(PublicKey::Ed25519(*public_key)),
+                    },
+                    is_valid: false,
+                },
+            }
+        })
+        .collect();
+    let notary_signature_is_valid = verify_signature(
+        &signed_intent_hash.0,
+        &notarized_transaction.notary_signature.0,
+        &notarized_transaction
+            .signed_intent
+            .intent
+            .header
+            .notary_public_key,
+    );
+
+    Ok(SignaturesVerification {
+        intent_signatures,
+        notary_signature_is_valid,
+    })
+}
+
+/// Verifies the signatures of a compiled notarized transaction.
+pub fn verify_signatures_from_payload<T>(
+    payload_bytes: T,
+) -> Result<SignaturesVerification, SignaturesVerificationError>
+where
+    T: AsRef<[u8]>,
+{
+    let notarized_transaction = decompile(payload_bytes)
+        .map_err(SignaturesVerificationError::DecodeError)?;
+    verify_signatures(&notarized_transaction)
+        .map_err(SignaturesVerificationError::PrepareError)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignaturesVerification {
+    /// The result of verifying each of the intent signatures in the order in
+    /// which they appear in the signed intent.
+    pub intent_signatures: Vec<IntentSignatureVerification>,
+    pub notary_signature_is_valid: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntentSignatureVerification {
+    /// The public key of the signer. This is [`None`] for an invalid Secp256k1
+    /// signature as the public key of these signatures can only be recovered
+    /// from a valid signature.
+    pub public_key: Option<PublicKey>,
+    pub is_valid: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignaturesVerificationError {
+    DecodeError(DecodeError),
+    PrepareError(PrepareError),
+}
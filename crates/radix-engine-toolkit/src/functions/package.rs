@@ -0,0 +1,1147 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Extracts the interfaces of the blueprints of a package from its package
+//! definition. The types of the inputs and outputs of the functions are
+//! resolved from the schema of the blueprint into the kinds of values that
+//! they're written as in a manifest so that typed call builders can be
+//! generated for the blueprints. The arguments of such calls can be built
+//! from loosely typed JSON values that are converted into the types that the
+//! schema of the blueprint expects.
+
+use std::str::FromStr;
+
+use radix_engine_interface::blueprints::package::*;
+use radix_transactions::data::{
+    from_decimal, from_non_fungible_local_id, from_precise_decimal,
+};
+use radix_transactions::prelude::{ManifestBuilder, TransactionManifestV1};
+use sbor::{LocalTypeId, Schema, SchemaTypeKind, TypeMetadata, TypeValidation};
+use scrypto::prelude::*;
+use scrypto::radix_blueprint_schema_init::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlueprintInterface {
+    pub functions: IndexMap<String, FunctionSignature>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionSignature {
+    /// The receiver of the function if it's a method and [`None`] if it's a
+    /// function.
+    pub receiver: Option<Receiver>,
+    /// The arguments of the function. The input of a function is a tuple of
+    /// its arguments.
+    pub arguments: Vec<FieldSignature>,
+    pub output: TypeSignature,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldSignature {
+    pub name: Option<String>,
+    pub type_signature: TypeSignature,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantSignature {
+    pub name: Option<String>,
+    pub fields: Vec<FieldSignature>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypeSignature {
+    /// Any value is accepted.
+    Any,
+    /// The type is the generic parameter of the blueprint with the given
+    /// index which is only known when the blueprint is instantiated.
+    Generic(u8),
+    /// The type contains itself and is only resolved at its outermost
+    /// occurrence.
+    Recursive {
+        type_name: Option<String>,
+    },
+    /// An owned object other than a bucket, proof, or address reservation
+    /// which can't be passed in a manifest.
+    Own,
+    /// A value with no children such as a number, a string, or an address.
+    Value(ManifestValueKind),
+    Array {
+        element: Box<TypeSignature>,
+    },
+    Tuple {
+        type_name: Option<String>,
+        fields: Vec<FieldSignature>,
+    },
+    Enum {
+        type_name: Option<String>,
+        variants: IndexMap<u8, VariantSignature>,
+    },
+    Map {
+        key: Box<TypeSignature>,
+        value: Box<TypeSignature>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublishPackageManifest {
+    /// The manifest publishing the package with the code registered as one of
+    /// its blobs.
+    pub manifest: TransactionManifestV1,
+    /// The hash of the code of the package.
+    pub code_hash: Hash,
+    /// The hashes of the schemas of the blueprints of the package keyed by
+    /// the name of the blueprint.
+    pub schema_hashes: IndexMap<String, Hash>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackageHashes {
+    /// The hash of the code of the package.
+    pub code_hash: Hash,
+    /// The hashes of the schemas of the blueprints of the package keyed by
+    /// the name of the blueprint.
+    pub schema_hashes: IndexMap<String, Hash>,
+}
+
+#[derive(Debug)]
+pub enum PackageDefinitionError {
+    DecodeError(DecodeError),
+    EncodeError(EncodeError),
+    InvalidLocalTypeId(LocalTypeId),
+}
+
+/// Decodes a package definition that is either manifest or Scrypto SBOR
+/// encoded and extracts the interfaces of its blueprints.
+pub fn extract_blueprint_interfaces<T>(
+    package_definition: T,
+) -> Result<IndexMap<String, BlueprintInterface>, PackageDefinitionError>
+where
+    T: AsRef<[u8]>,
+{
+    blueprint_interfaces(&decode_package_definition(package_definition)?)
+}
+
+/// Decodes a package definition that is either manifest or Scrypto SBOR
+/// encoded.
+pub fn decode_package_definition<T>(
+    package_definition: T,
+) -> Result<PackageDefinition, PackageDefinitionError>
+where
+    T: AsRef<[u8]>,
+{
+    let package_definition = package_definition.as_ref();
+    match package_definition.first().copied() {
+        Some(MANIFEST_SBOR_V1_PAYLOAD_PREFIX) => {
+            manifest_decode::<PackageDefinition>(package_definition)
+        }
+        _ => scrypto_decode::<PackageDefinition>(package_definition),
+    }
+    .map_err(PackageDefinitionError::DecodeError)
+}
+
+/// Builds the manifest that publishes a package with the given code and
+/// definition. The code and schema hashes are computed in the same way that
+/// the engine computes them when the package is published.
+pub fn build_publish_package_manifest<T>(
+    code: Vec<u8>,
+    package_definition: T,
+    metadata: MetadataInit,
+    owner_role: OwnerRole,
+) -> Result<PublishPackageManifest, PackageDefinitionError>
+where
+    T: AsRef<[u8]>,
+{
+    let package_definition = decode_package_definition(package_definition)?;
+    let PackageHashes {
+        code_hash,
+        schema_hashes,
+    } = package_hashes(&code, &package_definition)?;
+
+    let manifest = ManifestBuilder::new()
+        .publish_package_advanced(
+            None,
+            code,
+            package_definition,
+            metadata,
+            owner_role,
+        )
+        .build();
+
+    Ok(PublishPackageManifest {
+        manifest,
+        code_hash,
+        schema_hashes,
+    })
+}
+
+/// Hashes the code of the package and the schemas of its blueprints in the
+/// same way that the engine hashes them when the package is published.
+pub fn package_hashes(
+    code: &[u8],
+    package_definition: &PackageDefinition,
+) -> Result<PackageHashes, PackageDefinitionError> {
+    let schema_hashes = package_definition
+        .blueprints
+        .iter()
+        .map(|(blueprint_name, blueprint_definition)| {
+            scrypto_encode(&blueprint_definition.schema.schema)
+                .map(|encoded_schema| {
+                    (blueprint_name.clone(), hash(encoded_schema))
+                })
+                .map_err(PackageDefinitionError::EncodeError)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(PackageHashes {
+        code_hash: hash(code),
+        schema_hashes,
+    })
+}
+
+/// The number of allocation indices that [`package_allocation_index`] searches.
+pub const MAX_SEARCHED_ALLOCATION_INDICES: u32 = 1024;
+
+/// Predicts the address of the package that is published to the address
+/// allocated by an `ALLOCATE_GLOBAL_ADDRESS` instruction. The engine derives
+/// the ids of the nodes allocated by a transaction from the intent hash of the
+/// transaction and the index of the allocation among all of the nodes that
+/// the transaction allocates, i.e., the address is the one at the given
+/// allocation index.
+///
+/// The allocation index is not the index of the instruction. Every node that
+/// the transaction allocates is counted, including the worktop, buckets,
+/// proofs, and the auth zones of calls, so the index can't be derived from the
+/// manifest alone. It only depends on how the manifest executes and not on the
+/// intent hash though, so it can be obtained by previewing the manifest and
+/// passing the address of the package in the preview receipt to
+/// [`package_allocation_index`]. The address can then be predicted for any
+/// intent of the same manifest, e.g., after the nonce or epochs change.
+pub fn predict_package_address(
+    intent_hash: &Hash,
+    allocation_index: u32,
+) -> PackageAddress {
+    let mut preimage = [0u8; Hash::LENGTH + 4];
+    preimage[..Hash::LENGTH].copy_from_slice(intent_hash.as_ref());
+    preimage[Hash::LENGTH..].copy_from_slice(&allocation_index.to_le_bytes());
+    let allocation_hash = hash(preimage);
+
+    let mut node_id = [0u8; NodeId::LENGTH];
+    node_id
+        .copy_from_slice(&allocation_hash.0[Hash::LENGTH - NodeId::LENGTH..]);
+    node_id[0] = EntityType::GlobalPackage as u8;
+    PackageAddress::new_or_panic(node_id)
+}
+
+/// Finds the allocation index of the package address that was allocated by a
+/// transaction with the given intent hash, e.g., the address of a package in
+/// the receipt of a preview. Returns [`None`] if the address was not allocated
+/// by the transaction within the first [`MAX_SEARCHED_ALLOCATION_INDICES`]
+/// allocations.
+pub fn package_allocation_index(
+    intent_hash: &Hash,
+    package_address: &PackageAddress,
+) -> Option<u32> {
+    (0..MAX_SEARCHED_ALLOCATION_INDICES).find(|allocation_index| {
+        predict_package_address(intent_hash, *allocation_index)
+            == *package_address
+    })
+}
+
+pub fn blueprint_interfaces(
+    package_definition: &PackageDefinition,
+) -> Result<IndexMap<String, BlueprintInterface>, PackageDefinitionError> {
+    package_definition
+        .blueprints
+        .iter()
+        .map(|(blueprint_name, blueprint_definition)| {
+            let schema = blueprint_definition.schema.schema.v1();
+            let functions = blueprint_definition
+                .schema
+                .functions
+                .functions
+                .iter()
+                .map(|(function_name, function_schema)| {
+                    let input = type_signature(schema, &function_schema.input)?;
+                    let output =
+                        type_signature(schema, &function_schema.output)?;
+                    let arguments = match input {
+                        TypeSignature::Tuple { fields, .. } => fields,
+                        type_signature => vec![FieldSignature {
+                            name: None,
+                            type_signature,
+                        }],
+                    };
+                    Ok((
+                        function_name.clone(),
+                        FunctionSignature {
+                            receiver: function_schema
+                                .receiver
+                                .as_ref()
+                                .map(|receiver_info| receiver_info.receiver),
+                            arguments,
+                            output,
+                        },
+                    ))
+                })
+                .collect::<Result<_, PackageDefinitionError>>()?;
+            Ok((blueprint_name.clone(), BlueprintInterface { functions }))
+        })
+        .collect()
+}
+
+/// The addresses and definitions of the native packages whose blueprints are
+/// bundled with the toolkit, i.e., the account, identity, consensus manager,
+/// pool, access controller, and resource packages as well as the packages of
+/// the metadata, royalty, and role assignment modules.
+pub(crate) fn native_package_definitions(
+) -> [(PackageAddress, &'static PackageDefinition); 9] {
+    use radix_engine::blueprints::native_schema::*;
+
+    [
+        (ACCOUNT_PACKAGE, &*ACCOUNT_PACKAGE_DEFINITION),
+        (IDENTITY_PACKAGE, &*IDENTITY_PACKAGE_DEFINITION),
+        (
+            CONSENSUS_MANAGER_PACKAGE,
+            &*CONSENSUS_MANAGER_PACKAGE_DEFINITION,
+        ),
+        (POOL_PACKAGE, &*POOL_PACKAGE_DEFINITION_V1_0),
+        (
+            ACCESS_CONTROLLER_PACKAGE,
+            &*ACCESS_CONTROLLER_PACKAGE_DEFINITION_V2_0,
+        ),
+        (RESOURCE_PACKAGE, &*RESOURCE_PACKAGE_DEFINITION),
+        (METADATA_MODULE_PACKAGE, &*METADATA_PACKAGE_DEFINITION),
+        (ROYALTY_MODULE_PACKAGE, &*ROYALTY_PACKAGE_DEFINITION),
+        (
+            ROLE_ASSIGNMENT_MODULE_PACKAGE,
+            &*ROLE_ASSIGNMENT_PACKAGE_DEFINITION,
+        ),
+    ]
+}
+
+fn type_signature(
+    schema: &Schema<ScryptoCustomSchema>,
+    type_ref: &TypeRef<LocalTypeId>,
+) -> Result<TypeSignature, PackageDefinitionError> {
+    match type_ref {
+        TypeRef::Static(local_type_id) => {
+            resolve_type_signature(schema, *local_type_id, &mut vec![])
+        }
+        TypeRef::Generic(index) => Ok(TypeSignature::Generic(*index)),
+    }
+}
+
+fn resolve_type_signature(
+    schema: &Schema<ScryptoCustomSchema>,
+    local_type_id: LocalTypeId,
+    ancestors: &mut Vec<LocalTypeId>,
+) -> Result<TypeSignature, PackageDefinitionError> {
+    let invalid_local_type_id =
+        || PackageDefinitionError::InvalidLocalTypeId(local_type_id);
+    let type_kind = schema
+        .resolve_type_kind(local_type_id)
+        .ok_or_else(invalid_local_type_id)?;
+    let type_metadata = schema
+        .resolve_type_metadata(local_type_id)
+        .ok_or_else(invalid_local_type_id)?;
+    let type_validation = schema
+        .resolve_type_validation(local_type_id)
+        .ok_or_else(invalid_local_type_id)?;
+    let type_name = type_metadata.get_name_string();
+
+    if ancestors.contains(&local_type_id) {
+        return Ok(TypeSignature::Recursive { type_name });
+    }
+    ancestors.push(local_type_id);
+
+    let mut resolve = |local_type_id: LocalTypeId| {
+        resolve_type_signature(schema, local_type_id, ancestors)
+    };
+    let type_signature = match type_kind {
+        SchemaTypeKind::<ScryptoCustomSchema>::Any => TypeSignature::Any,
+        SchemaTypeKind::<ScryptoCustomSchema>::Bool => {
+            TypeSignature::Value(ManifestValueKind::Bool)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::I8 => {
+            TypeSignature::Value(ManifestValueKind::I8)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::I16 => {
+            TypeSignature::Value(ManifestValueKind::I16)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::I32 => {
+            TypeSignature::Value(ManifestValueKind::I32)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::I64 => {
+            TypeSignature::Value(ManifestValueKind::I64)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::I128 => {
+            TypeSignature::Value(ManifestValueKind::I128)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::U8 => {
+            TypeSignature::Value(ManifestValueKind::U8)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::U16 => {
+            TypeSignature::Value(ManifestValueKind::U16)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::U32 => {
+            TypeSignature::Value(ManifestValueKind::U32)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::U64 => {
+            TypeSignature::Value(ManifestValueKind::U64)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::U128 => {
+            TypeSignature::Value(ManifestValueKind::U128)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::String => {
+            TypeSignature::Value(ManifestValueKind::String)
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::Array { element_type } => {
+            TypeSignature::Array {
+                element: Box::new(resolve(*element_type)?),
+            }
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::Tuple { field_types } => {
+            TypeSignature::Tuple {
+                type_name,
+                fields: field_signatures(
+                    field_types,
+                    Some(type_metadata),
+                    &mut resolve,
+                )?,
+            }
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::Enum { variants } => {
+            TypeSignature::Enum {
+                type_name,
+                variants: variants
+                    .iter()
+                    .map(|(discriminator, field_types)| {
+                        let variant_metadata =
+                            type_metadata.get_enum_variant_data(*discriminator);
+                        Ok((
+                            *discriminator,
+                            VariantSignature {
+                                name: variant_metadata
+                                    .and_then(TypeMetadata::get_name_string),
+                                fields: field_signatures(
+                                    field_types,
+                                    variant_metadata,
+                                    &mut resolve,
+                                )?,
+                            },
+                        ))
+                    })
+                    .collect::<Result<_, PackageDefinitionError>>()?,
+            }
+        }
+        SchemaTypeKind::<ScryptoCustomSchema>::Map {
+            key_type,
+            value_type,
+        } => TypeSignature::Map {
+            key: Box::new(resolve(*key_type)?),
+            value: Box::new(resolve(*value_type)?),
+        },
+        SchemaTypeKind::<ScryptoCustomSchema>::Custom(custom_type_kind) => {
+            custom_value_kind(custom_type_kind, type_validation)
+                .map(|custom_value_kind| {
+                    TypeSignature::Value(ManifestValueKind::Custom(
+                        custom_value_kind,
+                    ))
+                })
+                .unwrap_or(TypeSignature::Own)
+        }
+    };
+
+    ancestors.pop();
+    Ok(type_signature)
+}
+
+fn field_signatures(
+    field_types: &[LocalTypeId],
+    type_metadata: Option<&TypeMetadata>,
+    resolve: &mut impl FnMut(
+        LocalTypeId,
+    ) -> Result<TypeSignature, PackageDefinitionError>,
+) -> Result<Vec<FieldSignature>, PackageDefinitionError> {
+    let field_names = type_metadata.and_then(TypeMetadata::get_field_names);
+    field_types
+        .iter()
+        .enumerate()
+        .map(|(index, field_type)| {
+            Ok(FieldSignature {
+                name: field_names
+                    .and_then(|field_names| field_names.get(index))
+                    .map(|field_name| field_name.to_string()),
+                type_signature: resolve(*field_type)?,
+            })
+        })
+        .collect()
+}
+
+/// The kind of value that a value of the custom type is written as in a
+/// manifest or [`None`] if it's an owned object that can't be passed in a
+/// manifest.
+fn custom_value_kind(
+    custom_type_kind: &ScryptoCustomTypeKind,
+    type_validation: &TypeValidation<ScryptoCustomTypeValidation>,
+) -> Option<ManifestCustomValueKind> {
+    match (custom_type_kind, type_validation) {
+        (ScryptoCustomTypeKind::Reference, _) => {
+            Some(ManifestCustomValueKind::Address)
+        }
+        (
+            ScryptoCustomTypeKind::Own,
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                OwnValidation::IsBucket,
+            )),
+        ) => Some(ManifestCustomValueKind::Bucket),
+        (
+            ScryptoCustomTypeKind::Own,
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                OwnValidation::IsProof,
+            )),
+        ) => Some(ManifestCustomValueKind::Proof),
+        (
+            ScryptoCustomTypeKind::Own,
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                OwnValidation::IsGlobalAddressReservation,
+            )),
+        ) => Some(ManifestCustomValueKind::AddressReservation),
+        (ScryptoCustomTypeKind::Own, _) => None,
+        (ScryptoCustomTypeKind::Decimal, _) => {
+            Some(ManifestCustomValueKind::Decimal)
+        }
+        (ScryptoCustomTypeKind::PreciseDecimal, _) => {
+            Some(ManifestCustomValueKind::PreciseDecimal)
+        }
+        (ScryptoCustomTypeKind::NonFungibleLocalId, _) => {
+            Some(ManifestCustomValueKind::NonFungibleLocalId)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CallArgumentsError {
+    BlueprintNotFound(String),
+    FunctionNotFound(String),
+    InvalidLocalTypeId(LocalTypeId),
+    /// The type at the path can't be built from JSON as it's generic, accepts
+    /// any value, or is an owned object that can't be passed in a manifest.
+    UnsupportedType {
+        path: String,
+    },
+    /// The JSON value at the path can't be converted into the type that the
+    /// schema expects.
+    InvalidValue {
+        path: String,
+        expected: String,
+    },
+    InvalidAddress {
+        path: String,
+        address: String,
+    },
+    LengthMismatch {
+        path: String,
+        expected: usize,
+        actual: usize,
+    },
+    MissingField {
+        path: String,
+        field: String,
+    },
+    UnknownField {
+        path: String,
+        field: String,
+    },
+    UnknownVariant {
+        path: String,
+        variant: String,
+    },
+}
+
+/// Builds the arguments of a call to a function of a blueprint from
+/// loosely typed JSON values, one for each of the arguments. The JSON values
+/// are converted into the types that the schema of the blueprint expects:
+///
+/// * Numbers, decimals, and non-fungible local ids may be given as JSON
+///   numbers or strings and must fit the type in the schema, e.g., a value
+///   with more than 18 decimal places is only accepted for a
+///   `PreciseDecimal`.
+/// * Addresses are given as Bech32m strings and must be of the kind of entity
+///   that the schema expects, e.g., a resource address where a
+///   `ResourceAddress` is expected. Typed global references are only checked
+///   to be global.
+/// * Buckets, proofs, and address reservations are given as their ids.
+/// * Tuples are given as arrays of their fields or as objects keyed by the
+///   names of their fields.
+/// * Options are given as `null` for `None` and as the value for `Some`.
+///   Other enums are given as the name or discriminator of a variant without
+///   fields or as an object with the `variant` and its `fields`.
+/// * Maps are given as objects or as arrays of key and value pairs.
+///
+/// Errors report the path of the offending value in the notation of the
+/// [`value_query`](crate::sbor::value_query) module.
+pub fn build_call_arguments(
+    package_definition: &PackageDefinition,
+    blueprint_name: &str,
+    function_name: &str,
+    arguments: &[serde_json::Value],
+    address_bech32_decoder: &AddressBech32Decoder,
+) -> Result<ManifestValue, CallArgumentsError> {
+    let blueprint_definition = package_definition
+        .blueprints
+        .get(blueprint_name)
+        .ok_or_else(|| {
+            CallArgumentsError::BlueprintNotFound(blueprint_name.to_owned())
+        })?;
+    let function_schema = blueprint_definition
+        .schema
+        .functions
+        .functions
+        .get(function_name)
+        .ok_or_else(|| {
+            CallArgumentsError::FunctionNotFound(function_name.to_owned())
+        })?;
+    let TypeRef::Static(input_type) = &function_schema.input else {
+        return Err(CallArgumentsError::UnsupportedType {
+            path: "$".to_owned(),
+        });
+    };
+
+    CallArgumentsBuilder {
+        schema: blueprint_definition.schema.schema.v1(),
+        address_bech32_decoder,
+    }
+    .build(
+        *input_type,
+        &serde_json::Value::Array(arguments.to_vec()),
+        "$",
+    )
+}
+
+struct CallArgumentsBuilder<'a> {
+    schema: &'a Schema<ScryptoCustomSchema>,
+    address_bech32_decoder: &'a AddressBech32Decoder,
+}
+
+impl<'a> CallArgumentsBuilder<'a> {
+    fn build(
+        &self,
+        local_type_id: LocalTypeId,
+        value: &serde_json::Value,
+        path: &str,
+    ) -> Result<ManifestValue, CallArgumentsError> {
+        let (type_kind, type_metadata, type_validation) =
+            self.resolve(local_type_id)?;
+        let invalid = |expected: &str| CallArgumentsError::InvalidValue {
+            path: path.to_owned(),
+            expected: expected.to_owned(),
+        };
+
+        let value = match type_kind {
+            SchemaTypeKind::<ScryptoCustomSchema>::Any => {
+                return Err(CallArgumentsError::UnsupportedType {
+                    path: path.to_owned(),
+                })
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Bool => {
+                ManifestValue::Bool {
+                    value: value.as_bool().ok_or_else(|| invalid("Bool"))?,
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::I8 => ManifestValue::I8 {
+                value: parse(value).ok_or_else(|| invalid("I8"))?,
+            },
+            SchemaTypeKind::<ScryptoCustomSchema>::I16 => ManifestValue::I16 {
+                value: parse(value).ok_or_else(|| invalid("I16"))?,
+            },
+            SchemaTypeKind::<ScryptoCustomSchema>::I32 => ManifestValue::I32 {
+                value: parse(value).ok_or_else(|| invalid("I32"))?,
+            },
+            SchemaTypeKind::<ScryptoCustomSchema>::I64 => ManifestValue::I64 {
+                value: parse(value).ok_or_else(|| invalid("I64"))?,
+            },
+            SchemaTypeKind::<ScryptoCustomSchema>::I128 => {
+                ManifestValue::I128 {
+                    value: parse(value).ok_or_else(|| invalid("I128"))?,
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::U8 => ManifestValue::U8 {
+                value: parse(value).ok_or_else(|| invalid("U8"))?,
+            },
+            SchemaTypeKind::<ScryptoCustomSchema>::U16 => ManifestValue::U16 {
+                value: parse(value).ok_or_else(|| invalid("U16"))?,
+            },
+            SchemaTypeKind::<ScryptoCustomSchema>::U32 => ManifestValue::U32 {
+                value: parse(value).ok_or_else(|| invalid("U32"))?,
+            },
+            SchemaTypeKind::<ScryptoCustomSchema>::U64 => ManifestValue::U64 {
+                value: parse(value).ok_or_else(|| invalid("U64"))?,
+            },
+            SchemaTypeKind::<ScryptoCustomSchema>::U128 => {
+                ManifestValue::U128 {
+                    value: parse(value).ok_or_else(|| invalid("U128"))?,
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::String => {
+                ManifestValue::String {
+                    value: value
+                        .as_str()
+                        .ok_or_else(|| invalid("String"))?
+                        .to_owned(),
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Array { element_type } => {
+                ManifestValue::Array {
+                    element_value_kind: self.value_kind(*element_type, path)?,
+                    elements: value
+                        .as_array()
+                        .ok_or_else(|| invalid("Array"))?
+                        .iter()
+                        .enumerate()
+                        .map(|(index, element)| {
+                            self.build(
+                                *element_type,
+                                element,
+                                &format!("{path}.{index}"),
+                            )
+                        })
+                        .collect::<Result<_, _>>()?,
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Tuple { field_types } => {
+                ManifestValue::Tuple {
+                    fields: self.build_fields(
+                        field_types,
+                        Some(type_metadata),
+                        value,
+                        path,
+                    )?,
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Enum { variants } => {
+                self.build_enum(variants, type_metadata, value, path)?
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Map {
+                key_type,
+                value_type,
+            } => {
+                let entries = match value {
+                    serde_json::Value::Object(object) => object
+                        .iter()
+                        .map(|(key, value)| {
+                            (serde_json::Value::String(key.clone()), value)
+                        })
+                        .collect::<Vec<_>>(),
+                    serde_json::Value::Array(entries) => entries
+                        .iter()
+                        .map(|entry| {
+                            match entry.as_array().map(Vec::as_slice) {
+                                Some([key, value]) => Ok((key.clone(), value)),
+                                _ => Err(invalid("Map entry")),
+                            }
+                        })
+                        .collect::<Result<_, _>>()?,
+                    _ => return Err(invalid("Map")),
+                };
+                ManifestValue::Map {
+                    key_value_kind: self.value_kind(*key_type, path)?,
+                    value_value_kind: self.value_kind(*value_type, path)?,
+                    entries: entries
+                        .iter()
+                        .enumerate()
+                        .map(|(index, (key, value))| {
+                            let path = format!("{path}.{index}");
+                            Ok((
+                                self.build(*key_type, key, &path)?,
+                                self.build(*value_type, value, &path)?,
+                            ))
+                        })
+                        .collect::<Result<_, _>>()?,
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Custom(custom_type_kind) => {
+                let value = match custom_value_kind(
+                    custom_type_kind,
+                    type_validation,
+                ) {
+                    Some(ManifestCustomValueKind::Address) => {
+                        ManifestCustomValue::Address(ManifestAddress::Static(
+                            self.node_id(value, type_validation, path)?,
+                        ))
+                    }
+                    Some(ManifestCustomValueKind::Bucket) => {
+                        ManifestCustomValue::Bucket(ManifestBucket(
+                            parse(value).ok_or_else(|| invalid("Bucket"))?,
+                        ))
+                    }
+                    Some(ManifestCustomValueKind::Proof) => {
+                        ManifestCustomValue::Proof(ManifestProof(
+                            parse(value).ok_or_else(|| invalid("Proof"))?,
+                        ))
+                    }
+                    Some(ManifestCustomValueKind::AddressReservation) => {
+                        ManifestCustomValue::AddressReservation(
+                            ManifestAddressReservation(
+                                parse(value).ok_or_else(|| {
+                                    invalid("AddressReservation")
+                                })?,
+                            ),
+                        )
+                    }
+                    Some(ManifestCustomValueKind::Decimal) => {
+                        ManifestCustomValue::Decimal(from_decimal(
+                            parse(value).ok_or_else(|| invalid("Decimal"))?,
+                        ))
+                    }
+                    Some(ManifestCustomValueKind::PreciseDecimal) => {
+                        ManifestCustomValue::PreciseDecimal(
+                            from_precise_decimal(
+                                parse(value)
+                                    .ok_or_else(|| invalid("PreciseDecimal"))?,
+                            ),
+                        )
+                    }
+                    Some(ManifestCustomValueKind::NonFungibleLocalId) => {
+                        ManifestCustomValue::NonFungibleLocalId(
+                            from_non_fungible_local_id(
+                                parse(value).ok_or_else(|| {
+                                    invalid("NonFungibleLocalId")
+                                })?,
+                            ),
+                        )
+                    }
+                    Some(
+                        ManifestCustomValueKind::Expression
+                        | ManifestCustomValueKind::Blob,
+                    )
+                    | None => {
+                        return Err(CallArgumentsError::UnsupportedType {
+                            path: path.to_owned(),
+                        })
+                    }
+                };
+                ManifestValue::Custom { value }
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn build_fields(
+        &self,
+        field_types: &[LocalTypeId],
+        type_metadata: Option<&TypeMetadata>,
+        value: &serde_json::Value,
+        path: &str,
+    ) -> Result<Vec<ManifestValue>, CallArgumentsError> {
+        let field_names = type_metadata
+            .and_then(TypeMetadata::get_field_names)
+            .unwrap_or_default();
+        let field_values = match value {
+            serde_json::Value::Array(field_values) => {
+                field_values.iter().collect::<Vec<_>>()
+            }
+            serde_json::Value::Object(object)
+                if field_names.len() == field_types.len() =>
+            {
+                if let Some(field) = object
+                    .keys()
+                    .find(|key| !field_names.iter().any(|name| name == *key))
+                {
+                    return Err(CallArgumentsError::UnknownField {
+                        path: path.to_owned(),
+                        field: field.clone(),
+                    });
+                }
+                field_names
+                    .iter()
+                    .map(|name| {
+                        object.get(&**name).ok_or_else(|| {
+                            CallArgumentsError::MissingField {
+                                path: path.to_owned(),
+                                field: name.to_string(),
+                            }
+                        })
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+            _ => {
+                return Err(CallArgumentsError::InvalidValue {
+                    path: path.to_owned(),
+                    expected: "Tuple".to_owned(),
+                })
+            }
+        };
+        if field_values.len() != field_types.len() {
+            return Err(CallArgumentsError::LengthMismatch {
+                path: path.to_owned(),
+                expected: field_types.len(),
+                actual: field_values.len(),
+            });
+        }
+
+        field_types
+            .iter()
+            .zip(field_values)
+            .enumerate()
+            .map(|(index, (field_type, field_value))| {
+                self.build(*field_type, field_value, &format!("{path}.{index}"))
+            })
+            .collect()
+    }
+
+    fn build_enum(
+        &self,
+        variants: &IndexMap<u8, Vec<LocalTypeId>>,
+        type_metadata: &TypeMetadata,
+        value: &serde_json::Value,
+        path: &str,
+    ) -> Result<ManifestValue, CallArgumentsError> {
+        // Options are given as null for None and as the value itself for Some
+        // rather than as an explicit variant.
+        if let (Some("Option"), Some([some_type])) = (
+            type_metadata.get_name(),
+            variants.get(&OPTION_VARIANT_SOME).map(Vec::as_slice),
+        ) {
+            return Ok(match value {
+                serde_json::Value::Null => ManifestValue::Enum {
+                    discriminator: OPTION_VARIANT_NONE,
+                    fields: vec![],
+                },
+                value => ManifestValue::Enum {
+                    discriminator: OPTION_VARIANT_SOME,
+                    fields: vec![self.build(
+                        *some_type,
+                        value,
+                        &format!("{path}#{OPTION_VARIANT_SOME}.0"),
+                    )?],
+                },
+            });
+        }
+
+        let (variant, fields) = match value {
+            serde_json::Value::Object(object) => (
+                object.get("variant").ok_or_else(|| {
+                    CallArgumentsError::MissingField {
+                        path: path.to_owned(),
+                        field: "variant".to_owned(),
+                    }
+                })?,
+                object.get("fields"),
+            ),
+            variant => (variant, None),
+        };
+        let discriminator = match variant {
+            serde_json::Value::String(variant) => variants
+                .keys()
+                .copied()
+                .find(|discriminator| {
+                    type_metadata
+                        .get_enum_variant_data(*discriminator)
+                        .and_then(TypeMetadata::get_name)
+                        == Some(variant.as_str())
+                })
+                .or_else(|| variant.parse().ok()),
+            serde_json::Value::Number(variant) => variant
+                .as_u64()
+                .and_then(|discriminator| u8::try_from(discriminator).ok()),
+            _ => None,
+        }
+        .filter(|discriminator| variants.contains_key(discriminator))
+        .ok_or_else(|| CallArgumentsError::UnknownVariant {
+            path: path.to_owned(),
+            variant: variant.to_string(),
+        })?;
+
+        Ok(ManifestValue::Enum {
+            discriminator,
+            fields: self.build_fields(
+                &variants[&discriminator],
+                type_metadata.get_enum_variant_data(discriminator),
+                fields.unwrap_or(&serde_json::Value::Array(vec![])),
+                &format!("{path}#{discriminator}"),
+            )?,
+        })
+    }
+
+    /// Decodes the address and checks that it's of the kind of entity that
+    /// the type validation expects.
+    fn node_id(
+        &self,
+        value: &serde_json::Value,
+        type_validation: &TypeValidation<ScryptoCustomTypeValidation>,
+        path: &str,
+    ) -> Result<NodeId, CallArgumentsError> {
+        let address =
+            value
+                .as_str()
+                .ok_or_else(|| CallArgumentsError::InvalidValue {
+                    path: path.to_owned(),
+                    expected: "Address".to_owned(),
+                })?;
+        let invalid_address = || CallArgumentsError::InvalidAddress {
+            path: path.to_owned(),
+            address: address.to_owned(),
+        };
+
+        let (entity_type, data) = self
+            .address_bech32_decoder
+            .validate_and_decode(address)
+            .map_err(|_| invalid_address())?;
+        let is_expected_entity_type = match type_validation {
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+                reference_validation,
+            )) => match reference_validation {
+                ReferenceValidation::IsGlobal
+                | ReferenceValidation::IsGlobalTyped(..) => {
+                    entity_type.is_global()
+                }
+                ReferenceValidation::IsGlobalPackage => {
+                    entity_type.is_global_package()
+                }
+                ReferenceValidation::IsGlobalComponent => {
+                    entity_type.is_global_component()
+                }
+                ReferenceValidation::IsGlobalResourceManager => {
+                    entity_type.is_global_resource_manager()
+                }
+                ReferenceValidation::IsInternal
+                | ReferenceValidation::IsInternalTyped(..) => {
+                    entity_type.is_internal()
+                }
+            },
+            _ => true,
+        };
+        if !is_expected_entity_type {
+            return Err(invalid_address());
+        }
+
+        data.try_into().map(NodeId).map_err(|_| invalid_address())
+    }
+
+    fn value_kind(
+        &self,
+        local_type_id: LocalTypeId,
+        path: &str,
+    ) -> Result<ManifestValueKind, CallArgumentsError> {
+        let (type_kind, _, type_validation) = self.resolve(local_type_id)?;
+        let value_kind = match type_kind {
+            SchemaTypeKind::<ScryptoCustomSchema>::Bool => {
+                ManifestValueKind::Bool
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::I8 => ManifestValueKind::I8,
+            SchemaTypeKind::<ScryptoCustomSchema>::I16 => {
+                ManifestValueKind::I16
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::I32 => {
+                ManifestValueKind::I32
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::I64 => {
+                ManifestValueKind::I64
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::I128 => {
+                ManifestValueKind::I128
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::U8 => ManifestValueKind::U8,
+            SchemaTypeKind::<ScryptoCustomSchema>::U16 => {
+                ManifestValueKind::U16
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::U32 => {
+                ManifestValueKind::U32
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::U64 => {
+                ManifestValueKind::U64
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::U128 => {
+                ManifestValueKind::U128
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::String => {
+                ManifestValueKind::String
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Array { .. } => {
+                ManifestValueKind::Array
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Tuple { .. } => {
+                ManifestValueKind::Tuple
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Enum { .. } => {
+                ManifestValueKind::Enum
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Map { .. } => {
+                ManifestValueKind::Map
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Custom(custom_type_kind) => {
+                match custom_value_kind(custom_type_kind, type_validation) {
+                    Some(custom_value_kind) => {
+                        ManifestValueKind::Custom(custom_value_kind)
+                    }
+                    None => {
+                        return Err(CallArgumentsError::UnsupportedType {
+                            path: path.to_owned(),
+                        })
+                    }
+                }
+            }
+            SchemaTypeKind::<ScryptoCustomSchema>::Any => {
+                return Err(CallArgumentsError::UnsupportedType {
+                    path: path.to_owned(),
+                })
+            }
+        };
+        Ok(value_kind)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn resolve(
+        &self,
+        local_type_id: LocalTypeId,
+    ) -> Result<
+        (
+            &'a SchemaTypeKind<ScryptoCustomSchema>,
+            &'a TypeMetadata,
+            &'a TypeValidation<ScryptoCustomTypeValidation>,
+        ),
+        CallArgumentsError,
+    > {
+        let invalid_local_type_id =
+            || CallArgumentsError::InvalidLocalTypeId(local_type_id);
+        Ok((
+            self.schema
+                .resolve_type_kind(local_type_id)
+                .ok_or_else(invalid_local_type_id)?,
+            self.schema
+                .resolve_type_metadata(local_type_id)
+                .ok_or_else(invalid_local_type_id)?,
+            self.schema
+                .resolve_type_validation(local_type_id)
+                .ok_or_else(invalid_local_type_id)?,
+        ))
+    }
+}
+
+/// Parses a value that may be given either as a JSON number or as a string.
+fn parse<T>(value: &serde_json::Value) -> Option<T>
+where
+    T: FromStr,
+{
+    match value {
+        serde_json::Value::Number(number) => number.to_string().parse().ok(),
+        serde_json::Value::String(string) => string.parse().ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decompiles transaction payloads whose kind is not known ahead of time by
+//! detecting whether they're intents, signed intents, or notarized transactions.
+
+use radix_transactions::model::*;
+use sbor::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PayloadKind {
+    Intent,
+    SignedIntent,
+    NotarizedTransaction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecompiledPayload {
+    Intent(IntentV1),
+    SignedIntent(SignedIntentV1),
+    NotarizedTransaction(NotarizedTransactionV1),
+}
+
+impl DecompiledPayload {
+    pub fn kind(&self) -> PayloadKind {
+        match self {
+            Self::Intent(..) => PayloadKind::Intent,
+            Self::SignedIntent(..) => PayloadKind::SignedIntent,
+            Self::NotarizedTransaction(..) => PayloadKind::NotarizedTransaction,
+        }
+    }
+
+    /// The intent of the payload regardless of its kind.
+    pub fn intent(&self) -> &IntentV1 {
+        match self {
+            Self::Intent(intent) => intent,
+            Self::SignedIntent(signed_intent) => &signed_intent.intent,
+            Self::NotarizedTransaction(notarized_transaction) => {
+                &notarized_transaction.signed_intent.intent
+            }
+        }
+    }
+}
+
+/// Decompiles a payload of an unknown kind. Each payload kind has a distinct
+/// discriminator so at most one of the kinds can decode the payload. If none
+/// of them can then the error of decoding it as a notarized transaction is
+/// returned.
+pub fn decompile_unknown<T>(
+    payload_bytes: T,
+) -> Result<DecompiledPayload, DecodeError>
+where
+    T: AsRef<[u8]>,
+{
+    let payload_bytes = payload_bytes.as_ref();
+    if let Ok(intent) = super::intent::decompile(payload_bytes) {
+        Ok(DecompiledPayload::Intent(intent))
+    } else if let Ok(signed_intent) =
+        super::signed_intent::decompile(payload_bytes)
+    {
+        Ok(DecompiledPayload::SignedIntent(signed_intent))
+    } else {
+        super::notarized_transaction::decompile(payload_bytes)
+            .map(DecompiledPayload::NotarizedTransaction)
+    }
+}
@@ -0,0 +1,197 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Manifests for contributing resources to one, two, and multi-resource pools
+//! and for redeeming pool units. The resources are withdrawn from the account
+//! and taken from the worktop into buckets that are passed to the pool, and
+//! whatever the pool returns, the pool units or the redeemed resources and
+//! any change, is deposited back into the account.
+
+use radix_engine_interface::blueprints::pool::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoolOperation {
+    /// Contributes the amounts of the resources to the pool in exchange for
+    /// pool units. One-resource pools take exactly one resource and
+    /// two-resource pools exactly two.
+    Contribute {
+        pool_address: ComponentAddress,
+        contributions: IndexMap<ResourceAddress, Decimal>,
+    },
+    /// Redeems pool units for the resources of the pool.
+    Redeem {
+        pool_address: ComponentAddress,
+        pool_unit_resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoolOperationError {
+    /// The address isn't the address of a one, two, or multi-resource pool.
+    NotAPool(ComponentAddress),
+    /// The number of contributed resources doesn't match the number of
+    /// resources of the pool.
+    InvalidContributionCount { expected: usize, actual: usize },
+}
+
+/// Builds the manifest that performs the operations in order with the
+/// resources of the account.
+pub fn build_pool_manifest(
+    account_address: ComponentAddress,
+    operations: &[PoolOperation],
+) -> Result<TransactionManifestV1, PoolOperationError> {
+    operations
+        .iter()
+        .enumerate()
+        .try_fold(ManifestBuilder::new(), |builder, (index, operation)| {
+            add_pool_operation(
+                builder,
+                account_address,
+                operation,
+                &format!("bucket{index}"),
+            )
+        })
+        .map(ManifestBuilder::build)
+}
+
+fn add_pool_operation(
+    builder: ManifestBuilder,
+    account_address: ComponentAddress,
+    operation: &PoolOperation,
+    bucket: &str,
+) -> Result<ManifestBuilder, PoolOperationError> {
+    match operation {
+        PoolOperation::Contribute {
+            pool_address,
+            contributions,
+        } => {
+            let entity_type = pool_entity_type(pool_address)?;
+            let expected = match entity_type {
+                EntityType::GlobalOneResourcePool => Some(1),
+                EntityType::GlobalTwoResourcePool => Some(2),
+                _ => None,
+            };
+            if let Some(expected) =
+                expected.filter(|expected| *expected != contributions.len())
+            {
+                return Err(PoolOperationError::InvalidContributionCount {
+                    expected,
+                    actual: contributions.len(),
+                });
+            }
+
+            let buckets = (0..contributions.len())
+                .map(|position| format!("{bucket}_{position}"))
+                .collect::<Vec<_>>();
+            let builder = contributions.iter().zip(buckets.iter()).fold(
+                builder,
+                |builder, ((resource_address, amount), bucket)| {
+                    builder
+                        .withdraw_from_account(
+                            account_address,
+                            *resource_address,
+                            *amount,
+                        )
+                        .take_from_worktop(
+                            *resource_address,
+                            *amount,
+                            bucket.as_str(),
+                        )
+                },
+            );
+            let builder = match entity_type {
+                EntityType::GlobalOneResourcePool => builder
+                    .call_method_with_name_lookup(
+                        *pool_address,
+                        ONE_RESOURCE_POOL_CONTRIBUTE_IDENT,
+                        |lookup| (lookup.bucket(&buckets[0]),),
+                    ),
+                EntityType::GlobalTwoResourcePool => builder
+                    .call_method_with_name_lookup(
+                        *pool_address,
+                        TWO_RESOURCE_POOL_CONTRIBUTE_IDENT,
+                        |lookup| {
+                            ((
+                                lookup.bucket(&buckets[0]),
+                                lookup.bucket(&buckets[1]),
+                            ),)
+                        },
+                    ),
+                _ => builder.call_method_with_name_lookup(
+                    *pool_address,
+                    MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT,
+                    |lookup| {
+                        (buckets
+                            .iter()
+                            .map(|bucket| lookup.bucket(bucket))
+                            .collect::<Vec<_>>(),)
+                    },
+                ),
+            };
+            Ok(builder
+                .try_deposit_entire_worktop_or_abort(account_address, None))
+        }
+        PoolOperation::Redeem {
+            pool_address,
+            pool_unit_resource_address,
+            amount,
+        } => {
+            let method_name = match pool_entity_type(pool_address)? {
+                EntityType::GlobalOneResourcePool => {
+                    ONE_RESOURCE_POOL_REDEEM_IDENT
+                }
+                EntityType::GlobalTwoResourcePool => {
+                    TWO_RESOURCE_POOL_REDEEM_IDENT
+                }
+                _ => MULTI_RESOURCE_POOL_REDEEM_IDENT,
+            };
+            Ok(builder
+                .withdraw_from_account(
+                    account_address,
+                    *pool_unit_resource_address,
+                    *amount,
+                )
+                .take_from_worktop(*pool_unit_resource_address, *amount, bucket)
+                .call_method_with_name_lookup(
+                    *pool_address,
+                    method_name,
+                    |lookup| (lookup.bucket(bucket),),
+                )
+                .try_deposit_entire_worktop_or_abort(account_address, None))
+        }
+    }
+}
+
+fn pool_entity_type(
+    pool_address: &ComponentAddress,
+) -> Result<EntityType, PoolOperationError> {
+    pool_address
+        .as_node_id()
+        .entity_type()
+        .filter(|entity_type| {
+            matches!(
+                entity_type,
+                EntityType::GlobalOneResourcePool
+                    | EntityType::GlobalTwoResourcePool
+                    | EntityType::GlobalMultiResourcePool
+            )
+        })
+        .ok_or(PoolOperationError::NotAPool(*pool_address))
+}
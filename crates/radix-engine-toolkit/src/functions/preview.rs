@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::*;
+use radix_engine::transaction::*;
+use radix_transactions::manifest::*;
+use radix_transactions::prelude::*;
+
+use crate::transaction_types::FeeSummary;
+
+/// A transaction to preview, structured the way that the preview endpoints
+/// of the Gateway and Core APIs expect it. Unlike a transaction intent, the
+/// manifest is kept as a string and the notary is optional.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreviewTransaction {
+    pub manifest: String,
+    pub blobs: Vec<Vec<u8>>,
+    pub header: PreviewHeader,
+    pub signer_public_keys: Vec<PublicKey>,
+    pub flags: PreviewFlags,
+}
+
+/// The subset of the transaction header that is needed for a preview.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreviewHeader {
+    pub start_epoch_inclusive: Epoch,
+    pub end_epoch_exclusive: Epoch,
+    pub notary_public_key: Option<PublicKey>,
+    pub notary_is_signatory: bool,
+    pub tip_percentage: u16,
+    pub nonce: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct PreviewFlags {
+    /// Whether the fees are paid from a free credit instead of requiring the
+    /// manifest to lock them.
+    pub use_free_credit: bool,
+    /// Whether all signature proofs are assumed to be present regardless of
+    /// the signer public keys.
+    pub assume_all_signature_proofs: bool,
+    /// Whether the epoch range of the header is checked against the current
+    /// epoch.
+    pub skip_epoch_check: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreviewTransactionError {
+    DecompileError(DecompileError),
+    InvalidEpochRange {
+        start_epoch_inclusive: Epoch,
+        end_epoch_exclusive: Epoch,
+    },
+}
+
+pub fn build_preview_transaction(
+    manifest: &TransactionManifestV1,
+    header: PreviewHeader,
+    signer_public_keys: Vec<PublicKey>,
+    flags: PreviewFlags,
+    network_definition: &NetworkDefinition,
+) -> Result<PreviewTransaction, PreviewTransactionError> {
+    if header.end_epoch_exclusive <= header.start_epoch_inclusive {
+        return Err(PreviewTransactionError::InvalidEpochRange {
+            start_epoch_inclusive: header.start_epoch_inclusive,
+            end_epoch_exclusive: header.end_epoch_exclusive,
+        });
+    }
+
+    let manifest_string = decompile(&manifest.instructions, network_definition)
+        .map_err(PreviewTransactionError::DecompileError)?;
+    let blobs = manifest.blobs.values().cloned().collect();
+
+    Ok(PreviewTransaction {
+        manifest: manifest_string,
+        blobs,
+        header,
+        signer_public_keys,
+        flags,
+    })
+}
+
+/// The parts of a preview receipt that clients are typically interested in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreviewReceipt {
+    pub status: PreviewReceiptStatus,
+    pub fee_summary: FeeSummary,
+    pub new_package_addresses: IndexSet<PackageAddress>,
+    pub new_component_addresses: IndexSet<ComponentAddress>,
+    pub new_resource_addresses: IndexSet<ResourceAddress>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreviewReceiptStatus {
+    Succeeded,
+    Failed(String),
+    Rejected(String),
+    Aborted(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreviewReceiptError {
+    DecodeError(DecodeError),
+    UnsupportedReceiptVersion,
+}
+
+/// Parses the SBOR encoded receipt returned by the preview endpoints.
+pub fn parse_preview_receipt<T>(
+    encoded_receipt: T,
+) -> Result<PreviewReceipt, PreviewReceiptError>
+where
+    T: AsRef<[u8]>,
+{
+    let versioned_receipt =
+        scrypto_decode::<VersionedTransactionReceipt>(encoded_receipt.as_ref())
+            .map_err(PreviewReceiptError::DecodeError)?;
+    let receipt = versioned_receipt
+        .as_latest_version()
+        .ok_or(PreviewReceiptError::UnsupportedReceiptVersion)?;
+    Ok(PreviewReceipt::from(receipt))
+}
+
+impl From<&TransactionReceipt> for PreviewReceipt {
+    fn from(receipt: &TransactionReceipt) -> Self {
+        let fee_summary = FeeSummary {
+            execution_cost: receipt.fee_summary.total_execution_cost_in_xrd,
+            finalization_cost: receipt
+                .fee_summary
+                .total_finalization_cost_in_xrd,
+            storage_expansion_cost: receipt
+                .fee_summary
+                .total_storage_cost_in_xrd,
+            royalty_cost: receipt.fee_summary.total_royalty_cost_in_xrd,
+            tipping_cost: receipt.fee_summary.total_tipping_cost_in_xrd,
+        };
+
+        match &receipt.result {
+            TransactionResult::Commit(commit) => Self {
+                status: match &commit.outcome {
+                    TransactionOutcome::Success(..) => {
+                        PreviewReceiptStatus::Succeeded
+                    }
+                    TransactionOutcome::Failure(error) => {
+                        PreviewReceiptStatus::Failed(format!("{error:?}"))
+                    }
+                },
+                fee_summary,
+                new_package_addresses: commit.new_package_addresses().clone(),
+                new_component_addresses: commit
+                    .new_component_addresses()
+                    .clone(),
+                new_resource_addresses: commit.new_resource_addresses().clone(),
+            },
+            TransactionResult::Reject(reject) => Self {
+                status: PreviewReceiptStatus::Rejected(format!(
+                    "{:?}",
+                    reject.reason
+                )),
+                fee_summary,
+                new_package_addresses: Default::default(),
+                new_component_addresses: Default::default(),
+                new_resource_addresses: Default::default(),
+            },
+            TransactionResult::Abort(abort) => Self {
+                status: PreviewReceiptStatus::Aborted(format!(
+                    "{:?}",
+                    abort.reason
+                )),
+                fee_summary,
+                new_package_addresses: Default::default(),
+                new_component_addresses: Default::default(),
+                new_resource_addresses: Default::default(),
+            },
+        }
+    }
+}
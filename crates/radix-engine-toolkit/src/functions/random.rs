@@ -0,0 +1,106 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Generation of nonces and keys from the randomness of the operating system.
+//! The toolkit generates them itself so that hosts whose own random number
+//! generators are weak or unavailable can still rely on it.
+//!
+//! Each function has a variant that draws its randomness from a caller
+//! provided [`EntropySource`] instead, which is what tests use to generate the
+//! same nonces and keys on every run.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+/// A source of the randomness that nonces and keys are generated from.
+pub trait EntropySource {
+    /// Fills all of the bytes with random values.
+    fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), RandomError>;
+}
+
+/// The randomness of the operating system.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OsEntropy;
+
+impl EntropySource for OsEntropy {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), RandomError> {
+        getrandom::getrandom(bytes).map_err(RandomError::EntropyUnavailable)
+    }
+}
+
+/// Generates a random nonce for a transaction header.
+pub fn generate_secure_random_nonce() -> Result<u32, RandomError> {
+    generate_random_nonce_from(&mut OsEntropy)
+}
+
+/// Generates a random nonce for a transaction header from the source.
+pub fn generate_random_nonce_from(
+    source: &mut impl EntropySource,
+) -> Result<u32, RandomError> {
+    let mut bytes = [0u8; 4];
+    source.fill_bytes(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// Generates an Ed25519 key pair. Every 32 random bytes are a valid Ed25519
+/// private key.
+pub fn generate_ed25519_keypair() -> Result<Ed25519PrivateKey, RandomError> {
+    generate_ed25519_keypair_from(&mut OsEntropy)
+}
+
+/// Generates an Ed25519 key pair from the randomness of the source.
+pub fn generate_ed25519_keypair_from(
+    source: &mut impl EntropySource,
+) -> Result<Ed25519PrivateKey, RandomError> {
+    let mut bytes = [0u8; Ed25519PrivateKey::LENGTH];
+    source.fill_bytes(&mut bytes)?;
+    Ed25519PrivateKey::from_bytes(&bytes).map_err(|_| RandomError::InvalidKey)
+}
+
+/// The number of times that random bytes are drawn for a Secp256k1 private
+/// key before giving up. Random bytes are not a valid scalar with a chance of
+/// about 2^-128, so running out of attempts means that the source is broken.
+pub const MAX_SECP256K1_KEY_ATTEMPTS: usize = 16;
+
+/// Generates a Secp256k1 key pair. Random bytes that are not a valid scalar
+/// for the curve are discarded and drawn again which is vanishingly rare, up
+/// to [`MAX_SECP256K1_KEY_ATTEMPTS`] times.
+pub fn generate_secp256k1_keypair() -> Result<Secp256k1PrivateKey, RandomError>
+{
+    generate_secp256k1_keypair_from(&mut OsEntropy)
+}
+
+/// Generates a Secp256k1 key pair from the randomness of the source.
+pub fn generate_secp256k1_keypair_from(
+    source: &mut impl EntropySource,
+) -> Result<Secp256k1PrivateKey, RandomError> {
+    let mut bytes = [0u8; Secp256k1PrivateKey::LENGTH];
+    for _ in 0..MAX_SECP256K1_KEY_ATTEMPTS {
+        source.fill_bytes(&mut bytes)?;
+        if let Ok(private_key) = Secp256k1PrivateKey::from_bytes(&bytes) {
+            return Ok(private_key);
+        }
+    }
+    Err(RandomError::InvalidKey)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RandomError {
+    /// The operating system failed to provide randomness.
+    EntropyUnavailable(getrandom::Error),
+    InvalidKey,
+}
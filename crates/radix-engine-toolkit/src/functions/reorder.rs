@@ -0,0 +1,385 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Checks whether the instructions of a manifest can be reordered without
+//! breaking the flow of buckets, proofs, and addresses between them. This is
+//! of use to manifest optimizers and to builders that move instructions such
+//! as the fee lock around after the manifest has been built.
+//!
+//! Buckets, proofs, and address reservations are allocated ids in the order of
+//! the instructions that create them. The dependencies are computed from the
+//! ids of the original order, the ids must be reassigned after reordering.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::sbor::indexed_manifest_value::*;
+
+/// A dependency between two instructions where the dependent instruction must
+/// come after the instruction that it depends on. Both are the indices of the
+/// instructions in the original order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionDependency {
+    pub kind: DependencyKind,
+    pub dependency: usize,
+    pub dependent: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DependencyKind {
+    /// The dependent uses a bucket that the dependency creates.
+    BucketDefinition(ManifestBucket),
+    /// The dependent consumes a bucket that the dependency uses.
+    BucketConsumption(ManifestBucket),
+    /// The dependent uses a proof that the dependency creates.
+    ProofDefinition(ManifestProof),
+    /// The dependent consumes a proof that the dependency uses.
+    ProofConsumption(ManifestProof),
+    /// The dependent uses an address reservation that the dependency
+    /// allocates.
+    AddressReservationDefinition(ManifestAddressReservation),
+    /// The dependent uses a named address that the dependency allocates.
+    NamedAddressDefinition(u32),
+    /// The dependent is an assertion on the worktop contents that the
+    /// dependency leaves behind and must come immediately after it.
+    Assertion,
+}
+
+/// Computes the dependencies between the instructions. An order of the
+/// instructions preserves the flow between them if all of the dependencies
+/// are satisfied.
+pub fn instruction_dependencies(
+    instructions: &[InstructionV1],
+) -> Vec<InstructionDependency> {
+    let mut dependencies = Vec::new();
+
+    let mut next_bucket_id = 0u32;
+    let mut next_proof_id = 0u32;
+    let mut next_address_reservation_id = 0u32;
+    let mut next_named_address_id = 0u32;
+    let mut buckets = IndexMap::<ManifestBucket, Lifetime>::new();
+    let mut proofs = IndexMap::<ManifestProof, Lifetime>::new();
+    let mut address_reservations =
+        IndexMap::<ManifestAddressReservation, usize>::new();
+    let mut named_addresses = IndexMap::<u32, usize>::new();
+
+    for (instruction_index, instruction) in instructions.iter().enumerate() {
+        let mut used_buckets = Vec::new();
+        let mut consumed_buckets = Vec::new();
+        let mut used_proofs = Vec::new();
+        let mut consumed_proofs = Vec::new();
+        let mut used_address_reservations = Vec::new();
+        let mut used_named_addresses = Vec::new();
+        let mut created_bucket = false;
+        let mut created_proof = false;
+
+        match instruction {
+            InstructionV1::TakeFromWorktop { .. }
+            | InstructionV1::TakeNonFungiblesFromWorktop { .. }
+            | InstructionV1::TakeAllFromWorktop { .. } => {
+                created_bucket = true;
+            }
+            InstructionV1::ReturnToWorktop { bucket_id }
+            | InstructionV1::BurnResource { bucket_id } => {
+                consumed_buckets.push(*bucket_id);
+            }
+            InstructionV1::CreateProofFromBucketOfAmount {
+                bucket_id, ..
+            }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles {
+                bucket_id,
+                ..
+            }
+            | InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
+                used_buckets.push(*bucket_id);
+                created_proof = true;
+            }
+            InstructionV1::PopFromAuthZone
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. } => {
+                created_proof = true;
+            }
+            InstructionV1::CloneProof { proof_id } => {
+                used_proofs.push(*proof_id);
+                created_proof = true;
+            }
+            InstructionV1::PushToAuthZone { proof_id }
+            | InstructionV1::DropProof { proof_id } => {
+                consumed_proofs.push(*proof_id);
+            }
+            InstructionV1::DropNamedProofs | InstructionV1::DropAllProofs => {
+                consumed_proofs.extend(
+                    proofs
+                        .iter()
+                        .filter(|(_, lifetime)| !lifetime.is_consumed)
+                        .map(|(proof, _)| *proof),
+                );
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                args,
+                ..
+            } => {
+                if let DynamicPackageAddress::Named(named_address) =
+                    package_address
+                {
+                    used_named_addresses.push(*named_address);
+                }
+                used_values_of_args(
+                    args,
+                    &mut consumed_buckets,
+                    &mut consumed_proofs,
+                    &mut used_address_reservations,
+                    &mut used_named_addresses,
+                );
+            }
+            InstructionV1::CallMethod { address, args, .. }
+            | InstructionV1::CallRoyaltyMethod { address, args, .. }
+            | InstructionV1::CallMetadataMethod { address, args, .. }
+            | InstructionV1::CallRoleAssignmentMethod {
+                address, args, ..
+            } => {
+                if let DynamicGlobalAddress::Named(named_address) = address {
+                    used_named_addresses.push(*named_address);
+                }
+                used_values_of_args(
+                    args,
+                    &mut consumed_buckets,
+                    &mut consumed_proofs,
+                    &mut used_address_reservations,
+                    &mut used_named_addresses,
+                );
+            }
+            InstructionV1::CallDirectVaultMethod { args, .. } => {
+                used_values_of_args(
+                    args,
+                    &mut consumed_buckets,
+                    &mut consumed_proofs,
+                    &mut used_address_reservations,
+                    &mut used_named_addresses,
+                );
+            }
+            InstructionV1::AllocateGlobalAddress { .. } => {
+                address_reservations.insert(
+                    ManifestAddressReservation(next_address_reservation_id),
+                    instruction_index,
+                );
+                named_addresses
+                    .insert(next_named_address_id, instruction_index);
+                next_address_reservation_id += 1;
+                next_named_address_id += 1;
+            }
+            InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. } => {
+                if let Some(dependency) = instruction_index.checked_sub(1) {
+                    dependencies.push(InstructionDependency {
+                        kind: DependencyKind::Assertion,
+                        dependency,
+                        dependent: instruction_index,
+                    })
+                }
+            }
+            InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs => {}
+        }
+
+        for (bucket, is_consumed) in used_buckets
+            .into_iter()
+            .map(|bucket| (bucket, false))
+            .chain(consumed_buckets.into_iter().map(|bucket| (bucket, true)))
+        {
+            if let Some(lifetime) = buckets.get_mut(&bucket) {
+                lifetime.record_use(
+                    instruction_index,
+                    is_consumed,
+                    DependencyKind::BucketDefinition(bucket),
+                    DependencyKind::BucketConsumption(bucket),
+                    &mut dependencies,
+                );
+            }
+        }
+        for (proof, is_consumed) in used_proofs
+            .into_iter()
+            .map(|proof| (proof, false))
+            .chain(consumed_proofs.into_iter().map(|proof| (proof, true)))
+        {
+            if let Some(lifetime) = proofs.get_mut(&proof) {
+                lifetime.record_use(
+                    instruction_index,
+                    is_consumed,
+                    DependencyKind::ProofDefinition(proof),
+                    DependencyKind::ProofConsumption(proof),
+                    &mut dependencies,
+                );
+            }
+        }
+        for address_reservation in used_address_reservations {
+            if let Some(dependency) =
+                address_reservations.get(&address_reservation)
+            {
+                dependencies.push(InstructionDependency {
+                    kind: DependencyKind::AddressReservationDefinition(
+                        address_reservation,
+                    ),
+                    dependency: *dependency,
+                    dependent: instruction_index,
+                })
+            }
+        }
+        for named_address in used_named_addresses {
+            if let Some(dependency) = named_addresses.get(&named_address) {
+                dependencies.push(InstructionDependency {
+                    kind: DependencyKind::NamedAddressDefinition(named_address),
+                    dependency: *dependency,
+                    dependent: instruction_index,
+                })
+            }
+        }
+
+        if created_bucket {
+            buckets.insert(
+                ManifestBucket(next_bucket_id),
+                Lifetime::new(instruction_index),
+            );
+            next_bucket_id += 1;
+        }
+        if created_proof {
+            proofs.insert(
+                ManifestProof(next_proof_id),
+                Lifetime::new(instruction_index),
+            );
+            next_proof_id += 1;
+        }
+    }
+
+    dependencies
+}
+
+/// Checks whether the instructions can be reordered without breaking the flow
+/// between them. The permutation holds the original index of the instruction
+/// at each position of the new order. The dependencies that the new order
+/// violates are returned, the reorder is safe when there are none.
+pub fn check_reorder(
+    instructions: &[InstructionV1],
+    permutation: &[usize],
+) -> Result<Vec<InstructionDependency>, ReorderError> {
+    if permutation.len() != instructions.len() {
+        return Err(ReorderError::InvalidPermutationLength {
+            expected: instructions.len(),
+            actual: permutation.len(),
+        });
+    }
+
+    let mut positions = vec![None; instructions.len()];
+    for (position, original_index) in permutation.iter().enumerate() {
+        match positions.get_mut(*original_index) {
+            Some(slot @ None) => *slot = Some(position),
+            _ => {
+                return Err(ReorderError::InvalidPermutationIndex(
+                    *original_index,
+                ))
+            }
+        }
+    }
+    let positions = positions.into_iter().flatten().collect::<Vec<_>>();
+
+    Ok(instruction_dependencies(instructions)
+        .into_iter()
+        .filter(|dependency| {
+            let dependency_position = positions[dependency.dependency];
+            let dependent_position = positions[dependency.dependent];
+            match dependency.kind {
+                DependencyKind::Assertion => {
+                    dependent_position != dependency_position + 1
+                }
+                _ => dependent_position <= dependency_position,
+            }
+        })
+        .collect())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReorderError {
+    InvalidPermutationLength {
+        expected: usize,
+        actual: usize,
+    },
+    /// The index is either out of bounds or appears more than once.
+    InvalidPermutationIndex(usize),
+}
+
+/// The instructions that a bucket or proof flows through.
+struct Lifetime {
+    definition: usize,
+    uses: Vec<usize>,
+    is_consumed: bool,
+}
+
+impl Lifetime {
+    fn new(definition: usize) -> Self {
+        Self {
+            definition,
+            uses: Vec::new(),
+            is_consumed: false,
+        }
+    }
+
+    fn record_use(
+        &mut self,
+        instruction_index: usize,
+        is_consumed: bool,
+        definition_kind: DependencyKind,
+        consumption_kind: DependencyKind,
+        dependencies: &mut Vec<InstructionDependency>,
+    ) {
+        dependencies.push(InstructionDependency {
+            kind: definition_kind,
+            dependency: self.definition,
+            dependent: instruction_index,
+        });
+        if is_consumed {
+            dependencies.extend(self.uses.iter().map(|dependency| {
+                InstructionDependency {
+                    kind: consumption_kind,
+                    dependency: *dependency,
+                    dependent: instruction_index,
+                }
+            }));
+            self.is_consumed = true;
+        } else {
+            self.uses.push(instruction_index);
+        }
+    }
+}
+
+fn used_values_of_args(
+    args: &ManifestValue,
+    consumed_buckets: &mut Vec<ManifestBucket>,
+    consumed_proofs: &mut Vec<ManifestProof>,
+    used_address_reservations: &mut Vec<ManifestAddressReservation>,
+    used_named_addresses: &mut Vec<u32>,
+) {
+    let args = IndexedManifestValue::from_typed(args);
+    consumed_buckets.extend(args.buckets().iter().copied());
+    consumed_proofs.extend(args.proofs().iter().copied());
+    used_address_reservations
+        .extend(args.address_reservations().iter().copied());
+    used_named_addresses.extend(args.named_addresses().iter().copied());
+}
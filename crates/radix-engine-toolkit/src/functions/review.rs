@@ -0,0 +1,630 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Renders manifests as an ordered list of plain-language statements such as
+//! "Withdraw 10 XRD from account_..." for review screens where the manifest
+//! itself is too long or too technical to show. The statements are backed by
+//! the classification of the manifest and by the static analysis of the
+//! worktop, which resolves the resources that instructions move whenever they
+//! can be known statically.
+
+use radix_engine::system::system_modules::execution_trace::ResourceSpecifier;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::functions::annotations::well_known_address_name;
+use crate::functions::decimal::*;
+use crate::functions::royalty::*;
+use crate::sbor::indexed_manifest_value::IndexedManifestValue;
+use crate::transaction_types::*;
+use crate::utils::*;
+
+/// Controls how amounts and addresses are written in the statements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocaleOptions {
+    /// The separator between the integer and fractional parts of amounts.
+    pub decimal_separator: String,
+    /// The separator between groups of three digits of the integer part of
+    /// amounts or [`None`] if the digits are not grouped.
+    pub grouping_separator: Option<String>,
+    /// The number of decimal places that amounts are rounded to or [`None`]
+    /// if amounts are written in full.
+    pub decimal_places: Option<u8>,
+    /// Whether addresses are shortened to the start and end of their data
+    /// part. Well-known addresses are always written by their name.
+    pub shorten_addresses: bool,
+}
+
+impl Default for LocaleOptions {
+    fn default() -> Self {
+        Self {
+            decimal_separator: ".".to_owned(),
+            grouping_separator: None,
+            decimal_places: None,
+            shorten_addresses: false,
+        }
+    }
+}
+
+/// Renders the manifest as plain-language statements. The first statement
+/// summarizes the manifest as a whole if it's of a known class and is followed
+/// by a statement for every instruction that moves resources, pays fees,
+/// guarantees amounts, or calls into an entity, in the order of the
+/// instructions.
+pub fn render_manifest_summary(
+    manifest: &TransactionManifestV1,
+    network_definition: &NetworkDefinition,
+    locale_options: &LocaleOptions,
+) -> Vec<String> {
+    let bech32_encoder = AddressBech32Encoder::new(network_definition);
+    let mut renderer = StatementRenderer {
+        bech32_encoder: &bech32_encoder,
+        locale_options,
+        buckets: vec![],
+    };
+
+    let trusted_worktop = trusted_worktop(manifest);
+    let mut statements = classify_manifest(manifest)
+        .first()
+        .and_then(|classification| renderer.classification(classification))
+        .into_iter()
+        .collect::<Vec<_>>();
+    for (instruction_index, instruction) in
+        manifest.instructions.iter().enumerate()
+    {
+        let trusted_resources = trusted_worktop
+            .get(instruction_index)
+            .filter(|instruction| instruction.is_trusted)
+            .map(|instruction| instruction.resources.as_slice());
+        statements.extend(renderer.render(instruction, trusted_resources));
+    }
+    statements
+}
+
+struct StatementRenderer<'a> {
+    bech32_encoder: &'a AddressBech32Encoder,
+    locale_options: &'a LocaleOptions,
+    /// The descriptions of the resources in the buckets indexed by their ids.
+    /// Buckets are only created by taking from the worktop so their ids are
+    /// allocated in order.
+    buckets: Vec<String>,
+}
+
+impl<'a> StatementRenderer<'a> {
+    fn classification(
+        &self,
+        classification: &ManifestClassification,
+    ) -> Option<String> {
+        let statement = match classification {
+            ManifestClassification::General => return None,
+            ManifestClassification::SimpleTransfer(SimpleTransfer {
+                from,
+                to,
+                resource,
+                amount,
+            }) => {
+                let amount = match amount {
+                    SimpleTransferAmount::Amount(amount) => {
+                        self.fungible(amount, resource)
+                    }
+                    SimpleTransferAmount::Ids(ids) => {
+                        self.non_fungibles(ids.iter(), resource)
+                    }
+                };
+                format!(
+                    "Transfer {amount} from {} to {}",
+                    self.node_id(from.as_node_id()),
+                    self.node_id(to.as_node_id())
+                )
+            }
+            ManifestClassification::GeneralTransfer => {
+                "Transfer resources between accounts".to_owned()
+            }
+            ManifestClassification::PoolContribution { pool_addresses } => {
+                format!(
+                    "Contribute resources to {}",
+                    self.node_ids(pool_addresses.iter())
+                )
+            }
+            ManifestClassification::PoolRedemption { pool_addresses } => {
+                format!(
+                    "Redeem pool units from {}",
+                    self.node_ids(pool_addresses.iter())
+                )
+            }
+            ManifestClassification::ValidatorStake {
+                validator_addresses,
+            } => format!(
+                "Stake XRD with {}",
+                self.node_ids(validator_addresses.iter())
+            ),
+            ManifestClassification::ValidatorUnstake {
+                validator_addresses,
+            } => format!(
+                "Unstake XRD from {}",
+                self.node_ids(validator_addresses.iter())
+            ),
+            ManifestClassification::ValidatorClaim {
+                validator_addresses,
+            } => format!(
+                "Claim XRD from {}",
+                self.node_ids(validator_addresses.iter())
+            ),
+            ManifestClassification::AccountDepositSettingsUpdate {
+                resource_preferences_updates,
+                deposit_mode_updates,
+                authorized_depositors_updates,
+            } => {
+                let accounts = resource_preferences_updates
+                    .keys()
+                    .chain(deposit_mode_updates.keys())
+                    .chain(authorized_depositors_updates.keys())
+                    .collect::<IndexSet<_>>();
+                format!(
+                    "Update the deposit settings of {}",
+                    self.node_ids(accounts.into_iter())
+                )
+            }
+            ManifestClassification::AccountLockerClaim { locker_addresses } => {
+                format!(
+                    "Claim resources from {}",
+                    self.node_ids(locker_addresses.iter())
+                )
+            }
+        };
+        Some(statement)
+    }
+
+    /// Returns the statements of the instruction. The trusted resources are
+    /// the resources that the worktop analysis determined the instruction
+    /// moves or [`None`] if they can't be determined statically.
+    fn render(
+        &mut self,
+        instruction: &InstructionV1,
+        trusted_resources: Option<&[ResourceSpecifier]>,
+    ) -> Vec<String> {
+        match instruction {
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => {
+                let bucket = self.fungible(amount, resource_address);
+                self.buckets.push(bucket);
+                vec![]
+            }
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => {
+                let bucket = self.non_fungibles(ids.iter(), resource_address);
+                self.buckets.push(bucket);
+                vec![]
+            }
+            InstructionV1::TakeAllFromWorktop { resource_address } => {
+                let bucket = match trusted_resources {
+                    Some(resources) if !resources.is_empty() => {
+                        self.resources(resources)
+                    }
+                    _ => format!("all {}", self.resource(resource_address)),
+                };
+                self.buckets.push(bucket);
+                vec![]
+            }
+            InstructionV1::AssertWorktopContains {
+                resource_address,
+                amount,
+            } => vec![format!(
+                "Guarantee at least {}",
+                self.fungible(amount, resource_address)
+            )],
+            InstructionV1::AssertWorktopContainsNonFungibles {
+                resource_address,
+                ids,
+            } => vec![format!(
+                "Guarantee {}",
+                self.non_fungibles(ids.iter(), resource_address)
+            )],
+            InstructionV1::AssertWorktopContainsAny { resource_address } => {
+                vec![format!(
+                    "Guarantee a non-zero amount of {}",
+                    self.resource(resource_address)
+                )]
+            }
+            InstructionV1::BurnResource { bucket_id } => {
+                vec![format!("Burn {}", self.bucket(bucket_id))]
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                args,
+            } => {
+                let package = match package_address {
+                    DynamicPackageAddress::Static(address) => {
+                        self.node_id(address.as_node_id())
+                    }
+                    DynamicPackageAddress::Named(..) => {
+                        "a newly allocated package".to_owned()
+                    }
+                };
+                vec![format!(
+                    "Call `{blueprint_name}::{function_name}` of {package}{}",
+                    self.passed_resources(args, trusted_resources)
+                        .map(|resources| format!(" with {resources}"))
+                        .unwrap_or_default()
+                )]
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                self.method_call(address, method_name, args, trusted_resources)
+            }
+            InstructionV1::CallRoyaltyMethod {
+                address,
+                method_name,
+                ..
+            } => match royalty_change(instruction) {
+                Some((method, amount)) => vec![format!(
+                    "Set the royalty of `{method}` on {} to {}",
+                    self.address(address),
+                    self.royalty_amount(&amount)
+                )],
+                None => vec![format!(
+                    "Call `{method_name}` on the royalties of {}",
+                    self.address(address)
+                )],
+            },
+            InstructionV1::CallMetadataMethod {
+                address,
+                method_name,
+                ..
+            } => vec![format!(
+                "Call `{method_name}` on the metadata of {}",
+                self.address(address)
+            )],
+            InstructionV1::CallRoleAssignmentMethod {
+                address,
+                method_name,
+                ..
+            } => vec![format!(
+                "Call `{method_name}` on the role assignments of {}",
+                self.address(address)
+            )],
+            InstructionV1::CallDirectVaultMethod {
+                address,
+                method_name,
+                ..
+            } => vec![format!(
+                "Call `{method_name}` on the vault {}",
+                self.node_id(address.as_node_id())
+            )],
+            _ => vec![],
+        }
+    }
+
+    fn method_call(
+        &self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+        trusted_resources: Option<&[ResourceSpecifier]>,
+    ) -> Vec<String> {
+        let entity = self.address(address);
+        let passed_resources = self
+            .passed_resources(args, trusted_resources)
+            .unwrap_or_else(|| "resources".to_owned());
+        if is_account(address) {
+            match method_name {
+                ACCOUNT_LOCK_FEE_IDENT => {
+                    if let Some(AccountLockFeeInput { amount }) =
+                        to_manifest_type(args)
+                    {
+                        return vec![self.fee(&amount, &entity)];
+                    }
+                }
+                ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                    if let Some(AccountLockContingentFeeInput { amount }) =
+                        to_manifest_type(args)
+                    {
+                        return vec![format!(
+                            "Pay a fee of up to {} from {entity} if the \
+                             transaction succeeds",
+                            self.fungible(&amount, &XRD)
+                        )];
+                    }
+                }
+                ACCOUNT_WITHDRAW_IDENT
+                | ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                    if let Some(resources) =
+                        self.withdrawn_resources(args, trusted_resources)
+                    {
+                        return vec![format!(
+                            "Withdraw {resources} from {entity}"
+                        )];
+                    }
+                }
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                    if let Some(AccountLockFeeAndWithdrawInput {
+                        amount_to_lock,
+                        resource_address,
+                        amount,
+                    }) = to_manifest_type(args)
+                    {
+                        let resources = trusted_resources
+                            .filter(|resources| !resources.is_empty())
+                            .map(|resources| self.resources(resources))
+                            .unwrap_or_else(|| {
+                                self.fungible(&amount, &resource_address)
+                            });
+                        return vec![
+                            self.fee(&amount_to_lock, &entity),
+                            format!("Withdraw {resources} from {entity}"),
+                        ];
+                    }
+                }
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                    if let Some(AccountLockFeeAndWithdrawNonFungiblesInput {
+                        amount_to_lock,
+                        resource_address,
+                        ids,
+                    }) = to_manifest_type(args)
+                    {
+                        return vec![
+                            self.fee(&amount_to_lock, &entity),
+                            format!(
+                                "Withdraw {} from {entity}",
+                                self.non_fungibles(
+                                    ids.iter(),
+                                    &resource_address
+                                )
+                            ),
+                        ];
+                    }
+                }
+                ACCOUNT_DEPOSIT_IDENT
+                | ACCOUNT_DEPOSIT_BATCH_IDENT
+                | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+                | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT
+                | ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT
+                | ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => {
+                    return vec![format!(
+                        "Deposit {passed_resources} to {entity}"
+                    )];
+                }
+                _ => {}
+            }
+        } else if is_validator(address) {
+            match method_name {
+                VALIDATOR_STAKE_IDENT => {
+                    return vec![format!(
+                        "Stake {passed_resources} with {entity}"
+                    )];
+                }
+                VALIDATOR_UNSTAKE_IDENT => {
+                    return vec![format!(
+                        "Unstake {passed_resources} from {entity}"
+                    )];
+                }
+                VALIDATOR_CLAIM_XRD_IDENT => {
+                    return vec![format!(
+                        "Claim XRD from {entity} with {passed_resources}"
+                    )];
+                }
+                _ => {}
+            }
+        }
+
+        vec![format!(
+            "Call `{method_name}` on {entity}{}",
+            self.passed_resources(args, trusted_resources)
+                .map(|resources| format!(" with {resources}"))
+                .unwrap_or_default()
+        )]
+    }
+
+    fn royalty_amount(&self, amount: &RoyaltyAmount) -> String {
+        match amount {
+            RoyaltyAmount::Free => "free".to_owned(),
+            RoyaltyAmount::Xrd(amount) => {
+                format!("{} XRD", self.amount(amount))
+            }
+            RoyaltyAmount::Usd(amount) => {
+                format!("{} USD", self.amount(amount))
+            }
+        }
+    }
+
+    fn fee(&self, amount: &Decimal, account: &str) -> String {
+        format!(
+            "Pay a fee of up to {} from {account}",
+            self.fungible(amount, &XRD)
+        )
+    }
+
+    /// The resources withdrawn by a withdraw method of an account. Withdrawing
+    /// non-fungibles by amount is not trusted by the worktop analysis as the
+    /// ids are not known, in which case the amount is used.
+    fn withdrawn_resources(
+        &self,
+        args: &ManifestValue,
+        trusted_resources: Option<&[ResourceSpecifier]>,
+    ) -> Option<String> {
+        if let Some(resources) =
+            trusted_resources.filter(|resources| !resources.is_empty())
+        {
+            return Some(self.resources(resources));
+        }
+        if let Some(AccountWithdrawInput {
+            resource_address,
+            amount,
+        }) = to_manifest_type(args)
+        {
+            return Some(self.fungible(&amount, &resource_address));
+        }
+        let AccountWithdrawNonFungiblesInput {
+            resource_address,
+            ids,
+        } = to_manifest_type(args)?;
+        Some(self.non_fungibles(ids.iter(), &resource_address))
+    }
+
+    /// The resources passed to an invocation in buckets or by passing the
+    /// entire worktop or [`None`] if no resources are passed.
+    fn passed_resources(
+        &self,
+        args: &ManifestValue,
+        trusted_resources: Option<&[ResourceSpecifier]>,
+    ) -> Option<String> {
+        let args = IndexedManifestValue::from_manifest_value(args);
+        let mut resources = args
+            .buckets()
+            .iter()
+            .map(|bucket_id| self.bucket(bucket_id))
+            .collect::<Vec<_>>();
+        if args
+            .expressions()
+            .contains(&ManifestExpression::EntireWorktop)
+        {
+            resources.push(match trusted_resources {
+                Some(trusted_resources) if args.buckets().is_empty() => {
+                    self.resources(trusted_resources)
+                }
+                _ => "all of the resources on the worktop".to_owned(),
+            });
+        }
+        resources
+            .into_iter()
+            .filter(|resources| !resources.is_empty())
+            .reduce(|joined, resources| format!("{joined}, {resources}"))
+    }
+
+    fn bucket(&self, bucket_id: &ManifestBucket) -> String {
+        self.buckets
+            .get(bucket_id.0 as usize)
+            .cloned()
+            .unwrap_or_else(|| "an unknown bucket".to_owned())
+    }
+
+    fn resources(&self, resources: &[ResourceSpecifier]) -> String {
+        resources
+            .iter()
+            .map(|resource| match resource {
+                ResourceSpecifier::Amount(resource_address, amount) => {
+                    self.fungible(amount, resource_address)
+                }
+                ResourceSpecifier::Ids(resource_address, ids) => {
+                    self.non_fungibles(ids.iter(), resource_address)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn fungible(
+        &self,
+        amount: &Decimal,
+        resource_address: &ResourceAddress,
+    ) -> String {
+        format!(
+            "{} {}",
+            self.amount(amount),
+            self.resource(resource_address)
+        )
+    }
+
+    fn non_fungibles<'i>(
+        &self,
+        ids: impl Iterator<Item = &'i NonFungibleLocalId>,
+        resource_address: &ResourceAddress,
+    ) -> String {
+        format!(
+            "{} {}",
+            self.resource(resource_address),
+            ids.map(ToString::to_string).collect::<Vec<_>>().join(", ")
+        )
+    }
+
+    fn resource(&self, resource_address: &ResourceAddress) -> String {
+        self.node_id(resource_address.as_node_id())
+    }
+
+    fn address(&self, address: &DynamicGlobalAddress) -> String {
+        match address {
+            DynamicGlobalAddress::Static(address) => {
+                self.node_id(address.as_node_id())
+            }
+            DynamicGlobalAddress::Named(..) => {
+                "a newly allocated address".to_owned()
+            }
+        }
+    }
+
+    fn node_ids<'i>(
+        &self,
+        addresses: impl Iterator<Item = &'i ComponentAddress>,
+    ) -> String {
+        addresses
+            .map(|address| self.node_id(address.as_node_id()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The name of the entity if it's well-known and its address otherwise,
+    /// shortened if the locale options ask for it.
+    fn node_id(&self, node_id: &NodeId) -> String {
+        if let Some(name) = well_known_address_name(node_id) {
+            return name.to_owned();
+        }
+        let Ok(address) = self.bech32_encoder.encode(node_id.as_bytes()) else {
+            return "an unknown address".to_owned();
+        };
+        if !self.locale_options.shorten_addresses {
+            return address;
+        }
+        // The data part starts after the separator of the human readable part
+        // and addresses are ASCII so they can be sliced by bytes.
+        match address.rfind('1') {
+            Some(separator) if address.len() > separator + 11 => format!(
+                "{}...{}",
+                &address[..separator + 5],
+                &address[address.len() - 6..]
+            ),
+            _ => address,
+        }
+    }
+
+    fn amount(&self, amount: &Decimal) -> String {
+        let options = DecimalFormatOptions {
+            locale: DecimalLocaleOptions {
+                decimal_separator: self
+                    .locale_options
+                    .decimal_separator
+                    .clone(),
+                thousands_separator: self
+                    .locale_options
+                    .grouping_separator
+                    .clone(),
+            },
+            max_fraction_digits: self.locale_options.decimal_places,
+            rounding_mode: RoundingMode::ToNearestMidpointAwayFromZero,
+        };
+        format_decimal(amount, &options).unwrap_or_else(|_| amount.to_string())
+    }
+}
@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! ROLA (Radix Off-Ledger Authentication) allows dApps to verify that a user
+//! controls an account or persona by having the wallet sign a challenge with
+//! the keys of the entity. The signed message binds the challenge to the dApp
+//! definition address and the origin of the dApp so that a signed challenge
+//! can't be replayed against another dApp.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::functions::derive::{
+    virtual_account_address_from_public_key,
+    virtual_identity_address_from_public_key,
+};
+use crate::functions::signing::verify_signature;
+
+/// The first byte of every ROLA message.
+pub const ROLA_MESSAGE_PREFIX: u8 = b'R';
+/// The length of the challenges issued by dApps.
+pub const ROLA_CHALLENGE_LENGTH: usize = 32;
+
+/// Constructs the ROLA message which is the prefix, followed by the challenge,
+/// the length of the dApp definition address as a single byte, the dApp
+/// definition address, and the origin.
+pub fn rola_message(
+    challenge: &[u8],
+    dapp_definition_address: &str,
+    origin: &str,
+) -> Result<Vec<u8>, RolaError> {
+    if challenge.len() != ROLA_CHALLENGE_LENGTH {
+        return Err(RolaError::InvalidChallengeLength {
+            length: challenge.len(),
+        });
+    }
+    let dapp_definition_address_length =
+        u8::try_from(dapp_definition_address.len()).map_err(|_| {
+            RolaError::DappDefinitionAddressTooLong {
+                length: dapp_definition_address.len(),
+            }
+        })?;
+
+    let mut message = Vec::with_capacity(
+        1 + challenge.len() + 1 + dapp_definition_address.len() + origin.len(),
+    );
+    message.push(ROLA_MESSAGE_PREFIX);
+    message.extend(challenge);
+    message.push(dapp_definition_address_length);
+    message.extend(dapp_definition_address.as_bytes());
+    message.extend(origin.as_bytes());
+    Ok(message)
+}
+
+/// The hash of the ROLA message which is what the wallet signs.
+pub fn rola_hash(
+    challenge: &[u8],
+    dapp_definition_address: &str,
+    origin: &str,
+) -> Result<Hash, RolaError> {
+    rola_message(challenge, dapp_definition_address, origin).map(hash)
+}
+
+/// Verifies that the signature is a signature over the ROLA hash by a key that
+/// controls the entity. The owner keys are the value of the `owner_keys`
+/// metadata of the entity which must be an array of public key hashes. An
+/// entity without owner keys is a virtual entity which is controlled by the
+/// key that its address is derived from.
+pub fn verify_rola_signature(
+    challenge: &[u8],
+    dapp_definition_address: &str,
+    origin: &str,
+    entity_address: &ComponentAddress,
+    public_key: &PublicKey,
+    signature: &SignatureV1,
+    owner_keys: Option<&MetadataValue>,
+) -> Result<(), RolaError> {
+    let hash = rola_hash(challenge, dapp_definition_address, origin)?;
+    if !verify_signature(&hash, signature, public_key) {
+        return Err(RolaError::InvalidSignature);
+    }
+
+    match owner_keys {
+        Some(MetadataValue::PublicKeyHashArray(owner_keys)) => {
+            if owner_keys
+                .contains(&PublicKeyHash::new_from_public_key(public_key))
+            {
+                Ok(())
+            } else {
+                Err(RolaError::PublicKeyIsNotAnOwnerKey)
+            }
+        }
+        Some(..) => Err(RolaError::InvalidOwnerKeysMetadata),
+        None => {
+            if *entity_address
+                == virtual_account_address_from_public_key(public_key)
+                || *entity_address
+                    == virtual_identity_address_from_public_key(public_key)
+            {
+                Ok(())
+            } else {
+                Err(RolaError::PublicKeyDoesNotControlEntity)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RolaError {
+    InvalidChallengeLength { length: usize },
+    DappDefinitionAddressTooLong { length: usize },
+    InvalidSignature,
+    InvalidOwnerKeysMetadata,
+    PublicKeyIsNotAnOwnerKey,
+    PublicKeyDoesNotControlEntity,
+}
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Royalty amounts as they appear in the arguments of the royalty module and
+//! the construction of the instructions that change them.
+
+use radix_engine_interface::object_modules::royalty::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::utils::*;
+
+pub fn royalty_amount_to_manifest_value(
+    amount: &RoyaltyAmount,
+) -> ManifestValue {
+    to_manifest_value_and_unwrap!(amount)
+}
+
+/// Decodes a royalty amount from a manifest value, [`None`] is returned if
+/// the value isn't a royalty amount.
+pub fn royalty_amount_from_manifest_value(
+    value: &ManifestValue,
+) -> Option<RoyaltyAmount> {
+    to_manifest_type(value)
+}
+
+/// The instruction that sets the royalty charged for calling the method of a
+/// component to the given amount.
+pub fn set_component_royalty(
+    address: impl Into<DynamicGlobalAddress>,
+    method: &str,
+    amount: RoyaltyAmount,
+) -> InstructionV1 {
+    InstructionV1::CallRoyaltyMethod {
+        address: address.into(),
+        method_name: COMPONENT_ROYALTY_SET_ROYALTY_IDENT.to_owned(),
+        args: to_manifest_value_and_unwrap!(&ComponentRoyaltySetInput {
+            method: method.to_owned(),
+            amount,
+        }),
+    }
+}
+
+/// The royalty set by the instruction as the method and the amount or [`None`]
+/// if the instruction doesn't set a royalty.
+pub fn royalty_change(
+    instruction: &InstructionV1,
+) -> Option<(String, RoyaltyAmount)> {
+    match instruction {
+        InstructionV1::CallRoyaltyMethod {
+            method_name, args, ..
+        } if method_name == COMPONENT_ROYALTY_SET_ROYALTY_IDENT => {
+            to_manifest_type(args).map(
+                |ComponentRoyaltySetInput { method, amount }| (method, amount),
+            )
+        }
+        _ => None,
+    }
+}
@@ -23,6 +23,9 @@ use sbor_json::scrypto::programmatic::value::ProgrammaticScryptoValue;
 use scrypto::address::*;
 use scrypto::prelude::*;
 
+use crate::sbor::scrypto_event_stream::*;
+use crate::sbor::value_query::*;
+
 pub fn encode(value: &ScryptoValue) -> Result<Vec<u8>, EncodeError> {
     scrypto_encode(value)
 }
@@ -34,6 +37,27 @@ where
     scrypto_decode(value.as_ref())
 }
 
+/// Decodes the value and returns its sub-values that match the path. See the
+/// [`value_query`](crate::sbor::value_query) module for the path language.
+pub fn query<T>(
+    value: T,
+    path: &str,
+) -> Result<Vec<ScryptoValue>, ScryptoSborError>
+where
+    T: AsRef<[u8]>,
+{
+    let path = parse_path(path).map_err(ScryptoSborError::InvalidQueryPath)?;
+    let value = decode(value).map_err(ScryptoSborError::DecodeError)?;
+    Ok(crate::sbor::value_query::query(&value, &path)
+        .into_iter()
+        .cloned()
+        .collect())
+}
+
+pub fn decode_to_events(value: &[u8]) -> ScryptoSborEventStream<'_> {
+    ScryptoSborEventStream::new(value)
+}
+
 pub fn decode_to_string_representation<T>(
     value: T,
     representation: SerializationMode,
@@ -99,12 +123,183 @@ pub fn encode_string_representation(
                 return Err(ScryptoSborError::ValueContainsNetworkMismatch);
             }
 
-            let value = value.to_scrypto_value();
+            let value = value.into_scrypto_value();
             scrypto_encode(&value).map_err(ScryptoSborError::EncodeError)
         }
     }
 }
 
+/// Encodes the string representation of a value of the type with the given id
+/// in the schema. The names of the types, fields, and enum variants given in
+/// the string representation are checked against the names in the schema and
+/// the encoded value is validated against the schema before it's returned.
+pub fn encode_string_representation_with_schema(
+    representation: StringRepresentation,
+    local_type_id: LocalTypeId,
+    schema: &Schema<ScryptoCustomSchema>,
+) -> Result<Vec<u8>, ScryptoSborError> {
+    match representation {
+        StringRepresentation::ProgrammaticJson(ref value) => {
+            let value = serde_json::from_str::<serde_json::Value>(value)
+                .map_err(ScryptoSborError::SerdeDeserializationFailed)?;
+            validate_programmatic_json_names(&value, schema, local_type_id)?;
+        }
+    }
+
+    let encoded = encode_string_representation(representation)?;
+    validate_payload_against_schema::<ScryptoCustomExtension, _>(
+        &encoded,
+        schema,
+        local_type_id,
+        &(),
+        SCRYPTO_SBOR_V1_MAX_DEPTH,
+    )
+    .map_err(|_| ScryptoSborError::SchemaValidationError)?;
+
+    Ok(encoded)
+}
+
+/// Checks the names given in the programmatic JSON against the names in the
+/// schema. The names are optional in the programmatic JSON and are only
+/// checked where they're given. Values that don't have the structure that the
+/// schema expects are skipped as they fail the validation of the encoded
+/// value.
+fn validate_programmatic_json_names(
+    value: &serde_json::Value,
+    schema: &Schema<ScryptoCustomSchema>,
+    type_id: LocalTypeId,
+) -> Result<(), ScryptoSborError> {
+    let (Some(type_kind), Some(type_metadata)) = (
+        schema.resolve_type_kind(type_id),
+        schema.resolve_type_metadata(type_id),
+    ) else {
+        return Err(ScryptoSborError::SchemaValidationError);
+    };
+    check_name(
+        value,
+        "type_name",
+        type_metadata.type_name.as_deref(),
+        |expected, actual| ScryptoSborError::TypeNameMismatch {
+            expected,
+            actual,
+        },
+    )?;
+
+    let children = |key: &str| {
+        value
+            .get(key)
+            .and_then(serde_json::Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    };
+    match type_kind {
+        TypeKind::Tuple { field_types } => validate_fields(
+            children("fields"),
+            field_types,
+            type_metadata,
+            schema,
+        ),
+        TypeKind::Enum { variants } => {
+            let Some((discriminator, field_types)) = value
+                .get("variant_id")
+                .and_then(serde_json::Value::as_str)
+                .and_then(|variant_id| variant_id.parse::<u8>().ok())
+                .and_then(|discriminator| {
+                    variants
+                        .get(&discriminator)
+                        .map(|field_types| (discriminator, field_types))
+                })
+            else {
+                return Ok(());
+            };
+            let variant_metadata = match &type_metadata.child_names {
+                Some(ChildNames::EnumVariants(variants)) => {
+                    variants.get(&discriminator)
+                }
+                _ => None,
+            };
+            check_name(
+                value,
+                "variant_name",
+                variant_metadata
+                    .and_then(|metadata| metadata.type_name.as_deref()),
+                |expected, actual| ScryptoSborError::VariantNameMismatch {
+                    expected,
+                    actual,
+                },
+            )?;
+            match variant_metadata {
+                Some(variant_metadata) => validate_fields(
+                    children("fields"),
+                    field_types,
+                    variant_metadata,
+                    schema,
+                ),
+                None => Ok(()),
+            }
+        }
+        TypeKind::Array { element_type } => {
+            children("elements").iter().try_for_each(|element| {
+                validate_programmatic_json_names(element, schema, *element_type)
+            })
+        }
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => children("entries").iter().try_for_each(|entry| {
+            if let Some(key) = entry.get("key") {
+                validate_programmatic_json_names(key, schema, *key_type)?;
+            }
+            if let Some(value) = entry.get("value") {
+                validate_programmatic_json_names(value, schema, *value_type)?;
+            }
+            Ok(())
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn validate_fields(
+    fields: &[serde_json::Value],
+    field_types: &[LocalTypeId],
+    metadata: &TypeMetadata,
+    schema: &Schema<ScryptoCustomSchema>,
+) -> Result<(), ScryptoSborError> {
+    let field_names = match &metadata.child_names {
+        Some(ChildNames::NamedFields(field_names)) => field_names.as_slice(),
+        _ => &[],
+    };
+    for (index, (field, field_type)) in
+        fields.iter().zip(field_types).enumerate()
+    {
+        check_name(
+            field,
+            "field_name",
+            field_names.get(index).map(|name| name.as_ref()),
+            |expected, actual| ScryptoSborError::FieldNameMismatch {
+                expected,
+                actual,
+            },
+        )?;
+        validate_programmatic_json_names(field, schema, *field_type)?;
+    }
+    Ok(())
+}
+
+fn check_name(
+    value: &serde_json::Value,
+    key: &str,
+    expected: Option<&str>,
+    error: impl FnOnce(String, String) -> ScryptoSborError,
+) -> Result<(), ScryptoSborError> {
+    match (expected, value.get(key).and_then(serde_json::Value::as_str)) {
+        (Some(expected), Some(actual)) if expected != actual => {
+            Err(error(expected.to_owned(), actual.to_owned()))
+        }
+        _ => Ok(()),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum StringRepresentation {
     ProgrammaticJson(String),
@@ -117,4 +312,8 @@ pub enum ScryptoSborError {
     EncodeError(EncodeError),
     SerdeDeserializationFailed(serde_json::Error),
     ValueContainsNetworkMismatch,
+    InvalidQueryPath(ValueQueryError),
+    TypeNameMismatch { expected: String, actual: String },
+    FieldNameMismatch { expected: String, actual: String },
+    VariantNameMismatch { expected: String, actual: String },
 }
@@ -17,11 +17,12 @@
 
 use radix_transactions::errors::*;
 use radix_transactions::model::*;
+use radix_transactions::prelude::{PrivateKey, Signer};
 use radix_transactions::validation::*;
 use sbor::*;
 use scrypto::prelude::*;
 
-use crate::models::transaction_hash::TransactionHash;
+use crate::models::transaction_hash::{PayloadHashError, TransactionHash};
 
 pub fn hash(
     signed_intent: &SignedIntentV1,
@@ -34,6 +35,18 @@ pub fn hash(
         })
 }
 
+/// Computes the signed intent hash of a compiled signed intent.
+pub fn hash_from_payload<T>(
+    payload_bytes: T,
+) -> Result<TransactionHash, PayloadHashError>
+where
+    T: AsRef<[u8]>,
+{
+    let signed_intent =
+        decompile(payload_bytes).map_err(PayloadHashError::DecodeError)?;
+    hash(&signed_intent).map_err(PayloadHashError::PrepareError)
+}
+
 pub fn compile(signed_intent: &SignedIntentV1) -> Result<Vec<u8>, EncodeError> {
     signed_intent.to_payload_bytes()
 }
@@ -55,3 +68,19 @@ pub fn statically_validate(
         .map_err(TransactionValidationError::PrepareError)
         .and_then(|prepared| validator.validate_intent_v1(&prepared.intent))
 }
+
+/// Notarizes the signed intent by signing its signed intent hash with the
+/// notary private key.
+pub fn notarize(
+    signed_intent: SignedIntentV1,
+    notary_private_key: &PrivateKey,
+) -> Result<NotarizedTransactionV1, PrepareError> {
+    let signed_intent_hash = signed_intent.prepare()?.signed_intent_hash();
+    let notary_signature = NotarySignatureV1(
+        notary_private_key.sign_without_public_key(&signed_intent_hash),
+    );
+    Ok(NotarizedTransactionV1 {
+        signed_intent,
+        notary_signature,
+    })
+}
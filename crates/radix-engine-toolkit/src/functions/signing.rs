@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+pub fn private_key_from_bytes(
+    bytes: &[u8],
+    curve: CurveType,
+) -> Result<PrivateKey, SigningError> {
+    let invalid_private_key = || SigningError::InvalidPrivateKey {
+        curve,
+        length: bytes.len(),
+    };
+    match curve {
+        CurveType::Secp256k1 => Secp256k1PrivateKey::from_bytes(bytes)
+            .map(PrivateKey::Secp256k1)
+            .map_err(|_| invalid_private_key()),
+        CurveType::Ed25519 => Ed25519PrivateKey::from_bytes(bytes)
+            .map(PrivateKey::Ed25519)
+            .map_err(|_| invalid_private_key()),
+    }
+}
+
+pub fn public_key_from_bytes(
+    bytes: &[u8],
+    curve: CurveType,
+) -> Result<PublicKey, SigningError> {
+    let invalid_public_key = || SigningError::InvalidPublicKey {
+        curve,
+        length: bytes.len(),
+    };
+    match curve {
+        CurveType::Secp256k1 => Secp256k1PublicKey::try_from(bytes)
+            .map(PublicKey::Secp256k1)
+            .map_err(|_| invalid_public_key()),
+        CurveType::Ed25519 => Ed25519PublicKey::try_from(bytes)
+            .map(PublicKey::Ed25519)
+            .map_err(|_| invalid_public_key()),
+    }
+}
+
+pub fn signature_from_bytes(
+    bytes: &[u8],
+    curve: CurveType,
+) -> Result<SignatureV1, SigningError> {
+    let invalid_signature = || SigningError::InvalidSignature {
+        curve,
+        length: bytes.len(),
+    };
+    match curve {
+        CurveType::Secp256k1 => Secp256k1Signature::try_from(bytes)
+            .map(SignatureV1::Secp256k1)
+            .map_err(|_| invalid_signature()),
+        CurveType::Ed25519 => Ed25519Signature::try_from(bytes)
+            .map(SignatureV1::Ed25519)
+            .map_err(|_| invalid_signature()),
+    }
+}
+
+pub fn sign_hash(
+    hash: &Hash,
+    private_key: &PrivateKey,
+) -> SignatureWithPublicKeyV1 {
+    private_key.sign_with_public_key(hash)
+}
+
+/// Verifies that the signature is a signature over the hash produced by the
+/// given public key. A signature and a public key of different curves never
+/// verify.
+pub fn verify_signature(
+    hash: &Hash,
+    signature: &SignatureV1,
+    public_key: &PublicKey,
+) -> bool {
+    match (signature, public_key) {
+        (
+            SignatureV1::Secp256k1(signature),
+            PublicKey::Secp256k1(public_key),
+        ) => verify_secp256k1(hash, public_key, signature),
+        (SignatureV1::Ed25519(signature), PublicKey::Ed25519(public_key)) => {
+            verify_ed25519(hash, public_key, signature)
+        }
+        (SignatureV1::Secp256k1(..), PublicKey::Ed25519(..))
+        | (SignatureV1::Ed25519(..), PublicKey::Secp256k1(..)) => false,
+    }
+}
+
+/// Recovers the public key of a signature if the signature is a valid signature
+/// over the given hash.
+pub fn recover_public_key(
+    hash: &Hash,
+    signature: &SignatureWithPublicKeyV1,
+) -> Option<PublicKey> {
+    match signature {
+        SignatureWithPublicKeyV1::Secp256k1 { signature } => {
+            recover_secp256k1(hash, signature).map(PublicKey::Secp256k1)
+        }
+        SignatureWithPublicKeyV1::Ed25519 {
+            public_key,
+            signature,
+        } => verify_ed25519(hash, public_key, signature)
+            .then_some(PublicKey::Ed25519(*public_key)),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SigningError {
+    InvalidPrivateKey { curve: CurveType, length: usize },
+    InvalidPublicKey { curve: CurveType, length: usize },
+    InvalidSignature { curve: CurveType, length: usize },
+}
@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Manifests for staking with validators and for the operations that the owner
+//! of a validator performs on it. Resources are withdrawn from the account,
+//! taken from the worktop into a bucket that's passed to the validator, and
+//! whatever the validator returns is deposited back into the account. The
+//! operations of the owner are authorized with a proof of the owner badge of
+//! the validator from the account.
+
+use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::functions::derive::validator_owner_badge_from_validator_address;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidatorOperation {
+    /// Stakes XRD with the validator in exchange for its stake units.
+    Stake {
+        validator_address: ComponentAddress,
+        amount: Decimal,
+    },
+    /// Unstakes stake units of the validator in exchange for a claim NFT.
+    Unstake {
+        validator_address: ComponentAddress,
+        stake_unit_resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+    /// Claims the XRD of claim NFTs whose unstaking delay has passed.
+    ClaimXrd {
+        validator_address: ComponentAddress,
+        claim_nft_resource_address: ResourceAddress,
+        ids: IndexSet<NonFungibleLocalId>,
+    },
+    Register {
+        validator_address: ComponentAddress,
+    },
+    Unregister {
+        validator_address: ComponentAddress,
+    },
+    UpdateFee {
+        validator_address: ComponentAddress,
+        new_fee_factor: Decimal,
+    },
+    /// Locks stake units of the validator in the owner stake vault.
+    LockOwnerStakeUnits {
+        validator_address: ComponentAddress,
+        stake_unit_resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+}
+
+/// Builds the manifest that performs the operations in order with the
+/// resources of the account.
+pub fn build_validator_manifest(
+    account_address: ComponentAddress,
+    operations: &[ValidatorOperation],
+) -> TransactionManifestV1 {
+    operations
+        .iter()
+        .enumerate()
+        .fold(ManifestBuilder::new(), |builder, (index, operation)| {
+            add_validator_operation(
+                builder,
+                account_address,
+                operation,
+                &format!("bucket{index}"),
+            )
+        })
+        .build()
+}
+
+fn add_validator_operation(
+    builder: ManifestBuilder,
+    account_address: ComponentAddress,
+    operation: &ValidatorOperation,
+    bucket: &str,
+) -> ManifestBuilder {
+    let call_with_bucket = |builder: ManifestBuilder,
+                            validator_address: ComponentAddress,
+                            method_name: &str| {
+        builder.call_method_with_name_lookup(
+            validator_address,
+            method_name,
+            |lookup| (lookup.bucket(bucket),),
+        )
+    };
+    let owner_proof =
+        |builder: ManifestBuilder, validator_address: &ComponentAddress| {
+            let badge =
+                validator_owner_badge_from_validator_address(validator_address);
+            builder.create_proof_from_account_of_non_fungibles(
+                account_address,
+                badge.resource_address(),
+                [badge.local_id().clone()],
+            )
+        };
+
+    match operation {
+        ValidatorOperation::Stake {
+            validator_address,
+            amount,
+        } => {
+            let builder = builder
+                .withdraw_from_account(account_address, XRD, *amount)
+                .take_from_worktop(XRD, *amount, bucket);
+            call_with_bucket(builder, *validator_address, VALIDATOR_STAKE_IDENT)
+                .try_deposit_entire_worktop_or_abort(account_address, None)
+        }
+        ValidatorOperation::Unstake {
+            validator_address,
+            stake_unit_resource_address,
+            amount,
+        } => {
+            let builder = builder
+                .withdraw_from_account(
+                    account_address,
+                    *stake_unit_resource_address,
+                    *amount,
+                )
+                .take_from_worktop(
+                    *stake_unit_resource_address,
+                    *amount,
+                    bucket,
+                );
+            call_with_bucket(
+                builder,
+                *validator_address,
+                VALIDATOR_UNSTAKE_IDENT,
+            )
+            .try_deposit_entire_worktop_or_abort(account_address, None)
+        }
+        ValidatorOperation::ClaimXrd {
+            validator_address,
+            claim_nft_resource_address,
+            ids,
+        } => {
+            let builder = builder
+                .withdraw_non_fungibles_from_account(
+                    account_address,
+                    *claim_nft_resource_address,
+                    ids.clone(),
+                )
+                .take_non_fungibles_from_worktop(
+                    *claim_nft_resource_address,
+                    ids.clone(),
+                    bucket,
+                );
+            call_with_bucket(
+                builder,
+                *validator_address,
+                VALIDATOR_CLAIM_XRD_IDENT,
+            )
+            .try_deposit_entire_worktop_or_abort(account_address, None)
+        }
+        ValidatorOperation::Register { validator_address } => owner_proof(
+            builder,
+            validator_address,
+        )
+        .call_method(*validator_address, VALIDATOR_REGISTER_IDENT, ()),
+        ValidatorOperation::Unregister { validator_address } => owner_proof(
+            builder,
+            validator_address,
+        )
+        .call_method(*validator_address, VALIDATOR_UNREGISTER_IDENT, ()),
+        ValidatorOperation::UpdateFee {
+            validator_address,
+            new_fee_factor,
+        } => owner_proof(builder, validator_address).call_method(
+            *validator_address,
+            VALIDATOR_UPDATE_FEE_IDENT,
+            (*new_fee_factor,),
+        ),
+        ValidatorOperation::LockOwnerStakeUnits {
+            validator_address,
+            stake_unit_resource_address,
+            amount,
+        } => {
+            let builder = owner_proof(builder, validator_address)
+                .withdraw_from_account(
+                    account_address,
+                    *stake_unit_resource_address,
+                    *amount,
+                )
+                .take_from_worktop(
+                    *stake_unit_resource_address,
+                    *amount,
+                    bucket,
+                );
+            call_with_bucket(
+                builder,
+                *validator_address,
+                VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT,
+            )
+        }
+    }
+}
@@ -0,0 +1,668 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The wallet interactions are the requests that dApps send to the Radix
+//! wallet either through the connector extension or as mobile deep links. The
+//! interactions are JSON objects and this module encodes them into and decodes
+//! them from their canonical JSON form: the keys of all objects are sorted and
+//! there is no insignificant whitespace. The hash of an interaction is the
+//! hash of its canonical form so that the dApp and the wallet agree on it
+//! regardless of how either of them formatted the JSON.
+
+use scrypto::prelude::*;
+use serde_json::{Map, Value};
+
+/// The version of the wallet interaction format that this module implements.
+pub const WALLET_INTERACTION_VERSION: u64 = 2;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalletInteraction {
+    pub interaction_id: String,
+    pub metadata: WalletInteractionMetadata,
+    pub items: WalletInteractionItems,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalletInteractionMetadata {
+    pub version: u64,
+    pub network_id: u8,
+    pub origin: String,
+    pub dapp_definition_address: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WalletInteractionItems {
+    Transaction(TransactionRequest),
+    UnauthorizedRequest(UnauthorizedDataRequest),
+    AuthorizedRequest(AuthorizedDataRequest),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRequest {
+    pub transaction_manifest: String,
+    pub version: u64,
+    pub blobs: Vec<Vec<u8>>,
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnauthorizedDataRequest {
+    pub one_time_accounts: Option<AccountsRequest>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizedDataRequest {
+    pub auth: AuthRequest,
+    pub ongoing_accounts: Option<AccountsRequest>,
+    pub one_time_accounts: Option<AccountsRequest>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuthRequest {
+    LoginWithoutChallenge,
+    LoginWithChallenge { challenge: [u8; 32] },
+    UsePersona { identity_address: String },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountsRequest {
+    pub number_of_accounts: NumberOfValues,
+    /// The ROLA challenge that the wallet signs with each of the accounts to
+    /// prove their ownership.
+    pub challenge: Option<[u8; 32]>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberOfValues {
+    pub quantifier: Quantifier,
+    pub quantity: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Quantifier {
+    Exactly,
+    AtLeast,
+}
+
+/// Encodes the wallet interaction into its canonical JSON form.
+pub fn encode_wallet_interaction(interaction: &WalletInteraction) -> String {
+    canonical_json(&interaction.to_json())
+}
+
+/// Decodes a wallet interaction from JSON in any formatting.
+pub fn decode_wallet_interaction(
+    json: &str,
+) -> Result<WalletInteraction, WalletInteractionError> {
+    let value = serde_json::from_str::<Value>(json).map_err(|error| {
+        WalletInteractionError::InvalidJson(error.to_string())
+    })?;
+    WalletInteraction::from_json(&value, "$")
+}
+
+/// Brings the JSON of a wallet interaction into its canonical form without
+/// interpreting it.
+pub fn canonicalize_wallet_interaction(
+    json: &str,
+) -> Result<String, WalletInteractionError> {
+    serde_json::from_str::<Value>(json)
+        .map(|value| canonical_json(&value))
+        .map_err(|error| WalletInteractionError::InvalidJson(error.to_string()))
+}
+
+/// The hash of the canonical form of the wallet interaction.
+pub fn wallet_interaction_hash(interaction: &WalletInteraction) -> Hash {
+    hash(encode_wallet_interaction(interaction))
+}
+
+/// Constructs the deep link that opens the wallet with the interaction. The
+/// canonical form of the interaction is percent-encoded into the `request`
+/// query parameter of the link.
+pub fn wallet_interaction_deep_link(
+    base_url: &str,
+    interaction: &WalletInteraction,
+) -> String {
+    format!(
+        "{base_url}?request={}",
+        percent_encode(&encode_wallet_interaction(interaction))
+    )
+}
+
+/// Decodes the wallet interaction from the `request` query parameter of a
+/// deep link.
+pub fn decode_wallet_interaction_deep_link(
+    deep_link: &str,
+) -> Result<WalletInteraction, WalletInteractionError> {
+    let request = deep_link
+        .split_once('?')
+        .and_then(|(_, query)| {
+            query
+                .split('&')
+                .find_map(|parameter| parameter.strip_prefix("request="))
+        })
+        .ok_or(WalletInteractionError::MissingRequestParameter)?;
+    decode_wallet_interaction(&percent_decode(request)?)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WalletInteractionError {
+    InvalidJson(String),
+    /// The JSON value at the path isn't of the kind that the wallet
+    /// interaction format expects there.
+    InvalidValue {
+        path: String,
+        expected: String,
+    },
+    MissingField {
+        path: String,
+    },
+    UnknownDiscriminator {
+        path: String,
+        discriminator: String,
+    },
+    MissingRequestParameter,
+    InvalidPercentEncoding,
+}
+
+impl WalletInteraction {
+    pub fn to_json(&self) -> Value {
+        object([
+            ("interactionId", self.interaction_id.clone().into()),
+            ("metadata", self.metadata.to_json()),
+            ("items", self.items.to_json()),
+        ])
+    }
+
+    pub fn from_json(
+        value: &Value,
+        path: &str,
+    ) -> Result<Self, WalletInteractionError> {
+        let object = as_object(value, path)?;
+        Ok(Self {
+            interaction_id: as_string(
+                required(object, "interactionId", path)?,
+                &format!("{path}.interactionId"),
+            )?,
+            metadata: WalletInteractionMetadata::from_json(
+                required(object, "metadata", path)?,
+                &format!("{path}.metadata"),
+            )?,
+            items: WalletInteractionItems::from_json(
+                required(object, "items", path)?,
+                &format!("{path}.items"),
+            )?,
+        })
+    }
+}
+
+impl WalletInteractionMetadata {
+    fn to_json(&self) -> Value {
+        object([
+            ("version", self.version.into()),
+            ("networkId", self.network_id.into()),
+            ("origin", self.origin.clone().into()),
+            (
+                "dAppDefinitionAddress",
+                self.dapp_definition_address.clone().into(),
+            ),
+        ])
+    }
+
+    fn from_json(
+        value: &Value,
+        path: &str,
+    ) -> Result<Self, WalletInteractionError> {
+        let object = as_object(value, path)?;
+        Ok(Self {
+            version: as_integer(
+                required(object, "version", path)?,
+                &format!("{path}.version"),
+            )?,
+            network_id: as_integer(
+                required(object, "networkId", path)?,
+                &format!("{path}.networkId"),
+            )?,
+            origin: as_string(
+                required(object, "origin", path)?,
+                &format!("{path}.origin"),
+            )?,
+            dapp_definition_address: as_string(
+                required(object, "dAppDefinitionAddress", path)?,
+                &format!("{path}.dAppDefinitionAddress"),
+            )?,
+        })
+    }
+}
+
+impl WalletInteractionItems {
+    fn to_json(&self) -> Value {
+        match self {
+            Self::Transaction(request) => object([
+                ("discriminator", "transaction".into()),
+                ("send", request.to_json()),
+            ]),
+            Self::UnauthorizedRequest(request) => {
+                let mut object =
+                    object([("discriminator", "unauthorizedRequest".into())]);
+                insert_accounts(
+                    &mut object,
+                    "oneTimeAccounts",
+                    &request.one_time_accounts,
+                );
+                object
+            }
+            Self::AuthorizedRequest(request) => {
+                let mut object = object([
+                    ("discriminator", "authorizedRequest".into()),
+                    ("auth", request.auth.to_json()),
+                ]);
+                insert_accounts(
+                    &mut object,
+                    "ongoingAccounts",
+                    &request.ongoing_accounts,
+                );
+                insert_accounts(
+                    &mut object,
+                    "oneTimeAccounts",
+                    &request.one_time_accounts,
+                );
+                object
+            }
+        }
+    }
+
+    fn from_json(
+        value: &Value,
+        path: &str,
+    ) -> Result<Self, WalletInteractionError> {
+        let object = as_object(value, path)?;
+        let discriminator = as_string(
+            required(object, "discriminator", path)?,
+            &format!("{path}.discriminator"),
+        )?;
+        let accounts = |key: &str| {
+            optional(object, key)
+                .map(|value| {
+                    AccountsRequest::from_json(value, &format!("{path}.{key}"))
+                })
+                .transpose()
+        };
+        match discriminator.as_str() {
+            "transaction" => TransactionRequest::from_json(
+                required(object, "send", path)?,
+                &format!("{path}.send"),
+            )
+            .map(Self::Transaction),
+            "unauthorizedRequest" => {
+                Ok(Self::UnauthorizedRequest(UnauthorizedDataRequest {
+                    one_time_accounts: accounts("oneTimeAccounts")?,
+                }))
+            }
+            "authorizedRequest" => {
+                Ok(Self::AuthorizedRequest(AuthorizedDataRequest {
+                    auth: AuthRequest::from_json(
+                        required(object, "auth", path)?,
+                        &format!("{path}.auth"),
+                    )?,
+                    ongoing_accounts: accounts("ongoingAccounts")?,
+                    one_time_accounts: accounts("oneTimeAccounts")?,
+                }))
+            }
+            _ => Err(WalletInteractionError::UnknownDiscriminator {
+                path: format!("{path}.discriminator"),
+                discriminator,
+            }),
+        }
+    }
+}
+
+impl TransactionRequest {
+    fn to_json(&self) -> Value {
+        let mut object = object([
+            (
+                "transactionManifest",
+                self.transaction_manifest.clone().into(),
+            ),
+            ("version", self.version.into()),
+        ]);
+        if !self.blobs.is_empty() {
+            object["blobs"] = self
+                .blobs
+                .iter()
+                .map(|blob| Value::from(hex::encode(blob)))
+                .collect();
+        }
+        if let Some(ref message) = self.message {
+            object["message"] = message.clone().into();
+        }
+        object
+    }
+
+    fn from_json(
+        value: &Value,
+        path: &str,
+    ) -> Result<Self, WalletInteractionError> {
+        let object = as_object(value, path)?;
+        let blobs = match optional(object, "blobs") {
+            Some(Value::Array(blobs)) => blobs
+                .iter()
+                .enumerate()
+                .map(|(index, blob)| {
+                    as_bytes(blob, &format!("{path}.blobs.{index}"))
+                })
+                .collect::<Result<_, _>>()?,
+            Some(..) => {
+                return Err(invalid_value(&format!("{path}.blobs"), "Array"))
+            }
+            None => vec![],
+        };
+        Ok(Self {
+            transaction_manifest: as_string(
+                required(object, "transactionManifest", path)?,
+                &format!("{path}.transactionManifest"),
+            )?,
+            version: as_integer(
+                required(object, "version", path)?,
+                &format!("{path}.version"),
+            )?,
+            blobs,
+            message: optional(object, "message")
+                .map(|message| as_string(message, &format!("{path}.message")))
+                .transpose()?,
+        })
+    }
+}
+
+impl AuthRequest {
+    fn to_json(&self) -> Value {
+        match self {
+            Self::LoginWithoutChallenge => {
+                object([("discriminator", "loginWithoutChallenge".into())])
+            }
+            Self::LoginWithChallenge { challenge } => object([
+                ("discriminator", "loginWithChallenge".into()),
+                ("challenge", hex::encode(challenge).into()),
+            ]),
+            Self::UsePersona { identity_address } => object([
+                ("discriminator", "usePersona".into()),
+                ("identityAddress", identity_address.clone().into()),
+            ]),
+        }
+    }
+
+    fn from_json(
+        value: &Value,
+        path: &str,
+    ) -> Result<Self, WalletInteractionError> {
+        let object = as_object(value, path)?;
+        let discriminator = as_string(
+            required(object, "discriminator", path)?,
+            &format!("{path}.discriminator"),
+        )?;
+        match discriminator.as_str() {
+            "loginWithoutChallenge" => Ok(Self::LoginWithoutChallenge),
+            "loginWithChallenge" => Ok(Self::LoginWithChallenge {
+                challenge: as_challenge(
+                    required(object, "challenge", path)?,
+                    &format!("{path}.challenge"),
+                )?,
+            }),
+            "usePersona" => Ok(Self::UsePersona {
+                identity_address: as_string(
+                    required(object, "identityAddress", path)?,
+                    &format!("{path}.identityAddress"),
+                )?,
+            }),
+            _ => Err(WalletInteractionError::UnknownDiscriminator {
+                path: format!("{path}.discriminator"),
+                discriminator,
+            }),
+        }
+    }
+}
+
+impl AccountsRequest {
+    fn to_json(&self) -> Value {
+        let mut object = object([(
+            "numberOfAccounts",
+            object([
+                (
+                    "quantifier",
+                    match self.number_of_accounts.quantifier {
+                        Quantifier::Exactly => "exactly",
+                        Quantifier::AtLeast => "atLeast",
+                    }
+                    .into(),
+                ),
+                ("quantity", self.number_of_accounts.quantity.into()),
+            ]),
+        )]);
+        if let Some(ref challenge) = self.challenge {
+            object["challenge"] = hex::encode(challenge).into();
+        }
+        object
+    }
+
+    fn from_json(
+        value: &Value,
+        path: &str,
+    ) -> Result<Self, WalletInteractionError> {
+        let object = as_object(value, path)?;
+        let number_of_accounts_path = format!("{path}.numberOfAccounts");
+        let number_of_accounts = as_object(
+            required(object, "numberOfAccounts", path)?,
+            &number_of_accounts_path,
+        )?;
+        let quantifier = match as_string(
+            required(
+                number_of_accounts,
+                "quantifier",
+                &number_of_accounts_path,
+            )?,
+            &format!("{number_of_accounts_path}.quantifier"),
+        )?
+        .as_str()
+        {
+            "exactly" => Quantifier::Exactly,
+            "atLeast" => Quantifier::AtLeast,
+            _ => {
+                return Err(invalid_value(
+                    &format!("{number_of_accounts_path}.quantifier"),
+                    "Quantifier",
+                ))
+            }
+        };
+        Ok(Self {
+            number_of_accounts: NumberOfValues {
+                quantifier,
+                quantity: as_integer(
+                    required(
+                        number_of_accounts,
+                        "quantity",
+                        &number_of_accounts_path,
+                    )?,
+                    &format!("{number_of_accounts_path}.quantity"),
+                )?,
+            },
+            challenge: optional(object, "challenge")
+                .map(|challenge| {
+                    as_challenge(challenge, &format!("{path}.challenge"))
+                })
+                .transpose()?,
+        })
+    }
+}
+
+/// Serializes the value with the keys of all of its objects sorted and without
+/// any whitespace.
+fn canonical_json(value: &Value) -> String {
+    fn sort(value: &Value) -> Value {
+        match value {
+            Value::Object(object) => {
+                let mut entries = object.iter().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key.clone(), sort(value)))
+                        .collect(),
+                )
+            }
+            Value::Array(values) => {
+                Value::Array(values.iter().map(sort).collect())
+            }
+            value => value.clone(),
+        }
+    }
+    sort(value).to_string()
+}
+
+fn percent_encode(string: &str) -> String {
+    string
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'~' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+fn percent_decode(string: &str) -> Result<String, WalletInteractionError> {
+    let mut bytes = Vec::with_capacity(string.len());
+    let mut iter = string.bytes();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'%' => {
+                let high =
+                    iter.next().and_then(|byte| (byte as char).to_digit(16));
+                let low =
+                    iter.next().and_then(|byte| (byte as char).to_digit(16));
+                match (high, low) {
+                    (Some(high), Some(low)) => {
+                        bytes.push((high * 16 + low) as u8)
+                    }
+                    _ => {
+                        return Err(
+                            WalletInteractionError::InvalidPercentEncoding,
+                        )
+                    }
+                }
+            }
+            b'+' => bytes.push(b' '),
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes)
+        .map_err(|_| WalletInteractionError::InvalidPercentEncoding)
+}
+
+fn object<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect(),
+    )
+}
+
+fn insert_accounts(
+    object: &mut Value,
+    key: &str,
+    accounts: &Option<AccountsRequest>,
+) {
+    if let Some(accounts) = accounts {
+        object[key] = accounts.to_json();
+    }
+}
+
+fn invalid_value(path: &str, expected: &str) -> WalletInteractionError {
+    WalletInteractionError::InvalidValue {
+        path: path.to_owned(),
+        expected: expected.to_owned(),
+    }
+}
+
+fn required<'a>(
+    object: &'a Map<String, Value>,
+    key: &str,
+    path: &str,
+) -> Result<&'a Value, WalletInteractionError> {
+    optional(object, key).ok_or_else(|| WalletInteractionError::MissingField {
+        path: format!("{path}.{key}"),
+    })
+}
+
+/// Fields that are `null` are treated the same as fields that are absent.
+fn optional<'a>(
+    object: &'a Map<String, Value>,
+    key: &str,
+) -> Option<&'a Value> {
+    object.get(key).filter(|value| !value.is_null())
+}
+
+fn as_object<'a>(
+    value: &'a Value,
+    path: &str,
+) -> Result<&'a Map<String, Value>, WalletInteractionError> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid_value(path, "Object"))
+}
+
+fn as_string(
+    value: &Value,
+    path: &str,
+) -> Result<String, WalletInteractionError> {
+    value
+        .as_str()
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| invalid_value(path, "String"))
+}
+
+fn as_integer<T>(value: &Value, path: &str) -> Result<T, WalletInteractionError>
+where
+    T: TryFrom<u64>,
+{
+    value
+        .as_u64()
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or_else(|| invalid_value(path, "Integer"))
+}
+
+fn as_bytes(
+    value: &Value,
+    path: &str,
+) -> Result<Vec<u8>, WalletInteractionError> {
+    value
+        .as_str()
+        .and_then(|string| hex::decode(string).ok())
+        .ok_or_else(|| invalid_value(path, "Hex"))
+}
+
+fn as_challenge(
+    value: &Value,
+    path: &str,
+) -> Result<[u8; 32], WalletInteractionError> {
+    as_bytes(value, path)?
+        .try_into()
+        .map_err(|_| invalid_value(path, "32 byte hex"))
+}
@@ -0,0 +1,51 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversions between XRD and attos, the smallest subunit of XRD in which
+//! fees and royalties are accounted (one atto is 10^-18 XRD), and from cost
+//! units to the XRD that the engine charges for them.
+
+use scrypto::prelude::*;
+
+/// The number of attos in one XRD.
+pub const ATTOS_PER_XRD: u128 = 10u128.pow(Decimal::SCALE);
+
+pub fn xrd_to_attos(amount: &Decimal) -> I192 {
+    amount.0
+}
+
+pub fn attos_to_xrd(attos: I192) -> Decimal {
+    Decimal(attos)
+}
+
+/// The XRD that the engine charges for the given number of cost units at the
+/// given cost unit price, including the tip. Like the engine, the price is
+/// raised by the tip percentage before it's multiplied by the cost units and
+/// each multiplication truncates to 18 decimal places. [`None`] is returned
+/// if the fee overflows.
+pub fn cost_units_to_xrd(
+    cost_units: u32,
+    cost_unit_price: &Decimal,
+    tip_percentage: u16,
+) -> Option<Decimal> {
+    let tip_multiplier = Decimal::from(tip_percentage)
+        .checked_div(100)
+        .and_then(|tip| Decimal::ONE.checked_add(tip))?;
+    cost_unit_price
+        .checked_mul(tip_multiplier)
+        .and_then(|price| price.checked_mul(cost_units))
+}
@@ -18,10 +18,13 @@
 #![allow(clippy::type_complexity)]
 #![feature(trait_upcasting)]
 
+pub mod caches;
 pub mod functions;
 pub mod models;
 pub mod sbor;
 pub mod schema_visitor;
 pub mod statics;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 pub mod transaction_types;
 pub mod utils;
@@ -146,19 +146,18 @@ macro_rules! define_canonical_addresses {
                     pub fn try_from_bech32(
                         address_string: &str,
                     ) -> Result<Self, CanonicalAddressError> {
-                        // Find the network definition based on the network of
-                        // the passed address.
-                        let network_definition = network_id_from_address_string(address_string)
-                            .map(network_definition_from_network_id)
+                        // Find the network id based on the HRP of the passed
+                        // address.
+                        let network_id = network_id_from_address_string(address_string)
                             .ok_or(
                                 CanonicalAddressError::FailedToFindNetworkIdFromBech32mString {
                                     bech32m_encoded_address: address_string.to_owned(),
                                 },
                             )?;
 
-                        // Construct the decoder and decode the address
-                        let decoder = AddressBech32Decoder::new(&network_definition);
-                        let (_, data) = decoder.validate_and_decode(address_string)?;
+                        // Decode the address with the cached decoder of the network
+                        let coders = crate::caches::bech32_coders(network_id);
+                        let (_, data) = coders.address_decoder.validate_and_decode(address_string)?;
 
                         // Construct a NodeId from the returned data.
                         let node_id = data.try_into().map(NodeId).map_err(|vec| {
@@ -168,7 +167,7 @@ macro_rules! define_canonical_addresses {
                             }
                         })?;
 
-                        Self::new(node_id, network_definition.id)
+                        Self::new(node_id, network_id)
                     }
                 }
 
@@ -206,10 +205,8 @@ macro_rules! define_canonical_addresses {
 
                 impl Display for [<Canonical $name Address>] {
                     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        let encoder = AddressBech32Encoder::new(
-                            &network_definition_from_network_id(self.network_id),
-                        );
-                        encoder
+                        crate::caches::bech32_coders(self.network_id)
+                            .address_encoder
                             .encode_to_fmt(formatter, &self.node_id.0)
                             .map_err(|_| fmt::Error)
                     }
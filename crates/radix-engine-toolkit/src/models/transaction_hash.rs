@@ -15,7 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use radix_transactions::prelude::{HashHasHrp, TransactionHashBech32Encoder};
+use radix_transactions::errors::PrepareError;
+use radix_transactions::prelude::HashHasHrp;
+use sbor::DecodeError;
 use scrypto::prelude::*;
 
 pub struct TransactionHash {
@@ -28,11 +30,20 @@ impl TransactionHash {
     where
         H: HashHasHrp + IsHash,
     {
-        let network_definition =
-            crate::utils::network_definition_from_network_id(network_id);
-        let encoder = TransactionHashBech32Encoder::new(&network_definition);
+        let coders = crate::caches::bech32_coders(network_id);
         let hash = *transaction_hash.as_hash();
-        let id = encoder.encode(&transaction_hash).unwrap();
+        let id = coders
+            .transaction_hash_encoder
+            .encode(&transaction_hash)
+            .unwrap();
         Self { hash, id }
     }
 }
+
+/// The errors that can be encountered when computing the hash of a compiled
+/// transaction payload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PayloadHashError {
+    DecodeError(DecodeError),
+    PrepareError(PrepareError),
+}
@@ -18,7 +18,8 @@
 use core::cell::RefCell;
 use radix_common::data::manifest::*;
 use radix_common::prelude::{
-    ManifestAddress, ManifestBucket, ManifestExpression,
+    ManifestAddress, ManifestAddressReservation, ManifestBlobRef,
+    ManifestBucket, ManifestExpression, ManifestProof,
 };
 use radix_common::types::*;
 use sbor::rust::cell::Ref;
@@ -36,7 +37,10 @@ pub struct IndexedManifestValue {
     static_addresses: Vec<NodeId>,
     named_addresses: Vec<u32>,
     buckets: Vec<ManifestBucket>,
+    proofs: Vec<ManifestProof>,
+    address_reservations: Vec<ManifestAddressReservation>,
     expressions: Vec<ManifestExpression>,
+    blobs: Vec<ManifestBlobRef>,
 }
 
 impl IndexedManifestValue {
@@ -50,7 +54,10 @@ impl IndexedManifestValue {
         let mut static_addresses = Vec::new();
         let mut named_addresses = Vec::new();
         let mut buckets = Vec::new();
+        let mut proofs = Vec::new();
+        let mut address_reservations = Vec::new();
         let mut expressions = Vec::new();
+        let mut blobs = Vec::new();
         loop {
             let event = traverser.next_event();
             match event.event {
@@ -72,15 +79,19 @@ impl IndexedManifestValue {
                             ManifestCustomValue::Bucket(bucket) => {
                                 buckets.push(bucket)
                             }
+                            ManifestCustomValue::Proof(proof) => {
+                                proofs.push(proof)
+                            }
+                            ManifestCustomValue::AddressReservation(
+                                address_reservation,
+                            ) => address_reservations.push(address_reservation),
                             ManifestCustomValue::Expression(expression) => {
                                 expressions.push(expression)
                             }
-                            ManifestCustomValue::Proof(_)
-                            | ManifestCustomValue::Blob(_)
-                            | ManifestCustomValue::Decimal(_)
+                            ManifestCustomValue::Blob(blob) => blobs.push(blob),
+                            ManifestCustomValue::Decimal(_)
                             | ManifestCustomValue::PreciseDecimal(_)
-                            | ManifestCustomValue::NonFungibleLocalId(_)
-                            | ManifestCustomValue::AddressReservation(_) => {}
+                            | ManifestCustomValue::NonFungibleLocalId(_) => {}
                         }
                     }
                 }
@@ -99,7 +110,10 @@ impl IndexedManifestValue {
             static_addresses,
             named_addresses,
             buckets,
+            proofs,
+            address_reservations,
             expressions,
+            blobs,
             manifest_value: RefCell::new(None),
         })
     }
@@ -175,4 +189,16 @@ impl IndexedManifestValue {
     pub fn buckets(&self) -> &Vec<ManifestBucket> {
         &self.buckets
     }
+
+    pub fn proofs(&self) -> &Vec<ManifestProof> {
+        &self.proofs
+    }
+
+    pub fn address_reservations(&self) -> &Vec<ManifestAddressReservation> {
+        &self.address_reservations
+    }
+
+    pub fn blobs(&self) -> &Vec<ManifestBlobRef> {
+        &self.blobs
+    }
 }
@@ -16,3 +16,5 @@
 // under the License.
 
 pub mod indexed_manifest_value;
+pub mod scrypto_event_stream;
+pub mod value_query;
@@ -0,0 +1,165 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::data::scrypto::*;
+use sbor::traversal::*;
+use sbor::*;
+
+/// An event emitted when walking over an SBOR payload. This is a flat, SAX
+/// like, view of the payload where containers are represented by a start and
+/// an end event and where all other values are emitted as they're encountered.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScryptoSborEvent {
+    TupleStart {
+        length: usize,
+    },
+    EnumVariantStart {
+        discriminator: u8,
+        length: usize,
+    },
+    ArrayStart {
+        element_value_kind: ScryptoValueKind,
+        length: usize,
+    },
+    MapStart {
+        key_value_kind: ScryptoValueKind,
+        value_value_kind: ScryptoValueKind,
+        length: usize,
+    },
+    ContainerEnd {
+        value_kind: ScryptoValueKind,
+    },
+    /// A terminal value, this is never a container value.
+    Value(ScryptoValue),
+    /// A batch of bytes found in an `Array<U8>`, emitted in place of the
+    /// individual `U8` values.
+    Bytes(Vec<u8>),
+}
+
+/// An iterator over the [`ScryptoSborEvent`]s of a Scrypto SBOR payload. The
+/// payload is decoded lazily as the iterator is advanced and the value tree is
+/// never materialized which makes this suitable for very large payloads.
+///
+/// The iterator yields an error at most once and stops after it.
+pub struct ScryptoSborEventStream<'de> {
+    traverser: ScryptoTraverser<'de>,
+    is_finished: bool,
+}
+
+impl<'de> ScryptoSborEventStream<'de> {
+    pub fn new(payload: &'de [u8]) -> Self {
+        let traverser = ScryptoTraverser::new(
+            payload,
+            SCRYPTO_SBOR_V1_MAX_DEPTH,
+            ExpectedStart::PayloadPrefix(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX),
+            true,
+        );
+        Self {
+            traverser,
+            is_finished: false,
+        }
+    }
+}
+
+impl<'de> Iterator for ScryptoSborEventStream<'de> {
+    type Item = Result<ScryptoSborEvent, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished {
+            return None;
+        }
+
+        let event = match self.traverser.next_event().event {
+            TraversalEvent::ContainerStart(header) => match header {
+                ContainerHeader::Tuple(TupleHeader { length }) => {
+                    ScryptoSborEvent::TupleStart { length }
+                }
+                ContainerHeader::EnumVariant(EnumVariantHeader {
+                    variant,
+                    length,
+                }) => ScryptoSborEvent::EnumVariantStart {
+                    discriminator: variant,
+                    length,
+                },
+                ContainerHeader::Array(ArrayHeader {
+                    element_value_kind,
+                    length,
+                }) => ScryptoSborEvent::ArrayStart {
+                    element_value_kind,
+                    length,
+                },
+                ContainerHeader::Map(MapHeader {
+                    key_value_kind,
+                    value_value_kind,
+                    length,
+                }) => ScryptoSborEvent::MapStart {
+                    key_value_kind,
+                    value_value_kind,
+                    length,
+                },
+            },
+            TraversalEvent::ContainerEnd(header) => {
+                let value_kind = match header {
+                    ContainerHeader::Tuple(..) => ValueKind::Tuple,
+                    ContainerHeader::EnumVariant(..) => ValueKind::Enum,
+                    ContainerHeader::Array(..) => ValueKind::Array,
+                    ContainerHeader::Map(..) => ValueKind::Map,
+                };
+                ScryptoSborEvent::ContainerEnd { value_kind }
+            }
+            TraversalEvent::TerminalValue(value) => {
+                ScryptoSborEvent::Value(terminal_value_to_owned(value))
+            }
+            TraversalEvent::TerminalValueBatch(TerminalValueBatchRef::U8(
+                bytes,
+            )) => ScryptoSborEvent::Bytes(bytes.to_vec()),
+            TraversalEvent::End => {
+                self.is_finished = true;
+                return None;
+            }
+            TraversalEvent::DecodeError(error) => {
+                self.is_finished = true;
+                return Some(Err(error));
+            }
+        };
+        Some(Ok(event))
+    }
+}
+
+fn terminal_value_to_owned(
+    value: TerminalValueRef<'_, ScryptoCustomTraversal>,
+) -> ScryptoValue {
+    match value {
+        TerminalValueRef::Bool(value) => ScryptoValue::Bool { value },
+        TerminalValueRef::I8(value) => ScryptoValue::I8 { value },
+        TerminalValueRef::I16(value) => ScryptoValue::I16 { value },
+        TerminalValueRef::I32(value) => ScryptoValue::I32 { value },
+        TerminalValueRef::I64(value) => ScryptoValue::I64 { value },
+        TerminalValueRef::I128(value) => ScryptoValue::I128 { value },
+        TerminalValueRef::U8(value) => ScryptoValue::U8 { value },
+        TerminalValueRef::U16(value) => ScryptoValue::U16 { value },
+        TerminalValueRef::U32(value) => ScryptoValue::U32 { value },
+        TerminalValueRef::U64(value) => ScryptoValue::U64 { value },
+        TerminalValueRef::U128(value) => ScryptoValue::U128 { value },
+        TerminalValueRef::String(value) => ScryptoValue::String {
+            value: value.to_owned(),
+        },
+        TerminalValueRef::Custom(ScryptoCustomTerminalValueRef(value)) => {
+            ScryptoValue::Custom { value }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A path language for querying sub-values of decoded SBOR values without
+//! having to walk the entire value. A path is made up of segments that are
+//! applied one after the other to the values matched so far:
+//!
+//! * `$` refers to the root value and may optionally start the path.
+//! * `.N` or `[N]` matches the `N`th field of a tuple or enum, the `N`th
+//!   element of an array, or the value of the `N`th entry of a map.
+//! * `.*` or `[*]` matches all of the fields, elements, or entry values.
+//! * `#D` matches the value itself if it's an enum with the discriminator `D`
+//!   and nothing otherwise.
+//!
+//! As an example, `$.0[*]#1.0` matches the only field of all of the elements
+//! of the first field of the root value that are enums of the variant `1`.
+
+use sbor::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    Index(usize),
+    Wildcard,
+    Variant(u8),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValueQueryError {
+    /// The path has a character at the given byte position that doesn't start
+    /// or continue a segment.
+    UnexpectedCharacter { position: usize, character: char },
+    /// The path ends in the middle of a segment.
+    UnexpectedEnd,
+    /// The number at the given byte position is not a valid index or enum
+    /// discriminator.
+    InvalidNumber { position: usize },
+}
+
+pub fn parse_path(path: &str) -> Result<Vec<PathSegment>, ValueQueryError> {
+    let mut segments = Vec::new();
+    let mut chars = path.char_indices().peekable();
+    if let Some((_, '$')) = chars.peek() {
+        chars.next();
+    }
+
+    while let Some((position, character)) = chars.next() {
+        let segment = match character {
+            '.' => match chars.peek() {
+                Some((_, '*')) => {
+                    chars.next();
+                    PathSegment::Wildcard
+                }
+                _ => PathSegment::Index(parse_number(&mut chars)?),
+            },
+            '[' => {
+                let segment = match chars.peek() {
+                    Some((_, '*')) => {
+                        chars.next();
+                        PathSegment::Wildcard
+                    }
+                    _ => PathSegment::Index(parse_number(&mut chars)?),
+                };
+                match chars.next() {
+                    Some((_, ']')) => segment,
+                    Some((position, character)) => {
+                        return Err(ValueQueryError::UnexpectedCharacter {
+                            position,
+                            character,
+                        })
+                    }
+                    None => return Err(ValueQueryError::UnexpectedEnd),
+                }
+            }
+            '#' => PathSegment::Variant(parse_number(&mut chars)?),
+            character => {
+                return Err(ValueQueryError::UnexpectedCharacter {
+                    position,
+                    character,
+                })
+            }
+        };
+        segments.push(segment);
+    }
+
+    Ok(segments)
+}
+
+fn parse_number<T: core::str::FromStr>(
+    chars: &mut core::iter::Peekable<core::str::CharIndices<'_>>,
+) -> Result<T, ValueQueryError> {
+    let (position, _) = *chars.peek().ok_or(ValueQueryError::UnexpectedEnd)?;
+    let mut digits = String::new();
+    while let Some((_, character)) =
+        chars.next_if(|(_, character)| character.is_ascii_digit())
+    {
+        digits.push(character)
+    }
+    digits
+        .parse()
+        .map_err(|_| ValueQueryError::InvalidNumber { position })
+}
+
+/// Returns the sub-values of the value that match the path in the order that
+/// they appear in the value.
+pub fn query<'v, X, Y>(
+    value: &'v Value<X, Y>,
+    path: &[PathSegment],
+) -> Vec<&'v Value<X, Y>>
+where
+    X: CustomValueKind,
+    Y: CustomValue<X>,
+{
+    let mut matches = vec![value];
+    for segment in path {
+        matches = matches
+            .into_iter()
+            .flat_map(|value| match segment {
+                PathSegment::Index(index) => {
+                    children(value).nth(*index).into_iter().collect()
+                }
+                PathSegment::Wildcard => children(value).collect(),
+                PathSegment::Variant(variant) => match value {
+                    Value::Enum { discriminator, .. }
+                        if discriminator == variant =>
+                    {
+                        vec![value]
+                    }
+                    _ => vec![],
+                },
+            })
+            .collect();
+    }
+    matches
+}
+
+fn children<'v, X, Y>(
+    value: &'v Value<X, Y>,
+) -> Box<dyn Iterator<Item = &'v Value<X, Y>> + 'v>
+where
+    X: CustomValueKind,
+    Y: CustomValue<X>,
+{
+    match value {
+        Value::Tuple { fields } | Value::Enum { fields, .. } => {
+            Box::new(fields.iter())
+        }
+        Value::Array { elements, .. } => Box::new(elements.iter()),
+        Value::Map { entries, .. } => {
+            Box::new(entries.iter().map(|(_, value)| value))
+        }
+        _ => Box::new(core::iter::empty()),
+    }
+}
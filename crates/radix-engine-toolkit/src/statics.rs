@@ -53,6 +53,47 @@ lazy_static! {
     pub static ref ROLE_ASSIGNMENT_METHODS_THAT_REQUIRE_AUTH: Vec<String> = role_assignment_methods_that_require_auth();
     pub static ref METADATA_METHODS_THAT_REQUIRE_AUTH: Vec<String> = metadata_methods_that_require_auth();
     pub static ref ROYALTY_METHODS_THAT_REQUIRE_AUTH: Vec<String> = royalty_methods_that_require_auth();
+
+    // Well-known addresses that have the same node id on all networks, all of
+    // the native addresses of `radix_common::constants::native_addresses`.
+    pub static ref WELL_KNOWN_ADDRESSES: IndexSet<NodeId> = well_known_addresses();
+}
+
+fn well_known_addresses() -> IndexSet<NodeId> {
+    [
+        XRD.into_node_id(),
+        SECP256K1_SIGNATURE_VIRTUAL_BADGE.into_node_id(),
+        ED25519_SIGNATURE_VIRTUAL_BADGE.into_node_id(),
+        PACKAGE_OF_DIRECT_CALLER_VIRTUAL_BADGE.into_node_id(),
+        GLOBAL_CALLER_VIRTUAL_BADGE.into_node_id(),
+        SYSTEM_TRANSACTION_BADGE.into_node_id(),
+        PACKAGE_OWNER_BADGE.into_node_id(),
+        VALIDATOR_OWNER_BADGE.into_node_id(),
+        ACCOUNT_OWNER_BADGE.into_node_id(),
+        IDENTITY_OWNER_BADGE.into_node_id(),
+        PACKAGE_PACKAGE.into_node_id(),
+        RESOURCE_PACKAGE.into_node_id(),
+        ACCOUNT_PACKAGE.into_node_id(),
+        IDENTITY_PACKAGE.into_node_id(),
+        CONSENSUS_MANAGER_PACKAGE.into_node_id(),
+        ACCESS_CONTROLLER_PACKAGE.into_node_id(),
+        POOL_PACKAGE.into_node_id(),
+        TRANSACTION_PROCESSOR_PACKAGE.into_node_id(),
+        METADATA_MODULE_PACKAGE.into_node_id(),
+        ROYALTY_MODULE_PACKAGE.into_node_id(),
+        ROLE_ASSIGNMENT_MODULE_PACKAGE.into_node_id(),
+        TEST_UTILS_PACKAGE.into_node_id(),
+        GENESIS_HELPER_PACKAGE.into_node_id(),
+        FAUCET_PACKAGE.into_node_id(),
+        TRANSACTION_TRACKER_PACKAGE.into_node_id(),
+        LOCKER_PACKAGE.into_node_id(),
+        CONSENSUS_MANAGER.into_node_id(),
+        GENESIS_HELPER.into_node_id(),
+        FAUCET.into_node_id(),
+        TRANSACTION_TRACKER.into_node_id(),
+    ]
+    .into_iter()
+    .collect()
 }
 
 fn account_blueprint_schema() -> BlueprintDefinitionInit {
@@ -0,0 +1,80 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Differential checks that a manifest survives the round trips between its
+//! different representations without any changes.
+
+use radix_transactions::manifest::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+/// Checks that the manifest is stable across the following round trips:
+///
+/// 1. Compiling the manifest to its SBOR payload and decompiling it back.
+/// 2. Decompiling the instructions to their string representation, compiling
+///    the string back to instructions, and compiling those to their SBOR
+///    payload which must match the payload from step 1.
+pub fn check_round_trip_stability(
+    manifest: &TransactionManifestV1,
+    network_definition: &NetworkDefinition,
+) -> Result<(), DifferentialError> {
+    // Payload round trip.
+    let payload = crate::functions::manifest::compile(manifest)?;
+    let decompiled = crate::functions::manifest::decompile(&payload)?;
+    if &decompiled != manifest {
+        return Err(DifferentialError::PayloadRoundTripMismatch);
+    }
+
+    // String round trip.
+    let string = decompile(&manifest.instructions, network_definition)?;
+    let recompiled = compile(
+        &string,
+        network_definition,
+        BlobProvider::new_with_blobs(
+            manifest.blobs.values().cloned().collect(),
+        ),
+    )?;
+    let recompiled_payload = crate::functions::manifest::compile(&recompiled)?;
+    if recompiled_payload != payload {
+        return Err(DifferentialError::StringRoundTripMismatch { string });
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum DifferentialError {
+    EncodeError(EncodeError),
+    DecodeError(DecodeError),
+    DecompileError(DecompileError),
+    CompileError(CompileError),
+    PayloadRoundTripMismatch,
+    StringRoundTripMismatch { string: String },
+}
+
+macro_rules! impl_from {
+    ($($error: ident),* $(,)?) => {
+        $(
+            impl From<$error> for DifferentialError {
+                fn from(value: $error) -> Self {
+                    Self::$error(value)
+                }
+            }
+        )*
+    };
+}
+impl_from![EncodeError, DecodeError, DecompileError, CompileError];
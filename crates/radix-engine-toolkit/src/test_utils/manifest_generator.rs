@@ -0,0 +1,682 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use super::split_mix64::SplitMix64;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+/// A deterministic generator of random-but-valid manifests. Two generators
+/// created with the same seed and config produce the exact same manifests.
+///
+/// The generated manifests exercise all of the instruction kinds and all of
+/// the manifest value kinds and they are statically valid: all buckets are
+/// consumed, proofs are dropped, and address reservations are used. They are
+/// not meant to be executed as they reference random addresses.
+pub struct ManifestGenerator {
+    rng: SplitMix64,
+    config: ManifestGeneratorConfig,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestGeneratorConfig {
+    /// The number of instructions to generate before the instructions that
+    /// clean up the remaining buckets, proofs, and address reservations.
+    pub instructions_count: usize,
+    /// The maximum depth of the generated manifest values.
+    pub max_value_depth: usize,
+    /// The maximum number of elements in generated arrays, maps, tuples, and
+    /// enums.
+    pub max_elements: usize,
+}
+
+impl Default for ManifestGeneratorConfig {
+    fn default() -> Self {
+        Self {
+            instructions_count: 32,
+            max_value_depth: 4,
+            max_elements: 4,
+        }
+    }
+}
+
+#[derive(Default)]
+struct GenerationState {
+    instructions: Vec<InstructionV1>,
+    blobs: IndexMap<Hash, Vec<u8>>,
+    next_bucket_id: u32,
+    next_proof_id: u32,
+    next_address_reservation_id: u32,
+    next_named_address_id: u32,
+    buckets: Vec<ManifestBucket>,
+    proofs: Vec<ManifestProof>,
+    address_reservations: Vec<ManifestAddressReservation>,
+    named_addresses: Vec<u32>,
+}
+
+impl ManifestGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self::new_with_config(seed, Default::default())
+    }
+
+    pub fn new_with_config(seed: u64, config: ManifestGeneratorConfig) -> Self {
+        Self {
+            rng: SplitMix64::new(seed),
+            config,
+        }
+    }
+
+    /// Generates the next manifest.
+    pub fn generate(&mut self) -> TransactionManifestV1 {
+        let mut state = GenerationState::default();
+        for _ in 0..self.config.instructions_count {
+            self.generate_instruction(&mut state);
+        }
+
+        // Cleaning up the remaining buckets, proofs, and address reservations
+        // so that the manifest is valid.
+        for bucket_id in std::mem::take(&mut state.buckets) {
+            state
+                .instructions
+                .push(InstructionV1::ReturnToWorktop { bucket_id });
+        }
+        for address_reservation in
+            std::mem::take(&mut state.address_reservations)
+        {
+            let instruction = InstructionV1::CallFunction {
+                package_address: DynamicPackageAddress::Static(
+                    self.package_address(),
+                ),
+                blueprint_name: self.ident(),
+                function_name: self.ident(),
+                args: to_manifest_value_and_unwrap!(&(address_reservation,)),
+            };
+            state.instructions.push(instruction);
+        }
+        if !state.proofs.is_empty() {
+            state.proofs.clear();
+            state.instructions.push(InstructionV1::DropAllProofs);
+        }
+
+        TransactionManifestV1 {
+            instructions: state.instructions,
+            blobs: state.blobs,
+        }
+    }
+
+    fn generate_instruction(&mut self, state: &mut GenerationState) {
+        const INSTRUCTION_KINDS: usize = 31;
+
+        let instruction = match self.rng.next_below(INSTRUCTION_KINDS) {
+            0 => {
+                self.new_bucket(state);
+                InstructionV1::TakeFromWorktop {
+                    resource_address: self.resource_address(),
+                    amount: self.decimal(),
+                }
+            }
+            1 => {
+                self.new_bucket(state);
+                InstructionV1::TakeNonFungiblesFromWorktop {
+                    resource_address: self.resource_address(),
+                    ids: self.non_fungible_local_ids(),
+                }
+            }
+            2 => {
+                self.new_bucket(state);
+                InstructionV1::TakeAllFromWorktop {
+                    resource_address: self.resource_address(),
+                }
+            }
+            3 => match self.take_bucket(state) {
+                Some(bucket_id) => InstructionV1::ReturnToWorktop { bucket_id },
+                None => return,
+            },
+            4 => InstructionV1::AssertWorktopContainsAny {
+                resource_address: self.resource_address(),
+            },
+            5 => InstructionV1::AssertWorktopContains {
+                resource_address: self.resource_address(),
+                amount: self.decimal(),
+            },
+            6 => InstructionV1::AssertWorktopContainsNonFungibles {
+                resource_address: self.resource_address(),
+                ids: self.non_fungible_local_ids(),
+            },
+            7 => {
+                self.new_proof(state);
+                InstructionV1::PopFromAuthZone
+            }
+            8 => match self.take_proof(state) {
+                Some(proof_id) => InstructionV1::PushToAuthZone { proof_id },
+                None => return,
+            },
+            9 => {
+                self.new_proof(state);
+                InstructionV1::CreateProofFromAuthZoneOfAmount {
+                    resource_address: self.resource_address(),
+                    amount: self.decimal(),
+                }
+            }
+            10 => {
+                self.new_proof(state);
+                InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                    resource_address: self.resource_address(),
+                    ids: self.non_fungible_local_ids(),
+                }
+            }
+            11 => {
+                self.new_proof(state);
+                InstructionV1::CreateProofFromAuthZoneOfAll {
+                    resource_address: self.resource_address(),
+                }
+            }
+            12 => InstructionV1::DropAuthZoneProofs,
+            13 => InstructionV1::DropAuthZoneRegularProofs,
+            14 => InstructionV1::DropAuthZoneSignatureProofs,
+            15 | 16 | 17 => {
+                let Some(bucket_id) = self.pick(&state.buckets).copied() else {
+                    return;
+                };
+                self.new_proof(state);
+                match self.rng.next_below(3) {
+                    0 => InstructionV1::CreateProofFromBucketOfAmount {
+                        bucket_id,
+                        amount: self.decimal(),
+                    },
+                    1 => InstructionV1::CreateProofFromBucketOfNonFungibles {
+                        bucket_id,
+                        ids: self.non_fungible_local_ids(),
+                    },
+                    _ => {
+                        InstructionV1::CreateProofFromBucketOfAll { bucket_id }
+                    }
+                }
+            }
+            18 => match self.take_bucket(state) {
+                Some(bucket_id) => InstructionV1::BurnResource { bucket_id },
+                None => return,
+            },
+            19 => {
+                let Some(proof_id) = self.pick(&state.proofs).copied() else {
+                    return;
+                };
+                self.new_proof(state);
+                InstructionV1::CloneProof { proof_id }
+            }
+            20 => match self.take_proof(state) {
+                Some(proof_id) => InstructionV1::DropProof { proof_id },
+                None => return,
+            },
+            21 => {
+                let package_address = match self.pick(&state.named_addresses) {
+                    Some(named_address) if self.rng.next_bool() => {
+                        DynamicPackageAddress::Named(*named_address)
+                    }
+                    _ => DynamicPackageAddress::Static(self.package_address()),
+                };
+                InstructionV1::CallFunction {
+                    package_address,
+                    blueprint_name: self.ident(),
+                    function_name: self.ident(),
+                    args: self.call_args(state),
+                }
+            }
+            22 => InstructionV1::CallMethod {
+                address: self.dynamic_global_address(state),
+                method_name: self.ident(),
+                args: self.call_args(state),
+            },
+            23 => InstructionV1::CallRoyaltyMethod {
+                address: self.dynamic_global_address(state),
+                method_name: self.ident(),
+                args: self.call_args(state),
+            },
+            24 => InstructionV1::CallMetadataMethod {
+                address: self.dynamic_global_address(state),
+                method_name: self.ident(),
+                args: self.call_args(state),
+            },
+            25 => InstructionV1::CallRoleAssignmentMethod {
+                address: self.dynamic_global_address(state),
+                method_name: self.ident(),
+                args: self.call_args(state),
+            },
+            26 => InstructionV1::CallDirectVaultMethod {
+                address: self.vault_address(),
+                method_name: self.ident(),
+                args: self.call_args(state),
+            },
+            27 => {
+                state.proofs.clear();
+                InstructionV1::DropNamedProofs
+            }
+            28 => {
+                state.proofs.clear();
+                InstructionV1::DropAllProofs
+            }
+            _ => {
+                state.address_reservations.push(ManifestAddressReservation(
+                    state.next_address_reservation_id,
+                ));
+                state.next_address_reservation_id += 1;
+                state.named_addresses.push(state.next_named_address_id);
+                state.next_named_address_id += 1;
+                InstructionV1::AllocateGlobalAddress {
+                    package_address: self.package_address(),
+                    blueprint_name: self.ident(),
+                }
+            }
+        };
+        state.instructions.push(instruction);
+    }
+
+    /// Generates the arguments of an invocation. The arguments are always a
+    /// tuple and might consume a bucket or an address reservation and might
+    /// include a proof.
+    fn call_args(&mut self, state: &mut GenerationState) -> ManifestValue {
+        let mut fields = (0..self.rng.next_below(self.config.max_elements + 1))
+            .map(|_| self.value(state, 1))
+            .collect::<Vec<_>>();
+        if self.rng.next_bool() {
+            if let Some(bucket_id) = self.take_bucket(state) {
+                fields.push(to_manifest_value_and_unwrap!(&bucket_id));
+            }
+        }
+        if self.rng.next_bool() {
+            if let Some(proof_id) = self.take_proof(state) {
+                fields.push(to_manifest_value_and_unwrap!(&proof_id));
+            }
+        }
+        if self.rng.next_bool() && !state.address_reservations.is_empty() {
+            let address_reservation = state.address_reservations.remove(0);
+            fields.push(to_manifest_value_and_unwrap!(&address_reservation));
+        }
+        ManifestValue::Tuple { fields }
+    }
+
+    /// Generates a value of any kind other than buckets, proofs, and address
+    /// reservations since those have a lifecycle that's tracked separately.
+    fn value(
+        &mut self,
+        state: &mut GenerationState,
+        depth: usize,
+    ) -> ManifestValue {
+        let value_kind = if depth >= self.config.max_value_depth {
+            self.leaf_value_kind()
+        } else {
+            self.value_kind()
+        };
+        self.value_of_kind(state, value_kind, depth)
+    }
+
+    fn value_of_kind(
+        &mut self,
+        state: &mut GenerationState,
+        value_kind: ManifestValueKind,
+        depth: usize,
+    ) -> ManifestValue {
+        match value_kind {
+            ManifestValueKind::Bool => ManifestValue::Bool {
+                value: self.rng.next_bool(),
+            },
+            ManifestValueKind::I8 => ManifestValue::I8 {
+                value: self.rng.next_u64() as i8,
+            },
+            ManifestValueKind::I16 => ManifestValue::I16 {
+                value: self.rng.next_u64() as i16,
+            },
+            ManifestValueKind::I32 => ManifestValue::I32 {
+                value: self.rng.next_u64() as i32,
+            },
+            ManifestValueKind::I64 => ManifestValue::I64 {
+                value: self.rng.next_u64() as i64,
+            },
+            ManifestValueKind::I128 => ManifestValue::I128 {
+                value: self.rng.next_u128() as i128,
+            },
+            ManifestValueKind::U8 => ManifestValue::U8 {
+                value: self.rng.next_u64() as u8,
+            },
+            ManifestValueKind::U16 => ManifestValue::U16 {
+                value: self.rng.next_u64() as u16,
+            },
+            ManifestValueKind::U32 => ManifestValue::U32 {
+                value: self.rng.next_u64() as u32,
+            },
+            ManifestValueKind::U64 => ManifestValue::U64 {
+                value: self.rng.next_u64(),
+            },
+            ManifestValueKind::U128 => ManifestValue::U128 {
+                value: self.rng.next_u128(),
+            },
+            ManifestValueKind::String => ManifestValue::String {
+                value: self.string(),
+            },
+            ManifestValueKind::Enum => ManifestValue::Enum {
+                discriminator: self.rng.next_u64() as u8,
+                fields: self.values(state, depth),
+            },
+            ManifestValueKind::Array => {
+                let element_value_kind = self.value_kind();
+                let elements = (0..self
+                    .rng
+                    .next_below(self.config.max_elements + 1))
+                    .map(|_| {
+                        self.value_of_kind(state, element_value_kind, depth + 1)
+                    })
+                    .collect();
+                ManifestValue::Array {
+                    element_value_kind,
+                    elements,
+                }
+            }
+            ManifestValueKind::Tuple => ManifestValue::Tuple {
+                fields: self.values(state, depth),
+            },
+            ManifestValueKind::Map => {
+                let key_value_kind = self.leaf_value_kind();
+                let value_value_kind = self.value_kind();
+                let entries =
+                    (0..self.rng.next_below(self.config.max_elements + 1))
+                        .map(|_| {
+                            (
+                                self.value_of_kind(
+                                    state,
+                                    key_value_kind,
+                                    depth + 1,
+                                ),
+                                self.value_of_kind(
+                                    state,
+                                    value_value_kind,
+                                    depth + 1,
+                                ),
+                            )
+                        })
+                        .collect();
+                ManifestValue::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                }
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Address) => {
+                match self.pick(&state.named_addresses).copied() {
+                    Some(named_address) if self.rng.next_bool() => {
+                        to_manifest_value_and_unwrap!(&ManifestAddress::Named(
+                            named_address
+                        ))
+                    }
+                    _ => {
+                        let address = self.global_address();
+                        to_manifest_value_and_unwrap!(&address)
+                    }
+                }
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Expression) => {
+                if self.rng.next_bool() {
+                    to_manifest_value_and_unwrap!(
+                        &ManifestExpression::EntireWorktop
+                    )
+                } else {
+                    to_manifest_value_and_unwrap!(
+                        &ManifestExpression::EntireAuthZone
+                    )
+                }
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Blob) => {
+                let blob = self.bytes(64);
+                let hash = hash(&blob);
+                state.blobs.insert(hash, blob);
+                to_manifest_value_and_unwrap!(&ManifestBlobRef(hash.0))
+            }
+            ManifestValueKind::Custom(ManifestCustomValueKind::Decimal) => {
+                to_manifest_value_and_unwrap!(&self.decimal())
+            }
+            ManifestValueKind::Custom(
+                ManifestCustomValueKind::PreciseDecimal,
+            ) => {
+                to_manifest_value_and_unwrap!(&self.precise_decimal())
+            }
+            ManifestValueKind::Custom(
+                ManifestCustomValueKind::NonFungibleLocalId,
+            ) => {
+                to_manifest_value_and_unwrap!(&self.non_fungible_local_id())
+            }
+            ManifestValueKind::Custom(
+                ManifestCustomValueKind::Bucket
+                | ManifestCustomValueKind::Proof
+                | ManifestCustomValueKind::AddressReservation,
+            ) => unreachable!("Not generated as values"),
+        }
+    }
+
+    fn values(
+        &mut self,
+        state: &mut GenerationState,
+        depth: usize,
+    ) -> Vec<ManifestValue> {
+        (0..self.rng.next_below(self.config.max_elements + 1))
+            .map(|_| self.value(state, depth + 1))
+            .collect()
+    }
+
+    fn value_kind(&mut self) -> ManifestValueKind {
+        const VALUE_KINDS: [ManifestValueKind; 4] = [
+            ManifestValueKind::Enum,
+            ManifestValueKind::Array,
+            ManifestValueKind::Tuple,
+            ManifestValueKind::Map,
+        ];
+        if self.rng.next_below(4) == 0 {
+            VALUE_KINDS[self.rng.next_below(VALUE_KINDS.len())]
+        } else {
+            self.leaf_value_kind()
+        }
+    }
+
+    fn leaf_value_kind(&mut self) -> ManifestValueKind {
+        const LEAF_VALUE_KINDS: [ManifestValueKind; 18] = [
+            ManifestValueKind::Bool,
+            ManifestValueKind::I8,
+            ManifestValueKind::I16,
+            ManifestValueKind::I32,
+            ManifestValueKind::I64,
+            ManifestValueKind::I128,
+            ManifestValueKind::U8,
+            ManifestValueKind::U16,
+            ManifestValueKind::U32,
+            ManifestValueKind::U64,
+            ManifestValueKind::U128,
+            ManifestValueKind::String,
+            ManifestValueKind::Custom(ManifestCustomValueKind::Address),
+            ManifestValueKind::Custom(ManifestCustomValueKind::Expression),
+            ManifestValueKind::Custom(ManifestCustomValueKind::Blob),
+            ManifestValueKind::Custom(ManifestCustomValueKind::Decimal),
+            ManifestValueKind::Custom(ManifestCustomValueKind::PreciseDecimal),
+            ManifestValueKind::Custom(
+                ManifestCustomValueKind::NonFungibleLocalId,
+            ),
+        ];
+        LEAF_VALUE_KINDS[self.rng.next_below(LEAF_VALUE_KINDS.len())]
+    }
+
+    fn new_bucket(&mut self, state: &mut GenerationState) {
+        state.buckets.push(ManifestBucket(state.next_bucket_id));
+        state.next_bucket_id += 1;
+    }
+
+    fn new_proof(&mut self, state: &mut GenerationState) {
+        state.proofs.push(ManifestProof(state.next_proof_id));
+        state.next_proof_id += 1;
+    }
+
+    fn take_bucket(
+        &mut self,
+        state: &mut GenerationState,
+    ) -> Option<ManifestBucket> {
+        if state.buckets.is_empty() {
+            None
+        } else {
+            let index = self.rng.next_below(state.buckets.len());
+            Some(state.buckets.remove(index))
+        }
+    }
+
+    fn take_proof(
+        &mut self,
+        state: &mut GenerationState,
+    ) -> Option<ManifestProof> {
+        if state.proofs.is_empty() {
+            None
+        } else {
+            let index = self.rng.next_below(state.proofs.len());
+            Some(state.proofs.remove(index))
+        }
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            items.get(self.rng.next_below(items.len()))
+        }
+    }
+
+    fn dynamic_global_address(
+        &mut self,
+        state: &GenerationState,
+    ) -> DynamicGlobalAddress {
+        match self.pick(&state.named_addresses) {
+            Some(named_address) if self.rng.next_bool() => {
+                DynamicGlobalAddress::Named(*named_address)
+            }
+            _ => DynamicGlobalAddress::Static(self.global_address()),
+        }
+    }
+
+    fn global_address(&mut self) -> GlobalAddress {
+        const ENTITY_TYPES: [EntityType; 6] = [
+            EntityType::GlobalPackage,
+            EntityType::GlobalFungibleResourceManager,
+            EntityType::GlobalNonFungibleResourceManager,
+            EntityType::GlobalGenericComponent,
+            EntityType::GlobalAccount,
+            EntityType::GlobalVirtualSecp256k1Account,
+        ];
+        let entity_type = ENTITY_TYPES[self.rng.next_below(ENTITY_TYPES.len())];
+        GlobalAddress::new_or_panic(self.node_id_bytes(entity_type))
+    }
+
+    fn package_address(&mut self) -> PackageAddress {
+        PackageAddress::new_or_panic(
+            self.node_id_bytes(EntityType::GlobalPackage),
+        )
+    }
+
+    fn resource_address(&mut self) -> ResourceAddress {
+        let entity_type = if self.rng.next_bool() {
+            EntityType::GlobalFungibleResourceManager
+        } else {
+            EntityType::GlobalNonFungibleResourceManager
+        };
+        ResourceAddress::new_or_panic(self.node_id_bytes(entity_type))
+    }
+
+    fn vault_address(&mut self) -> InternalAddress {
+        let entity_type = if self.rng.next_bool() {
+            EntityType::InternalFungibleVault
+        } else {
+            EntityType::InternalNonFungibleVault
+        };
+        InternalAddress::new_or_panic(self.node_id_bytes(entity_type))
+    }
+
+    fn node_id_bytes(&mut self, entity_type: EntityType) -> [u8; 30] {
+        let mut bytes = [0u8; NodeId::LENGTH];
+        self.rng.fill(&mut bytes);
+        bytes[0] = entity_type as u8;
+        bytes
+    }
+
+    fn decimal(&mut self) -> Decimal {
+        let whole = self.rng.next_u64() % 1_000_000_000;
+        let fraction = self.rng.next_u64() % 1_000_000_000_000_000_000;
+        Decimal::from_str(&format!("{whole}.{fraction:018}"))
+            .expect("Must succeed")
+    }
+
+    fn precise_decimal(&mut self) -> PreciseDecimal {
+        let whole = self.rng.next_u64() % 1_000_000_000;
+        let fraction = self.rng.next_u128() % 10u128.pow(36);
+        PreciseDecimal::from_str(&format!("{whole}.{fraction:036}"))
+            .expect("Must succeed")
+    }
+
+    fn non_fungible_local_ids(&mut self) -> Vec<NonFungibleLocalId> {
+        (0..self.rng.next_below(self.config.max_elements) + 1)
+            .map(|_| self.non_fungible_local_id())
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    fn non_fungible_local_id(&mut self) -> NonFungibleLocalId {
+        match self.rng.next_below(4) {
+            0 => NonFungibleLocalId::integer(self.rng.next_u64()),
+            1 => {
+                NonFungibleLocalId::string(self.ident()).expect("Must succeed")
+            }
+            2 => {
+                let length = self.rng.next_below(64) + 1;
+                NonFungibleLocalId::bytes(self.bytes(length))
+                    .expect("Must succeed")
+            }
+            _ => {
+                let mut bytes = [0u8; 32];
+                self.rng.fill(&mut bytes);
+                NonFungibleLocalId::ruid(bytes)
+            }
+        }
+    }
+
+    fn ident(&mut self) -> String {
+        const FIRST: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+        const REST: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789_";
+        let length = self.rng.next_below(16);
+        std::iter::once(FIRST[self.rng.next_below(FIRST.len())])
+            .chain((0..length).map(|_| REST[self.rng.next_below(REST.len())]))
+            .map(char::from)
+            .collect()
+    }
+
+    fn string(&mut self) -> String {
+        const CHARACTERS: &[u8] =
+            b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789 ";
+        let length = self.rng.next_below(32);
+        (0..length)
+            .map(|_| {
+                char::from(CHARACTERS[self.rng.next_below(CHARACTERS.len())])
+            })
+            .collect()
+    }
+
+    fn bytes(&mut self, length: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; length];
+        self.rng.fill(&mut bytes);
+        bytes
+    }
+}
@@ -0,0 +1,94 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Manifests that create resources and fund accounts on networks with a
+//! faucet such as the simulator network. The fees of all of the manifests are
+//! locked from the faucet and the created resources are deposited into the
+//! account.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+/// The data of the sample non-fungibles created by
+/// [`create_sample_non_fungibles`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, ScryptoSbor, ManifestSbor, NonFungibleData,
+)]
+pub struct SampleNonFungibleData {
+    pub name: String,
+}
+
+/// Gets free XRD from the faucet and deposits it into the account.
+pub fn get_free_xrd(
+    account_address: ComponentAddress,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account_address, None)
+        .build()
+}
+
+/// Creates a fungible resource without an owner or roles and deposits its
+/// initial supply into the account.
+pub fn create_fungible_with_initial_supply(
+    account_address: ComponentAddress,
+    initial_supply: Decimal,
+    divisibility: u8,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            divisibility,
+            FungibleResourceRoles::default(),
+            metadata!(),
+            Some(initial_supply),
+        )
+        .try_deposit_entire_worktop_or_abort(account_address, None)
+        .build()
+}
+
+/// Creates a non-fungible resource with integer ids without an owner or roles
+/// and deposits the non-fungibles with the ids `1` through `count` into the
+/// account.
+pub fn create_sample_non_fungibles(
+    account_address: ComponentAddress,
+    count: u64,
+) -> TransactionManifestV1 {
+    let entries = (1..=count).map(|id| {
+        (
+            NonFungibleLocalId::integer(id),
+            SampleNonFungibleData {
+                name: format!("Sample #{id}"),
+            },
+        )
+    });
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_non_fungible_resource(
+            OwnerRole::None,
+            NonFungibleIdType::Integer,
+            true,
+            NonFungibleResourceRoles::default(),
+            metadata!(),
+            Some(entries),
+        )
+        .try_deposit_entire_worktop_or_abort(account_address, None)
+        .build()
+}
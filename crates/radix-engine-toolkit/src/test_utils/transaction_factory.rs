@@ -0,0 +1,124 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A factory of signed and notarized transactions whose keys and nonces are
+//! deterministic so that the payloads it produces are the same on every run.
+
+use radix_transactions::model::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+/// The number of epochs after the start epoch that the transactions of the
+/// factory are valid for.
+const EPOCH_RANGE: u64 = 10;
+
+/// Builds signed and notarized transactions for a network. The signers are
+/// identified by ids from which their keys are derived, and the notary and the
+/// nonces are fixed, so the same calls always produce the same transactions.
+pub struct TestTransactionFactory {
+    network_definition: NetworkDefinition,
+    notary_private_key: Ed25519PrivateKey,
+    start_epoch: Epoch,
+    next_nonce: u32,
+}
+
+impl TestTransactionFactory {
+    /// Creates a factory of transactions for the network that are valid from
+    /// the genesis epoch.
+    pub fn new(network_definition: NetworkDefinition) -> Self {
+        Self {
+            network_definition,
+            notary_private_key: Ed25519PrivateKey::from_u64(1).unwrap(),
+            start_epoch: Epoch::of(0),
+            next_nonce: 0,
+        }
+    }
+
+    /// Makes the transactions valid from the epoch rather than from the
+    /// genesis epoch.
+    pub fn with_start_epoch(mut self, start_epoch: Epoch) -> Self {
+        self.start_epoch = start_epoch;
+        self
+    }
+
+    /// The private key of the signer with the id. The same id always gives the
+    /// same key and every id, including zero, gives a valid key.
+    pub fn signer_private_key(id: u64) -> Secp256k1PrivateKey {
+        // The id is offset by one since a private key of zero isn't valid.
+        let scalar = id as u128 + 1;
+        let mut bytes = [0u8; Secp256k1PrivateKey::LENGTH];
+        bytes[Secp256k1PrivateKey::LENGTH - 16..]
+            .copy_from_slice(&scalar.to_be_bytes());
+        Secp256k1PrivateKey::from_bytes(&bytes)
+            .expect("Any scalar below 2^65 is a valid private key")
+    }
+
+    /// The address of the virtual account of the signer with the id.
+    pub fn signer_account_address(id: u64) -> ComponentAddress {
+        ComponentAddress::virtual_account_from_public_key(
+            &Self::signer_private_key(id).public_key(),
+        )
+    }
+
+    /// The private key that notarizes all of the transactions of the factory.
+    pub fn notary_private_key(&self) -> &Ed25519PrivateKey {
+        &self.notary_private_key
+    }
+
+    /// Signs the manifest with the keys of the signers with the ids and
+    /// notarizes it. Every transaction of the factory has the next nonce.
+    pub fn notarized_transaction(
+        &mut self,
+        manifest: TransactionManifestV1,
+        signer_ids: &[u64],
+    ) -> NotarizedTransactionV1 {
+        let nonce = self.next_nonce;
+        self.next_nonce += 1;
+
+        let header = TransactionHeaderV1 {
+            network_id: self.network_definition.id,
+            start_epoch_inclusive: self.start_epoch,
+            end_epoch_exclusive: Epoch::of(
+                self.start_epoch.number() + EPOCH_RANGE,
+            ),
+            nonce,
+            notary_public_key: self.notary_private_key.public_key().into(),
+            notary_is_signatory: false,
+            tip_percentage: 0,
+        };
+        signer_ids
+            .iter()
+            .fold(
+                TransactionBuilder::new().header(header).manifest(manifest),
+                |builder, id| builder.sign(&Self::signer_private_key(*id)),
+            )
+            .notarize(&self.notary_private_key)
+            .build()
+    }
+
+    /// The compiled payload of the transaction built by
+    /// [`Self::notarized_transaction`].
+    pub fn compiled_notarized_transaction(
+        &mut self,
+        manifest: TransactionManifestV1,
+        signer_ids: &[u64],
+    ) -> Vec<u8> {
+        let transaction = self.notarized_transaction(manifest, signer_ids);
+        crate::functions::notarized_transaction::compile(&transaction)
+            .expect("A notarized transaction can always be compiled")
+    }
+}
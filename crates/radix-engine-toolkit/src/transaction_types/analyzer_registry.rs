@@ -0,0 +1,106 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The registry of the custom analyzers that run alongside the trusted
+//! worktop. Crates built on top of the toolkit register their own
+//! [`ManifestAnalyzer`]s while callers over the FFI register declarative
+//! [`AnalyzerRules`].
+
+use radix_common::prelude::*;
+use radix_transactions::prelude::*;
+
+use crate::transaction_types::*;
+
+type AnalyzerFactory = Box<dyn Fn() -> Box<dyn ManifestAnalyzer> + Send + Sync>;
+
+/// A registry of analyzers. Analyzers hold the state of a single traversal
+/// and the registry therefore stores factories that create a new analyzer for
+/// every manifest that is analyzed.
+#[derive(Default)]
+pub struct ManifestAnalyzerRegistry {
+    factories: Vec<AnalyzerFactory>,
+}
+
+impl ManifestAnalyzerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an analyzer. The factory is called once for every analyzed
+    /// manifest.
+    pub fn register<F, A>(&mut self, factory: F) -> &mut Self
+    where
+        F: Fn() -> A + Send + Sync + 'static,
+        A: ManifestAnalyzer + 'static,
+    {
+        self.factories.push(Box::new(move || {
+            Box::new(factory()) as Box<dyn ManifestAnalyzer>
+        }));
+        self
+    }
+
+    /// Registers an analyzer that evaluates the given declarative rules.
+    pub fn register_rules(&mut self, rules: AnalyzerRules) -> &mut Self {
+        self.register(move || RuleAnalyzer::new(rules.clone()))
+    }
+
+    /// Traverses the manifest once with the trusted worktop and all of the
+    /// registered analyzers.
+    pub fn analyze(
+        &self,
+        manifest: &TransactionManifestV1,
+    ) -> ManifestAnalysis {
+        let mut trusted_worktop = StaticWorktopContentsTracker::default();
+        let mut analyzers = self
+            .factories
+            .iter()
+            .map(|factory| factory())
+            .collect::<Vec<_>>();
+
+        let mut callbacks =
+            vec![&mut trusted_worktop as &mut dyn ManifestSummaryCallback];
+        callbacks.extend(
+            analyzers
+                .iter_mut()
+                .map(|analyzer| analyzer as &mut dyn ManifestSummaryCallback),
+        );
+        traverser::manifest_summary::traverse(&mut callbacks, manifest);
+
+        let mut sections = IndexMap::<String, Vec<AnalysisFinding>>::new();
+        for analyzer in analyzers {
+            let name = analyzer.name();
+            sections.entry(name).or_default().extend(analyzer.output());
+        }
+
+        ManifestAnalysis {
+            trusted_worktop: trusted_worktop.output(),
+            sections,
+        }
+    }
+
+    /// Analyzes each of the manifests, returning the analyses in the order of
+    /// the manifests. With the `parallel` feature the manifests are analyzed
+    /// across the threads of the rayon thread pool.
+    pub fn analyze_bulk(
+        &self,
+        manifests: &[TransactionManifestV1],
+    ) -> Vec<ManifestAnalysis> {
+        crate::utils::map_in_order(manifests.iter().collect(), |manifest| {
+            self.analyze(manifest)
+        })
+    }
+}
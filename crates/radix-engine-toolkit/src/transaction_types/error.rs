@@ -21,4 +21,7 @@ pub enum TransactionTypesError {
     /// receipt does not have the execution trace information or due to the
     /// transaction failing.
     InvalidReceipt,
+    /// The encoded preview receipt could not be decoded as a versioned
+    /// transaction receipt.
+    UndecodableReceipt,
 }
@@ -49,6 +49,8 @@ pub fn summary(manifest: &TransactionManifestV1) -> ManifestSummary {
     let mut validator_claim_detector = ValidatorClaimDetector::default();
     let mut accounts_settings_detector =
         AccountSettingsUpdateDetector::default();
+    let mut account_locker_claim_detector =
+        AccountLockerClaimDetector::default();
 
     // Traversing the manifest with the passed detectors
     traverser::manifest_summary::traverse(
@@ -66,6 +68,7 @@ pub fn summary(manifest: &TransactionManifestV1) -> ManifestSummary {
             &mut validator_unstake_detector,
             &mut validator_claim_detector,
             &mut accounts_settings_detector,
+            &mut account_locker_claim_detector,
             // TODO: Re-enable trusted worktop but some thinking needs to be put
             // into how we can get the transactions to be be summarized if they
             // are syntactically valid but contain invalid invocations to things
@@ -118,6 +121,10 @@ pub fn summary(manifest: &TransactionManifestV1) -> ManifestSummary {
                 ManifestClass::AccountDepositSettingsUpdate,
                 accounts_settings_detector.is_valid(),
             ),
+            (
+                ManifestClass::AccountLockerClaim,
+                account_locker_claim_detector.is_valid(),
+            ),
         ]
         .into_iter()
         .filter_map(
@@ -138,10 +145,268 @@ pub fn summary(manifest: &TransactionManifestV1) -> ManifestSummary {
     }
 }
 
-pub fn execution_summary(
+/// Classifies the manifest into all of the classes that it conforms to, the
+/// most specific class first, along with the details of each class that can
+/// be determined without executing the manifest.
+pub fn classify_manifest(
+    manifest: &TransactionManifestV1,
+) -> Vec<ManifestClassification> {
+    let mut general_transaction_detector = GeneralDetector::default();
+    let mut transfer_transaction_detector = TransferDetector::default();
+    let mut pool_contribution_detector = PoolContributionDetector::default();
+    let mut pool_redemption_detector = PoolRedemptionDetector::default();
+    let mut validator_stake_detector = ValidatorStakeDetector::default();
+    let mut validator_unstake_detector = ValidatorUnstakeDetector::default();
+    let mut validator_claim_detector = ValidatorClaimDetector::default();
+    let mut accounts_settings_detector =
+        AccountSettingsUpdateDetector::default();
+    let mut account_locker_claim_detector =
+        AccountLockerClaimDetector::default();
+
+    traverser::manifest_summary::traverse(
+        &mut [
+            &mut general_transaction_detector,
+            &mut transfer_transaction_detector,
+            &mut pool_contribution_detector,
+            &mut pool_redemption_detector,
+            &mut validator_stake_detector,
+            &mut validator_unstake_detector,
+            &mut validator_claim_detector,
+            &mut accounts_settings_detector,
+            &mut account_locker_claim_detector,
+        ],
+        manifest,
+    );
+
+    [
+        general_transaction_detector
+            .output()
+            .map(|_| ManifestClassification::General),
+        transfer_transaction_detector.output().map(|_| {
+            match simple_transfer(&manifest.instructions) {
+                Some(simple_transfer) => {
+                    ManifestClassification::SimpleTransfer(simple_transfer)
+                }
+                None => ManifestClassification::GeneralTransfer,
+            }
+        }),
+        pool_contribution_detector.output().map(|(pool_addresses, _)| {
+            ManifestClassification::PoolContribution { pool_addresses }
+        }),
+        pool_redemption_detector.output().map(|(pool_addresses, _)| {
+            ManifestClassification::PoolRedemption { pool_addresses }
+        }),
+        validator_stake_detector.output().map(|(validator_addresses, _)| {
+            ManifestClassification::ValidatorStake {
+                validator_addresses,
+            }
+        }),
+        validator_unstake_detector.output().map(|(validator_addresses, _)| {
+            ManifestClassification::ValidatorUnstake {
+                validator_addresses,
+            }
+        }),
+        validator_claim_detector.output().map(|(validator_addresses, _)| {
+            ManifestClassification::ValidatorClaim {
+                validator_addresses,
+            }
+        }),
+        accounts_settings_detector.output().map(
+            |(
+                resource_preferences_updates,
+                deposit_mode_updates,
+                authorized_depositors_updates,
+            )| {
+                ManifestClassification::AccountDepositSettingsUpdate {
+                    resource_preferences_updates,
+                    deposit_mode_updates,
+                    authorized_depositors_updates:
+                        authorized_depositors_updates
+                            .into_iter()
+                            .map(|(account, updates)| {
+                                (
+                                    account,
+                                    updates
+                                        .into_iter()
+                                        .map(|(badge, update)| {
+                                            let operation = match update {
+                                                Update::Set(()) => {
+                                                    Operation::Added
+                                                }
+                                                Update::Remove => {
+                                                    Operation::Removed
+                                                }
+                                            };
+                                            (badge, operation)
+                                        })
+                                        .collect(),
+                                )
+                            })
+                            .collect(),
+                }
+            },
+        ),
+        account_locker_claim_detector.output().map(|(locker_addresses, _)| {
+            ManifestClassification::AccountLockerClaim { locker_addresses }
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .rev()
+    .collect()
+}
+
+/// Summarizes each of the manifests, returning the summaries in the order of
+/// the manifests. With the `parallel` feature the manifests are summarized
+/// across the threads of the rayon thread pool.
+pub fn analyze_manifests_bulk(
+    manifests: Vec<TransactionManifestV1>,
+) -> Vec<ManifestSummary> {
+    crate::utils::map_in_order(manifests, |manifest| summary(&manifest))
+}
+
+/// Determines for every instruction in the manifest whether its effect on the
+/// worktop can be known statically and the resources that it moves.
+pub fn trusted_worktop(
+    manifest: &TransactionManifestV1,
+) -> Vec<TrustedWorktopInstruction> {
+    let mut trusted_worktop = StaticWorktopContentsTracker::default();
+    traverser::manifest_summary::traverse(&mut [&mut trusted_worktop], manifest);
+    trusted_worktop.output()
+}
+
+/// Determines the accounts that the manifest withdraws from, deposits into, and
+/// requires the signatures of without classifying the manifest.
+pub fn account_interactions(
+    manifest: &TransactionManifestV1,
+) -> AccountInteractions {
+    let mut presented_proofs_detector = PresentedProofsDetector::default();
+    let mut requiring_auth_detector = RequiringAuthDetector::default();
+    let mut account_resource_movements_detector =
+        StaticAccountResourceMovementsDetector::default();
+    traverser::manifest_summary::traverse(
+        &mut [
+            &mut presented_proofs_detector,
+            &mut requiring_auth_detector,
+            &mut account_resource_movements_detector,
+        ],
+        manifest,
+    );
+
+    let (accounts_withdrawn_from, accounts_deposited_into) =
+        account_resource_movements_detector.output();
+    let (accounts_requiring_auth, _) = requiring_auth_detector.output();
+    AccountInteractions {
+        accounts_withdrawn_from,
+        accounts_deposited_into,
+        accounts_requiring_auth,
+        presented_proofs: presented_proofs_detector.output(),
+    }
+}
+
+/// Detects the instructions in the manifest that operate on security-sensitive
+/// native entities.
+pub fn detect_reserved_instructions(
     manifest: &TransactionManifestV1,
-    receipt: &TransactionReceipt,
+) -> Vec<SensitiveInstruction> {
+    let mut sensitive_instructions_detector =
+        SensitiveInstructionsDetector::default();
+    traverser::manifest_summary::traverse(
+        &mut [&mut sensitive_instructions_detector],
+        manifest,
+    );
+    sensitive_instructions_detector.output()
+}
+
+/// Detects the instructions in the manifest that change the owner role, role
+/// assignments, metadata locks, or royalty configuration of entities.
+pub fn security_impacts(
+    manifest: &TransactionManifestV1,
+) -> Vec<SecurityImpact> {
+    let mut security_impact_detector = SecurityImpactDetector::default();
+    traverser::manifest_summary::traverse(
+        &mut [&mut security_impact_detector],
+        manifest,
+    );
+    security_impact_detector.output()
+}
+
+/// Tracks the lineage of every named proof created in the manifest.
+pub fn proof_lineage(manifest: &TransactionManifestV1) -> Vec<ProofLineage> {
+    let mut proof_tracker = ProofTracker::default();
+    traverser::manifest_summary::traverse(&mut [&mut proof_tracker], manifest);
+    proof_tracker.output()
+}
+
+pub fn execution_summary<'r>(
+    manifest: &TransactionManifestV1,
+    receipt: impl Into<ExecutionReceipt<'r>>,
 ) -> Result<ExecutionSummary, TransactionTypesError> {
+    execution_summary_with_deposit_settings(
+        manifest,
+        receipt,
+        &Default::default(),
+    )
+}
+
+/// Builds the graph of the movements of resources between the entities invoked
+/// in the manifest from the execution of the manifest.
+pub fn resource_movements_graph<'r>(
+    manifest: &TransactionManifestV1,
+    receipt: impl Into<ExecutionReceipt<'r>>,
+) -> Result<ResourceMovementsGraph, TransactionTypesError> {
+    let versioned_receipt;
+    let receipt = match receipt.into() {
+        ExecutionReceipt::Native(receipt) => receipt,
+        ExecutionReceipt::EncodedPreview(encoded_receipt) => {
+            versioned_receipt = decode_preview_receipt(encoded_receipt)?;
+            versioned_receipt
+                .as_latest_version()
+                .ok_or(TransactionTypesError::InvalidReceipt)?
+        }
+    };
+    let receipt = TransactionTypesReceipt::new(receipt)
+        .ok_or(TransactionTypesError::InvalidReceipt)?;
+
+    let mut resource_movements_graph_builder =
+        ResourceMovementsGraphBuilder::default();
+    traverser::execution_summary::traverse(
+        &mut [&mut resource_movements_graph_builder],
+        manifest,
+        &receipt,
+    );
+    Ok(resource_movements_graph_builder.output())
+}
+
+fn decode_preview_receipt(
+    encoded_receipt: &[u8],
+) -> Result<VersionedTransactionReceipt, TransactionTypesError> {
+    scrypto_decode::<VersionedTransactionReceipt>(encoded_receipt)
+        .map_err(|_| TransactionTypesError::UndecodableReceipt)
+}
+
+/// Summarizes the execution of the manifest and checks the deposits into the
+/// accounts whose deposit settings are provided against those settings.
+pub fn execution_summary_with_deposit_settings<'r>(
+    manifest: &TransactionManifestV1,
+    receipt: impl Into<ExecutionReceipt<'r>>,
+    account_deposit_settings: &IndexMap<
+        ComponentAddress,
+        AccountDepositSettings,
+    >,
+) -> Result<ExecutionSummary, TransactionTypesError> {
+    // Normalize the preview receipts into native receipts
+    let versioned_receipt;
+    let receipt = match receipt.into() {
+        ExecutionReceipt::Native(receipt) => receipt,
+        ExecutionReceipt::EncodedPreview(encoded_receipt) => {
+            versioned_receipt = decode_preview_receipt(encoded_receipt)?;
+            versioned_receipt
+                .as_latest_version()
+                .ok_or(TransactionTypesError::InvalidReceipt)?
+        }
+    };
+
     // Attempt to create a tx types receipt from the passed receipt
     let receipt = TransactionTypesReceipt::new(receipt)
         .ok_or(TransactionTypesError::InvalidReceipt)?;
@@ -155,6 +420,7 @@ pub fn execution_summary(
         ReservedInstructionsDetector::default();
     let mut account_resource_movements_detector =
         AccountResourceMovementsDetector::default();
+    let mut fee_locks_detector = FeeLocksDetector::default();
     let newly_created_non_fungibles = receipt.new_non_fungibles();
 
     let mut general_transaction_detector = GeneralDetector::default();
@@ -166,6 +432,8 @@ pub fn execution_summary(
     let mut validator_claim_detector = ValidatorClaimDetector::default();
     let mut accounts_settings_detector =
         AccountSettingsUpdateDetector::default();
+    let mut account_locker_claim_detector =
+        AccountLockerClaimDetector::default();
 
     // Traversing the manifest with the passed detectors
     traverser::execution_summary::traverse(
@@ -175,6 +443,7 @@ pub fn execution_summary(
             &mut requiring_auth_detector,
             &mut reserved_instructions_detector,
             &mut account_resource_movements_detector,
+            &mut fee_locks_detector,
             &mut general_transaction_detector,
             &mut transfer_transaction_detector,
             &mut pool_contribution_detector,
@@ -183,6 +452,7 @@ pub fn execution_summary(
             &mut validator_unstake_detector,
             &mut validator_claim_detector,
             &mut accounts_settings_detector,
+            &mut account_locker_claim_detector,
         ],
         manifest,
         &receipt,
@@ -191,6 +461,10 @@ pub fn execution_summary(
     // Extracting the data into an ExecutionSummary
     let (account_withdraws, account_deposits) =
         account_resource_movements_detector.output();
+    let balance_changes = AccountBalanceChanges::from_movements(
+        &account_withdraws,
+        &account_deposits,
+    );
     let presented_proofs = presented_proofs_detector.output();
     let new_entities = NewEntities {
         component_addresses: receipt.new_components().clone(),
@@ -311,6 +585,14 @@ pub fn execution_summary(
                 }
             },
         ),
+        account_locker_claim_detector.output().map(
+            |(locker_addresses, locker_claims)| {
+                DetailedManifestClass::AccountLockerClaim {
+                    locker_addresses,
+                    locker_claims,
+                }
+            },
+        ),
     ]
     .into_iter()
     .flatten()
@@ -321,11 +603,38 @@ pub fn execution_summary(
         lock: receipt.execution_trace().fee_locks.lock,
         contingent_lock: receipt.execution_trace().fee_locks.contingent_lock,
     };
+    let fee_locks_by_account = fee_locks_detector.output();
+    let deposit_compatibility = account_deposits
+        .iter()
+        .filter_map(|(account, deposits)| {
+            let settings = account_deposit_settings.get(account)?;
+            Some((
+                *account,
+                deposits
+                    .iter()
+                    .map(|deposit| {
+                        let resource_address = match deposit {
+                            ResourceIndicator::Fungible(resource_address, _)
+                            | ResourceIndicator::NonFungible(
+                                resource_address,
+                                _,
+                            ) => *resource_address,
+                        };
+                        (
+                            resource_address,
+                            settings.deposit_compatibility(&resource_address),
+                        )
+                    })
+                    .collect(),
+            ))
+        })
+        .collect();
     let fee_summary = FeeSummary {
         execution_cost: receipt.fee_summary.total_execution_cost_in_xrd,
         finalization_cost: receipt.fee_summary.total_finalization_cost_in_xrd,
         storage_expansion_cost: receipt.fee_summary.total_storage_cost_in_xrd,
         royalty_cost: receipt.fee_summary.total_royalty_cost_in_xrd,
+        tipping_cost: receipt.fee_summary.total_tipping_cost_in_xrd,
     };
 
     Ok(ExecutionSummary {
@@ -338,8 +647,11 @@ pub fn execution_summary(
         identities_requiring_auth,
         reserved_instructions,
         fee_locks,
+        fee_locks_by_account,
         fee_summary,
         detailed_classification,
         newly_created_non_fungibles,
+        deposit_compatibility,
+        balance_changes,
     })
 }
@@ -18,12 +18,16 @@
 //! A module containing the logic and types needed for the transaction types
 //! classification and for the execution summary.
 
+mod analyzer_registry;
 mod error;
 mod interface;
+mod simple_transfer;
 mod traverser;
 mod types;
 
+pub use analyzer_registry::*;
 pub use error::*;
 pub use interface::*;
+pub use simple_transfer::*;
 pub use traverser::*;
 pub use types::*;
@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::contains;
+use crate::sbor::indexed_manifest_value::*;
+use crate::transaction_types::*;
+use crate::utils::*;
+
+/// A compact description of a simple transfer: a withdraw of a single resource
+/// from one account and a deposit of all of it into another account with an
+/// optional lock fee before them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimpleTransfer {
+    pub from: ComponentAddress,
+    pub to: ComponentAddress,
+    pub resource: ResourceAddress,
+    pub amount: SimpleTransferAmount,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimpleTransferAmount {
+    Amount(Decimal),
+    Ids(IndexSet<NonFungibleLocalId>),
+}
+
+/// The result of analyzing a manifest with the simple transfer fast-path.
+#[derive(Clone, Debug)]
+pub enum SimpleTransferOrSummary {
+    /// The manifest is a simple transfer, the traverser was not used.
+    SimpleTransfer(SimpleTransfer),
+    /// The manifest is not a simple transfer and was fully analyzed.
+    Summary(ManifestSummary),
+}
+
+/// Detects if the manifest is a simple transfer by matching against the exact
+/// instruction shapes of such transfers without running the traverser. The
+/// following instructions are matched:
+///
+/// 1. An optional lock fee against an account.
+/// 2. A withdraw of a single resource from an account.
+/// 3. A take of the withdrawn resources from the worktop into a bucket.
+/// 4. A deposit of the bucket into an account.
+///
+/// [`None`] is returned if the manifest does not have this exact shape.
+pub fn simple_transfer(
+    instructions: &[InstructionV1],
+) -> Option<SimpleTransfer> {
+    let instructions = match instructions {
+        [InstructionV1::CallMethod {
+            address,
+            method_name,
+            ..
+        }, rest @ ..]
+            if is_account(address)
+                && contains!(method_name => [
+                    ACCOUNT_LOCK_FEE_IDENT,
+                    ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+                ]) =>
+        {
+            rest
+        }
+        _ => instructions,
+    };
+    let [withdraw, take, deposit] = instructions else {
+        return None;
+    };
+
+    let (from, resource, withdrawn_amount) = withdraw_information(withdraw)?;
+
+    let amount = match (take, withdrawn_amount) {
+        (
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            },
+            SimpleTransferAmount::Amount(withdrawn_amount),
+        ) if *resource_address == resource && *amount == withdrawn_amount => {
+            SimpleTransferAmount::Amount(withdrawn_amount)
+        }
+        (
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            },
+            SimpleTransferAmount::Ids(withdrawn_ids),
+        ) if *resource_address == resource
+            && ids.iter().cloned().collect::<IndexSet<_>>()
+                == withdrawn_ids =>
+        {
+            SimpleTransferAmount::Ids(withdrawn_ids)
+        }
+        (
+            InstructionV1::TakeAllFromWorktop { resource_address },
+            withdrawn_amount,
+        ) if *resource_address == resource => withdrawn_amount,
+        _ => return None,
+    };
+
+    let InstructionV1::CallMethod {
+        address: DynamicGlobalAddress::Static(to),
+        method_name,
+        args,
+    } = deposit
+    else {
+        return None;
+    };
+    if !is_account(to)
+        || !contains!(method_name => [
+            ACCOUNT_DEPOSIT_IDENT,
+            ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+        ])
+    {
+        return None;
+    }
+    let args = IndexedManifestValue::from_typed(args);
+    if args.buckets() != &vec![ManifestBucket(0)]
+        || !args.expressions().is_empty()
+    {
+        return None;
+    }
+
+    Some(SimpleTransfer {
+        from,
+        to: ComponentAddress::try_from(*to).ok()?,
+        resource,
+        amount,
+    })
+}
+
+/// Uses the simple transfer fast-path if the manifest is a simple transfer and
+/// falls back to the full manifest summary otherwise.
+pub fn simple_transfer_or_summary(
+    manifest: &TransactionManifestV1,
+) -> SimpleTransferOrSummary {
+    match simple_transfer(&manifest.instructions) {
+        Some(simple_transfer) => {
+            SimpleTransferOrSummary::SimpleTransfer(simple_transfer)
+        }
+        None => SimpleTransferOrSummary::Summary(summary(manifest)),
+    }
+}
+
+fn withdraw_information(
+    instruction: &InstructionV1,
+) -> Option<(ComponentAddress, ResourceAddress, SimpleTransferAmount)> {
+    let InstructionV1::CallMethod {
+        address: DynamicGlobalAddress::Static(address),
+        method_name,
+        args,
+    } = instruction
+    else {
+        return None;
+    };
+    if !is_account(address) {
+        return None;
+    }
+    let account = ComponentAddress::try_from(*address).ok()?;
+
+    match method_name.as_str() {
+        ACCOUNT_WITHDRAW_IDENT => {
+            let AccountWithdrawInput {
+                resource_address,
+                amount,
+            } = to_manifest_type(args)?;
+            Some((
+                account,
+                resource_address,
+                SimpleTransferAmount::Amount(amount),
+            ))
+        }
+        ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+            let AccountLockFeeAndWithdrawInput {
+                resource_address,
+                amount,
+                ..
+            } = to_manifest_type(args)?;
+            Some((
+                account,
+                resource_address,
+                SimpleTransferAmount::Amount(amount),
+            ))
+        }
+        ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+            let AccountWithdrawNonFungiblesInput {
+                resource_address,
+                ids,
+            } = to_manifest_type(args)?;
+            Some((
+                account,
+                resource_address,
+                SimpleTransferAmount::Ids(ids.into_iter().collect()),
+            ))
+        }
+        ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+            let AccountLockFeeAndWithdrawNonFungiblesInput {
+                resource_address,
+                ids,
+                ..
+            } = to_manifest_type(args)?;
+            Some((
+                account,
+                resource_address,
+                SimpleTransferAmount::Ids(ids.into_iter().collect()),
+            ))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine::transaction::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::transaction_types::*;
+use crate::utils::*;
+
+/// Aggregates the amount of fees locked by each account in the manifest. The
+/// amounts are the ones found in the arguments of the lock fee invocations
+/// and not the amounts that were actually charged.
+#[derive(Default)]
+pub struct FeeLocksDetector {
+    fee_locks: IndexMap<ComponentAddress, FeeLocks>,
+}
+
+impl FeeLocksDetector {
+    pub fn output(self) -> IndexMap<ComponentAddress, FeeLocks> {
+        self.fee_locks
+    }
+}
+
+impl ManifestSummaryCallback for FeeLocksDetector {
+    fn on_instruction(&mut self, instruction: &InstructionV1, _: usize) {
+        let InstructionV1::CallMethod {
+            address: dynamic_address @ DynamicGlobalAddress::Static(address),
+            method_name,
+            args,
+        } = instruction
+        else {
+            return;
+        };
+        if !is_account(dynamic_address) {
+            return;
+        }
+
+        let encoded_args = manifest_encode(args).expect("Must succeed!");
+        let (lock, contingent_lock) = match method_name.as_str() {
+            ACCOUNT_LOCK_FEE_IDENT => {
+                let Ok(AccountLockFeeInput { amount }) =
+                    manifest_decode(&encoded_args)
+                else {
+                    return;
+                };
+                (amount, Decimal::ZERO)
+            }
+            ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => {
+                let Ok(AccountLockContingentFeeInput { amount }) =
+                    manifest_decode(&encoded_args)
+                else {
+                    return;
+                };
+                (Decimal::ZERO, amount)
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                let Ok(AccountLockFeeAndWithdrawInput {
+                    amount_to_lock, ..
+                }) = manifest_decode(&encoded_args)
+                else {
+                    return;
+                };
+                (amount_to_lock, Decimal::ZERO)
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                let Ok(AccountLockFeeAndWithdrawNonFungiblesInput {
+                    amount_to_lock,
+                    ..
+                }) = manifest_decode(&encoded_args)
+                else {
+                    return;
+                };
+                (amount_to_lock, Decimal::ZERO)
+            }
+            _ => return,
+        };
+
+        let account =
+            ComponentAddress::try_from(*address).expect("Must succeed!");
+        let fee_locks = self.fee_locks.entry(account).or_insert(FeeLocks {
+            lock: Decimal::ZERO,
+            contingent_lock: Decimal::ZERO,
+        });
+        fee_locks.lock =
+            fee_locks.lock.checked_add(lock).unwrap_or(Decimal::MAX);
+        fee_locks.contingent_lock = fee_locks
+            .contingent_lock
+            .checked_add(contingent_lock)
+            .unwrap_or(Decimal::MAX);
+    }
+}
+
+impl ExecutionSummaryCallback for FeeLocksDetector {}
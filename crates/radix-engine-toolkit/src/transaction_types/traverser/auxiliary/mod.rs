@@ -16,15 +16,27 @@
 // under the License.
 
 mod encountered_entities;
+mod fee_locks;
 mod presented_proofs;
+mod proof_tracker;
 mod requiring_auth;
 mod reserved_instructions;
+mod resource_movements_graph;
+mod rule_analyzer;
+mod security_impact;
+mod sensitive_instructions;
 mod static_account_resource_movements;
 mod static_worktop_contents_tracker;
 
 pub use encountered_entities::*;
+pub use fee_locks::*;
 pub use presented_proofs::*;
+pub use proof_tracker::*;
 pub use requiring_auth::*;
 pub use reserved_instructions::*;
+pub use resource_movements_graph::*;
+pub use rule_analyzer::*;
+pub use security_impact::*;
+pub use sensitive_instructions::*;
 pub use static_account_resource_movements::*;
 pub use static_worktop_contents_tracker::*;
@@ -0,0 +1,328 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::sbor::indexed_manifest_value::*;
+use crate::transaction_types::*;
+
+/// The lineage of a named proof created in the manifest: what it derives from,
+/// who it is presented to, and where it is dropped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofLineage {
+    pub proof: ManifestProof,
+    /// The index of the instruction that created the proof.
+    pub created_at: usize,
+    pub origin: ProofOrigin,
+    /// The proof that this proof is a clone of, if it was cloned.
+    pub cloned_from: Option<ManifestProof>,
+    /// The instructions that the proof was presented in. Presenting a proof
+    /// moves it, so a proof is presented at most once unless it's cloned.
+    pub presentations: Vec<ProofPresentation>,
+    /// The index of the instruction that dropped the proof, if it was dropped
+    /// while still named.
+    pub dropped_at: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofOrigin {
+    /// The proof was created from the resources in the auth zone.
+    AuthZone {
+        resource_address: ResourceAddress,
+        amount: ProofAmount,
+    },
+    /// The proof was popped from the auth zone and its resource can't be
+    /// determined statically.
+    AuthZonePop,
+    /// The proof was created from a bucket. The resource is only known if the
+    /// bucket was taken from the worktop.
+    Bucket {
+        bucket_id: ManifestBucket,
+        resource_address: Option<ResourceAddress>,
+        amount: ProofAmount,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofAmount {
+    Amount(Decimal),
+    NonFungibles(Vec<NonFungibleLocalId>),
+    All,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofPresentation {
+    pub instruction_index: usize,
+    pub recipient: ProofRecipient,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofRecipient {
+    /// The proof was pushed to the auth zone where it is available to all of
+    /// the following invocations.
+    AuthZone,
+    /// The proof was passed to an invocation of a global entity.
+    Entity(GlobalAddress),
+    /// The proof was passed to an invocation of an entity whose address is
+    /// reserved in the manifest.
+    NamedAddress(u32),
+    /// The proof was passed to a direct vault method.
+    Vault(InternalAddress),
+}
+
+#[derive(Default)]
+pub struct ProofTracker {
+    /// The resource of every bucket taken from the worktop.
+    bucket_resources: IndexMap<ManifestBucket, ResourceAddress>,
+    next_bucket_id: u32,
+    next_proof_id: u32,
+    lineages: IndexMap<ManifestProof, ProofLineage>,
+    /// The proofs that are still named, i.e., have not been moved or dropped.
+    named_proofs: IndexSet<ManifestProof>,
+}
+
+impl ProofTracker {
+    pub fn output(self) -> Vec<ProofLineage> {
+        self.lineages.into_values().collect()
+    }
+
+    fn new_bucket(&mut self, resource_address: ResourceAddress) {
+        self.bucket_resources
+            .insert(ManifestBucket(self.next_bucket_id), resource_address);
+        self.next_bucket_id += 1;
+    }
+
+    fn new_proof(
+        &mut self,
+        instruction_index: usize,
+        origin: ProofOrigin,
+        cloned_from: Option<ManifestProof>,
+    ) {
+        let proof = ManifestProof(self.next_proof_id);
+        self.next_proof_id += 1;
+        self.named_proofs.insert(proof);
+        self.lineages.insert(
+            proof,
+            ProofLineage {
+                proof,
+                created_at: instruction_index,
+                origin,
+                cloned_from,
+                presentations: vec![],
+                dropped_at: None,
+            },
+        );
+    }
+
+    fn new_bucket_proof(
+        &mut self,
+        instruction_index: usize,
+        bucket_id: &ManifestBucket,
+        amount: ProofAmount,
+    ) {
+        let origin = ProofOrigin::Bucket {
+            bucket_id: *bucket_id,
+            resource_address: self.bucket_resources.get(bucket_id).copied(),
+            amount,
+        };
+        self.new_proof(instruction_index, origin, None)
+    }
+
+    fn present_proof(
+        &mut self,
+        proof: &ManifestProof,
+        instruction_index: usize,
+        recipient: ProofRecipient,
+    ) {
+        self.named_proofs.swap_remove(proof);
+        if let Some(lineage) = self.lineages.get_mut(proof) {
+            lineage.presentations.push(ProofPresentation {
+                instruction_index,
+                recipient,
+            })
+        }
+    }
+
+    fn drop_proof(&mut self, proof: &ManifestProof, instruction_index: usize) {
+        self.named_proofs.swap_remove(proof);
+        if let Some(lineage) = self.lineages.get_mut(proof) {
+            lineage.dropped_at = Some(instruction_index)
+        }
+    }
+}
+
+impl ManifestSummaryCallback for ProofTracker {
+    fn on_instruction(
+        &mut self,
+        instruction: &InstructionV1,
+        instruction_index: usize,
+    ) {
+        match instruction {
+            InstructionV1::TakeFromWorktop {
+                resource_address, ..
+            }
+            | InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ..
+            }
+            | InstructionV1::TakeAllFromWorktop { resource_address } => {
+                self.new_bucket(*resource_address)
+            }
+            InstructionV1::PopFromAuthZone => self.new_proof(
+                instruction_index,
+                ProofOrigin::AuthZonePop,
+                None,
+            ),
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                amount,
+            } => self.new_proof(
+                instruction_index,
+                ProofOrigin::AuthZone {
+                    resource_address: *resource_address,
+                    amount: ProofAmount::Amount(*amount),
+                },
+                None,
+            ),
+            InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ids,
+            } => self.new_proof(
+                instruction_index,
+                ProofOrigin::AuthZone {
+                    resource_address: *resource_address,
+                    amount: ProofAmount::NonFungibles(ids.clone()),
+                },
+                None,
+            ),
+            InstructionV1::CreateProofFromAuthZoneOfAll {
+                resource_address,
+            } => self.new_proof(
+                instruction_index,
+                ProofOrigin::AuthZone {
+                    resource_address: *resource_address,
+                    amount: ProofAmount::All,
+                },
+                None,
+            ),
+            InstructionV1::CreateProofFromBucketOfAmount {
+                bucket_id,
+                amount,
+            } => self.new_bucket_proof(
+                instruction_index,
+                bucket_id,
+                ProofAmount::Amount(*amount),
+            ),
+            InstructionV1::CreateProofFromBucketOfNonFungibles {
+                bucket_id,
+                ids,
+            } => self.new_bucket_proof(
+                instruction_index,
+                bucket_id,
+                ProofAmount::NonFungibles(ids.clone()),
+            ),
+            InstructionV1::CreateProofFromBucketOfAll { bucket_id } => self
+                .new_bucket_proof(
+                    instruction_index,
+                    bucket_id,
+                    ProofAmount::All,
+                ),
+            InstructionV1::CloneProof { proof_id } => {
+                if let Some(lineage) = self.lineages.get(proof_id) {
+                    let origin = lineage.origin.clone();
+                    self.new_proof(instruction_index, origin, Some(*proof_id))
+                }
+            }
+            InstructionV1::PushToAuthZone { proof_id } => self.present_proof(
+                proof_id,
+                instruction_index,
+                ProofRecipient::AuthZone,
+            ),
+            InstructionV1::DropProof { proof_id } => {
+                self.drop_proof(proof_id, instruction_index)
+            }
+            InstructionV1::DropNamedProofs | InstructionV1::DropAllProofs => {
+                for proof in std::mem::take(&mut self.named_proofs) {
+                    self.drop_proof(&proof, instruction_index)
+                }
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                args,
+                ..
+            } => {
+                let recipient = match package_address {
+                    DynamicPackageAddress::Static(address) => {
+                        ProofRecipient::Entity((*address).into())
+                    }
+                    DynamicPackageAddress::Named(name) => {
+                        ProofRecipient::NamedAddress(*name)
+                    }
+                };
+                self.on_invocation(args, instruction_index, recipient)
+            }
+            InstructionV1::CallMethod { address, args, .. }
+            | InstructionV1::CallRoyaltyMethod { address, args, .. }
+            | InstructionV1::CallMetadataMethod { address, args, .. }
+            | InstructionV1::CallRoleAssignmentMethod {
+                address, args, ..
+            } => {
+                let recipient = match address {
+                    DynamicGlobalAddress::Static(address) => {
+                        ProofRecipient::Entity(*address)
+                    }
+                    DynamicGlobalAddress::Named(name) => {
+                        ProofRecipient::NamedAddress(*name)
+                    }
+                };
+                self.on_invocation(args, instruction_index, recipient)
+            }
+            InstructionV1::CallDirectVaultMethod { address, args, .. } => self
+                .on_invocation(
+                    args,
+                    instruction_index,
+                    ProofRecipient::Vault(*address),
+                ),
+            InstructionV1::ReturnToWorktop { .. }
+            | InstructionV1::BurnResource { .. }
+            | InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::AllocateGlobalAddress { .. } => {}
+        }
+    }
+}
+
+impl ProofTracker {
+    fn on_invocation(
+        &mut self,
+        args: &ManifestValue,
+        instruction_index: usize,
+        recipient: ProofRecipient,
+    ) {
+        let args = IndexedManifestValue::from_typed(args);
+        for proof in args.proofs() {
+            self.present_proof(proof, instruction_index, recipient.clone())
+        }
+    }
+}
+
+impl ExecutionSummaryCallback for ProofTracker {}
@@ -64,7 +64,7 @@ impl ManifestSummaryCallback for ReservedInstructionsDetector {
         } else if is_identity(address) && method_name == IDENTITY_SECURIFY_IDENT
         {
             self.reserved_instructions
-                .insert(ReservedInstruction::AccountLockFee);
+                .insert(ReservedInstruction::IdentitySecurify);
         } else if is_account(address)
             && contains!(
                 method_name => [
@@ -0,0 +1,158 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine::system::system_modules::execution_trace::ResourceSpecifier;
+use radix_engine_interface::blueprints::resource::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::transaction_types::*;
+use crate::utils::*;
+
+/// Builds the graph of the movements of resources between the worktop and the
+/// entities invoked in the manifest from the resources that went into and out
+/// of each of the instructions.
+#[derive(Default)]
+pub struct ResourceMovementsGraphBuilder {
+    graph: ResourceMovementsGraph,
+}
+
+impl ResourceMovementsGraphBuilder {
+    pub fn output(self) -> ResourceMovementsGraph {
+        self.graph
+    }
+
+    /// The node that the resources of the instruction are moved into and out
+    /// of. Instructions that only move resources between the worktop and
+    /// buckets have none.
+    fn node_of_instruction(
+        instruction: &InstructionV1,
+    ) -> Option<ResourceMovementsNode> {
+        match instruction {
+            InstructionV1::CallMethod {
+                address: DynamicGlobalAddress::Static(address),
+                method_name,
+                ..
+            } if address.as_node_id().is_global_resource_manager() => {
+                match method_name.as_str() {
+                    FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT
+                    | NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT
+                    | NON_FUNGIBLE_RESOURCE_MANAGER_MINT_SINGLE_RUID_IDENT => {
+                        Some(ResourceMovementsNode::Mint)
+                    }
+                    RESOURCE_MANAGER_BURN_IDENT => {
+                        Some(ResourceMovementsNode::Burn)
+                    }
+                    _ => Some(ResourceMovementsNode::Entity(*address)),
+                }
+            }
+            InstructionV1::CallMethod { address, .. }
+            | InstructionV1::CallRoyaltyMethod { address, .. }
+            | InstructionV1::CallMetadataMethod { address, .. }
+            | InstructionV1::CallRoleAssignmentMethod { address, .. } => {
+                Some(match address {
+                    DynamicGlobalAddress::Static(address)
+                        if is_account(address) =>
+                    {
+                        ResourceMovementsNode::Account(
+                            ComponentAddress::try_from(*address)
+                                .expect("Must succeed!"),
+                        )
+                    }
+                    DynamicGlobalAddress::Static(address) => {
+                        ResourceMovementsNode::Entity(*address)
+                    }
+                    DynamicGlobalAddress::Named(named_address) => {
+                        ResourceMovementsNode::NamedEntity(*named_address)
+                    }
+                })
+            }
+            InstructionV1::CallFunction {
+                package_address, ..
+            } => Some(match package_address {
+                DynamicPackageAddress::Static(package_address) => {
+                    ResourceMovementsNode::Entity((*package_address).into())
+                }
+                DynamicPackageAddress::Named(named_address) => {
+                    ResourceMovementsNode::NamedEntity(*named_address)
+                }
+            }),
+            InstructionV1::CallDirectVaultMethod { address, .. } => {
+                Some(ResourceMovementsNode::Vault(*address))
+            }
+            InstructionV1::BurnResource { .. } => {
+                Some(ResourceMovementsNode::Burn)
+            }
+            InstructionV1::TakeAllFromWorktop { .. }
+            | InstructionV1::TakeFromWorktop { .. }
+            | InstructionV1::TakeNonFungiblesFromWorktop { .. }
+            | InstructionV1::ReturnToWorktop { .. }
+            | InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. }
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropNamedProofs
+            | InstructionV1::DropAllProofs
+            | InstructionV1::AllocateGlobalAddress { .. } => None,
+        }
+    }
+}
+
+impl ManifestSummaryCallback for ResourceMovementsGraphBuilder {}
+
+impl ExecutionSummaryCallback for ResourceMovementsGraphBuilder {
+    fn on_instruction(
+        &mut self,
+        instruction: &InstructionV1,
+        instruction_index: usize,
+        input_resources: &[ResourceSpecifier],
+        output_resources: &[ResourceSpecifier],
+    ) {
+        let Some(node) = Self::node_of_instruction(instruction) else {
+            return;
+        };
+
+        for resource in input_resources {
+            self.graph.add_edge(
+                ResourceMovementsNode::Worktop,
+                node.clone(),
+                resource.clone(),
+                instruction_index,
+            );
+        }
+        for resource in output_resources {
+            self.graph.add_edge(
+                node.clone(),
+                ResourceMovementsNode::Worktop,
+                resource.clone(),
+                instruction_index,
+            );
+        }
+    }
+}
@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::transaction_types::*;
+
+/// A declarative set of rules which allows callers that can't implement
+/// [`ManifestAnalyzer`] themselves, such as callers over the FFI, to register
+/// analyzers. Every instruction that matches a rule produces a finding with the
+/// message of the rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnalyzerRules {
+    pub name: String,
+    pub rules: Vec<AnalyzerRule>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnalyzerRule {
+    pub condition: AnalyzerRuleCondition,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnalyzerRuleCondition {
+    /// A method with the given name is called on the main module of the given
+    /// address or of any address if none is given.
+    CallsMethod {
+        address: Option<GlobalAddress>,
+        method_name: String,
+    },
+    /// A function of the blueprint is called in the given package or in any
+    /// package if none is given.
+    CallsFunction {
+        package_address: Option<PackageAddress>,
+        blueprint_name: String,
+        function_name: String,
+    },
+    /// The address is referenced anywhere in the instruction.
+    EncountersEntity { address: GlobalAddress },
+}
+
+/// The analyzer that evaluates a set of [`AnalyzerRules`].
+pub struct RuleAnalyzer {
+    rules: AnalyzerRules,
+    instruction_index: usize,
+    findings: Vec<AnalysisFinding>,
+}
+
+impl RuleAnalyzer {
+    pub fn new(rules: AnalyzerRules) -> Self {
+        Self {
+            rules,
+            instruction_index: 0,
+            findings: vec![],
+        }
+    }
+
+    fn add_findings(
+        &mut self,
+        mut matches: impl FnMut(&AnalyzerRuleCondition) -> bool,
+    ) {
+        let instruction_index = self.instruction_index;
+        self.findings.extend(
+            self.rules
+                .rules
+                .iter()
+                .filter(|rule| matches(&rule.condition))
+                .map(|rule| AnalysisFinding {
+                    instruction_index: Some(instruction_index),
+                    message: rule.message.clone(),
+                }),
+        );
+    }
+}
+
+impl ManifestSummaryCallback for RuleAnalyzer {
+    fn on_instruction(
+        &mut self,
+        instruction: &InstructionV1,
+        instruction_index: usize,
+    ) {
+        self.instruction_index = instruction_index;
+        match instruction {
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            } => self.add_findings(|condition| match condition {
+                AnalyzerRuleCondition::CallsMethod {
+                    address: rule_address,
+                    method_name: rule_method_name,
+                } => {
+                    rule_method_name == method_name
+                        && match rule_address {
+                            Some(rule_address) => matches!(
+                                address,
+                                DynamicGlobalAddress::Static(address)
+                                    if address == rule_address
+                            ),
+                            None => true,
+                        }
+                }
+                _ => false,
+            }),
+            InstructionV1::CallFunction {
+                package_address,
+                blueprint_name,
+                function_name,
+                ..
+            } => self.add_findings(|condition| match condition {
+                AnalyzerRuleCondition::CallsFunction {
+                    package_address: rule_package_address,
+                    blueprint_name: rule_blueprint_name,
+                    function_name: rule_function_name,
+                } => {
+                    rule_blueprint_name == blueprint_name
+                        && rule_function_name == function_name
+                        && match rule_package_address {
+                            Some(rule_package_address) => matches!(
+                                package_address,
+                                DynamicPackageAddress::Static(package_address)
+                                    if package_address == rule_package_address
+                            ),
+                            None => true,
+                        }
+                }
+                _ => false,
+            }),
+            _ => {}
+        }
+    }
+
+    fn on_global_entity_encounter(&mut self, address: GlobalAddress) {
+        self.add_findings(|condition| {
+            matches!(
+                condition,
+                AnalyzerRuleCondition::EncountersEntity {
+                    address: rule_address
+                } if *rule_address == address
+            )
+        })
+    }
+}
+
+impl ExecutionSummaryCallback for RuleAnalyzer {}
+
+impl ManifestAnalyzer for RuleAnalyzer {
+    fn name(&self) -> String {
+        self.rules.name.clone()
+    }
+
+    fn output(self: Box<Self>) -> Vec<AnalysisFinding> {
+        self.findings
+    }
+}
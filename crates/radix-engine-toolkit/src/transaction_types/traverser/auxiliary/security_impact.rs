@@ -0,0 +1,179 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::object_modules::royalty::*;
+use radix_engine_interface::prelude::{
+    MetadataLockInput, RoleAssignmentLockOwnerInput, RoleAssignmentSetInput,
+    RoleAssignmentSetOwnerInput, METADATA_LOCK_IDENT,
+    ROLE_ASSIGNMENT_LOCK_OWNER_IDENT, ROLE_ASSIGNMENT_SET_IDENT,
+    ROLE_ASSIGNMENT_SET_OWNER_IDENT,
+};
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::transaction_types::*;
+use crate::utils::*;
+
+/// An instruction that changes who controls an entity or how it can be used,
+/// such as changing its owner role, its role assignments, locking its metadata
+/// or configuring its royalties. Wallets are expected to highlight these to
+/// the user when signing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecurityImpact {
+    pub instruction_index: usize,
+    /// The entity whose configuration is changed by the instruction.
+    pub entity: DynamicGlobalAddress,
+    pub kind: SecurityImpactKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecurityImpactKind {
+    /// The owner role of the entity is set to a new rule.
+    OwnerRoleSet { rule: AccessRule },
+    /// The owner role of the entity is locked and can no longer be updated.
+    OwnerRoleLocked,
+    /// A role of one of the modules of the entity is set to a new rule.
+    RoleSet {
+        module: ObjectModuleId,
+        role_key: String,
+        rule: AccessRule,
+    },
+    /// A metadata key of the entity is locked and can no longer be updated.
+    MetadataLocked { key: String },
+    /// The royalty charged for calling a method of the entity is set.
+    RoyaltySet {
+        method: String,
+        amount: RoyaltyAmount,
+    },
+    /// The royalty of a method of the entity is locked and can no longer be
+    /// updated.
+    RoyaltyLocked { method: String },
+}
+
+/// Detects the instructions that change the owner role, role assignments,
+/// metadata locks, or royalty configuration of entities. The arguments of the
+/// instructions are decoded to report the new rules and the affected keys,
+/// instructions whose arguments can't be decoded are rejected by the engine
+/// and are therefore not reported.
+#[derive(Default)]
+pub struct SecurityImpactDetector {
+    security_impacts: Vec<SecurityImpact>,
+}
+
+impl SecurityImpactDetector {
+    pub fn output(self) -> Vec<SecurityImpact> {
+        self.security_impacts
+    }
+}
+
+impl ManifestSummaryCallback for SecurityImpactDetector {
+    fn on_instruction(
+        &mut self,
+        instruction: &InstructionV1,
+        instruction_index: usize,
+    ) {
+        let (entity, kind) = match instruction {
+            InstructionV1::CallRoleAssignmentMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let kind = match method_name.as_str() {
+                    ROLE_ASSIGNMENT_SET_OWNER_IDENT => {
+                        let Some(RoleAssignmentSetOwnerInput { rule }) =
+                            to_manifest_type(args)
+                        else {
+                            return;
+                        };
+                        SecurityImpactKind::OwnerRoleSet { rule }
+                    }
+                    ROLE_ASSIGNMENT_LOCK_OWNER_IDENT => {
+                        let Some(RoleAssignmentLockOwnerInput {}) =
+                            to_manifest_type(args)
+                        else {
+                            return;
+                        };
+                        SecurityImpactKind::OwnerRoleLocked
+                    }
+                    ROLE_ASSIGNMENT_SET_IDENT => {
+                        let Some(RoleAssignmentSetInput {
+                            module,
+                            role_key,
+                            rule,
+                        }) = to_manifest_type(args)
+                        else {
+                            return;
+                        };
+                        SecurityImpactKind::RoleSet {
+                            module,
+                            role_key: role_key.key,
+                            rule,
+                        }
+                    }
+                    _ => return,
+                };
+                (address, kind)
+            }
+            InstructionV1::CallMetadataMethod {
+                address,
+                method_name,
+                args,
+            } if method_name == METADATA_LOCK_IDENT => {
+                let Some(MetadataLockInput { key }) = to_manifest_type(args)
+                else {
+                    return;
+                };
+                (address, SecurityImpactKind::MetadataLocked { key })
+            }
+            InstructionV1::CallRoyaltyMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let kind = match method_name.as_str() {
+                    COMPONENT_ROYALTY_SET_ROYALTY_IDENT => {
+                        let Some(ComponentRoyaltySetInput { method, amount }) =
+                            to_manifest_type(args)
+                        else {
+                            return;
+                        };
+                        SecurityImpactKind::RoyaltySet { method, amount }
+                    }
+                    COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT => {
+                        let Some(ComponentRoyaltyLockInput { method }) =
+                            to_manifest_type(args)
+                        else {
+                            return;
+                        };
+                        SecurityImpactKind::RoyaltyLocked { method }
+                    }
+                    _ => return,
+                };
+                (address, kind)
+            }
+            _ => return,
+        };
+
+        self.security_impacts.push(SecurityImpact {
+            instruction_index,
+            entity: *entity,
+            kind,
+        });
+    }
+}
+
+impl ExecutionSummaryCallback for SecurityImpactDetector {}
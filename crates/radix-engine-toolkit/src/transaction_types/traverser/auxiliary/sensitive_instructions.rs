@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::access_controller::*;
+use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::identity::*;
+use radix_engine_interface::blueprints::locker::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use crate::transaction_types::*;
+use crate::utils::*;
+use crate::*;
+
+/// Detects the instructions that operate on security-sensitive native entities
+/// along with the index of the instruction.
+#[derive(Default)]
+pub struct SensitiveInstructionsDetector {
+    sensitive_instructions: Vec<SensitiveInstruction>,
+}
+
+impl SensitiveInstructionsDetector {
+    pub fn output(self) -> Vec<SensitiveInstruction> {
+        self.sensitive_instructions
+    }
+}
+
+impl ManifestSummaryCallback for SensitiveInstructionsDetector {
+    fn on_instruction(
+        &mut self,
+        instruction: &InstructionV1,
+        instruction_index: usize,
+    ) {
+        let kind = match instruction {
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            } => {
+                if is_account_locker(address)
+                    && !contains!(
+                        method_name => [
+                            ACCOUNT_LOCKER_GET_AMOUNT_IDENT,
+                            ACCOUNT_LOCKER_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT,
+                        ]
+                    )
+                {
+                    SensitiveInstructionKind::AccountLockerOperation
+                } else if is_access_controller(address)
+                    && method_name != ACCESS_CONTROLLER_CREATE_PROOF_IDENT
+                {
+                    SensitiveInstructionKind::AccessControllerRecovery
+                } else if is_identity(address)
+                    && method_name == IDENTITY_SECURIFY_IDENT
+                {
+                    SensitiveInstructionKind::IdentityOwnerBadgeUpdate
+                } else if is_validator(address)
+                    && contains!(
+                        method_name => [
+                            VALIDATOR_REGISTER_IDENT,
+                            VALIDATOR_UNREGISTER_IDENT,
+                            VALIDATOR_UPDATE_KEY_IDENT,
+                            VALIDATOR_UPDATE_FEE_IDENT,
+                            VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT,
+                            VALIDATOR_STAKE_AS_OWNER_IDENT,
+                            VALIDATOR_LOCK_OWNER_STAKE_UNITS_IDENT,
+                            VALIDATOR_START_UNLOCK_OWNER_STAKE_UNITS_IDENT,
+                            VALIDATOR_FINISH_UNLOCK_OWNER_STAKE_UNITS_IDENT,
+                        ]
+                    )
+                {
+                    SensitiveInstructionKind::ValidatorOwnerOperation
+                } else {
+                    return;
+                }
+            }
+            // The owner role of identities is what their owner badge protects
+            // and changing it changes who controls the identity.
+            InstructionV1::CallRoleAssignmentMethod { address, .. }
+                if is_identity(address) =>
+            {
+                SensitiveInstructionKind::IdentityOwnerBadgeUpdate
+            }
+            InstructionV1::CallRoleAssignmentMethod { address, .. }
+            | InstructionV1::CallMetadataMethod { address, .. }
+                if is_validator(address) =>
+            {
+                SensitiveInstructionKind::ValidatorOwnerOperation
+            }
+            _ => return,
+        };
+
+        self.sensitive_instructions.push(SensitiveInstruction {
+            instruction_index,
+            kind,
+        });
+    }
+}
+
+impl ExecutionSummaryCallback for SensitiveInstructionsDetector {}
@@ -52,6 +52,44 @@ pub trait ManifestSummaryCallback {
     fn on_finish(&mut self, _instructions_count: usize) {}
 }
 
+/// A [`ManifestSummaryCallback`] that contributes a named section of findings
+/// to a [`ManifestAnalysis`]. Analyzers are registered on a
+/// [`ManifestAnalyzerRegistry`] and run alongside the trusted worktop.
+pub trait ManifestAnalyzer: ManifestSummaryCallback {
+    /// The name of the section that the analyzer contributes.
+    fn name(&self) -> String;
+
+    /// Called once the traversal has finished to obtain the findings of the
+    /// analyzer.
+    fn output(self: Box<Self>) -> Vec<AnalysisFinding>;
+}
+
+impl ManifestSummaryCallback for Box<dyn ManifestAnalyzer> {
+    fn on_instruction(
+        &mut self,
+        instruction: &InstructionV1,
+        instruction_index: usize,
+    ) {
+        (**self).on_instruction(instruction, instruction_index)
+    }
+
+    fn on_create_proof(
+        &mut self,
+        account: &ComponentAddress,
+        resource: &ResourceSpecifier,
+    ) {
+        (**self).on_create_proof(account, resource)
+    }
+
+    fn on_global_entity_encounter(&mut self, address: GlobalAddress) {
+        (**self).on_global_entity_encounter(address)
+    }
+
+    fn on_finish(&mut self, instructions_count: usize) {
+        (**self).on_finish(instructions_count)
+    }
+}
+
 pub trait ExecutionSummaryCallback
 where
     Self: ManifestSummaryCallback,
@@ -0,0 +1,267 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+use radix_engine::system::system_modules::execution_trace::ResourceSpecifier;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::locker::{
+    ACCOUNT_LOCKER_CLAIM_IDENT, ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT,
+};
+
+use crate::transaction_types::*;
+use crate::utils::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrackedAccountLockerClaim {
+    pub locker_address: ComponentAddress,
+    pub claimant: ComponentAddress,
+    /// The resources claimed from the locker.
+    pub claimed_resources: ResourceSpecifier,
+}
+
+pub struct AccountLockerClaimDetector {
+    is_valid: bool,
+    required_method_called: bool,
+    /// The account lockers encountered in this manifest.
+    lockers: IndexSet<ComponentAddress>,
+    /// Tracks the claims in the transaction.
+    tracked_claims: Vec<TrackedAccountLockerClaim>,
+}
+
+impl AccountLockerClaimDetector {
+    pub fn output(
+        self,
+    ) -> Option<(IndexSet<ComponentAddress>, Vec<TrackedAccountLockerClaim>)>
+    {
+        if self.is_valid() {
+            Some((self.lockers, self.tracked_claims))
+        } else {
+            None
+        }
+    }
+}
+
+impl ManifestSummaryCallback for AccountLockerClaimDetector {
+    fn on_finish(&mut self, instructions_count: usize) {
+        if instructions_count == 0 {
+            self.is_valid = false
+        }
+    }
+
+    fn on_instruction(&mut self, instruction: &InstructionV1, _: usize) {
+        self.is_valid &= match instruction {
+            /* Maybe Permitted - Need more info */
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            } => Self::construct_fn_rules(address).is_fn_permitted(method_name),
+            /* Permitted */
+            InstructionV1::TakeFromWorktop { .. }
+            | InstructionV1::TakeNonFungiblesFromWorktop { .. }
+            | InstructionV1::TakeAllFromWorktop { .. }
+            | InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. } => true,
+            /* Not Permitted */
+            InstructionV1::BurnResource { .. }
+            | InstructionV1::CallRoyaltyMethod { .. }
+            | InstructionV1::CallMetadataMethod { .. }
+            | InstructionV1::CallRoleAssignmentMethod { .. }
+            | InstructionV1::CallDirectVaultMethod { .. }
+            | InstructionV1::AllocateGlobalAddress { .. }
+            | InstructionV1::ReturnToWorktop { .. }
+            | InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAmount { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromAuthZoneOfAll { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::CreateProofFromBucketOfAmount { .. }
+            | InstructionV1::CreateProofFromBucketOfNonFungibles { .. }
+            | InstructionV1::CreateProofFromBucketOfAll { .. }
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropNamedProofs
+            | InstructionV1::DropAllProofs
+            | InstructionV1::CallFunction { .. } => false,
+        };
+
+        // Handle required method call
+        match instruction {
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                ..
+            } if is_account_locker(address) && is_claim_method(method_name) => {
+                self.required_method_called = true
+            }
+            _ => {}
+        };
+    }
+
+    fn on_global_entity_encounter(&mut self, address: GlobalAddress) {
+        if address
+            .as_node_id()
+            .entity_type()
+            .is_some_and(|entity_type| {
+                matches!(entity_type, EntityType::GlobalAccountLocker)
+            })
+        {
+            self.lockers.insert(
+                ComponentAddress::try_from(address).expect("Must succeed!"),
+            );
+        }
+    }
+}
+
+impl ExecutionSummaryCallback for AccountLockerClaimDetector {
+    fn on_instruction(
+        &mut self,
+        instruction: &InstructionV1,
+        _: usize,
+        _: &[ResourceSpecifier],
+        output_resources: &[ResourceSpecifier],
+    ) {
+        match instruction {
+            InstructionV1::CallMethod {
+                address: dynamic_address @ DynamicGlobalAddress::Static(address),
+                method_name,
+                args,
+            } if is_account_locker(dynamic_address)
+                && is_claim_method(method_name) =>
+            {
+                let locker_address = ComponentAddress::try_from(*address)
+                    .expect("Must succeed!");
+
+                // Both claim methods take the claimant as their first argument
+                let Some((claimant, ..)) =
+                    manifest_encode(args).ok().and_then(|encoded| {
+                        manifest_decode::<(
+                            ComponentAddress,
+                            ResourceAddress,
+                            ManifestValue,
+                        )>(&encoded)
+                        .ok()
+                    })
+                else {
+                    return;
+                };
+
+                let Some(claimed_resources) = output_resources.first() else {
+                    return;
+                };
+
+                self.tracked_claims.push(TrackedAccountLockerClaim {
+                    locker_address,
+                    claimant,
+                    claimed_resources: claimed_resources.clone(),
+                });
+            }
+            _ => { /* No-op */ }
+        }
+    }
+}
+
+impl AccountLockerClaimDetector {
+    pub fn is_valid(&self) -> bool {
+        self.is_valid && self.required_method_called
+    }
+
+    fn construct_fn_rules(address: &DynamicGlobalAddress) -> FnRules {
+        match address {
+            DynamicGlobalAddress::Named(..) => FnRules::all_disallowed(),
+            DynamicGlobalAddress::Static(address) => {
+                address
+                    .as_node_id()
+                    .entity_type()
+                    .map(|entity_type| {
+                        match entity_type {
+                            EntityType::GlobalAccount
+                            | EntityType::GlobalVirtualSecp256k1Account
+                            | EntityType::GlobalVirtualEd25519Account => {
+                                FnRules {
+                                    allowed: &[
+                                        /* All deposit methods */
+                                        ACCOUNT_DEPOSIT_IDENT,
+                                        ACCOUNT_DEPOSIT_BATCH_IDENT,
+                                        ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+                                        ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+                                        /* Lock Fees */
+                                        ACCOUNT_LOCK_FEE_IDENT,
+                                        ACCOUNT_LOCK_CONTINGENT_FEE_IDENT,
+                                    ],
+                                    disallowed: &[],
+                                    default: FnRule::Disallowed,
+                                }
+                            }
+                            EntityType::GlobalAccountLocker => FnRules {
+                                allowed: &[
+                                    ACCOUNT_LOCKER_CLAIM_IDENT,
+                                    ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT,
+                                ],
+                                disallowed: &[],
+                                default: FnRule::Disallowed,
+                            },
+                            /* Disallowed */
+                            EntityType::GlobalGenericComponent
+                            | EntityType::GlobalIdentity
+                            | EntityType::GlobalVirtualSecp256k1Identity
+                            | EntityType::GlobalVirtualEd25519Identity
+                            | EntityType::InternalGenericComponent
+                            | EntityType::GlobalPackage
+                            | EntityType::GlobalValidator
+                            | EntityType::GlobalFungibleResourceManager
+                            | EntityType::GlobalNonFungibleResourceManager
+                            | EntityType::GlobalConsensusManager
+                            | EntityType::InternalFungibleVault
+                            | EntityType::InternalNonFungibleVault
+                            | EntityType::InternalKeyValueStore
+                            | EntityType::GlobalTransactionTracker
+                            | EntityType::GlobalAccessController
+                            | EntityType::GlobalOneResourcePool
+                            | EntityType::GlobalTwoResourcePool
+                            | EntityType::GlobalMultiResourcePool => {
+                                FnRules::all_disallowed()
+                            }
+                        }
+                    })
+                    .unwrap_or(FnRules::all_disallowed())
+            }
+        }
+    }
+}
+
+impl Default for AccountLockerClaimDetector {
+    fn default() -> Self {
+        Self {
+            is_valid: true,
+            required_method_called: false,
+            lockers: Default::default(),
+            tracked_claims: Default::default(),
+        }
+    }
+}
+
+fn is_claim_method(method_name: &str) -> bool {
+    method_name == ACCOUNT_LOCKER_CLAIM_IDENT
+        || method_name == ACCOUNT_LOCKER_CLAIM_NON_FUNGIBLES_IDENT
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod account_locker_claim;
 mod account_resource_movements;
 mod account_settings;
 mod general;
@@ -25,6 +26,7 @@ mod validator_claim;
 mod validator_stake;
 mod validator_unstake;
 
+pub use account_locker_claim::*;
 pub use account_resource_movements::*;
 pub use account_settings::*;
 pub use general::*;
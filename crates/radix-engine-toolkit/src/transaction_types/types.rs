@@ -84,6 +84,8 @@ pub struct ExecutionSummary {
     pub reserved_instructions: IndexSet<ReservedInstruction>,
     /// Information on how much fees were contingent and how much were not.
     pub fee_locks: FeeLocks,
+    /// The amount of fees locked by each account in the manifest.
+    pub fee_locks_by_account: IndexMap<ComponentAddress, FeeLocks>,
     /// Detailed information on the amount of cost units consumed.
     pub fee_summary: FeeSummary,
     /// The various classifications that this manifest matched against. Note
@@ -91,6 +93,86 @@ pub struct ExecutionSummary {
     pub detailed_classification: Vec<DetailedManifestClass>,
     /// List of newly created Non-Fungibles during this transaction.
     pub newly_created_non_fungibles: HashSet<NonFungibleGlobalId>,
+    /// Whether the resources deposited into accounts would be accepted by the
+    /// deposit rules of the accounts. This only includes the accounts whose
+    /// deposit settings were provided when summarizing the transaction.
+    pub deposit_compatibility: IndexMap<
+        ComponentAddress,
+        IndexMap<ResourceAddress, DepositCompatibility>,
+    >,
+    /// The net change in the balances of each of the accounts withdrawn from
+    /// or deposited into, computed from the withdraws and deposits.
+    pub balance_changes: IndexMap<ComponentAddress, AccountBalanceChanges>,
+}
+
+/// The deposit settings of an account as known to the caller, used to check
+/// whether the deposits into the account would be accepted or rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountDepositSettings {
+    pub default_deposit_rule: DefaultDepositRule,
+    pub resource_preferences: IndexMap<ResourceAddress, ResourcePreference>,
+    /// The resources that the account holds vaults of, which are accepted when
+    /// the default deposit rule is [`DefaultDepositRule::AllowExisting`].
+    pub existing_resources: IndexSet<ResourceAddress>,
+}
+
+impl AccountDepositSettings {
+    /// Determines whether a deposit of the resource into the account would be
+    /// accepted. This follows the logic of the account blueprint where the
+    /// resource preferences take precedence over the default deposit rule and
+    /// XRD is always treated as an existing resource. Deposits made with the
+    /// badge of an authorized depositor bypass these rules and are not
+    /// considered here.
+    pub fn deposit_compatibility(
+        &self,
+        resource_address: &ResourceAddress,
+    ) -> DepositCompatibility {
+        match self.resource_preferences.get(resource_address) {
+            Some(ResourcePreference::Allowed) => {
+                DepositCompatibility::AllowedByResourcePreference
+            }
+            Some(ResourcePreference::Disallowed) => {
+                DepositCompatibility::DisallowedByResourcePreference
+            }
+            None => match self.default_deposit_rule {
+                DefaultDepositRule::Accept => {
+                    DepositCompatibility::AcceptedByDefaultDepositRule
+                }
+                DefaultDepositRule::Reject => {
+                    DepositCompatibility::RejectedByDefaultDepositRule
+                }
+                DefaultDepositRule::AllowExisting
+                    if *resource_address == XRD
+                        || self
+                            .existing_resources
+                            .contains(resource_address) =>
+                {
+                    DepositCompatibility::AcceptedByDefaultDepositRule
+                }
+                DefaultDepositRule::AllowExisting => {
+                    DepositCompatibility::RejectedByDefaultDepositRule
+                }
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DepositCompatibility {
+    AllowedByResourcePreference,
+    DisallowedByResourcePreference,
+    AcceptedByDefaultDepositRule,
+    RejectedByDefaultDepositRule,
+}
+
+impl DepositCompatibility {
+    pub fn is_blocked(&self) -> bool {
+        matches!(
+            self,
+            Self::DisallowedByResourcePreference
+                | Self::RejectedByDefaultDepositRule
+        )
+    }
 }
 
 /// The classification process classifies manifests into classes. The following
@@ -119,6 +201,9 @@ pub enum ManifestClass {
     ValidatorClaim,
     /// A manifest that updated the deposit settings of the account.
     AccountDepositSettingsUpdate,
+    /// A manifest where resources are claimed from one or more account
+    /// lockers.
+    AccountLockerClaim,
 }
 
 /// The execution summary process not only determines the class of the manifest,
@@ -206,6 +291,14 @@ pub enum DetailedManifestClass {
             IndexMap<ResourceOrNonFungible, Operation>,
         >,
     },
+    /// A manifest where resources are claimed from one or more account
+    /// lockers.
+    AccountLockerClaim {
+        /// The set of account lockers in the transaction
+        locker_addresses: IndexSet<ComponentAddress>,
+        /// The claims observed in the transaction
+        locker_claims: Vec<TrackedAccountLockerClaim>,
+    },
 }
 
 impl From<DetailedManifestClass> for ManifestClass {
@@ -231,10 +324,116 @@ impl From<DetailedManifestClass> for ManifestClass {
             DetailedManifestClass::AccountDepositSettingsUpdate { .. } => {
                 ManifestClass::AccountDepositSettingsUpdate
             }
+            DetailedManifestClass::AccountLockerClaim { .. } => {
+                ManifestClass::AccountLockerClaim
+            }
+        }
+    }
+}
+
+/// The classification of a manifest along with the details of its class that
+/// can be determined statically from the manifest without executing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifestClassification {
+    /// A general manifest that involves any amount of arbitrary components
+    /// and packages where nothing more concrete can be said about the manifest
+    /// and its nature.
+    General,
+    /// A manifest of a transfer of a single resource from one account to
+    /// another account.
+    SimpleTransfer(SimpleTransfer),
+    /// A manifest of a transfer of resources from one account to one or more
+    /// accounts that is not a simple transfer.
+    GeneralTransfer,
+    /// A manifest that contributes resources to one or more pools.
+    PoolContribution {
+        pool_addresses: IndexSet<ComponentAddress>,
+    },
+    /// A manifest that redeems resources from one or more pools.
+    PoolRedemption {
+        pool_addresses: IndexSet<ComponentAddress>,
+    },
+    /// A manifest where XRD is staked to one or more validators.
+    ValidatorStake {
+        validator_addresses: IndexSet<ComponentAddress>,
+    },
+    /// A manifest where XRD is unstaked from one or more validators.
+    ValidatorUnstake {
+        validator_addresses: IndexSet<ComponentAddress>,
+    },
+    /// A manifest where XRD is claimed from one or more validators.
+    ValidatorClaim {
+        validator_addresses: IndexSet<ComponentAddress>,
+    },
+    /// A manifest that updates the deposit settings of accounts.
+    AccountDepositSettingsUpdate {
+        resource_preferences_updates: IndexMap<
+            ComponentAddress,
+            IndexMap<ResourceAddress, Update<ResourcePreference>>,
+        >,
+        deposit_mode_updates: IndexMap<ComponentAddress, DefaultDepositRule>,
+        authorized_depositors_updates: IndexMap<
+            ComponentAddress,
+            IndexMap<ResourceOrNonFungible, Operation>,
+        >,
+    },
+    /// A manifest where resources are claimed from one or more account
+    /// lockers.
+    AccountLockerClaim {
+        locker_addresses: IndexSet<ComponentAddress>,
+    },
+}
+
+impl From<ManifestClassification> for ManifestClass {
+    fn from(value: ManifestClassification) -> Self {
+        match value {
+            ManifestClassification::General => ManifestClass::General,
+            ManifestClassification::SimpleTransfer(..)
+            | ManifestClassification::GeneralTransfer => {
+                ManifestClass::Transfer
+            }
+            ManifestClassification::PoolContribution { .. } => {
+                ManifestClass::PoolContribution
+            }
+            ManifestClassification::PoolRedemption { .. } => {
+                ManifestClass::PoolRedemption
+            }
+            ManifestClassification::ValidatorStake { .. } => {
+                ManifestClass::ValidatorStake
+            }
+            ManifestClassification::ValidatorUnstake { .. } => {
+                ManifestClass::ValidatorUnstake
+            }
+            ManifestClassification::ValidatorClaim { .. } => {
+                ManifestClass::ValidatorClaim
+            }
+            ManifestClassification::AccountDepositSettingsUpdate { .. } => {
+                ManifestClass::AccountDepositSettingsUpdate
+            }
+            ManifestClassification::AccountLockerClaim { .. } => {
+                ManifestClass::AccountLockerClaim
+            }
         }
     }
 }
 
+/// The receipts that the execution of a manifest can be summarized from. Both
+/// are normalized into a native receipt before the summary is computed.
+#[derive(Clone, Copy, Debug)]
+pub enum ExecutionReceipt<'r> {
+    /// A receipt obtained from the engine.
+    Native(&'r TransactionReceipt),
+    /// The SBOR encoded receipt found in the `encoded_receipt` field of the
+    /// response of the preview endpoint of the Core API.
+    EncodedPreview(&'r [u8]),
+}
+
+impl<'r> From<&'r TransactionReceipt> for ExecutionReceipt<'r> {
+    fn from(value: &'r TransactionReceipt) -> Self {
+        Self::Native(value)
+    }
+}
+
 /// A receipt used for the calculation of the execution summary. This receipt
 /// must belong to a transaction that executed successfully and the execution
 /// trace must be present.
@@ -507,6 +706,63 @@ pub enum ReservedInstruction {
     AccessControllerMethod,
 }
 
+/// An instruction that operates on a security-sensitive native entity. Unlike
+/// [`ReservedInstruction`]s these are allowed in manifests that did not come
+/// from the wallet, but wallets are expected to make the user aware of them
+/// when signing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SensitiveInstruction {
+    pub instruction_index: usize,
+    pub kind: SensitiveInstructionKind,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SensitiveInstructionKind {
+    /// A method that stores, recovers, or claims resources on an account
+    /// locker.
+    AccountLockerOperation,
+    /// A method on an access controller other than creating a proof, such as
+    /// initiating or confirming a recovery or locking the primary role.
+    AccessControllerRecovery,
+    /// Securifying an identity or changing the roles of an identity.
+    IdentityOwnerBadgeUpdate,
+    /// A method on a validator that requires the validator's owner badge.
+    ValidatorOwnerOperation,
+}
+
+/// The accounts that a manifest interacts with as determined through a static
+/// analysis of the manifest. This is a subset of the [`ManifestSummary`] which
+/// is cheaper to compute as it doesn't require classifying the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountInteractions {
+    /// The set of accounts withdrawn from observed in the manifest.
+    pub accounts_withdrawn_from: IndexSet<ComponentAddress>,
+    /// The set of accounts deposited into observed in the manifest.
+    pub accounts_deposited_into: IndexSet<ComponentAddress>,
+    /// The set of accounts encountered in the manifest where privileged
+    /// methods were called and that therefore need to sign the transaction.
+    pub accounts_requiring_auth: IndexSet<ComponentAddress>,
+    /// The list of the resources of proofs that were presented in the manifest.
+    pub presented_proofs: IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+}
+
+/// The trusted worktop of a manifest along with the sections contributed by
+/// the analyzers of a [`ManifestAnalyzerRegistry`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestAnalysis {
+    pub trusted_worktop: Vec<TrustedWorktopInstruction>,
+    /// The findings of each of the analyzers keyed by the analyzer name in the
+    /// order that the analyzers were registered in.
+    pub sections: IndexMap<String, Vec<AnalysisFinding>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnalysisFinding {
+    /// The index of the instruction that the finding is about, if any.
+    pub instruction_index: Option<usize>,
+    pub message: String,
+}
+
 pub enum FnRule {
     Allowed,
     Disallowed,
@@ -693,10 +949,157 @@ pub enum Operation {
     Removed,
 }
 
+/// The net change in the balances of an account. Movements that cancel each
+/// other out, such as withdrawing and depositing back the same amount, are
+/// not included.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AccountBalanceChanges {
+    /// The net change in the balance of each of the fungible resources.
+    pub fungible: IndexMap<ResourceAddress, Decimal>,
+    /// The ids added to and removed from each of the non-fungible resources.
+    pub non_fungible: IndexMap<ResourceAddress, NonFungibleBalanceChange>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct NonFungibleBalanceChange {
+    pub added: IndexSet<NonFungibleLocalId>,
+    pub removed: IndexSet<NonFungibleLocalId>,
+}
+
+impl AccountBalanceChanges {
+    pub fn is_empty(&self) -> bool {
+        self.fungible.is_empty() && self.non_fungible.is_empty()
+    }
+
+    fn add_movement(
+        &mut self,
+        indicator: &ResourceIndicator,
+        is_deposit: bool,
+    ) {
+        match indicator {
+            ResourceIndicator::Fungible(resource_address, amount) => {
+                let balance =
+                    self.fungible.entry(*resource_address).or_default();
+                *balance = if is_deposit {
+                    balance.checked_add(**amount).unwrap_or(Decimal::MAX)
+                } else {
+                    balance.checked_sub(**amount).unwrap_or(Decimal::MIN)
+                };
+            }
+            ResourceIndicator::NonFungible(resource_address, indicator) => {
+                let ids = match indicator {
+                    NonFungibleResourceIndicator::ByAll {
+                        predicted_ids,
+                        ..
+                    }
+                    | NonFungibleResourceIndicator::ByAmount {
+                        predicted_ids,
+                        ..
+                    } => &predicted_ids.value,
+                    NonFungibleResourceIndicator::ByIds(ids) => ids,
+                };
+                let change =
+                    self.non_fungible.entry(*resource_address).or_default();
+                let (into, out_of) = if is_deposit {
+                    (&mut change.added, &mut change.removed)
+                } else {
+                    (&mut change.removed, &mut change.added)
+                };
+                for id in ids {
+                    if !out_of.swap_remove(id) {
+                        into.insert(id.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Aggregates the withdraws from and deposits into accounts into the net
+    /// change in the balances of each of the accounts.
+    pub fn from_movements(
+        account_withdraws: &IndexMap<ComponentAddress, Vec<ResourceIndicator>>,
+        account_deposits: &IndexMap<ComponentAddress, Vec<ResourceIndicator>>,
+    ) -> IndexMap<ComponentAddress, Self> {
+        let mut balance_changes = IndexMap::<ComponentAddress, Self>::new();
+        for (movements, is_deposit) in
+            [(account_withdraws, false), (account_deposits, true)]
+        {
+            for (account, indicators) in movements {
+                let changes = balance_changes.entry(*account).or_default();
+                for indicator in indicators {
+                    changes.add_movement(indicator, is_deposit);
+                }
+            }
+        }
+
+        for changes in balance_changes.values_mut() {
+            changes.fungible.retain(|_, amount| !amount.is_zero());
+            changes.non_fungible.retain(|_, change| {
+                !change.added.is_empty() || !change.removed.is_empty()
+            });
+        }
+        balance_changes.retain(|_, changes| !changes.is_empty());
+        balance_changes
+    }
+}
+
+/// A graph of the movements of resources in a transaction. The nodes are what
+/// the resources moved between and the edges are the movements. Buckets are
+/// considered to be a part of the worktop.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ResourceMovementsGraph {
+    pub nodes: IndexSet<ResourceMovementsNode>,
+    pub edges: Vec<ResourceMovementsEdge>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceMovementsNode {
+    Worktop,
+    Account(ComponentAddress),
+    /// A component, package, or resource manager that was invoked.
+    Entity(GlobalAddress),
+    /// An entity whose address was allocated in the manifest.
+    NamedEntity(ManifestNamedAddress),
+    Vault(InternalAddress),
+    Mint,
+    Burn,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceMovementsEdge {
+    /// The index of the node that the resources moved out of.
+    pub from: usize,
+    /// The index of the node that the resources moved into.
+    pub to: usize,
+    pub resource: ResourceSpecifier,
+    /// The index of the instruction that the movement happened in.
+    pub instruction_index: usize,
+}
+
+impl ResourceMovementsGraph {
+    pub fn add_edge(
+        &mut self,
+        from: ResourceMovementsNode,
+        to: ResourceMovementsNode,
+        resource: ResourceSpecifier,
+        instruction_index: usize,
+    ) {
+        let (from, _) = self.nodes.insert_full(from);
+        let (to, _) = self.nodes.insert_full(to);
+        self.edges.push(ResourceMovementsEdge {
+            from,
+            to,
+            resource,
+            instruction_index,
+        });
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct FeeSummary {
     pub execution_cost: Decimal,
     pub finalization_cost: Decimal,
     pub storage_expansion_cost: Decimal,
     pub royalty_cost: Decimal,
+    pub tipping_cost: Decimal,
 }
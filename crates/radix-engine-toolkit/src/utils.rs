@@ -172,6 +172,27 @@ pub fn to_manifest_type<D: ManifestDecode>(value: &ManifestValue) -> Option<D> {
         .and_then(|encoded| manifest_decode(&encoded).ok())
 }
 
+/// The depth of a value where a value without any children has a depth of 1.
+pub fn manifest_value_depth(value: &ManifestValue) -> usize {
+    let children_depth = match value {
+        ManifestValue::Enum { fields, .. }
+        | ManifestValue::Tuple { fields } => {
+            fields.iter().map(manifest_value_depth).max()
+        }
+        ManifestValue::Array { elements, .. } => {
+            elements.iter().map(manifest_value_depth).max()
+        }
+        ManifestValue::Map { entries, .. } => entries
+            .iter()
+            .map(|(key, value)| {
+                manifest_value_depth(key).max(manifest_value_depth(value))
+            })
+            .max(),
+        _ => None,
+    };
+    1 + children_depth.unwrap_or_default()
+}
+
 // TODO: This should return a `bool`.
 #[allow(clippy::result_unit_err)]
 pub fn validate_manifest_value_against_schema<S: ScryptoDescribe>(
@@ -234,6 +255,20 @@ pub fn is_access_controller<A: Into<DynamicGlobalAddress> + Clone>(
     }
 }
 
+pub fn is_account_locker<A: Into<DynamicGlobalAddress> + Clone>(
+    node_id: &A,
+) -> bool {
+    match node_id.clone().into() {
+        DynamicGlobalAddress::Named(_) => false,
+        DynamicGlobalAddress::Static(address) => {
+            matches!(
+                address.as_node_id().entity_type(),
+                Some(EntityType::GlobalAccountLocker)
+            )
+        }
+    }
+}
+
 pub fn is_identity<A: Into<DynamicGlobalAddress> + Clone>(node_id: &A) -> bool {
     match node_id.clone().into() {
         DynamicGlobalAddress::Named(_) => false,
@@ -250,6 +285,29 @@ pub fn is_identity<A: Into<DynamicGlobalAddress> + Clone>(node_id: &A) -> bool {
     }
 }
 
+/// Maps the items in parallel when the `parallel` feature is enabled and
+/// sequentially otherwise. The outputs are always in the order of the items.
+#[cfg(feature = "parallel")]
+pub fn map_in_order<T, O, F>(items: Vec<T>, map: F) -> Vec<O>
+where
+    T: Send,
+    O: Send,
+    F: Fn(T) -> O + Send + Sync,
+{
+    use rayon::prelude::*;
+    items.into_par_iter().map(map).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn map_in_order<T, O, F>(items: Vec<T>, map: F) -> Vec<O>
+where
+    T: Send,
+    O: Send,
+    F: Fn(T) -> O + Send + Sync,
+{
+    items.into_iter().map(map).collect()
+}
+
 #[macro_export]
 macro_rules! contains {
     (
@@ -0,0 +1,168 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::access_controller::*;
+use radix_engine_toolkit::functions::access_controller::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn recovery_is_initiated_with_the_method_of_the_proposer() {
+    // Arrange
+    let access_controller = access_controller();
+    let rule_set = rule_set();
+    let expected = ManifestBuilder::new()
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+            (rule_set.clone(), Some(10u32)),
+        )
+        .build();
+
+    // Act
+    let manifest = build_access_controller_manifest(
+        access_controller,
+        &[AccessControllerOperation::InitiateRecovery {
+            proposer: AccessControllerProposer::Recovery,
+            rule_set,
+            timed_recovery_delay_in_minutes: Some(10),
+        }],
+    );
+
+    // Assert
+    assert_eq!(manifest.unwrap().instructions, expected.instructions);
+}
+
+#[test]
+fn withdrawn_badge_is_deposited_into_the_account() {
+    // Arrange
+    let access_controller = access_controller();
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    let expected = ManifestBuilder::new()
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_BADGE_WITHDRAW_ATTEMPT_IDENT,
+            (),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let manifest = build_access_controller_manifest(
+        access_controller,
+        &[
+            AccessControllerOperation::QuickConfirmBadgeWithdrawAttempt {
+                proposer: AccessControllerProposer::Primary,
+                account_address: account,
+            },
+        ],
+    );
+
+    // Assert
+    assert_eq!(manifest.unwrap().instructions, expected.instructions);
+}
+
+#[test]
+fn rule_set_with_a_role_that_allows_all_is_rejected() {
+    // Arrange
+    let rule_set = RuleSet {
+        confirmation_role: AccessRule::AllowAll,
+        ..rule_set()
+    };
+
+    // Act
+    let result = validate_rule_set(&rule_set);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(AccessControllerOperationError::RoleAllowsAll(
+            AccessControllerRole::Confirmation
+        ))
+    );
+}
+
+#[test]
+fn rule_set_that_cannot_be_recovered_is_rejected() {
+    // Arrange
+    let rule_set = RuleSet {
+        recovery_role: AccessRule::DenyAll,
+        confirmation_role: AccessRule::DenyAll,
+        ..rule_set()
+    };
+
+    // Act
+    let manifest = build_access_controller_manifest(
+        access_controller(),
+        &[AccessControllerOperation::InitiateRecovery {
+            proposer: AccessControllerProposer::Primary,
+            rule_set,
+            timed_recovery_delay_in_minutes: None,
+        }],
+    );
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Err(AccessControllerOperationError::UnrecoverableRuleSet)
+    );
+}
+
+#[test]
+fn operations_on_other_components_are_rejected() {
+    // Arrange
+    let address = ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    );
+
+    // Act
+    let manifest = build_access_controller_manifest(
+        address,
+        &[AccessControllerOperation::CancelRecovery {
+            proposer: AccessControllerProposer::Primary,
+        }],
+    );
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Err(AccessControllerOperationError::NotAnAccessController(
+            address
+        ))
+    );
+}
+
+fn access_controller() -> ComponentAddress {
+    ComponentAddress::new_or_panic(
+        [EntityType::GlobalAccessController as u8; NodeId::LENGTH],
+    )
+}
+
+fn rule_set() -> RuleSet {
+    let badge = |id: u8| {
+        let mut node_id = [id; NodeId::LENGTH];
+        node_id[0] = EntityType::GlobalFungibleResourceManager as u8;
+        ResourceAddress::new_or_panic(node_id)
+    };
+    RuleSet {
+        primary_role: rule!(require(badge(1))),
+        recovery_role: rule!(require(badge(2))),
+        confirmation_role: rule!(require(badge(3))),
+    }
+}
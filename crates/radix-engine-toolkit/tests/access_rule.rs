@@ -0,0 +1,85 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::access_rule;
+use radix_engine_toolkit::functions::access_rule::{all_of, any_of};
+use scrypto::prelude::*;
+
+#[test]
+fn any_of_combines_protected_rules_into_an_any_of_node() {
+    // Arrange
+    let rule1 = access_rule::require(XRD);
+    let rule2 = access_rule::require_amount(dec!("10"), ACCOUNT_OWNER_BADGE);
+
+    // Act
+    let rule = any_of([rule1, AccessRule::DenyAll, rule2]);
+
+    // Assert
+    assert_eq!(
+        rule,
+        rule!(require(XRD) || require_amount(dec!("10"), ACCOUNT_OWNER_BADGE))
+    );
+}
+
+#[test]
+fn any_of_with_a_rule_that_allows_all_allows_all() {
+    // Arrange
+    let rules = [access_rule::require(XRD), AccessRule::AllowAll];
+
+    // Act
+    let rule = any_of(rules);
+
+    // Assert
+    assert_eq!(rule, AccessRule::AllowAll);
+}
+
+#[test]
+fn all_of_with_a_rule_that_denies_all_denies_all() {
+    // Arrange
+    let rules = [access_rule::require(XRD), AccessRule::DenyAll];
+
+    // Act
+    let rule = all_of(rules);
+
+    // Assert
+    assert_eq!(rule, AccessRule::DenyAll);
+}
+
+#[test]
+fn combining_a_single_protected_rule_returns_the_rule() {
+    // Arrange
+    let rule = access_rule::require(XRD);
+
+    // Act
+    let any_of_rule = any_of([rule.clone(), AccessRule::DenyAll]);
+    let all_of_rule = all_of([rule.clone(), AccessRule::AllowAll]);
+
+    // Assert
+    assert_eq!(any_of_rule, rule);
+    assert_eq!(all_of_rule, rule);
+}
+
+#[test]
+fn combining_no_rules_gives_the_identity_of_the_combination() {
+    // Act
+    let any_of_rule = any_of(Vec::<AccessRule>::new());
+    let all_of_rule = all_of(Vec::<AccessRule>::new());
+
+    // Assert
+    assert_eq!(any_of_rule, AccessRule::DenyAll);
+    assert_eq!(all_of_rule, AccessRule::AllowAll);
+}
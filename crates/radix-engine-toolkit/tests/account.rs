@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_toolkit::functions::account::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn withdraw_matches_the_manifest_builder() {
+    // Arrange
+    let account = account(1);
+    let expected = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .build();
+
+    // Act
+    let instruction = call_account_method(
+        account,
+        &AccountMethod::Withdraw {
+            resource_address: XRD,
+            amount: dec!(10),
+        },
+    );
+
+    // Assert
+    assert_eq!(instruction, expected.instructions[0]);
+}
+
+#[test]
+fn lock_fee_and_withdraw_non_fungibles_matches_the_manifest_builder() {
+    // Arrange
+    let account = account(1);
+    let ids = [NonFungibleLocalId::integer(1)]
+        .into_iter()
+        .collect::<IndexSet<_>>();
+    let expected = ManifestBuilder::new()
+        .lock_fee_and_withdraw_non_fungibles(
+            account,
+            10,
+            ACCOUNT_OWNER_BADGE,
+            ids.clone(),
+        )
+        .build();
+
+    // Act
+    let instruction = call_account_method(
+        account,
+        &AccountMethod::LockFeeAndWithdrawNonFungibles {
+            amount_to_lock: dec!(10),
+            resource_address: ACCOUNT_OWNER_BADGE,
+            ids,
+        },
+    );
+
+    // Assert
+    assert_eq!(instruction, expected.instructions[0]);
+}
+
+#[test]
+fn try_deposit_or_abort_matches_the_manifest_builder() {
+    // Arrange
+    let account = account(1);
+    let expected = ManifestBuilder::new()
+        .take_from_worktop(XRD, 10, "xrd")
+        .try_deposit_or_abort(account, None, "xrd")
+        .build();
+
+    // Act
+    let instruction = call_account_method(
+        account,
+        &AccountMethod::TryDepositOrAbort {
+            bucket: ManifestBucket(0),
+            authorized_depositor_badge: None,
+        },
+    );
+
+    // Assert
+    assert_eq!(instruction, expected.instructions[1]);
+}
+
+#[test]
+fn set_default_deposit_rule_encodes_the_rule_as_an_enum() {
+    // Act
+    let instruction = call_account_method(
+        account(1),
+        &AccountMethod::SetDefaultDepositRule {
+            default_deposit_rule: DefaultDepositRule::Reject,
+        },
+    );
+
+    // Assert
+    let InstructionV1::CallMethod {
+        method_name, args, ..
+    } = instruction
+    else {
+        panic!("Not a method call");
+    };
+    assert_eq!(method_name, ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT);
+    assert_eq!(
+        args,
+        ManifestValue::Tuple {
+            fields: vec![ManifestValue::Enum {
+                discriminator: 1,
+                fields: vec![]
+            }]
+        }
+    );
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
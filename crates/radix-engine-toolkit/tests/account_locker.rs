@@ -0,0 +1,127 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::locker::*;
+use radix_engine_toolkit::functions::account_locker::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn fungible_airdrop_takes_the_total_amount_into_one_bucket() {
+    // Arrange
+    let claimants = [(account(2), dec!(10)), (account(3), dec!(5))]
+        .into_iter()
+        .collect::<IndexMap<_, _>>();
+    let account = account(1);
+    let locker = locker();
+    let expected = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 15)
+        .take_from_worktop(XRD, 15, "xrd")
+        .call_method_with_name_lookup(
+            locker,
+            ACCOUNT_LOCKER_AIRDROP_IDENT,
+            |lookup| {
+                (
+                    claimants
+                        .iter()
+                        .map(|(claimant, amount)| {
+                            (*claimant, ResourceSpecifier::Fungible(*amount))
+                        })
+                        .collect::<IndexMap<_, _>>(),
+                    lookup.bucket("xrd"),
+                    true,
+                )
+            },
+        )
+        .build();
+
+    // Act
+    let manifest = build_account_locker_manifest(
+        locker,
+        account,
+        &[AccountLockerOperation::AirdropFungible {
+            resource_address: XRD,
+            claimants: claimants.clone(),
+            try_direct_send: true,
+        }],
+    );
+
+    // Assert
+    assert_eq!(manifest.unwrap().instructions, expected.instructions);
+}
+
+#[test]
+fn claimed_resources_are_deposited_into_the_account() {
+    // Arrange
+    let account = account(1);
+    let locker = locker();
+    let expected = ManifestBuilder::new()
+        .call_method(
+            locker,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            (account, XRD, dec!(3)),
+        )
+        .deposit_entire_worktop(account)
+        .build();
+
+    // Act
+    let manifest = build_account_locker_manifest(
+        locker,
+        account,
+        &[AccountLockerOperation::Claim {
+            resource_address: XRD,
+            amount: dec!(3),
+        }],
+    );
+
+    // Assert
+    assert_eq!(manifest.unwrap().instructions, expected.instructions);
+}
+
+#[test]
+fn operations_on_components_that_are_not_lockers_are_rejected() {
+    // Arrange
+    let account = account(1);
+
+    // Act
+    let manifest = build_account_locker_manifest(
+        account,
+        account,
+        &[AccountLockerOperation::Claim {
+            resource_address: XRD,
+            amount: dec!(1),
+        }],
+    );
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Err(AccountLockerOperationError::NotAnAccountLocker(account))
+    );
+}
+
+fn locker() -> ComponentAddress {
+    ComponentAddress::new_or_panic(
+        [EntityType::GlobalAccountLocker as u8; NodeId::LENGTH],
+    )
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
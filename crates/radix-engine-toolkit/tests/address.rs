@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::address::{
+    self, AddressValidationFailure,
+};
+use scrypto::prelude::*;
+
+#[test]
+fn virtual_account_address_can_be_inspected() {
+    // Arrange
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    let encoded_address =
+        AddressBech32Encoder::new(&NetworkDefinition::mainnet())
+            .encode(account.as_node_id().as_bytes())
+            .unwrap();
+
+    // Act
+    let inspection = address::inspect(&encoded_address).unwrap();
+
+    // Assert
+    assert_eq!(inspection.network_id, 0x01);
+    assert_eq!(inspection.network_name, "mainnet");
+    assert_eq!(
+        inspection.entity_type,
+        EntityType::GlobalVirtualSecp256k1Account
+    );
+    assert_eq!(inspection.hrp, "account_rdx");
+    assert_eq!(inspection.data, account.as_node_id().0);
+    assert!(inspection.is_global);
+    assert!(!inspection.is_internal);
+    assert!(inspection.is_virtual);
+}
+
+#[test]
+fn internal_vault_address_is_not_global() {
+    // Arrange
+    let vault = InternalAddress::new_or_panic(
+        [EntityType::InternalFungibleVault as u8; NodeId::LENGTH],
+    );
+    let encoded_address =
+        AddressBech32Encoder::new(&NetworkDefinition::simulator())
+            .encode(vault.as_node_id().as_bytes())
+            .unwrap();
+
+    // Act
+    let inspection = address::inspect(&encoded_address).unwrap();
+
+    // Assert
+    assert_eq!(inspection.entity_type, EntityType::InternalFungibleVault);
+    assert!(!inspection.is_global);
+    assert!(inspection.is_internal);
+    assert!(!inspection.is_virtual);
+}
+
+#[test]
+fn invalid_address_can_not_be_inspected() {
+    // Act
+    let inspection = address::inspect("account_rdx1invalid");
+
+    // Assert
+    assert!(inspection.is_none());
+}
+
+#[test]
+fn address_of_expected_entity_type_and_network_is_valid() {
+    // Arrange
+    let encoded_address =
+        AddressBech32Encoder::new(&NetworkDefinition::mainnet())
+            .encode(XRD.as_node_id().as_bytes())
+            .unwrap();
+
+    // Act
+    let failures = address::validate(
+        &encoded_address,
+        &[EntityType::GlobalFungibleResourceManager],
+        0x01,
+    );
+
+    // Assert
+    assert!(failures.is_empty());
+}
+
+#[test]
+fn address_validation_reports_all_failures() {
+    // Arrange
+    let encoded_address =
+        AddressBech32Encoder::new(&NetworkDefinition::mainnet())
+            .encode(XRD.as_node_id().as_bytes())
+            .unwrap();
+
+    // Act
+    let failures = address::validate(
+        &encoded_address,
+        &[EntityType::GlobalVirtualSecp256k1Account],
+        0x02,
+    );
+
+    // Assert
+    assert_eq!(
+        failures,
+        vec![
+            AddressValidationFailure::NetworkMismatch {
+                expected: 0x02,
+                actual: 0x01
+            },
+            AddressValidationFailure::UnexpectedEntityType {
+                expected: vec![EntityType::GlobalVirtualSecp256k1Account],
+                actual: EntityType::GlobalFungibleResourceManager
+            }
+        ]
+    );
+}
@@ -0,0 +1,209 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::address_book::*;
+use scrypto::prelude::*;
+
+#[test]
+fn exported_address_book_can_be_imported() {
+    // Arrange
+    let address_book = address_book(vec![
+        entry("Alice", account(1), 0xf2),
+        entry("Bob", account(2), 0xf2),
+    ]);
+
+    // Act
+    let exported = export(&address_book).unwrap();
+    let imported = import(exported).unwrap();
+
+    // Assert
+    assert_eq!(imported, address_book);
+}
+
+#[test]
+fn tampered_address_book_fails_the_checksum_check() {
+    // Arrange
+    let mut exported =
+        export(&address_book(vec![entry("Alice", account(1), 0xf2)])).unwrap();
+    let label_index = exported
+        .windows(5)
+        .position(|window| window == b"Alice")
+        .unwrap();
+    exported[label_index] = b'M';
+
+    // Act
+    let imported = import(exported);
+
+    // Assert
+    assert!(matches!(
+        imported,
+        Err(AddressBookError::ChecksumMismatch { .. })
+    ))
+}
+
+#[test]
+fn addresses_of_different_networks_are_rejected_unless_allowed() {
+    // Arrange
+    let mut address_book = address_book(vec![
+        entry("Alice", account(1), 0x01),
+        entry("Bob", account(2), 0xf2),
+    ]);
+
+    // Act
+    let disallowed = validate(&address_book);
+    address_book.allow_mixed_networks = true;
+    let allowed = validate(&address_book);
+
+    // Assert
+    assert!(matches!(
+        disallowed,
+        Err(AddressBookError::MixedNetworks {
+            index: 1,
+            expected: 0x01,
+            actual: 0xf2
+        })
+    ));
+    assert!(allowed.is_ok());
+}
+
+#[test]
+fn duplicate_addresses_are_rejected() {
+    // Arrange
+    let address_book = address_book(vec![
+        entry("Alice", account(1), 0xf2),
+        entry("Also Alice", account(1), 0xf2),
+    ]);
+
+    // Act
+    let result = validate(&address_book);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(AddressBookError::DuplicateAddress {
+            first_index: 0,
+            duplicate_index: 1,
+            ..
+        })
+    ))
+}
+
+#[test]
+fn entries_with_an_incorrect_declared_network_are_rejected() {
+    // Arrange
+    let mut entry = entry("Alice", account(1), 0xf2);
+    entry.network_id = 0x01;
+
+    // Act
+    let result = validate(&address_book(vec![entry]));
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(AddressBookError::NetworkMismatch {
+            declared: 0x01,
+            actual: 0xf2,
+            ..
+        })
+    ))
+}
+
+#[test]
+fn merge_appends_new_entries_and_reports_label_conflicts() {
+    // Arrange
+    let base = address_book(vec![
+        entry("Alice", account(1), 0xf2),
+        entry("Bob", account(2), 0xf2),
+    ]);
+    let other = address_book(vec![
+        entry("Robert", account(2), 0xf2),
+        entry("Carol", account(3), 0xf2),
+    ]);
+
+    // Act
+    let AddressBookMerge {
+        address_book,
+        conflicts,
+    } = merge(&base, &other).unwrap();
+
+    // Assert
+    assert_eq!(
+        address_book
+            .entries
+            .iter()
+            .map(|entry| entry.label.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Alice", "Bob", "Carol"]
+    );
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].base_label, "Bob");
+    assert_eq!(conflicts[0].other_label, "Robert");
+}
+
+#[test]
+fn diff_reports_added_removed_and_relabeled_entries() {
+    // Arrange
+    let old = address_book(vec![
+        entry("Alice", account(1), 0xf2),
+        entry("Bob", account(2), 0xf2),
+    ]);
+    let new = address_book(vec![
+        entry("Robert", account(2), 0xf2),
+        entry("Carol", account(3), 0xf2),
+    ]);
+
+    // Act
+    let diff = diff(&old, &new);
+
+    // Assert
+    assert_eq!(diff.added, vec![entry("Carol", account(3), 0xf2)]);
+    assert_eq!(diff.removed, vec![entry("Alice", account(1), 0xf2)]);
+    assert_eq!(diff.relabeled.len(), 1);
+    assert_eq!(diff.relabeled[0].other_label, "Robert");
+}
+
+fn address_book(entries: Vec<AddressBookEntry>) -> AddressBook {
+    AddressBook {
+        entries,
+        allow_mixed_networks: false,
+    }
+}
+
+fn entry(
+    label: &str,
+    address: ComponentAddress,
+    network_id: u8,
+) -> AddressBookEntry {
+    let network_definition =
+        radix_engine_toolkit::utils::network_definition_from_network_id(
+            network_id,
+        );
+    AddressBookEntry {
+        label: label.to_owned(),
+        address: AddressBech32Encoder::new(&network_definition)
+            .encode(address.as_node_id().as_bytes())
+            .unwrap(),
+        entity_type: address.as_node_id().entity_type().unwrap(),
+        network_id,
+    }
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
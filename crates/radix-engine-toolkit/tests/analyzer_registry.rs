@@ -0,0 +1,206 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::transaction_types::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn registered_analyzers_contribute_sections_in_registration_order() {
+    // Arrange
+    let component = component();
+    let manifest = ManifestBuilder::new()
+        .call_method(component, "free", ())
+        .call_method(component, "buy", ())
+        .build();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry
+        .register(InstructionCounter::default)
+        .register_rules(AnalyzerRules {
+            name: "purchases".to_owned(),
+            rules: vec![AnalyzerRule {
+                condition: AnalyzerRuleCondition::CallsMethod {
+                    address: Some(component.into()),
+                    method_name: "buy".to_owned(),
+                },
+                message: "Buys from the component".to_owned(),
+            }],
+        });
+
+    // Act
+    let analysis = registry.analyze(&manifest);
+
+    // Assert
+    assert_eq!(
+        analysis.sections.keys().collect::<Vec<_>>(),
+        vec!["instruction_count", "purchases"]
+    );
+    assert_eq!(
+        analysis.sections["instruction_count"],
+        vec![AnalysisFinding {
+            instruction_index: None,
+            message: "2 instructions".to_owned(),
+        }]
+    );
+    assert_eq!(
+        analysis.sections["purchases"],
+        vec![AnalysisFinding {
+            instruction_index: Some(1),
+            message: "Buys from the component".to_owned(),
+        }]
+    );
+    assert_eq!(analysis.trusted_worktop.len(), 2);
+}
+
+#[test]
+fn registry_creates_a_new_analyzer_for_every_manifest() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_method(component(), "free", ())
+        .build();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry.register(InstructionCounter::default);
+
+    // Act
+    let first = registry.analyze(&manifest);
+    let second = registry.analyze(&manifest);
+
+    // Assert
+    assert_eq!(first, second);
+}
+
+#[test]
+fn encountered_entity_rule_matches_every_instruction_referencing_it() {
+    // Arrange
+    let component = component();
+    let manifest = ManifestBuilder::new()
+        .call_method(component, "free", ())
+        .drop_auth_zone_proofs()
+        .call_method(component, "buy", ())
+        .build();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry.register_rules(AnalyzerRules {
+        name: "component".to_owned(),
+        rules: vec![AnalyzerRule {
+            condition: AnalyzerRuleCondition::EncountersEntity {
+                address: component.into(),
+            },
+            message: "Interacts with the component".to_owned(),
+        }],
+    });
+
+    // Act
+    let analysis = registry.analyze(&manifest);
+
+    // Assert
+    assert_eq!(
+        analysis.sections["component"]
+            .iter()
+            .map(|finding| finding.instruction_index)
+            .collect::<Vec<_>>(),
+        vec![Some(0), Some(2)]
+    );
+}
+
+#[test]
+fn bulk_analysis_returns_the_analyses_in_the_order_of_the_manifests() {
+    // Arrange
+    let manifests = (1..=8)
+        .map(|count| {
+            (0..count)
+                .fold(ManifestBuilder::new(), |builder, _| {
+                    builder.drop_auth_zone_proofs()
+                })
+                .build()
+        })
+        .collect::<Vec<_>>();
+    let mut registry = ManifestAnalyzerRegistry::new();
+    registry.register(InstructionCounter::default);
+
+    // Act
+    let analyses = registry.analyze_bulk(&manifests);
+
+    // Assert
+    assert_eq!(
+        analyses,
+        manifests
+            .iter()
+            .map(|manifest| registry.analyze(manifest))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn bulk_summaries_are_in_the_order_of_the_manifests() {
+    // Arrange
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    let manifests = vec![
+        ManifestBuilder::new().drop_auth_zone_proofs().build(),
+        ManifestBuilder::new()
+            .withdraw_from_account(account, XRD, 10)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+    ];
+
+    // Act
+    let summaries = analyze_manifests_bulk(manifests.clone());
+
+    // Assert
+    assert_eq!(
+        summaries
+            .iter()
+            .map(|summary| summary.accounts_withdrawn_from.clone())
+            .collect::<Vec<_>>(),
+        manifests
+            .iter()
+            .map(|manifest| summary(manifest).accounts_withdrawn_from)
+            .collect::<Vec<_>>()
+    );
+    assert!(summaries[1].accounts_withdrawn_from.contains(&account));
+}
+
+#[derive(Default)]
+struct InstructionCounter {
+    instructions_count: usize,
+}
+
+impl ManifestSummaryCallback for InstructionCounter {
+    fn on_finish(&mut self, instructions_count: usize) {
+        self.instructions_count = instructions_count;
+    }
+}
+
+impl ManifestAnalyzer for InstructionCounter {
+    fn name(&self) -> String {
+        "instruction_count".to_owned()
+    }
+
+    fn output(self: Box<Self>) -> Vec<AnalysisFinding> {
+        vec![AnalysisFinding {
+            instruction_index: None,
+            message: format!("{} instructions", self.instructions_count),
+        }]
+    }
+}
+
+fn component() -> ComponentAddress {
+    ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    )
+}
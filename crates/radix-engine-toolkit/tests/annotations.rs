@@ -0,0 +1,75 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::annotations::*;
+use radix_transactions::manifest::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn annotated_manifest_names_well_known_addresses_and_buckets() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "xrd")
+        .create_proof_from_bucket_of_all("xrd", "proof")
+        .drop_proof("proof")
+        .try_deposit_or_abort(account(2), None, "xrd")
+        .build();
+    let network_definition = NetworkDefinition::simulator();
+
+    // Act
+    let annotated =
+        decompile_annotated(&manifest.instructions, &network_definition)
+            .unwrap();
+
+    // Assert
+    assert!(annotated.contains(" # XRD\n"));
+    assert!(annotated.contains("# Locks a fee from the XRD in the account\n"));
+    assert!(annotated.contains("# bucket1: 10 of XRD taken from the worktop\n"));
+    assert!(annotated
+        .contains("# proof1: proof of all of the resources in bucket1\n"));
+}
+
+#[test]
+fn annotated_manifest_compiles_to_the_same_instructions() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+    let network_definition = NetworkDefinition::simulator();
+
+    // Act
+    let annotated =
+        decompile_annotated(&manifest.instructions, &network_definition)
+            .unwrap();
+
+    // Assert
+    let compiled =
+        compile(&annotated, &network_definition, MockBlobProvider::new())
+            .unwrap();
+    assert_eq!(compiled.instructions, manifest.instructions);
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
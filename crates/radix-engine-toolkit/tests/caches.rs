@@ -0,0 +1,60 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use radix_engine_toolkit::caches::*;
+use scrypto::prelude::*;
+
+#[test]
+fn cached_coders_are_reused_until_the_caches_are_cleared() {
+    // Arrange
+    let first = bech32_coders(0x01);
+
+    // Act
+    let second = bech32_coders(0x01);
+    clear_caches();
+    let third = bech32_coders(0x01);
+
+    // Assert
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(!Arc::ptr_eq(&first, &third));
+    assert_eq!(third.network_definition.id, 0x01);
+}
+
+#[test]
+fn cached_coders_encode_for_their_network() {
+    // Arrange
+    let coders = bech32_coders(0x02);
+
+    // Act
+    let address = coders
+        .address_encoder
+        .encode(XRD.as_node_id().as_bytes())
+        .unwrap();
+
+    // Assert
+    assert!(address.starts_with("resource_tdx_2_1"));
+    assert_eq!(
+        coders
+            .address_decoder
+            .validate_and_decode(&address)
+            .unwrap()
+            .1,
+        XRD.as_node_id().as_bytes()
+    );
+}
@@ -0,0 +1,118 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::decimal::*;
+use scrypto::prelude::*;
+
+fn options(
+    max_fraction_digits: Option<u8>,
+    rounding_mode: RoundingMode,
+) -> DecimalFormatOptions {
+    DecimalFormatOptions {
+        locale: DecimalLocaleOptions {
+            decimal_separator: ",".to_owned(),
+            thousands_separator: Some(".".to_owned()),
+        },
+        max_fraction_digits,
+        rounding_mode,
+    }
+}
+
+#[test]
+fn decimal_is_formatted_with_the_separators_of_the_locale() {
+    // Act
+    let formatted = format_decimal(
+        &dec!("-1234567.891"),
+        &options(None, RoundingMode::ToZero),
+    );
+
+    // Assert
+    assert_eq!(formatted, Ok("-1.234.567,891".to_owned()));
+}
+
+#[test]
+fn decimal_is_rounded_or_truncated_to_the_max_fraction_digits() {
+    // Arrange
+    let value = dec!("0.999999999999999999");
+
+    // Act
+    let rounded = format_decimal(
+        &value,
+        &options(Some(2), RoundingMode::ToNearestMidpointAwayFromZero),
+    );
+    let truncated =
+        format_decimal(&value, &options(Some(2), RoundingMode::ToZero));
+
+    // Assert
+    assert_eq!(rounded, Ok("1".to_owned()));
+    assert_eq!(truncated, Ok("0,99".to_owned()));
+}
+
+#[test]
+fn precise_decimal_keeps_all_of_its_fraction_digits() {
+    // Arrange
+    let value = pdec!("0.000000000000000000000000000000000001");
+
+    // Act
+    let formatted =
+        format_precise_decimal(&value, &options(None, RoundingMode::ToZero));
+
+    // Assert
+    assert_eq!(
+        formatted,
+        Ok("0,000000000000000000000000000000000001".to_owned())
+    );
+}
+
+#[test]
+fn localized_decimal_is_parsed() {
+    // Arrange
+    let locale = options(None, RoundingMode::ToZero).locale;
+
+    // Act
+    let parsed = parse_decimal("-1.234.567,891", &locale);
+
+    // Assert
+    assert_eq!(parsed, Ok(dec!("-1234567.891")));
+}
+
+#[test]
+fn localized_decimal_that_breaks_the_rules_is_rejected() {
+    // Arrange
+    let locale = options(None, RoundingMode::ToZero).locale;
+
+    // Act & Assert
+    assert_eq!(
+        parse_decimal("12.34,5", &locale),
+        Err(DecimalFormatError::InvalidGrouping("12.34,5".to_owned()))
+    );
+    assert_eq!(
+        parse_decimal("1,2,3", &locale),
+        Err(DecimalFormatError::InvalidNumber("1,2,3".to_owned()))
+    );
+    assert_eq!(
+        parse_decimal("0,0000000000000000001", &locale),
+        Err(DecimalFormatError::TooManyFractionDigits {
+            digits: 19,
+            max_digits: 18
+        })
+    );
+    assert_eq!(
+        parse_precise_decimal("0,0000000000000000001", &locale),
+        Ok(pdec!("0.0000000000000000001"))
+    );
+}
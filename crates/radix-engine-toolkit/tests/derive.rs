@@ -16,8 +16,10 @@
 // under the License.
 
 use radix_common::prelude::AddressBech32Decoder;
-use radix_engine_toolkit::functions::derive::{self, OlympiaNetwork};
-use scrypto::prelude::*;
+use radix_engine_toolkit::functions::derive::{
+    self, DerivationError, OlympiaAccountAddressDetails, OlympiaNetwork,
+};
+use scrypto_test::prelude::*;
 
 #[test]
 fn virtual_account_address_can_be_derived_from_public_key() {
@@ -122,6 +124,40 @@ fn resource_address_can_be_derived_from_olympia_resource_address() {
     assert_eq!(resource_address, expected_babylon_resource_address)
 }
 
+#[test]
+fn olympia_native_token_rri_is_derived_as_xrd() {
+    // Arrange
+    let olympia_address = "xrd_rr1qy5wfsfh";
+
+    // Act
+    let resource_address =
+        derive::resource_address_from_olympia_resource_address(olympia_address)
+            .unwrap();
+
+    // Assert
+    assert_eq!(resource_address, XRD)
+}
+
+#[test]
+fn olympia_account_address_is_not_derived_as_a_resource_address() {
+    // Arrange
+    let olympia_address =
+        "rdx1qspx7zxmnrh36q33av24srdfzg7m3cj65968erpjuh7ja3rm3kmn6hq4j9842";
+
+    // Act
+    let resource_address =
+        derive::resource_address_from_olympia_resource_address(olympia_address);
+
+    // Assert
+    assert!(matches!(
+        resource_address,
+        Err(DerivationError::InvalidOlympiaAddressPrefix {
+            expected: 0x03,
+            actual: 0x04
+        })
+    ))
+}
+
 #[test]
 fn olympia_address_can_be_derived_from_public_key() {
     // Arrange
@@ -142,6 +178,46 @@ fn olympia_address_can_be_derived_from_public_key() {
     assert_eq!(olympia_address, expected_olympia_address)
 }
 
+#[test]
+fn olympia_account_address_details_report_the_network_and_public_key() {
+    // Arrange
+    let olympia_address =
+        "rdx1qspx7zxmnrh36q33av24srdfzg7m3cj65968erpjuh7ja3rm3kmn6hq4j9842";
+    let expected_public_key =
+        "026f08db98ef1d0231eb15580da9123db8e25aa1747c8c32e5fd2ec47b8db73d5c"
+            .parse::<Secp256k1PublicKey>()
+            .unwrap();
+
+    // Act
+    let details =
+        derive::olympia_account_address_details(olympia_address).unwrap();
+
+    // Assert
+    assert_eq!(
+        details,
+        OlympiaAccountAddressDetails {
+            olympia_network: OlympiaNetwork::Mainnet,
+            public_key: expected_public_key,
+        }
+    )
+}
+
+#[test]
+fn olympia_account_address_of_unknown_network_is_invalid() {
+    // Arrange
+    let olympia_address =
+        "zdx1qspx7zxmnrh36q33av24srdfzg7m3cj65968erpjuh7ja3rm3kmn6hqwz8kmw";
+
+    // Act
+    let details = derive::olympia_account_address_details(olympia_address);
+
+    // Assert
+    assert!(matches!(
+        details,
+        Err(DerivationError::UnknownOlympiaNetworkHrp(hrp)) if hrp == "zdx"
+    ))
+}
+
 #[test]
 fn node_address_can_be_derived_from_public_key() {
     // Arrange
@@ -159,6 +235,37 @@ fn node_address_can_be_derived_from_public_key() {
     assert_eq!(node_address, expected_node_address)
 }
 
+#[test]
+fn validator_owner_badge_can_be_derived_from_validator_address() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (public_key, _, account) = ledger.new_allocated_account();
+    let validator_address =
+        ledger.new_validator_with_pub_key(public_key, account);
+
+    // Act
+    let owner_badge = derive::validator_owner_badge_from_validator_address(
+        &validator_address,
+    );
+
+    // Assert
+    assert_eq!(owner_badge.resource_address(), VALIDATOR_OWNER_BADGE);
+    ledger
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .create_proof_from_account_of_non_fungibles(
+                    account,
+                    VALIDATOR_OWNER_BADGE,
+                    [owner_badge.local_id().clone()],
+                )
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+}
+
 fn public_key() -> PublicKey {
     let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
     private_key.public_key().into()
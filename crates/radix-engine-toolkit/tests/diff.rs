@@ -0,0 +1,148 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::diff::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn identical_manifests_have_no_changes() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let diff = diff_manifests(&manifest, &manifest).unwrap();
+
+    // Assert
+    assert!(diff.instructions.iter().all(|instruction_diff| matches!(
+        instruction_diff,
+        InstructionDiff::Unchanged { old_index, new_index } if old_index == new_index
+    )));
+    assert!(diff.added_blobs.is_empty());
+    assert!(diff.removed_blobs.is_empty());
+}
+
+#[test]
+fn added_and_removed_instructions_are_reported() {
+    // Arrange
+    let old = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .drop_all_proofs()
+        .withdraw_from_account(account(1), XRD, 10)
+        .build();
+    let new = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let diff = diff_instructions(&old.instructions, &new.instructions).unwrap();
+
+    // Assert
+    assert_eq!(
+        diff,
+        vec![
+            InstructionDiff::Unchanged {
+                old_index: 0,
+                new_index: 0
+            },
+            InstructionDiff::Removed {
+                old_index: 1,
+                instruction: InstructionV1::DropAllProofs
+            },
+            InstructionDiff::Unchanged {
+                old_index: 2,
+                new_index: 1
+            },
+            InstructionDiff::Added {
+                new_index: 2,
+                instruction: new.instructions[2].clone()
+            },
+        ]
+    );
+}
+
+#[test]
+fn modified_instructions_report_the_changed_arguments() {
+    // Arrange
+    let old = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .build();
+    let new = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 20)
+        .build();
+
+    // Act
+    let diff = diff_instructions(&old.instructions, &new.instructions).unwrap();
+
+    // Assert
+    let [InstructionDiff::Modified { value_diffs, .. }] = diff.as_slice()
+    else {
+        panic!("Expected a single modified instruction");
+    };
+    assert_eq!(
+        value_diffs
+            .iter()
+            .map(|value_diff| value_diff.path.clone())
+            .collect::<Vec<_>>(),
+        vec![vec![2, 1]]
+    );
+}
+
+#[test]
+fn long_manifests_are_diffed() {
+    // Arrange
+    let old = vec![InstructionV1::PopFromAuthZone; 20_000];
+    let mut new = old.clone();
+    new[10_000] = InstructionV1::DropAllProofs;
+
+    // Act
+    let diff = diff_instructions(&old, &new).unwrap();
+
+    // Assert
+    assert_eq!(diff.len(), old.len() + 1);
+    assert_eq!(
+        diff.iter()
+            .filter(|instruction_diff| !matches!(
+                instruction_diff,
+                InstructionDiff::Unchanged { .. }
+            ))
+            .cloned()
+            .collect::<Vec<_>>(),
+        vec![
+            InstructionDiff::Removed {
+                old_index: 10_000,
+                instruction: InstructionV1::PopFromAuthZone
+            },
+            InstructionDiff::Added {
+                new_index: 10_000,
+                instruction: InstructionV1::DropAllProofs
+            },
+        ]
+    );
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::explain::*;
+use radix_transactions::errors::*;
+use scrypto::prelude::*;
+
+#[test]
+fn typed_header_validation_errors_are_explained() {
+    // Arrange
+    let error = TransactionValidationError::HeaderValidationError(
+        HeaderValidationError::InvalidEpochRange,
+    );
+
+    // Act
+    let explanation = explain_validation_error(&error);
+
+    // Assert
+    assert_eq!(explanation.error, Some("InvalidEpochRange"));
+    assert_eq!(
+        explanation.location,
+        Some(ValidationFailureLocation::Header {
+            field: "end_epoch_exclusive"
+        })
+    );
+    assert!(!explanation.causes.is_empty());
+    assert!(!explanation.fixes.is_empty());
+}
+
+#[test]
+fn manifest_validation_errors_report_the_offending_id() {
+    // Arrange
+    let error = TransactionValidationError::IdValidationError(
+        ManifestIdValidationError::BucketNotFound(ManifestBucket(3)),
+    );
+
+    // Act
+    let explanation = explain_validation_error(&error);
+
+    // Assert
+    assert_eq!(explanation.error, Some("BucketNotFound"));
+    assert_eq!(
+        explanation.location,
+        Some(ValidationFailureLocation::Manifest {
+            detail: Some("ManifestBucket(3)".to_owned())
+        })
+    );
+}
+
+#[test]
+fn node_rejection_strings_are_explained() {
+    // Arrange
+    let rejection = "Rejected: TransactionEpochNoLongerValid { valid_until_epoch: 10, current_epoch: 12 }";
+
+    // Act
+    let explanation = explain_validation_failure(rejection);
+
+    // Assert
+    assert_eq!(explanation.error, Some("EpochNoLongerValid"));
+    assert_eq!(
+        explanation.location,
+        Some(ValidationFailureLocation::Header {
+            field: "end_epoch_exclusive"
+        })
+    );
+}
+
+#[test]
+fn unknown_failures_have_an_empty_explanation() {
+    // Act
+    let explanation = explain_validation_failure("SomethingElseEntirely");
+
+    // Assert
+    assert_eq!(explanation.error, None);
+    assert_eq!(explanation.location, None);
+    assert!(explanation.causes.is_empty());
+}
@@ -0,0 +1,130 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::instruction_arguments::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn calls_with_valid_arguments_have_no_issues() {
+    // Arrange
+    let account = account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 10)
+        .withdraw_from_account(account, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let issues = validate_instruction_arguments(
+        &manifest.instructions,
+        native_blueprint_interfaces(),
+    );
+
+    // Assert
+    assert_eq!(issues, vec![]);
+}
+
+#[test]
+fn calls_with_the_wrong_number_of_arguments_are_reported() {
+    // Arrange
+    let account = account();
+    let manifest = ManifestBuilder::new()
+        .call_method(account, "lock_fee", (dec!(10), dec!(1)))
+        .build();
+
+    // Act
+    let issues = validate_instruction_arguments(
+        &manifest.instructions,
+        native_blueprint_interfaces(),
+    );
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![InstructionArgumentsIssue {
+            instruction_index: 0,
+            kind: InstructionArgumentsIssueKind::ArgumentCountMismatch {
+                expected: 1,
+                actual: 2,
+            },
+        }]
+    );
+}
+
+#[test]
+fn arguments_of_the_wrong_kind_are_reported() {
+    // Arrange
+    let account = account();
+    let manifest = ManifestBuilder::new()
+        .call_method(account, "lock_fee", ("10",))
+        .build();
+
+    // Act
+    let issues = validate_instruction_arguments(
+        &manifest.instructions,
+        native_blueprint_interfaces(),
+    );
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![InstructionArgumentsIssue {
+            instruction_index: 0,
+            kind: InstructionArgumentsIssueKind::ValueKindMismatch {
+                path: "$.0".to_owned(),
+                expected: ManifestValueKind::Custom(
+                    ManifestCustomValueKind::Decimal
+                ),
+                actual: ManifestValueKind::String,
+            },
+        }]
+    );
+}
+
+#[test]
+fn calls_to_unknown_methods_are_reported() {
+    // Arrange
+    let account = account();
+    let manifest = ManifestBuilder::new()
+        .call_method(account, "withdraw_everything", ())
+        .build();
+
+    // Act
+    let issues = validate_instruction_arguments(
+        &manifest.instructions,
+        native_blueprint_interfaces(),
+    );
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![InstructionArgumentsIssue {
+            instruction_index: 0,
+            kind: InstructionArgumentsIssueKind::FunctionNotFound {
+                blueprint_name: ACCOUNT_BLUEPRINT.to_owned(),
+                function_name: "withdraw_everything".to_owned(),
+            },
+        }]
+    );
+}
+
+fn account() -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    )
+}
@@ -0,0 +1,174 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::instructions::*;
+use radix_engine_toolkit::models::node_id::TypedNodeId;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn address_usage_distinguishes_call_targets_from_arguments() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "xrd")
+        .try_deposit_or_abort(account(2), None, "xrd")
+        .set_metadata(account(1), "owner", GlobalAddress::from(account(3)))
+        .build();
+
+    // Act
+    let addresses = extract_addresses_with_usage(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        addresses.get(&TypedNodeId::from(account(1))),
+        Some(&indexset![
+            AddressUsage::CallTarget,
+            AddressUsage::MetadataSubject
+        ])
+    );
+    assert_eq!(
+        addresses.get(&TypedNodeId::from(account(2))),
+        Some(&indexset![AddressUsage::CallTarget])
+    );
+    assert_eq!(
+        addresses.get(&TypedNodeId::from(account(3))),
+        Some(&indexset![AddressUsage::Metadata])
+    );
+    assert_eq!(
+        addresses.get(&TypedNodeId::from(XRD)),
+        Some(&indexset![AddressUsage::Argument, AddressUsage::Resource])
+    );
+}
+
+#[test]
+fn address_occurrences_report_the_instructions_that_addresses_appear_in() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "xrd")
+        .try_deposit_or_abort(account(2), None, "xrd")
+        .claim_component_royalties(account(1))
+        .build();
+
+    // Act
+    let addresses = extract_address_occurrences(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        addresses.get(&TypedNodeId::from(account(1))),
+        Some(&vec![
+            AddressOccurrence {
+                instruction_index: 0,
+                usage: AddressUsage::CallTarget,
+            },
+            AddressOccurrence {
+                instruction_index: 3,
+                usage: AddressUsage::RoyaltyTarget,
+            },
+        ])
+    );
+    assert_eq!(
+        addresses.get(&TypedNodeId::from(XRD)),
+        Some(&vec![
+            AddressOccurrence {
+                instruction_index: 0,
+                usage: AddressUsage::Argument,
+            },
+            AddressOccurrence {
+                instruction_index: 1,
+                usage: AddressUsage::Resource,
+            },
+        ])
+    );
+}
+
+#[test]
+fn unknown_instructions_are_kept_and_compiled_back_into_the_same_bytes() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+    let unknown_instruction = ManifestValue::Enum {
+        discriminator: 0xff,
+        fields: vec![ManifestValue::String {
+            value: "new".to_owned(),
+        }],
+    };
+    let mut elements = manifest
+        .instructions
+        .iter()
+        .map(|instruction| {
+            manifest_decode::<ManifestValue>(
+                &manifest_encode(instruction).unwrap(),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    elements.insert(1, unknown_instruction);
+    let compiled = manifest_encode(&ManifestValue::Array {
+        element_value_kind: ManifestValueKind::Enum,
+        elements,
+    })
+    .unwrap();
+
+    // Act
+    let decompiled = decompile_forward_compatible(&compiled).unwrap();
+
+    // Assert
+    assert!(decompile(&compiled).is_err());
+    assert_eq!(
+        decompiled,
+        vec![
+            DecompiledInstruction::Known(manifest.instructions[0].clone()),
+            DecompiledInstruction::Unknown {
+                discriminator: 0xff,
+                fields: vec![ManifestValue::String {
+                    value: "new".to_owned()
+                }],
+            },
+            DecompiledInstruction::Known(manifest.instructions[1].clone()),
+        ]
+    );
+    assert_eq!(compile_forward_compatible(&decompiled).unwrap(), compiled);
+}
+
+#[test]
+fn malformed_known_instructions_are_not_treated_as_unknown() {
+    // Arrange
+    let compiled = manifest_encode(&ManifestValue::Array {
+        element_value_kind: ManifestValueKind::Enum,
+        elements: vec![ManifestValue::Enum {
+            discriminator: INSTRUCTION_TAKE_ALL_FROM_WORKTOP_DISCRIMINATOR,
+            fields: vec![],
+        }],
+    })
+    .unwrap();
+
+    // Act
+    let decompiled = decompile_forward_compatible(&compiled);
+
+    // Assert
+    assert!(decompiled.is_err());
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
@@ -15,7 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_engine_toolkit::functions::intent::{
+    NonceSource, SignatureMergeError, TransactionHeaderError,
+    TransactionHeaderOptions,
+};
+use radix_transactions::model::*;
+use radix_transactions::prelude::{PrivateKey, Signer};
 use radix_transactions::validation::ValidationConfig;
+use scrypto::prelude::{
+    hash, Ed25519PrivateKey, Ed25519PublicKey, Epoch, PublicKey,
+};
 mod test_data;
 
 #[test]
@@ -74,3 +83,339 @@ fn intent_can_be_statically_validated() {
     // Assert
     assert!(validation_result.is_ok())
 }
+
+#[test]
+fn replay_protection_of_intent_matches_its_header_and_hash() {
+    // Arrange
+    let intent = test_data::intent();
+
+    // Act
+    let replay_protection =
+        radix_engine_toolkit::functions::intent::replay_protection(&intent)
+            .unwrap();
+
+    // Assert
+    let intent_hash =
+        radix_engine_toolkit::functions::intent::hash(&intent).unwrap();
+    assert_eq!(replay_protection.network_id, intent.header.network_id);
+    assert_eq!(
+        replay_protection.start_epoch_inclusive,
+        intent.header.start_epoch_inclusive
+    );
+    assert_eq!(
+        replay_protection.end_epoch_exclusive,
+        intent.header.end_epoch_exclusive
+    );
+    assert_eq!(replay_protection.nonce, intent.header.nonce);
+    assert_eq!(replay_protection.intent_hash.0, intent_hash.hash);
+}
+
+#[test]
+fn replay_protection_of_compiled_intent_matches_that_of_the_intent() {
+    // Arrange
+    let intent = test_data::intent();
+    let compiled =
+        radix_engine_toolkit::functions::intent::compile(&intent).unwrap();
+
+    // Act
+    let replay_protection =
+        radix_engine_toolkit::functions::intent::replay_protection_from_payload(
+            compiled,
+        );
+
+    // Assert
+    assert_eq!(
+        replay_protection,
+        Ok(
+            radix_engine_toolkit::functions::intent::replay_protection(&intent)
+                .unwrap()
+        )
+    )
+}
+
+#[test]
+fn intent_can_be_signed() {
+    // Arrange
+    let intent = test_data::intent();
+    let private_key = PrivateKey::Ed25519(test_data::private_key2());
+    let intent_hash = intent.prepare().unwrap().intent_hash();
+
+    // Act
+    let signature =
+        radix_engine_toolkit::functions::intent::sign(&intent, &private_key);
+
+    // Assert
+    assert_eq!(
+        signature,
+        Ok(private_key.sign_with_public_key(&intent_hash))
+    )
+}
+
+#[test]
+fn intent_can_be_signed_to_a_signed_intent() {
+    // Arrange
+    let intent = test_data::intent();
+    let private_keys = [
+        PrivateKey::Secp256k1(test_data::private_key1()),
+        PrivateKey::Ed25519(test_data::private_key2()),
+    ];
+
+    // Act
+    let signed_intent =
+        radix_engine_toolkit::functions::intent::sign_to_signed_intent(
+            intent.clone(),
+            &private_keys,
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(signed_intent.intent, intent);
+    assert_eq!(
+        signed_intent.intent_signatures.signatures,
+        private_keys
+            .iter()
+            .map(|private_key| IntentSignatureV1(
+                radix_engine_toolkit::functions::intent::sign(
+                    &intent,
+                    private_key
+                )
+                .unwrap()
+            ))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn signatures_of_different_signers_can_be_merged_without_duplicates() {
+    // Arrange
+    let intent = test_data::intent();
+    let sign = |private_key: PrivateKey| {
+        radix_engine_toolkit::functions::intent::sign(&intent, &private_key)
+            .unwrap()
+    };
+    let signature1 = sign(PrivateKey::Secp256k1(test_data::private_key1()));
+    let signature2 = sign(PrivateKey::Ed25519(test_data::private_key2()));
+    let signature3 = sign(PrivateKey::Secp256k1(test_data::private_key3()));
+
+    // Act
+    let signed_intent =
+        radix_engine_toolkit::functions::intent::merge_signatures(
+            intent.clone(),
+            [
+                vec![signature1.clone(), signature2.clone()],
+                vec![signature2.clone(), signature3.clone()],
+            ],
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(signed_intent.intent, intent);
+    assert_eq!(
+        signed_intent.intent_signatures.signatures,
+        vec![
+            IntentSignatureV1(signature1),
+            IntentSignatureV1(signature2),
+            IntentSignatureV1(signature3),
+        ]
+    );
+}
+
+#[test]
+fn merging_a_signature_over_another_hash_fails() {
+    // Arrange
+    let intent = test_data::intent();
+    let signature = PrivateKey::Ed25519(test_data::private_key2())
+        .sign_with_public_key(&hash(b"not the intent hash"));
+
+    // Act
+    let signed_intent =
+        radix_engine_toolkit::functions::intent::merge_signatures(
+            intent,
+            [vec![signature.clone()]],
+        );
+
+    // Assert
+    assert_eq!(
+        signed_intent,
+        Err(SignatureMergeError::InvalidSignature(signature))
+    );
+}
+
+#[test]
+fn intent_hash_can_be_obtained_from_payload() {
+    // Arrange
+    let intent = test_data::intent();
+    let compiled =
+        radix_engine_toolkit::functions::intent::compile(&intent).unwrap();
+
+    // Act
+    let hash =
+        radix_engine_toolkit::functions::intent::hash_from_payload(compiled)
+            .unwrap();
+
+    // Assert
+    let expected =
+        radix_engine_toolkit::functions::intent::hash(&intent).unwrap();
+    assert_eq!(hash.hash, expected.hash);
+    assert_eq!(hash.id, expected.id);
+}
+
+#[test]
+fn transaction_header_can_be_built_with_a_fixed_nonce() {
+    // Arrange
+    let options = header_options(100, 200, 0);
+
+    // Act
+    let header =
+        radix_engine_toolkit::functions::intent::build_transaction_header(
+            options,
+            &ValidationConfig::default(0x01),
+        );
+
+    // Assert
+    assert_eq!(
+        header,
+        Ok(TransactionHeaderV1 {
+            network_id: 0x01,
+            start_epoch_inclusive: Epoch::of(100),
+            end_epoch_exclusive: Epoch::of(200),
+            nonce: 10,
+            notary_public_key: test_data::private_key1().public_key().into(),
+            notary_is_signatory: true,
+            tip_percentage: 0,
+        })
+    )
+}
+
+#[test]
+fn transaction_header_with_an_end_epoch_before_the_start_epoch_is_rejected() {
+    // Arrange
+    let options = header_options(200, 200, 0);
+
+    // Act
+    let header =
+        radix_engine_toolkit::functions::intent::build_transaction_header(
+            options,
+            &ValidationConfig::default(0x01),
+        );
+
+    // Assert
+    assert_eq!(
+        header,
+        Err(TransactionHeaderError::InvalidEpochRange {
+            start_epoch_inclusive: Epoch::of(200),
+            end_epoch_exclusive: Epoch::of(200),
+        })
+    )
+}
+
+#[test]
+fn transaction_header_with_a_large_epoch_window_is_rejected() {
+    // Arrange
+    let validation_config = ValidationConfig::default(0x01);
+    let options =
+        header_options(100, 101 + validation_config.max_epoch_range, 0);
+
+    // Act
+    let header =
+        radix_engine_toolkit::functions::intent::build_transaction_header(
+            options,
+            &validation_config,
+        );
+
+    // Assert
+    assert_eq!(
+        header,
+        Err(TransactionHeaderError::EpochRangeTooLarge {
+            max_epoch_range: validation_config.max_epoch_range,
+            epoch_range: validation_config.max_epoch_range + 1,
+        })
+    )
+}
+
+#[test]
+fn transaction_header_with_a_tip_above_the_maximum_is_rejected() {
+    // Arrange
+    let validation_config = ValidationConfig::default(0x01);
+    let options =
+        header_options(100, 200, validation_config.max_tip_percentage + 1);
+
+    // Act
+    let header =
+        radix_engine_toolkit::functions::intent::build_transaction_header(
+            options,
+            &validation_config,
+        );
+
+    // Assert
+    assert!(matches!(
+        header,
+        Err(TransactionHeaderError::TipPercentageOutOfRange { .. })
+    ))
+}
+
+#[test]
+fn transaction_header_with_an_ed25519_notary_key_off_the_curve_is_rejected() {
+    // Arrange
+    let mut public_key = [0u8; Ed25519PublicKey::LENGTH];
+    public_key[0] = 2;
+    let notary_public_key = PublicKey::Ed25519(Ed25519PublicKey(public_key));
+    let options = TransactionHeaderOptions {
+        notary_public_key,
+        ..header_options(100, 200, 0)
+    };
+
+    // Act
+    let header =
+        radix_engine_toolkit::functions::intent::build_transaction_header(
+            options,
+            &ValidationConfig::default(0x01),
+        );
+
+    // Assert
+    assert_eq!(
+        header,
+        Err(TransactionHeaderError::InvalidNotaryPublicKey(
+            notary_public_key
+        ))
+    )
+}
+
+#[test]
+fn transaction_header_with_a_valid_ed25519_notary_key_is_built() {
+    // Arrange
+    let notary_public_key: PublicKey =
+        Ed25519PrivateKey::from_u64(1).unwrap().public_key().into();
+    let options = TransactionHeaderOptions {
+        notary_public_key,
+        ..header_options(100, 200, 0)
+    };
+
+    // Act
+    let header =
+        radix_engine_toolkit::functions::intent::build_transaction_header(
+            options,
+            &ValidationConfig::default(0x01),
+        );
+
+    // Assert
+    assert_eq!(
+        header.map(|header| header.notary_public_key),
+        Ok(notary_public_key)
+    )
+}
+
+fn header_options(
+    start_epoch_inclusive: u64,
+    end_epoch_exclusive: u64,
+    tip_percentage: u16,
+) -> TransactionHeaderOptions {
+    TransactionHeaderOptions {
+        start_epoch_inclusive: Epoch::of(start_epoch_inclusive),
+        end_epoch_exclusive: Epoch::of(end_epoch_exclusive),
+        nonce: NonceSource::Fixed(10),
+        notary_public_key: test_data::private_key1().public_key().into(),
+        notary_is_signatory: true,
+        tip_percentage,
+    }
+}
@@ -0,0 +1,212 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::key_derivation::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn derivation_path_can_be_parsed_and_displayed() {
+    // Arrange
+    let path = "m/44'/1022'/1'/525'/1460'/0'";
+
+    // Act
+    let derivation_path = path.parse::<DerivationPath>().unwrap();
+
+    // Assert
+    assert_eq!(
+        derivation_path,
+        DerivationPath::account(1, 0, CurveType::Ed25519)
+    );
+    assert_eq!(derivation_path.to_string(), path);
+}
+
+#[test]
+fn derivation_path_accepts_h_as_hardening_marker() {
+    // Act
+    let derivation_path = "m/44H/1022H/0H/0/5H".parse::<DerivationPath>();
+
+    // Assert
+    assert_eq!(
+        derivation_path,
+        Ok(DerivationPath::account(1, 5, CurveType::Secp256k1))
+    );
+}
+
+#[test]
+fn invalid_derivation_paths_are_rejected() {
+    for path in ["", "44'/1022'", "m/a'", "m/2147483648", "m//1"] {
+        // Act
+        let derivation_path = path.parse::<DerivationPath>();
+
+        // Assert
+        assert!(matches!(
+            derivation_path,
+            Err(KeyDerivationError::InvalidDerivationPath { .. })
+        ));
+    }
+}
+
+#[test]
+fn seed_from_mnemonic_matches_bip39_test_vector() {
+    // Arrange
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon \
+                    abandon abandon abandon abandon about";
+
+    // Act
+    let seed = seed_from_mnemonic(mnemonic, "TREZOR").unwrap();
+
+    // Assert
+    assert_eq!(
+        seed.to_vec(),
+        hex::decode(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553\
+             1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn mnemonic_of_invalid_word_count_is_rejected() {
+    // Act
+    let seed = seed_from_mnemonic("abandon about", "");
+
+    // Assert
+    assert_eq!(
+        seed,
+        Err(KeyDerivationError::InvalidMnemonicWordCount { word_count: 2 })
+    );
+}
+
+#[test]
+fn mnemonic_with_an_unknown_word_is_rejected() {
+    // Arrange
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon \
+                    abandon abandon abandon abandonn about";
+
+    // Act
+    let seed = seed_from_mnemonic(mnemonic, "");
+
+    // Assert
+    assert_eq!(
+        seed,
+        Err(KeyDerivationError::UnknownMnemonicWord {
+            word: "abandonn".to_owned()
+        })
+    );
+}
+
+#[test]
+fn mnemonic_with_an_invalid_checksum_is_rejected() {
+    // Arrange
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon \
+                    abandon abandon abandon abandon abandon";
+
+    // Act
+    let seed = seed_from_mnemonic(mnemonic, "");
+
+    // Assert
+    assert_eq!(seed, Err(KeyDerivationError::InvalidMnemonicChecksum));
+}
+
+#[test]
+fn ed25519_derivation_matches_slip10_test_vector() {
+    // Arrange
+    let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let derivation_path = "m/0'/1'".parse::<DerivationPath>().unwrap();
+
+    // Act
+    let private_key =
+        derive_private_key(&seed, &derivation_path, CurveType::Ed25519);
+
+    // Assert
+    let Ok(PrivateKey::Ed25519(private_key)) = private_key else {
+        panic!("Expected an Ed25519 private key")
+    };
+    assert_eq!(
+        private_key.to_bytes(),
+        hex::decode(
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn ed25519_derivation_rejects_non_hardened_components() {
+    // Arrange
+    let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let derivation_path = "m/0'/1".parse::<DerivationPath>().unwrap();
+
+    // Act
+    let private_key =
+        derive_private_key(&seed, &derivation_path, CurveType::Ed25519);
+
+    // Assert
+    assert!(matches!(
+        private_key,
+        Err(KeyDerivationError::NonHardenedEd25519Derivation { index: 1 })
+    ));
+}
+
+#[test]
+fn secp256k1_derivation_matches_bip32_test_vector() {
+    // Arrange
+    let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+    let derivation_path = "m/0'/1".parse::<DerivationPath>().unwrap();
+
+    // Act
+    let private_key =
+        derive_private_key(&seed, &derivation_path, CurveType::Secp256k1);
+
+    // Assert
+    let Ok(PrivateKey::Secp256k1(private_key)) = private_key else {
+        panic!("Expected a Secp256k1 private key")
+    };
+    assert_eq!(
+        private_key.to_bytes(),
+        hex::decode(
+            "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn key_pair_derived_from_mnemonic_controls_the_derived_account() {
+    // Arrange
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon \
+                    abandon abandon abandon abandon about";
+    let derivation_path = DerivationPath::account(1, 0, CurveType::Ed25519);
+
+    // Act
+    let key_pair = derive_key_pair_from_mnemonic(
+        mnemonic,
+        "",
+        &derivation_path,
+        CurveType::Ed25519,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(key_pair.public_key, key_pair.private_key.public_key());
+    assert_eq!(
+        key_pair.virtual_account_address,
+        ComponentAddress::virtual_account_from_public_key(&key_pair.public_key)
+    );
+}
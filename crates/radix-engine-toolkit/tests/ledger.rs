@@ -0,0 +1,99 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine_toolkit::functions::intent::{compile, hash};
+use radix_engine_toolkit::functions::key_derivation::DerivationPath;
+use radix_engine_toolkit::functions::ledger::*;
+use radix_transactions::model::CurveType;
+mod test_data;
+
+#[test]
+fn compiled_intent_is_sent_in_chunks_after_the_derivation_path() {
+    // Arrange
+    let intent = test_data::intent();
+    let compiled_intent = compile(&intent).unwrap();
+    let derivation_path = DerivationPath::account(
+        intent.header.network_id,
+        0,
+        CurveType::Ed25519,
+    );
+
+    // Act
+    let payload = ledger_signing_payload(
+        &compiled_intent,
+        &derivation_path,
+        CurveType::Ed25519,
+    )
+    .unwrap();
+
+    // Assert
+    let (first, chunks) = payload.apdus.split_first().unwrap();
+    assert_eq!(first.cla, LEDGER_CLA);
+    assert_eq!(first.ins, LEDGER_INS_SIGN_TX_ED25519);
+    assert_eq!(
+        first.data,
+        encode_derivation_path(&derivation_path, CurveType::Ed25519).unwrap()
+    );
+    assert_eq!(
+        chunks
+            .iter()
+            .flat_map(|apdu| apdu.data.clone())
+            .collect::<Vec<_>>(),
+        compiled_intent
+    );
+    let (last, rest) = chunks.split_last().unwrap();
+    assert_eq!(last.cla, LEDGER_CLA_LAST_DATA);
+    assert!(rest.iter().all(|apdu| apdu.cla == LEDGER_CLA_CONTINUATION));
+    assert!(chunks
+        .iter()
+        .all(|apdu| apdu.data.len() <= LEDGER_MAX_APDU_DATA_LENGTH));
+    assert_eq!(payload.intent_hash.hash, hash(&intent).unwrap().hash);
+}
+
+#[test]
+fn derivation_paths_are_encoded_as_big_endian_components() {
+    // Arrange
+    let derivation_path =
+        "m/44'/1022'/0'/0/1'".parse::<DerivationPath>().unwrap();
+
+    // Act
+    let encoded =
+        encode_derivation_path(&derivation_path, CurveType::Secp256k1);
+
+    // Assert
+    assert_eq!(
+        encoded,
+        Ok(vec![
+            5, 0x80, 0x00, 0x00, 0x2C, 0x80, 0x00, 0x03, 0xFE, 0x80, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x01,
+        ])
+    );
+}
+
+#[test]
+fn non_hardened_ed25519_derivation_paths_are_rejected() {
+    // Arrange
+    let derivation_path = "m/44'/1022'/0'/0".parse::<DerivationPath>().unwrap();
+
+    // Act
+    let encoded = encode_derivation_path(&derivation_path, CurveType::Ed25519);
+
+    // Assert
+    assert_eq!(
+        encoded,
+        Err(LedgerPayloadError::NonHardenedEd25519Derivation { index: 0 })
+    );
+}
@@ -0,0 +1,148 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_common::prelude::*;
+use radix_engine_toolkit::functions::limits::*;
+mod test_data;
+
+#[test]
+fn notarized_transaction_within_the_network_limits_passes_the_check() {
+    // Arrange
+    let notarized_transaction = test_data::notarized_transaction();
+    let compiled =
+        radix_engine_toolkit::functions::notarized_transaction::compile(
+            &notarized_transaction,
+        )
+        .unwrap();
+    let instruction_count = notarized_transaction
+        .signed_intent
+        .intent
+        .instructions
+        .0
+        .len();
+
+    // Act
+    let result = check_limits(
+        &compiled,
+        instruction_count,
+        &CompilationLimits::for_network(0x01),
+    );
+
+    // Assert
+    assert_eq!(result, Ok(()))
+}
+
+#[test]
+fn payload_larger_than_the_limit_is_reported() {
+    // Arrange
+    let intent = test_data::intent();
+    let compiled =
+        radix_engine_toolkit::functions::intent::compile(&intent).unwrap();
+    let limits = CompilationLimits {
+        max_payload_size: 10,
+        ..CompilationLimits::for_network(0x01)
+    };
+
+    // Act
+    let result = check_limits(&compiled, intent.instructions.0.len(), &limits);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(LimitExceeded {
+            limit: Limit::PayloadSize(10),
+            actual: compiled.len()
+        })
+    )
+}
+
+#[test]
+fn instruction_count_larger_than_the_limit_is_reported() {
+    // Arrange
+    let intent = test_data::intent();
+    let compiled =
+        radix_engine_toolkit::functions::intent::compile(&intent).unwrap();
+    let instruction_count = intent.instructions.0.len();
+    let limits = CompilationLimits {
+        max_instructions: instruction_count - 1,
+        ..CompilationLimits::for_network(0x01)
+    };
+
+    // Act
+    let result = check_limits(&compiled, instruction_count, &limits);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(LimitExceeded {
+            limit: Limit::InstructionCount(instruction_count - 1),
+            actual: instruction_count
+        })
+    )
+}
+
+#[test]
+fn payload_deeper_than_the_limit_is_reported() {
+    // Arrange
+    let intent = test_data::intent();
+    let compiled =
+        radix_engine_toolkit::functions::intent::compile(&intent).unwrap();
+    let limits = CompilationLimits {
+        max_depth: 2,
+        ..CompilationLimits::for_network(0x01)
+    };
+
+    // Act
+    let result = check_limits(&compiled, intent.instructions.0.len(), &limits);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(LimitExceeded {
+            limit: Limit::Depth(2),
+            actual
+        }) if actual > 2
+    ))
+}
+
+#[test]
+fn payload_deeper_than_the_decoder_allows_is_reported() {
+    // Arrange
+    let mut value = ManifestValue::Tuple { fields: vec![] };
+    for _ in 0..64 {
+        value = ManifestValue::Tuple {
+            fields: vec![value],
+        };
+    }
+    let payload = manifest_encode_with_depth_limit(&value, 128).unwrap();
+    let limits = CompilationLimits {
+        max_depth: 32,
+        ..CompilationLimits::for_network(0x01)
+    };
+
+    // Act
+    let result = check_limits(&payload, 0, &limits);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(LimitExceeded {
+            limit: Limit::Depth(32),
+            actual: 33
+        })
+    )
+}
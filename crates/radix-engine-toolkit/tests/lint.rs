@@ -0,0 +1,142 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::lint::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn well_formed_manifest_has_no_diagnostics() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "bucket")
+        .try_deposit_or_abort(account(2), None, "bucket")
+        .build();
+
+    // Act
+    let diagnostics = lint(&manifest.instructions, &LintConfig::default());
+
+    // Assert
+    assert_eq!(diagnostics, vec![]);
+}
+
+#[test]
+fn missing_lock_fee_is_reported() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let diagnostics = lint(&manifest.instructions, &LintConfig::default());
+
+    // Assert
+    assert_eq!(rules(&diagnostics), vec![LintRule::MissingLockFee]);
+    assert_eq!(diagnostics[0].instruction_index, None);
+    assert_eq!(diagnostics[0].severity, LintSeverity::Warning);
+}
+
+#[test]
+fn dangling_bucket_is_reported_at_the_instruction_creating_it() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "bucket")
+        .try_deposit_entire_worktop_or_abort(account(1), None)
+        .build();
+
+    // Act
+    let diagnostics = lint(&manifest.instructions, &LintConfig::default());
+
+    // Assert
+    assert_eq!(rules(&diagnostics), vec![LintRule::DanglingBucket]);
+    assert_eq!(diagnostics[0].instruction_index, Some(2));
+    assert_eq!(diagnostics[0].severity, LintSeverity::Error);
+}
+
+#[test]
+fn deposit_without_withdrawal_is_reported() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let diagnostics = lint(&manifest.instructions, &LintConfig::default());
+
+    // Assert
+    assert_eq!(
+        rules(&diagnostics),
+        vec![LintRule::DepositWithoutWithdrawal]
+    );
+    assert_eq!(diagnostics[0].instruction_index, Some(1));
+}
+
+#[test]
+fn undropped_proof_is_reported() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .create_proof_from_auth_zone_of_all(XRD, "proof")
+        .build();
+
+    // Act
+    let diagnostics = lint(&manifest.instructions, &LintConfig::default());
+
+    // Assert
+    assert_eq!(rules(&diagnostics), vec![LintRule::UndroppedProof]);
+    assert_eq!(diagnostics[0].instruction_index, Some(1));
+    assert_eq!(diagnostics[0].severity, LintSeverity::Info);
+}
+
+#[test]
+fn disabled_rules_are_not_reported_and_severities_can_be_overridden() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_auth_zone_of_all(XRD, "proof")
+        .build();
+    let config = LintConfig {
+        rules: indexmap! {
+            LintRule::UndroppedProof => LintSeverity::Error
+        },
+    };
+
+    // Act
+    let diagnostics = lint(&manifest.instructions, &config);
+
+    // Assert
+    assert_eq!(rules(&diagnostics), vec![LintRule::UndroppedProof]);
+    assert_eq!(diagnostics[0].severity, LintSeverity::Error);
+}
+
+fn rules(diagnostics: &[LintDiagnostic]) -> Vec<LintRule> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.rule)
+        .collect()
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
@@ -18,6 +18,9 @@
 use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_toolkit::functions::manifest::*;
+use radix_engine_toolkit::transaction_types::{
+    SimpleTransfer, SimpleTransferAmount, SimpleTransferOrSummary,
+};
 use radix_transactions::prelude::*;
 use scrypto::prelude::*;
 
@@ -1097,6 +1100,389 @@ fn assertions_are_added_at_expected_indices_even_with_other_things_need_to_be_ad
     assert_eq!(modified_manifest.instructions, expected_instructions);
 }
 
+#[test]
+fn simple_transfer_is_detected_by_the_fast_path() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "xrd")
+        .try_deposit_or_abort(account(2), None, "xrd")
+        .build();
+
+    // Act
+    let simple_transfer =
+        radix_engine_toolkit::functions::manifest::simple_transfer(&manifest);
+
+    // Assert
+    assert_eq!(
+        simple_transfer,
+        Some(SimpleTransfer {
+            from: account(1),
+            to: account(2),
+            resource: XRD,
+            amount: SimpleTransferAmount::Amount(dec!("10")),
+        })
+    )
+}
+
+#[test]
+fn simple_transfer_with_a_lock_fee_is_detected_by_the_fast_path() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_all_from_worktop(XRD, "xrd")
+        .deposit(account(2), "xrd")
+        .build();
+
+    // Act
+    let simple_transfer =
+        radix_engine_toolkit::functions::manifest::simple_transfer(&manifest);
+
+    // Assert
+    assert_eq!(
+        simple_transfer,
+        Some(SimpleTransfer {
+            from: account(1),
+            to: account(2),
+            resource: XRD,
+            amount: SimpleTransferAmount::Amount(dec!("10")),
+        })
+    )
+}
+
+#[test]
+fn take_of_a_different_amount_is_not_a_simple_transfer() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 5, "xrd")
+        .try_deposit_or_abort(account(2), None, "xrd")
+        .build();
+
+    // Act
+    let simple_transfer =
+        radix_engine_toolkit::functions::manifest::simple_transfer(&manifest);
+
+    // Assert
+    assert_eq!(simple_transfer, None)
+}
+
+#[test]
+fn non_simple_transfers_fall_back_to_the_full_summary() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let result =
+        radix_engine_toolkit::functions::manifest::simple_transfer_or_summary(
+            &manifest,
+        );
+
+    // Assert
+    let SimpleTransferOrSummary::Summary(summary) = result else {
+        panic!("Expected the full summary to be used")
+    };
+    assert_eq!(summary.accounts_withdrawn_from, indexset![account(1)]);
+    assert_eq!(summary.accounts_deposited_into, indexset![account(2)]);
+}
+
+#[test]
+fn only_allocated_addresses_are_network_bound() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .create_proof_from_account_of_amount(access_controller(1), XRD, 1)
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let network_bound_addresses =
+        radix_engine_toolkit::functions::manifest::network_bound_addresses(
+            &manifest,
+        );
+
+    // Assert
+    assert_eq!(
+        network_bound_addresses,
+        indexset![access_controller(1).into()]
+    );
+}
+
+#[test]
+fn addresses_of_native_packages_are_not_network_bound() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            LOCKER_PACKAGE,
+            ACCOUNT_LOCKER_BLUEPRINT,
+            ACCOUNT_LOCKER_INSTANTIATE_SIMPLE_IDENT,
+            manifest_args!(true),
+        )
+        .build();
+
+    // Act
+    let network_bound_addresses =
+        radix_engine_toolkit::functions::manifest::network_bound_addresses(
+            &manifest,
+        );
+
+    // Assert
+    assert_eq!(network_bound_addresses, indexset![]);
+}
+
+#[test]
+fn required_signers_are_the_keys_of_the_accounts_requiring_auth() {
+    // Arrange
+    let public_key = |id: u64| -> PublicKey {
+        Secp256k1PrivateKey::from_u64(id).unwrap().public_key().into()
+    };
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let required_signers = required_signers(
+        &manifest,
+        &[public_key(2), public_key(1), public_key(3), public_key(1)],
+    );
+
+    // Assert
+    assert_eq!(required_signers, vec![public_key(1)]);
+}
+
+#[test]
+fn manifest_statistics_count_instructions_addresses_and_blobs() {
+    // Arrange
+    let blob = vec![0u8; 10];
+    let mut manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .try_deposit_or_abort(account(2), None, "bucket")
+        .call_method(
+            account(2),
+            "some_method",
+            manifest_args!((1u8, (2u8, vec![3u8]))),
+        )
+        .build();
+    manifest
+        .blobs
+        .insert(scrypto::prelude::hash(&blob), blob);
+
+    // Act
+    let statistics = statistics(&manifest);
+
+    // Assert
+    assert_eq!(
+        statistics.instruction_counts,
+        indexmap! {
+            "CallMethod" => 4,
+            "TakeAllFromWorktop" => 1,
+        }
+    );
+    assert_eq!(statistics.component_count, 2);
+    assert_eq!(statistics.package_count, 0);
+    assert_eq!(statistics.resource_count, 1);
+    assert_eq!(statistics.max_value_depth, 5);
+    assert_eq!(statistics.blob_count, 1);
+    assert_eq!(statistics.blobs_size, 10);
+    assert_eq!(
+        statistics.compiled_size,
+        Some(compile(&manifest).unwrap().len())
+    );
+}
+
+#[test]
+fn setting_the_fee_payer_inserts_a_lock_fee_when_there_is_none() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let modified_manifest =
+        set_fee_payer(&manifest, account(3), dec!("10")).unwrap();
+
+    // Assert
+    let expected_manifest = ManifestBuilder::new()
+        .lock_fee(account(3), dec!("10"))
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+    assert_eq!(modified_manifest, expected_manifest);
+}
+
+#[test]
+fn setting_the_fee_payer_replaces_an_existing_lock_fee() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), dec!("5"))
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let modified_manifest =
+        set_fee_payer(&manifest, account(2), dec!("10")).unwrap();
+
+    // Assert
+    let expected_manifest = ManifestBuilder::new()
+        .lock_fee(account(2), dec!("10"))
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+    assert_eq!(modified_manifest, expected_manifest);
+}
+
+#[test]
+fn setting_the_fee_payer_updates_the_amount_of_a_lock_fee_and_withdraw_of_the_same_account(
+) {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw(account(1), dec!("5"), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let modified_manifest =
+        set_fee_payer(&manifest, account(1), dec!("10")).unwrap();
+
+    // Assert
+    let expected_manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw(account(1), dec!("10"), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+    assert_eq!(modified_manifest, expected_manifest);
+}
+
+#[test]
+fn setting_the_fee_payer_splits_a_lock_fee_and_withdraw_of_a_different_account(
+) {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw(account(1), dec!("5"), XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .try_deposit_or_abort(account(2), None, "bucket")
+        .build();
+
+    // Act
+    let modified_manifest =
+        set_fee_payer(&manifest, account(3), dec!("10")).unwrap();
+
+    // Assert
+    let expected_manifest = ManifestBuilder::new()
+        .lock_fee(account(3), dec!("10"))
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_all_from_worktop(XRD, "bucket")
+        .try_deposit_or_abort(account(2), None, "bucket")
+        .build();
+    assert_eq!(modified_manifest, expected_manifest);
+}
+
+#[test]
+fn setting_the_fee_payer_keeps_a_lock_contingent_fee_contingent() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_contingent_fee(account(1), dec!("5"))
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let modified_manifest =
+        set_fee_payer(&manifest, account(2), dec!("10")).unwrap();
+
+    // Assert
+    let expected_manifest = ManifestBuilder::new()
+        .lock_contingent_fee(account(2), dec!("10"))
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+    assert_eq!(modified_manifest, expected_manifest);
+}
+
+#[test]
+fn setting_the_fee_payer_replaces_a_lock_fee_that_is_not_the_first_instruction(
+) {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .lock_fee(account(1), dec!("5"))
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let modified_manifest =
+        set_fee_payer(&manifest, account(3), dec!("10")).unwrap();
+
+    // Assert
+    let expected_manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .lock_fee(account(3), dec!("10"))
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+    assert_eq!(modified_manifest, expected_manifest);
+}
+
+#[test]
+fn setting_the_fee_payer_of_a_manifest_with_multiple_fee_locks_is_an_error() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), dec!("5"))
+        .withdraw_from_account(account(1), XRD, 10)
+        .lock_contingent_fee(account(2), dec!("5"))
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let result = set_fee_payer(&manifest, account(3), dec!("10"));
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(ManifestModificationError::MultipleFeeLocks {
+            instruction_indices: vec![0, 2]
+        })
+    );
+}
+
+#[test]
+fn blob_references_are_resolved_from_the_blobs_of_the_manifest() {
+    // Arrange
+    let blob = vec![1u8, 2, 3];
+    let expected_hash = scrypto::prelude::hash(&blob);
+    let mut manifest = ManifestBuilder::new()
+        .call_method(
+            account(1),
+            "some_method",
+            manifest_args!(ManifestBlobRef(expected_hash.0)),
+        )
+        .build();
+
+    // Act
+    let missing_blob_result = validate_blob_references(&manifest);
+    let blob_hash = add_blob(&mut manifest, blob.clone());
+    let result = validate_blob_references(&manifest);
+
+    // Assert
+    assert_eq!(blob_references(&manifest), indexset! { expected_hash });
+    assert_eq!(
+        missing_blob_result,
+        Err(BlobReferenceError::BlobNotFound(expected_hash))
+    );
+    assert_eq!(result, Ok(()));
+    assert_eq!(blob_hash, expected_hash);
+    assert_eq!(get_blob(&manifest, &blob_hash), Some(&blob));
+}
+
 fn account(id: u64) -> ComponentAddress {
     ComponentAddress::virtual_account_from_public_key(
         &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
@@ -1108,3 +1494,46 @@ fn access_controller(id: u64) -> ComponentAddress {
     bytes[0] = EntityType::GlobalAccessController as u8;
     ComponentAddress::new_or_panic(bytes)
 }
+
+#[test]
+fn manifest_string_compile_errors_are_located() {
+    // Arrange
+    let manifest_string = r#"
+DROP_ALL_PROOFS;
+# A comment; with a semicolon.
+DROP_AUTH_ZONE_PROOFS;
+    RETURN_TO_WORKTOP
+        Bucket("a;b");
+DROP_ALL_PROOFS;
+"#;
+
+    // Act
+    let error =
+        compile_string(manifest_string, &NetworkDefinition::simulator())
+            .unwrap_err();
+
+    // Assert
+    assert_eq!(
+        error.location,
+        Some(ManifestSourceLocation {
+            instruction_index: 2,
+            line: 5,
+            column: 5,
+        })
+    );
+}
+
+#[test]
+fn manifest_strings_that_compile_have_no_error_location() {
+    // Arrange
+    let manifest_string = "DROP_ALL_PROOFS;\nDROP_AUTH_ZONE_PROOFS;\n";
+
+    // Act
+    let location = locate_compile_error(
+        manifest_string,
+        &NetworkDefinition::simulator(),
+    );
+
+    // Assert
+    assert_eq!(location, None);
+}
@@ -0,0 +1,82 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::manifest::statically_validate;
+use radix_engine_toolkit::test_utils::differential::*;
+use radix_engine_toolkit::test_utils::manifest_generator::*;
+use scrypto::prelude::*;
+
+const SEEDS: std::ops::Range<u64> = 0..256;
+
+#[test]
+fn manifest_generator_is_deterministic() {
+    // Arrange
+    let mut generator1 = ManifestGenerator::new(42);
+    let mut generator2 = ManifestGenerator::new(42);
+
+    // Act
+    let manifests1 = (0..8).map(|_| generator1.generate()).collect::<Vec<_>>();
+    let manifests2 = (0..8).map(|_| generator2.generate()).collect::<Vec<_>>();
+
+    // Assert
+    assert_eq!(manifests1, manifests2);
+}
+
+#[test]
+fn manifests_with_different_seeds_are_different() {
+    // Arrange
+    let mut generator1 = ManifestGenerator::new(1);
+    let mut generator2 = ManifestGenerator::new(2);
+
+    // Act
+    let manifest1 = generator1.generate();
+    let manifest2 = generator2.generate();
+
+    // Assert
+    assert_ne!(manifest1, manifest2);
+}
+
+#[test]
+fn generated_manifests_are_statically_valid() {
+    for seed in SEEDS {
+        // Arrange
+        let manifest = ManifestGenerator::new(seed).generate();
+
+        // Act
+        let result = statically_validate(&manifest);
+
+        // Assert
+        assert!(result.is_ok(), "Seed {seed} failed with {result:?}");
+    }
+}
+
+#[test]
+fn generated_manifests_are_stable_across_round_trips() {
+    for seed in SEEDS {
+        // Arrange
+        let manifest = ManifestGenerator::new(seed).generate();
+
+        // Act
+        let result = check_round_trip_stability(
+            &manifest,
+            &NetworkDefinition::simulator(),
+        );
+
+        // Assert
+        assert!(result.is_ok(), "Seed {seed} failed with {result:?}");
+    }
+}
@@ -0,0 +1,143 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::manifest_template::*;
+use scrypto::prelude::*;
+
+fn template() -> ManifestTemplate {
+    ManifestTemplate {
+        manifest: r#"
+            CALL_METHOD
+                ${account}
+                "withdraw"
+                Address("resource_sim1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxakj8n3")
+                ${amount}
+            ;
+        "#
+        .to_owned(),
+        parameters: indexmap! {
+            "account".to_owned() => ManifestAstValueKind::Address,
+            "amount".to_owned() => ManifestAstValueKind::Decimal,
+        },
+    }
+}
+
+#[test]
+fn template_is_instantiated_with_arguments_of_the_declared_kinds() {
+    // Arrange
+    let template = ManifestTemplate {
+        manifest: "CALL_METHOD ${account} \"withdraw\" ${amount};".to_owned(),
+        parameters: indexmap! {
+            "account".to_owned() => ManifestAstValueKind::Address,
+            "amount".to_owned() => ManifestAstValueKind::Decimal,
+        },
+    };
+    let arguments = indexmap! {
+        "account".to_owned() => "Address(\"account_sim1\")".to_owned(),
+        "amount".to_owned() => "Decimal(\"10\")".to_owned(),
+    };
+
+    // Act
+    let manifest = instantiate(&template, &arguments);
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Ok("CALL_METHOD Address(\"account_sim1\") \"withdraw\" \
+            Decimal(\"10\");"
+            .to_owned())
+    );
+}
+
+#[test]
+fn arguments_of_a_different_kind_are_rejected() {
+    // Arrange
+    let template = template();
+    let arguments = indexmap! {
+        "account".to_owned() => "Address(\"account_sim1\")".to_owned(),
+        "amount".to_owned() => "10u32".to_owned(),
+    };
+
+    // Act
+    let manifest = instantiate(&template, &arguments);
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Err(ManifestTemplateError::ValueKindMismatch {
+            parameter: "amount".to_owned(),
+            expected: ManifestAstValueKind::Decimal,
+            actual: ManifestAstValueKind::U32,
+        })
+    );
+}
+
+#[test]
+fn arguments_that_end_the_instruction_are_rejected() {
+    // Arrange
+    let template = template();
+    let arguments = indexmap! {
+        "account".to_owned() => "Address(\"account_sim1\")".to_owned(),
+        "amount".to_owned() => "Decimal(\"10\"); DROP_ALL_PROOFS".to_owned(),
+    };
+
+    // Act
+    let manifest = instantiate(&template, &arguments);
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Err(ManifestTemplateError::InvalidArgument("amount".to_owned()))
+    );
+}
+
+#[test]
+fn arguments_that_comment_out_the_rest_of_the_instruction_are_rejected() {
+    // Arrange
+    let template = template();
+    let arguments = indexmap! {
+        "account".to_owned() => "Address(\"account_sim1\")".to_owned(),
+        "amount".to_owned() => "Decimal(\"10\") # ".to_owned(),
+    };
+
+    // Act
+    let manifest = instantiate(&template, &arguments);
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Err(ManifestTemplateError::InvalidArgument("amount".to_owned()))
+    );
+}
+
+#[test]
+fn missing_arguments_are_rejected() {
+    // Arrange
+    let template = template();
+    let arguments = indexmap! {
+        "account".to_owned() => "Address(\"account_sim1\")".to_owned(),
+    };
+
+    // Act
+    let manifest = instantiate(&template, &arguments);
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Err(ManifestTemplateError::MissingArgument("amount".to_owned()))
+    );
+}
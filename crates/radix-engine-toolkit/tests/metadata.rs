@@ -0,0 +1,75 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::metadata::*;
+use scrypto::object_modules::metadata::MetadataValue;
+use scrypto::prelude::*;
+
+#[test]
+fn metadata_value_can_be_encoded_and_decoded() {
+    // Arrange
+    let value = MetadataValue::UrlArray(vec![
+        UncheckedUrl::of("https://www.radixdlt.com"),
+        UncheckedUrl::of("https://www.example.com"),
+    ]);
+
+    // Act
+    let encoded_value = encode_metadata_value(&value).unwrap();
+    let decoded_value = decode_metadata_value(&encoded_value).unwrap();
+
+    // Assert
+    assert_eq!(encoded_value, scrypto_encode(&value).unwrap());
+    assert_eq!(decoded_value, value);
+}
+
+#[test]
+fn manifest_sbor_encoded_metadata_value_can_be_decoded() {
+    // Arrange
+    let value = MetadataValue::GlobalAddress(XRD.into());
+    let encoded_value = manifest_encode(&value).unwrap();
+
+    // Act
+    let decoded_value = decode_metadata_value(encoded_value).unwrap();
+
+    // Assert
+    assert_eq!(decoded_value, value);
+}
+
+#[test]
+fn metadata_value_can_be_converted_to_and_from_a_manifest_value() {
+    // Arrange
+    let value = MetadataValue::Instant(Instant::new(1_700_000_000));
+
+    // Act
+    let manifest_value = metadata_value_to_manifest_value(&value);
+    let converted_value = metadata_value_from_manifest_value(&manifest_value);
+
+    // Assert
+    assert_eq!(converted_value, Some(value));
+}
+
+#[test]
+fn manifest_value_that_is_not_a_metadata_value_is_rejected() {
+    // Arrange
+    let manifest_value = ManifestValue::U128 { value: 1 };
+
+    // Act
+    let converted_value = metadata_value_from_manifest_value(&manifest_value);
+
+    // Assert
+    assert_eq!(converted_value, None);
+}
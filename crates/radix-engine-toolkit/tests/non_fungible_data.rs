@@ -0,0 +1,162 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::non_fungible_data::*;
+use sbor::generate_full_schema_from_single_type;
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor)]
+struct Ticket {
+    name: String,
+    seat: u32,
+}
+
+fn ticket_definition() -> NonFungibleDataDefinition {
+    let (type_id, schema) =
+        generate_full_schema_from_single_type::<Ticket, ScryptoCustomSchema>();
+    NonFungibleDataDefinition {
+        type_id,
+        schema: schema.v1().clone(),
+        mutable_fields: indexset!["seat".to_owned()],
+    }
+}
+
+#[test]
+fn non_fungible_data_is_encoded_from_its_fields_in_any_order() {
+    // Arrange
+    let fields = indexmap! {
+        "seat".to_owned() => ScryptoValue::U32 { value: 12 },
+        "name".to_owned() => ScryptoValue::String {
+            value: "Concert".to_owned(),
+        },
+    };
+
+    // Act
+    let encoded =
+        encode_non_fungible_data(fields, &ticket_definition()).unwrap();
+
+    // Assert
+    assert_eq!(
+        encoded,
+        scrypto_encode(&Ticket {
+            name: "Concert".to_owned(),
+            seat: 12,
+        })
+        .unwrap()
+    );
+}
+
+#[test]
+fn decoded_non_fungible_data_marks_the_mutable_fields() {
+    // Arrange
+    let data = scrypto_encode(&Ticket {
+        name: "Concert".to_owned(),
+        seat: 12,
+    })
+    .unwrap();
+
+    // Act
+    let fields = decode_non_fungible_data(&data, &ticket_definition()).unwrap();
+
+    // Assert
+    let fields = fields
+        .into_iter()
+        .map(|field| (field.name, field.mutable, field.value))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        vec![
+            (
+                "name".to_owned(),
+                false,
+                ScryptoValue::String {
+                    value: "Concert".to_owned(),
+                },
+            ),
+            ("seat".to_owned(), true, ScryptoValue::U32 { value: 12 }),
+        ]
+    );
+}
+
+#[test]
+fn missing_and_unknown_fields_are_rejected() {
+    // Arrange
+    let missing = indexmap! {
+        "name".to_owned() => ScryptoValue::String {
+            value: "Concert".to_owned(),
+        },
+    };
+    let unknown = indexmap! {
+        "name".to_owned() => ScryptoValue::String {
+            value: "Concert".to_owned(),
+        },
+        "seat".to_owned() => ScryptoValue::U32 { value: 12 },
+        "row".to_owned() => ScryptoValue::U32 { value: 1 },
+    };
+
+    // Act
+    let missing = encode_non_fungible_data(missing, &ticket_definition());
+    let unknown = encode_non_fungible_data(unknown, &ticket_definition());
+
+    // Assert
+    assert_eq!(
+        missing,
+        Err(NonFungibleDataError::MissingField("seat".to_owned()))
+    );
+    assert_eq!(
+        unknown,
+        Err(NonFungibleDataError::UnknownField("row".to_owned()))
+    );
+}
+
+#[test]
+fn field_of_the_wrong_type_fails_the_schema_validation() {
+    // Arrange
+    let fields = indexmap! {
+        "name".to_owned() => ScryptoValue::String {
+            value: "Concert".to_owned(),
+        },
+        "seat".to_owned() => ScryptoValue::U64 { value: 12 },
+    };
+
+    // Act
+    let encoded = encode_non_fungible_data(fields, &ticket_definition());
+
+    // Assert
+    assert_eq!(encoded, Err(NonFungibleDataError::SchemaValidationError));
+}
+
+#[test]
+fn mutable_field_that_does_not_exist_is_rejected() {
+    // Arrange
+    let mut definition = ticket_definition();
+    definition.mutable_fields.insert("row".to_owned());
+    let data = scrypto_encode(&Ticket {
+        name: "Concert".to_owned(),
+        seat: 12,
+    })
+    .unwrap();
+
+    // Act
+    let fields = decode_non_fungible_data(&data, &definition);
+
+    // Assert
+    assert_eq!(
+        fields,
+        Err(NonFungibleDataError::UnknownMutableField("row".to_owned()))
+    );
+}
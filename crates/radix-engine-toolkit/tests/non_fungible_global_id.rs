@@ -0,0 +1,116 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::address::AddressValidationFailure;
+use radix_engine_toolkit::functions::non_fungible_global_id::*;
+use radix_engine_toolkit::functions::non_fungible_local_id::NonFungibleLocalIdError;
+use scrypto::prelude::*;
+
+fn encode_address<A: Into<GlobalAddress>>(
+    address: A,
+    network_id: u8,
+) -> String {
+    let network_definition = match network_id {
+        1 => NetworkDefinition::mainnet(),
+        _ => NetworkDefinition::stokenet(),
+    };
+    AddressBech32Encoder::new(&network_definition)
+        .encode(address.into().as_node_id().as_bytes())
+        .unwrap()
+}
+
+#[test]
+fn global_id_is_built_from_its_parts_and_parsed_back() {
+    // Arrange
+    let resource_address = encode_address(SECP256K1_SIGNATURE_RESOURCE, 1);
+
+    // Act
+    let built =
+        build_non_fungible_global_id(&resource_address, "#1#", 1).unwrap();
+    let parsed =
+        parse_non_fungible_global_id(&format!("{resource_address}:#1#"), 1)
+            .unwrap();
+
+    // Assert
+    assert_eq!(
+        built,
+        NonFungibleGlobalId::new(
+            SECP256K1_SIGNATURE_RESOURCE,
+            NonFungibleLocalId::integer(1)
+        )
+    );
+    assert_eq!(parsed, built);
+}
+
+#[test]
+fn global_id_on_another_network_is_rejected() {
+    // Arrange
+    let resource_address = encode_address(SECP256K1_SIGNATURE_RESOURCE, 2);
+
+    // Act
+    let result =
+        parse_non_fungible_global_id(&format!("{resource_address}:#1#"), 1);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(NonFungibleGlobalIdError::InvalidResourceAddress(vec![
+            AddressValidationFailure::NetworkMismatch {
+                expected: 1,
+                actual: 2
+            }
+        ]))
+    );
+}
+
+#[test]
+fn global_id_of_a_fungible_resource_is_rejected() {
+    // Arrange
+    let resource_address = encode_address(XRD, 1);
+
+    // Act
+    let result = build_non_fungible_global_id(&resource_address, "#1#", 1);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(NonFungibleGlobalIdError::InvalidResourceAddress(..))
+    ));
+}
+
+#[test]
+fn global_id_without_a_separator_or_with_an_invalid_local_id_is_rejected() {
+    // Arrange
+    let resource_address = encode_address(SECP256K1_SIGNATURE_RESOURCE, 1);
+
+    // Act
+    let no_separator = parse_non_fungible_global_id(&resource_address, 1);
+    let invalid_local_id =
+        build_non_fungible_global_id(&resource_address, "#one#", 1);
+
+    // Assert
+    assert_eq!(
+        no_separator,
+        Err(NonFungibleGlobalIdError::NoSeparator(resource_address))
+    );
+    assert_eq!(
+        invalid_local_id,
+        Err(NonFungibleGlobalIdError::InvalidLocalId(
+            NonFungibleLocalIdError::InvalidInteger("one".to_owned())
+        ))
+    );
+}
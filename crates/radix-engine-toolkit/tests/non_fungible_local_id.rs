@@ -0,0 +1,109 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::non_fungible_local_id::*;
+use scrypto::prelude::*;
+
+#[test]
+fn ids_of_all_types_survive_a_parse_format_round_trip() {
+    for string in [
+        "#1#",
+        "<ticket_1>",
+        "[c0de]",
+        "{0000000000000001-0000000000000002-0000000000000003-0000000000000004}",
+    ] {
+        // Act
+        let local_id = parse_non_fungible_local_id(string).unwrap();
+
+        // Assert
+        assert_eq!(format_non_fungible_local_id(&local_id), string);
+        assert_eq!(NonFungibleLocalId::from_str(string).unwrap(), local_id);
+    }
+}
+
+#[test]
+fn non_canonical_ids_are_formatted_canonically() {
+    // Act
+    let integer = parse_non_fungible_local_id("#007#").unwrap();
+    let bytes = parse_non_fungible_local_id("[C0DE]").unwrap();
+
+    // Assert
+    assert_eq!(format_non_fungible_local_id(&integer), "#7#");
+    assert_eq!(format_non_fungible_local_id(&bytes), "[c0de]");
+}
+
+#[test]
+fn ids_that_break_the_rules_are_rejected_with_the_broken_rule() {
+    // Arrange
+    let too_long = format!("<{}>", "a".repeat(65));
+
+    // Act & Assert
+    assert_eq!(
+        parse_non_fungible_local_id("ticket_1"),
+        Err(NonFungibleLocalIdError::InvalidDelimiters(
+            "ticket_1".to_owned()
+        ))
+    );
+    assert_eq!(
+        parse_non_fungible_local_id("#-1#"),
+        Err(NonFungibleLocalIdError::InvalidInteger("-1".to_owned()))
+    );
+    assert_eq!(
+        parse_non_fungible_local_id("<>"),
+        Err(NonFungibleLocalIdError::Empty)
+    );
+    assert_eq!(
+        parse_non_fungible_local_id(&too_long),
+        Err(NonFungibleLocalIdError::TooLong {
+            length: 65,
+            max_length: 64
+        })
+    );
+    assert_eq!(
+        parse_non_fungible_local_id("<ticket-1>"),
+        Err(NonFungibleLocalIdError::InvalidCharacter {
+            character: '-',
+            index: 6
+        })
+    );
+    assert_eq!(
+        parse_non_fungible_local_id("[c0d]"),
+        Err(NonFungibleLocalIdError::InvalidHex("c0d".to_owned()))
+    );
+    assert_eq!(
+        parse_non_fungible_local_id("{0000000000000001}"),
+        Err(NonFungibleLocalIdError::InvalidRuid(
+            "0000000000000001".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn id_of_an_unexpected_type_fails_the_validation() {
+    // Act
+    let result =
+        validate_non_fungible_local_id("#1#", Some(NonFungibleIdType::String));
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(NonFungibleLocalIdError::IdTypeMismatch {
+            expected: NonFungibleIdType::String,
+            actual: NonFungibleIdType::Integer,
+        })
+    );
+}
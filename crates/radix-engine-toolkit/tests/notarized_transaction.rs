@@ -15,7 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_transactions::model::NotarySignatureV1;
+use radix_transactions::prelude::Signer;
 use radix_transactions::validation::ValidationConfig;
+use scrypto::prelude::hash;
 mod test_data;
 
 #[test]
@@ -84,3 +87,84 @@ fn notarized_transaction_can_be_statically_validated() {
     // Assert
     assert!(validation_result.is_ok())
 }
+
+#[test]
+fn notarized_transaction_hash_can_be_obtained_from_payload() {
+    // Arrange
+    let notarized_transaction = test_data::notarized_transaction();
+    let compiled =
+        radix_engine_toolkit::functions::notarized_transaction::compile(
+            &notarized_transaction,
+        )
+        .unwrap();
+
+    // Act
+    let hash =
+        radix_engine_toolkit::functions::notarized_transaction::hash_from_payload(compiled)
+            .unwrap();
+
+    // Assert
+    let expected =
+        radix_engine_toolkit::functions::notarized_transaction::hash(
+            &notarized_transaction,
+        )
+        .unwrap();
+    assert_eq!(hash.hash, expected.hash);
+    assert_eq!(hash.id, expected.id);
+}
+
+#[test]
+fn signatures_of_notarized_transaction_can_be_verified_from_payload() {
+    // Arrange
+    let notarized_transaction = test_data::notarized_transaction();
+    let compiled =
+        radix_engine_toolkit::functions::notarized_transaction::compile(
+            &notarized_transaction,
+        )
+        .unwrap();
+
+    // Act
+    let verification =
+        radix_engine_toolkit::functions::notarized_transaction::verify_signatures_from_payload(compiled)
+            .unwrap();
+
+    // Assert
+    assert!(verification.notary_signature_is_valid);
+    assert_eq!(
+        verification
+            .intent_signatures
+            .iter()
+            .map(|signature| (signature.public_key, signature.is_valid))
+            .collect::<Vec<_>>(),
+        vec![
+            (Some(test_data::private_key2().public_key().into()), true),
+            (Some(test_data::private_key3().public_key().into()), true),
+            (Some(test_data::private_key4().public_key().into()), true),
+        ]
+    );
+}
+
+#[test]
+fn notary_signature_over_another_hash_is_invalid() {
+    // Arrange
+    let mut notarized_transaction = test_data::notarized_transaction();
+    notarized_transaction.notary_signature = NotarySignatureV1(
+        test_data::private_key1()
+            .sign_without_public_key(&hash("Hello World"))
+            .into(),
+    );
+
+    // Act
+    let verification =
+        radix_engine_toolkit::functions::notarized_transaction::verify_signatures(
+            &notarized_transaction,
+        )
+        .unwrap();
+
+    // Assert
+    assert!(!verification.notary_signature_is_valid);
+    assert!(verification
+        .intent_signatures
+        .iter()
+        .all(|signature| signature.is_valid));
+}
@@ -0,0 +1,366 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine::blueprints::native_schema::*;
+use radix_engine::transaction::ExecutionConfig;
+use radix_engine_interface::blueprints::package::{
+    PackageDefinition, PACKAGE_BLUEPRINT,
+};
+use radix_engine_toolkit::functions::package::*;
+use radix_transactions::data::from_decimal;
+use radix_transactions::prelude::{
+    ManifestBuilder, TestTransaction, TransactionManifestV1,
+};
+use scrypto::blueprints::account::*;
+use scrypto::prelude::*;
+use scrypto::radix_blueprint_schema_init::Receiver;
+use scrypto_test::prelude::LedgerSimulatorBuilder;
+use serde_json::json;
+
+#[test]
+fn blueprint_interfaces_can_be_extracted_from_a_package_definition() {
+    // Arrange
+    let package_definition =
+        manifest_encode(&*ACCOUNT_PACKAGE_DEFINITION).unwrap();
+
+    // Act
+    let blueprint_interfaces =
+        extract_blueprint_interfaces(package_definition).unwrap();
+
+    // Assert
+    let account = blueprint_interfaces.get(ACCOUNT_BLUEPRINT).unwrap();
+    let deposit = account.functions.get(ACCOUNT_DEPOSIT_IDENT).unwrap();
+    assert_eq!(deposit.receiver, Some(Receiver::SelfRefMut));
+    assert_eq!(
+        deposit.arguments,
+        vec![FieldSignature {
+            name: Some("bucket".to_owned()),
+            type_signature: TypeSignature::Value(ManifestValueKind::Custom(
+                ManifestCustomValueKind::Bucket
+            )),
+        }]
+    );
+
+    let create = account.functions.get(ACCOUNT_CREATE_IDENT).unwrap();
+    assert_eq!(create.receiver, None);
+    assert!(create.arguments.is_empty());
+}
+
+#[test]
+fn scrypto_encoded_package_definition_can_be_decoded() {
+    // Arrange
+    let package_definition =
+        scrypto_encode(&*ACCOUNT_PACKAGE_DEFINITION).unwrap();
+
+    // Act
+    let blueprint_interfaces = extract_blueprint_interfaces(package_definition);
+
+    // Assert
+    assert!(blueprint_interfaces.is_ok())
+}
+
+#[test]
+fn invalid_package_definition_is_rejected() {
+    // Arrange
+    let package_definition = [0x4d, 0x00];
+
+    // Act
+    let blueprint_interfaces = extract_blueprint_interfaces(package_definition);
+
+    // Assert
+    assert!(matches!(
+        blueprint_interfaces,
+        Err(PackageDefinitionError::DecodeError(..))
+    ))
+}
+
+#[test]
+fn call_arguments_can_be_built_from_loosely_typed_json() {
+    // Arrange
+    let encoder = AddressBech32Encoder::for_simulator();
+    let xrd = encoder.encode(XRD.as_node_id().as_bytes()).unwrap();
+
+    // Act
+    let arguments = build_call_arguments(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_WITHDRAW_IDENT,
+        &[json!(xrd), json!(10)],
+        &AddressBech32Decoder::for_simulator(),
+    );
+
+    // Assert
+    assert_eq!(
+        arguments,
+        Ok(ManifestValue::Tuple {
+            fields: vec![
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Address(
+                        ManifestAddress::Static(XRD.into_node_id())
+                    )
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Decimal(from_decimal(dec!(10)))
+                },
+            ]
+        })
+    )
+}
+
+#[test]
+fn call_arguments_encode_null_options_as_none() {
+    // Arrange
+    let arguments = [json!(0), json!(null)];
+
+    // Act
+    let arguments = build_call_arguments(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT,
+        &arguments,
+        &AddressBech32Decoder::for_simulator(),
+    );
+
+    // Assert
+    assert_eq!(
+        arguments,
+        Ok(ManifestValue::Tuple {
+            fields: vec![
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Bucket(ManifestBucket(0))
+                },
+                ManifestValue::Enum {
+                    discriminator: OPTION_VARIANT_NONE,
+                    fields: vec![]
+                },
+            ]
+        })
+    )
+}
+
+#[test]
+fn call_arguments_reject_decimals_with_too_many_decimal_places() {
+    // Arrange
+    let encoder = AddressBech32Encoder::for_simulator();
+    let xrd = encoder.encode(XRD.as_node_id().as_bytes()).unwrap();
+
+    // Act
+    let arguments = build_call_arguments(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_WITHDRAW_IDENT,
+        &[json!(xrd), json!("0.0000000000000000001")],
+        &AddressBech32Decoder::for_simulator(),
+    );
+
+    // Assert
+    assert_eq!(
+        arguments,
+        Err(CallArgumentsError::InvalidValue {
+            path: "$.1".to_owned(),
+            expected: "Decimal".to_owned()
+        })
+    )
+}
+
+#[test]
+fn call_arguments_reject_addresses_of_the_wrong_entity_type() {
+    // Arrange
+    let encoder = AddressBech32Encoder::for_simulator();
+    let faucet = encoder.encode(FAUCET.as_node_id().as_bytes()).unwrap();
+
+    // Act
+    let arguments = build_call_arguments(
+        &ACCOUNT_PACKAGE_DEFINITION,
+        ACCOUNT_BLUEPRINT,
+        ACCOUNT_WITHDRAW_IDENT,
+        &[json!(faucet), json!("1")],
+        &AddressBech32Decoder::for_simulator(),
+    );
+
+    // Assert
+    assert_eq!(
+        arguments,
+        Err(CallArgumentsError::InvalidAddress {
+            path: "$.0".to_owned(),
+            address: faucet
+        })
+    )
+}
+
+#[test]
+fn publish_package_manifest_registers_the_code_as_a_blob() {
+    // Arrange
+    let code = vec![1u8, 2, 3];
+    let package_definition =
+        manifest_encode(&*ACCOUNT_PACKAGE_DEFINITION).unwrap();
+
+    // Act
+    let PublishPackageManifest {
+        manifest,
+        code_hash,
+        schema_hashes,
+    } = build_publish_package_manifest(
+        code.clone(),
+        package_definition,
+        MetadataInit::default(),
+        OwnerRole::None,
+    )
+    .unwrap();
+
+    // Assert
+    let expected_manifest = ManifestBuilder::new()
+        .publish_package_advanced(
+            None,
+            code.clone(),
+            ACCOUNT_PACKAGE_DEFINITION.clone(),
+            MetadataInit::default(),
+            OwnerRole::None,
+        )
+        .build();
+    let account_schema = &ACCOUNT_PACKAGE_DEFINITION
+        .blueprints
+        .get(ACCOUNT_BLUEPRINT)
+        .unwrap()
+        .schema
+        .schema;
+    assert_eq!(manifest, expected_manifest);
+    assert_eq!(code_hash, hash(&code));
+    assert_eq!(manifest.blobs.get(&code_hash), Some(&code));
+    assert_eq!(
+        schema_hashes.get(ACCOUNT_BLUEPRINT),
+        Some(&hash(scrypto_encode(account_schema).unwrap()))
+    );
+}
+
+#[test]
+fn package_hashes_are_the_hashes_of_the_code_and_schemas() {
+    // Arrange
+    let code = vec![1u8, 2, 3];
+
+    // Act
+    let PackageHashes {
+        code_hash,
+        schema_hashes,
+    } = package_hashes(&code, &ACCOUNT_PACKAGE_DEFINITION).unwrap();
+
+    // Assert
+    assert_eq!(code_hash, hash(&code));
+    assert_eq!(
+        schema_hashes.keys().collect::<Vec<_>>(),
+        ACCOUNT_PACKAGE_DEFINITION
+            .blueprints
+            .keys()
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn predicted_package_addresses_depend_on_the_allocation_index() {
+    // Arrange
+    let intent_hash = hash([0u8; 32]);
+
+    // Act
+    let first_address = predict_package_address(&intent_hash, 0);
+    let second_address = predict_package_address(&intent_hash, 1);
+
+    // Assert
+    assert_eq!(
+        first_address.as_node_id().entity_type(),
+        Some(EntityType::GlobalPackage)
+    );
+    assert_eq!(first_address, predict_package_address(&intent_hash, 0));
+    assert_ne!(first_address, second_address);
+}
+
+#[test]
+fn predicted_package_address_matches_the_address_of_the_published_package() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let mut publish = |intent_hash: Hash| {
+        let transaction =
+            TestTransaction::new(publish_to_allocated_address(), intent_hash)
+                .prepare()
+                .unwrap();
+        *ledger
+            .execute_transaction(
+                transaction.get_executable(Default::default()),
+                ExecutionConfig::for_test_transaction(),
+            )
+            .expect_commit_success()
+            .new_package_addresses()
+            .first()
+            .unwrap()
+    };
+    let previewed_intent_hash = hash("previewed intent");
+    let submitted_intent_hash = hash("submitted intent");
+    let previewed_package_address = publish(previewed_intent_hash);
+
+    // Act
+    let allocation_index = package_allocation_index(
+        &previewed_intent_hash,
+        &previewed_package_address,
+    )
+    .unwrap();
+    let predicted_package_address =
+        predict_package_address(&submitted_intent_hash, allocation_index);
+
+    // Assert
+    assert_eq!(predicted_package_address, publish(submitted_intent_hash));
+}
+
+#[test]
+fn allocation_index_of_an_address_not_allocated_by_the_transaction_is_none() {
+    // Arrange
+    let package_address = predict_package_address(&hash("some intent"), 0);
+
+    // Act
+    let allocation_index =
+        package_allocation_index(&hash("another intent"), &package_address);
+
+    // Assert
+    assert_eq!(allocation_index, None);
+}
+
+/// A manifest that publishes a package without any blueprints to an address
+/// that it allocates.
+fn publish_to_allocated_address() -> TransactionManifestV1 {
+    // A module that only defines and exports the memory that the engine
+    // requires.
+    let code = vec![
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x05, 0x03, 0x01, 0x00,
+        0x01, 0x07, 0x0a, 0x01, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02,
+        0x00,
+    ];
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .allocate_global_address(
+            PACKAGE_PACKAGE,
+            PACKAGE_BLUEPRINT,
+            "reservation",
+            "address",
+        )
+        .publish_package_advanced(
+            Some("reservation".to_owned()),
+            code,
+            PackageDefinition::default(),
+            MetadataInit::default(),
+            OwnerRole::None,
+        )
+        .build()
+}
@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::payload::*;
+use radix_engine_toolkit::functions::*;
+mod test_data;
+
+#[test]
+fn compiled_intent_is_detected_as_an_intent() {
+    // Arrange
+    let intent = test_data::intent();
+    let compiled = intent::compile(&intent).unwrap();
+
+    // Act
+    let decompiled = decompile_unknown(compiled);
+
+    // Assert
+    assert_eq!(decompiled, Ok(DecompiledPayload::Intent(intent)))
+}
+
+#[test]
+fn compiled_signed_intent_is_detected_as_a_signed_intent() {
+    // Arrange
+    let signed_intent = test_data::signed_intent();
+    let compiled = signed_intent::compile(&signed_intent).unwrap();
+
+    // Act
+    let decompiled = decompile_unknown(compiled);
+
+    // Assert
+    assert_eq!(
+        decompiled,
+        Ok(DecompiledPayload::SignedIntent(signed_intent))
+    )
+}
+
+#[test]
+fn compiled_notarized_transaction_is_detected_as_a_notarized_transaction() {
+    // Arrange
+    let notarized_transaction = test_data::notarized_transaction();
+    let compiled =
+        notarized_transaction::compile(&notarized_transaction).unwrap();
+
+    // Act
+    let decompiled = decompile_unknown(compiled).unwrap();
+
+    // Assert
+    assert_eq!(decompiled.kind(), PayloadKind::NotarizedTransaction);
+    assert_eq!(
+        decompiled,
+        DecompiledPayload::NotarizedTransaction(notarized_transaction)
+    )
+}
+
+#[test]
+fn invalid_payloads_fail_to_decompile() {
+    // Act
+    let decompiled = decompile_unknown([0x00, 0x01, 0x02]);
+
+    // Assert
+    assert!(decompiled.is_err())
+}
+
+#[test]
+fn instruction_summaries_follow_the_order_of_the_instructions() {
+    // Arrange
+    let intent = test_data::intent();
+
+    // Act
+    let summaries = instructions::summarize(&intent.instructions.0);
+
+    // Assert
+    assert_eq!(summaries.len(), intent.instructions.0.len());
+    for (summary, instruction) in
+        summaries.iter().zip(intent.instructions.0.iter())
+    {
+        assert_eq!(summary.kind, instructions::instruction_kind(instruction));
+    }
+}
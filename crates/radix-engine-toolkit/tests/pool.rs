@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::pool::*;
+use radix_engine_toolkit::functions::pool::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn contributing_to_a_two_resource_pool_passes_a_tuple_of_buckets() {
+    // Arrange
+    let account = account(1);
+    let pool = pool(EntityType::GlobalTwoResourcePool);
+    let [resource1, resource2] = [resource(1), resource(2)];
+    let expected =
+        ManifestBuilder::new()
+            .withdraw_from_account(account, resource1, 10)
+            .take_from_worktop(resource1, 10, "resource1")
+            .withdraw_from_account(account, resource2, 20)
+            .take_from_worktop(resource2, 20, "resource2")
+            .call_method_with_name_lookup(
+                pool,
+                TWO_RESOURCE_POOL_CONTRIBUTE_IDENT,
+                |lookup| {
+                    ((lookup.bucket("resource1"), lookup.bucket("resource2")),)
+                },
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+    // Act
+    let manifest = build_pool_manifest(
+        account,
+        &[PoolOperation::Contribute {
+            pool_address: pool,
+            contributions: [(resource1, dec!(10)), (resource2, dec!(20))]
+                .into_iter()
+                .collect(),
+        }],
+    );
+
+    // Assert
+    assert_eq!(manifest.unwrap().instructions, expected.instructions);
+}
+
+#[test]
+fn redeeming_pool_units_withdraws_takes_calls_and_deposits() {
+    // Arrange
+    let account = account(1);
+    let pool = pool(EntityType::GlobalMultiResourcePool);
+    let pool_units = resource(1);
+    let expected = ManifestBuilder::new()
+        .withdraw_from_account(account, pool_units, 5)
+        .take_from_worktop(pool_units, 5, "pool_units")
+        .call_method_with_name_lookup(
+            pool,
+            MULTI_RESOURCE_POOL_REDEEM_IDENT,
+            |lookup| (lookup.bucket("pool_units"),),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let manifest = build_pool_manifest(
+        account,
+        &[PoolOperation::Redeem {
+            pool_address: pool,
+            pool_unit_resource_address: pool_units,
+            amount: dec!(5),
+        }],
+    );
+
+    // Assert
+    assert_eq!(manifest.unwrap().instructions, expected.instructions);
+}
+
+#[test]
+fn contribution_count_must_match_the_pool() {
+    // Arrange
+    let account = account(1);
+    let pool = pool(EntityType::GlobalOneResourcePool);
+
+    // Act
+    let manifest = build_pool_manifest(
+        account,
+        &[PoolOperation::Contribute {
+            pool_address: pool,
+            contributions: [(resource(1), dec!(1)), (resource(2), dec!(1))]
+                .into_iter()
+                .collect(),
+        }],
+    );
+
+    // Assert
+    assert_eq!(
+        manifest,
+        Err(PoolOperationError::InvalidContributionCount {
+            expected: 1,
+            actual: 2
+        })
+    );
+}
+
+#[test]
+fn operations_on_components_that_are_not_pools_are_rejected() {
+    // Arrange
+    let account = account(1);
+
+    // Act
+    let manifest = build_pool_manifest(
+        account,
+        &[PoolOperation::Redeem {
+            pool_address: account,
+            pool_unit_resource_address: resource(1),
+            amount: dec!(1),
+        }],
+    );
+
+    // Assert
+    assert_eq!(manifest, Err(PoolOperationError::NotAPool(account)));
+}
+
+fn pool(entity_type: EntityType) -> ComponentAddress {
+    ComponentAddress::new_or_panic([entity_type as u8; NodeId::LENGTH])
+}
+
+fn resource(id: u8) -> ResourceAddress {
+    let mut node_id = [id; NodeId::LENGTH];
+    node_id[0] = EntityType::GlobalFungibleResourceManager as u8;
+    ResourceAddress::new_or_panic(node_id)
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
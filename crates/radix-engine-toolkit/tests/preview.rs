@@ -0,0 +1,254 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine::transaction::*;
+use radix_engine_toolkit::functions::preview::{
+    build_preview_transaction, parse_preview_receipt, PreviewFlags,
+    PreviewHeader, PreviewReceiptError, PreviewReceiptStatus,
+    PreviewTransactionError,
+};
+use radix_engine_toolkit::transaction_types::{
+    execution_summary, ExecutionReceipt, TransactionTypesError,
+};
+use scrypto_test::prelude::*;
+mod test_runner_extension;
+use test_runner_extension::LedgerSimulatorEDExt;
+
+#[test]
+fn preview_transaction_contains_the_decompiled_manifest_and_blobs() {
+    // Arrange
+    let network_definition = NetworkDefinition::simulator();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package_advanced(
+            None,
+            vec![1, 2, 3],
+            PackageDefinition::default(),
+            MetadataInit::default(),
+            OwnerRole::None,
+        )
+        .build();
+
+    // Act
+    let preview_transaction = build_preview_transaction(
+        &manifest,
+        header(),
+        vec![],
+        PreviewFlags::default(),
+        &network_definition,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        preview_transaction.manifest,
+        decompile(&manifest.instructions, &network_definition).unwrap()
+    );
+    assert_eq!(
+        preview_transaction.blobs,
+        manifest.blobs.values().cloned().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn preview_transaction_keeps_the_header_signers_and_flags() {
+    // Arrange
+    let public_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+    };
+    let manifest = ManifestBuilder::new().drop_all_proofs().build();
+
+    // Act
+    let preview_transaction = build_preview_transaction(
+        &manifest,
+        header(),
+        vec![public_key.into()],
+        flags,
+        &NetworkDefinition::simulator(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(preview_transaction.header, header());
+    assert_eq!(
+        preview_transaction.signer_public_keys,
+        vec![public_key.into()]
+    );
+    assert_eq!(preview_transaction.flags, flags);
+}
+
+#[test]
+fn preview_transaction_with_an_empty_epoch_range_is_rejected() {
+    // Arrange
+    let header = PreviewHeader {
+        end_epoch_exclusive: Epoch::of(10),
+        ..header()
+    };
+    let manifest = ManifestBuilder::new().drop_all_proofs().build();
+
+    // Act
+    let preview_transaction = build_preview_transaction(
+        &manifest,
+        header,
+        vec![],
+        PreviewFlags::default(),
+        &NetworkDefinition::simulator(),
+    );
+
+    // Assert
+    assert_eq!(
+        preview_transaction,
+        Err(PreviewTransactionError::InvalidEpochRange {
+            start_epoch_inclusive: Epoch::of(10),
+            end_epoch_exclusive: Epoch::of(10),
+        })
+    );
+}
+
+#[test]
+fn receipt_of_a_successful_preview_is_parsed() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (_, _, account) = ledger.new_account(false);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            18,
+            FungibleResourceRoles::default(),
+            metadata!(),
+            Some(dec!(100)),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = LedgerSimulatorEDExt::preview(&mut ledger, manifest);
+    let encoded_receipt =
+        scrypto_encode(&VersionedTransactionReceipt::from(receipt)).unwrap();
+
+    // Act
+    let preview_receipt = parse_preview_receipt(encoded_receipt).unwrap();
+
+    // Assert
+    assert_eq!(preview_receipt.status, PreviewReceiptStatus::Succeeded);
+    assert!(preview_receipt.fee_summary.execution_cost.is_positive());
+    assert_eq!(preview_receipt.new_resource_addresses.len(), 1);
+    assert!(preview_receipt.new_component_addresses.is_empty());
+}
+
+#[test]
+fn receipt_of_a_failed_preview_is_parsed() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_worktop_contains(XRD, dec!(1))
+        .build();
+    let receipt = LedgerSimulatorEDExt::preview(&mut ledger, manifest);
+    let encoded_receipt =
+        scrypto_encode(&VersionedTransactionReceipt::from(receipt)).unwrap();
+
+    // Act
+    let preview_receipt = parse_preview_receipt(encoded_receipt).unwrap();
+
+    // Assert
+    assert!(matches!(
+        preview_receipt.status,
+        PreviewReceiptStatus::Failed(..)
+    ));
+    assert!(preview_receipt.new_resource_addresses.is_empty());
+}
+
+#[test]
+fn invalid_receipt_bytes_are_rejected() {
+    // Act
+    let preview_receipt = parse_preview_receipt([0x01, 0x02, 0x03]);
+
+    // Assert
+    assert!(matches!(
+        preview_receipt,
+        Err(PreviewReceiptError::DecodeError(..))
+    ));
+}
+
+#[test]
+fn execution_of_an_encoded_preview_receipt_is_summarized_like_a_native_one() {
+    // Arrange
+    let mut ledger = LedgerSimulatorBuilder::new().build();
+    let (_, _, account) = ledger.new_account(false);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = LedgerSimulatorEDExt::preview(&mut ledger, manifest.clone());
+    let encoded_receipt =
+        scrypto_encode(&VersionedTransactionReceipt::from(receipt.clone()))
+            .unwrap();
+
+    // Act
+    let native_summary = execution_summary(&manifest, &receipt).unwrap();
+    let preview_summary = execution_summary(
+        &manifest,
+        ExecutionReceipt::EncodedPreview(&encoded_receipt),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        preview_summary.account_deposits,
+        native_summary.account_deposits
+    );
+    assert_eq!(preview_summary.new_entities, native_summary.new_entities);
+    assert_eq!(preview_summary.fee_summary, native_summary.fee_summary);
+    assert_eq!(
+        preview_summary.detailed_classification,
+        native_summary.detailed_classification
+    );
+}
+
+#[test]
+fn execution_of_an_undecodable_preview_receipt_is_not_summarized() {
+    // Arrange
+    let manifest = ManifestBuilder::new().drop_all_proofs().build();
+
+    // Act
+    let summary = execution_summary(
+        &manifest,
+        ExecutionReceipt::EncodedPreview(&[0x01, 0x02, 0x03]),
+    );
+
+    // Assert
+    assert!(matches!(
+        summary,
+        Err(TransactionTypesError::UndecodableReceipt)
+    ));
+}
+
+fn header() -> PreviewHeader {
+    PreviewHeader {
+        start_epoch_inclusive: Epoch::of(10),
+        end_epoch_exclusive: Epoch::of(20),
+        notary_public_key: None,
+        notary_is_signatory: false,
+        tip_percentage: 0,
+        nonce: 100,
+    }
+}
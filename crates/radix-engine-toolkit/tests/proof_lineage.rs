@@ -0,0 +1,115 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::transaction_types::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn proofs_created_from_buckets_are_traced_to_the_resource_of_the_bucket() {
+    // Arrange
+    let account = account();
+    let component = component();
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 10)
+        .take_from_worktop(XRD, 10, "bucket")
+        .create_proof_from_bucket_of_amount("bucket", 5, "proof")
+        .call_method_with_name_lookup(component, "present", |lookup| {
+            (lookup.proof("proof"),)
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let lineage = proof_lineage(&manifest);
+
+    // Assert
+    assert_eq!(
+        lineage,
+        vec![ProofLineage {
+            proof: ManifestProof(0),
+            created_at: 2,
+            origin: ProofOrigin::Bucket {
+                bucket_id: ManifestBucket(0),
+                resource_address: Some(XRD),
+                amount: ProofAmount::Amount(dec!(5)),
+            },
+            cloned_from: None,
+            presentations: vec![ProofPresentation {
+                instruction_index: 3,
+                recipient: ProofRecipient::Entity(component.into()),
+            }],
+            dropped_at: None,
+        }]
+    );
+}
+
+#[test]
+fn cloned_proofs_keep_the_origin_of_the_proof_they_were_cloned_from() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_auth_zone_of_all(XRD, "proof")
+        .clone_proof("proof", "clone")
+        .push_to_auth_zone("proof")
+        .drop_all_proofs()
+        .build();
+
+    // Act
+    let lineage = proof_lineage(&manifest);
+
+    // Assert
+    let origin = ProofOrigin::AuthZone {
+        resource_address: XRD,
+        amount: ProofAmount::All,
+    };
+    assert_eq!(
+        lineage,
+        vec![
+            ProofLineage {
+                proof: ManifestProof(0),
+                created_at: 0,
+                origin: origin.clone(),
+                cloned_from: None,
+                presentations: vec![ProofPresentation {
+                    instruction_index: 2,
+                    recipient: ProofRecipient::AuthZone,
+                }],
+                dropped_at: None,
+            },
+            ProofLineage {
+                proof: ManifestProof(1),
+                created_at: 1,
+                origin,
+                cloned_from: Some(ManifestProof(0)),
+                presentations: vec![],
+                dropped_at: Some(3),
+            },
+        ]
+    );
+}
+
+fn account() -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    )
+}
+
+fn component() -> ComponentAddress {
+    ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    )
+}
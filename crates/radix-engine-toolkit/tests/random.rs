@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::random::*;
+use radix_engine_toolkit::functions::signing::verify_signature;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn generated_ed25519_key_pair_produces_valid_signatures() {
+    // Arrange
+    let private_key = generate_ed25519_keypair().unwrap();
+    let message_hash = hash("Hello World");
+
+    // Act
+    let signature = private_key.sign(&message_hash);
+
+    // Assert
+    assert!(verify_signature(
+        &message_hash,
+        &SignatureV1::Ed25519(signature),
+        &private_key.public_key().into()
+    ))
+}
+
+#[test]
+fn generated_secp256k1_key_pair_produces_valid_signatures() {
+    // Arrange
+    let private_key = generate_secp256k1_keypair().unwrap();
+    let message_hash = hash("Hello World");
+
+    // Act
+    let signature = private_key.sign(&message_hash);
+
+    // Assert
+    assert!(verify_signature(
+        &message_hash,
+        &SignatureV1::Secp256k1(signature),
+        &private_key.public_key().into()
+    ))
+}
+
+#[test]
+fn generated_key_pairs_are_distinct() {
+    // Act
+    let private_key1 = generate_secp256k1_keypair().unwrap();
+    let private_key2 = generate_secp256k1_keypair().unwrap();
+
+    // Assert
+    assert_ne!(private_key1.to_bytes(), private_key2.to_bytes())
+}
+
+#[test]
+fn secp256k1_key_generation_gives_up_on_a_source_of_invalid_keys() {
+    // Arrange
+    struct ZeroEntropy;
+    impl EntropySource for ZeroEntropy {
+        fn fill_bytes(&mut self, bytes: &mut [u8]) -> Result<(), RandomError> {
+            bytes.fill(0);
+            Ok(())
+        }
+    }
+
+    // Act
+    let private_key = generate_secp256k1_keypair_from(&mut ZeroEntropy);
+
+    // Assert
+    assert_eq!(private_key.map(|_| ()), Err(RandomError::InvalidKey));
+}
+
+#[cfg(feature = "test-utils")]
+#[test]
+fn generators_seeded_the_same_produce_the_same_values() {
+    use radix_engine_toolkit::test_utils::split_mix64::SplitMix64;
+
+    // Arrange
+    let mut source1 = SplitMix64::new(42);
+    let mut source2 = SplitMix64::new(42);
+
+    // Act
+    let nonce1 = generate_random_nonce_from(&mut source1).unwrap();
+    let private_key1 = generate_ed25519_keypair_from(&mut source1).unwrap();
+    let nonce2 = generate_random_nonce_from(&mut source2).unwrap();
+    let private_key2 = generate_ed25519_keypair_from(&mut source2).unwrap();
+
+    // Assert
+    assert_eq!(nonce1, nonce2);
+    assert_eq!(private_key1.to_bytes(), private_key2.to_bytes());
+}
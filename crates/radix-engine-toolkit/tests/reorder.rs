@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::reorder::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn moving_the_fee_lock_to_the_end_is_safe() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "bucket")
+        .try_deposit_or_abort(account(2), None, "bucket")
+        .build();
+
+    // Act
+    let violations = check_reorder(&manifest.instructions, &[1, 2, 3, 0]);
+
+    // Assert
+    assert_eq!(violations, Ok(vec![]));
+}
+
+#[test]
+fn using_a_bucket_before_it_is_taken_is_reported() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "bucket")
+        .try_deposit_or_abort(account(2), None, "bucket")
+        .build();
+
+    // Act
+    let violations = check_reorder(&manifest.instructions, &[0, 1, 3, 2]);
+
+    // Assert
+    assert_eq!(
+        violations,
+        Ok(vec![InstructionDependency {
+            kind: DependencyKind::BucketDefinition(ManifestBucket(0)),
+            dependency: 2,
+            dependent: 3,
+        }])
+    );
+}
+
+#[test]
+fn consuming_a_bucket_before_a_proof_is_created_from_it_is_reported() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "bucket")
+        .create_proof_from_bucket_of_all("bucket", "proof")
+        .drop_proof("proof")
+        .return_to_worktop("bucket")
+        .try_deposit_entire_worktop_or_abort(account(1), None)
+        .build();
+
+    // Act
+    let violations = check_reorder(&manifest.instructions, &[0, 1, 4, 2, 3, 5]);
+
+    // Assert
+    assert!(violations.unwrap().contains(&InstructionDependency {
+        kind: DependencyKind::BucketConsumption(ManifestBucket(0)),
+        dependency: 2,
+        dependent: 4,
+    }));
+}
+
+#[test]
+fn separating_an_assertion_from_the_instruction_before_it_is_reported() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, 10)
+        .assert_worktop_contains(XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let violations = check_reorder(&manifest.instructions, &[0, 2, 1]);
+
+    // Assert
+    assert_eq!(
+        violations,
+        Ok(vec![InstructionDependency {
+            kind: DependencyKind::Assertion,
+            dependency: 0,
+            dependent: 1,
+        }])
+    );
+}
+
+#[test]
+fn permutation_with_a_repeated_index_is_rejected() {
+    // Arrange
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account(2), None)
+        .build();
+
+    // Act
+    let violations = check_reorder(&manifest.instructions, &[0, 1, 1]);
+
+    // Assert
+    assert_eq!(violations, Err(ReorderError::InvalidPermutationIndex(1)));
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
@@ -0,0 +1,143 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine_toolkit::functions::review::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn simple_transfers_are_summarized_and_each_movement_is_rendered() {
+    // Arrange
+    let network_definition = NetworkDefinition::simulator();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account(1), 10)
+        .withdraw_from_account(account(1), XRD, 10)
+        .take_from_worktop(XRD, 10, "xrd")
+        .try_deposit_or_abort(account(2), None, "xrd")
+        .build();
+
+    // Act
+    let statements = render_manifest_summary(
+        &manifest,
+        &network_definition,
+        &LocaleOptions::default(),
+    );
+
+    // Assert
+    let from = address(account(1), &network_definition);
+    let to = address(account(2), &network_definition);
+    assert_eq!(
+        statements,
+        vec![
+            format!("Transfer 10 XRD from {from} to {to}"),
+            format!("Pay a fee of up to 10 XRD from {from}"),
+            format!("Withdraw 10 XRD from {from}"),
+            format!("Deposit 10 XRD to {to}"),
+        ]
+    );
+}
+
+#[test]
+fn amounts_follow_the_locale_and_unknown_amounts_are_rendered_as_all() {
+    // Arrange
+    let network_definition = NetworkDefinition::simulator();
+    let component = ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    );
+    let resource = ResourceAddress::new_or_panic(
+        [EntityType::GlobalFungibleResourceManager as u8; NodeId::LENGTH],
+    );
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account(1), XRD, dec!("1234567.891"))
+        .take_all_from_worktop(XRD, "xrd")
+        .call_method_with_name_lookup(component, "swap", |lookup| {
+            (lookup.bucket("xrd"),)
+        })
+        .take_all_from_worktop(resource, "resource")
+        .try_deposit_or_abort(account(1), None, "resource")
+        .build();
+    let locale_options = LocaleOptions {
+        decimal_separator: ",".to_owned(),
+        grouping_separator: Some(".".to_owned()),
+        decimal_places: Some(2),
+        shorten_addresses: false,
+    };
+
+    // Act
+    let statements = render_manifest_summary(
+        &manifest,
+        &network_definition,
+        &locale_options,
+    );
+
+    // Assert
+    let account = address(account(1), &network_definition);
+    let component = address(component, &network_definition);
+    let resource = address(resource, &network_definition);
+    assert_eq!(
+        statements,
+        vec![
+            format!("Withdraw 1.234.567,89 XRD from {account}"),
+            format!("Call `swap` on {component} with 1.234.567,89 XRD"),
+            format!("Deposit all {resource} to {account}"),
+        ]
+    );
+}
+
+#[test]
+fn royalty_changes_are_rendered_with_their_amount() {
+    // Arrange
+    let network_definition = NetworkDefinition::simulator();
+    let component = ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    );
+    let manifest = ManifestBuilder::new()
+        .set_component_royalty(component, "swap", RoyaltyAmount::Xrd(dec!(1)))
+        .set_component_royalty(component, "quote", RoyaltyAmount::Free)
+        .build();
+
+    // Act
+    let statements = render_manifest_summary(
+        &manifest,
+        &network_definition,
+        &LocaleOptions::default(),
+    );
+
+    // Assert
+    let component = address(component, &network_definition);
+    assert_eq!(
+        statements,
+        vec![
+            format!("Set the royalty of `swap` on {component} to 1 XRD"),
+            format!("Set the royalty of `quote` on {component} to free"),
+        ]
+    );
+}
+
+fn address<A: Into<GlobalAddress>>(
+    address: A,
+    network_definition: &NetworkDefinition,
+) -> String {
+    AddressBech32Encoder::new(network_definition)
+        .encode(address.into().as_node_id().as_bytes())
+        .unwrap()
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
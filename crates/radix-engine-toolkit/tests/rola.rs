@@ -0,0 +1,137 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::derive::*;
+use radix_engine_toolkit::functions::rola::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+const DAPP_DEFINITION_ADDRESS: &str =
+    "account_tdx_2_12yf9gd53yfep7a669fv2t3wm7nz9zeezwd04n02a433ker8vza6rhe";
+const ORIGIN: &str = "https://dashboard.radixdlt.com";
+
+#[test]
+fn rola_message_contains_the_challenge_address_and_origin() {
+    // Arrange
+    let challenge = [0xAB; ROLA_CHALLENGE_LENGTH];
+
+    // Act
+    let message =
+        rola_message(&challenge, DAPP_DEFINITION_ADDRESS, ORIGIN).unwrap();
+
+    // Assert
+    let mut expected = vec![ROLA_MESSAGE_PREFIX];
+    expected.extend(challenge);
+    expected.push(DAPP_DEFINITION_ADDRESS.len() as u8);
+    expected.extend(DAPP_DEFINITION_ADDRESS.as_bytes());
+    expected.extend(ORIGIN.as_bytes());
+    assert_eq!(message, expected);
+}
+
+#[test]
+fn challenge_of_invalid_length_is_rejected() {
+    // Arrange
+    let challenge = [0xAB; 16];
+
+    // Act
+    let message = rola_message(&challenge, DAPP_DEFINITION_ADDRESS, ORIGIN);
+
+    // Assert
+    assert_eq!(
+        message,
+        Err(RolaError::InvalidChallengeLength { length: 16 })
+    );
+}
+
+#[test]
+fn signature_by_the_key_of_a_virtual_account_is_valid() {
+    // Arrange
+    let challenge = [0xAB; ROLA_CHALLENGE_LENGTH];
+    let (public_key, signature) = sign_challenge(1, &challenge);
+    let account = virtual_account_address_from_public_key(&public_key);
+
+    // Act
+    let result = verify_rola_signature(
+        &challenge,
+        DAPP_DEFINITION_ADDRESS,
+        ORIGIN,
+        &account,
+        &public_key,
+        &signature,
+        None,
+    );
+
+    // Assert
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn signature_over_another_origin_is_invalid() {
+    // Arrange
+    let challenge = [0xAB; ROLA_CHALLENGE_LENGTH];
+    let (public_key, signature) = sign_challenge(1, &challenge);
+    let account = virtual_account_address_from_public_key(&public_key);
+
+    // Act
+    let result = verify_rola_signature(
+        &challenge,
+        DAPP_DEFINITION_ADDRESS,
+        "https://example.com",
+        &account,
+        &public_key,
+        &signature,
+        None,
+    );
+
+    // Assert
+    assert_eq!(result, Err(RolaError::InvalidSignature));
+}
+
+#[test]
+fn signature_by_a_key_not_in_the_owner_keys_is_invalid() {
+    // Arrange
+    let challenge = [0xAB; ROLA_CHALLENGE_LENGTH];
+    let (public_key, signature) = sign_challenge(1, &challenge);
+    let (other_public_key, _) = sign_challenge(2, &challenge);
+    let account = virtual_account_address_from_public_key(&public_key);
+    let owner_keys = MetadataValue::PublicKeyHashArray(vec![
+        PublicKeyHash::new_from_public_key(&other_public_key),
+    ]);
+
+    // Act
+    let result = verify_rola_signature(
+        &challenge,
+        DAPP_DEFINITION_ADDRESS,
+        ORIGIN,
+        &account,
+        &public_key,
+        &signature,
+        Some(&owner_keys),
+    );
+
+    // Assert
+    assert_eq!(result, Err(RolaError::PublicKeyIsNotAnOwnerKey));
+}
+
+fn sign_challenge(key: u64, challenge: &[u8]) -> (PublicKey, SignatureV1) {
+    let private_key = Secp256k1PrivateKey::from_u64(key).unwrap();
+    let hash = rola_hash(challenge, DAPP_DEFINITION_ADDRESS, ORIGIN).unwrap();
+    (
+        private_key.public_key().into(),
+        SignatureV1::Secp256k1(private_key.sign(&hash)),
+    )
+}
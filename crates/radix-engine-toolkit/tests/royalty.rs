@@ -0,0 +1,76 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::royalty::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn royalty_amount_round_trips_through_a_manifest_value() {
+    // Arrange
+    let amount = RoyaltyAmount::Usd(dec!("0.05"));
+
+    // Act
+    let value = royalty_amount_to_manifest_value(&amount);
+    let decoded = royalty_amount_from_manifest_value(&value);
+
+    // Assert
+    assert_eq!(decoded, Some(amount));
+}
+
+#[test]
+fn manifest_value_that_is_not_a_royalty_amount_is_not_decoded() {
+    // Arrange
+    let value = to_manifest_value_and_unwrap!(&("swap",));
+
+    // Act
+    let decoded = royalty_amount_from_manifest_value(&value);
+
+    // Assert
+    assert_eq!(decoded, None);
+}
+
+#[test]
+fn royalty_change_of_the_built_instruction_is_decoded() {
+    // Arrange
+    let component = ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    );
+    let instruction =
+        set_component_royalty(component, "swap", RoyaltyAmount::Xrd(dec!(1)));
+
+    // Act
+    let change = royalty_change(&instruction);
+
+    // Assert
+    assert_eq!(
+        change,
+        Some(("swap".to_owned(), RoyaltyAmount::Xrd(dec!(1))))
+    );
+}
+
+#[test]
+fn instructions_that_do_not_set_a_royalty_have_no_royalty_change() {
+    // Arrange
+    let instruction = InstructionV1::DropAllProofs;
+
+    // Act
+    let change = royalty_change(&instruction);
+
+    // Assert
+    assert_eq!(change, None);
+}
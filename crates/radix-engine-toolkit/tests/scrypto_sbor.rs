@@ -19,8 +19,14 @@ use radix_common::prelude::{
     scrypto_encode, AddressBech32Encoder, ScryptoCustomSchema, ScryptoValue,
 };
 use radix_common::ScryptoSbor;
+use radix_engine_toolkit::functions::scrypto_sbor::{
+    ScryptoSborError, StringRepresentation,
+};
+use radix_engine_toolkit::sbor::scrypto_event_stream::ScryptoSborEvent;
+use radix_engine_toolkit::sbor::value_query::*;
 use sbor::generate_full_schema_from_single_type;
 use sbor::representations::SerializationMode;
+use sbor::ValueKind;
 
 #[test]
 fn scrypto_value_can_be_encoded() {
@@ -87,6 +93,254 @@ fn scrypto_value_can_be_represented_as_a_string() {
     }
 }
 
+#[test]
+fn scrypto_value_can_be_decoded_to_events() {
+    // Arrange
+    let value = MyStruct { value: true };
+    let encoded_value = scrypto_encode(&value).unwrap();
+
+    // Act
+    let events =
+        radix_engine_toolkit::functions::scrypto_sbor::decode_to_events(
+            &encoded_value,
+        )
+        .collect::<Result<Vec<_>, _>>();
+
+    // Assert
+    assert_eq!(
+        events,
+        Ok(vec![
+            ScryptoSborEvent::TupleStart { length: 1 },
+            ScryptoSborEvent::Value(ScryptoValue::Bool { value: true }),
+            ScryptoSborEvent::ContainerEnd {
+                value_kind: ValueKind::Tuple
+            },
+        ])
+    )
+}
+
+#[test]
+fn bytes_are_decoded_to_a_single_event() {
+    // Arrange
+    let bytes = vec![1u8, 2, 3, 4];
+    let encoded_value = scrypto_encode(&bytes).unwrap();
+
+    // Act
+    let events =
+        radix_engine_toolkit::functions::scrypto_sbor::decode_to_events(
+            &encoded_value,
+        )
+        .collect::<Result<Vec<_>, _>>();
+
+    // Assert
+    assert_eq!(
+        events,
+        Ok(vec![
+            ScryptoSborEvent::ArrayStart {
+                element_value_kind: ValueKind::U8,
+                length: 4
+            },
+            ScryptoSborEvent::Bytes(bytes),
+            ScryptoSborEvent::ContainerEnd {
+                value_kind: ValueKind::Array
+            },
+        ])
+    )
+}
+
+#[test]
+fn decoding_an_invalid_payload_to_events_yields_a_single_error() {
+    // Arrange
+    let encoded_value = [0x00, 0x01];
+
+    // Act
+    let events =
+        radix_engine_toolkit::functions::scrypto_sbor::decode_to_events(
+            &encoded_value,
+        )
+        .collect::<Vec<_>>();
+
+    // Assert
+    assert_eq!(events.len(), 1);
+    assert!(events[0].is_err());
+}
+
+#[test]
+fn query_path_can_be_parsed() {
+    // Arrange
+    let path = "$.0[*]#1[2]";
+
+    // Act
+    let segments = parse_path(path);
+
+    // Assert
+    assert_eq!(
+        segments,
+        Ok(vec![
+            PathSegment::Index(0),
+            PathSegment::Wildcard,
+            PathSegment::Variant(1),
+            PathSegment::Index(2),
+        ])
+    )
+}
+
+#[test]
+fn query_path_with_an_unterminated_bracket_is_rejected() {
+    // Arrange
+    let path = "$[1";
+
+    // Act
+    let segments = parse_path(path);
+
+    // Assert
+    assert_eq!(segments, Err(ValueQueryError::UnexpectedEnd))
+}
+
+#[test]
+fn query_matches_fields_of_enum_variants_under_a_wildcard() {
+    // Arrange
+    let value = ScryptoValue::Tuple {
+        fields: vec![ScryptoValue::Array {
+            element_value_kind: ValueKind::Enum,
+            elements: vec![
+                ScryptoValue::Enum {
+                    discriminator: 1,
+                    fields: vec![ScryptoValue::U32 { value: 1 }],
+                },
+                ScryptoValue::Enum {
+                    discriminator: 0,
+                    fields: vec![],
+                },
+                ScryptoValue::Enum {
+                    discriminator: 1,
+                    fields: vec![ScryptoValue::U32 { value: 2 }],
+                },
+            ],
+        }],
+    };
+    let encoded_value = scrypto_encode(&value).unwrap();
+
+    // Act
+    let matches = radix_engine_toolkit::functions::scrypto_sbor::query(
+        encoded_value,
+        "$.0[*]#1.0",
+    );
+
+    // Assert
+    assert_eq!(
+        matches.unwrap(),
+        vec![
+            ScryptoValue::U32 { value: 1 },
+            ScryptoValue::U32 { value: 2 }
+        ]
+    )
+}
+
+#[test]
+fn query_with_an_out_of_bounds_index_matches_nothing() {
+    // Arrange
+    let value = ScryptoValue::Tuple {
+        fields: vec![ScryptoValue::Bool { value: true }],
+    };
+    let encoded_value = scrypto_encode(&value).unwrap();
+
+    // Act
+    let matches = radix_engine_toolkit::functions::scrypto_sbor::query(
+        encoded_value,
+        "$.1",
+    );
+
+    // Assert
+    assert_eq!(matches.unwrap(), vec![])
+}
+
+#[test]
+fn programmatic_json_with_schema_can_be_encoded_back_to_the_same_bytes() {
+    // Arrange
+    let value = MyStruct { value: true };
+    let encoded_value = scrypto_encode(&value).unwrap();
+    let (local_type_id, schema) = generate_full_schema_from_single_type::<
+        MyStruct,
+        ScryptoCustomSchema,
+    >();
+    let schema = schema.v1().clone();
+    let string =
+        radix_engine_toolkit::functions::scrypto_sbor::decode_to_string_representation(
+            &encoded_value,
+            SerializationMode::Programmatic,
+            &AddressBech32Encoder::for_simulator(),
+            Some((local_type_id, schema.clone())),
+        )
+        .unwrap();
+
+    // Act
+    let encoded =
+        radix_engine_toolkit::functions::scrypto_sbor::encode_string_representation_with_schema(
+            StringRepresentation::ProgrammaticJson(string),
+            local_type_id,
+            &schema,
+        );
+
+    // Assert
+    assert_eq!(encoded.unwrap(), encoded_value)
+}
+
+#[test]
+fn programmatic_json_with_a_wrong_field_name_is_rejected_by_the_schema() {
+    // Arrange
+    let (local_type_id, schema) = generate_full_schema_from_single_type::<
+        MyStruct,
+        ScryptoCustomSchema,
+    >();
+    let string = r#"{
+        "kind": "Tuple",
+        "fields": [{ "kind": "Bool", "field_name": "other", "value": true }]
+    }"#;
+
+    // Act
+    let encoded =
+        radix_engine_toolkit::functions::scrypto_sbor::encode_string_representation_with_schema(
+            StringRepresentation::ProgrammaticJson(string.to_owned()),
+            local_type_id,
+            schema.v1(),
+        );
+
+    // Assert
+    assert!(matches!(
+        encoded,
+        Err(ScryptoSborError::FieldNameMismatch { expected, actual })
+            if expected == "value" && actual == "other"
+    ))
+}
+
+#[test]
+fn programmatic_json_with_a_wrong_type_is_rejected_by_the_schema() {
+    // Arrange
+    let (local_type_id, schema) = generate_full_schema_from_single_type::<
+        MyStruct,
+        ScryptoCustomSchema,
+    >();
+    let string = r#"{
+        "kind": "Tuple",
+        "fields": [{ "kind": "U8", "value": "1" }]
+    }"#;
+
+    // Act
+    let encoded =
+        radix_engine_toolkit::functions::scrypto_sbor::encode_string_representation_with_schema(
+            StringRepresentation::ProgrammaticJson(string.to_owned()),
+            local_type_id,
+            schema.v1(),
+        );
+
+    // Assert
+    assert!(matches!(
+        encoded,
+        Err(ScryptoSborError::SchemaValidationError)
+    ))
+}
+
 #[derive(ScryptoSbor)]
 struct MyStruct {
     value: bool,
@@ -0,0 +1,122 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_toolkit::functions::manifest::security_impacts;
+use radix_engine_toolkit::transaction_types::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn owner_role_and_role_assignment_changes_are_reported_with_their_rules() {
+    // Arrange
+    let component = component();
+    let badge =
+        NonFungibleGlobalId::new(resource(), NonFungibleLocalId::integer(1));
+    let manifest = ManifestBuilder::new()
+        .set_owner_role(component, rule!(require(badge.clone())))
+        .lock_owner_role(component)
+        .set_role(component, ObjectModuleId::Main, "admin", rule!(allow_all))
+        .build();
+
+    // Act
+    let security_impacts = security_impacts(&manifest);
+
+    // Assert
+    assert_eq!(
+        security_impacts,
+        vec![
+            SecurityImpact {
+                instruction_index: 0,
+                entity: DynamicGlobalAddress::Static(component.into()),
+                kind: SecurityImpactKind::OwnerRoleSet {
+                    rule: rule!(require(badge)),
+                },
+            },
+            SecurityImpact {
+                instruction_index: 1,
+                entity: DynamicGlobalAddress::Static(component.into()),
+                kind: SecurityImpactKind::OwnerRoleLocked,
+            },
+            SecurityImpact {
+                instruction_index: 2,
+                entity: DynamicGlobalAddress::Static(component.into()),
+                kind: SecurityImpactKind::RoleSet {
+                    module: ObjectModuleId::Main,
+                    role_key: "admin".to_owned(),
+                    rule: rule!(allow_all),
+                },
+            },
+        ]
+    );
+}
+
+#[test]
+fn metadata_locks_and_royalty_changes_are_reported_but_metadata_updates_are_not(
+) {
+    // Arrange
+    let component = component();
+    let manifest = ManifestBuilder::new()
+        .set_metadata(component, "name", "Component")
+        .lock_metadata(component, "name")
+        .set_component_royalty(component, "swap", RoyaltyAmount::Xrd(dec!(1)))
+        .lock_component_royalty(component, "swap")
+        .build();
+
+    // Act
+    let security_impacts = security_impacts(&manifest);
+
+    // Assert
+    assert_eq!(
+        security_impacts,
+        vec![
+            SecurityImpact {
+                instruction_index: 1,
+                entity: DynamicGlobalAddress::Static(component.into()),
+                kind: SecurityImpactKind::MetadataLocked {
+                    key: "name".to_owned(),
+                },
+            },
+            SecurityImpact {
+                instruction_index: 2,
+                entity: DynamicGlobalAddress::Static(component.into()),
+                kind: SecurityImpactKind::RoyaltySet {
+                    method: "swap".to_owned(),
+                    amount: RoyaltyAmount::Xrd(dec!(1)),
+                },
+            },
+            SecurityImpact {
+                instruction_index: 3,
+                entity: DynamicGlobalAddress::Static(component.into()),
+                kind: SecurityImpactKind::RoyaltyLocked {
+                    method: "swap".to_owned(),
+                },
+            },
+        ]
+    );
+}
+
+fn component() -> ComponentAddress {
+    ComponentAddress::new_or_panic(
+        [EntityType::GlobalGenericComponent as u8; NodeId::LENGTH],
+    )
+}
+
+fn resource() -> ResourceAddress {
+    ResourceAddress::new_or_panic(
+        [EntityType::GlobalNonFungibleResourceManager as u8; NodeId::LENGTH],
+    )
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use radix_transactions::prelude::PrivateKey;
 use radix_transactions::validation::ValidationConfig;
 mod test_data;
 
@@ -77,3 +78,46 @@ fn signed_intent_can_be_statically_validated() {
     // Assert
     assert!(validation_result.is_ok())
 }
+
+#[test]
+fn signed_intent_can_be_notarized() {
+    // Arrange
+    let signed_intent = test_data::signed_intent();
+    let notary_private_key = PrivateKey::Secp256k1(test_data::private_key1());
+
+    // Act
+    let notarized_transaction =
+        radix_engine_toolkit::functions::signed_intent::notarize(
+            signed_intent,
+            &notary_private_key,
+        );
+
+    // Assert
+    assert_eq!(
+        notarized_transaction,
+        Ok(test_data::notarized_transaction())
+    )
+}
+
+#[test]
+fn signed_intent_hash_can_be_obtained_from_payload() {
+    // Arrange
+    let signed_intent = test_data::signed_intent();
+    let compiled =
+        radix_engine_toolkit::functions::signed_intent::compile(&signed_intent)
+            .unwrap();
+
+    // Act
+    let hash =
+        radix_engine_toolkit::functions::signed_intent::hash_from_payload(
+            compiled,
+        )
+        .unwrap();
+
+    // Assert
+    let expected =
+        radix_engine_toolkit::functions::signed_intent::hash(&signed_intent)
+            .unwrap();
+    assert_eq!(hash.hash, expected.hash);
+    assert_eq!(hash.id, expected.id);
+}
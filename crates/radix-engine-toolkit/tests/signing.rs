@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::signing::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn private_key_can_be_created_from_bytes_of_each_curve() {
+    // Arrange
+    let secp256k1_bytes = Secp256k1PrivateKey::from_u64(1).unwrap().to_bytes();
+    let ed25519_bytes = Ed25519PrivateKey::from_u64(1).unwrap().to_bytes();
+
+    // Act
+    let secp256k1_private_key =
+        private_key_from_bytes(&secp256k1_bytes, CurveType::Secp256k1);
+    let ed25519_private_key =
+        private_key_from_bytes(&ed25519_bytes, CurveType::Ed25519);
+
+    // Assert
+    assert!(matches!(
+        secp256k1_private_key,
+        Ok(PrivateKey::Secp256k1(..))
+    ));
+    assert!(matches!(ed25519_private_key, Ok(PrivateKey::Ed25519(..))));
+}
+
+#[test]
+fn private_key_of_invalid_length_is_rejected() {
+    // Arrange
+    let bytes = [1u8; 12];
+
+    // Act
+    let private_key = private_key_from_bytes(&bytes, CurveType::Ed25519);
+
+    // Assert
+    assert!(matches!(
+        private_key,
+        Err(SigningError::InvalidPrivateKey {
+            curve: CurveType::Ed25519,
+            length: 12
+        })
+    ));
+}
+
+#[test]
+fn signing_a_hash_produces_a_signature_with_the_public_key() {
+    // Arrange
+    let message_hash = hash("Hello World");
+    let private_key =
+        PrivateKey::Ed25519(Ed25519PrivateKey::from_u64(1).unwrap());
+
+    // Act
+    let signature = sign_hash(&message_hash, &private_key);
+
+    // Assert
+    assert_eq!(signature, private_key.sign_with_public_key(&message_hash));
+}
+
+#[test]
+fn signature_over_a_hash_is_verified_against_the_signer_public_key() {
+    // Arrange
+    let message_hash = hash("Hello World");
+    let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+    let signature = private_key.sign_without_public_key(&message_hash);
+
+    // Act
+    let is_valid = verify_signature(
+        &message_hash,
+        &signature,
+        &private_key.public_key().into(),
+    );
+
+    // Assert
+    assert!(is_valid);
+}
+
+#[test]
+fn signature_is_not_verified_against_another_hash_or_public_key() {
+    // Arrange
+    let message_hash = hash("Hello World");
+    let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+    let signature = private_key.sign_without_public_key(&message_hash);
+
+    // Act
+    let is_valid_for_another_hash = verify_signature(
+        &hash("Goodbye World"),
+        &signature,
+        &private_key.public_key().into(),
+    );
+    let is_valid_for_another_public_key = verify_signature(
+        &message_hash,
+        &signature,
+        &Ed25519PrivateKey::from_u64(2).unwrap().public_key().into(),
+    );
+    let is_valid_for_another_curve = verify_signature(
+        &message_hash,
+        &signature,
+        &Secp256k1PrivateKey::from_u64(1)
+            .unwrap()
+            .public_key()
+            .into(),
+    );
+
+    // Assert
+    assert!(!is_valid_for_another_hash);
+    assert!(!is_valid_for_another_public_key);
+    assert!(!is_valid_for_another_curve);
+}
+
+#[test]
+fn public_key_and_signature_of_invalid_length_are_rejected() {
+    // Arrange
+    let bytes = [1u8; 12];
+
+    // Act
+    let public_key = public_key_from_bytes(&bytes, CurveType::Secp256k1);
+    let signature = signature_from_bytes(&bytes, CurveType::Ed25519);
+
+    // Assert
+    assert_eq!(
+        public_key,
+        Err(SigningError::InvalidPublicKey {
+            curve: CurveType::Secp256k1,
+            length: 12
+        })
+    );
+    assert_eq!(
+        signature,
+        Err(SigningError::InvalidSignature {
+            curve: CurveType::Ed25519,
+            length: 12
+        })
+    );
+}
@@ -130,10 +130,6 @@ where
             panic!("Not commit success: {receipt:?}")
         }
 
-        let mut trusted_worktop = StaticWorktopContentsTracker::default();
-
-        manifest_summary::traverse(&mut [&mut trusted_worktop], &manifest);
-
-        trusted_worktop.output()
+        radix_engine_toolkit::transaction_types::trusted_worktop(manifest)
     }
 }
@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::notarized_transaction::statically_validate;
+use radix_engine_toolkit::test_utils::manifests::*;
+use radix_engine_toolkit::test_utils::transaction_factory::*;
+use radix_transactions::validation::*;
+use scrypto_test::prelude::*;
+
+#[test]
+fn faucet_manifests_execute_on_the_simulator() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account) = ledger.new_account(false);
+
+    // Act
+    let receipts = [
+        get_free_xrd(account),
+        create_fungible_with_initial_supply(account, dec!(1000), 18),
+        create_sample_non_fungibles(account, 3),
+    ]
+    .map(|manifest| ledger.execute_manifest(manifest, vec![]));
+
+    // Assert
+    for receipt in receipts {
+        receipt.expect_commit_success();
+    }
+}
+
+#[test]
+fn notarized_transactions_of_the_factory_are_valid() {
+    // Arrange
+    let network_definition = NetworkDefinition::simulator();
+    let mut factory = TestTransactionFactory::new(network_definition.clone());
+    let account = TestTransactionFactory::signer_account_address(1);
+
+    // Act
+    let transaction = factory.notarized_transaction(
+        ManifestBuilder::new()
+            .lock_fee(account, 10)
+            .withdraw_from_account(account, XRD, 10)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        &[1],
+    );
+
+    // Assert
+    assert!(statically_validate(
+        &transaction,
+        ValidationConfig::default(network_definition.id)
+    )
+    .is_ok());
+}
+
+#[test]
+fn factories_produce_the_same_payloads_with_increasing_nonces() {
+    // Arrange
+    let mut factory1 =
+        TestTransactionFactory::new(NetworkDefinition::simulator());
+    let mut factory2 =
+        TestTransactionFactory::new(NetworkDefinition::simulator());
+    let account = TestTransactionFactory::signer_account_address(1);
+
+    // Act
+    let payloads1 = [0, 1].map(|_| {
+        factory1.compiled_notarized_transaction(get_free_xrd(account), &[])
+    });
+    let payloads2 = [0, 1].map(|_| {
+        factory2.compiled_notarized_transaction(get_free_xrd(account), &[])
+    });
+
+    // Assert
+    assert_eq!(payloads1, payloads2);
+    assert_ne!(payloads1[0], payloads1[1]);
+}
+
+#[test]
+fn every_signer_id_including_zero_has_a_distinct_key() {
+    // Act
+    let private_keys = [0, 1, u64::MAX]
+        .map(|id| TestTransactionFactory::signer_private_key(id).to_bytes());
+
+    // Assert
+    assert_ne!(private_keys[0], private_keys[1]);
+    assert_ne!(private_keys[1], private_keys[2]);
+    assert_ne!(private_keys[0], private_keys[2]);
+}
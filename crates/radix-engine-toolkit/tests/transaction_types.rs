@@ -17,8 +17,11 @@
 
 use radix_engine::blueprints::pool::v1::constants::*;
 use radix_engine::system::system_modules::execution_trace::ResourceSpecifier;
+use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::identity::*;
+use radix_engine_interface::blueprints::locker::*;
 use radix_engine_interface::blueprints::pool::*;
 use radix_engine_toolkit::transaction_types::*;
 use radix_transactions::prelude::*;
@@ -162,6 +165,34 @@ fn lock_fee_still_keeps_the_transfer_classification_but_adds_a_reserved_instruct
     assert_eq!(execution_summary.newly_created_non_fungibles.len(), 0);
 }
 
+#[test]
+fn fee_locks_of_each_account_are_aggregated_in_the_execution_summary() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+
+    let (_, _, account1) = ledger.new_account(false);
+    let (_, _, account2) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 10)
+        .lock_fee(account1, 5)
+        .lock_contingent_fee(account2, 3)
+        .build();
+
+    // Act
+    let (_, execution_summary) = ledger.summarize(manifest);
+
+    // Assert
+    let fee_locks = &execution_summary.fee_locks_by_account;
+    assert_eq!(fee_locks.len(), 2);
+    assert_eq!(fee_locks[&account1].lock, dec!(15));
+    assert_eq!(fee_locks[&account1].contingent_lock, dec!(0));
+    assert_eq!(fee_locks[&account2].lock, dec!(0));
+    assert_eq!(fee_locks[&account2].contingent_lock, dec!(3));
+    assert_eq!(execution_summary.fee_summary.tipping_cost, dec!(0));
+}
+
 #[test]
 fn simple_transfer_satisfies_the_transfer_and_general_transaction_types() {
     // Arrange
@@ -2683,3 +2714,461 @@ fn lock_fee_manifest_has_no_classification_except_general() {
         indexset![ManifestClass::General]
     );
 }
+
+#[test]
+fn deposits_are_checked_against_the_provided_account_deposit_settings() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account1) = ledger.new_account(false);
+    let (_, _, account2) = ledger.new_account(false);
+    let resource_address =
+        ledger.create_fungible_resource(dec!(100), 0, account1);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, 10)
+        .withdraw_from_account(account1, resource_address, 10)
+        .try_deposit_entire_worktop_or_abort(account2, None)
+        .build();
+    let receipt = LedgerSimulatorEDExt::preview(&mut ledger, manifest.clone());
+    let account_deposit_settings = indexmap! {
+        account2 => AccountDepositSettings {
+            default_deposit_rule: DefaultDepositRule::Reject,
+            resource_preferences: indexmap! {
+                resource_address => ResourcePreference::Allowed
+            },
+            existing_resources: indexset![],
+        }
+    };
+
+    // Act
+    let execution_summary = execution_summary_with_deposit_settings(
+        &manifest,
+        &receipt,
+        &account_deposit_settings,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        execution_summary.deposit_compatibility,
+        indexmap! {
+            account2 => indexmap! {
+                XRD => DepositCompatibility::RejectedByDefaultDepositRule,
+                resource_address =>
+                    DepositCompatibility::AllowedByResourcePreference,
+            }
+        }
+    );
+}
+
+#[test]
+fn allow_existing_deposit_rule_accepts_xrd_and_existing_resources_only() {
+    // Arrange
+    let existing_resource = ResourceAddress::new_or_panic(
+        [EntityType::GlobalFungibleResourceManager as u8; NodeId::LENGTH],
+    );
+    let new_resource = ResourceAddress::new_or_panic(
+        [EntityType::GlobalNonFungibleResourceManager as u8; NodeId::LENGTH],
+    );
+    let settings = AccountDepositSettings {
+        default_deposit_rule: DefaultDepositRule::AllowExisting,
+        resource_preferences: indexmap! {},
+        existing_resources: indexset![existing_resource],
+    };
+
+    // Act
+    let xrd = settings.deposit_compatibility(&XRD);
+    let existing = settings.deposit_compatibility(&existing_resource);
+    let new = settings.deposit_compatibility(&new_resource);
+
+    // Assert
+    assert!(!xrd.is_blocked());
+    assert!(!existing.is_blocked());
+    assert!(new.is_blocked());
+}
+
+#[test]
+fn simple_transfer_is_classified_with_its_details() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (_, _, account1) = ledger.new_account(false);
+    let (_, _, account2) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account1, XRD, 10)
+        .take_from_worktop(XRD, 10, "xrd")
+        .try_deposit_or_abort(account2, None, "xrd")
+        .build();
+
+    // Act
+    let classification = classify_manifest(&manifest);
+
+    // Assert
+    assert_eq!(
+        classification,
+        vec![
+            ManifestClassification::SimpleTransfer(SimpleTransfer {
+                from: account1,
+                to: account2,
+                resource: XRD,
+                amount: SimpleTransferAmount::Amount(dec!(10)),
+            }),
+            ManifestClassification::General,
+        ]
+    );
+}
+
+#[test]
+fn validator_stake_is_classified_with_the_validators_staked_to() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (pk, _, account) = ledger.new_account(false);
+    let (validator, _, _) = ledger.new_validator(pk, account);
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 100)
+        .take_from_worktop(XRD, 100, "xrd")
+        .stake_validator(validator, "xrd")
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let classification = classify_manifest(&manifest);
+
+    // Assert
+    assert_eq!(
+        classification.first(),
+        Some(&ManifestClassification::ValidatorStake {
+            validator_addresses: indexset![validator]
+        })
+    );
+    assert_eq!(
+        classification
+            .into_iter()
+            .map(ManifestClass::from)
+            .collect::<IndexSet<_>>(),
+        ledger.summarize(manifest).0.classification
+    );
+}
+
+#[test]
+fn balance_changes_of_a_transfer_are_aggregated_per_account() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+
+    let (_, _, account1) = ledger.new_account(false);
+    let (_, _, account2) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account1, XRD, 10)
+        .withdraw_from_account(account1, XRD, 5)
+        .try_deposit_entire_worktop_or_abort(account2, None)
+        .build();
+
+    // Act
+    let (_, execution_summary) = ledger.summarize(manifest);
+
+    // Assert
+    let balance_changes = &execution_summary.balance_changes;
+    assert_eq!(balance_changes.len(), 2);
+    assert_eq!(balance_changes[&account1].fungible[&XRD], dec!(-15));
+    assert_eq!(balance_changes[&account2].fungible[&XRD], dec!(15));
+    assert!(balance_changes[&account1].non_fungible.is_empty());
+}
+
+#[test]
+fn balance_changes_that_cancel_out_are_not_included() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+
+    let (_, _, account) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let (_, execution_summary) = ledger.summarize(manifest);
+
+    // Assert
+    assert!(execution_summary.balance_changes.is_empty());
+}
+
+#[test]
+fn non_fungible_balance_changes_net_the_ids_added_and_removed() {
+    // Arrange
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    let resource_address = ResourceAddress::new_or_panic(
+        [EntityType::GlobalNonFungibleResourceManager as u8; NodeId::LENGTH],
+    );
+    let ids = |ids: &[u64]| {
+        ids.iter()
+            .copied()
+            .map(NonFungibleLocalId::integer)
+            .collect::<IndexSet<_>>()
+    };
+    let account_withdraws = indexmap! {
+        account => vec![ResourceIndicator::NonFungible(
+            resource_address,
+            NonFungibleResourceIndicator::ByIds(ids(&[1, 2])),
+        )]
+    };
+    let account_deposits = indexmap! {
+        account => vec![ResourceIndicator::NonFungible(
+            resource_address,
+            NonFungibleResourceIndicator::ByIds(ids(&[2, 3])),
+        )]
+    };
+
+    // Act
+    let balance_changes = AccountBalanceChanges::from_movements(
+        &account_withdraws,
+        &account_deposits,
+    );
+
+    // Assert
+    let change = &balance_changes[&account].non_fungible[&resource_address];
+    assert_eq!(change.added, ids(&[3]));
+    assert_eq!(change.removed, ids(&[1]));
+}
+
+#[test]
+fn resource_movements_graph_connects_accounts_through_the_worktop() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+
+    let (_, _, account1) = ledger.new_account(false);
+    let (_, _, account2) = ledger.new_account(false);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account1, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(account2, None)
+        .build();
+    let receipt = LedgerSimulatorEDExt::preview(&mut ledger, manifest.clone());
+
+    // Act
+    let graph = resource_movements_graph(&manifest, &receipt).unwrap();
+
+    // Assert
+    let account1 = graph
+        .nodes
+        .get_index_of(&ResourceMovementsNode::Account(account1))
+        .unwrap();
+    let account2 = graph
+        .nodes
+        .get_index_of(&ResourceMovementsNode::Account(account2))
+        .unwrap();
+    let worktop = graph
+        .nodes
+        .get_index_of(&ResourceMovementsNode::Worktop)
+        .unwrap();
+    assert_eq!(
+        graph.edges,
+        vec![
+            ResourceMovementsEdge {
+                from: account1,
+                to: worktop,
+                resource: ResourceSpecifier::Amount(XRD, dec!(10)),
+                instruction_index: 1,
+            },
+            ResourceMovementsEdge {
+                from: worktop,
+                to: account2,
+                resource: ResourceSpecifier::Amount(XRD, dec!(10)),
+                instruction_index: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn instructions_on_security_sensitive_entities_are_detected_with_their_index() {
+    // Arrange
+    let address_of = |entity_type: EntityType| {
+        ComponentAddress::new_or_panic([entity_type as u8; NodeId::LENGTH])
+    };
+    let account = address_of(EntityType::GlobalVirtualSecp256k1Account);
+    let access_controller = address_of(EntityType::GlobalAccessController);
+    let identity = address_of(EntityType::GlobalVirtualSecp256k1Identity);
+    let validator = address_of(EntityType::GlobalValidator);
+    let locker = address_of(EntityType::GlobalAccountLocker);
+
+    let manifest = ManifestBuilder::new()
+        .call_method(account, ACCOUNT_LOCK_FEE_IDENT, manifest_args!(dec!(1)))
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_CREATE_PROOF_IDENT,
+            manifest_args!(),
+        )
+        .call_method(
+            access_controller,
+            ACCESS_CONTROLLER_TIMED_CONFIRM_RECOVERY_IDENT,
+            manifest_args!(),
+        )
+        .call_method(identity, IDENTITY_SECURIFY_IDENT, manifest_args!())
+        .call_method(
+            validator,
+            VALIDATOR_UPDATE_FEE_IDENT,
+            manifest_args!(dec!(0.1)),
+        )
+        .call_method(validator, VALIDATOR_STAKE_IDENT, manifest_args!())
+        .call_method(
+            locker,
+            ACCOUNT_LOCKER_GET_AMOUNT_IDENT,
+            manifest_args!(account, XRD),
+        )
+        .call_method(
+            locker,
+            ACCOUNT_LOCKER_RECOVER_IDENT,
+            manifest_args!(account, XRD, dec!(1)),
+        )
+        .build();
+
+    // Act
+    let sensitive_instructions = detect_reserved_instructions(&manifest);
+
+    // Assert
+    assert_eq!(
+        sensitive_instructions,
+        vec![
+            SensitiveInstruction {
+                instruction_index: 2,
+                kind: SensitiveInstructionKind::AccessControllerRecovery,
+            },
+            SensitiveInstruction {
+                instruction_index: 3,
+                kind: SensitiveInstructionKind::IdentityOwnerBadgeUpdate,
+            },
+            SensitiveInstruction {
+                instruction_index: 4,
+                kind: SensitiveInstructionKind::ValidatorOwnerOperation,
+            },
+            SensitiveInstruction {
+                instruction_index: 7,
+                kind: SensitiveInstructionKind::AccountLockerOperation,
+            },
+        ]
+    );
+}
+
+#[test]
+fn identity_securify_is_reported_as_a_reserved_instruction() {
+    // Arrange
+    let identity = ComponentAddress::new_or_panic(
+        [EntityType::GlobalVirtualSecp256k1Identity as u8; NodeId::LENGTH],
+    );
+    let manifest = ManifestBuilder::new()
+        .call_method(identity, IDENTITY_SECURIFY_IDENT, manifest_args!())
+        .build();
+
+    // Act
+    let summary = summary(&manifest);
+
+    // Assert
+    assert_eq!(
+        summary.reserved_instructions,
+        indexset![ReservedInstruction::IdentitySecurify]
+    );
+}
+
+#[test]
+fn account_locker_claim_is_classified_with_the_claimed_resources() {
+    // Arrange
+    let mut ledger =
+        LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (public_key, _, account) = ledger.new_account(false);
+    let storer_badge = ledger.create_fungible_resource(dec!(1), 0, account);
+
+    let account_locker = ledger
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(
+                    LOCKER_PACKAGE,
+                    ACCOUNT_LOCKER_BLUEPRINT,
+                    ACCOUNT_LOCKER_INSTANTIATE_IDENT,
+                    AccountLockerInstantiateManifestInput {
+                        owner_role: OwnerRole::None,
+                        storer_role: rule!(require(storer_badge)),
+                        storer_updater_role: rule!(deny_all),
+                        recoverer_role: rule!(deny_all),
+                        recoverer_updater_role: rule!(deny_all),
+                        address_reservation: None,
+                    },
+                )
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success()
+        .new_component_addresses()
+        .first()
+        .copied()
+        .unwrap();
+    ledger
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .get_free_xrd_from_faucet()
+                .create_proof_from_account_of_amount(
+                    account,
+                    storer_badge,
+                    dec!(1),
+                )
+                .take_from_worktop(XRD, dec!(10), "bucket")
+                .with_bucket("bucket", |builder, bucket| {
+                    builder.call_method(
+                        account_locker,
+                        ACCOUNT_LOCKER_STORE_IDENT,
+                        AccountLockerStoreManifestInput {
+                            bucket,
+                            claimant: account,
+                            try_direct_send: false,
+                        },
+                    )
+                })
+                .try_deposit_entire_worktop_or_abort(account, None)
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            account_locker,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            (account, XRD, dec!(10)),
+        )
+        .deposit_batch(account)
+        .build();
+    let (manifest_summary, execution_summary) = ledger.summarize(manifest);
+
+    // Assert
+    assert_eq!(
+        manifest_summary.classification,
+        indexset![ManifestClass::AccountLockerClaim, ManifestClass::General]
+    );
+    assert_eq!(
+        execution_summary.detailed_classification.first(),
+        Some(&DetailedManifestClass::AccountLockerClaim {
+            locker_addresses: indexset![account_locker],
+            locker_claims: vec![TrackedAccountLockerClaim {
+                locker_address: account_locker,
+                claimant: account,
+                claimed_resources: ResourceSpecifier::Amount(XRD, dec!(10)),
+            }],
+        })
+    );
+}
@@ -0,0 +1,133 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_toolkit::functions::derive::*;
+use radix_engine_toolkit::functions::validator::*;
+use radix_transactions::prelude::*;
+use scrypto::prelude::*;
+
+#[test]
+fn stake_withdraws_takes_calls_and_deposits() {
+    // Arrange
+    let account = account(1);
+    let validator = validator();
+    let expected = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, 100)
+        .take_from_worktop(XRD, 100, "xrd")
+        .call_method_with_name_lookup(
+            validator,
+            VALIDATOR_STAKE_IDENT,
+            |lookup| (lookup.bucket("xrd"),),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let manifest = build_validator_manifest(
+        account,
+        &[ValidatorOperation::Stake {
+            validator_address: validator,
+            amount: dec!(100),
+        }],
+    );
+
+    // Assert
+    assert_eq!(manifest.instructions, expected.instructions);
+}
+
+#[test]
+fn buckets_of_consecutive_operations_do_not_clash() {
+    // Arrange
+    let account = account(1);
+    let validator = validator();
+    let stake_units = ResourceAddress::new_or_panic(
+        [EntityType::GlobalFungibleResourceManager as u8; NodeId::LENGTH],
+    );
+
+    // Act
+    let manifest = build_validator_manifest(
+        account,
+        &[
+            ValidatorOperation::Stake {
+                validator_address: validator,
+                amount: dec!(100),
+            },
+            ValidatorOperation::Unstake {
+                validator_address: validator,
+                stake_unit_resource_address: stake_units,
+                amount: dec!(50),
+            },
+        ],
+    );
+
+    // Assert
+    let buckets = manifest
+        .instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            InstructionV1::CallMethod { args, .. } => {
+                manifest_decode::<(ManifestBucket,)>(
+                    &manifest_encode(args).unwrap(),
+                )
+                .ok()
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(buckets, vec![(ManifestBucket(0),), (ManifestBucket(1),)]);
+}
+
+#[test]
+fn owner_operations_are_authorized_with_the_owner_badge() {
+    // Arrange
+    let account = account(1);
+    let validator = validator();
+
+    // Act
+    let manifest = build_validator_manifest(
+        account,
+        &[ValidatorOperation::UpdateFee {
+            validator_address: validator,
+            new_fee_factor: dec!("0.01"),
+        }],
+    );
+
+    // Assert
+    let badge = validator_owner_badge_from_validator_address(&validator);
+    let expected = ManifestBuilder::new()
+        .create_proof_from_account_of_non_fungibles(
+            account,
+            badge.resource_address(),
+            [badge.local_id().clone()],
+        )
+        .call_method(validator, VALIDATOR_UPDATE_FEE_IDENT, (dec!("0.01"),))
+        .build();
+    assert_eq!(manifest.instructions, expected.instructions);
+}
+
+fn validator() -> ComponentAddress {
+    ComponentAddress::new_or_panic(
+        [EntityType::GlobalValidator as u8; NodeId::LENGTH],
+    )
+}
+
+fn account(id: u64) -> ComponentAddress {
+    ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(id).unwrap().public_key(),
+    )
+}
@@ -0,0 +1,152 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::wallet_interaction::*;
+
+fn transaction_interaction() -> WalletInteraction {
+    WalletInteraction {
+        interaction_id: "3ad5d5c7-8cb1-4f0b-8a2f-0a1d1f8e6b9e".to_owned(),
+        metadata: WalletInteractionMetadata {
+            version: WALLET_INTERACTION_VERSION,
+            network_id: 2,
+            origin: "https://dashboard.radixdlt.com".to_owned(),
+            dapp_definition_address: "account_tdx_2_1xyz".to_owned(),
+        },
+        items: WalletInteractionItems::Transaction(TransactionRequest {
+            transaction_manifest: "CALL_METHOD Address(\"x\") \"y\";"
+                .to_owned(),
+            version: 1,
+            blobs: vec![vec![0xDE, 0xAD]],
+            message: Some("Hello".to_owned()),
+        }),
+    }
+}
+
+#[test]
+fn encoded_interaction_has_sorted_keys_and_no_whitespace() {
+    // Arrange
+    let interaction = WalletInteraction {
+        interaction_id: "id".to_owned(),
+        metadata: WalletInteractionMetadata {
+            version: 2,
+            network_id: 1,
+            origin: "o".to_owned(),
+            dapp_definition_address: "d".to_owned(),
+        },
+        items: WalletInteractionItems::UnauthorizedRequest(
+            UnauthorizedDataRequest {
+                one_time_accounts: Some(AccountsRequest {
+                    number_of_accounts: NumberOfValues {
+                        quantifier: Quantifier::AtLeast,
+                        quantity: 1,
+                    },
+                    challenge: None,
+                }),
+            },
+        ),
+    };
+
+    // Act
+    let encoded = encode_wallet_interaction(&interaction);
+
+    // Assert
+    assert_eq!(
+        encoded,
+        concat!(
+            r#"{"interactionId":"id","#,
+            r#""items":{"discriminator":"unauthorizedRequest","#,
+            r#""oneTimeAccounts":{"numberOfAccounts":"#,
+            r#"{"quantifier":"atLeast","quantity":1}}},"#,
+            r#""metadata":{"dAppDefinitionAddress":"d","networkId":1,"#,
+            r#""origin":"o","version":2}}"#
+        )
+    );
+}
+
+#[test]
+fn interaction_survives_encoding_and_decoding() {
+    // Arrange
+    let interaction = transaction_interaction();
+
+    // Act
+    let decoded =
+        decode_wallet_interaction(&encode_wallet_interaction(&interaction));
+
+    // Assert
+    assert_eq!(decoded, Ok(interaction));
+}
+
+#[test]
+fn formatting_of_the_json_does_not_change_the_hash() {
+    // Arrange
+    let interaction = transaction_interaction();
+    let encoded = encode_wallet_interaction(&interaction);
+    let value = serde_json::from_str::<serde_json::Value>(&encoded).unwrap();
+    let pretty = serde_json::to_string_pretty(&value).unwrap();
+
+    // Act
+    let decoded = decode_wallet_interaction(&pretty).unwrap();
+
+    // Assert
+    assert_eq!(
+        wallet_interaction_hash(&decoded),
+        wallet_interaction_hash(&interaction)
+    );
+    assert_eq!(canonicalize_wallet_interaction(&pretty), Ok(encoded));
+}
+
+#[test]
+fn interaction_survives_a_deep_link() {
+    // Arrange
+    let interaction = transaction_interaction();
+
+    // Act
+    let deep_link =
+        wallet_interaction_deep_link("radixwallet://connect", &interaction);
+    let decoded = decode_wallet_interaction_deep_link(&deep_link);
+
+    // Assert
+    assert!(deep_link.starts_with("radixwallet://connect?request=%7B"));
+    assert_eq!(decoded, Ok(interaction));
+}
+
+#[test]
+fn unknown_discriminator_is_reported_with_its_path() {
+    // Arrange
+    let json = r#"{
+        "interactionId": "id",
+        "metadata": {
+            "version": 2,
+            "networkId": 1,
+            "origin": "o",
+            "dAppDefinitionAddress": "d"
+        },
+        "items": { "discriminator": "cancel" }
+    }"#;
+
+    // Act
+    let decoded = decode_wallet_interaction(json);
+
+    // Assert
+    assert_eq!(
+        decoded,
+        Err(WalletInteractionError::UnknownDiscriminator {
+            path: "$.items.discriminator".to_owned(),
+            discriminator: "cancel".to_owned(),
+        })
+    );
+}
@@ -0,0 +1,69 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use radix_engine_toolkit::functions::xrd::*;
+use scrypto::prelude::*;
+
+#[test]
+fn xrd_is_converted_to_attos_and_back() {
+    // Arrange
+    let amount = dec!("1.000000000000000001");
+
+    // Act
+    let attos = xrd_to_attos(&amount);
+    let xrd = attos_to_xrd(attos);
+
+    // Assert
+    assert_eq!(attos, I192::from(ATTOS_PER_XRD + 1));
+    assert_eq!(xrd, amount);
+}
+
+#[test]
+fn cost_units_are_converted_to_xrd() {
+    // Act
+    let fee = cost_units_to_xrd(10_000_000, &dec!("0.00000005"), 0);
+
+    // Assert
+    assert_eq!(fee, Some(dec!("0.5")));
+}
+
+#[test]
+fn tip_raises_the_cost_unit_price() {
+    // Act
+    let fee = cost_units_to_xrd(10_000_000, &dec!("0.00000005"), 25);
+
+    // Assert
+    assert_eq!(fee, Some(dec!("0.625")));
+}
+
+#[test]
+fn cost_unit_price_is_truncated_after_the_tip_is_applied() {
+    // Act
+    let fee = cost_units_to_xrd(3, &dec!("0.000000000000000001"), 50);
+
+    // Assert
+    assert_eq!(fee, Some(dec!("0.000000000000000003")));
+}
+
+#[test]
+fn overflowing_fee_is_none() {
+    // Act
+    let fee = cost_units_to_xrd(u32::MAX, &Decimal::MAX, 0);
+
+    // Assert
+    assert_eq!(fee, None);
+}
@@ -0,0 +1,121 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Benchmarks of the conversions between [`ScryptoValue`] and the programmatic
+//! JSON value model. The values used here resemble the arguments of an NFT
+//! mint: a map of several thousand non-fungible local ids to tuples of their
+//! data, and a large byte array.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use radix_common::prelude::*;
+use sbor_json::scrypto::programmatic::value::*;
+
+const ELEMENTS: u64 = 5_000;
+
+fn nft_mint_value() -> ScryptoValue {
+    let entries = (0..ELEMENTS)
+        .map(|id| {
+            (
+                ScryptoValue::Custom {
+                    value: ScryptoCustomValue::NonFungibleLocalId(
+                        NonFungibleLocalId::integer(id),
+                    ),
+                },
+                ScryptoValue::Tuple {
+                    fields: vec![
+                        ScryptoValue::String {
+                            value: format!("NFT #{id}"),
+                        },
+                        ScryptoValue::String {
+                            value: format!("https://example.com/{id}.png"),
+                        },
+                        ScryptoValue::U64 { value: id },
+                    ],
+                },
+            )
+        })
+        .collect();
+    let bytes = (0..ELEMENTS)
+        .map(|value| ScryptoValue::U8 { value: value as u8 })
+        .collect();
+
+    ScryptoValue::Tuple {
+        fields: vec![
+            ScryptoValue::Map {
+                key_value_kind: ValueKind::Custom(
+                    ScryptoCustomValueKind::NonFungibleLocalId,
+                ),
+                value_value_kind: ValueKind::Tuple,
+                entries,
+            },
+            ScryptoValue::Array {
+                element_value_kind: ValueKind::U8,
+                elements: bytes,
+            },
+        ],
+    }
+}
+
+fn from_scrypto_value(c: &mut Criterion) {
+    let value = nft_mint_value();
+
+    let mut group = c.benchmark_group("from_scrypto_value");
+    group.bench_function("borrowed", |b| {
+        b.iter(|| {
+            ProgrammaticScryptoValue::from_scrypto_value(
+                black_box(&value),
+                0xF2,
+            )
+        })
+    });
+    group.bench_function("owned", |b| {
+        b.iter_batched(
+            || value.clone(),
+            |value| {
+                ProgrammaticScryptoValue::from_scrypto_value_owned(
+                    black_box(value),
+                    0xF2,
+                )
+            },
+            criterion::BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn to_scrypto_value(c: &mut Criterion) {
+    let value = ProgrammaticScryptoValue::from_scrypto_value_owned(
+        nft_mint_value(),
+        0xF2,
+    );
+
+    let mut group = c.benchmark_group("to_scrypto_value");
+    group.bench_function("borrowed", |b| {
+        b.iter(|| black_box(&value).to_scrypto_value())
+    });
+    group.bench_function("owned", |b| {
+        b.iter_batched(
+            || value.clone(),
+            |value| black_box(value).into_scrypto_value(),
+            criterion::BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, from_scrypto_value, to_scrypto_value);
+criterion_main!(benches);
@@ -18,6 +18,7 @@
 use radix_common::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use std::borrow::Cow;
 
 use crate::common::address::SerializableNodeId;
 use crate::common::map_entry::MapEntry;
@@ -346,30 +347,20 @@ impl ProgrammaticScryptoValue {
             SborValue::Array {
                 element_value_kind,
                 elements,
-            } if elements
-                .iter()
-                .all(|element| matches!(element, ScryptoValue::U8 { .. })) =>
-            {
-                Self::Bytes {
+            } => match scrypto_value_bytes(elements) {
+                Some(value) => Self::Bytes {
+                    element_value_kind: (*element_value_kind).into(),
+                    value,
+                },
+                None => Self::Array {
                     element_value_kind: (*element_value_kind).into(),
-                    value: elements
+                    elements: elements
                         .iter()
-                        .map_while(|value| match value {
-                            ScryptoValue::U8 { value } => Some(*value),
-                            _ => None,
+                        .map(|value| {
+                            Self::from_scrypto_value(value, network_id)
                         })
-                        .collect::<Vec<u8>>(),
-                }
-            }
-            SborValue::Array {
-                element_value_kind,
-                elements,
-            } => Self::Array {
-                element_value_kind: (*element_value_kind).into(),
-                elements: elements
-                    .iter()
-                    .map(|value| Self::from_scrypto_value(value, network_id))
-                    .collect(),
+                        .collect(),
+                },
             },
             SborValue::Tuple { fields } => Self::Tuple {
                 fields: fields
@@ -417,4 +408,233 @@ impl ProgrammaticScryptoValue {
             },
         }
     }
+
+    /// Converts the value into a [`ScryptoValue`] by moving the strings,
+    /// byte arrays, and collections out of it rather than cloning them. This
+    /// should be preferred over [`Self::to_scrypto_value`] whenever the value
+    /// is no longer needed after the conversion.
+    pub fn into_scrypto_value(self) -> ScryptoValue {
+        match self {
+            Self::Bool { value } => ScryptoValue::Bool { value },
+            Self::I8 { value } => ScryptoValue::I8 { value },
+            Self::I16 { value } => ScryptoValue::I16 { value },
+            Self::I32 { value } => ScryptoValue::I32 { value },
+            Self::I64 { value } => ScryptoValue::I64 { value },
+            Self::I128 { value } => ScryptoValue::I128 { value },
+            Self::U8 { value } => ScryptoValue::U8 { value },
+            Self::U16 { value } => ScryptoValue::U16 { value },
+            Self::U32 { value } => ScryptoValue::U32 { value },
+            Self::U64 { value } => ScryptoValue::U64 { value },
+            Self::U128 { value } => ScryptoValue::U128 { value },
+            Self::String { value } => ScryptoValue::String { value },
+            Self::Enum {
+                discriminator,
+                fields,
+            } => ScryptoValue::Enum {
+                discriminator,
+                fields: fields
+                    .into_iter()
+                    .map(Self::into_scrypto_value)
+                    .collect(),
+            },
+            Self::Array {
+                element_value_kind,
+                elements,
+            } => ScryptoValue::Array {
+                element_value_kind: element_value_kind.into(),
+                elements: elements
+                    .into_iter()
+                    .map(Self::into_scrypto_value)
+                    .collect(),
+            },
+            Self::Tuple { fields } => ScryptoValue::Tuple {
+                fields: fields
+                    .into_iter()
+                    .map(Self::into_scrypto_value)
+                    .collect(),
+            },
+            Self::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            } => ScryptoValue::Map {
+                key_value_kind: key_value_kind.into(),
+                value_value_kind: value_value_kind.into(),
+                entries: entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        (key.into_scrypto_value(), value.into_scrypto_value())
+                    })
+                    .collect(),
+            },
+            Self::Reference { value } => ScryptoValue::Custom {
+                value: ScryptoCustomValue::Reference(Reference(value.0)),
+            },
+            Self::Own { value } => ScryptoValue::Custom {
+                value: ScryptoCustomValue::Own(Own(value.0)),
+            },
+            Self::Decimal { value } => ScryptoValue::Custom {
+                value: ScryptoCustomValue::Decimal(value),
+            },
+            Self::PreciseDecimal { value } => ScryptoValue::Custom {
+                value: ScryptoCustomValue::PreciseDecimal(value),
+            },
+            Self::NonFungibleLocalId { value } => ScryptoValue::Custom {
+                value: ScryptoCustomValue::NonFungibleLocalId(value),
+            },
+            Self::Bytes {
+                element_value_kind,
+                value,
+            } => ScryptoValue::Array {
+                element_value_kind: element_value_kind.into(),
+                elements: value
+                    .into_iter()
+                    .map(|value| ScryptoValue::U8 { value })
+                    .collect(),
+            },
+        }
+    }
+
+    /// The owned counterpart of [`Self::from_scrypto_value`] which moves the
+    /// strings, non-fungible local ids, and collections of the given value
+    /// into the programmatic value instead of cloning them.
+    pub fn from_scrypto_value_owned(
+        value: ScryptoValue,
+        network_id: u8,
+    ) -> Self {
+        match value {
+            SborValue::Bool { value } => Self::Bool { value },
+            SborValue::I8 { value } => Self::I8 { value },
+            SborValue::I16 { value } => Self::I16 { value },
+            SborValue::I32 { value } => Self::I32 { value },
+            SborValue::I64 { value } => Self::I64 { value },
+            SborValue::I128 { value } => Self::I128 { value },
+            SborValue::U8 { value } => Self::U8 { value },
+            SborValue::U16 { value } => Self::U16 { value },
+            SborValue::U32 { value } => Self::U32 { value },
+            SborValue::U64 { value } => Self::U64 { value },
+            SborValue::U128 { value } => Self::U128 { value },
+            SborValue::String { value } => Self::String { value },
+            SborValue::Enum {
+                discriminator,
+                fields,
+            } => Self::Enum {
+                discriminator,
+                fields: fields
+                    .into_iter()
+                    .map(|value| {
+                        Self::from_scrypto_value_owned(value, network_id)
+                    })
+                    .collect(),
+            },
+            SborValue::Array {
+                element_value_kind,
+                elements,
+            } => match scrypto_value_bytes(&elements) {
+                Some(value) => Self::Bytes {
+                    element_value_kind: element_value_kind.into(),
+                    value,
+                },
+                None => Self::Array {
+                    element_value_kind: element_value_kind.into(),
+                    elements: elements
+                        .into_iter()
+                        .map(|value| {
+                            Self::from_scrypto_value_owned(value, network_id)
+                        })
+                        .collect(),
+                },
+            },
+            SborValue::Tuple { fields } => Self::Tuple {
+                fields: fields
+                    .into_iter()
+                    .map(|value| {
+                        Self::from_scrypto_value_owned(value, network_id)
+                    })
+                    .collect(),
+            },
+            SborValue::Map {
+                key_value_kind,
+                value_value_kind,
+                entries,
+            } => Self::Map {
+                key_value_kind: key_value_kind.into(),
+                value_value_kind: value_value_kind.into(),
+                entries: entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        (
+                            Self::from_scrypto_value_owned(key, network_id),
+                            Self::from_scrypto_value_owned(value, network_id),
+                        )
+                    })
+                    .collect(),
+            },
+            SborValue::Custom {
+                value: ScryptoCustomValue::Reference(value),
+            } => Self::Reference {
+                value: SerializableNodeId(value.0, network_id),
+            },
+            SborValue::Custom {
+                value: ScryptoCustomValue::Own(value),
+            } => Self::Own {
+                value: SerializableNodeId(value.0, network_id),
+            },
+            SborValue::Custom {
+                value: ScryptoCustomValue::Decimal(value),
+            } => Self::Decimal { value },
+            SborValue::Custom {
+                value: ScryptoCustomValue::PreciseDecimal(value),
+            } => Self::PreciseDecimal { value },
+            SborValue::Custom {
+                value: ScryptoCustomValue::NonFungibleLocalId(value),
+            } => Self::NonFungibleLocalId { value },
+        }
+    }
+
+    /// Returns the string held by this value if it's a [`Self::String`]. The
+    /// string is borrowed from the value and not copied.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String { value } => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes held by this value if it's a [`Self::Bytes`] or an
+    /// [`Self::Array`] of [`Self::U8`]s. Bytes are borrowed from the value
+    /// when possible and are only collected into a new vector in the case of
+    /// the array.
+    pub fn as_bytes(&self) -> Option<Cow<'_, [u8]>> {
+        match self {
+            Self::Bytes { value, .. } => Some(Cow::Borrowed(value.as_slice())),
+            Self::Array { elements, .. } => elements
+                .iter()
+                .map(|element| match element {
+                    Self::U8 { value } => Some(*value),
+                    _ => None,
+                })
+                .collect::<Option<Vec<u8>>>()
+                .map(Cow::Owned),
+            _ => None,
+        }
+    }
+}
+
+/// Collects the elements of a [`ScryptoValue`] array into a byte vector of the
+/// exact size required if all of them are [`ScryptoValue::U8`], otherwise
+/// [`None`] is returned without allocating.
+fn scrypto_value_bytes(elements: &[ScryptoValue]) -> Option<Vec<u8>> {
+    if !elements
+        .iter()
+        .all(|element| matches!(element, ScryptoValue::U8 { .. }))
+    {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(elements.len());
+    bytes.extend(elements.iter().map_while(|element| match element {
+        ScryptoValue::U8 { value } => Some(*value),
+        _ => None,
+    }));
+    Some(bytes)
 }
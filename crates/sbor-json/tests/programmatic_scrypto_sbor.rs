@@ -24,6 +24,7 @@ use sbor_json::scrypto::programmatic::utils::value_contains_network_mismatch;
 use sbor_json::scrypto::programmatic::value::{
     ProgrammaticScryptoValue, ProgrammaticScryptoValueKind,
 };
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 serialization_tests! {
@@ -200,6 +201,64 @@ pub fn value_with_two_address_of_the_differing_networks_has_a_network_mismatch()
     assert!(contains_network_mismatch)
 }
 
+#[test]
+pub fn owned_and_borrowed_conversions_from_scrypto_value_are_equal() {
+    // Arrange
+    let value = scrypto_decode::<ScryptoValue>(
+        &scrypto_encode(&(
+            "Hello World".to_owned(),
+            vec![1u8, 2, 3],
+            vec![NonFungibleLocalId::integer(1)],
+            indexmap!(1u32 => dec!("10")),
+        ))
+        .unwrap(),
+    )
+    .unwrap();
+
+    // Act
+    let borrowed = ProgrammaticScryptoValue::from_scrypto_value(&value, 0xF2);
+    let owned =
+        ProgrammaticScryptoValue::from_scrypto_value_owned(value.clone(), 0xF2);
+
+    // Assert
+    assert_eq!(borrowed, owned);
+    assert_eq!(owned.to_scrypto_value(), value);
+    assert_eq!(owned.into_scrypto_value(), value);
+}
+
+#[test]
+pub fn bytes_of_a_bytes_value_are_borrowed() {
+    // Arrange
+    let value = ProgrammaticScryptoValue::Bytes {
+        element_value_kind: ProgrammaticScryptoValueKind::U8,
+        value: vec![1, 2, 3],
+    };
+
+    // Act
+    let bytes = value.as_bytes();
+
+    // Assert
+    assert!(matches!(bytes, Some(Cow::Borrowed([1, 2, 3]))))
+}
+
+#[test]
+pub fn bytes_of_an_array_of_u8_are_collected() {
+    // Arrange
+    let value = ProgrammaticScryptoValue::Array {
+        element_value_kind: ProgrammaticScryptoValueKind::U8,
+        elements: vec![
+            ProgrammaticScryptoValue::U8 { value: 1 },
+            ProgrammaticScryptoValue::U8 { value: 2 },
+        ],
+    };
+
+    // Act
+    let bytes = value.as_bytes();
+
+    // Assert
+    assert_eq!(bytes.as_deref(), Some([1u8, 2].as_slice()))
+}
+
 /// Tests that the programmatic JSON representation from the
 /// radixdlt/radixdlt-scrypto repo and the one in this repo match.
 pub fn programmatic_json_representations_match<T>(object: &T)
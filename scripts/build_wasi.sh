@@ -0,0 +1,15 @@
+SCRIPT_DIR=$( cd -- "$( dirname -- "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )
+
+cargo +nightly-2024-02-01 build \
+    --manifest-path="$SCRIPT_DIR/../Cargo.toml" \
+    --package radix-engine-toolkit-json \
+    --bin radix_engine_toolkit_wasi \
+    --target wasm32-wasi \
+    --release
+npx wasm-opt@1.4 -Oz -g \
+    --strip-dwarf \
+    --strip-debug \
+    --strip-producers \
+    -o \
+    "$SCRIPT_DIR/../target/wasm32-wasi/release/radix_engine_toolkit_wasi.wasm" \
+    "$SCRIPT_DIR/../target/wasm32-wasi/release/radix_engine_toolkit_wasi.wasm"
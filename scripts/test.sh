@@ -1,4 +1,4 @@
 SCRIPT_DIR=$( cd -- "$( dirname -- "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )
 
 cd $SCRIPT_DIR/..
-JAVA_OPTS="-Xmx8g" cargo nextest run
+JAVA_OPTS="-Xmx8g" cargo nextest run --features radix-engine-toolkit/test-utils,radix-engine-toolkit/native-blueprint-interfaces